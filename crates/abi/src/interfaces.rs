@@ -244,23 +244,43 @@ impl<'a> CompositeSource<'a> {
         false
     }
 
-    /// Collects all struct (non-record) composites as ABI structs.
+    /// Collects all struct (non-record, non-enum) composites as ABI structs.
     fn all_structs(&self) -> Vec<abi::Struct> {
         let mut out = Vec::new();
         match self {
             CompositeSource::Program { scope, modules, .. } => {
-                out.extend(scope.composites.iter().filter(|(_, c)| !c.is_record).map(|(_, c)| convert_struct(c, &[])));
+                out.extend(
+                    scope
+                        .composites
+                        .iter()
+                        .filter(|(_, c)| !c.is_record && !c.is_enum())
+                        .map(|(_, c)| convert_struct(c, &[])),
+                );
                 for (mp, module) in *modules {
                     out.extend(
-                        module.composites.iter().filter(|(_, c)| !c.is_record).map(|(_, c)| convert_struct(c, mp)),
+                        module
+                            .composites
+                            .iter()
+                            .filter(|(_, c)| !c.is_record && !c.is_enum())
+                            .map(|(_, c)| convert_struct(c, mp)),
                     );
                 }
             }
             CompositeSource::Library { library, .. } => {
-                out.extend(library.structs.iter().filter(|(_, c)| !c.is_record).map(|(_, c)| convert_struct(c, &[])));
+                out.extend(
+                    library
+                        .structs
+                        .iter()
+                        .filter(|(_, c)| !c.is_record && !c.is_enum())
+                        .map(|(_, c)| convert_struct(c, &[])),
+                );
                 for (mp, module) in &library.modules {
                     out.extend(
-                        module.composites.iter().filter(|(_, c)| !c.is_record).map(|(_, c)| convert_struct(c, mp)),
+                        module
+                            .composites
+                            .iter()
+                            .filter(|(_, c)| !c.is_record && !c.is_enum())
+                            .map(|(_, c)| convert_struct(c, mp)),
                     );
                 }
             }
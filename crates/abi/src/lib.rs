@@ -59,9 +59,14 @@ pub fn generate(ast: &ast::Program) -> abi::Program {
 
     let program = scope.program_id.to_string();
 
-    // Collect program-scope composites (path = [name])
-    let mut structs: Vec<abi::Struct> =
-        scope.composites.iter().filter(|(_, c)| !c.is_record).map(|(_, c)| convert_struct(c, &[])).collect();
+    // Collect program-scope composites (path = [name]). Enums are still unlowered here, but they
+    // can never appear in the public interface, so they are left out.
+    let mut structs: Vec<abi::Struct> = scope
+        .composites
+        .iter()
+        .filter(|(_, c)| !c.is_record && !c.is_enum())
+        .map(|(_, c)| convert_struct(c, &[]))
+        .collect();
 
     let mut records: Vec<abi::Record> =
         scope.composites.iter().filter(|(_, c)| c.is_record).map(|(_, c)| convert_record(c, &[])).collect();
@@ -71,7 +76,7 @@ pub fn generate(ast: &ast::Program) -> abi::Program {
         for (_, composite) in &module.composites {
             if composite.is_record {
                 records.push(convert_record(composite, module_path));
-            } else {
+            } else if !composite.is_enum() {
                 structs.push(convert_struct(composite, module_path));
             }
         }
@@ -17,7 +17,10 @@
 pub mod member;
 pub use member::*;
 
-use crate::{ConstParameter, Identifier, Indent, Mode, Node, NodeID, ProgramId, Type};
+pub mod variant;
+pub use variant::*;
+
+use crate::{ConstParameter, Function, Identifier, Indent, Mode, Node, NodeID, ProgramId, Type};
 use leo_span::{Span, Symbol};
 
 use itertools::Itertools;
@@ -32,8 +35,14 @@ use snarkvm::{
     },
 };
 
+/// The maximum number of variants of an enum, as the variant of an enum value is stored in a `u8` tag.
+pub const MAX_ENUM_VARIANTS: usize = 256;
+
 /// A composite type definition, e.g., `struct Foo { my_field: Bar }` and `record Token { owner: address, amount: u64}`.
 ///
+/// Enums such as `enum Shape { Empty, Circle(u32) }` are composites too, with `variants` set and no members,
+/// until they are lowered to tagged structs.
+///
 /// Type identity is decided by the full path including `identifier`,
 /// as the record is nominal, not structural.
 /// The fields are named so `struct Foo(u8, u16)` is not allowed.
@@ -52,6 +61,11 @@ pub struct Composite {
     /// Was this a `record Foo { ... }`?
     /// If so, it wasn't a composite.
     pub is_record: bool,
+    /// The variants of the type if it was an `enum Foo { ... }`.
+    pub variants: Option<Vec<EnumVariant>>,
+    /// The associated functions declared in `impl Foo { ... }` blocks, named `Foo::name`,
+    /// until they are lowered to functions of the enclosing module.
+    pub functions: Vec<(Symbol, Function)>,
     /// The entire span of the composite definition.
     pub span: Span,
     /// The ID of the node.
@@ -72,6 +86,21 @@ impl Composite {
         self.identifier.name
     }
 
+    /// Returns `true` if this composite is an enum.
+    pub fn is_enum(&self) -> bool {
+        self.variants.is_some()
+    }
+
+    /// Returns the name `Type::name` given to the associated function `name` of the type named `type_name`.
+    pub fn associated_function_name(type_name: Symbol, name: Symbol) -> Symbol {
+        Symbol::intern(&format!("{type_name}::{name}"))
+    }
+
+    /// Returns the variant named `name`, if this composite is an enum with such a variant.
+    pub fn variant(&self, name: Symbol) -> Option<&EnumVariant> {
+        self.variants.as_ref()?.iter().find(|variant| variant.name() == name)
+    }
+
     pub fn from_external_record<N: Network>(input: &RecordType<N>, program_id: ProgramId) -> Self {
         let mut members = Vec::with_capacity(input.entries().len() + 1);
         members.push(Member {
@@ -98,6 +127,8 @@ impl Composite {
             const_parameters: Vec::new(),
             members,
             is_record: true,
+            variants: None,
+            functions: Vec::new(),
             span: Default::default(),
             id: Default::default(),
         }
@@ -120,6 +151,8 @@ impl Composite {
                 })
                 .collect(),
             is_record: false,
+            variants: None,
+            functions: Vec::new(),
             span: Default::default(),
             id: Default::default(),
         }
@@ -131,6 +164,13 @@ impl fmt::Display for Composite {
         if self.is_exported == Some(true) {
             f.write_str("export ")?;
         }
+        if let Some(variants) = &self.variants {
+            writeln!(f, "enum {} {{", self.identifier)?;
+            for variant in variants.iter() {
+                writeln!(f, "{},", Indent(variant))?;
+            }
+            return write!(f, "}}");
+        }
         f.write_str(if self.is_record { "record" } else { "struct" })?;
         write!(f, " {}", self.identifier)?;
         if !self.const_parameters.is_empty() {
@@ -141,7 +181,20 @@ impl fmt::Display for Composite {
         for field in self.members.iter() {
             writeln!(f, "{},", Indent(field))?;
         }
-        write!(f, "}}")
+        write!(f, "}}")?;
+
+        if !self.functions.is_empty() {
+            write!(f, "\nimpl {}", self.identifier)?;
+            if !self.const_parameters.is_empty() {
+                write!(f, "::[{}]", self.const_parameters.iter().format(", "))?;
+            }
+            writeln!(f, " {{")?;
+            for (_, function) in self.functions.iter() {
+                writeln!(f, "{}", Indent(function))?;
+            }
+            write!(f, "}}")?;
+        }
+        Ok(())
     }
 }
 
@@ -194,6 +194,15 @@ fn evaluate_intrinsic_inner(
         Intrinsic::DynamicCall | Intrinsic::DynamicContains | Intrinsic::DynamicGet | Intrinsic::DynamicGetOrUse => {
             return Ok(None);
         }
+        // Cheatcodes act on the interpreter running a native test.
+        Intrinsic::TestSetCaller
+        | Intrinsic::TestSetSigner
+        | Intrinsic::TestWarp
+        | Intrinsic::TestExpectRevert
+        | Intrinsic::TestSnapshot
+        | Intrinsic::TestRestore => {
+            return Ok(None);
+        }
     };
 
     Ok(Some(value))
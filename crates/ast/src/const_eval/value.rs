@@ -623,6 +623,16 @@ impl Value {
         Some(array[i].clone().into())
     }
 
+    /// The number of elements of an array value, or `None` if this is not an array.
+    pub fn array_len(&self) -> Option<usize> {
+        let plaintext: &SvmPlaintext = self.try_as_ref()?;
+        let Plaintext::Array(array, ..) = plaintext else {
+            return None;
+        };
+
+        Some(array.len())
+    }
+
     pub fn array_index_set(&mut self, i: usize, value: Self) -> Option<()> {
         let plaintext_rhs: SvmPlaintext = value.try_into().ok()?;
 
@@ -695,6 +705,20 @@ impl Value {
                     member.to_string().parse().expect("Member name should be valid identifier");
                 Some(map.get(&identifier)?.clone().into())
             }
+            ValueVariants::Svm(SvmValueParam::Record(record)) => {
+                if member == Symbol::intern("owner") {
+                    return Some(match record.owner() {
+                        Owner::Public(address) => (*address).into(),
+                        Owner::Private(plaintext) => plaintext.clone().into(),
+                    });
+                }
+                let identifier: SvmIdentifier = member.to_string().parse().ok()?;
+                match record.data().get(&identifier)? {
+                    Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                        Some(plaintext.clone().into())
+                    }
+                }
+            }
             _ => None,
         }
     }
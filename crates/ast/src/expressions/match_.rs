@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use itertools::Itertools;
+
+/// The pattern of a match arm, e.g. `Shape::Circle(r)` or `_`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchPattern {
+    /// The wildcard pattern `_`, matching any value.
+    Wildcard,
+    /// A pattern matching one variant of an enum, e.g. `Shape::Rect(w, _)`.
+    Variant {
+        /// The path to the variant, e.g. `Shape::Rect`.
+        path: Path,
+        /// The names bound to the payload of the variant, `None` for `_`.
+        bindings: Vec<Option<Identifier>>,
+    },
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Wildcard => write!(f, "_"),
+            MatchPattern::Variant { path, bindings } if bindings.is_empty() => write!(f, "{path}"),
+            MatchPattern::Variant { path, bindings } => write!(
+                f,
+                "{path}({})",
+                bindings.iter().format_with(", ", |binding, f| match binding {
+                    Some(binding) => f(binding),
+                    None => f(&"_"),
+                })
+            ),
+        }
+    }
+}
+
+/// An arm `pattern => expression` of a match expression.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchArm {
+    /// The pattern deciding whether the arm is taken.
+    pub pattern: MatchPattern,
+    /// The expression the match evaluates to if the arm is taken.
+    pub expression: Expression,
+    /// The span from the pattern to the expression.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.expression)
+    }
+}
+
+crate::simple_node_impl!(MatchArm);
+
+/// A match expression, e.g. `match shape { Shape::Circle(r) => r, _ => 0u32 }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchExpression {
+    /// The enum value being matched on.
+    pub scrutinee: Expression,
+    /// The arms, tried in order.
+    pub arms: Vec<MatchArm>,
+    /// The span from `match` to the closing `}`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "match {} {{ {} }}", self.scrutinee, self.arms.iter().format(", "))
+    }
+}
+
+impl From<MatchExpression> for Expression {
+    fn from(value: MatchExpression) -> Self {
+        Expression::Match(Box::new(value))
+    }
+}
+
+crate::simple_node_impl!(MatchExpression);
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use itertools::Itertools as _;
+
+/// A method call expression, e.g. `point.add(other)`, calling an associated function of the receiver's type
+/// that takes `self` as its first parameter.
+///
+/// Method calls are lowered to ordinary calls `Point::add(point, other)` once types are known.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MethodCallExpression {
+    /// The value the method is called on, passed as `self`.
+    pub receiver: Expression,
+    /// The name of the method.
+    pub name: Identifier,
+    /// Expressions for the const arguments passed to the method's own const parameters.
+    pub const_arguments: Vec<Expression>,
+    /// Expressions for the arguments passed to the method's parameters after `self`.
+    pub arguments: Vec<Expression>,
+    /// Span of the entire call `receiver.name(arguments)`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MethodCallExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.receiver.precedence() < 20 {
+            write!(f, "({})", self.receiver)?;
+        } else {
+            write!(f, "{}", self.receiver)?;
+        }
+        write!(f, ".{}", self.name)?;
+        if !self.const_arguments.is_empty() {
+            write!(f, "::[{}]", self.const_arguments.iter().format(", "))?;
+        }
+        write!(f, "({})", self.arguments.iter().format(", "))
+    }
+}
+
+impl From<MethodCallExpression> for Expression {
+    fn from(value: MethodCallExpression) -> Self {
+        Expression::MethodCall(Box::new(value))
+    }
+}
+
+crate::simple_node_impl!(MethodCallExpression);
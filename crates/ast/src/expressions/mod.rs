@@ -47,9 +47,15 @@ pub use dynamic_op::*;
 mod err;
 pub use err::*;
 
+mod match_;
+pub use match_::*;
+
 mod member_access;
 pub use member_access::*;
 
+mod method_call;
+pub use method_call::*;
+
 mod intrinsic;
 pub use intrinsic::*;
 
@@ -103,8 +109,12 @@ pub enum Expression {
     Path(Path),
     /// A literal expression.
     Literal(Literal),
+    /// A match expression, e.g., `match shape { Shape::Circle(r) => r, _ => 0u32 }`.
+    Match(Box<MatchExpression>),
     /// An access of a composite member, e.g. `composite.member`.
     MemberAccess(Box<MemberAccess>),
+    /// A method call on a value, e.g. `point.add(other)`.
+    MethodCall(Box<MethodCallExpression>),
     /// An array expression constructed from one repeated element, e.g., `[1u32; 5]`.
     Repeat(Box<RepeatExpression>),
     /// A ternary conditional expression `cond ? if_expr : else_expr`.
@@ -141,7 +151,9 @@ impl Node for Expression {
             Intrinsic(n) => n.span(),
             Path(n) => n.span(),
             Literal(n) => n.span(),
+            Match(n) => n.span(),
             MemberAccess(n) => n.span(),
+            MethodCall(n) => n.span(),
             Repeat(n) => n.span(),
             Ternary(n) => n.span(),
             Tuple(n) => n.span(),
@@ -166,7 +178,9 @@ impl Node for Expression {
             Intrinsic(n) => n.set_span(span),
             Path(n) => n.set_span(span),
             Literal(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             MemberAccess(n) => n.set_span(span),
+            MethodCall(n) => n.set_span(span),
             Repeat(n) => n.set_span(span),
             Ternary(n) => n.set_span(span),
             Tuple(n) => n.set_span(span),
@@ -189,7 +203,9 @@ impl Node for Expression {
             Composite(n) => n.id(),
             Path(n) => n.id(),
             Literal(n) => n.id(),
+            Match(n) => n.id(),
             MemberAccess(n) => n.id(),
+            MethodCall(n) => n.id(),
             Repeat(n) => n.id(),
             Err(n) => n.id(),
             Intrinsic(n) => n.id(),
@@ -214,7 +230,9 @@ impl Node for Expression {
             Composite(n) => n.set_id(id),
             Path(n) => n.set_id(id),
             Literal(n) => n.set_id(id),
+            Match(n) => n.set_id(id),
             MemberAccess(n) => n.set_id(id),
+            MethodCall(n) => n.set_id(id),
             Repeat(n) => n.set_id(id),
             Err(n) => n.set_id(id),
             Intrinsic(n) => n.set_id(id),
@@ -243,7 +261,9 @@ impl fmt::Display for Expression {
             Intrinsic(n) => n.fmt(f),
             Path(n) => n.fmt(f),
             Literal(n) => n.fmt(f),
+            Match(n) => n.fmt(f),
             MemberAccess(n) => n.fmt(f),
+            MethodCall(n) => n.fmt(f),
             Repeat(n) => n.fmt(f),
             Ternary(n) => n.fmt(f),
             Tuple(n) => n.fmt(f),
@@ -269,7 +289,8 @@ impl Expression {
             Cast(_) => 12,
             Ternary(_) => 0,
             Array(_) | ArrayAccess(_) | Async(_) | Call(_) | DynamicOp(_) | Composite(_) | Err(_) | Intrinsic(_)
-            | Path(_) | Literal(_) | MemberAccess(_) | Repeat(_) | Tuple(_) | TupleAccess(_) | Unary(_) | Unit(_) => 20,
+            | Path(_) | Literal(_) | Match(_) | MemberAccess(_) | MethodCall(_) | Repeat(_) | Tuple(_)
+            | TupleAccess(_) | Unary(_) | Unit(_) => 20,
         }
     }
 
@@ -325,6 +346,7 @@ impl Expression {
             // We may be indirectly referring to an impure item
             // This analysis could be more granular
             Expression::Call(..)
+            | Expression::MethodCall(..)
             | Expression::DynamicOp(..)
             | Expression::Err(..)
             | Expression::Async(..)
@@ -376,6 +398,9 @@ impl Expression {
             Expression::Ternary(expr) => {
                 expr.condition.is_pure(get_type) && expr.if_true.is_pure(get_type) && expr.if_false.is_pure(get_type)
             }
+            Expression::Match(expr) => {
+                expr.scrutinee.is_pure(get_type) && expr.arms.iter().all(|arm| arm.expression.is_pure(get_type))
+            }
             Expression::Tuple(expr) => expr.elements.iter().all(|e| e.is_pure(get_type)),
         }
     }
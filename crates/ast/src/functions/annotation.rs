@@ -38,6 +38,39 @@ pub struct Annotation {
 
 simple_node_impl!(Annotation);
 
+impl Annotation {
+    /// Splits a bracketed, comma-separated list written in the value of a key, like the rows of the `inputs` of
+    /// `@test`, into its trimmed elements, or returns `None` if `text` is not one.
+    pub fn split_list(text: &str) -> Option<Vec<&str>> {
+        let inner = text.trim().strip_prefix('[')?.strip_suffix(']')?;
+        let mut elements = Vec::new();
+        let (mut depth, mut start) = (0usize, 0);
+        for (i, c) in inner.char_indices() {
+            match c {
+                '[' | '{' | '(' => depth += 1,
+                ']' | '}' | ')' => depth = depth.checked_sub(1)?,
+                ',' if depth == 0 => {
+                    elements.push(inner[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            return None;
+        }
+        // Allow a trailing comma.
+        let last = inner[start..].trim();
+        if !last.is_empty() {
+            elements.push(last);
+        }
+        if elements.iter().any(|element| element.is_empty()) {
+            return None;
+        }
+        Some(elements)
+    }
+}
+
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "@{}", self.identifier)
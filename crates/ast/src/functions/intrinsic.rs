@@ -96,6 +96,27 @@ pub enum Intrinsic {
     BlockHeight,
     BlockTimestamp,
     NetworkId,
+
+    // Test cheatcodes, only evaluated by the interpreter behind native tests.
+    TestSetCaller,
+    TestSetSigner,
+    TestWarp,
+    TestExpectRevert,
+    TestSnapshot,
+    TestRestore,
+}
+
+/// The Leo-level shape of an intrinsic call, as returned by [`Intrinsic::signature`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntrinsicSignature {
+    /// Parameters as `(name, type)` pairs, including any receiver of the method-call form.
+    pub parameters: Vec<(&'static str, String)>,
+    /// The return type, `()` when the intrinsic produces no value.
+    pub output: String,
+    /// Names of the type parameters supplied as `::[..]` at the call site.
+    pub type_parameters: Vec<&'static str>,
+    /// Whether the last parameter may repeat any number of times.
+    pub variadic: bool,
 }
 
 impl Intrinsic {
@@ -114,6 +135,12 @@ impl Intrinsic {
             sym::_block_height => Self::BlockHeight,
             sym::_block_timestamp => Self::BlockTimestamp,
             sym::_network_id => Self::NetworkId,
+            sym::_test_set_caller => Self::TestSetCaller,
+            sym::_test_set_signer => Self::TestSetSigner,
+            sym::_test_warp => Self::TestWarp,
+            sym::_test_expect_revert => Self::TestExpectRevert,
+            sym::_test_snapshot => Self::TestSnapshot,
+            sym::_test_restore => Self::TestRestore,
             sym::_deserialize_from_bits => {
                 // The type parameter count is validated in the type checker. Use `Type::Err` as a
                 // placeholder when the parameter is absent so that `from_symbol` always returns
@@ -1235,6 +1262,8 @@ impl Intrinsic {
             Self::BlockHeight => 0,
             Self::BlockTimestamp => 0,
             Self::NetworkId => 0,
+            Self::TestExpectRevert => 0,
+            Self::TestSnapshot => 0,
 
             Self::Commit(_, _) => 2,
             Self::Hash(_, _) => 1,
@@ -1277,6 +1306,170 @@ impl Intrinsic {
             Self::DynamicContains => 4,
             Self::DynamicGet => 4,
             Self::DynamicGetOrUse => 5,
+
+            Self::TestSetCaller => 1,
+            Self::TestSetSigner => 1,
+            Self::TestWarp => 1,
+            Self::TestRestore => 1,
+        }
+    }
+
+    /// Returns the parameters and return type of the intrinsic as Leo source, for editor tooling.
+    ///
+    /// Positions the type checker resolves from the arguments are spelled with
+    /// placeholders: `T` for any value, `K`/`V` for mapping keys and values, and
+    /// `N`/`M` for array lengths.
+    pub fn signature(&self) -> IntrinsicSignature {
+        // Byte sizes of `snarkvm`'s `ECDSASignature`, which is unavailable in wasm builds.
+        const ECDSA_SIGNATURE: &str = "[u8; 65]";
+        const ECDSA_VERIFYING_KEY: &str = "[u8; 33]";
+        const ETHEREUM_ADDRESS: &str = "[u8; 20]";
+        const ECDSA_PREHASH: &str = "[u8; 32]";
+        const CHECKSUM: &str = "[u8; 32]";
+
+        let param = |name: &'static str, type_: &str| (name, type_.to_string());
+        let mapping = || param("mapping", "mapping K => V");
+        let vector = || param("vector", "[T]");
+        let signature = |parameters: Vec<(&'static str, String)>, output: &str| IntrinsicSignature {
+            parameters,
+            output: output.to_string(),
+            type_parameters: Vec::new(),
+            variadic: false,
+        };
+        // Dynamic dispatch takes the target as `(program, network, item)` identifiers or fields.
+        let dynamic_target =
+            || vec![param("program", "identifier"), param("network", "identifier"), param("name", "identifier")];
+
+        match self {
+            Self::ChaChaRand(type_) => signature(vec![], &Type::from(*type_).to_string()),
+            Self::Commit(_, type_) => {
+                signature(vec![param("value", "T"), param("randomness", "scalar")], &Type::from(*type_).to_string())
+            }
+            Self::ECDSAVerify(variant) => {
+                let is_eth = matches!(
+                    variant,
+                    ECDSAVerifyVariant::DigestEth
+                        | ECDSAVerifyVariant::HashKeccak256Eth
+                        | ECDSAVerifyVariant::HashKeccak384Eth
+                        | ECDSAVerifyVariant::HashKeccak512Eth
+                        | ECDSAVerifyVariant::HashSha3_256Eth
+                        | ECDSAVerifyVariant::HashSha3_384Eth
+                        | ECDSAVerifyVariant::HashSha3_512Eth
+                );
+                let is_digest = matches!(variant, ECDSAVerifyVariant::Digest | ECDSAVerifyVariant::DigestEth);
+                signature(
+                    vec![
+                        param("signature", ECDSA_SIGNATURE),
+                        if is_eth {
+                            param("address", ETHEREUM_ADDRESS)
+                        } else {
+                            param("public_key", ECDSA_VERIFYING_KEY)
+                        },
+                        if is_digest { param("digest", ECDSA_PREHASH) } else { param("message", "T") },
+                    ],
+                    "bool",
+                )
+            }
+            Self::Hash(_, type_) => signature(vec![param("value", "T")], &type_.to_string()),
+
+            Self::MappingGet => signature(vec![mapping(), param("key", "K")], "V"),
+            Self::MappingGetOrUse => signature(vec![mapping(), param("key", "K"), param("default", "V")], "V"),
+            Self::MappingSet => signature(vec![mapping(), param("key", "K"), param("value", "V")], "()"),
+            Self::MappingRemove => signature(vec![mapping(), param("key", "K")], "()"),
+            Self::MappingContains => signature(vec![mapping(), param("key", "K")], "bool"),
+
+            Self::OptionalUnwrap => signature(vec![param("optional", "T?")], "T"),
+            Self::OptionalUnwrapOr => signature(vec![param("optional", "T?"), param("default", "T")], "T"),
+
+            Self::VectorGet => signature(vec![vector(), param("index", "u32")], "T?"),
+            Self::VectorSet => signature(vec![vector(), param("index", "u32"), param("value", "T")], "()"),
+            Self::VectorPush => signature(vec![vector(), param("value", "T")], "()"),
+            Self::VectorLen => signature(vec![vector()], "u32"),
+            Self::VectorClear => signature(vec![vector()], "()"),
+            Self::VectorPop => signature(vec![vector()], "T?"),
+            Self::VectorSwapRemove => signature(vec![vector(), param("index", "u32")], "T"),
+
+            Self::GroupToXCoordinate | Self::GroupToYCoordinate => signature(vec![param("point", "group")], "field"),
+            Self::GroupGen | Self::AleoGenerator => signature(vec![], "group"),
+            Self::AleoGeneratorPowers => signature(vec![], "[group; 251]"),
+
+            Self::SignatureVerify => signature(
+                vec![param("signature", "signature"), param("signer", "address"), param("message", "T")],
+                "bool",
+            ),
+            Self::SnarkVerify => signature(
+                vec![
+                    param("verifying_key", "[u8; N]"),
+                    param("varuna_version", "u8"),
+                    param("inputs", "[field; M]"),
+                    param("proof", "[u8; P]"),
+                ],
+                "bool",
+            ),
+            Self::SnarkVerifyBatch => signature(
+                vec![
+                    param("verifying_keys", "[[u8; N]; M]"),
+                    param("varuna_version", "u8"),
+                    param("inputs", "[[[field; I]; J]; M]"),
+                    param("proof", "[u8; P]"),
+                ],
+                "bool",
+            ),
+            Self::FinalRun => signature(vec![param("final", "Final")], "()"),
+
+            Self::ProgramChecksum => signature(vec![param("program", "address")], CHECKSUM),
+            Self::ProgramEdition => signature(vec![param("program", "address")], "u16"),
+            Self::ProgramOwner => signature(vec![param("program", "address")], "address"),
+            Self::FunctionChecksum => {
+                signature(vec![param("program", "address"), param("function", "identifier")], CHECKSUM)
+            }
+
+            Self::Serialize(_) => signature(vec![param("value", "T")], "[bool; N]"),
+            Self::Deserialize(_, type_) => {
+                let mut signature = signature(vec![param("bits", "[bool; N]")], "T");
+                if matches!(type_, Type::Err) {
+                    signature.type_parameters = vec!["T"];
+                } else {
+                    signature.output = type_.to_string();
+                }
+                signature
+            }
+
+            Self::DynamicCall => IntrinsicSignature {
+                parameters: dynamic_target().into_iter().chain([param("inputs", "T")]).collect(),
+                output: "R".to_string(),
+                type_parameters: vec!["R"],
+                variadic: true,
+            },
+            Self::DynamicContains => {
+                signature(dynamic_target().into_iter().chain([param("key", "K")]).collect(), "bool")
+            }
+            Self::DynamicGet => IntrinsicSignature {
+                type_parameters: vec!["V"],
+                ..signature(dynamic_target().into_iter().chain([param("key", "K")]).collect(), "V")
+            },
+            Self::DynamicGetOrUse => IntrinsicSignature {
+                type_parameters: vec!["V"],
+                ..signature(
+                    dynamic_target().into_iter().chain([param("key", "K"), param("default", "V")]).collect(),
+                    "V",
+                )
+            },
+
+            Self::SelfAddress | Self::SelfCaller | Self::SelfId | Self::SelfProgramOwner | Self::SelfSigner => {
+                signature(vec![], "address")
+            }
+            Self::SelfChecksum => signature(vec![], CHECKSUM),
+            Self::SelfEdition | Self::NetworkId => signature(vec![], "u16"),
+            Self::BlockHeight => signature(vec![], "u32"),
+            Self::BlockTimestamp => signature(vec![], "i64"),
+
+            Self::TestSetCaller => signature(vec![param("caller", "address")], "()"),
+            Self::TestSetSigner => signature(vec![param("signer", "address")], "()"),
+            Self::TestWarp => signature(vec![param("height", "u32")], "()"),
+            Self::TestExpectRevert => signature(vec![], "()"),
+            Self::TestSnapshot => signature(vec![], "u32"),
+            Self::TestRestore => signature(vec![param("snapshot", "u32")], "()"),
         }
     }
 
@@ -1330,7 +1523,14 @@ impl Intrinsic {
             | Intrinsic::NetworkId
             | Intrinsic::SignatureVerify
             | Intrinsic::Serialize(_)
-            | Intrinsic::Deserialize(_, _) => false,
+            | Intrinsic::Deserialize(_, _)
+            // Cheatcodes may appear anywhere in a native test.
+            | Intrinsic::TestSetCaller
+            | Intrinsic::TestSetSigner
+            | Intrinsic::TestWarp
+            | Intrinsic::TestExpectRevert
+            | Intrinsic::TestSnapshot
+            | Intrinsic::TestRestore => false,
         }
     }
 
@@ -1369,7 +1569,14 @@ impl Intrinsic {
             // Random draws advance the generator state.
             | Intrinsic::ChaChaRand(_)
             // Deserialization can halt on invalid encodings.
-            | Intrinsic::Deserialize(_, _) => false,
+            | Intrinsic::Deserialize(_, _)
+            // Cheatcodes change the state and context later code observes.
+            | Intrinsic::TestSetCaller
+            | Intrinsic::TestSetSigner
+            | Intrinsic::TestWarp
+            | Intrinsic::TestExpectRevert
+            | Intrinsic::TestSnapshot
+            | Intrinsic::TestRestore => false,
             Intrinsic::ProgramChecksum
             | Intrinsic::ProgramEdition
             | Intrinsic::ProgramOwner
@@ -1405,3 +1612,84 @@ impl Intrinsic {
             || matches!(self, Intrinsic::Commit(_, _) | Intrinsic::ECDSAVerify(_) | Intrinsic::Deserialize(_, _))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_parameters_match_num_args() {
+        let intrinsics = [
+            Intrinsic::ChaChaRand(LiteralType::U8),
+            Intrinsic::Commit(CommitVariant::CommitBHP256, LiteralType::Field),
+            Intrinsic::ECDSAVerify(ECDSAVerifyVariant::DigestEth),
+            Intrinsic::Hash(HashVariant::HashBHP256, Type::Field),
+            Intrinsic::MappingGet,
+            Intrinsic::MappingGetOrUse,
+            Intrinsic::MappingSet,
+            Intrinsic::MappingRemove,
+            Intrinsic::MappingContains,
+            Intrinsic::OptionalUnwrap,
+            Intrinsic::OptionalUnwrapOr,
+            Intrinsic::VectorGet,
+            Intrinsic::VectorSet,
+            Intrinsic::VectorPush,
+            Intrinsic::VectorLen,
+            Intrinsic::VectorClear,
+            Intrinsic::VectorPop,
+            Intrinsic::VectorSwapRemove,
+            Intrinsic::GroupToXCoordinate,
+            Intrinsic::GroupToYCoordinate,
+            Intrinsic::GroupGen,
+            Intrinsic::AleoGenerator,
+            Intrinsic::AleoGeneratorPowers,
+            Intrinsic::SignatureVerify,
+            Intrinsic::SnarkVerify,
+            Intrinsic::SnarkVerifyBatch,
+            Intrinsic::FinalRun,
+            Intrinsic::ProgramChecksum,
+            Intrinsic::ProgramEdition,
+            Intrinsic::ProgramOwner,
+            Intrinsic::FunctionChecksum,
+            Intrinsic::Serialize(SerializeVariant::ToBits),
+            Intrinsic::Deserialize(DeserializeVariant::FromBits, Type::Err),
+            Intrinsic::DynamicCall,
+            Intrinsic::DynamicContains,
+            Intrinsic::DynamicGet,
+            Intrinsic::DynamicGetOrUse,
+            Intrinsic::SelfAddress,
+            Intrinsic::SelfCaller,
+            Intrinsic::SelfChecksum,
+            Intrinsic::SelfEdition,
+            Intrinsic::SelfId,
+            Intrinsic::SelfProgramOwner,
+            Intrinsic::SelfSigner,
+            Intrinsic::BlockHeight,
+            Intrinsic::BlockTimestamp,
+            Intrinsic::NetworkId,
+            Intrinsic::TestSetCaller,
+            Intrinsic::TestSetSigner,
+            Intrinsic::TestWarp,
+            Intrinsic::TestExpectRevert,
+            Intrinsic::TestSnapshot,
+            Intrinsic::TestRestore,
+        ];
+        for intrinsic in intrinsics {
+            let signature = intrinsic.signature();
+            // Variadic signatures list their repeated parameter once, which may be zero times at a call.
+            let expected = if signature.variadic { signature.parameters.len() - 1 } else { signature.parameters.len() };
+            assert_eq!(expected, intrinsic.num_args(), "{intrinsic:?}");
+        }
+    }
+
+    #[test]
+    fn deserialize_signature_uses_type_argument() {
+        let explicit = Intrinsic::Deserialize(DeserializeVariant::FromBits, Type::Field).signature();
+        assert_eq!(explicit.output, "field");
+        assert!(explicit.type_parameters.is_empty());
+
+        let pending = Intrinsic::Deserialize(DeserializeVariant::FromBits, Type::Err).signature();
+        assert_eq!(pending.output, "T");
+        assert_eq!(pending.type_parameters, vec!["T"]);
+    }
+}
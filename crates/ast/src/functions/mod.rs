@@ -118,6 +118,11 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns `true` if the function is a method, i.e. an associated function whose first parameter is `self`.
+    pub fn is_method(&self) -> bool {
+        self.input.first().is_some_and(|input| input.identifier.name == leo_span::sym::SelfLower)
+    }
+
     /// Returns `true` if any output of the function is a `Final`
     pub fn has_final_output(&self) -> bool {
         self.output.iter().any(|o| matches!(o.type_, Type::Future(_)))
@@ -127,6 +132,14 @@ impl Function {
     pub fn is_test(&self) -> bool {
         self.annotations.iter().any(|a| a.identifier.name == leo_span::sym::test)
     }
+
+    /// Returns `true` if the function is an `@test(mode = "native")` test, which the interpreter runs instead of the VM.
+    pub fn is_native_test(&self) -> bool {
+        self.annotations.iter().any(|a| {
+            a.identifier.name == leo_span::sym::test
+                && a.map.get(&leo_span::sym::mode).is_some_and(|mode| mode == "native")
+        })
+    }
 }
 
 impl From<FunctionStub> for Function {
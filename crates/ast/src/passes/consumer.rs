@@ -36,7 +36,9 @@ pub trait ExpressionConsumer {
             Expression::Err(err) => self.consume_err(err),
             Expression::Path(path) => self.consume_path(path),
             Expression::Literal(value) => self.consume_literal(value),
+            Expression::Match(match_) => self.consume_match(*match_),
             Expression::MemberAccess(access) => self.consume_member_access(*access),
+            Expression::MethodCall(call) => self.consume_method_call(*call),
             Expression::Repeat(repeat) => self.consume_repeat(*repeat),
             Expression::Ternary(ternary) => self.consume_ternary(*ternary),
             Expression::Tuple(tuple) => self.consume_tuple(tuple),
@@ -75,6 +77,14 @@ pub trait ExpressionConsumer {
 
     fn consume_literal(&mut self, _input: Literal) -> Self::Output;
 
+    fn consume_match(&mut self, _input: MatchExpression) -> Self::Output {
+        panic!("`MatchExpression`s should be lowered before this phase of compilation.")
+    }
+
+    fn consume_method_call(&mut self, _input: MethodCallExpression) -> Self::Output {
+        panic!("`MethodCallExpression`s should be lowered before this phase of compilation.")
+    }
+
     fn consume_repeat(&mut self, _input: RepeatExpression) -> Self::Output;
 
     fn consume_intrinsic(&mut self, _input: IntrinsicExpression) -> Self::Output;
@@ -97,12 +107,16 @@ pub trait StatementConsumer {
             Statement::Assert(assert) => self.consume_assert(assert),
             Statement::Assign(stmt) => self.consume_assign(*stmt),
             Statement::Block(stmt) => self.consume_block(stmt),
+            Statement::Break(stmt) => self.consume_break(stmt),
             Statement::Conditional(stmt) => self.consume_conditional(stmt),
             Statement::Const(stmt) => self.consume_const(stmt),
+            Statement::Continue(stmt) => self.consume_continue(stmt),
             Statement::Definition(stmt) => self.consume_definition(stmt),
             Statement::Expression(stmt) => self.consume_expression_statement(stmt),
             Statement::Iteration(stmt) => self.consume_iteration(*stmt),
+            Statement::Match(stmt) => self.consume_match_statement(stmt),
             Statement::Return(stmt) => self.consume_return(stmt),
+            Statement::While(stmt) => self.consume_while(*stmt),
         }
     }
 
@@ -112,17 +126,33 @@ pub trait StatementConsumer {
 
     fn consume_block(&mut self, input: Block) -> Self::Output;
 
+    fn consume_break(&mut self, _input: BreakStatement) -> Self::Output {
+        panic!("`BreakStatement`s should be lowered before this phase of compilation.")
+    }
+
     fn consume_conditional(&mut self, input: ConditionalStatement) -> Self::Output;
 
     fn consume_const(&mut self, input: ConstDeclaration) -> Self::Output;
 
+    fn consume_continue(&mut self, _input: ContinueStatement) -> Self::Output {
+        panic!("`ContinueStatement`s should be lowered before this phase of compilation.")
+    }
+
     fn consume_definition(&mut self, input: DefinitionStatement) -> Self::Output;
 
     fn consume_expression_statement(&mut self, input: ExpressionStatement) -> Self::Output;
 
     fn consume_iteration(&mut self, input: IterationStatement) -> Self::Output;
 
+    fn consume_match_statement(&mut self, _input: MatchStatement) -> Self::Output {
+        panic!("`MatchStatement`s should be lowered before this phase of compilation.")
+    }
+
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output;
+
+    fn consume_while(&mut self, _input: WhileStatement) -> Self::Output {
+        panic!("`WhileStatement`s should be lowered before this phase of compilation.")
+    }
 }
 
 /// A Consumer trait for functions in the AST.
@@ -134,7 +134,9 @@ pub trait AstReconstructor {
             Expression::Err(err) => self.reconstruct_err(err, additional),
             Expression::Path(path) => self.reconstruct_path(path, additional),
             Expression::Literal(value) => self.reconstruct_literal(value, additional),
+            Expression::Match(match_) => self.reconstruct_match(*match_, additional),
             Expression::MemberAccess(access) => self.reconstruct_member_access(*access, additional),
+            Expression::MethodCall(call) => self.reconstruct_method_call(*call, additional),
             Expression::Repeat(repeat) => self.reconstruct_repeat(*repeat, additional),
             Expression::Ternary(ternary) => self.reconstruct_ternary(*ternary, additional),
             Expression::Tuple(tuple) => self.reconstruct_tuple(tuple, additional),
@@ -387,6 +389,55 @@ pub trait AstReconstructor {
         (input.into(), Default::default())
     }
 
+    fn reconstruct_match(
+        &mut self,
+        input: MatchExpression,
+        _additional: &Self::AdditionalInput,
+    ) -> (Expression, Self::AdditionalOutput) {
+        (
+            MatchExpression {
+                scrutinee: self.reconstruct_expression(input.scrutinee, &Default::default()).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        expression: self.reconstruct_expression(arm.expression, &Default::default()).0,
+                        ..arm
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_method_call(
+        &mut self,
+        input: MethodCallExpression,
+        _additional: &Self::AdditionalInput,
+    ) -> (Expression, Self::AdditionalOutput) {
+        (
+            MethodCallExpression {
+                receiver: self.reconstruct_expression(input.receiver, &Default::default()).0,
+                const_arguments: input
+                    .const_arguments
+                    .into_iter()
+                    .map(|arg| self.reconstruct_expression(arg, &Default::default()).0)
+                    .collect(),
+                arguments: input
+                    .arguments
+                    .into_iter()
+                    .map(|arg| self.reconstruct_expression(arg, &Default::default()).0)
+                    .collect(),
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_ternary(
         &mut self,
         input: TernaryExpression,
@@ -453,12 +504,16 @@ pub trait AstReconstructor {
                 let (stmt, output) = self.reconstruct_block(stmt);
                 (stmt.into(), output)
             }
+            Statement::Break(stmt) => self.reconstruct_break(stmt),
             Statement::Conditional(stmt) => self.reconstruct_conditional(stmt),
             Statement::Const(stmt) => self.reconstruct_const(stmt),
+            Statement::Continue(stmt) => self.reconstruct_continue(stmt),
             Statement::Definition(stmt) => self.reconstruct_definition(stmt),
             Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
             Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Match(stmt) => self.reconstruct_match_statement(stmt),
             Statement::Return(stmt) => self.reconstruct_return(stmt),
+            Statement::While(stmt) => self.reconstruct_while(*stmt),
         }
     }
 
@@ -508,6 +563,10 @@ pub trait AstReconstructor {
         )
     }
 
+    fn reconstruct_break(&mut self, input: BreakStatement) -> (Statement, Self::AdditionalOutput) {
+        (input.into(), Default::default())
+    }
+
     fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
         (
             ConditionalStatement {
@@ -533,6 +592,10 @@ pub trait AstReconstructor {
         )
     }
 
+    fn reconstruct_continue(&mut self, input: ContinueStatement) -> (Statement, Self::AdditionalOutput) {
+        (input.into(), Default::default())
+    }
+
     fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
         (
             DefinitionStatement {
@@ -570,6 +633,22 @@ pub trait AstReconstructor {
         )
     }
 
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            MatchStatement {
+                scrutinee: self.reconstruct_expression(input.scrutinee, &Default::default()).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchStatementArm { block: self.reconstruct_block(arm.block).0, ..arm })
+                    .collect(),
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         (
             ReturnStatement {
@@ -580,6 +659,19 @@ pub trait AstReconstructor {
             Default::default(),
         )
     }
+
+    fn reconstruct_while(&mut self, input: WhileStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            WhileStatement {
+                bound: self.reconstruct_expression(input.bound, &Default::default()).0,
+                condition: self.reconstruct_expression(input.condition, &Default::default()).0,
+                block: self.reconstruct_block(input.block).0,
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
 }
 
 /// A Reconstructor trait for a compilation unit (program or library) represented by the AST.
@@ -841,6 +933,16 @@ pub trait UnitReconstructor: AstReconstructor {
                     member
                 })
                 .collect(),
+            variants: input.variants.map(|variants| {
+                variants
+                    .into_iter()
+                    .map(|variant| EnumVariant {
+                        types: variant.types.into_iter().map(|type_| self.reconstruct_type(type_).0).collect(),
+                        ..variant
+                    })
+                    .collect()
+            }),
+            functions: input.functions.into_iter().map(|(name, f)| (name, self.reconstruct_function(f))).collect(),
             ..input
         }
     }
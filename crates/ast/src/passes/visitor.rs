@@ -120,7 +120,9 @@ pub trait AstVisitor {
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Path(path) => self.visit_path(path, additional),
             Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Match(match_) => self.visit_match(match_, additional),
             Expression::MemberAccess(access) => self.visit_member_access(access, additional),
+            Expression::MethodCall(call) => self.visit_method_call(call, additional),
             Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
             Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
             Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
@@ -246,12 +248,31 @@ pub trait AstVisitor {
         Default::default()
     }
 
+    fn visit_method_call(&mut self, input: &MethodCallExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, &Default::default());
+        input.const_arguments.iter().for_each(|expr| {
+            self.visit_expression(expr, &Default::default());
+        });
+        input.arguments.iter().for_each(|expr| {
+            self.visit_expression(expr, &Default::default());
+        });
+        Default::default()
+    }
+
     fn visit_repeat(&mut self, input: &RepeatExpression, _additional: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.expr, &Default::default());
         self.visit_expression(&input.count, &Default::default());
         Default::default()
     }
 
+    fn visit_match(&mut self, input: &MatchExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.scrutinee, &Default::default());
+        input.arms.iter().for_each(|arm| {
+            self.visit_expression(&arm.expression, &Default::default());
+        });
+        Default::default()
+    }
+
     fn visit_ternary(&mut self, input: &TernaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.condition, &Default::default());
         self.visit_expression(&input.if_true, &Default::default());
@@ -281,12 +302,16 @@ pub trait AstVisitor {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(stmt) => self.visit_break(stmt),
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Continue(stmt) => self.visit_continue(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match_statement(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
+            Statement::While(stmt) => self.visit_while(stmt),
         }
     }
 
@@ -309,6 +334,8 @@ pub trait AstVisitor {
         input.statements.iter().for_each(|stmt| self.visit_statement(stmt));
     }
 
+    fn visit_break(&mut self, _input: &BreakStatement) {}
+
     fn visit_conditional(&mut self, input: &ConditionalStatement) {
         self.visit_expression(&input.condition, &Default::default());
         self.visit_block(&input.then);
@@ -322,6 +349,8 @@ pub trait AstVisitor {
         self.visit_expression(&input.value, &Default::default());
     }
 
+    fn visit_continue(&mut self, _input: &ContinueStatement) {}
+
     fn visit_definition(&mut self, input: &DefinitionStatement) {
         if let Some(ty) = input.type_.as_ref() {
             self.visit_type(ty)
@@ -342,9 +371,20 @@ pub trait AstVisitor {
         self.visit_block(&input.block);
     }
 
+    fn visit_match_statement(&mut self, input: &MatchStatement) {
+        self.visit_expression(&input.scrutinee, &Default::default());
+        input.arms.iter().for_each(|arm| self.visit_block(&arm.block));
+    }
+
     fn visit_return(&mut self, input: &ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
     }
+
+    fn visit_while(&mut self, input: &WhileStatement) {
+        self.visit_expression(&input.bound, &Default::default());
+        self.visit_expression(&input.condition, &Default::default());
+        self.visit_block(&input.block);
+    }
 }
 
 /// A Visitor trait for a compilation unit (program or library) represented by the AST.
@@ -396,6 +436,8 @@ pub trait UnitVisitor: AstVisitor {
     fn visit_composite(&mut self, input: &Composite) {
         input.const_parameters.iter().for_each(|input| self.visit_type(&input.type_));
         input.members.iter().for_each(|member| self.visit_type(&member.type_));
+        input.variants.iter().flatten().flat_map(|variant| &variant.types).for_each(|type_| self.visit_type(type_));
+        input.functions.iter().for_each(|(_, f)| self.visit_function(f));
     }
 
     fn visit_mapping(&mut self, input: &Mapping) {
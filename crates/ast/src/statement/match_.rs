@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Indent, MatchPattern, Node, NodeID, Statement};
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An arm `pattern => { ... }` of a match statement.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchStatementArm {
+    /// The pattern deciding whether the arm is taken.
+    pub pattern: MatchPattern,
+    /// The block to evaluate if the arm is taken.
+    pub block: Block,
+    /// The span from the pattern to the end of the block.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchStatementArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.block)
+    }
+}
+
+crate::simple_node_impl!(MatchStatementArm);
+
+/// A `match scrutinee { arms }` statement.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchStatement {
+    /// The enum value being matched on.
+    pub scrutinee: Expression,
+    /// The arms, tried in order.
+    pub arms: Vec<MatchStatementArm>,
+    /// The span from `match` to the closing `}`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "match {} {{", self.scrutinee)?;
+        for arm in self.arms.iter() {
+            writeln!(f, "{}", Indent(arm))?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl From<MatchStatement> for Statement {
+    fn from(value: MatchStatement) -> Self {
+        Statement::Match(value)
+    }
+}
+
+crate::simple_node_impl!(MatchStatement);
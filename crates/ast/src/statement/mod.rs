@@ -23,12 +23,18 @@ pub use assign::*;
 mod block;
 pub use block::*;
 
+mod break_;
+pub use break_::*;
+
 mod conditional;
 pub use conditional::*;
 
 mod const_;
 pub use const_::*;
 
+mod continue_;
+pub use continue_::*;
+
 mod definition;
 pub use definition::*;
 
@@ -38,9 +44,15 @@ pub use expression::*;
 mod iteration;
 pub use iteration::*;
 
+mod match_;
+pub use match_::*;
+
 mod return_;
 pub use return_::*;
 
+mod while_;
+pub use while_::*;
+
 use crate::{Expression, Node, NodeID};
 
 use leo_span::Span;
@@ -57,18 +69,26 @@ pub enum Statement {
     Assign(Box<AssignStatement>),
     /// A block statement.
     Block(Block),
+    /// A `break` statement.
+    Break(BreakStatement),
     /// An `if` statement.
     Conditional(ConditionalStatement),
     /// A binding from identifier to constant value.
     Const(ConstDeclaration),
+    /// A `continue` statement.
+    Continue(ContinueStatement),
     /// A binding or set of bindings / variables to declare.
     Definition(DefinitionStatement),
     /// An expression statement
     Expression(ExpressionStatement),
     /// A `for` statement.
     Iteration(Box<IterationStatement>),
+    /// A `match` statement.
+    Match(MatchStatement),
     /// A return statement `return expr;`.
     Return(ReturnStatement),
+    /// A bounded `while` statement.
+    While(Box<WhileStatement>),
 }
 
 impl Statement {
@@ -80,7 +100,7 @@ impl Statement {
     pub(crate) fn semicolon(&self) -> &'static str {
         use Statement::*;
 
-        if matches!(self, Block(..) | Conditional(..) | Iteration(..)) { "" } else { ";" }
+        if matches!(self, Block(..) | Conditional(..) | Iteration(..) | Match(..) | While(..)) { "" } else { ";" }
     }
 
     /// Returns `true` if the statement produces no instructions
@@ -104,12 +124,16 @@ impl fmt::Display for Statement {
             Statement::Assert(x) => x.fmt(f),
             Statement::Assign(x) => x.fmt(f),
             Statement::Block(x) => x.fmt(f),
+            Statement::Break(x) => x.fmt(f),
             Statement::Conditional(x) => x.fmt(f),
             Statement::Const(x) => x.fmt(f),
+            Statement::Continue(x) => x.fmt(f),
             Statement::Definition(x) => x.fmt(f),
             Statement::Expression(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
+            Statement::Match(x) => x.fmt(f),
             Statement::Return(x) => x.fmt(f),
+            Statement::While(x) => x.fmt(f),
         }
     }
 }
@@ -121,12 +145,16 @@ impl Node for Statement {
             Assert(n) => n.span(),
             Assign(n) => n.span(),
             Block(n) => n.span(),
+            Break(n) => n.span(),
             Conditional(n) => n.span(),
             Const(n) => n.span(),
+            Continue(n) => n.span(),
             Definition(n) => n.span(),
             Expression(n) => n.span(),
             Iteration(n) => n.span(),
+            Match(n) => n.span(),
             Return(n) => n.span(),
+            While(n) => n.span(),
         }
     }
 
@@ -136,12 +164,16 @@ impl Node for Statement {
             Assert(n) => n.set_span(span),
             Assign(n) => n.set_span(span),
             Block(n) => n.set_span(span),
+            Break(n) => n.set_span(span),
             Conditional(n) => n.set_span(span),
             Const(n) => n.set_span(span),
+            Continue(n) => n.set_span(span),
             Definition(n) => n.set_span(span),
             Expression(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             Return(n) => n.set_span(span),
+            While(n) => n.set_span(span),
         }
     }
 
@@ -151,12 +183,16 @@ impl Node for Statement {
             Assert(n) => n.id(),
             Assign(n) => n.id(),
             Block(n) => n.id(),
+            Break(n) => n.id(),
             Conditional(n) => n.id(),
             Const(n) => n.id(),
+            Continue(n) => n.id(),
             Definition(n) => n.id(),
             Expression(n) => n.id(),
             Iteration(n) => n.id(),
+            Match(n) => n.id(),
             Return(n) => n.id(),
+            While(n) => n.id(),
         }
     }
 
@@ -166,12 +202,16 @@ impl Node for Statement {
             Assert(n) => n.set_id(id),
             Assign(n) => n.set_id(id),
             Block(n) => n.set_id(id),
+            Break(n) => n.set_id(id),
             Conditional(n) => n.set_id(id),
             Const(n) => n.set_id(id),
+            Continue(n) => n.set_id(id),
             Definition(n) => n.set_id(id),
             Expression(n) => n.set_id(id),
             Iteration(n) => n.set_id(id),
+            Match(n) => n.set_id(id),
             Return(n) => n.set_id(id),
+            While(n) => n.set_id(id),
         }
     }
 }
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Indent, Node, NodeID, Statement};
+
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A bounded `while` loop statement `@bound(bound) while condition block`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct WhileStatement {
+    /// The condition checked before each iteration.
+    pub condition: Expression,
+    /// The maximum number of iterations, which must be known at compile time.
+    pub bound: Expression,
+    /// The block to run on each iteration.
+    pub block: Block,
+    /// The span from `@bound` to `block`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for WhileStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "@bound({})", self.bound)?;
+        writeln!(f, "while {} {{", self.condition)?;
+        for stmt in self.block.statements.iter() {
+            writeln!(f, "{}{}", Indent(stmt), stmt.semicolon())?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl From<WhileStatement> for Statement {
+    fn from(value: WhileStatement) -> Self {
+        Statement::While(Box::new(value))
+    }
+}
+
+crate::simple_node_impl!(WhileStatement);
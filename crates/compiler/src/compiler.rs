@@ -76,6 +76,8 @@ pub struct CompiledProgram {
     pub name: String,
     /// The generated Aleo bytecode.
     pub bytecode: String,
+    /// Maps the bytecode's instructions back to the Leo source.
+    pub source_map: AleoSourceMap,
     /// The ABI describing the program's public interface.
     pub abi: leo_abi::Program,
 }
@@ -310,6 +312,22 @@ impl Compiler {
         })
     }
 
+    /// Parses a single standalone source file, merges import stubs, and runs frontend passes.
+    ///
+    /// The standalone counterpart of [`Self::analyze_frontend_from_directory_with_file_source`],
+    /// for test files: their siblings in `tests/` are independent programs, not modules.
+    pub fn analyze_frontend_from_file(&mut self, entry_file_path: impl AsRef<Path>) -> Result<FrontendAnalysis<'_>> {
+        self.parse_program_from_file(entry_file_path)?;
+        self.add_import_stubs()?;
+        self.frontend_passes()?;
+
+        Ok(FrontendAnalysis {
+            ast: &self.state.ast,
+            symbol_table: &self.state.symbol_table,
+            type_table: &self.state.type_table,
+        })
+    }
+
     /// Returns a new Leo compiler.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -372,6 +390,7 @@ impl Compiler {
         self.do_pass_with_check::<PathResolution, _>((), &mut should_continue)?;
         self.do_pass_with_check::<GlobalItemsCollection, _>((), &mut should_continue)?;
         self.do_pass_with_check::<CheckInterfaces, _>((), &mut should_continue)?;
+        self.do_pass_with_check::<TypeMonomorphization, _>((), &mut should_continue)?;
         self.do_pass_with_check::<TypeChecking, _>(TypeCheckingInput::new(self.state.network), &mut should_continue)?;
         self.do_pass_with_check::<Disambiguate, _>((), &mut should_continue)?;
         self.do_pass_with_check::<CeiAnalyzing, _>((), &mut should_continue)?;
@@ -396,6 +415,9 @@ impl Compiler {
 
         self.frontend_passes()?;
 
+        // Methods are kept through the frontend so that the LSP and debugger see them as written.
+        self.do_pass::<MethodLowering>(type_checking_config.clone())?;
+
         // Drop unreachable library functions
         self.do_pass::<LibraryPruning>(())?;
 
@@ -407,6 +429,8 @@ impl Compiler {
 
         self.do_pass::<StorageLowering>(type_checking_config.clone())?;
 
+        self.do_pass::<EnumLowering>(type_checking_config.clone())?;
+
         self.do_pass::<OptionLowering>(type_checking_config)?;
 
         self.do_pass::<SsaForming>(SsaFormingInput { rename_defs: true })?;
@@ -515,6 +539,7 @@ impl Compiler {
         let primary = CompiledProgram {
             name: self.unit_name.clone().unwrap(),
             bytecode: bytecodes.primary_bytecode,
+            source_map: bytecodes.primary_source_map,
             abi: primary_abi,
         };
 
@@ -524,7 +549,7 @@ impl Compiler {
             .into_iter()
             .map(|bc| {
                 let abi = import_abis.get(&bc.program_name).expect("ABI should exist for all imports").clone();
-                CompiledProgram { name: bc.program_name, bytecode: bc.bytecode, abi }
+                CompiledProgram { name: bc.program_name, bytecode: bc.bytecode, source_map: bc.source_map, abi }
             })
             .collect();
 
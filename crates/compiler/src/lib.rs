@@ -28,11 +28,14 @@ mod options;
 pub use options::*;
 
 // Re-export types from leo_passes for convenience
-pub use leo_passes::{Bytecode, CompiledPrograms};
+pub use leo_passes::{AleoSourceMap, Bytecode, CompiledPrograms};
 pub use leo_span::file_source::{DiskFileSource, FileSource, InMemoryFileSource};
 
 pub mod run;
 
+mod trace;
+pub use trace::{InstructionId, Trace};
+
 #[cfg(test)]
 mod test_utils;
 
@@ -27,8 +27,11 @@
 //!
 //! Also defines types for program configuration, test cases, and outcomes.
 
+use crate::{Trace, trace::Tracer};
+
 use leo_ast::{TEST_PRIVATE_KEY, const_eval::Value};
 use leo_errors::Result;
+use leo_passes::AleoSourceMap;
 
 use aleo_std_storage::StorageMode;
 use anyhow::anyhow;
@@ -38,6 +41,7 @@ use snarkvm::{
     circuit::AleoTestnetV0,
     prelude::{
         Address,
+        Authorization,
         Block,
         Certificate,
         ConsensusVersion,
@@ -56,22 +60,24 @@ use snarkvm::{
         VM,
         Value as SvmValue,
         VerifyingKey,
+        block::Output,
         deployment_cost,
         execution_cost,
         store::{ConsensusStore, helpers::memory::ConsensusMemory},
     },
-    synthesizer::program::{FinalizeStoreTrait, ProgramCore, StackTrait},
+    synthesizer::program::{FinalizeGlobalState, FinalizeStoreTrait, ProgramCore, StackTrait},
 };
 use std::{
     fmt,
     panic::{AssertUnwindSafe, catch_unwind},
     str::FromStr as _,
+    time::{Duration, Instant},
 };
 
 type CurrentNetwork = TestnetV0;
 
 /// Programs and configuration to run.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Config {
     pub seed: u64,
     // If `None`, start at the height for the latest consensus version.
@@ -79,6 +85,8 @@ pub struct Config {
     pub programs: Vec<Program>,
     /// Skip proof generation for faster testing. Requires `dev_skip_checks`.
     pub skip_proving: bool,
+    /// Record the instructions each case runs into the `trace` of its outcome.
+    pub trace: bool,
 }
 
 /// A program to deploy to the ledger.
@@ -86,6 +94,22 @@ pub struct Config {
 pub struct Program {
     pub bytecode: String,
     pub name: String,
+    /// Used to point failures at the Leo source of the failing instruction, when available.
+    pub source_map: Option<AleoSourceMap>,
+}
+
+impl Config {
+    /// Appends the Leo location of the instruction a snarkVM error `message` is about, when a
+    /// source map covers it. The called program's map is searched first, then the others.
+    fn annotate(&self, case: &Case, message: &str) -> String {
+        let mut source_maps =
+            self.programs.iter().filter_map(|program| program.source_map.as_ref()).collect::<Vec<_>>();
+        source_maps.sort_by_key(|source_map| source_map.program != case.program_name);
+        source_maps
+            .into_iter()
+            .find(|source_map| source_map.locate(message, Some(&case.function)).is_some())
+            .map_or_else(|| message.to_string(), |source_map| source_map.annotate(message, Some(&case.function)))
+    }
 }
 
 /// A single finalize-store entry to write before a case is evaluated.
@@ -94,7 +118,9 @@ pub struct Program {
 /// is the only way to give view test cases (and finalize-reading transitions) state to read.
 #[derive(Clone, Debug)]
 pub struct SeedMapping {
-    /// Mapping name in the case's `program_name`.
+    /// Program declaring the mapping; the case's `program_name` when `None`.
+    pub program: Option<String>,
+    /// Mapping name in `program`.
     pub mapping: String,
     /// Plaintext key in snarkVM display form.
     pub key: String,
@@ -102,6 +128,29 @@ pub struct SeedMapping {
     pub value: String,
 }
 
+/// A finalize-store entry to read after a case is executed.
+#[derive(Clone, Debug)]
+pub struct MappingKey {
+    /// Program declaring the mapping; the case's `program_name` when `None`.
+    pub program: Option<String>,
+    /// Mapping name in `program`.
+    pub mapping: String,
+    /// Plaintext key in snarkVM display form.
+    pub key: String,
+}
+
+/// An input of a case taken from an output of an earlier case in the same case set, e.g. a record
+/// one case creates and a later one spends.
+#[derive(Clone, Debug)]
+pub struct LinkedInput {
+    /// The position in the case's `input` the output fills.
+    pub position: usize,
+    /// The index of the earlier case in its case set.
+    pub case: usize,
+    /// The index of the output among the earlier case's outputs.
+    pub output: usize,
+}
+
 /// A particular case to run.
 #[derive(Clone, Debug, Default)]
 pub struct Case {
@@ -111,6 +160,13 @@ pub struct Case {
     pub input: Vec<String>,
     /// Pre-populated finalize-store entries written before the case is evaluated.
     pub seed_mapping: Vec<SeedMapping>,
+    /// Finalize-store entries read back once the case is executed, into [`ExecutionOutcome::mappings`].
+    pub read_mappings: Vec<MappingKey>,
+    /// Inputs filled from the outputs of earlier cases in the same case set. Only `run_with_ledger` links inputs.
+    pub linked_inputs: Vec<LinkedInput>,
+    /// Empty blocks added before the case is executed, moving `block.height` forward. Only `run_with_ledger`
+    /// advances blocks.
+    pub advance_blocks: u32,
 }
 
 /// The status of a case that was run.
@@ -170,6 +226,8 @@ impl Outcome {
 pub struct EvaluationOutcome {
     pub outcome: Outcome,
     pub status: EvaluationStatus,
+    /// The instructions the case ran, when the config asks for traces.
+    pub trace: Trace,
 }
 
 impl EvaluationOutcome {
@@ -185,6 +243,12 @@ pub struct ExecutionOutcome {
     pub verified: bool,
     pub execution: String,
     pub status: ExecutionStatus,
+    /// The value of each of the case's `read_mappings` after execution; `None` if the key is absent.
+    pub mappings: Vec<Option<Value>>,
+    /// How long the case took, from seeding its mappings to reading them back.
+    pub duration: Duration,
+    /// The instructions the case ran, when the config asks for traces.
+    pub trace: Trace,
 }
 
 impl ExecutionOutcome {
@@ -291,7 +355,7 @@ pub fn run_without_ledger(config: &Config, cases: &[Case]) -> Result<Vec<Evaluat
     let programs_and_editions: Vec<(snarkvm::prelude::Program<CurrentNetwork>, u16)> = config
         .programs
         .iter()
-        .map(|Program { bytecode, name }| {
+        .map(|Program { bytecode, name, .. }| {
             let program = snarkvm::prelude::Program::<CurrentNetwork>::from_str(bytecode)
                 .map_err(|e| anyhow!("Failed to parse bytecode of program {name}: {e}"))?;
             // Assume edition 1. We can consider parametrizing this in the future.
@@ -300,7 +364,7 @@ pub fn run_without_ledger(config: &Config, cases: &[Case]) -> Result<Vec<Evaluat
         })
         .collect::<Result<Vec<_>>>()?;
 
-    let outcomes: Vec<EvaluationOutcome> = cases
+    let mut outcomes: Vec<EvaluationOutcome> = cases
         .iter()
         .map(|case| {
             let rng = &mut ChaCha20Rng::seed_from_u64(config.seed);
@@ -313,6 +377,7 @@ pub fn run_without_ledger(config: &Config, cases: &[Case]) -> Result<Vec<Evaluat
                     output: Value::make_unit(),
                 },
                 status: EvaluationStatus::Failed(e),
+                trace: Trace::default(),
             };
 
             let vm = match ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(
@@ -358,22 +423,8 @@ pub fn run_without_ledger(config: &Config, cases: &[Case]) -> Result<Vec<Evaluat
             // Seed any pre-populated mapping entries before evaluating the case. Useful for
             // view test cases where `run_without_ledger` doesn't run finalize blocks, so
             // mappings are otherwise empty.
-            for SeedMapping { mapping: mapping_name_str, key: key_str, value: value_str } in &case.seed_mapping {
-                let mapping_name = match Identifier::<CurrentNetwork>::from_str(mapping_name_str) {
-                    Ok(n) => n,
-                    Err(e) => return failed_outcome(format!("Failed to parse seed mapping name: {e}")),
-                };
-                let key = match snarkvm::prelude::Plaintext::<CurrentNetwork>::from_str(key_str) {
-                    Ok(k) => k,
-                    Err(e) => return failed_outcome(format!("Failed to parse seed key: {e}")),
-                };
-                let value = match SvmValue::<CurrentNetwork>::from_str(value_str) {
-                    Ok(v) => v,
-                    Err(e) => return failed_outcome(format!("Failed to parse seed value: {e}")),
-                };
-                if let Err(e) = vm.finalize_store().update_key_value(program_id, mapping_name, key, value) {
-                    return failed_outcome(format!("Failed to seed mapping: {e}"));
-                }
+            if let Err(e) = seed_mappings(&vm, case) {
+                return failed_outcome(e);
             }
 
             // Views and transitions take different snarkVM paths:
@@ -386,14 +437,24 @@ pub fn run_without_ledger(config: &Config, cases: &[Case]) -> Result<Vec<Evaluat
                 .map(|stack| stack.program().contains_view(&function_id))
                 .unwrap_or(false);
 
-            if is_view {
-                handle_view(case, &vm, program_id, function_id)
+            let mut trace = Trace::default();
+            let trace_into = config.trace.then_some(&mut trace);
+            let outcome = if is_view {
+                handle_view(case, &vm, program_id, function_id, trace_into)
             } else {
-                handle_transition(case, &vm, program_id, function_id, &private_key, rng)
-            }
+                handle_transition(case, &vm, program_id, function_id, &private_key, rng, trace_into)
+            };
+            EvaluationOutcome { trace, ..outcome }
         })
         .collect();
 
+    // Point failures at the Leo source that produced the failing instruction.
+    for (outcome, case) in outcomes.iter_mut().zip(cases) {
+        if let EvaluationStatus::Failed(message) = &mut outcome.status {
+            *message = config.annotate(case, message);
+        }
+    }
+
     Ok(outcomes)
 }
 
@@ -403,6 +464,7 @@ fn handle_view(
     vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
     program_id: ProgramID<CurrentNetwork>,
     function_id: Identifier<CurrentNetwork>,
+    trace: Option<&mut Trace>,
 ) -> EvaluationOutcome {
     let failed = |e: String| failed_evaluation_outcome(case, e);
     let parsed_inputs: Vec<SvmValue<CurrentNetwork>> = match case
@@ -449,6 +511,17 @@ fn handle_view(
         Ok(owner) => stack.set_program_owner(Some(owner)),
         Err(e) => return failed(format!("Failed to derive program owner: {e}")),
     };
+    if let Some(trace) = trace {
+        let _ = catch_unwind(AssertUnwindSafe(|| {
+            Tracer::new(trace, ChaCha20Rng::seed_from_u64(0)).view(
+                &stack,
+                vm.finalize_store(),
+                state,
+                &function_id,
+                parsed_inputs.clone(),
+            )
+        }));
+    }
     let response = match catch_unwind(AssertUnwindSafe(|| {
         snarkvm::synthesizer::process::evaluate_view_with_stack_at_height(
             state,
@@ -471,6 +544,7 @@ fn handle_view(
     EvaluationOutcome {
         outcome: Outcome { program_name: case.program_name.clone(), function: case.function.clone(), output },
         status: EvaluationStatus::Success,
+        trace: Trace::default(),
     }
 }
 
@@ -482,6 +556,7 @@ fn handle_transition(
     function_id: Identifier<CurrentNetwork>,
     private_key: &PrivateKey<CurrentNetwork>,
     rng: &mut ChaCha20Rng,
+    trace: Option<&mut Trace>,
 ) -> EvaluationOutcome {
     let failed = |e: String| failed_evaluation_outcome(case, e);
     let inputs = case.input.iter();
@@ -493,6 +568,9 @@ fn handle_transition(
             Ok(Err(e)) => return failed(format!("{e}")),
             Err(e) => return failed(format!("{e:?}")),
         };
+    if let Some(trace) = trace {
+        trace_functions(vm, &authorization.replicate(), rng.clone(), trace);
+    }
 
     // --- catch panics from evaluate ---
     let response = match catch_unwind(AssertUnwindSafe(|| vm.process().evaluate::<AleoTestnetV0>(authorization))) {
@@ -511,6 +589,7 @@ fn handle_transition(
     EvaluationOutcome {
         outcome: Outcome { program_name: case.program_name.clone(), function: case.function.clone(), output },
         status: EvaluationStatus::Success,
+        trace: Trace::default(),
     }
 }
 
@@ -523,11 +602,124 @@ fn failed_evaluation_outcome(case: &Case, e: String) -> EvaluationOutcome {
             output: Value::make_unit(),
         },
         status: EvaluationStatus::Failed(e),
+        trace: Trace::default(),
+    }
+}
+
+/// Traces the functions and closures `authorization` runs into `trace`.
+///
+/// A trace ends where the case fails, so errors and panics end it without being reported.
+fn trace_functions(
+    vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
+    authorization: &Authorization<CurrentNetwork>,
+    rng: ChaCha20Rng,
+    trace: &mut Trace,
+) {
+    let _ = catch_unwind(AssertUnwindSafe(|| -> anyhow::Result<()> {
+        let stack = vm.process().get_stack(authorization.peek_next()?.program_id())?;
+        Tracer::new(trace, rng).function(&stack, authorization, None, None)?;
+        Ok(())
+    }));
+}
+
+/// Traces the finalize logic of the execution `transaction` into `trace`, on the finalize store of `vm` as it is
+/// before `block` is added, with the state `block` finalizes it in. The writes are rolled back, so the block
+/// still finalizes the transaction itself.
+fn trace_finalize(
+    vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
+    block: &Block<CurrentNetwork>,
+    transaction: &Transaction<CurrentNetwork>,
+    trace: &mut Trace,
+) {
+    let Transaction::Execute(_, _, execution, _) = transaction else {
+        return;
+    };
+    // Only the root transition is finalized; it awaits the futures of the others.
+    let Some(root) = execution.transitions().last() else {
+        return;
+    };
+    let Some(Output::Future(_, Some(future))) = root.outputs().last() else {
+        return;
+    };
+    let timestamp = (block.height() >= CurrentNetwork::CONSENSUS_HEIGHT(ConsensusVersion::V12).unwrap_or_default())
+        .then_some(block.timestamp());
+    let Ok(state) = FinalizeGlobalState::new::<CurrentNetwork>(
+        block.round(),
+        block.height(),
+        timestamp,
+        block.cumulative_weight(),
+        block.cumulative_proof_target(),
+        block.previous_hash(),
+        None,
+    ) else {
+        return;
+    };
+    let Ok(stack) = vm.process().get_stack(future.program_id()) else {
+        return;
+    };
+
+    vm.finalize_store().start_atomic();
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        Tracer::new(trace, ChaCha20Rng::seed_from_u64(0)).finalize(
+            &stack,
+            vm.finalize_store(),
+            state,
+            *root.id(),
+            future,
+            &mut 0,
+        )
+    }));
+    vm.finalize_store().abort_atomic();
+}
+
+/// Writes the `seed_mapping` entries of `case` into the finalize store of `vm`.
+fn seed_mappings(vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>, case: &Case) -> Result<(), String> {
+    for SeedMapping { program, mapping, key, value } in &case.seed_mapping {
+        let program_id = ProgramID::<CurrentNetwork>::from_str(program.as_deref().unwrap_or(&case.program_name))
+            .map_err(|e| format!("Failed to parse seed mapping program: {e}"))?;
+        let mapping_name = Identifier::<CurrentNetwork>::from_str(mapping)
+            .map_err(|e| format!("Failed to parse seed mapping name: {e}"))?;
+        let key = snarkvm::prelude::Plaintext::<CurrentNetwork>::from_str(key)
+            .map_err(|e| format!("Failed to parse seed key: {e}"))?;
+        let value =
+            SvmValue::<CurrentNetwork>::from_str(value).map_err(|e| format!("Failed to parse seed value: {e}"))?;
+        vm.finalize_store()
+            .update_key_value(program_id, mapping_name, key, value)
+            .map_err(|e| format!("Failed to seed mapping: {e}"))?;
     }
+    Ok(())
+}
+
+/// Reads the `read_mappings` entries of `case` from the finalize store of `vm`.
+///
+/// Entries whose key is absent, or that don't name a valid mapping and key, read as `None`.
+fn read_mappings(vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>, case: &Case) -> Vec<Option<Value>> {
+    case.read_mappings
+        .iter()
+        .map(|MappingKey { program, mapping, key }| {
+            let program_id =
+                ProgramID::<CurrentNetwork>::from_str(program.as_deref().unwrap_or(&case.program_name)).ok()?;
+            let mapping_name = Identifier::<CurrentNetwork>::from_str(mapping).ok()?;
+            let key = snarkvm::prelude::Plaintext::<CurrentNetwork>::from_str(key).ok()?;
+            let value = vm.finalize_store().get_value_speculative(program_id, mapping_name, &key).ok()??;
+            Some(value.into())
+        })
+        .collect()
 }
 
 /// Run the functions indicated by `cases` from the programs in `config`.
 pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<Vec<ExecutionOutcome>>> {
+    run_with_ledger_until(config, case_sets, |_, _| true)
+}
+
+/// Like [`run_with_ledger`], but passes the index and outcomes of each case set to `on_case_set` as
+/// soon as the set has run, and runs no further case sets once it returns `false`. Case sets that
+/// are not run have no outcomes.
+pub fn run_with_ledger_until(
+    config: &Config,
+    case_sets: &[Vec<Case>],
+    mut on_case_set: impl FnMut(usize, &[ExecutionOutcome]) -> bool,
+) -> Result<Vec<Vec<ExecutionOutcome>>> {
     if case_sets.is_empty() {
         return Ok(Vec::new());
     }
@@ -566,7 +758,7 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
     }
 
     // Deploy each bytecode separately.
-    for Program { bytecode, name } in &config.programs {
+    for Program { bytecode, name, .. } in &config.programs {
         // Parse the bytecode as an Aleo program.
         // Note that this function checks that the bytecode is well-formed.
         let aleo_program =
@@ -640,9 +832,13 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
     );
 
     // For each of the case sets, run the cases sequentially.
+    let mut keep_going = true;
     let results = indexed_ledgers
         .into_iter()
-        .map(|(index, ledger)| {
+        .map(|(index, ledger)| -> Result<(usize, Vec<ExecutionOutcome>)> {
+            if !keep_going {
+                return Ok((index, Vec::new()));
+            }
             // Get the cases for this ledger.
             let cases = &case_sets[index];
             // Clone the RNG.
@@ -709,8 +905,11 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
             ledger.advance_to_next_block(&block).expect("Failed to advance to next block");
 
             let mut case_outcomes = Vec::new();
+            // The outputs of each case so far, for later cases to link to.
+            let mut case_outputs: Vec<Vec<SvmValue<CurrentNetwork>>> = Vec::new();
 
             for case in cases {
+                let started = Instant::now();
                 assert!(
                     ledger.vm().contains_program(&ProgramID::from_str(&case.program_name).unwrap()),
                     "Program {} should exist.",
@@ -723,6 +922,66 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
                     .map(|key| PrivateKey::from_str(key).expect("Failed to parse private key."))
                     .unwrap_or(genesis_private_key);
 
+                // Seed any pre-populated mapping entries before executing the case.
+                seed_mappings(ledger.vm(), case).map_err(|e| anyhow!("{e}"))?;
+
+                // Move `block.height` forward before the case, e.g. past a deadline it checks.
+                for _ in 0..case.advance_blocks {
+                    let block = ledger
+                        .prepare_advance_to_next_beacon_block(&genesis_private_key, vec![], vec![], vec![], &mut rng)
+                        .map_err(|e| anyhow!("Failed to prepare advance to next beacon block: {e}"))?;
+                    ledger
+                        .advance_to_next_block(&block)
+                        .map_err(|e| anyhow!("Failed to advance to next block: {e}"))?;
+                }
+
+                // Fill in the inputs taken from the outputs of earlier cases.
+                let mut input = case.input.clone();
+                let unlinked = case.linked_inputs.iter().find_map(|LinkedInput { position, case: earlier, output }| {
+                    let value = case_outputs.get(*earlier).and_then(|outputs| outputs.get(*output));
+                    match (input.get_mut(*position), value) {
+                        (Some(slot), Some(value)) => {
+                            *slot = value.to_string();
+                            None
+                        }
+                        _ => Some(format!("Output {output} of case {earlier} is not available as input {position}")),
+                    }
+                });
+                if let Some(reason) = unlinked {
+                    case_outputs.push(Vec::new());
+                    case_outcomes.push(ExecutionOutcome {
+                        outcome: Outcome {
+                            program_name: case.program_name.clone(),
+                            function: case.function.clone(),
+                            output: Value::make_unit(),
+                        },
+                        status: ExecutionStatus::Halted(reason),
+                        verified: false,
+                        execution: "".to_string(),
+                        mappings: read_mappings(ledger.vm(), case),
+                        duration: started.elapsed(),
+                        trace: Trace::default(),
+                    });
+                    continue;
+                }
+
+                // The execution authorizes the case with `rng` first, so a copy of it authorizes the same requests.
+                let mut trace = Trace::default();
+                if config.trace {
+                    let authorization = catch_unwind(AssertUnwindSafe(|| {
+                        ledger.vm().authorize(
+                            &private_key,
+                            &case.program_name,
+                            &case.function,
+                            input.iter(),
+                            &mut rng.clone(),
+                        )
+                    }));
+                    if let Ok(Ok(authorization)) = authorization {
+                        trace_functions(ledger.vm(), &authorization, rng.clone(), &mut trace);
+                    }
+                }
+
                 let mut execution = None;
                 let mut verified = false;
                 let mut status = ExecutionStatus::None;
@@ -738,7 +997,7 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
                             &private_key,
                             &case.program_name,
                             &case.function,
-                            case.input.iter(),
+                            input.iter(),
                             latest_consensus_version,
                             &mut rng,
                         )
@@ -748,7 +1007,7 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
                             .execute_with_response(
                                 &private_key,
                                 (&case.program_name, &case.function),
-                                case.input.iter(),
+                                input.iter(),
                                 None,
                                 0,
                                 None,
@@ -763,15 +1022,19 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
                     let s2 = payload.downcast_ref::<String>().cloned();
                     let s = s1.or(s2).unwrap_or_else(|| "Unknown panic payload".to_string());
 
+                    case_outputs.push(Vec::new());
                     case_outcomes.push(ExecutionOutcome {
                         outcome: Outcome {
                             program_name: case.program_name.clone(),
                             function: case.function.clone(),
                             output: Value::make_unit(),
                         },
-                        status: ExecutionStatus::Halted(s),
+                        status: ExecutionStatus::Halted(config.annotate(case, &s)),
                         verified: false,
                         execution: "".to_string(),
+                        mappings: read_mappings(ledger.vm(), case),
+                        duration: started.elapsed(),
+                        trace,
                     });
 
                     continue;
@@ -799,6 +1062,12 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
                             (true, true) => ExecutionStatus::Accepted,
                             (true, false) => ExecutionStatus::Rejected,
                         };
+                    if config.trace
+                        && !matches!(status, ExecutionStatus::Aborted(_))
+                        && let Some(transaction) = &execution
+                    {
+                        trace_finalize(ledger.vm(), &block, transaction, &mut trace);
+                    }
                     ledger.advance_to_next_block(&block)?;
                     Ok(response)
                 });
@@ -806,13 +1075,20 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
                 let output = match result {
                     Ok(response) => {
                         let outputs = response.outputs();
+                        case_outputs.push(outputs.to_vec());
                         match outputs.len() {
                             0 => Value::make_unit(),
                             1 => outputs[0].clone().into(),
                             _ => Value::make_tuple(outputs.iter().map(|x| x.clone().into())),
                         }
                     }
-                    Err(e) => Value::make_string(format!("Failed to extract output: {e}")),
+                    Err(e) => {
+                        case_outputs.push(Vec::new());
+                        Value::make_string(format!(
+                            "Failed to extract output: {}",
+                            config.annotate(case, &e.to_string())
+                        ))
+                    }
                 };
 
                 // Extract the execution, removing the global state root and proof.
@@ -832,9 +1108,13 @@ pub fn run_with_ledger(config: &Config, case_sets: &[Vec<Case>]) -> Result<Vec<V
                     status,
                     verified,
                     execution: serde_json::to_string_pretty(&execution).expect("Serialization failure"),
+                    mappings: read_mappings(ledger.vm(), case),
+                    duration: started.elapsed(),
+                    trace,
                 });
             }
 
+            keep_going = on_case_set(index, &case_outcomes);
             Ok((index, case_outcomes))
         })
         .collect::<Result<Vec<_>>>()?;
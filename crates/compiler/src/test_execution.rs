@@ -47,8 +47,13 @@ fn execution_run_test(
 ) -> Result<String> {
     let mut import_stubs = IndexMap::new();
 
-    let mut ledger_config =
-        run::Config { seed: config.seed, start_height: config.start_height, programs: Vec::new(), skip_proving: true };
+    let mut ledger_config = run::Config {
+        seed: config.seed,
+        start_height: config.start_height,
+        programs: Vec::new(),
+        skip_proving: true,
+        trace: false,
+    };
 
     // We assume config.sources is non-empty.
     let (last, rest) = config.sources.split_last().expect("non-empty sources");
@@ -94,13 +99,18 @@ fn execution_run_test(
     let mut requires_ledger = false;
     for import in &compiled.imports {
         requires_ledger |= import.bytecode.contains("async");
-        ledger_config.programs.push(run::Program { bytecode: import.bytecode.clone(), name: import.name.clone() });
+        ledger_config.programs.push(run::Program {
+            bytecode: import.bytecode.clone(),
+            name: import.name.clone(),
+            source_map: None,
+        });
     }
 
     // Add main program.
     let primary_bytecode = compiled.primary.bytecode.clone();
     requires_ledger |= primary_bytecode.contains("async");
-    ledger_config.programs.push(run::Program { bytecode: primary_bytecode, name: program_name });
+    // Expectations record snarkVM's messages as-is, so failures are not annotated with Leo locations.
+    ledger_config.programs.push(run::Program { bytecode: primary_bytecode, name: program_name, source_map: None });
 
     let mut result = ledger_config
         .programs
@@ -162,7 +172,7 @@ fn execution_runner(source: &str) -> String {
             let parts: Vec<String> = re_input.captures_iter(rest).map(|s| s[1].to_string()).collect();
             assert_eq!(parts.len(), 3, "seed_mapping expects 3 quote-delimited strings: mapping, key, value");
             let [mapping, key, value]: [String; 3] = parts.try_into().unwrap();
-            cases.last_mut().unwrap().seed_mapping.push(crate::run::SeedMapping { mapping, key, value });
+            cases.last_mut().unwrap().seed_mapping.push(crate::run::SeedMapping { program: None, mapping, key, value });
         } else if let Some(rest) = line.strip_prefix("seed = ") {
             config.seed = rest.parse::<u64>().unwrap();
         } else if let Some(rest) = line.strip_prefix("start_height = ") {
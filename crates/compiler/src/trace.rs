@@ -0,0 +1,360 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Traces of the Aleo instructions a case ran, for `leo test --coverage`.
+//!
+//! snarkVM does not report which instructions it ran, so [`Tracer`] steps through the same
+//! functions, closures, finalize logic, and views instruction by instruction, with snarkVM's own
+//! registers and instruction semantics, on the authorization and finalize store of the case being
+//! run. Functions and closures are straight-line code, so only the `ternary`s they select with and
+//! the instruction they halt at vary; finalize logic and views also branch.
+
+use anyhow::{anyhow, bail};
+use indexmap::IndexMap;
+use rand_chacha::ChaCha20Rng;
+use snarkvm::{
+    circuit::AleoTestnetV0,
+    prelude::{Field, Future, Identifier, Literal, Network, Plaintext, ProgramID, TestnetV0, Value},
+    synthesizer::{
+        process::{Authorization, CallStack, CallTrait, FinalizeRegisters, Registers, Stack},
+        program::{
+            Call,
+            CallOperator,
+            Command,
+            FinalizeGlobalState,
+            FinalizeStoreTrait,
+            Instruction,
+            Operand,
+            RegistersSigner,
+            RegistersTrait,
+            StackTrait,
+        },
+    },
+};
+use std::collections::HashMap;
+
+type CurrentNetwork = TestnetV0;
+
+/// An instruction of a deployed program, identified as in its [`crate::AleoSourceMap`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InstructionId {
+    /// The program, e.g. `hello.aleo`.
+    pub program: String,
+    /// The block containing the instruction, e.g. `function main` or `finalize main`.
+    pub block: String,
+    /// The index of the instruction within its block, counting as snarkVM does.
+    pub index: usize,
+}
+
+/// How often each traced instruction ran, and which way each branching one went.
+#[derive(Clone, Debug, Default)]
+pub struct Trace {
+    /// The number of times each instruction ran, including the one a case halted at.
+    pub instructions: IndexMap<InstructionId, u64>,
+    /// For each `ternary`, how often it selected its first and second operand, and for each
+    /// conditional `branch.eq` or `branch.neq`, how often it fell through and jumped.
+    pub branches: IndexMap<InstructionId, [u64; 2]>,
+}
+
+impl Trace {
+    /// Adds the counts of `other` to this trace.
+    pub fn extend(&mut self, other: &Trace) {
+        for (id, count) in &other.instructions {
+            *self.instructions.entry(id.clone()).or_default() += count;
+        }
+        for (id, [first, second]) in &other.branches {
+            let counts = self.branches.entry(id.clone()).or_default();
+            counts[0] += first;
+            counts[1] += second;
+        }
+    }
+
+    fn record(&mut self, program: &ProgramID<CurrentNetwork>, block: &str, index: usize) {
+        let id = InstructionId { program: program.to_string(), block: block.to_string(), index };
+        *self.instructions.entry(id).or_default() += 1;
+    }
+
+    fn record_branch(&mut self, program: &ProgramID<CurrentNetwork>, block: &str, index: usize, second: bool) {
+        let id = InstructionId { program: program.to_string(), block: block.to_string(), index };
+        self.branches.entry(id).or_default()[usize::from(second)] += 1;
+    }
+}
+
+/// Steps through the logic a case runs, recording each instruction into a [`Trace`].
+///
+/// A failing instruction is recorded and ends the trace with its error, as it ends the run.
+pub(crate) struct Tracer<'a> {
+    trace: &'a mut Trace,
+    /// Drives `call.dynamic`, which is evaluated by snarkVM rather than stepped through.
+    rng: ChaCha20Rng,
+}
+
+impl<'a> Tracer<'a> {
+    pub(crate) fn new(trace: &'a mut Trace, rng: ChaCha20Rng) -> Self {
+        Self { trace, rng }
+    }
+
+    /// Steps through the function of the next request of `authorization`, and the functions and
+    /// closures it calls, in the order snarkVM evaluates them.
+    pub(crate) fn function(
+        &mut self,
+        stack: &Stack<CurrentNetwork>,
+        authorization: &Authorization<CurrentNetwork>,
+        caller: Option<ProgramID<CurrentNetwork>>,
+        root_tvk: Option<Field<CurrentNetwork>>,
+    ) -> anyhow::Result<Vec<Value<CurrentNetwork>>> {
+        let request = authorization.next()?;
+        let function = stack.get_function(request.function_name())?;
+        let block = format!("function {}", function.name());
+
+        let mut registers = Registers::<CurrentNetwork, AleoTestnetV0>::new(
+            CallStack::Evaluate(authorization.clone()),
+            stack.get_register_types(function.name())?.clone(),
+        );
+        registers.set_signer(*request.signer());
+        registers.set_caller(match caller {
+            Some(caller) => caller.to_address()?,
+            None => *request.signer(),
+        });
+        registers.set_tvk(*request.tvk());
+        registers.set_root_tvk(root_tvk.unwrap_or(*request.tvk()));
+        for (input, value) in function.inputs().iter().zip(request.inputs()) {
+            registers.store(stack, input.register(), value.clone())?;
+        }
+
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            self.instruction(stack, &block, index, instruction, &mut registers)?;
+        }
+        function.outputs().iter().map(|output| registers.load(stack, output.operand())).collect()
+    }
+
+    /// Steps through the closure `name` of `stack`, called with `inputs` from `caller`.
+    fn closure(
+        &mut self,
+        stack: &Stack<CurrentNetwork>,
+        name: &Identifier<CurrentNetwork>,
+        inputs: Vec<Value<CurrentNetwork>>,
+        caller: &Registers<CurrentNetwork, AleoTestnetV0>,
+    ) -> anyhow::Result<Vec<Value<CurrentNetwork>>> {
+        let closure = stack.program().get_closure(name)?;
+        let block = format!("closure {name}");
+
+        let mut registers = Registers::<CurrentNetwork, AleoTestnetV0>::new(
+            caller.call_stack(),
+            stack.get_register_types(closure.name())?.clone(),
+        );
+        registers.set_signer(caller.signer()?);
+        registers.set_caller(caller.caller()?);
+        registers.set_tvk(caller.tvk()?);
+        for (input, value) in closure.inputs().iter().zip(inputs) {
+            registers.store(stack, input.register(), value)?;
+        }
+
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            self.instruction(stack, &block, index, instruction, &mut registers)?;
+        }
+        closure.outputs().iter().map(|output| registers.load(stack, output.operand())).collect()
+    }
+
+    /// Records and runs one instruction of a function or closure.
+    fn instruction(
+        &mut self,
+        stack: &Stack<CurrentNetwork>,
+        block: &str,
+        index: usize,
+        instruction: &Instruction<CurrentNetwork>,
+        registers: &mut Registers<CurrentNetwork, AleoTestnetV0>,
+    ) -> anyhow::Result<()> {
+        let program = stack.program_id();
+        self.trace.record(program, block, index);
+        match instruction {
+            Instruction::Call(call) => self.call(stack, call, registers),
+            Instruction::CallDynamic(call) => {
+                CallTrait::evaluate(call, stack, registers, &mut self.rng).map_err(|e| anyhow!("{e}"))
+            }
+            _ => {
+                if let Instruction::Ternary(ternary) = instruction {
+                    let selects_second = !selects_first(stack, registers, &ternary.operands()[0])?;
+                    self.trace.record_branch(program, block, index, selects_second);
+                }
+                instruction.evaluate(stack, registers).map_err(|e| anyhow!("{e}"))
+            }
+        }
+    }
+
+    /// Steps into the closure or function `call` calls, storing its outputs as snarkVM does.
+    fn call(
+        &mut self,
+        stack: &Stack<CurrentNetwork>,
+        call: &Call<CurrentNetwork>,
+        registers: &mut Registers<CurrentNetwork, AleoTestnetV0>,
+    ) -> anyhow::Result<()> {
+        let inputs = call.operands().iter().map(|operand| registers.load(stack, operand)).collect::<Result<_, _>>()?;
+        let (external, resource) = match call.operator() {
+            CallOperator::Locator(locator) => {
+                (Some(stack.get_external_stack(locator.program_id())?), locator.resource())
+            }
+            CallOperator::Resource(resource) => (None, resource),
+        };
+        let substack = external.as_deref().unwrap_or(stack);
+
+        let outputs = if substack.program().contains_closure(resource) {
+            self.closure(substack, resource, inputs, registers)?
+        } else {
+            let CallStack::Evaluate(authorization) = registers.call_stack() else {
+                bail!("Functions can only be traced from an authorization");
+            };
+            self.function(substack, &authorization, Some(*stack.program_id()), Some(registers.root_tvk()?))?
+        };
+        for (output, destination) in outputs.into_iter().zip(call.destinations()) {
+            registers.store(stack, &destination, output)?;
+        }
+        Ok(())
+    }
+
+    /// Steps through the finalize logic of `future`, output by a function of `stack`, and the
+    /// finalize logic it awaits, on `store`.
+    ///
+    /// `transition_id` is the root transition of the execution, which finalize logic is run with
+    /// from `ConsensusVersion::V3` on, and `nonce` counts the finalize blocks started so far.
+    pub(crate) fn finalize(
+        &mut self,
+        stack: &Stack<CurrentNetwork>,
+        store: &impl FinalizeStoreTrait<CurrentNetwork>,
+        state: FinalizeGlobalState,
+        transition_id: <CurrentNetwork as Network>::TransitionID,
+        future: &Future<CurrentNetwork>,
+        nonce: &mut u64,
+    ) -> anyhow::Result<()> {
+        let name = *future.function_name();
+        let Some(finalize) = stack.get_function_ref(&name)?.finalize_logic() else {
+            bail!("`{}/{name}` has no finalize logic", future.program_id());
+        };
+
+        let mut registers = FinalizeRegisters::new(
+            state,
+            Some(transition_id),
+            name,
+            stack.get_finalize_types(&name)?.clone(),
+            Some(*nonce),
+        );
+        *nonce += 1;
+        for (input, argument) in finalize.inputs().iter().zip(future.arguments()) {
+            registers.store(stack, input.register(), Value::from(argument.clone()))?;
+        }
+
+        let block = format!("finalize {name}");
+        let commands = finalize.commands();
+        let mut counter = 0;
+        while counter < commands.len() {
+            let Command::Await(await_) = &commands[counter] else {
+                counter =
+                    self.command(stack, store, &block, commands, finalize.positions(), counter, &mut registers)?;
+                continue;
+            };
+            self.trace.record(stack.program_id(), &block, counter);
+            let Value::Future(awaited) = registers.load(stack, &Operand::Register(await_.register().clone()))? else {
+                bail!("`{}` does not hold a future", await_.register());
+            };
+            let external = match awaited.program_id() == stack.program_id() {
+                true => None,
+                false => Some(stack.get_external_stack(awaited.program_id())?),
+            };
+            self.finalize(external.as_deref().unwrap_or(stack), store, state, transition_id, &awaited, nonce)?;
+            counter += 1;
+        }
+        Ok(())
+    }
+
+    /// Steps through the view `name` of `stack`, called with `inputs`, on `store`.
+    pub(crate) fn view(
+        &mut self,
+        stack: &Stack<CurrentNetwork>,
+        store: &impl FinalizeStoreTrait<CurrentNetwork>,
+        state: FinalizeGlobalState,
+        name: &Identifier<CurrentNetwork>,
+        inputs: Vec<Value<CurrentNetwork>>,
+    ) -> anyhow::Result<()> {
+        let view = stack.program().get_view_ref(name)?;
+        let mut registers = FinalizeRegisters::new(state, None, *name, stack.get_view_types(name)?, None);
+        for (input, value) in view.inputs().iter().zip(inputs) {
+            registers.store(stack, input.register(), value)?;
+        }
+
+        let block = format!("view {name}");
+        let mut counter = 0;
+        while counter < view.commands().len() {
+            counter = self.command(stack, store, &block, view.commands(), view.positions(), counter, &mut registers)?;
+        }
+        Ok(())
+    }
+
+    /// Records and runs the command at `counter` of `block`, returning the index of the next one.
+    ///
+    /// Branches are taken here, as snarkVM does, rather than by the commands themselves. Only
+    /// branches on a value are recorded; `branch.eq true true` is how a block jumps over its `else`.
+    #[allow(clippy::too_many_arguments)]
+    fn command(
+        &mut self,
+        stack: &Stack<CurrentNetwork>,
+        store: &impl FinalizeStoreTrait<CurrentNetwork>,
+        block: &str,
+        commands: &[Command<CurrentNetwork>],
+        positions: &HashMap<Identifier<CurrentNetwork>, usize>,
+        counter: usize,
+        registers: &mut FinalizeRegisters<CurrentNetwork>,
+    ) -> anyhow::Result<usize> {
+        let program = stack.program_id();
+        let command = &commands[counter];
+        self.trace.record(program, block, counter);
+
+        let branch = match command {
+            Command::BranchEq(branch) => Some((branch.first(), branch.second(), true, branch.position())),
+            Command::BranchNeq(branch) => Some((branch.first(), branch.second(), false, branch.position())),
+            _ => None,
+        };
+        if let Some((first, second, jumps_if_equal, position)) = branch {
+            let equal = registers.load(stack, first)? == registers.load(stack, second)?;
+            let jumps = equal == jumps_if_equal;
+            if !matches!((first, second), (Operand::Literal(_), Operand::Literal(_))) {
+                self.trace.record_branch(program, block, counter, jumps);
+            }
+            return match jumps {
+                true => positions.get(position).copied().ok_or_else(|| anyhow!("Unknown position `{position}`")),
+                false => Ok(counter + 1),
+            };
+        }
+
+        if let Command::Instruction(Instruction::Ternary(ternary)) = command {
+            let selects_second = !selects_first(stack, registers, &ternary.operands()[0])?;
+            self.trace.record_branch(program, block, counter, selects_second);
+        }
+        command.finalize(stack, store, registers).map_err(|e| anyhow!("{e}"))?;
+        Ok(counter + 1)
+    }
+}
+
+/// Whether the condition `operand` of a `ternary` selects its first operand.
+fn selects_first(
+    stack: &Stack<CurrentNetwork>,
+    registers: &impl RegistersTrait<CurrentNetwork>,
+    operand: &Operand<CurrentNetwork>,
+) -> anyhow::Result<bool> {
+    match registers.load(stack, operand)? {
+        Value::Plaintext(Plaintext::Literal(Literal::Boolean(condition), _)) => Ok(*condition),
+        value => bail!("The ternary condition `{value}` is not a boolean"),
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Source positions and line breakpoints.
+
+use crate::errors;
+
+use leo_errors::Result;
+use leo_span::{Span, source_map::FileName, with_session_globals};
+
+use indexmap::IndexMap;
+use std::{
+    collections::BTreeSet,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// A resolved position in a source file, with 1-based line and column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourcePosition {
+    /// The file the position lies in, as registered in the session source map.
+    pub path: PathBuf,
+    /// The 1-based line number.
+    pub line: u32,
+    /// The 1-based column, counted in characters.
+    pub column: u32,
+}
+
+impl SourcePosition {
+    /// Resolves the start of `span` through the session source map.
+    ///
+    /// Returns `None` for synthesized spans that do not point into a registered source.
+    pub fn of_span(span: Span) -> Option<Self> {
+        if span.is_dummy() {
+            return None;
+        }
+        with_session_globals(|globals| {
+            let file = globals.source_map.find_source_file(span.lo)?;
+            let (line, column) = file.line_col(span.lo);
+            let path = match &file.name {
+                FileName::Real(path) => path.clone(),
+                FileName::Custom(name) => PathBuf::from(name),
+            };
+            Some(Self { path, line: line + 1, column: column + 1 })
+        })
+    }
+
+    /// Resolves the end of `span`, the position just past its last character.
+    pub fn of_span_end(span: Span) -> Option<Self> {
+        let start = Self::of_span(span)?;
+        with_session_globals(|globals| {
+            let file = globals.source_map.find_source_file(span.lo)?;
+            let (line, column) = file.line_col(span.hi);
+            Some(Self { line: line + 1, column: column + 1, ..start })
+        })
+    }
+
+    /// The text of the source line containing the start of `span`, without its line terminator.
+    pub fn line_text(span: Span) -> Option<String> {
+        with_session_globals(|globals| {
+            let file = globals.source_map.find_source_file(span.lo)?;
+            let (line, _) = file.line_col(span.lo);
+            file.src.lines().nth(line as usize).map(|text| text.trim_end_matches('\r').to_owned())
+        })
+    }
+}
+
+impl fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
+
+/// Line breakpoints, grouped by file.
+///
+/// A breakpoint path matches a source path when the two are equal or when the
+/// source path ends with the breakpoint path, so `main.leo:12` and
+/// `src/main.leo:12` both work from the command line.
+#[derive(Clone, Debug, Default)]
+pub struct Breakpoints {
+    lines: IndexMap<PathBuf, BTreeSet<u32>>,
+}
+
+impl Breakpoints {
+    /// Adds a breakpoint at the 1-based `line` of `path`, returning whether it was new.
+    pub fn insert(&mut self, path: impl AsRef<Path>, line: u32) -> bool {
+        self.lines.entry(normalize(path.as_ref())).or_default().insert(line)
+    }
+
+    /// Removes the breakpoint at `line` of `path`, returning whether one existed.
+    pub fn remove(&mut self, path: impl AsRef<Path>, line: u32) -> bool {
+        let path = normalize(path.as_ref());
+        let Some(lines) = self.lines.get_mut(&path) else {
+            return false;
+        };
+        let removed = lines.remove(&line);
+        if lines.is_empty() {
+            self.lines.shift_remove(&path);
+        }
+        removed
+    }
+
+    /// Replaces every breakpoint in `path` with `lines`, as the Debug Adapter Protocol requires.
+    pub fn set(&mut self, path: impl AsRef<Path>, lines: impl IntoIterator<Item = u32>) {
+        let lines: BTreeSet<u32> = lines.into_iter().collect();
+        let path = normalize(path.as_ref());
+        if lines.is_empty() {
+            self.lines.shift_remove(&path);
+        } else {
+            self.lines.insert(path, lines);
+        }
+    }
+
+    /// Whether a breakpoint is set on the line of `position`.
+    pub fn contains(&self, position: &SourcePosition) -> bool {
+        self.lines.iter().any(|(path, lines)| lines.contains(&position.line) && position.path.ends_with(path))
+    }
+
+    /// Every breakpoint as a `(path, line)` pair.
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, u32)> + '_ {
+        self.lines.iter().flat_map(|(path, lines)| lines.iter().map(move |line| (path.as_path(), *line)))
+    }
+
+    /// Whether no breakpoints are set.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Parses a `[file:]line` breakpoint specification, using `default_file` when no file is given.
+    pub fn parse_spec(spec: &str, default_file: &Path) -> Result<(PathBuf, u32)> {
+        let (path, line) = match spec.rsplit_once(':') {
+            Some((path, line)) => (PathBuf::from(path), line),
+            None => (default_file.to_path_buf(), spec),
+        };
+        match line.trim().parse::<u32>() {
+            Ok(line) if line > 0 => Ok((path, line)),
+            _ => Err(errors::invalid_input(format!("`{spec}` is not a breakpoint; expected `[file:]line`")).into()),
+        }
+    }
+}
+
+/// Canonicalizes absolute paths so editor paths match compiler paths through symlinks.
+fn normalize(path: &Path) -> PathBuf {
+    if path.is_absolute() { path.canonicalize().unwrap_or_else(|_| path.to_path_buf()) } else { path.to_path_buf() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Breakpoints, SourcePosition};
+    use std::path::{Path, PathBuf};
+
+    fn position(path: &str, line: u32) -> SourcePosition {
+        SourcePosition { path: PathBuf::from(path), line, column: 1 }
+    }
+
+    /// Verifies breakpoint paths match by suffix, that `set` replaces a file's lines, and spec parsing.
+    #[test]
+    fn breakpoints_match_by_path_suffix() {
+        let mut breakpoints = Breakpoints::default();
+        assert!(breakpoints.insert("main.leo", 4));
+        assert!(!breakpoints.insert("main.leo", 4));
+
+        assert!(breakpoints.contains(&position("/work/token/src/main.leo", 4)));
+        assert!(!breakpoints.contains(&position("/work/token/src/main.leo", 5)));
+        assert!(!breakpoints.contains(&position("/work/token/src/other_main.leo", 4)));
+
+        breakpoints.set("src/main.leo", [7, 9]);
+        assert!(breakpoints.contains(&position("/work/token/src/main.leo", 9)));
+        assert_eq!(breakpoints.iter().count(), 3);
+
+        assert_eq!(Breakpoints::parse_spec("12", Path::new("main.leo")).unwrap(), (PathBuf::from("main.leo"), 12));
+        assert_eq!(
+            Breakpoints::parse_spec("src/lib.leo:3", Path::new("main.leo")).unwrap().0,
+            PathBuf::from("src/lib.leo")
+        );
+        assert!(Breakpoints::parse_spec("main.leo:0", Path::new("main.leo")).is_err());
+
+        breakpoints.set("src/main.leo", []);
+        assert!(breakpoints.remove("main.leo", 4));
+        assert!(breakpoints.is_empty());
+    }
+}
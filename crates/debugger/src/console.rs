@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The interactive `leo debug` prompt.
+
+use crate::{Breakpoints, ChainState, Controller, Frame, Resume, Stop, StopReason, errors, render_binding};
+
+use leo_errors::Result;
+use leo_span::Symbol;
+
+use std::{
+    io::{BufRead, Write},
+    path::PathBuf,
+};
+
+const HELP: &str = "\
+Commands:
+  next, n            run to the next statement in this function (also: empty line)
+  step, s            run to the next statement, entering calls
+  finish, o          run until the current function returns
+  continue, c        run until the next breakpoint
+  break, b [FILE:]LINE
+                     set a breakpoint, in the current file by default
+  delete, d [FILE:]LINE
+                     remove a breakpoint
+  breakpoints        list breakpoints
+  locals, l          show the local variables of the current function
+  print, p NAME      show one local variable
+  backtrace, bt      show the call stack
+  mappings, m        show every mapping entry
+  storage            show storage variables and vectors
+  quit, q            stop debugging";
+
+/// A [`Controller`] that reads commands from a line-based prompt.
+///
+/// Once the input reaches end of file the remaining run continues without pausing.
+pub struct Console<R, W> {
+    input: R,
+    output: W,
+    finished_input: bool,
+}
+
+impl<R: BufRead, W: Write> Console<R, W> {
+    /// Creates a prompt that reads commands from `input` and writes to `output`.
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output, finished_input: false }
+    }
+
+    fn print_stop(&mut self, stop: &Stop<'_>) -> std::io::Result<()> {
+        match &stop.reason {
+            StopReason::Entry => writeln!(self.output, "Stopped at entry")?,
+            StopReason::Breakpoint => writeln!(self.output, "Breakpoint hit")?,
+            StopReason::Step => {}
+            StopReason::Halted(message) => writeln!(self.output, "Halted: {message}")?,
+        }
+        if let Some(frame) = stop.innermost() {
+            match frame.position() {
+                Some(position) => writeln!(self.output, "  in {} ({}) at {position}", frame.function, frame.phase)?,
+                None => writeln!(self.output, "  in {} ({})", frame.function, frame.phase)?,
+            }
+            if let (Some(position), Some(text)) = (frame.position(), frame.line_text()) {
+                writeln!(self.output, "{:>6} | {text}", position.line)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs one command, returning how to resume if the command resumes execution.
+    fn command(&mut self, line: &str, stop: &Stop<'_>, breakpoints: &mut Breakpoints) -> Result<Option<Resume>> {
+        let (command, argument) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let argument = argument.trim();
+        let current_file = stop.innermost().and_then(Frame::position).map(|position| position.path);
+        let resume = match command {
+            "" | "n" | "next" => Some(Resume::StepOver),
+            "s" | "step" => Some(Resume::StepIn),
+            "o" | "finish" => Some(Resume::StepOut),
+            "c" | "continue" => Some(Resume::Continue),
+            "q" | "quit" => Some(Resume::Terminate),
+            "b" | "break" | "d" | "delete" => {
+                let default_file = current_file.unwrap_or_else(|| PathBuf::from("main.leo"));
+                match Breakpoints::parse_spec(argument, &default_file) {
+                    Ok((path, line)) if matches!(command, "b" | "break") => {
+                        breakpoints.insert(&path, line);
+                        self.print(format_args!("Breakpoint set at {}:{line}", path.display()))?;
+                    }
+                    Ok((path, line)) => {
+                        if breakpoints.remove(&path, line) {
+                            self.print(format_args!("Breakpoint removed at {}:{line}", path.display()))?;
+                        } else {
+                            self.print(format_args!("No breakpoint at {}:{line}", path.display()))?;
+                        }
+                    }
+                    Err(error) => self.print(format_args!("{error}"))?,
+                }
+                None
+            }
+            "breakpoints" => {
+                if breakpoints.is_empty() {
+                    self.print(format_args!("No breakpoints"))?;
+                }
+                for (path, line) in breakpoints.iter() {
+                    self.print(format_args!("  {}:{line}", path.display()))?;
+                }
+                None
+            }
+            "l" | "locals" => {
+                if let Some(frame) = stop.innermost() {
+                    for (name, value) in frame.locals() {
+                        self.print(format_args!("  {name} = {}", render_binding(value)))?;
+                    }
+                }
+                None
+            }
+            "p" | "print" => {
+                match stop.innermost().and_then(|frame| frame.local(Symbol::intern(argument))) {
+                    Some(value) => self.print(format_args!("{argument} = {}", render_binding(value)))?,
+                    None => self.print(format_args!("No local variable named `{argument}`"))?,
+                }
+                None
+            }
+            "bt" | "backtrace" => {
+                for (i, frame) in stop.frames.iter().rev().enumerate() {
+                    match frame.position() {
+                        Some(position) => {
+                            self.print(format_args!("  #{i} {} ({}) at {position}", frame.function, frame.phase))?
+                        }
+                        None => self.print(format_args!("  #{i} {} ({})", frame.function, frame.phase))?,
+                    }
+                }
+                None
+            }
+            "m" | "mappings" => {
+                self.print_mappings(stop.state)?;
+                None
+            }
+            "storage" => {
+                self.print_storage(stop.state)?;
+                None
+            }
+            "h" | "help" => {
+                self.print(format_args!("{HELP}"))?;
+                None
+            }
+            _ => {
+                self.print(format_args!("Unknown command `{command}`; type `help` for a list"))?;
+                None
+            }
+        };
+        Ok(resume)
+    }
+
+    fn print_mappings(&mut self, state: &ChainState) -> Result<()> {
+        if state.mappings.is_empty() {
+            self.print(format_args!("No mappings"))?;
+        }
+        for (location, entries) in &state.mappings {
+            self.print(format_args!("{location} ({} entries)", entries.len()))?;
+            for (key, value) in entries {
+                self.print(format_args!("  {key} => {}", render_binding(value)))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn print_storage(&mut self, state: &ChainState) -> Result<()> {
+        if state.storage.is_empty() && state.vectors.is_empty() {
+            self.print(format_args!("No storage values"))?;
+        }
+        for (location, value) in &state.storage {
+            self.print(format_args!("{location} = {}", render_binding(value)))?;
+        }
+        for (location, elements) in &state.vectors {
+            let elements = elements.iter().map(render_binding).collect::<Vec<_>>().join(", ");
+            self.print(format_args!("{location} = [{elements}]"))?;
+        }
+        Ok(())
+    }
+
+    fn print(&mut self, text: std::fmt::Arguments<'_>) -> Result<()> {
+        writeln!(self.output, "{text}").map_err(|error| errors::io_error(error).into())
+    }
+}
+
+impl<R: BufRead, W: Write> Controller for Console<R, W> {
+    fn stopped(&mut self, stop: &Stop<'_>, breakpoints: &mut Breakpoints) -> Result<Resume> {
+        self.print_stop(stop).map_err(errors::io_error)?;
+        if self.finished_input {
+            return Ok(Resume::Continue);
+        }
+        loop {
+            write!(self.output, "(leo-dbg) ").and_then(|()| self.output.flush()).map_err(errors::io_error)?;
+            let mut line = String::new();
+            if self.input.read_line(&mut line).map_err(errors::io_error)? == 0 {
+                self.finished_input = true;
+                return Ok(Resume::Continue);
+            }
+            if let Some(resume) = self.command(line.trim(), stop, breakpoints)? {
+                return Ok(resume);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Console;
+    use crate::{Breakpoints, ChainState, Controller, Resume, Stop, StopReason};
+
+    /// Verifies breakpoint commands edit the breakpoint set and that the prompt resumes on a step command.
+    #[test]
+    fn console_edits_breakpoints_until_a_resume_command() {
+        let mut output = Vec::new();
+        let mut console = Console::new(&b"break main.leo:4\nbreakpoints\nbogus\nstep\n"[..], &mut output);
+        let state = ChainState::default();
+        let stop = Stop { reason: StopReason::Entry, frames: &[], state: &state };
+        let mut breakpoints = Breakpoints::default();
+
+        assert_eq!(console.stopped(&stop, &mut breakpoints).unwrap(), Resume::StepIn);
+        assert_eq!(breakpoints.iter().count(), 1);
+        assert_eq!(console.stopped(&stop, &mut breakpoints).unwrap(), Resume::Continue);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Breakpoint set at main.leo:4"));
+        assert!(output.contains("Unknown command `bogus`"));
+    }
+}
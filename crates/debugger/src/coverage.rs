@@ -0,0 +1,746 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Statement, branch, and function coverage of test runs.
+//!
+//! [`Coverage::track`] registers every statement, branch point, and function of a
+//! program up front, so code that never runs is reported with a zero count. Runs
+//! of a [`crate::Debugger`] whose `coverage` is set then count each of them as
+//! they execute, following source-level control flow. Runs of the compiled
+//! programs are counted from a [`Trace`] of the Aleo instructions they ran, which
+//! [`Coverage::record_trace`] attributes to statements and branch arms through the
+//! programs' source maps.
+//!
+//! Counts are keyed by source position rather than by node or span, so runs of
+//! different test programs that import the same source add up.
+
+use crate::{DebugProgram, SourcePosition};
+
+use leo_ast::{AstVisitor, ConditionalStatement, Expression, Node, Statement, TernaryExpression};
+use leo_compiler::{AleoSourceMap, InstructionId, Trace};
+use leo_passes::SourceLocation;
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write as _},
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Debug)]
+struct FunctionCounter {
+    /// The function's path within its program, e.g. `transfer` or `utils::clamp`.
+    name: String,
+    calls: u64,
+}
+
+#[derive(Clone, Debug)]
+struct StatementCounter {
+    /// The position of the function the statement belongs to.
+    function: SourcePosition,
+    /// The position just past the end of the statement.
+    end: SourcePosition,
+    /// The branch point and arm the statement is in, if any, with arms numbered as in [`BranchCounter::taken`].
+    arm: Option<(SourcePosition, usize)>,
+    /// The ternary the statement assigns, if it assigns one, whose selection is generated as part of the statement.
+    ternary: Option<SourcePosition>,
+    /// Whether the statement is a `return`, which `Flattening` turns into a selection spanned by the statement.
+    is_return: bool,
+    hits: u64,
+}
+
+#[derive(Clone, Debug)]
+struct BranchCounter {
+    /// The position of the function the branch point belongs to.
+    function: SourcePosition,
+    /// The position just past the end of the branch point.
+    end: SourcePosition,
+    /// For an `if`, the start and end of its condition, which finalize logic branches on.
+    condition: Option<(SourcePosition, SourcePosition)>,
+    /// The statement the branch point belongs to; an `if` is a statement of its own.
+    statement: Option<SourcePosition>,
+    /// How often each arm was taken: the `then` block or `a` in `cond ? a : b` first, then the other.
+    taken: [u64; 2],
+}
+
+impl BranchCounter {
+    fn reached(&self) -> bool {
+        self.taken.iter().any(|&taken| taken > 0)
+    }
+}
+
+/// Execution counts for the statements, branch points, and functions of tracked programs.
+#[derive(Clone, Debug, Default)]
+pub struct Coverage {
+    functions: IndexMap<SourcePosition, FunctionCounter>,
+    statements: IndexMap<SourcePosition, StatementCounter>,
+    branches: IndexMap<SourcePosition, BranchCounter>,
+}
+
+impl Coverage {
+    /// Registers every function of the program `unit` that `program` has Leo source for.
+    ///
+    /// Only tracked code is counted, so tests driving `unit` do not show up in its report.
+    /// Tracking the same source again, e.g. through another test program, is a no-op.
+    pub fn track(&mut self, program: &DebugProgram, unit: Symbol) {
+        for (location, function) in program.functions.iter().filter(|(location, _)| location.program == unit) {
+            let Some(position) = SourcePosition::of_span(function.span) else {
+                continue;
+            };
+            let name = location.path.iter().map(ToString::to_string).collect::<Vec<_>>().join("::");
+            self.functions.entry(position.clone()).or_insert(FunctionCounter { name, calls: 0 });
+            Tracker { coverage: self, function: position, arm: None, statement: None }.visit_block(&function.block);
+        }
+    }
+
+    /// Whether nothing is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    pub(crate) fn record_call(&mut self, span: Span) {
+        if let Some(counter) = SourcePosition::of_span(span).and_then(|position| self.functions.get_mut(&position)) {
+            counter.calls += 1;
+        }
+    }
+
+    pub(crate) fn record_statement(&mut self, span: Span) {
+        if let Some(counter) = SourcePosition::of_span(span).and_then(|position| self.statements.get_mut(&position)) {
+            counter.hits += 1;
+        }
+    }
+
+    pub(crate) fn record_branch(&mut self, span: Span, condition: bool) {
+        if let Some(counter) = SourcePosition::of_span(span).and_then(|position| self.branches.get_mut(&position)) {
+            counter.taken[usize::from(!condition)] += 1;
+        }
+    }
+
+    /// Counts the statements, branch arms, and calls that `trace`, of runs of the compiled programs, ran.
+    ///
+    /// Instructions are attributed to the innermost statement they were generated from through
+    /// `source_maps`, and each statement counts the most its instructions ran in any one block. Selections
+    /// and conditional branches count the arms of the branch point they were generated for: a `ternary`,
+    /// the `if` whose results `Flattening` selects between, or the `return` it folds into a selection.
+    ///
+    /// Because `Flattening` runs both arms of an `if` in a transition, a statement counts at most as often
+    /// as the arm it is in was taken, and a statement without instructions as often as that arm. Copies
+    /// of a statement that loop unrolling or inlining put in the same block count once per run of the block,
+    /// and the arms of a flattened `if` that neither assign nor return count as often as the `if` ran.
+    pub fn record_trace(&mut self, trace: &Trace, source_maps: &[AleoSourceMap]) {
+        let locate = |id: &InstructionId| {
+            let source_map = source_maps.iter().find(|source_map| source_map.program == id.program)?;
+            source_map.entry(&id.block, id.index).map(|entry| Location::of(&entry.source))
+        };
+        // Statements that have instructions run zero times until traced, rather than as often as their arm.
+        let mapped = source_maps
+            .iter()
+            .flat_map(|source_map| &source_map.entries)
+            .filter_map(|entry| self.statement_at(&Location::of(&entry.source)))
+            .collect::<IndexSet<_>>();
+
+        // The most any instruction of a statement, or any selection of an arm, ran in each block.
+        let mut statement_runs: IndexMap<(&str, &str), IndexMap<SourcePosition, u64>> = IndexMap::new();
+        for (id, &count) in &trace.instructions {
+            if let Some(statement) = locate(id).and_then(|location| self.statement_at(&location)) {
+                let runs = statement_runs.entry((&id.program, &id.block)).or_default().entry(statement).or_default();
+                *runs = count.max(*runs);
+            }
+        }
+        let mut arm_runs: IndexMap<(&str, &str), IndexMap<SourcePosition, [u64; 2]>> = IndexMap::new();
+        for (id, &counts) in &trace.branches {
+            let Some(location) = locate(id) else {
+                continue;
+            };
+            for (branch, arm, count) in self.arms_at(&location, counts) {
+                let runs = &mut arm_runs.entry((&id.program, &id.block)).or_default().entry(branch).or_default()[arm];
+                *runs = count.max(*runs);
+            }
+        }
+        let mut measured: IndexMap<SourcePosition, u64> = IndexMap::new();
+        for (statement, runs) in statement_runs.into_values().flatten() {
+            *measured.entry(statement).or_default() += runs;
+        }
+        let mut selected: IndexMap<SourcePosition, [u64; 2]> = IndexMap::new();
+        for (branch, runs) in arm_runs.into_values().flatten() {
+            let counts = selected.entry(branch).or_default();
+            counts[0] += runs[0];
+            counts[1] += runs[1];
+        }
+
+        // A function runs as often as its most executed top-level statement.
+        let mut calls: IndexMap<SourcePosition, u64> = IndexMap::new();
+        for (position, statement) in self.statements.iter().filter(|(_, statement)| statement.arm.is_none()) {
+            let runs = measured.get(position).copied().unwrap_or_default();
+            let calls = calls.entry(statement.function.clone()).or_default();
+            *calls = runs.max(*calls);
+        }
+
+        // Bound statements by their arm and arms by their statement, outermost first; an `if` is visited as a
+        // statement before its arms are, as it starts where its branch point does.
+        let mut order = self
+            .statements
+            .keys()
+            .map(|position| (position.clone(), false))
+            .chain(self.branches.keys().map(|position| (position.clone(), true)))
+            .collect::<Vec<_>>();
+        order.sort();
+        let mut hits: IndexMap<SourcePosition, u64> = IndexMap::new();
+        let mut taken: IndexMap<SourcePosition, [u64; 2]> = IndexMap::new();
+        for (position, is_branch) in order {
+            if is_branch {
+                let (Some(runs), Some(branch)) = (selected.get(&position), self.branches.get(&position)) else {
+                    continue;
+                };
+                let bound = branch.statement.as_ref().and_then(|statement| hits.get(statement)).copied();
+                let bound = bound.unwrap_or(u64::MAX);
+                taken.insert(position, [runs[0].min(bound), runs[1].min(bound)]);
+                continue;
+            }
+            let statement = &self.statements[&position];
+            let bound = match &statement.arm {
+                Some((branch, arm)) => match taken.get(branch) {
+                    Some(taken) => taken[*arm],
+                    None => hits.get(branch).copied().unwrap_or_default(),
+                },
+                None => calls.get(&statement.function).copied().unwrap_or_default(),
+            };
+            let runs = match mapped.contains(&position) {
+                true => measured.get(&position).copied().unwrap_or_default().min(bound),
+                false => bound,
+            };
+            hits.insert(position, runs);
+        }
+
+        for (position, runs) in hits {
+            self.statements[&position].hits += runs;
+        }
+        for (position, counts) in taken {
+            let branch = &mut self.branches[&position];
+            branch.taken[0] += counts[0];
+            branch.taken[1] += counts[1];
+        }
+        for (position, runs) in calls {
+            if let Some(function) = self.functions.get_mut(&position) {
+                function.calls += runs;
+            }
+        }
+    }
+
+    /// The innermost tracked statement `location` lies in.
+    fn statement_at(&self, location: &Location) -> Option<SourcePosition> {
+        self.statements
+            .iter()
+            .filter(|(position, statement)| location.within(position, &statement.end))
+            .max_by_key(|(position, _)| *position)
+            .map(|(position, _)| position.clone())
+    }
+
+    /// The arms a selection or conditional branch at `location` counts, with how often it took each.
+    fn arms_at(&self, location: &Location, [first, second]: [u64; 2]) -> Vec<(SourcePosition, usize, u64)> {
+        let both = |branch: &SourcePosition| vec![(branch.clone(), 0, first), (branch.clone(), 1, second)];
+        // A `ternary`, or the results of an `if` selected after flattening it.
+        if let Some((branch, _)) = self.branches.iter().find(|(position, branch)| location.is(position, &branch.end)) {
+            return both(branch);
+        }
+        // The branch finalize logic takes on the condition of an `if`.
+        if let Some((branch, _)) = self
+            .branches
+            .iter()
+            .find(|(_, branch)| branch.condition.as_ref().is_some_and(|(start, end)| location.is(start, end)))
+        {
+            return both(branch);
+        }
+        let Some((_, statement)) =
+            self.statements.iter().find(|(position, statement)| location.is(position, &statement.end))
+        else {
+            return Vec::new();
+        };
+        if !statement.is_return {
+            return statement.ternary.as_ref().map(both).unwrap_or_default();
+        }
+        // A `return` is selected when all the arms it is in are taken.
+        let mut arms = Vec::new();
+        let mut arm = statement.arm.clone();
+        while let Some((branch, index)) = arm {
+            arm = self.statements.get(&branch).and_then(|statement| statement.arm.clone());
+            arms.push((branch, index, first));
+        }
+        if let [(branch, index, _)] = arms.as_slice() {
+            let other = (branch.clone(), 1 - index, second);
+            arms.push(other);
+        }
+        arms
+    }
+
+    /// Renders the counts as an lcov tracefile, with one record per source file.
+    pub fn lcov(&self) -> String {
+        let mut out = String::new();
+        for file in self.files() {
+            let _ = writeln!(out, "TN:\nSF:{}", file.path.display());
+            for (position, function) in &file.functions {
+                let _ = writeln!(out, "FN:{},{}", position.line, function.name);
+            }
+            for (_, function) in &file.functions {
+                let _ = writeln!(out, "FNDA:{},{}", function.calls, function.name);
+            }
+            let called = file.functions.iter().filter(|(_, function)| function.calls > 0).count();
+            let _ = writeln!(out, "FNF:{}\nFNH:{called}", file.functions.len());
+            for (block, (position, branch)) in file.branches.iter().enumerate() {
+                for (arm, taken) in branch.taken.iter().enumerate() {
+                    let taken = if branch.reached() { taken.to_string() } else { "-".to_string() };
+                    let _ = writeln!(out, "BRDA:{},{block},{arm},{taken}", position.line);
+                }
+            }
+            let branches = file.branch_tally(|_| true);
+            let _ = writeln!(out, "BRF:{}\nBRH:{}", branches.total, branches.hit);
+            let lines = file.lines();
+            for (line, hits) in &lines {
+                let _ = writeln!(out, "DA:{line},{hits}");
+            }
+            let hit = lines.values().filter(|&&hits| hits > 0).count();
+            let _ = writeln!(out, "LF:{}\nLH:{hit}\nend_of_record", lines.len());
+        }
+        out
+    }
+
+    /// Renders a table of statement, branch, and function coverage per file and function.
+    pub fn summary(&self) -> String {
+        let files = self.files();
+        let mut rows = vec![["File / function".to_string(), "Statements".into(), "Branches".into(), "Calls".into()]];
+        let (mut statements, mut branches, mut functions) = (Tally::default(), Tally::default(), Tally::default());
+        for file in &files {
+            let file_functions = file.function_tally();
+            rows.push([
+                display_path(file.path),
+                file.statement_tally(|_| true).to_string(),
+                file.branch_tally(|_| true).to_string(),
+                format!("{}/{} functions", file_functions.hit, file_functions.total),
+            ]);
+            for (position, function) in &file.functions {
+                rows.push([
+                    format!("  {}", function.name),
+                    file.statement_tally(|owner| owner == *position).to_string(),
+                    file.branch_tally(|owner| owner == *position).to_string(),
+                    function.calls.to_string(),
+                ]);
+            }
+            statements += file.statement_tally(|_| true);
+            branches += file.branch_tally(|_| true);
+            functions += file_functions;
+        }
+        rows.push([
+            "Total".to_string(),
+            statements.to_string(),
+            branches.to_string(),
+            format!("{}/{} functions", functions.hit, functions.total),
+        ]);
+
+        let widths: Vec<usize> =
+            (0..4).map(|column| rows.iter().map(|row| row[column].chars().count()).max().unwrap_or(0)).collect();
+        let mut out = String::new();
+        for row in &rows {
+            let line =
+                row.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect::<Vec<_>>().join("  ");
+            let _ = writeln!(out, "{}", line.trim_end());
+        }
+        out
+    }
+
+    /// Renders a standalone HTML page with the summary table and each file's source, annotated with hit counts.
+    pub fn html(&self) -> String {
+        let files = self.files();
+        let mut out = String::from(HTML_HEADER);
+        out.push_str(
+            "<table class=\"summary\">\n<tr><th>File</th><th>Statements</th><th>Branches</th><th>Functions</th></tr>\n",
+        );
+        for (index, file) in files.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "<tr><td><a href=\"#file-{index}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&display_path(file.path)),
+                file.statement_tally(|_| true),
+                file.branch_tally(|_| true),
+                file.function_tally(),
+            );
+        }
+        out.push_str("</table>\n");
+
+        for (index, file) in files.iter().enumerate() {
+            let _ = writeln!(out, "<h2 id=\"file-{index}\">{}</h2>", escape(&display_path(file.path)));
+            let Ok(source) = std::fs::read_to_string(file.path) else {
+                out.push_str("<p>The source file could not be read.</p>\n");
+                continue;
+            };
+            let lines = file.lines();
+            out.push_str("<table class=\"source\">\n");
+            for (number, text) in (1u32..).zip(source.lines()) {
+                let (class, count) = match lines.get(&number) {
+                    Some(0) => ("miss", "0".to_string()),
+                    Some(hits) if file.has_partial_branch(number) => ("partial", hits.to_string()),
+                    Some(hits) => ("hit", hits.to_string()),
+                    None => ("", String::new()),
+                };
+                let _ = writeln!(
+                    out,
+                    "<tr class=\"{class}\"><td class=\"line\">{number}</td><td class=\"count\">{count}</td><td><pre>{}</pre></td></tr>",
+                    escape(text)
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// The tracked positions grouped by file, each group sorted by position.
+    fn files(&self) -> Vec<FileReport<'_>> {
+        let mut files: BTreeMap<&Path, FileReport<'_>> = BTreeMap::new();
+        for (position, function) in &self.functions {
+            files
+                .entry(&position.path)
+                .or_insert_with(|| FileReport::new(&position.path))
+                .functions
+                .push((position, function));
+        }
+        for (position, statement) in &self.statements {
+            files
+                .entry(&position.path)
+                .or_insert_with(|| FileReport::new(&position.path))
+                .statements
+                .push((position, statement));
+        }
+        for (position, branch) in &self.branches {
+            files
+                .entry(&position.path)
+                .or_insert_with(|| FileReport::new(&position.path))
+                .branches
+                .push((position, branch));
+        }
+        let mut files = files.into_values().collect::<Vec<_>>();
+        for file in &mut files {
+            file.functions.sort_by_key(|(position, _)| *position);
+            file.statements.sort_by_key(|(position, _)| *position);
+            file.branches.sort_by_key(|(position, _)| *position);
+        }
+        files
+    }
+}
+
+/// The range of source a source map attributes an instruction to.
+struct Location {
+    start: SourcePosition,
+    end: SourcePosition,
+}
+
+impl Location {
+    fn of(source: &SourceLocation) -> Self {
+        let position = |line: usize, column: usize| SourcePosition {
+            path: PathBuf::from(&source.file),
+            line: line as u32,
+            column: column as u32,
+        };
+        Self { start: position(source.line, source.column), end: position(source.end_line, source.end_column) }
+    }
+
+    /// Whether the location lies within the range from `start` to `end`.
+    fn within(&self, start: &SourcePosition, end: &SourcePosition) -> bool {
+        start.path == self.start.path && *start <= self.start && self.end <= *end
+    }
+
+    /// Whether the location is exactly the range from `start` to `end`.
+    fn is(&self, start: &SourcePosition, end: &SourcePosition) -> bool {
+        self.start == *start && self.end == *end
+    }
+}
+
+/// Registers the statements and branch points of one function body.
+struct Tracker<'a> {
+    coverage: &'a mut Coverage,
+    function: SourcePosition,
+    /// The innermost branch arm being visited.
+    arm: Option<(SourcePosition, usize)>,
+    /// The innermost statement being visited.
+    statement: Option<SourcePosition>,
+}
+
+impl Tracker<'_> {
+    fn track_branch(&mut self, span: Span, condition: Option<Span>) -> Option<SourcePosition> {
+        let (position, end) = SourcePosition::of_span(span).zip(SourcePosition::of_span_end(span))?;
+        let condition = condition.and_then(|span| SourcePosition::of_span(span).zip(SourcePosition::of_span_end(span)));
+        let (function, statement) = (self.function.clone(), self.statement.clone());
+        self.coverage.branches.entry(position.clone()).or_insert(BranchCounter {
+            function,
+            end,
+            condition,
+            statement,
+            taken: [0; 2],
+        });
+        Some(position)
+    }
+}
+
+impl AstVisitor for Tracker<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_statement(&mut self, input: &Statement) {
+        let outer = self.statement.clone();
+        // Blocks are not executed as statements of their own, so only their contents are counted.
+        if !matches!(input, Statement::Block(_))
+            && let Some((position, end)) =
+                SourcePosition::of_span(input.span()).zip(SourcePosition::of_span_end(input.span()))
+        {
+            let ternary = match input {
+                Statement::Definition(stmt) => Some(&stmt.value),
+                Statement::Assign(stmt) => Some(&stmt.value),
+                _ => None,
+            };
+            let ternary = match ternary {
+                Some(Expression::Ternary(ternary)) => SourcePosition::of_span(ternary.span),
+                _ => None,
+            };
+            self.coverage.statements.entry(position.clone()).or_insert(StatementCounter {
+                function: self.function.clone(),
+                end,
+                arm: self.arm.clone(),
+                ternary,
+                is_return: matches!(input, Statement::Return(_)),
+                hits: 0,
+            });
+            self.statement = Some(position);
+        }
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(stmt) => self.visit_break(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Continue(stmt) => self.visit_continue(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match_statement(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+            Statement::While(stmt) => self.visit_while(stmt),
+        }
+        self.statement = outer;
+    }
+
+    fn visit_conditional(&mut self, input: &ConditionalStatement) {
+        let position = self.track_branch(input.span, Some(input.condition.span()));
+        self.visit_expression(&input.condition, &());
+        let outer = self.arm.clone();
+        self.arm = position.clone().map(|position| (position, 0));
+        self.visit_block(&input.then);
+        if let Some(otherwise) = &input.otherwise {
+            self.arm = position.map(|position| (position, 1));
+            self.visit_statement(otherwise);
+        }
+        self.arm = outer;
+    }
+
+    fn visit_ternary(&mut self, input: &TernaryExpression, _additional: &()) {
+        self.track_branch(input.span, None);
+        self.visit_expression(&input.condition, &());
+        self.visit_expression(&input.if_true, &());
+        self.visit_expression(&input.if_false, &());
+    }
+}
+
+/// The tracked positions of one source file.
+struct FileReport<'a> {
+    path: &'a Path,
+    functions: Vec<(&'a SourcePosition, &'a FunctionCounter)>,
+    statements: Vec<(&'a SourcePosition, &'a StatementCounter)>,
+    branches: Vec<(&'a SourcePosition, &'a BranchCounter)>,
+}
+
+impl<'a> FileReport<'a> {
+    fn new(path: &'a Path) -> Self {
+        Self { path, functions: Vec::new(), statements: Vec::new(), branches: Vec::new() }
+    }
+
+    /// Hit counts per line with a statement, taking the most executed statement starting on each line.
+    fn lines(&self) -> BTreeMap<u32, u64> {
+        let mut lines = BTreeMap::new();
+        for (position, statement) in &self.statements {
+            let hits = lines.entry(position.line).or_insert(0);
+            *hits = statement.hits.max(*hits);
+        }
+        lines
+    }
+
+    /// Whether a branch point on `line` was reached without taking both arms.
+    fn has_partial_branch(&self, line: u32) -> bool {
+        self.branches
+            .iter()
+            .any(|(position, branch)| position.line == line && branch.taken.iter().any(|&taken| taken == 0))
+    }
+
+    fn statement_tally(&self, owner: impl Fn(&SourcePosition) -> bool) -> Tally {
+        self.statements
+            .iter()
+            .filter(|(_, statement)| owner(&statement.function))
+            .fold(Tally::default(), |tally, (_, statement)| tally.with(statement.hits > 0))
+    }
+
+    /// Counts branch arms, so an `if` contributes two.
+    fn branch_tally(&self, owner: impl Fn(&SourcePosition) -> bool) -> Tally {
+        self.branches
+            .iter()
+            .filter(|(_, branch)| owner(&branch.function))
+            .flat_map(|(_, branch)| branch.taken)
+            .fold(Tally::default(), |tally, taken| tally.with(taken > 0))
+    }
+
+    fn function_tally(&self) -> Tally {
+        self.functions.iter().fold(Tally::default(), |tally, (_, function)| tally.with(function.calls > 0))
+    }
+}
+
+/// How many of some set of items were executed.
+#[derive(Clone, Copy, Debug, Default)]
+struct Tally {
+    hit: usize,
+    total: usize,
+}
+
+impl Tally {
+    fn with(self, hit: bool) -> Self {
+        Self { hit: self.hit + usize::from(hit), total: self.total + 1 }
+    }
+}
+
+impl std::ops::AddAssign for Tally {
+    fn add_assign(&mut self, other: Self) {
+        self.hit += other.hit;
+        self.total += other.total;
+    }
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.total == 0 {
+            return write!(f, "-");
+        }
+        write!(f, "{}/{} ({:.1}%)", self.hit, self.total, self.hit as f64 * 100.0 / self.total as f64)
+    }
+}
+
+/// The path relative to the working directory when it lies inside it.
+fn display_path(path: &Path) -> String {
+    let relative = std::env::current_dir().ok().and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf));
+    relative.as_deref().unwrap_or(path).display().to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+const HTML_HEADER: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Leo coverage</title>
+<style>
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+.summary td, .summary th { padding: 0.2em 1em; text-align: left; }
+.source td { padding: 0 0.5em; vertical-align: top; }
+.source pre { margin: 0; }
+.line, .count { color: #888; text-align: right; }
+.hit { background: #e6ffed; }
+.miss { background: #ffeef0; }
+.partial { background: #fff5b1; }
+</style>
+</head>
+<body>
+<h1>Leo coverage</h1>
+"#;
+
+#[cfg(test)]
+mod tests {
+    use crate::{Coverage, DebugProgram, Debugger, Unattended};
+
+    use leo_ast::NetworkName;
+    use leo_span::{Symbol, create_session_if_not_set_then, file_source::InMemoryFileSource};
+
+    use indexmap::IndexMap;
+    use std::path::PathBuf;
+
+    const SOURCE: &str = r#"program clamp.aleo {
+    fn clamp(public amount: u64) -> u64 {
+        let capped: u64 = amount > 10u64 ? 10u64 : amount;
+        if capped == 10u64 {
+            return capped;
+        } else {
+            return amount;
+        }
+    }
+
+    @noupgrade
+    constructor() {}
+}
+"#;
+
+    /// Verifies statements, both branch kinds, and calls are counted per arm and reported in lcov.
+    #[test]
+    fn counts_statements_and_branch_arms() {
+        create_session_if_not_set_then(|_| {
+            let mut source = InMemoryFileSource::new();
+            source.set(PathBuf::from("/clamp/src/main.leo"), SOURCE.into());
+            let program = DebugProgram::analyze(
+                "/clamp/src/main.leo",
+                "/clamp/src",
+                &source,
+                IndexMap::new(),
+                NetworkName::TestnetV0,
+            )
+            .unwrap_or_else(|err| panic!("analysis failed: {err}"));
+
+            let mut coverage = Coverage::default();
+            coverage.track(&program, Symbol::intern("clamp.aleo"));
+            let mut debugger = Debugger::new(program);
+            debugger.coverage = Some(coverage);
+
+            let function = debugger.program().resolve_function("clamp").unwrap();
+            for input in ["3u64", "4u64"] {
+                let inputs = debugger.parse_inputs(&function, &[input.into()]).unwrap();
+                debugger.run(&function, inputs, &mut Unattended, false).unwrap().unwrap();
+            }
+
+            let lcov = debugger.coverage.as_ref().unwrap().lcov();
+            for expected in [
+                "SF:/clamp/src/main.leo",
+                "FNDA:2,clamp",
+                "BRDA:3,0,0,0",
+                "BRDA:3,0,1,2",
+                "BRDA:4,1,0,0",
+                "BRDA:4,1,1,2",
+                "BRF:4\nBRH:2",
+                "DA:3,2",
+                "DA:5,0",
+                "DA:7,2",
+                "LF:4\nLH:3",
+            ] {
+                assert!(lcov.contains(expected), "missing `{expected}` in:\n{lcov}");
+            }
+        });
+    }
+}
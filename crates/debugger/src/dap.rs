@@ -0,0 +1,432 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A Debug Adapter Protocol server over a byte stream.
+//!
+//! The server speaks the subset of the protocol a single-threaded, launch-only
+//! debugger needs: one thread, line breakpoints, stepping, and three variable
+//! scopes per frame (locals, mappings, and storage). Messages use the
+//! protocol's `Content-Length` framing.
+
+use crate::{Breakpoints, Controller, Frame, Resume, Stop, StopReason, errors, render_binding};
+
+use leo_errors::Result;
+use leo_span::Symbol;
+
+use serde_json::{Value as Json, json};
+use std::io::{BufRead, Write};
+
+/// The only thread the debugger reports.
+const THREAD_ID: i64 = 1;
+
+/// Variable references at or above this value expand a single mapping.
+const MAPPING_REFERENCE_BASE: i64 = 1_000_000;
+
+/// The arguments of the client's `launch` request.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LaunchRequest {
+    /// The function to run, as accepted by [`crate::DebugProgram::resolve_function`].
+    pub function: Option<String>,
+    /// Inputs to the function, in Leo value syntax.
+    pub inputs: Option<Vec<String>>,
+    /// Whether to pause before the first statement.
+    pub stop_on_entry: bool,
+}
+
+/// A [`Controller`] that serves a Debug Adapter Protocol client.
+pub struct DapServer<R, W> {
+    input: R,
+    output: W,
+    seq: i64,
+}
+
+impl<R: BufRead, W: Write> DapServer<R, W> {
+    /// Creates a server reading requests from `input` and writing responses and events to `output`.
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output, seq: 0 }
+    }
+
+    /// Serves requests up to the end of configuration and returns the client's launch arguments.
+    pub fn handshake(&mut self, breakpoints: &mut Breakpoints) -> Result<LaunchRequest> {
+        let mut launch = None;
+        let mut configured = false;
+        while launch.is_none() || !configured {
+            let Some(request) = self.read()? else {
+                return Err(errors::protocol_error("the client disconnected before launching").into());
+            };
+            match command(&request) {
+                "initialize" => {
+                    let capabilities = json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsEvaluateForHovers": true,
+                    });
+                    self.respond(&request, capabilities)?;
+                    self.event("initialized", json!({}))?;
+                }
+                "launch" => {
+                    let arguments = &request["arguments"];
+                    launch = Some(LaunchRequest {
+                        function: arguments["function"].as_str().map(str::to_owned),
+                        inputs: arguments["inputs"].as_array().map(|inputs| {
+                            inputs
+                                .iter()
+                                .map(|input| input.as_str().map_or_else(|| input.to_string(), str::to_owned))
+                                .collect()
+                        }),
+                        stop_on_entry: arguments["stopOnEntry"].as_bool().unwrap_or(false),
+                    });
+                    self.respond(&request, json!({}))?;
+                }
+                "configurationDone" => {
+                    configured = true;
+                    self.respond(&request, json!({}))?;
+                }
+                "setBreakpoints" => self.set_breakpoints(&request, breakpoints)?,
+                "setExceptionBreakpoints" => self.respond(&request, json!({}))?,
+                "threads" => self.respond(&request, threads())?,
+                "disconnect" | "terminate" => {
+                    self.respond(&request, json!({}))?;
+                    return Err(errors::protocol_error("the client disconnected before launching").into());
+                }
+                _ => self.fail(&request, "not supported before launch")?,
+            }
+        }
+        Ok(launch.expect("the loop only exits after a launch request"))
+    }
+
+    /// Sends program output to the client's debug console.
+    pub fn output(&mut self, text: &str) -> Result<()> {
+        self.event("output", json!({ "category": "console", "output": format!("{text}\n") }))
+    }
+
+    /// Reports the end of the run and serves the client until it disconnects.
+    pub fn finish(&mut self, exit_code: i32) -> Result<()> {
+        self.event("exited", json!({ "exitCode": exit_code }))?;
+        self.event("terminated", json!({}))?;
+        while let Some(request) = self.read()? {
+            match command(&request) {
+                "disconnect" => return self.respond(&request, json!({})),
+                "threads" => self.respond(&request, threads())?,
+                _ => self.fail(&request, "the program has exited")?,
+            }
+        }
+        Ok(())
+    }
+
+    fn set_breakpoints(&mut self, request: &Json, breakpoints: &mut Breakpoints) -> Result<()> {
+        let arguments = &request["arguments"];
+        let Some(path) = arguments["source"]["path"].as_str() else {
+            return self.fail(request, "breakpoints need a source path");
+        };
+        let lines: Vec<u32> = arguments["breakpoints"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|breakpoint| breakpoint["line"].as_u64()?.try_into().ok())
+            .collect();
+        breakpoints.set(path, lines.iter().copied());
+        let verified: Vec<Json> = lines.iter().map(|line| json!({ "verified": true, "line": line })).collect();
+        self.respond(request, json!({ "breakpoints": verified }))
+    }
+
+    fn stack_trace(&mut self, request: &Json, stop: &Stop<'_>) -> Result<()> {
+        let frames: Vec<Json> = stop
+            .frames
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(id, frame)| {
+                let mut json = json!({ "id": id, "name": format!("{} ({})", frame.function, frame.phase) });
+                match frame.position() {
+                    Some(position) => {
+                        let name = position.path.file_name().map(|name| name.to_string_lossy().into_owned());
+                        json["source"] = json!({ "name": name, "path": position.path });
+                        json["line"] = position.line.into();
+                        json["column"] = position.column.into();
+                    }
+                    None => {
+                        json["line"] = 0.into();
+                        json["column"] = 0.into();
+                    }
+                }
+                json
+            })
+            .collect();
+        let total = frames.len();
+        self.respond(request, json!({ "stackFrames": frames, "totalFrames": total }))
+    }
+
+    fn scopes(&mut self, request: &Json) -> Result<()> {
+        let base = request["arguments"]["frameId"].as_i64().unwrap_or(0) * 3;
+        let scopes = json!([
+            { "name": "Locals", "presentationHint": "locals", "variablesReference": base + 1, "expensive": false },
+            { "name": "Mappings", "variablesReference": base + 2, "expensive": false },
+            { "name": "Storage", "variablesReference": base + 3, "expensive": false },
+        ]);
+        self.respond(request, json!({ "scopes": scopes }))
+    }
+
+    fn variables(&mut self, request: &Json, stop: &Stop<'_>) -> Result<()> {
+        let reference = request["arguments"]["variablesReference"].as_i64().unwrap_or(0);
+        let variable = |name: String, value: String, reference: i64| json!({ "name": name, "value": value, "variablesReference": reference });
+        let variables: Vec<Json> = if reference >= MAPPING_REFERENCE_BASE {
+            let index = (reference - MAPPING_REFERENCE_BASE) as usize;
+            let entries = stop.state.mappings.get_index(index).map(|(_, entries)| entries);
+            entries
+                .into_iter()
+                .flatten()
+                .map(|(key, value)| variable(key.to_string(), render_binding(value), 0))
+                .collect()
+        } else if reference >= 1 {
+            match (reference - 1) % 3 {
+                0 => frame_at(stop, (reference - 1) / 3)
+                    .map(|frame| {
+                        frame
+                            .locals()
+                            .into_iter()
+                            .map(|(name, value)| variable(name.to_string(), render_binding(value), 0))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                1 => stop
+                    .state
+                    .mappings
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (location, entries))| {
+                        let reference = if entries.is_empty() { 0 } else { MAPPING_REFERENCE_BASE + i as i64 };
+                        variable(location.to_string(), format!("{} entries", entries.len()), reference)
+                    })
+                    .collect(),
+                _ => {
+                    let storage = stop
+                        .state
+                        .storage
+                        .iter()
+                        .map(|(location, value)| variable(location.to_string(), render_binding(value), 0));
+                    let vectors = stop.state.vectors.iter().map(|(location, elements)| {
+                        let elements = elements.iter().map(render_binding).collect::<Vec<_>>().join(", ");
+                        variable(location.to_string(), format!("[{elements}]"), 0)
+                    });
+                    storage.chain(vectors).collect()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        self.respond(request, json!({ "variables": variables }))
+    }
+
+    fn evaluate(&mut self, request: &Json, stop: &Stop<'_>) -> Result<()> {
+        let arguments = &request["arguments"];
+        let expression = arguments["expression"].as_str().unwrap_or_default().trim();
+        let frame = frame_at(stop, arguments["frameId"].as_i64().unwrap_or(0));
+        match frame.and_then(|frame| frame.local(Symbol::intern(expression))) {
+            Some(value) => self.respond(request, json!({ "result": render_binding(value), "variablesReference": 0 })),
+            None => self.fail(request, &format!("`{expression}` is not a local variable")),
+        }
+    }
+
+    /// Reads one message, or `None` at end of input.
+    fn read(&mut self) -> Result<Option<Json>> {
+        let mut length = None;
+        loop {
+            let mut line = String::new();
+            if self.input.read_line(&mut line).map_err(errors::io_error)? == 0 {
+                return Ok(None);
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                if length.is_some() {
+                    break;
+                }
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("Content-Length:") {
+                let value = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| errors::protocol_error(format!("`{value}` is not a valid content length")))?;
+                length = Some(value);
+            }
+        }
+        let mut body = vec![0; length.expect("the header loop only exits with a length")];
+        self.input.read_exact(&mut body).map_err(errors::io_error)?;
+        serde_json::from_slice(&body).map(Some).map_err(|error| errors::protocol_error(error).into())
+    }
+
+    fn send(&mut self, mut message: Json) -> Result<()> {
+        self.seq += 1;
+        message["seq"] = self.seq.into();
+        let body = message.to_string();
+        write!(self.output, "Content-Length: {}\r\n\r\n{body}", body.len())
+            .and_then(|()| self.output.flush())
+            .map_err(|error| errors::io_error(error).into())
+    }
+
+    fn respond(&mut self, request: &Json, body: Json) -> Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": true,
+            "command": request["command"],
+            "body": body,
+        }))
+    }
+
+    fn fail(&mut self, request: &Json, message: &str) -> Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": false,
+            "command": request["command"],
+            "message": message,
+        }))
+    }
+
+    fn event(&mut self, event: &str, body: Json) -> Result<()> {
+        self.send(json!({ "type": "event", "event": event, "body": body }))
+    }
+}
+
+impl<R: BufRead, W: Write> Controller for DapServer<R, W> {
+    fn stopped(&mut self, stop: &Stop<'_>, breakpoints: &mut Breakpoints) -> Result<Resume> {
+        let (reason, text) = match &stop.reason {
+            StopReason::Entry => ("entry", None),
+            StopReason::Breakpoint => ("breakpoint", None),
+            StopReason::Step => ("step", None),
+            StopReason::Halted(message) => ("exception", Some(message.as_str())),
+        };
+        self.event(
+            "stopped",
+            json!({ "reason": reason, "text": text, "threadId": THREAD_ID, "allThreadsStopped": true }),
+        )?;
+
+        loop {
+            let Some(request) = self.read()? else {
+                return Ok(Resume::Terminate);
+            };
+            let resume = match command(&request) {
+                "continue" => Some(Resume::Continue),
+                "next" => Some(Resume::StepOver),
+                "stepIn" => Some(Resume::StepIn),
+                "stepOut" => Some(Resume::StepOut),
+                "disconnect" | "terminate" => Some(Resume::Terminate),
+                "threads" => {
+                    self.respond(&request, threads())?;
+                    None
+                }
+                "stackTrace" => {
+                    self.stack_trace(&request, stop)?;
+                    None
+                }
+                "scopes" => {
+                    self.scopes(&request)?;
+                    None
+                }
+                "variables" => {
+                    self.variables(&request, stop)?;
+                    None
+                }
+                "evaluate" => {
+                    self.evaluate(&request, stop)?;
+                    None
+                }
+                "setBreakpoints" => {
+                    self.set_breakpoints(&request, breakpoints)?;
+                    None
+                }
+                "setExceptionBreakpoints" | "pause" => {
+                    self.respond(&request, json!({}))?;
+                    None
+                }
+                _ => {
+                    self.fail(&request, "not supported")?;
+                    None
+                }
+            };
+            if let Some(resume) = resume {
+                let body = if resume == Resume::Continue { json!({ "allThreadsContinued": true }) } else { json!({}) };
+                self.respond(&request, body)?;
+                return Ok(resume);
+            }
+        }
+    }
+}
+
+fn command(request: &Json) -> &str {
+    request["command"].as_str().unwrap_or_default()
+}
+
+fn threads() -> Json {
+    json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] })
+}
+
+/// The frame a protocol frame ID refers to; IDs count from the innermost frame.
+fn frame_at<'a>(stop: &'a Stop<'_>, id: i64) -> Option<&'a Frame> {
+    stop.frames.iter().rev().nth(usize::try_from(id).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DapServer, LaunchRequest};
+    use crate::Breakpoints;
+
+    use serde_json::{Value as Json, json};
+
+    fn frame(message: Json) -> String {
+        let body = message.to_string();
+        format!("Content-Length: {}\r\n\r\n{body}", body.len())
+    }
+
+    /// Verifies the handshake answers configuration requests and returns the launch arguments.
+    #[test]
+    fn handshake_collects_breakpoints_and_launch_arguments() {
+        let input = [
+            frame(json!({ "seq": 1, "type": "request", "command": "initialize", "arguments": {} })),
+            frame(json!({
+                "seq": 2,
+                "type": "request",
+                "command": "setBreakpoints",
+                "arguments": { "source": { "path": "src/main.leo" }, "breakpoints": [{ "line": 4 }, { "line": 9 }] },
+            })),
+            frame(json!({
+                "seq": 3,
+                "type": "request",
+                "command": "launch",
+                "arguments": { "function": "bump", "inputs": ["5u64"], "stopOnEntry": true },
+            })),
+            frame(json!({ "seq": 4, "type": "request", "command": "configurationDone" })),
+        ]
+        .concat();
+
+        let mut output = Vec::new();
+        let mut breakpoints = Breakpoints::default();
+        let launch = DapServer::new(input.as_bytes(), &mut output).handshake(&mut breakpoints).unwrap();
+
+        assert_eq!(launch, LaunchRequest {
+            function: Some("bump".into()),
+            inputs: Some(vec!["5u64".into()]),
+            stop_on_entry: true,
+        });
+        assert_eq!(breakpoints.iter().count(), 2);
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("Content-Length:").count(), 5);
+        assert!(output.contains(r#""event":"initialized""#));
+        assert!(output.contains(r#""verified":true"#));
+    }
+}
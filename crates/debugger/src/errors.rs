@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt::Display;
+
+use leo_errors::{Backtraced, Formatted};
+use leo_span::Span;
+
+const CODE_PREFIX: &str = "DBG";
+const CODE_MASK: i32 = 14000;
+
+pub(crate) fn execution_halted(reason: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK, format!("execution halted: {reason}"), span)
+        .with_help("The same operation would halt the program when it runs on the network.")
+}
+
+pub(crate) fn assertion_failed(reason: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 1, format!("assertion failed: {reason}"), span)
+}
+
+pub(crate) fn unsupported_operation(operation: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 2, format!("the debugger cannot evaluate {operation}"), span).with_help(
+        "Use `leo run` or `leo execute` to run code that depends on the live network or on Aleo bytecode dependencies.",
+    )
+}
+
+pub(crate) fn unknown_function(name: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 3, format!("`{name}` is not a function with Leo source in this package"))
+        .with_help("Pass the function as `name` or `program.aleo::name`.")
+}
+
+pub(crate) fn invalid_input(reason: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 4, format!("invalid debugger input: {reason}"))
+}
+
+pub(crate) fn not_a_program() -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 5, "only programs can be debugged")
+        .with_help("Libraries have no entry points; debug a program that calls into the library instead.")
+}
+
+pub(crate) fn protocol_error(reason: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 6, format!("debug adapter protocol error: {reason}"))
+}
+
+pub(crate) fn io_error(error: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 7, format!("debugger I/O failed: {error}"))
+}
@@ -0,0 +1,1552 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Statement-level evaluation of the typed AST with pause points.
+//!
+//! The interpreter keeps one [`Frame`] per active call and checks the
+//! breakpoints and the pending step request before every statement. Errors
+//! leave the failing frames on the stack so the controller can inspect them
+//! in the final [`StopReason::Halted`] stop.
+
+use crate::{Breakpoints, ChainState, Coverage, DebugProgram, Environment, SourcePosition, errors, render_binding};
+
+use leo_ast::{
+    AssertStatement,
+    AssertVariant,
+    BinaryOperation,
+    Block,
+    CompositeExpression,
+    DefinitionPlace,
+    Expression,
+    IntegerType,
+    Intrinsic,
+    IntrinsicExpression,
+    IterationStatement,
+    Literal,
+    LiteralVariant,
+    Location,
+    MatchPattern,
+    Node,
+    NodeID,
+    Output,
+    Statement,
+    Type,
+    WhileStatement,
+    const_eval::{self, AsyncExecution, Value},
+};
+use leo_errors::{Backtraced, Formatted, LeoError, Result};
+use leo_passes::{method_call_to_call, payload_field, tag_field};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+use std::fmt;
+
+/// Why execution paused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StopReason {
+    /// The first statement of a run started with `stop_on_entry`.
+    Entry,
+    /// A statement on a line with a breakpoint.
+    Breakpoint,
+    /// The statement a step request ended on.
+    Step,
+    /// Execution failed with the given message; resuming ends the phase.
+    Halted(String),
+}
+
+/// How a [`Controller`] resumes a paused execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resume {
+    /// Run until the next breakpoint.
+    Continue,
+    /// Run until the next statement in the current function or one of its callers.
+    StepOver,
+    /// Run until the next statement, entering calls.
+    StepIn,
+    /// Run until the current function returns to its caller.
+    StepOut,
+    /// Abandon the run.
+    Terminate,
+}
+
+/// The half of a transaction a frame executes in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    /// Off-chain execution of entry points and helpers.
+    Transition,
+    /// On-chain execution of finalize code against mappings and storage.
+    Finalize,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Transition => write!(f, "transition"),
+            Phase::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+/// One active function call.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    /// The function being executed.
+    pub function: Location,
+    /// Whether the function runs off-chain or on-chain.
+    pub phase: Phase,
+    /// The program whose address `self.address` reports in this frame.
+    program: Symbol,
+    /// The value `self.caller` reports in this frame.
+    caller: Value,
+    /// The statement about to execute, or the function itself before its first statement.
+    span: Span,
+    /// Local bindings, innermost block last.
+    scopes: Vec<IndexMap<Symbol, Value>>,
+}
+
+impl Frame {
+    /// The source position of the statement about to execute.
+    pub fn position(&self) -> Option<SourcePosition> {
+        SourcePosition::of_span(self.span)
+    }
+
+    /// The source line of the statement about to execute.
+    pub fn line_text(&self) -> Option<String> {
+        if self.span.is_dummy() { None } else { SourcePosition::line_text(self.span) }
+    }
+
+    /// Every visible local binding in declaration order, with inner scopes shadowing outer ones.
+    pub fn locals(&self) -> IndexMap<Symbol, &Value> {
+        let mut locals = IndexMap::new();
+        for (name, value) in self.scopes.iter().flatten() {
+            locals.shift_remove(name);
+            locals.insert(*name, value);
+        }
+        locals
+    }
+
+    /// The visible binding of `name`, if any.
+    pub fn local(&self, name: Symbol) -> Option<&Value> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(&name))
+    }
+}
+
+/// A paused execution, as presented to a [`Controller`].
+pub struct Stop<'a> {
+    /// Why execution paused.
+    pub reason: StopReason,
+    /// The call stack, outermost first.
+    pub frames: &'a [Frame],
+    /// Mapping and storage contents at the pause point.
+    pub state: &'a ChainState,
+}
+
+impl Stop<'_> {
+    /// The frame of the statement about to execute.
+    pub fn innermost(&self) -> Option<&Frame> {
+        self.frames.last()
+    }
+}
+
+/// Decides how execution continues each time it pauses.
+pub trait Controller {
+    /// Called whenever execution pauses.
+    ///
+    /// The controller may edit `breakpoints` before returning how to resume.
+    fn stopped(&mut self, stop: &Stop<'_>, breakpoints: &mut Breakpoints) -> Result<Resume>;
+}
+
+/// A controller that never pauses, for runs nobody is watching, such as coverage runs.
+pub struct Unattended;
+
+impl Controller for Unattended {
+    fn stopped(&mut self, _stop: &Stop<'_>, _breakpoints: &mut Breakpoints) -> Result<Resume> {
+        Ok(Resume::Continue)
+    }
+}
+
+/// The outcome of the finalize phase of a run.
+#[derive(Debug)]
+pub enum FinalizeOutcome {
+    /// The entry point returned no futures.
+    Skipped,
+    /// Every future ran to completion and its state changes were kept.
+    Accepted,
+    /// A future halted; its state changes were rolled back.
+    Rejected(LeoError),
+}
+
+/// The result of a run that was not terminated by the controller.
+#[derive(Debug)]
+pub struct Execution {
+    /// The value the entry point returned.
+    pub output: Value,
+    /// What happened to the futures in `output`.
+    pub finalize: FinalizeOutcome,
+}
+
+/// Runs functions of a [`DebugProgram`] under the control of a [`Controller`].
+pub struct Debugger {
+    program: DebugProgram,
+    /// Mapping and storage contents, carried across runs.
+    pub state: ChainState,
+    /// Block and transaction context.
+    pub environment: Environment,
+    /// Line breakpoints checked before every statement.
+    pub breakpoints: Breakpoints,
+    /// When set, counts the tracked statements, branches, and calls each run executes.
+    pub coverage: Option<Coverage>,
+}
+
+impl Debugger {
+    /// Creates a debugger with empty chain state and the default environment.
+    pub fn new(program: DebugProgram) -> Self {
+        Self {
+            state: ChainState::for_program(&program),
+            program,
+            environment: Environment::default(),
+            breakpoints: Breakpoints::default(),
+            coverage: None,
+        }
+    }
+
+    /// The program being debugged.
+    pub fn program(&self) -> &DebugProgram {
+        &self.program
+    }
+
+    /// Parses command-line style `inputs` against the parameters of `function`.
+    pub fn parse_inputs(&self, function: &Location, inputs: &[String]) -> Result<Vec<Value>> {
+        let Some(declaration) = self.program.function(function) else {
+            return Err(errors::unknown_function(function).into());
+        };
+        if inputs.len() != declaration.input.len() {
+            return Err(errors::invalid_input(format!(
+                "`{function}` takes {} inputs but {} were given",
+                declaration.input.len(),
+                inputs.len()
+            ))
+            .into());
+        }
+        declaration
+            .input
+            .iter()
+            .zip(inputs)
+            .map(|(input, text)| {
+                let value: Value = text.trim().parse().map_err(|()| {
+                    errors::invalid_input(format!("`{text}` is not a valid value for `{}`", input.identifier))
+                })?;
+                let mut value =
+                    value.resolve_if_unsuffixed(&Some(input.type_.clone())).map_err(errors::invalid_input)?;
+                if let Type::Composite(composite) = &input.type_ {
+                    value.id = composite.path.try_global_location().cloned();
+                }
+                Ok(value)
+            })
+            .collect()
+    }
+
+    /// Runs `function` with `inputs`, then runs the futures it returns as the finalize phase.
+    ///
+    /// Pauses at the first statement when `stop_on_entry` is set. Returns `None` when the
+    /// controller terminates the run. A failing transition is returned as an error; a failing
+    /// finalize is reported as [`FinalizeOutcome::Rejected`] and leaves the state untouched.
+    pub fn run(
+        &mut self,
+        function: &Location,
+        inputs: Vec<Value>,
+        controller: &mut dyn Controller,
+        stop_on_entry: bool,
+    ) -> Result<Option<Execution>> {
+        let random_state = self.environment.random_seed;
+        let interpreter = Interpreter {
+            program: &self.program,
+            state: &mut self.state,
+            environment: &mut self.environment,
+            breakpoints: &mut self.breakpoints,
+            coverage: self.coverage.as_mut(),
+            controller,
+            frames: Vec::new(),
+            mode: if stop_on_entry { StepMode::Entry } else { StepMode::Continue },
+            async_blocks: IndexMap::new(),
+            consts: IndexMap::new(),
+            addresses: IndexMap::new(),
+            random_state,
+            expect_revert: false,
+            snapshots: Vec::new(),
+        };
+        interpreter.run(function, inputs)
+    }
+}
+
+/// Why evaluation unwound.
+enum Interrupt {
+    Error(LeoError),
+    Terminated,
+}
+
+impl From<LeoError> for Interrupt {
+    fn from(error: LeoError) -> Self {
+        Interrupt::Error(error)
+    }
+}
+
+impl From<Formatted> for Interrupt {
+    fn from(error: Formatted) -> Self {
+        Interrupt::Error(error.into())
+    }
+}
+
+impl From<Backtraced> for Interrupt {
+    fn from(error: Backtraced) -> Self {
+        Interrupt::Error(error.into())
+    }
+}
+
+type Eval<T> = std::result::Result<T, Interrupt>;
+
+/// Where the next pause happens when no breakpoint is hit first.
+#[derive(Clone, Copy)]
+enum StepMode {
+    Continue,
+    Entry,
+    In,
+    /// Pause once the stack is at most this deep.
+    Over(usize),
+    /// Pause once the stack is shallower than this.
+    Out(usize),
+}
+
+/// Whether a statement completed normally, exited its loop, or returned from its function.
+enum Flow {
+    Next,
+    Break,
+    Continue,
+    Return(Value),
+}
+
+struct Interpreter<'a> {
+    program: &'a DebugProgram,
+    state: &'a mut ChainState,
+    environment: &'a mut Environment,
+    breakpoints: &'a mut Breakpoints,
+    coverage: Option<&'a mut Coverage>,
+    controller: &'a mut dyn Controller,
+    frames: Vec<Frame>,
+    mode: StepMode,
+    /// Bodies of `final { .. }` blocks evaluated so far, for futures built from stubs.
+    async_blocks: IndexMap<NodeID, Block>,
+    /// Global constants evaluated so far.
+    consts: IndexMap<Location, Value>,
+    /// Program addresses derived so far.
+    addresses: IndexMap<Symbol, Value>,
+    random_state: u64,
+    /// Set by `std::test::expect_revert` until the next cross-program entry call or `Final.run()`.
+    expect_revert: bool,
+    /// States recorded by `std::test::snapshot`, indexed by the identifier it returned.
+    snapshots: Vec<(ChainState, Environment)>,
+}
+
+impl Interpreter<'_> {
+    fn run(mut self, function: &Location, inputs: Vec<Value>) -> Result<Option<Execution>> {
+        let output = match self.call(function, Vec::new(), inputs, Span::dummy()) {
+            Ok(output) => output,
+            Err(interrupt) => {
+                return match self.report(interrupt)? {
+                    Some(error) => Err(error),
+                    None => Ok(None),
+                };
+            }
+        };
+
+        let futures = futures_of(&output);
+        if futures.is_empty() {
+            return Ok(Some(Execution { output, finalize: FinalizeOutcome::Skipped }));
+        }
+
+        let snapshot = self.state.clone();
+        self.frames.clear();
+        let finalize = match self.run_futures(&futures) {
+            Ok(()) => FinalizeOutcome::Accepted,
+            Err(interrupt) => match self.report(interrupt)? {
+                Some(error) => {
+                    *self.state = snapshot;
+                    FinalizeOutcome::Rejected(error)
+                }
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(Execution { output, finalize }))
+    }
+
+    /// Shows a failure to the controller with the failing frames still on the stack.
+    ///
+    /// Returns the error to surface, or `None` if the run was terminated.
+    fn report(&mut self, interrupt: Interrupt) -> Result<Option<LeoError>> {
+        let Interrupt::Error(error) = interrupt else {
+            return Ok(None);
+        };
+        match self.stop(StopReason::Halted(summary(&error))) {
+            Err(Interrupt::Error(controller_error)) => Err(controller_error),
+            Err(Interrupt::Terminated) | Ok(()) => Ok(Some(error)),
+        }
+    }
+
+    fn frame(&self) -> &Frame {
+        self.frames.last().expect("code only executes inside a frame")
+    }
+
+    fn frame_mut(&mut self) -> &mut Frame {
+        self.frames.last_mut().expect("code only executes inside a frame")
+    }
+
+    fn bind(&mut self, name: Symbol, value: Value) {
+        self.frame_mut().scopes.last_mut().expect("frames always have a scope").insert(name, value);
+    }
+
+    /// Pauses if a breakpoint or the current step request applies to the statement at `span`.
+    fn before_statement(&mut self, span: Span) -> Eval<()> {
+        self.frame_mut().span = span;
+        let depth = self.frames.len();
+        let reason = match self.mode {
+            StepMode::Entry => Some(StopReason::Entry),
+            StepMode::In => Some(StopReason::Step),
+            StepMode::Over(target) if depth <= target => Some(StopReason::Step),
+            StepMode::Out(target) if depth < target => Some(StopReason::Step),
+            _ if self.breakpoints.is_empty() => None,
+            _ => SourcePosition::of_span(span)
+                .filter(|position| self.breakpoints.contains(position))
+                .map(|_| StopReason::Breakpoint),
+        };
+        match reason {
+            Some(reason) => self.stop(reason),
+            None => Ok(()),
+        }
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Eval<()> {
+        let depth = self.frames.len();
+        let stop = Stop { reason, frames: &self.frames, state: &*self.state };
+        self.mode = match self.controller.stopped(&stop, &mut *self.breakpoints)? {
+            Resume::Continue => StepMode::Continue,
+            Resume::StepOver => StepMode::Over(depth),
+            Resume::StepIn => StepMode::In,
+            Resume::StepOut => StepMode::Out(depth),
+            Resume::Terminate => return Err(Interrupt::Terminated),
+        };
+        Ok(())
+    }
+
+    /// Calls the function at `location`, or defers it into a future when a transition calls finalize code.
+    fn call(
+        &mut self,
+        location: &Location,
+        const_arguments: Vec<Value>,
+        arguments: Vec<Value>,
+        span: Span,
+    ) -> Eval<Value> {
+        let program = self.program;
+        let Some(function) = program.function(location) else {
+            return Err(errors::unsupported_operation(
+                format!("a call to `{location}`, which has no Leo source"),
+                span,
+            )
+            .into());
+        };
+        if function.variant.is_finalize_context() && self.frames.last().is_some_and(|f| f.phase == Phase::Transition) {
+            return Ok(Value::from(vec![AsyncExecution::AsyncFunctionCall { function: location.clone(), arguments }]));
+        }
+        if arguments.len() != function.input.len() {
+            return Err(errors::execution_halted(
+                format!("`{location}` takes {} arguments but {} were given", function.input.len(), arguments.len()),
+                span,
+            )
+            .into());
+        }
+
+        let phase = match self.frames.last() {
+            _ if function.variant.is_onchain() => Phase::Finalize,
+            Some(frame) => frame.phase,
+            None => Phase::Transition,
+        };
+        let calling_program = self.frames.last().map(|frame| frame.program);
+        let program_name =
+            if is_program_unit(location.program) { location.program } else { calling_program.unwrap_or(program.name) };
+        let is_external_entry =
+            calling_program.is_some_and(|calling| function.variant.is_entry() && calling != program_name);
+        if is_external_entry && self.expect_revert {
+            self.expect_revert = false;
+            let empty = |output: &Output| match output.type_ {
+                Type::Future(_) => Value::from(Vec::<AsyncExecution>::new()),
+                _ => Value::make_unit(),
+            };
+            let empty = match function.output.as_slice() {
+                [] => Value::make_unit(),
+                [output] => empty(output),
+                outputs => Value::make_tuple(outputs.iter().map(empty)),
+            };
+            return self.expect_failure(empty, span, |this| this.call(location, const_arguments, arguments, span));
+        }
+        let caller = match calling_program {
+            None => self.environment.signer.clone(),
+            Some(calling) if is_external_entry => match &self.environment.caller {
+                Some(caller) if calling == program.name => caller.clone(),
+                _ => self.address_of(calling, span)?,
+            },
+            Some(_) => self.frame().caller.clone(),
+        };
+
+        if let Some(coverage) = self.coverage.as_deref_mut() {
+            coverage.record_call(function.span);
+        }
+
+        let mut scope = IndexMap::new();
+        for (parameter, value) in function.const_parameters.iter().zip(const_arguments) {
+            scope.insert(parameter.identifier.name, value);
+        }
+        for (input, value) in function.input.iter().zip(arguments) {
+            scope.insert(input.identifier.name, value);
+        }
+        self.frames.push(Frame {
+            function: location.clone(),
+            phase,
+            program: program_name,
+            caller,
+            span: function.span,
+            scopes: vec![scope],
+        });
+        let flow = self.exec_block(&function.block)?;
+        self.frames.pop();
+        Ok(match flow {
+            Flow::Return(value) => value,
+            // Type checking rejects `break` and `continue` outside of a loop.
+            Flow::Next | Flow::Break | Flow::Continue => Value::make_unit(),
+        })
+    }
+
+    /// Runs `body`, which the test expects to fail. A failure is caught, its state changes are rolled
+    /// back, and `empty` stands in for its result; success halts the run instead.
+    fn expect_failure(&mut self, empty: Value, span: Span, body: impl FnOnce(&mut Self) -> Eval<Value>) -> Eval<Value> {
+        let state = self.state.clone();
+        let depth = self.frames.len();
+        match body(self) {
+            Ok(_) => Err(halted("expected the call to revert, but it succeeded", span)),
+            Err(Interrupt::Error(_)) => {
+                *self.state = state;
+                self.frames.truncate(depth);
+                Ok(empty)
+            }
+            Err(Interrupt::Terminated) => Err(Interrupt::Terminated),
+        }
+    }
+
+    /// Runs deferred finalize code in order.
+    fn run_futures(&mut self, executions: &[AsyncExecution]) -> Eval<()> {
+        for execution in executions {
+            match execution {
+                AsyncExecution::AsyncFunctionCall { function, arguments } => {
+                    self.call(function, Vec::new(), arguments.clone(), Span::dummy())?;
+                }
+                AsyncExecution::AsyncBlock { containing_function, block, names } => {
+                    let Some(body) = self.async_blocks.get(block).cloned() else {
+                        return Err(errors::unsupported_operation(
+                            format!("a `final` block of `{containing_function}` created outside this run"),
+                            Span::dummy(),
+                        )
+                        .into());
+                    };
+                    let program = if is_program_unit(containing_function.program) {
+                        containing_function.program
+                    } else {
+                        self.frames.last().map_or(self.program.name, |frame| frame.program)
+                    };
+                    let caller =
+                        self.frames.last().map_or_else(|| self.environment.signer.clone(), |f| f.caller.clone());
+                    let scope = names
+                        .iter()
+                        .filter_map(|(path, value)| match path.as_slice() {
+                            [name] => Some((*name, value.clone())),
+                            _ => None,
+                        })
+                        .collect();
+                    self.frames.push(Frame {
+                        function: containing_function.clone(),
+                        phase: Phase::Finalize,
+                        program,
+                        caller,
+                        span: body.span,
+                        scopes: vec![scope],
+                    });
+                    self.exec_block(&body)?;
+                    self.frames.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn exec_block(&mut self, block: &Block) -> Eval<Flow> {
+        self.frame_mut().scopes.push(IndexMap::new());
+        for statement in &block.statements {
+            let flow = self.exec_statement(statement)?;
+            if !matches!(flow, Flow::Next) {
+                self.frame_mut().scopes.pop();
+                return Ok(flow);
+            }
+        }
+        self.frame_mut().scopes.pop();
+        Ok(Flow::Next)
+    }
+
+    fn exec_statement(&mut self, statement: &Statement) -> Eval<Flow> {
+        if !matches!(statement, Statement::Block(_)) {
+            self.before_statement(statement.span())?;
+            if let Some(coverage) = self.coverage.as_deref_mut() {
+                coverage.record_statement(statement.span());
+            }
+        }
+        match statement {
+            Statement::Assert(assert) => self.exec_assert(assert)?,
+            Statement::Assign(assign) => {
+                let value = self.eval(&assign.value)?;
+                self.assign(&assign.place, value)?;
+            }
+            Statement::Block(block) => return self.exec_block(block),
+            Statement::Break(_) => return Ok(Flow::Break),
+            Statement::Conditional(conditional) => {
+                let condition = self.eval_bool(&conditional.condition)?;
+                if let Some(coverage) = self.coverage.as_deref_mut() {
+                    coverage.record_branch(conditional.span, condition);
+                }
+                if condition {
+                    return self.exec_block(&conditional.then);
+                }
+                if let Some(otherwise) = &conditional.otherwise {
+                    return self.exec_statement(otherwise);
+                }
+            }
+            Statement::Continue(_) => return Ok(Flow::Continue),
+            Statement::Const(declaration) => {
+                let value = self.eval(&declaration.value)?;
+                self.bind(declaration.place.name, value);
+            }
+            Statement::Definition(definition) => {
+                let value = self.eval(&definition.value)?;
+                match &definition.place {
+                    DefinitionPlace::Single(identifier) => self.bind(identifier.name, value),
+                    DefinitionPlace::Multiple(identifiers) => {
+                        for (i, identifier) in identifiers.iter().enumerate() {
+                            let element = tuple_element(&value, i, definition.span)?;
+                            self.bind(identifier.name, element);
+                        }
+                    }
+                }
+            }
+            Statement::Expression(statement) => {
+                self.eval(&statement.expression)?;
+            }
+            Statement::Iteration(iteration) => return self.exec_iteration(iteration),
+            Statement::Match(statement) => {
+                let scrutinee = self.eval(&statement.scrutinee)?;
+                let patterns = statement.arms.iter().map(|arm| &arm.pattern);
+                let (index, bindings) = self.select_arm(&scrutinee, patterns, statement.span)?;
+                self.frame_mut().scopes.push(bindings);
+                let flow = self.exec_block(&statement.arms[index].block)?;
+                self.frame_mut().scopes.pop();
+                return Ok(flow);
+            }
+            Statement::Return(statement) => return Ok(Flow::Return(self.eval(&statement.expression)?)),
+            Statement::While(statement) => return self.exec_while(statement),
+        }
+        Ok(Flow::Next)
+    }
+
+    fn exec_assert(&mut self, assert: &AssertStatement) -> Eval<()> {
+        let span = assert.span;
+        match &assert.variant {
+            AssertVariant::Assert(condition) => {
+                if !self.eval_bool(condition)? {
+                    return Err(errors::assertion_failed("the condition is false", span).into());
+                }
+            }
+            AssertVariant::AssertEq(left, right) => {
+                let (left, right) = (self.eval(left)?, self.eval(right)?);
+                if !self.equal(&left, &right, span)? {
+                    let reason = format!("`{}` is not equal to `{}`", render_binding(&left), render_binding(&right));
+                    return Err(errors::assertion_failed(reason, span).into());
+                }
+            }
+            AssertVariant::AssertNeq(left, right) => {
+                let (left, right) = (self.eval(left)?, self.eval(right)?);
+                if self.equal(&left, &right, span)? {
+                    let reason = format!("both sides are `{}`", render_binding(&left));
+                    return Err(errors::assertion_failed(reason, span).into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn exec_iteration(&mut self, iteration: &IterationStatement) -> Eval<Flow> {
+        let span = iteration.span;
+        let type_ = iteration.type_.clone().or_else(|| self.program.types.get(&iteration.variable.id));
+        let start = self.eval(&iteration.start)?.resolve_if_unsuffixed(&type_).map_err(|e| halted(e, span))?;
+        let stop = self.eval(&iteration.stop)?.resolve_if_unsuffixed(&type_).map_err(|e| halted(e, span))?;
+
+        let mut index = start;
+        loop {
+            let in_range = if iteration.inclusive { index.lte(&stop) } else { index.lt(&stop) };
+            match in_range {
+                Some(true) => {}
+                Some(false) => break,
+                None => return Err(halted("the loop bounds are not integers of the same type", span)),
+            }
+
+            self.frame_mut().scopes.push(IndexMap::from([(iteration.variable.name, index.clone())]));
+            let flow = self.exec_block(&iteration.block)?;
+            self.frame_mut().scopes.pop();
+            match flow {
+                Flow::Next | Flow::Continue => {}
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+            }
+
+            if iteration.inclusive && index.eq(&stop) == Ok(true) {
+                break;
+            }
+            index = index.inc_wrapping().ok_or_else(|| halted("the loop counter is not an integer", span))?;
+        }
+        Ok(Flow::Next)
+    }
+
+    fn exec_while(&mut self, statement: &WhileStatement) -> Eval<Flow> {
+        let span = statement.span;
+        let bound = self.eval(&statement.bound)?;
+        let bound = bound
+            .resolve_if_unsuffixed(&Some(Type::Integer(IntegerType::U32)))
+            .ok()
+            .and_then(|bound| bound.as_u32())
+            .ok_or_else(|| halted(format!("`{}` is not a valid loop bound", render_binding(&bound)), span))?;
+
+        let mut iterations = 0u32;
+        while self.eval_bool(&statement.condition)? {
+            // The compiled program asserts that the loop has exited once its bound is exhausted.
+            if iterations == bound {
+                return Err(halted(format!("the loop did not exit within its bound of {bound} iterations"), span));
+            }
+            iterations += 1;
+
+            match self.exec_block(&statement.block)? {
+                Flow::Next | Flow::Continue => {}
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Stores `value` into the place expression `place`.
+    fn assign(&mut self, place: &Expression, value: Value) -> Eval<()> {
+        match place {
+            Expression::Path(path) => {
+                if let Some(name) = path.try_local_symbol() {
+                    let slot = self.frame_mut().scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&name));
+                    match slot {
+                        Some(slot) => *slot = value,
+                        None => self.bind(name, value),
+                    }
+                    return Ok(());
+                }
+                match path.try_global_location() {
+                    Some(location) if self.program.storage.contains_key(location) => {
+                        if value == Value::make_unit() {
+                            self.state.storage.shift_remove(location);
+                        } else {
+                            self.state.storage.insert(location.clone(), value);
+                        }
+                        Ok(())
+                    }
+                    _ => Err(errors::unsupported_operation(format!("an assignment to `{path}`"), path.span()).into()),
+                }
+            }
+            Expression::MemberAccess(access) => {
+                let mut inner = self.eval(&access.inner)?;
+                inner
+                    .member_set(access.name.name, value)
+                    .ok_or_else(|| halted(format!("cannot assign to the member `{}`", access.name), access.span))?;
+                self.assign(&access.inner, inner)
+            }
+            Expression::ArrayAccess(access) => {
+                let mut array = self.eval(&access.array)?;
+                let index = self.eval_index(&access.index)?;
+                let len = array.array_len().ok_or_else(|| halted("the indexed value is not an array", access.span))?;
+                if index >= len || array.array_index_set(index, value).is_none() {
+                    return Err(halted(
+                        format!("cannot assign to index {index} of an array of length {len}"),
+                        access.span,
+                    ));
+                }
+                self.assign(&access.array, array)
+            }
+            Expression::TupleAccess(access) => {
+                let mut tuple = self.eval(&access.tuple)?;
+                let index = access.index.value();
+                tuple
+                    .tuple_index_set(index, value)
+                    .ok_or_else(|| halted(format!("cannot assign to element {index} of the tuple"), access.span))?;
+                self.assign(&access.tuple, tuple)
+            }
+            Expression::Tuple(tuple) => {
+                for (i, element) in tuple.elements.iter().enumerate() {
+                    let value = tuple_element(&value, i, tuple.span)?;
+                    self.assign(element, value)?;
+                }
+                Ok(())
+            }
+            _ => Err(errors::unsupported_operation("an assignment to this expression", place.span()).into()),
+        }
+    }
+
+    fn eval_bool(&mut self, expression: &Expression) -> Eval<bool> {
+        let value = self.eval(expression)?;
+        if value == Value::from(true) {
+            Ok(true)
+        } else if value == Value::from(false) {
+            Ok(false)
+        } else {
+            Err(halted(format!("expected a boolean but found `{}`", render_binding(&value)), expression.span()))
+        }
+    }
+
+    fn eval_index(&mut self, expression: &Expression) -> Eval<usize> {
+        let value = self.eval(expression)?;
+        index_value(&value, expression.span())
+    }
+
+    fn eval_all(&mut self, expressions: &[Expression]) -> Eval<Vec<Value>> {
+        expressions.iter().map(|expression| self.eval(expression)).collect()
+    }
+
+    /// The type the frontend inferred for `id`, with optionals unwrapped since `none` is tracked separately.
+    fn type_of(&self, id: NodeID) -> Option<Type> {
+        match self.program.types.get(&id)? {
+            Type::Optional(optional) => Some(*optional.inner),
+            type_ => Some(type_),
+        }
+    }
+
+    /// Compares two values, treating the unit value as an optional `none`.
+    fn equal(&self, left: &Value, right: &Value, span: Span) -> Eval<bool> {
+        let unit = Value::make_unit();
+        if *left == unit || *right == unit {
+            return Ok(left == right);
+        }
+        let left = left.resolve_if_unsuffixed(&right.get_numeric_type()).map_err(|e| halted(e, span))?;
+        let right = right.resolve_if_unsuffixed(&left.get_numeric_type()).map_err(|e| halted(e, span))?;
+        left.eq(&right).map_err(|e| halted(e, span))
+    }
+
+    fn eval(&mut self, expression: &Expression) -> Eval<Value> {
+        match expression {
+            Expression::ArrayAccess(access) => {
+                let array = self.eval(&access.array)?;
+                let index = self.eval_index(&access.index)?;
+                let len = array.array_len().ok_or_else(|| halted("the indexed value is not an array", access.span))?;
+                if index >= len {
+                    return Err(halted(
+                        format!("index {index} is out of bounds for an array of length {len}"),
+                        access.span,
+                    ));
+                }
+                Ok(array.array_index(index).expect("the index is in bounds"))
+            }
+            Expression::Async(async_) => {
+                self.async_blocks.insert(async_.block.id, async_.block.clone());
+                let frame = self.frame();
+                let names = frame.locals().into_iter().map(|(name, value)| (vec![name], value.clone())).collect();
+                let execution = AsyncExecution::AsyncBlock {
+                    containing_function: frame.function.clone(),
+                    block: async_.block.id,
+                    names,
+                };
+                Ok(Value::from(vec![execution]))
+            }
+            Expression::Array(array) => {
+                let elements = self.eval_all(&array.elements)?;
+                if elements.contains(&Value::make_unit()) {
+                    return Err(errors::unsupported_operation("an array holding `none`", array.span).into());
+                }
+                Ok(Value::make_array(elements.into_iter()))
+            }
+            Expression::Binary(binary) => {
+                let left = self.eval(&binary.left)?;
+                let right = self.eval(&binary.right)?;
+                match binary.op {
+                    BinaryOperation::Eq => Ok(Value::from(self.equal(&left, &right, binary.span)?)),
+                    BinaryOperation::Neq => Ok(Value::from(!self.equal(&left, &right, binary.span)?)),
+                    op => const_eval::evaluate_binary(binary.span, op, &left, &right, &self.type_of(binary.id))
+                        .map_err(runtime_error),
+                }
+            }
+            Expression::Intrinsic(intrinsic) => self.eval_intrinsic(intrinsic),
+            Expression::Call(call) => {
+                let Some(location) = call.function.try_global_location() else {
+                    return Err(
+                        errors::unsupported_operation(format!("a call to `{}`", call.function), call.span).into()
+                    );
+                };
+                let const_arguments = self.eval_all(&call.const_arguments)?;
+                let arguments = self.eval_all(&call.arguments)?;
+                match self.program.variant(location) {
+                    Some(variant) => self.make_variant(variant, arguments, call.span),
+                    None => self.call(location, const_arguments, arguments, call.span),
+                }
+            }
+            Expression::DynamicOp(operation) => {
+                Err(errors::unsupported_operation("a dynamic call", operation.span).into())
+            }
+            Expression::Cast(cast) => {
+                let value = self.eval(&cast.expression)?;
+                let value = value
+                    .resolve_if_unsuffixed(&self.type_of(cast.expression.id()))
+                    .map_err(|e| halted(e, cast.span))?;
+                value.cast(&cast.type_).ok_or_else(|| {
+                    halted(format!("cannot cast `{}` to `{}`", render_binding(&value), cast.type_), cast.span)
+                })
+            }
+            Expression::Composite(composite) => self.eval_composite(composite),
+            Expression::Err(error) => {
+                Err(errors::unsupported_operation("an expression that failed to parse", error.span).into())
+            }
+            Expression::Path(path) => {
+                if let Some(name) = path.try_local_symbol() {
+                    return self
+                        .frames
+                        .last()
+                        .and_then(|frame| frame.local(name))
+                        .cloned()
+                        .ok_or_else(|| halted(format!("`{name}` is not bound"), path.span()));
+                }
+                match path.try_global_location() {
+                    Some(location) => self.global(location, path.span()),
+                    None => Err(errors::unsupported_operation(format!("the path `{path}`"), path.span()).into()),
+                }
+            }
+            Expression::Literal(literal) => self.eval_literal(literal),
+            Expression::Match(match_) => {
+                let scrutinee = self.eval(&match_.scrutinee)?;
+                let patterns = match_.arms.iter().map(|arm| &arm.pattern);
+                let (index, bindings) = self.select_arm(&scrutinee, patterns, match_.span)?;
+                self.frame_mut().scopes.push(bindings);
+                let value = self.eval(&match_.arms[index].expression)?;
+                self.frame_mut().scopes.pop();
+                Ok(value)
+            }
+            Expression::MethodCall(call) => {
+                let receiver_type = self.type_of(call.receiver.id());
+                match receiver_type.and_then(|type_| method_call_to_call((**call).clone(), &type_)) {
+                    Some(call) => self.eval(&call.into()),
+                    None => Err(errors::unsupported_operation(format!("the method `{}`", call.name), call.span).into()),
+                }
+            }
+            Expression::MemberAccess(access) => {
+                let inner = self.eval(&access.inner)?;
+                inner
+                    .member_access(access.name.name)
+                    .ok_or_else(|| halted(format!("the value has no member `{}`", access.name), access.span))
+            }
+            Expression::Repeat(repeat) => {
+                let element = self.eval(&repeat.expr)?;
+                let count = self.eval_index(&repeat.count)?;
+                if element == Value::make_unit() {
+                    return Err(errors::unsupported_operation("an array holding `none`", repeat.span).into());
+                }
+                Ok(Value::make_array(std::iter::repeat_n(element, count)))
+            }
+            Expression::Ternary(ternary) => {
+                let condition = self.eval_bool(&ternary.condition)?;
+                if let Some(coverage) = self.coverage.as_deref_mut() {
+                    coverage.record_branch(ternary.span, condition);
+                }
+                if condition { self.eval(&ternary.if_true) } else { self.eval(&ternary.if_false) }
+            }
+            Expression::Tuple(tuple) => Ok(Value::make_tuple(self.eval_all(&tuple.elements)?)),
+            Expression::TupleAccess(access) => {
+                let tuple = self.eval(&access.tuple)?;
+                tuple_element(&tuple, access.index.value(), access.span)
+            }
+            Expression::Unary(unary) => {
+                let value = self.eval(&unary.receiver)?;
+                const_eval::evaluate_unary(unary.span, unary.op, &value, &self.type_of(unary.id)).map_err(runtime_error)
+            }
+            Expression::Unit(_) => Ok(Value::make_unit()),
+        }
+    }
+
+    fn eval_literal(&mut self, literal: &Literal) -> Eval<Value> {
+        if let LiteralVariant::None = literal.variant {
+            return Ok(Value::make_unit());
+        }
+        // Only numeric types guide literal parsing; other expected types would reject valid literals.
+        let type_ = self
+            .type_of(literal.id)
+            .filter(|type_| matches!(type_, Type::Integer(_) | Type::Field | Type::Group | Type::Scalar));
+        const_eval::literal_to_value(literal, &type_).map_err(|e| halted(e, literal.span))
+    }
+
+    /// Reads a global constant or storage variable.
+    fn global(&mut self, location: &Location, span: Span) -> Eval<Value> {
+        if let Some(value) = self.consts.get(location) {
+            return Ok(value.clone());
+        }
+        let program = self.program;
+        if let Some(declaration) = program.consts.get(location) {
+            let value = self.eval(&declaration.value)?;
+            let value = value.resolve_if_unsuffixed(&Some(declaration.type_.clone())).map_err(|e| halted(e, span))?;
+            self.consts.insert(location.clone(), value.clone());
+            return Ok(value);
+        }
+        if program.storage.contains_key(location) {
+            return Ok(self.state.storage.get(location).cloned().unwrap_or_else(Value::make_unit));
+        }
+        if let Some(variant) = program.variant(location) {
+            return self.make_variant(variant, Vec::new(), span);
+        }
+        Err(errors::unsupported_operation(format!("`{location}` as a value"), span).into())
+    }
+
+    fn eval_composite(&mut self, composite: &CompositeExpression) -> Eval<Value> {
+        let span = composite.span;
+        let Some(location) = composite.path.try_global_location().cloned() else {
+            return Err(errors::unsupported_operation(format!("the composite `{}`", composite.path), span).into());
+        };
+
+        let mut provided = IndexMap::new();
+        for member in &composite.members {
+            let value = match &member.expression {
+                Some(expression) => self.eval(expression)?,
+                None => self
+                    .frame()
+                    .local(member.identifier.name)
+                    .cloned()
+                    .ok_or_else(|| halted(format!("`{}` is not bound", member.identifier), member.span))?,
+            };
+            provided.insert(member.identifier.name, value);
+        }
+        let base = composite.base.as_ref().map(|base| self.eval(base)).transpose()?;
+
+        let names: Vec<Symbol> = match self.program.composites.get(&location) {
+            Some(declaration) => declaration.members.iter().map(|member| member.identifier.name).collect(),
+            None => provided.keys().copied().collect(),
+        };
+        let mut members = Vec::with_capacity(names.len());
+        for name in names {
+            let value = match provided.shift_remove(&name) {
+                Some(value) => value,
+                None => base
+                    .as_ref()
+                    .and_then(|base| base.member_access(name))
+                    .ok_or_else(|| halted(format!("no value for the member `{name}`"), span))?,
+            };
+            if value == Value::make_unit() {
+                return Err(errors::unsupported_operation("a composite member holding `none`", span).into());
+            }
+            members.push((name, value));
+        }
+        Ok(Value::make_struct(members.into_iter(), location))
+    }
+
+    /// Builds the value of the variant at `index` of the enum at `enum_location`, laid out like the struct the
+    /// enum is lowered to, but with the payload fields of the other variants left out.
+    fn make_variant(&self, (enum_location, index): (Location, usize), payload: Vec<Value>, span: Span) -> Eval<Value> {
+        let types = self.program.composites[&enum_location].variants.as_ref().map(|variants| &variants[index].types);
+        let mut members = vec![(tag_field(), Value::from(index as u8))];
+        for (i, value) in payload.into_iter().enumerate() {
+            let type_ = types.and_then(|types| types.get(i)).cloned();
+            let value = value.resolve_if_unsuffixed(&type_).map_err(|e| halted(e, span))?;
+            if value == Value::make_unit() {
+                return Err(errors::unsupported_operation("a variant holding `none`", span).into());
+            }
+            members.push((payload_field(index, i), value));
+        }
+        Ok(Value::make_struct(members.into_iter(), enum_location))
+    }
+
+    /// Picks the first of `patterns` that `scrutinee` matches, and returns its index with the bindings it makes.
+    fn select_arm<'p>(
+        &self,
+        scrutinee: &Value,
+        patterns: impl Iterator<Item = &'p MatchPattern>,
+        span: Span,
+    ) -> Eval<(usize, IndexMap<Symbol, Value>)> {
+        let Some(tag) = scrutinee.member_access(tag_field()).and_then(|tag| tag.as_u32()) else {
+            return Err(halted(format!("`{}` is not an enum value", render_binding(scrutinee)), span));
+        };
+        for (i, pattern) in patterns.enumerate() {
+            let MatchPattern::Variant { path, bindings } = pattern else {
+                return Ok((i, IndexMap::new()));
+            };
+            let Some((_, index)) = path.try_global_location().and_then(|location| self.program.variant(location))
+            else {
+                return Err(errors::unsupported_operation(format!("the pattern `{pattern}`"), path.span()).into());
+            };
+            if index as u32 != tag {
+                continue;
+            }
+            let mut scope = IndexMap::new();
+            for (j, binding) in bindings.iter().enumerate() {
+                if let Some(binding) = binding {
+                    let value = scrutinee
+                        .member_access(payload_field(index, j))
+                        .ok_or_else(|| halted(format!("the variant has no payload at {j}"), span))?;
+                    scope.insert(binding.name, value);
+                }
+            }
+            return Ok((i, scope));
+        }
+        Err(halted(format!("no arm matches `{}`", render_binding(scrutinee)), span))
+    }
+
+    fn eval_intrinsic(&mut self, expression: &IntrinsicExpression) -> Eval<Value> {
+        let span = expression.span;
+        let Some(intrinsic) = Intrinsic::from_symbol(expression.name, &expression.type_parameters) else {
+            return Err(errors::unsupported_operation(format!("the intrinsic `{}`", expression.name), span).into());
+        };
+        match intrinsic {
+            Intrinsic::MappingGet
+            | Intrinsic::MappingGetOrUse
+            | Intrinsic::MappingSet
+            | Intrinsic::MappingRemove
+            | Intrinsic::MappingContains => self.eval_mapping(intrinsic, expression),
+            Intrinsic::VectorGet
+            | Intrinsic::VectorSet
+            | Intrinsic::VectorPush
+            | Intrinsic::VectorLen
+            | Intrinsic::VectorClear
+            | Intrinsic::VectorPop
+            | Intrinsic::VectorSwapRemove => self.eval_vector(intrinsic, expression),
+            Intrinsic::OptionalUnwrap => {
+                let value = self.eval_all(&expression.arguments)?.swap_remove(0);
+                if value == Value::make_unit() { Err(halted("unwrapped a `none` value", span)) } else { Ok(value) }
+            }
+            Intrinsic::OptionalUnwrapOr => {
+                let mut arguments = self.eval_all(&expression.arguments)?;
+                let default = arguments.pop().expect("`unwrap_or` takes a default");
+                let value = arguments.swap_remove(0);
+                Ok(if value == Value::make_unit() { default } else { value })
+            }
+            Intrinsic::FinalRun => {
+                let future = self.eval_all(&expression.arguments)?.swap_remove(0);
+                let executions = future.as_future().ok_or_else(|| halted("only a `Final` can be run", span))?.to_vec();
+                if std::mem::take(&mut self.expect_revert) {
+                    return self.expect_failure(Value::make_unit(), span, |this| {
+                        this.run_futures(&executions).map(|()| Value::make_unit())
+                    });
+                }
+                self.run_futures(&executions)?;
+                Ok(Value::make_unit())
+            }
+            Intrinsic::TestSetCaller => {
+                self.environment.caller = Some(self.eval_all(&expression.arguments)?.swap_remove(0));
+                Ok(Value::make_unit())
+            }
+            Intrinsic::TestSetSigner => {
+                self.environment.signer = self.eval_all(&expression.arguments)?.swap_remove(0);
+                Ok(Value::make_unit())
+            }
+            Intrinsic::TestWarp => {
+                let height = self.eval_all(&expression.arguments)?.swap_remove(0);
+                self.environment.block_height =
+                    height.as_u32().ok_or_else(|| halted("the height must be a `u32`", span))?;
+                Ok(Value::make_unit())
+            }
+            Intrinsic::TestExpectRevert => {
+                self.expect_revert = true;
+                Ok(Value::make_unit())
+            }
+            Intrinsic::TestSnapshot => {
+                let id = u32::try_from(self.snapshots.len()).map_err(|_| halted("too many snapshots", span))?;
+                self.snapshots.push((self.state.clone(), self.environment.clone()));
+                Ok(Value::from(id))
+            }
+            Intrinsic::TestRestore => {
+                let id = self.eval_all(&expression.arguments)?.swap_remove(0);
+                let Some((state, environment)) = id.as_u32().and_then(|id| self.snapshots.get(id as usize)) else {
+                    return Err(halted(format!("no snapshot has the identifier `{id}`"), span));
+                };
+                *self.state = state.clone();
+                *self.environment = environment.clone();
+                Ok(Value::make_unit())
+            }
+            Intrinsic::SelfAddress => {
+                let program = self.frame().program;
+                self.address_of(program, span)
+            }
+            Intrinsic::SelfCaller => Ok(self.frame().caller.clone()),
+            Intrinsic::SelfSigner | Intrinsic::SelfProgramOwner => Ok(self.environment.signer.clone()),
+            Intrinsic::SelfEdition => Ok(Value::from(self.environment.edition)),
+            Intrinsic::BlockHeight => Ok(Value::from(self.environment.block_height)),
+            Intrinsic::BlockTimestamp => Ok(Value::from(self.environment.block_timestamp)),
+            Intrinsic::NetworkId => Ok(Value::from(self.environment.network_id)),
+            Intrinsic::ChaChaRand(literal_type) => {
+                let bits = (u128::from(self.next_random()) << 64) | u128::from(self.next_random());
+                Value::from(bits).cast_lossy(&literal_type).ok_or_else(|| {
+                    errors::unsupported_operation(format!("random values of type `{literal_type}`"), span).into()
+                })
+            }
+            _ => {
+                let mut values = self.eval_all(&expression.arguments)?;
+                match const_eval::evaluate_intrinsic(&mut values, intrinsic, &expression.arguments, span) {
+                    Ok(Some(value)) => Ok(value),
+                    Ok(None) => {
+                        Err(errors::unsupported_operation(format!("the intrinsic `{}`", expression.name), span).into())
+                    }
+                    Err(error) => Err(runtime_error(error)),
+                }
+            }
+        }
+    }
+
+    fn eval_mapping(&mut self, intrinsic: Intrinsic, expression: &IntrinsicExpression) -> Eval<Value> {
+        let span = expression.span;
+        let location = match expression.arguments.first() {
+            Some(Expression::Path(path)) => {
+                path.try_global_location().filter(|l| self.program.mappings.contains_key(*l))
+            }
+            _ => None,
+        };
+        let Some(location) = location.cloned() else {
+            return Err(errors::unsupported_operation("a mapping that is not declared in Leo source", span).into());
+        };
+        let arguments = self.eval_all(&expression.arguments[1..])?;
+
+        let entries = self.state.mappings.entry(location.clone()).or_default();
+        match (intrinsic, arguments.as_slice()) {
+            (Intrinsic::MappingGet, [key]) => entries
+                .get(key)
+                .cloned()
+                .ok_or_else(|| halted(format!("the key `{key}` is not present in mapping `{location}`"), span)),
+            (Intrinsic::MappingGetOrUse, [key, default]) => {
+                Ok(entries.get(key).cloned().unwrap_or_else(|| default.clone()))
+            }
+            (Intrinsic::MappingSet, [key, value]) => {
+                entries.insert(key.clone(), value.clone());
+                Ok(Value::make_unit())
+            }
+            (Intrinsic::MappingRemove, [key]) => {
+                entries.shift_remove(key);
+                Ok(Value::make_unit())
+            }
+            (Intrinsic::MappingContains, [key]) => Ok(Value::from(entries.contains_key(key))),
+            _ => Err(halted(format!("`{}` was given {} arguments", expression.name, arguments.len()), span)),
+        }
+    }
+
+    fn eval_vector(&mut self, intrinsic: Intrinsic, expression: &IntrinsicExpression) -> Eval<Value> {
+        let span = expression.span;
+        let location = match expression.arguments.first() {
+            Some(Expression::Path(path)) => {
+                path.try_global_location().filter(|l| self.program.storage.contains_key(*l))
+            }
+            _ => None,
+        };
+        let Some(location) = location.cloned() else {
+            return Err(errors::unsupported_operation("a vector that is not declared in Leo source", span).into());
+        };
+        let arguments = self.eval_all(&expression.arguments[1..])?;
+
+        let elements = self.state.vectors.entry(location).or_default();
+        let len = elements.len();
+        let out_of_bounds =
+            |index: usize| halted(format!("index {index} is out of bounds for a vector of length {len}"), span);
+        match (intrinsic, arguments.as_slice()) {
+            (Intrinsic::VectorGet, [index]) => {
+                Ok(elements.get(index_value(index, span)?).cloned().unwrap_or_else(Value::make_unit))
+            }
+            (Intrinsic::VectorSet, [index, value]) => {
+                let index = index_value(index, span)?;
+                *elements.get_mut(index).ok_or_else(|| out_of_bounds(index))? = value.clone();
+                Ok(Value::make_unit())
+            }
+            (Intrinsic::VectorPush, [value]) => {
+                elements.push(value.clone());
+                Ok(Value::make_unit())
+            }
+            (Intrinsic::VectorLen, []) => {
+                Ok(Value::from(u32::try_from(len).map_err(|_| halted("the vector is too long", span))?))
+            }
+            (Intrinsic::VectorClear, []) => {
+                elements.clear();
+                Ok(Value::make_unit())
+            }
+            (Intrinsic::VectorPop, []) => Ok(elements.pop().unwrap_or_else(Value::make_unit)),
+            (Intrinsic::VectorSwapRemove, [index]) => {
+                let index = index_value(index, span)?;
+                if index >= len {
+                    return Err(out_of_bounds(index));
+                }
+                Ok(elements.swap_remove(index))
+            }
+            _ => Err(halted(format!("`{}` was given {} arguments", expression.name, arguments.len()), span)),
+        }
+    }
+
+    /// The address of `program`, derived from its program ID.
+    fn address_of(&mut self, program: Symbol, span: Span) -> Eval<Value> {
+        if let Some(address) = self.addresses.get(&program) {
+            return Ok(address.clone());
+        }
+        let literal = Literal::address(program.to_string(), Span::dummy(), NodeID::default());
+        let address = const_eval::literal_to_value(&literal, &None).map_err(|e| halted(e, span))?;
+        self.addresses.insert(program, address.clone());
+        Ok(address)
+    }
+
+    /// A deterministic stand-in for the on-chain randomness beacon (splitmix64).
+    fn next_random(&mut self) -> u64 {
+        self.random_state = self.random_state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.random_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+fn halted(reason: impl fmt::Display, span: Span) -> Interrupt {
+    errors::execution_halted(reason, span).into()
+}
+
+/// Re-reports a `const_eval` failure as a runtime halt.
+fn runtime_error(error: Formatted) -> Interrupt {
+    let reason =
+        error.message().replace(" at compile time", "").replace("compile-time evaluation of ", "evaluation of ");
+    halted(reason, error.span())
+}
+
+/// The one-line description of `error` shown when execution halts.
+fn summary(error: &LeoError) -> String {
+    match error {
+        LeoError::Formatted(error) => error.message().to_owned(),
+        LeoError::Backtraced(error) => error.message.clone(),
+        error => error.to_string(),
+    }
+}
+
+fn index_value(value: &Value, span: Span) -> Eval<usize> {
+    let index = match value.as_u32() {
+        Some(index) => index,
+        None => value
+            .resolve_if_unsuffixed(&Some(Type::Integer(IntegerType::U32)))
+            .ok()
+            .and_then(|value| value.as_u32())
+            .ok_or_else(|| halted(format!("`{}` is not a valid index", render_binding(value)), span))?,
+    };
+    Ok(index as usize)
+}
+
+fn tuple_element(value: &Value, index: usize, span: Span) -> Eval<Value> {
+    match value.tuple_len() {
+        Some(len) if index < len => Ok(value.tuple_index(index).expect("the index is in bounds")),
+        Some(len) => Err(halted(format!("element {index} is out of bounds for a tuple of length {len}"), span)),
+        None => Err(halted("the value is not a tuple", span)),
+    }
+}
+
+/// Whether `unit` names a deployable program rather than a library.
+fn is_program_unit(unit: Symbol) -> bool {
+    unit.to_string().ends_with(".aleo")
+}
+
+/// The futures an entry point returned, either directly or as elements of its output tuple.
+fn futures_of(output: &Value) -> Vec<AsyncExecution> {
+    let values = match output.tuple_len() {
+        Some(len) => (0..len).filter_map(|i| output.tuple_index(i)).collect(),
+        None => vec![output.clone()],
+    };
+    values.iter().filter_map(|value| value.as_future()).flatten().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Controller, Debugger, FinalizeOutcome, Phase, Resume, Stop, StopReason};
+    use crate::{Breakpoints, DebugProgram};
+
+    use leo_ast::{NetworkName, const_eval::Value};
+    use leo_errors::Result;
+    use leo_span::{Symbol, create_session_if_not_set_then, file_source::InMemoryFileSource};
+
+    use indexmap::IndexMap;
+    use std::path::PathBuf;
+
+    const SOURCE: &str = r#"program counter.aleo {
+    mapping counts: address => u64;
+
+    fn bump(public amount: u64) -> Final {
+        let total: u64 = 0u64;
+        for i: u8 in 0u8..3u8 {
+            total = total + amount;
+        }
+        let caller: address = std::ctx::caller();
+        return final {
+            let current: u64 = counts.get_or_use(caller, 0u64);
+            assert(current + total < 100u64);
+            counts.set(caller, current + total);
+        };
+    }
+
+    @noupgrade
+    constructor() {}
+}
+"#;
+
+    /// A controller that records every stop and replays a fixed list of resumptions.
+    struct Script {
+        resumes: Vec<Resume>,
+        stops: Vec<(StopReason, u32, Phase, Option<String>)>,
+    }
+
+    impl Controller for Script {
+        fn stopped(&mut self, stop: &Stop<'_>, _breakpoints: &mut Breakpoints) -> Result<Resume> {
+            let frame = stop.innermost().expect("stops happen inside a frame");
+            let line = frame.position().map_or(0, |position| position.line);
+            let i = frame.local(Symbol::intern("i")).map(ToString::to_string);
+            self.stops.push((stop.reason.clone(), line, frame.phase, i));
+            Ok(if self.resumes.is_empty() { Resume::Continue } else { self.resumes.remove(0) })
+        }
+    }
+
+    fn debugger() -> Debugger {
+        let mut source = InMemoryFileSource::new();
+        source.set(PathBuf::from("/counter/src/main.leo"), SOURCE.into());
+        let program = DebugProgram::analyze(
+            "/counter/src/main.leo",
+            "/counter/src",
+            &source,
+            IndexMap::new(),
+            NetworkName::TestnetV0,
+        )
+        .unwrap_or_else(|err| panic!("analysis failed: {err}"));
+        Debugger::new(program)
+    }
+
+    fn counts(debugger: &Debugger) -> Option<Value> {
+        let signer = debugger.environment.signer.clone();
+        debugger.state.mappings.values().next().and_then(|entries| entries.get(&signer).cloned())
+    }
+
+    /// Verifies a line breakpoint inside a loop stops once per iteration with the counter in scope.
+    #[test]
+    fn breakpoint_stops_on_every_iteration() {
+        create_session_if_not_set_then(|_| {
+            let mut debugger = debugger();
+            debugger.breakpoints.insert("main.leo", 7);
+            let function = debugger.program().resolve_function("bump").unwrap();
+            let inputs = debugger.parse_inputs(&function, &["5u64".into()]).unwrap();
+
+            let mut script = Script { resumes: Vec::new(), stops: Vec::new() };
+            debugger.run(&function, inputs, &mut script, false).unwrap().expect("the run was not terminated");
+
+            let iterations: Vec<_> = script.stops.iter().map(|(_, _, _, i)| i.clone().unwrap()).collect();
+            assert_eq!(iterations, ["0u8", "1u8", "2u8"]);
+            assert!(script.stops.iter().all(|(reason, line, ..)| *reason == StopReason::Breakpoint && *line == 7));
+        });
+    }
+
+    /// Verifies stepping reaches the finalize phase and that finalize writes persist across runs.
+    #[test]
+    fn stepping_reaches_finalize_and_state_persists() {
+        create_session_if_not_set_then(|_| {
+            let mut debugger = debugger();
+            let function = debugger.program().resolve_function("counter.aleo::bump").unwrap();
+
+            let mut script = Script { resumes: vec![Resume::StepOver; 16], stops: Vec::new() };
+            let inputs = debugger.parse_inputs(&function, &["5u64".into()]).unwrap();
+            let execution = debugger.run(&function, inputs, &mut script, true).unwrap().unwrap();
+            assert!(matches!(execution.finalize, FinalizeOutcome::Accepted));
+            assert_eq!(
+                script.stops.first().map(|(reason, line, ..)| (reason.clone(), *line)),
+                Some((StopReason::Entry, 5))
+            );
+            assert!(script.stops.iter().any(|(_, line, phase, _)| *phase == Phase::Finalize && *line == 13));
+
+            let inputs = debugger.parse_inputs(&function, &["5u64".into()]).unwrap();
+            debugger.run(&function, inputs, &mut Script { resumes: Vec::new(), stops: Vec::new() }, false).unwrap();
+            assert_eq!(counts(&debugger), Some(Value::from(30u64)));
+        });
+    }
+
+    /// Verifies a failing finalize halts at the assertion and rolls back its state changes.
+    #[test]
+    fn failed_finalize_is_rolled_back() {
+        create_session_if_not_set_then(|_| {
+            let mut debugger = debugger();
+            let function = debugger.program().resolve_function("bump").unwrap();
+
+            let inputs = debugger.parse_inputs(&function, &["20u64".into()]).unwrap();
+            let mut script = Script { resumes: Vec::new(), stops: Vec::new() };
+            let execution = debugger.run(&function, inputs, &mut script, false).unwrap().unwrap();
+            assert!(matches!(execution.finalize, FinalizeOutcome::Accepted));
+
+            let inputs = debugger.parse_inputs(&function, &["20u64".into()]).unwrap();
+            let execution = debugger.run(&function, inputs, &mut script, false).unwrap().unwrap();
+            assert!(matches!(execution.finalize, FinalizeOutcome::Rejected(_)));
+            assert!(matches!(script.stops.last(), Some((StopReason::Halted(_), 12, Phase::Finalize, _))));
+            assert_eq!(counts(&debugger), Some(Value::from(60u64)));
+        });
+    }
+
+    const CHEATS: &str = r#"program test_cheats.aleo {
+    mapping heights: u8 => u32;
+
+    @test(mode = "native")
+    fn cheats() {
+        std::test::warp(7u32);
+        heights.set(0u8, std::ctx::block_height());
+        let id: u32 = std::test::snapshot();
+        std::test::warp(9u32);
+        heights.set(0u8, std::ctx::block_height());
+        std::test::restore(id);
+        assert_eq(heights.get(0u8), 7u32);
+    }
+
+    @noupgrade
+    constructor() {}
+}
+"#;
+
+    /// Verifies native test cheatcodes warp the height and restore snapshots, the environment included.
+    #[test]
+    fn native_test_cheatcodes() {
+        create_session_if_not_set_then(|_| {
+            let dir = std::env::temp_dir().join("debugger_test_cheats");
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("test_cheats.leo");
+            std::fs::write(&path, CHEATS).unwrap();
+            let program =
+                DebugProgram::analyze_test(&path, IndexMap::new(), Default::default(), NetworkName::TestnetV0)
+                    .unwrap_or_else(|err| panic!("analysis failed: {err}"));
+            let mut debugger = Debugger::new(program);
+            let function = debugger.program().resolve_function("cheats").unwrap();
+
+            let mut script = Script { resumes: Vec::new(), stops: Vec::new() };
+            let execution = debugger.run(&function, Vec::new(), &mut script, false).unwrap().unwrap();
+            assert!(matches!(execution.finalize, FinalizeOutcome::Skipped));
+            assert_eq!(debugger.environment.block_height, 7);
+            assert!(script.stops.is_empty());
+            std::fs::remove_dir_all(&dir).unwrap();
+        });
+    }
+}
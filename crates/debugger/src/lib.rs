@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Step debugger for Leo programs.
+//!
+//! The debugger evaluates the typed AST produced by the compiler frontend
+//! with the `const_eval` value machinery, so it follows source-level control
+//! flow rather than the lowered Aleo instructions. An entry function runs as
+//! the transition; the futures it returns then run as the finalize phase
+//! against an in-memory copy of the program's mappings and storage.
+//!
+//! Execution pauses before statements that hit a breakpoint or satisfy the
+//! current step request and hands control to a [`Controller`]. The crate
+//! ships [`Console`] for the interactive `leo debug` prompt, [`DapServer`]
+//! for editors speaking the Debug Adapter Protocol, and [`Unattended`] for
+//! runs that should never pause.
+//!
+//! Runs can also count the statements, branch arms, and calls they execute
+//! into a [`Coverage`], which `leo test --coverage` reports as lcov and HTML.
+
+mod breakpoints;
+pub use breakpoints::*;
+
+mod console;
+pub use console::*;
+
+mod coverage;
+pub use coverage::*;
+
+mod dap;
+pub use dap::*;
+
+mod errors;
+
+mod interpreter;
+pub use interpreter::*;
+
+mod program;
+pub use program::*;
+
+mod state;
+pub use state::*;
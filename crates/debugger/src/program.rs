@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The analyzed program the debugger executes.
+//!
+//! Every item with a Leo body is indexed by its resolved [`Location`], the
+//! same key the frontend writes into call and composite paths, across the
+//! main program, its modules, and the Leo program and library stubs it
+//! imports.
+
+use crate::errors;
+
+use leo_ast::{
+    Ast,
+    Composite,
+    ConstDeclaration,
+    Function,
+    Library,
+    Location,
+    Mapping,
+    Module,
+    NetworkName,
+    NodeBuilder,
+    Program,
+    StorageVariable,
+    Stub,
+};
+use leo_compiler::{Compiler, CompilerOptions, FrontendAnalysis, load_import_stubs_for_package};
+use leo_errors::{Handler, Result};
+use leo_passes::TypeTable;
+use leo_span::{
+    Symbol,
+    file_source::{DiskFileSource, FileSource},
+};
+
+use indexmap::IndexMap;
+use std::{path::Path, rc::Rc};
+
+/// A program after frontend analysis, indexed for execution.
+#[derive(Debug)]
+pub struct DebugProgram {
+    /// The unit name of the main program, e.g. `token.aleo`.
+    pub(crate) name: Symbol,
+    pub(crate) functions: IndexMap<Location, Function>,
+    pub(crate) composites: IndexMap<Location, Composite>,
+    pub(crate) consts: IndexMap<Location, ConstDeclaration>,
+    pub(crate) mappings: IndexMap<Location, Mapping>,
+    pub(crate) storage: IndexMap<Location, StorageVariable>,
+    pub(crate) types: TypeTable,
+}
+
+impl DebugProgram {
+    /// Analyzes the package at `package_root`, whose entry file is `entry_file`.
+    ///
+    /// Local dependencies are loaded as Leo stubs so calls into them can be
+    /// stepped through; network dependencies have no source and are not loaded.
+    pub fn load(package_root: &Path, entry_file: &Path, network: NetworkName) -> Result<Self> {
+        let stubs = load_import_stubs_for_package(package_root, network)?.stubs;
+        Self::analyze(entry_file, package_root.join("src"), &DiskFileSource, stubs, network)
+    }
+
+    /// Runs the compiler frontend over `entry_file` and indexes the result.
+    pub fn analyze(
+        entry_file: impl AsRef<Path>,
+        source_directory: impl AsRef<Path>,
+        file_source: &impl FileSource,
+        import_stubs: IndexMap<Symbol, Stub>,
+        network: NetworkName,
+    ) -> Result<Self> {
+        let node_builder = Rc::new(NodeBuilder::default());
+        let mut compiler = Compiler::new(None, false, Handler::default(), node_builder, None, import_stubs, network);
+        let FrontendAnalysis { ast, type_table, .. } =
+            compiler.analyze_frontend_from_directory_with_file_source(entry_file, source_directory, file_source)?;
+        Self::new(ast, type_table)
+    }
+
+    /// Runs the compiler frontend over the standalone test file `entry_file` and indexes the result.
+    ///
+    /// `import_stubs` should hold the program under test as a Leo stub so calls into it can be run.
+    pub fn analyze_test(
+        entry_file: impl AsRef<Path>,
+        import_stubs: IndexMap<Symbol, Stub>,
+        options: CompilerOptions,
+        network: NetworkName,
+    ) -> Result<Self> {
+        let node_builder = Rc::new(NodeBuilder::default());
+        let mut compiler =
+            Compiler::new(None, true, Handler::default(), node_builder, Some(options), import_stubs, network);
+        let FrontendAnalysis { ast, type_table, .. } = compiler.analyze_frontend_from_file(entry_file)?;
+        Self::new(ast, type_table)
+    }
+
+    /// Indexes an analyzed AST together with the type table the frontend produced for it.
+    pub fn new(ast: &Ast, types: &TypeTable) -> Result<Self> {
+        let Ast::Program(program) = ast else {
+            return Err(errors::not_a_program().into());
+        };
+        let Some(name) = program.program_scopes.keys().next().copied() else {
+            return Err(errors::not_a_program().into());
+        };
+
+        let mut index = Self {
+            name,
+            functions: IndexMap::new(),
+            composites: IndexMap::new(),
+            consts: IndexMap::new(),
+            mappings: IndexMap::new(),
+            storage: IndexMap::new(),
+            types: types.clone(),
+        };
+        index.index_program(program);
+        Ok(index)
+    }
+
+    /// The unit name of the main program, e.g. `token.aleo`.
+    pub fn name(&self) -> Symbol {
+        self.name
+    }
+
+    /// Looks up a function by `name` or `program.aleo::name`.
+    pub fn resolve_function(&self, name: &str) -> Result<Location> {
+        let location = match name.rsplit_once("::").or_else(|| name.rsplit_once('/')) {
+            Some((program, function)) => Location::new(Symbol::intern(program), vec![Symbol::intern(function)]),
+            None => Location::new(self.name, vec![Symbol::intern(name)]),
+        };
+        if self.functions.contains_key(&location) { Ok(location) } else { Err(errors::unknown_function(name).into()) }
+    }
+
+    /// The function at `location`, if it has a Leo body.
+    pub fn function(&self, location: &Location) -> Option<&Function> {
+        self.functions.get(location)
+    }
+
+    /// The location of the enum and the index of the variant, if `location` names a variant of an enum.
+    pub(crate) fn variant(&self, location: &Location) -> Option<(Location, usize)> {
+        let (name, enum_path) = location.path.split_last()?;
+        let enum_location = Location::new(location.program, enum_path.to_vec());
+        let index = self.composites.get(&enum_location)?.variants.as_ref()?.iter().position(|v| v.name() == *name)?;
+        Some((enum_location, index))
+    }
+
+    /// Every entry point of the main program, in declaration order.
+    pub fn entry_points(&self) -> impl Iterator<Item = (&Location, &Function)> + '_ {
+        self.functions
+            .iter()
+            .filter(|(location, function)| location.program == self.name && function.variant.is_externally_callable())
+    }
+
+    fn index_program(&mut self, program: &Program) {
+        for scope in program.program_scopes.values() {
+            let unit = scope.program_id.as_symbol();
+            let location = |name: &Symbol| Location::new(unit, vec![*name]);
+            for (name, function) in &scope.functions {
+                self.functions.entry(location(name)).or_insert_with(|| function.clone());
+            }
+            for (name, composite) in &scope.composites {
+                self.index_composite(location(name), composite);
+            }
+            for (name, declaration) in &scope.consts {
+                self.consts.entry(location(name)).or_insert_with(|| declaration.clone());
+            }
+            for (name, mapping) in &scope.mappings {
+                self.mappings.entry(location(name)).or_insert_with(|| mapping.clone());
+            }
+            for (name, variable) in &scope.storage_variables {
+                self.storage.entry(location(name)).or_insert_with(|| variable.clone());
+            }
+        }
+        for module in program.modules.values() {
+            self.index_module(module);
+        }
+        for stub in program.stubs.values() {
+            self.index_stub(stub);
+        }
+    }
+
+    fn index_module(&mut self, module: &Module) {
+        let location =
+            |name: &Symbol| Location::new(module.unit_name, module.path.iter().chain([name]).copied().collect());
+        for (name, function) in &module.functions {
+            self.functions.entry(location(name)).or_insert_with(|| function.clone());
+        }
+        for (name, composite) in &module.composites {
+            self.index_composite(location(name), composite);
+        }
+        for (name, declaration) in &module.consts {
+            self.consts.entry(location(name)).or_insert_with(|| declaration.clone());
+        }
+    }
+
+    fn index_library(&mut self, library: &Library) {
+        let location = |name: &Symbol| Location::new(library.name, vec![*name]);
+        for (name, function) in &library.functions {
+            self.functions.entry(location(name)).or_insert_with(|| function.clone());
+        }
+        for (name, composite) in &library.structs {
+            self.index_composite(location(name), composite);
+        }
+        for (name, declaration) in &library.consts {
+            self.consts.entry(location(name)).or_insert_with(|| declaration.clone());
+        }
+        for module in library.modules.values() {
+            self.index_module(module);
+        }
+        for stub in library.stubs.values() {
+            self.index_stub(stub);
+        }
+    }
+
+    /// Indexes `composite` at `location`, with its associated functions next to it under their `Type::name` names.
+    fn index_composite(&mut self, location: Location, composite: &Composite) {
+        let (_, module) = location.path.split_last().expect("composite locations are never empty");
+        for (name, function) in &composite.functions {
+            let location = Location::new(location.program, module.iter().chain([name]).copied().collect());
+            self.functions.entry(location).or_insert_with(|| function.clone());
+        }
+        self.composites.entry(location).or_insert_with(|| composite.clone());
+    }
+
+    fn index_stub(&mut self, stub: &Stub) {
+        match stub {
+            Stub::FromLeo { program, .. } => self.index_program(program),
+            Stub::FromLibrary { library, .. } => self.index_library(library),
+            // Aleo bytecode has no Leo bodies to step through.
+            Stub::FromAleo { .. } => {}
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! On-chain state and block context seen by a debugged execution.
+
+use crate::DebugProgram;
+
+use leo_ast::{
+    Location,
+    const_eval::{AsyncExecution, Value},
+};
+
+use indexmap::IndexMap;
+
+/// The address of the development account that signs debugged transactions by default.
+pub const DEFAULT_SIGNER: &str = "aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px";
+
+/// Mapping and storage contents, keyed by the resolved location of their declaration.
+///
+/// The state starts empty and persists across runs of the same [`crate::Debugger`],
+/// so a sequence of calls can build up state the way consecutive transactions do.
+#[derive(Clone, Debug, Default)]
+pub struct ChainState {
+    /// Entries of every declared mapping.
+    pub mappings: IndexMap<Location, IndexMap<Value, Value>>,
+    /// Values of singleton storage variables; an absent entry reads as `none`.
+    pub storage: IndexMap<Location, Value>,
+    /// Elements of vector storage variables.
+    pub vectors: IndexMap<Location, Vec<Value>>,
+}
+
+impl ChainState {
+    /// Empty state with an entry for every mapping the program declares.
+    pub fn for_program(program: &DebugProgram) -> Self {
+        Self {
+            mappings: program.mappings.keys().map(|location| (location.clone(), IndexMap::new())).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Block and transaction context the `std::ctx` and `std::block` intrinsics read.
+#[derive(Clone, Debug)]
+pub struct Environment {
+    /// The transaction signer, also used as the caller of the top-level entry point.
+    pub signer: Value,
+    /// The value `block.height` returns during finalize.
+    pub block_height: u32,
+    /// The value `block.timestamp` returns during finalize.
+    pub block_timestamp: i64,
+    /// The network ID returned by `network.id`.
+    pub network_id: u16,
+    /// The edition reported for every program.
+    pub edition: u16,
+    /// Seed for the deterministic stand-in for `ChaCha::rand_*`.
+    pub random_seed: u64,
+    /// The caller entry points of other programs see when the main program calls them, in place
+    /// of its address. Set by `std::test::set_caller`.
+    pub caller: Option<Value>,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self {
+            signer: DEFAULT_SIGNER.parse().expect("the default signer is a valid address"),
+            block_height: 0,
+            block_timestamp: 0,
+            network_id: 1,
+            edition: 0,
+            random_seed: 0,
+            caller: None,
+        }
+    }
+}
+
+/// Renders `value` for display, spelling out the calls deferred in futures.
+pub fn render_value(value: &Value) -> String {
+    if let Some(executions) = value.as_future() {
+        let calls = executions.iter().map(|execution| match execution {
+            AsyncExecution::AsyncFunctionCall { function, arguments } => {
+                format!("{function}({})", arguments.iter().map(render_value).collect::<Vec<_>>().join(", "))
+            }
+            AsyncExecution::AsyncBlock { containing_function, .. } => format!("{containing_function}/final {{ .. }}"),
+        });
+        return format!("Final [{}]", calls.collect::<Vec<_>>().join(", "));
+    }
+    if let Some(len) = value.tuple_len() {
+        let elements = (0..len).filter_map(|i| value.tuple_index(i));
+        return format!("({})", elements.map(|element| render_value(&element)).collect::<Vec<_>>().join(", "));
+    }
+    value.to_string()
+}
+
+/// Renders a variable binding, where the unit value stands for an optional `none`.
+pub fn render_binding(value: &Value) -> String {
+    if *value == Value::make_unit() { "none".to_owned() } else { render_value(value) }
+}
@@ -65,6 +65,48 @@ impl Label {
     }
 }
 
+/// A machine-applicable fix attached to a diagnostic.
+///
+/// Each edit replaces the source text under its span; an empty span inserts.
+/// The CLI does not render suggestions; editor tooling (notably `leo-lsp`)
+/// turns them into quick fixes so it never has to parse message text.
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    msg: String,
+    edits: Vec<(Span, String)>,
+}
+
+impl Suggestion {
+    pub fn new(msg: impl fmt::Display) -> Self {
+        Self { msg: msg.to_string(), edits: Vec::new() }
+    }
+
+    /// Create a suggestion that replaces the text under `span` with `replacement`.
+    pub fn replace(msg: impl fmt::Display, span: Span, replacement: impl fmt::Display) -> Self {
+        Self::new(msg).with_edit(span, replacement)
+    }
+
+    /// Create a suggestion that inserts `text` at the absolute position `pos`.
+    pub fn insert(msg: impl fmt::Display, pos: u32, text: impl fmt::Display) -> Self {
+        Self::new(msg).with_edit(Span::new(pos, pos), text)
+    }
+
+    pub fn with_edit(mut self, span: Span, replacement: impl fmt::Display) -> Self {
+        self.edits.push((span, replacement.to_string()));
+        self
+    }
+
+    /// Borrow the suggestion's title.
+    pub fn message(&self) -> &str {
+        &self.msg
+    }
+
+    /// Borrow the edits, each a span paired with its replacement text.
+    pub fn edits(&self) -> &[(Span, String)] {
+        &self.edits
+    }
+}
+
 /// Helper span for Ariadne that includes the source file start index.
 #[derive(Clone)]
 struct AriadneSpan {
@@ -114,6 +156,7 @@ struct FormattedInner {
     error: bool,
     span: Span,
     labels: Vec<Label>,
+    suggestions: Vec<Suggestion>,
     primary_span_underline: bool,
 }
 
@@ -141,6 +184,7 @@ impl Formatted {
                 error,
                 span,
                 labels,
+                suggestions: Vec::new(),
                 primary_span_underline: false,
             }),
         }
@@ -171,6 +215,18 @@ impl Formatted {
         self
     }
 
+    /// Attach a machine-applicable fix for editor tooling.
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.inner.suggestions.push(suggestion);
+        self
+    }
+
+    /// Attach every fix in `suggestions`; accepts an `Option` for fixes that may not apply.
+    pub fn with_suggestions(mut self, suggestions: impl IntoIterator<Item = Suggestion>) -> Self {
+        self.inner.suggestions.extend(suggestions);
+        self
+    }
+
     /// Render the primary single-line span with a plain underline even when the
     /// diagnostic has no primary label message.
     pub fn with_primary_span_underline(mut self) -> Self {
@@ -272,6 +328,7 @@ impl Formatted {
             is_error: self.inner.error,
             span: Some(self.inner.span),
             labels,
+            suggestions: &self.inner.suggestions,
         }
     }
 
@@ -427,6 +484,8 @@ pub struct DiagnosticView<'a> {
     pub span: Option<Span>,
     /// Secondary spans annotated with their own human-readable messages.
     pub labels: Vec<DiagnosticLabelView>,
+    /// Machine-applicable fixes attached by the emitting pass.
+    pub suggestions: &'a [Suggestion],
 }
 
 /// One secondary label paired with its source span.
@@ -443,7 +502,7 @@ pub struct DiagnosticLabelView {
 
 #[cfg(test)]
 mod tests {
-    use super::{Color, Formatted, Label};
+    use super::{Color, Formatted, Label, Suggestion};
     use leo_span::{Span, create_session_if_not_set_then, source_map::FileName};
 
     /// Verifies the structured view round-trips primary message, code, help, and note.
@@ -480,6 +539,22 @@ mod tests {
         });
     }
 
+    /// Verifies suggestions are exposed with their titles and edits intact.
+    #[test]
+    fn diagnostic_view_exposes_suggestions() {
+        create_session_if_not_set_then(|_| {
+            let error = Formatted::error("TST", 6, "boom", Span::new(0, 4))
+                .with_suggestion(Suggestion::replace("cast it", Span::new(0, 4), "x as u8"))
+                .with_suggestion(Suggestion::insert("add a line", 9, "y;"));
+
+            let view = error.diagnostic_view();
+            assert_eq!(view.suggestions.len(), 2);
+            assert_eq!(view.suggestions[0].message(), "cast it");
+            assert_eq!(view.suggestions[0].edits(), &[(Span::new(0, 4), "x as u8".to_owned())]);
+            assert_eq!(view.suggestions[1].edits(), &[(Span::new(9, 9), "y;".to_owned())]);
+        });
+    }
+
     /// Verifies warnings round-trip through the structured view with severity preserved.
     #[test]
     fn diagnostic_view_marks_warnings() {
@@ -41,6 +41,8 @@ pub fn format_node(node: &SyntaxNode, out: &mut Output) {
         // Declarations
         FUNCTION_DEF | FINAL_FN_DEF | VIEW_FN_DEF | CONSTRUCTOR_DEF => format_function(node, out),
         STRUCT_DEF | RECORD_DEF => format_composite(node, out),
+        ENUM_DEF => format_enum(node, out),
+        IMPL_DEF => format_impl(node, out),
         INTERFACE_DEF => format_interface(node, out),
         FN_PROTOTYPE_DEF => format_fn_prototype(node, out),
         RECORD_PROTOTYPE_DEF => format_record_prototype(node, out),
@@ -67,6 +69,10 @@ pub fn format_node(node: &SyntaxNode, out: &mut Output) {
         ASSIGN_STMT | COMPOUND_ASSIGN_STMT => format_assign(node, out),
         IF_STMT => format_conditional(node, out),
         FOR_STMT | FOR_INCLUSIVE_STMT => format_iteration(node, out),
+        WHILE_STMT => format_while(node, out),
+        BREAK_STMT => format_jump(node, out, "break"),
+        CONTINUE_STMT => format_jump(node, out, "continue"),
+        MATCH_STMT => format_match(node, out),
         ASSERT_STMT => format_assert(node, out),
         ASSERT_EQ_STMT => format_assert_pair(node, out, "assert_eq"),
         ASSERT_NEQ_STMT => format_assert_pair(node, out, "assert_neq"),
@@ -82,11 +88,9 @@ pub fn format_node(node: &SyntaxNode, out: &mut Output) {
         SELF_EXPR => out.write("self"),
         BLOCK_KW_EXPR => out.write("block"),
         NETWORK_KW_EXPR => out.write("network"),
-        SELF_UPPER_EXPR => {
-            out.write(node.text().to_string().trim());
-        }
         UNARY_EXPR => format_unary(node, out),
         TERNARY_EXPR => format_ternary(node, out),
+        MATCH_EXPR => format_match(node, out),
         FIELD_EXPR => format_field_expr(node, out),
         TUPLE_ACCESS_EXPR => format_tuple_access(node, out),
         INDEX_EXPR => format_index_expr(node, out),
@@ -105,6 +109,7 @@ pub fn format_node(node: &SyntaxNode, out: &mut Output) {
         IDENT_PATTERN => format_ident_pattern(node, out),
         TUPLE_PATTERN => format_tuple_pattern(node, out),
         WILDCARD_PATTERN => out.write("_"),
+        VARIANT_PATTERN => format_variant_pattern(node, out),
 
         // Types
         k if k.is_type() => format_type(node, out),
@@ -306,10 +311,20 @@ fn format_program(node: &SyntaxNode, out: &mut Output) {
         out.newline();
     }
 
-    // Iterate children_with_tokens to handle items and comments in order.
-    // Block items (structs, records, functions, interfaces, constructors) always
-    // get a blank line separating them from adjacent items. Inline items (consts,
-    // mappings) preserve source spacing — only get a blank line if the source had one.
+    format_item_body(&elems, out);
+
+    if has_token(node, R_BRACE) {
+        out.write("}");
+        out.newline();
+    }
+}
+
+/// Formats the items between the braces of a program or `impl` block, one per line.
+///
+/// Block items (structs, records, functions, interfaces, constructors) always
+/// get a blank line separating them from adjacent items. Inline items (consts,
+/// mappings) preserve source spacing — only get a blank line if the source had one.
+fn format_item_body(elems: &[SyntaxElement], out: &mut Output) {
     let mut after_lbrace = false;
     let mut saw_linebreak = false;
     let mut linebreak_count: usize = 0;
@@ -317,7 +332,7 @@ fn format_program(node: &SyntaxNode, out: &mut Output) {
     let mut prev_was_block_item = false;
     let mut prev_was_comment = false;
 
-    for elem in &elems {
+    for elem in elems {
         match elem {
             SyntaxElement::Token(tok) => match tok.kind() {
                 L_BRACE => {
@@ -396,11 +411,6 @@ fn format_program(node: &SyntaxNode, out: &mut Output) {
             _ => {}
         }
     }
-
-    if has_token(node, R_BRACE) {
-        out.write("}");
-        out.newline();
-    }
 }
 
 fn is_program_item_non_annotation(kind: SyntaxKind) -> bool {
@@ -412,6 +422,8 @@ fn is_program_item_non_annotation(kind: SyntaxKind) -> bool {
             | CONSTRUCTOR_DEF
             | STRUCT_DEF
             | RECORD_DEF
+            | ENUM_DEF
+            | IMPL_DEF
             | MAPPING_DEF
             | STORAGE_DEF
             | GLOBAL_CONST
@@ -423,7 +435,15 @@ fn is_program_item_non_annotation(kind: SyntaxKind) -> bool {
 fn is_block_item(kind: SyntaxKind) -> bool {
     matches!(
         kind,
-        FUNCTION_DEF | FINAL_FN_DEF | VIEW_FN_DEF | CONSTRUCTOR_DEF | STRUCT_DEF | RECORD_DEF | INTERFACE_DEF
+        FUNCTION_DEF
+            | FINAL_FN_DEF
+            | VIEW_FN_DEF
+            | CONSTRUCTOR_DEF
+            | STRUCT_DEF
+            | RECORD_DEF
+            | ENUM_DEF
+            | IMPL_DEF
+            | INTERFACE_DEF
     )
 }
 
@@ -740,6 +760,103 @@ fn format_composite(node: &SyntaxNode, out: &mut Output) {
     out.ensure_newline();
 }
 
+fn format_enum(node: &SyntaxNode, out: &mut Output) {
+    let elems = elements(node);
+    let rbrace_idx = find_last_token_index(&elems, R_BRACE);
+
+    // Comments between the variants are rare enough that the enum is kept as written.
+    let has_interior_comment = elems[..rbrace_idx.unwrap_or(elems.len())]
+        .iter()
+        .skip_while(|elem| matches!(elem, SyntaxElement::Token(tok) if is_trivia(tok.kind())))
+        .any(|elem| match elem {
+            SyntaxElement::Token(tok) => matches!(tok.kind(), COMMENT_LINE | COMMENT_BLOCK),
+            SyntaxElement::Node(n) => has_deep_comment(n),
+        });
+    if has_error_descendant(node) || has_interior_comment {
+        write_node_verbatim(node, out);
+        out.set_mark();
+        out.ensure_newline();
+        return;
+    }
+
+    emit_leading_comments(node, out);
+    if has_token(node, KW_EXPORT) {
+        out.write("export");
+        out.space();
+    }
+    out.write("enum");
+    out.space();
+    if let Some(name) = first_token_text(node, IDENT) {
+        out.write(&name);
+        out.space();
+    }
+    out.write("{");
+    out.newline();
+    out.indented(|out| {
+        for variant in node.children().filter(|child| child.kind() == ENUM_VARIANT) {
+            if let Some(name) = first_token_text(&variant, IDENT) {
+                out.write(&name);
+            }
+            if has_token(&variant, L_PAREN) {
+                out.write("(");
+                for (i, ty) in variant.children().filter(|child| child.kind().is_type()).enumerate() {
+                    if i > 0 {
+                        out.write(",");
+                        out.space();
+                    }
+                    format_type(&ty, out);
+                }
+                out.write(")");
+            }
+            out.write(",");
+            out.newline();
+        }
+    });
+    if has_token(node, R_BRACE) {
+        out.write("}");
+        if let Some(idx) = rbrace_idx {
+            emit_comments_after(&elems, idx, out);
+        }
+    }
+    if let Some(next) = node.next_sibling() {
+        emit_stolen_trailing_comments(&next, out);
+    }
+    out.set_mark();
+    out.ensure_newline();
+}
+
+fn format_impl(node: &SyntaxNode, out: &mut Output) {
+    let elems = elements(node);
+    let has_items = node.children().any(|c| is_program_item_non_annotation(c.kind()) || c.kind() == ERROR);
+
+    emit_leading_comments(node, out);
+    out.write("impl");
+    out.space();
+    if let Some(name) = first_token_text(node, IDENT) {
+        out.write(&name);
+    }
+    if let Some(params) = node.children().find(|child| child.kind() == CONST_PARAM_LIST) {
+        out.write("::");
+        format_const_parameter_list(&params, out);
+    }
+    out.space();
+    out.write("{");
+    if has_items {
+        if let Some(first_item) = node.children().find(|child| is_program_item(child.kind()) || child.kind() == ERROR) {
+            emit_stolen_trailing_comments(&first_item, out);
+        }
+        out.newline();
+    }
+
+    format_item_body(&elems, out);
+
+    if has_token(node, R_BRACE) {
+        out.write("}");
+    }
+    out.set_mark();
+    out.ensure_newline();
+}
+
 fn format_interface(node: &SyntaxNode, out: &mut Output) {
     let elems = elements(node);
     let rbrace_idx = find_last_token_index(&elems, R_BRACE);
@@ -1354,7 +1471,9 @@ fn format_parameter(node: &SyntaxNode, out: &mut Output) {
         .children_with_tokens()
         .any(|elem| matches!(elem, SyntaxElement::Token(tok) if tok.kind() == IDENT && tok.text() == "mut"));
 
-    if has_error_descendant(node) || ident_count != 1 || has_mut_ident {
+    // The receiver `self` of a method is the only parameter without a name.
+    let is_receiver = ident_count == 0 && has_token(node, KW_SELF);
+    if has_error_descendant(node) || (ident_count != 1 && !is_receiver) || has_mut_ident {
         write_node_verbatim(node, out);
         return;
     }
@@ -2299,6 +2418,103 @@ fn format_iteration(node: &SyntaxNode, out: &mut Output) {
     }
 }
 
+fn format_while(node: &SyntaxNode, out: &mut Output) {
+    if has_error_descendant(node) {
+        write_node_verbatim(node, out);
+        return;
+    }
+
+    emit_leading_comments(node, out);
+    for elem in node.children_with_tokens() {
+        match elem {
+            SyntaxElement::Token(tok) => {
+                let k = tok.kind();
+                match k {
+                    AT => out.write("@"),
+                    R_PAREN => {
+                        out.write(")");
+                        out.newline();
+                    }
+                    KW_WHILE => {
+                        out.write("while");
+                        out.space();
+                    }
+                    WHITESPACE | LINEBREAK => {}
+                    _ => out.write(tok.text()),
+                }
+            }
+            SyntaxElement::Node(n) => {
+                let k = n.kind();
+                if k.is_expression() {
+                    format_node(&n, out);
+                } else if k == BLOCK {
+                    out.space();
+                    format_block(&n, out);
+                }
+            }
+        }
+    }
+}
+
+fn format_jump(node: &SyntaxNode, out: &mut Output, keyword: &str) {
+    emit_leading_comments(node, out);
+    out.write(keyword);
+    write_semicolon_with_comments(node, out);
+}
+
+/// Format a `match` statement or expression. Statement arms have block bodies; expression arms
+/// have expression bodies and are terminated by commas.
+fn format_match(node: &SyntaxNode, out: &mut Output) {
+    let arms: Vec<_> = node.children().filter(|child| child.kind() == MATCH_ARM).collect();
+
+    // Comments outside the arm bodies are kept as written, as are arms missing a pattern or a body.
+    let has_interior_comment = node
+        .children_with_tokens()
+        .skip_while(|elem| matches!(elem, SyntaxElement::Token(tok) if is_trivia(tok.kind())))
+        .any(|elem| match elem {
+            SyntaxElement::Token(tok) => matches!(tok.kind(), COMMENT_LINE | COMMENT_BLOCK),
+            SyntaxElement::Node(n) if n.kind() == MATCH_ARM => n.children_with_tokens().any(|elem| match elem {
+                SyntaxElement::Token(tok) => matches!(tok.kind(), COMMENT_LINE | COMMENT_BLOCK),
+                SyntaxElement::Node(child) => child.kind() != BLOCK && has_deep_comment(&child),
+            }),
+            SyntaxElement::Node(n) => has_deep_comment(&n),
+        });
+    if has_error_descendant(node) || has_interior_comment || arms.iter().any(|arm| arm.children().count() != 2) {
+        write_node_verbatim(node, out);
+        return;
+    }
+
+    emit_leading_comments(node, out);
+    out.write("match");
+    out.space();
+    if let Some(scrutinee) = node.children().find(|child| child.kind().is_expression()) {
+        format_node(&scrutinee, out);
+        out.space();
+    }
+    out.write("{");
+    out.newline();
+    out.indented(|out| {
+        for arm in &arms {
+            let mut children = arm.children();
+            let (Some(pattern), Some(body)) = (children.next(), children.next()) else { continue };
+            format_node(&pattern, out);
+            out.space();
+            out.write("=>");
+            out.space();
+            if body.kind() == BLOCK {
+                format_block(&body, out);
+            } else {
+                format_node(&body, out);
+                out.write(",");
+            }
+            out.ensure_newline();
+        }
+    });
+    if has_token(node, R_BRACE) {
+        out.write("}");
+    }
+}
+
 fn format_assert(node: &SyntaxNode, out: &mut Output) {
     if has_error_descendant(node) || node.children().filter(|child| child.kind().is_expression()).count() != 1 {
         write_node_verbatim(node, out);
@@ -3201,6 +3417,32 @@ fn format_ident_pattern(node: &SyntaxNode, out: &mut Output) {
     }
 }
 
+fn format_variant_pattern(node: &SyntaxNode, out: &mut Output) {
+    // VARIANT_PATTERN: path tokens, then optional `(bindings)`
+    for elem in node.children_with_tokens() {
+        if let SyntaxElement::Token(tok) = elem {
+            match tok.kind() {
+                IDENT | COLON_COLON => out.write(tok.text()),
+                L_PAREN => break,
+                _ => {}
+            }
+        }
+    }
+    if has_token(node, L_PAREN) {
+        let bindings: Vec<_> =
+            node.children().filter(|c| matches!(c.kind(), IDENT_PATTERN | WILDCARD_PATTERN)).collect();
+        out.write("(");
+        for (i, binding) in bindings.iter().enumerate() {
+            if i > 0 {
+                out.write(",");
+                out.space();
+            }
+            format_node(binding, out);
+        }
+        out.write(")");
+    }
+}
+
 fn write_node_verbatim(node: &SyntaxNode, out: &mut Output) {
     let text = node.text().to_string();
     let text = text.trim();
@@ -3841,6 +4083,8 @@ fn is_program_item(kind: SyntaxKind) -> bool {
             | CONSTRUCTOR_DEF
             | STRUCT_DEF
             | RECORD_DEF
+            | ENUM_DEF
+            | IMPL_DEF
             | MAPPING_DEF
             | STORAGE_DEF
             | GLOBAL_CONST
@@ -3902,6 +4146,13 @@ fn has_token(node: &SyntaxNode, kind: SyntaxKind) -> bool {
     node.children_with_tokens().any(|e| matches!(e, SyntaxElement::Token(t) if t.kind() == kind))
 }
 
+fn first_token_text(node: &SyntaxNode, kind: SyntaxKind) -> Option<String> {
+    node.children_with_tokens().find_map(|e| match e {
+        SyntaxElement::Token(t) if t.kind() == kind => Some(t.text().to_string()),
+        _ => None,
+    })
+}
+
 /// Find the index of a token with a given kind.
 fn find_token_index(elems: &[SyntaxElement], kind: SyntaxKind) -> Option<usize> {
     elems.iter().position(|e| matches!(e, SyntaxElement::Token(t) if t.kind() == kind))
@@ -406,14 +406,26 @@ mod tests {
     }
 
     #[test]
-    fn preserves_self_upper_recovery() {
-        // `Self::…` is reserved; parser recovers by consuming the whole path so it becomes one
-        // SELF_UPPER_EXPR node. Fmt must emit the raw text — a naive `out.write("Self")` arm
-        // would drop everything after `Self`.
+    fn preserves_self_upper_path() {
+        // `Self` is an ordinary first path segment; the path after it must be kept intact.
         let source = "program test.aleo {\n    fn f() -> address {\n        return Self::ctx::caller();\n    }\n}\n";
         assert_eq!(format_source(source), source);
     }
 
+    #[test]
+    fn formats_impl_block() {
+        let source = "struct Point{x:u32,y:u32}\nimpl Point{fn new(x:u32,y:u32)->Self{return Self{x,y};}\n// The sum.\nfn sum(self)->u32{return self.x+self.y;}}\n";
+        let expected = "struct Point {\n    x: u32,\n    y: u32,\n}\n\nimpl Point {\n    fn new(x: u32, y: u32) -> Self {\n        return Self { x, y };\n    }\n\n    // The sum.\n    fn sum(self) -> u32 {\n        return self.x + self.y;\n    }\n}\n";
+        assert_eq!(format_source(source), expected);
+    }
+
+    #[test]
+    fn formats_impl_block_with_const_params() {
+        let source = "impl Vec::[N:u32]{fn len(self)->u32{return N;}}\n";
+        let expected = "impl Vec::[N: u32] {\n    fn len(self) -> u32 {\n        return N;\n    }\n}\n";
+        assert_eq!(format_source(source), expected);
+    }
+
     #[test]
     fn preserves_dyn_record_type_and_cast() {
         let source = "program test.aleo{record Token{owner:address,balance:u64}fn main(t:Token)->dyn record{let d:dyn record=t as dyn record;return d;}}\n";
@@ -53,8 +53,10 @@ const RAND_LEO: &str = include_str!("leo/rand.leo");
 const SIG_LEO: &str = include_str!("leo/sig.leo");
 const SERIALIZE_LEO: &str = include_str!("leo/serialize.leo");
 const GRP_LEO: &str = include_str!("leo/grp.leo");
+const MATH_LEO: &str = include_str!("leo/math.leo");
 const CTX_LEO: &str = include_str!("leo/ctx.leo");
 const PROG_LEO: &str = include_str!("leo/prog.leo");
+const TEST_LEO: &str = include_str!("leo/test.leo");
 
 /// Entry source of the standard library (contents of `lib.leo`).
 pub fn entry_source() -> &'static str {
@@ -94,8 +96,10 @@ pub fn modules() -> &'static [(&'static str, &'static str)] {
         ("sig.leo", SIG_LEO),
         ("serialize.leo", SERIALIZE_LEO),
         ("grp.leo", GRP_LEO),
+        ("math.leo", MATH_LEO),
         ("ctx.leo", CTX_LEO),
         ("prog.leo", PROG_LEO),
+        ("test.leo", TEST_LEO),
     ]
 }
 
@@ -67,6 +67,11 @@ enum Commands {
         #[clap(flatten)]
         command: LeoRun,
     },
+    #[clap(about = "Step through a function with breakpoints, at a prompt or from an editor")]
+    Debug {
+        #[clap(flatten)]
+        command: LeoDebug,
+    },
     #[clap(about = "Test a Leo program", visible_alias = "t")]
     Test {
         #[clap(flatten)]
@@ -150,6 +155,7 @@ impl Commands {
             Commands::Account { .. } => "account",
             Commands::New { .. } => "new",
             Commands::Run { .. } => "run",
+            Commands::Debug { .. } => "debug",
             Commands::Test { .. } => "test",
             Commands::Execute { .. } => "execute",
             Commands::Deploy { .. } => "deploy",
@@ -196,8 +202,9 @@ enum Output {
 
 /// Run command with custom build arguments.
 pub fn run_with_args(cli: CLI) -> Result<()> {
-    // JSON output mode implies quiet mode.
-    let quiet = cli.quiet || cli.json_output.is_some();
+    // JSON output mode implies quiet mode, as does serving the debug adapter protocol over stdout.
+    let quiet =
+        cli.quiet || cli.json_output.is_some() || matches!(&cli.command, Commands::Debug { command } if command.dap);
 
     // Print the variables found in the `.env` files.
     if !quiet && let Ok(vars) = dotenvy::dotenv_iter().map(|v| v.flatten().collect::<Vec<_>>()) {
@@ -260,6 +267,7 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
         Commands::Devnet { command } => command.try_execute(context)?,
         Commands::Devnode { command } => command.try_execute(context)?,
         Commands::Run { command } => command_output = Some(Output::Run(command.execute(context)?)),
+        Commands::Debug { command } => command.try_execute(context)?,
         Commands::Test { command } => command_output = Some(Output::Test(command.execute(context)?)),
         Commands::Execute { command } => command_output = Some(Output::Execute(command.execute(context)?)),
         Commands::Plugins => crate::cli::plugin::print_all(),
@@ -17,7 +17,7 @@
 use super::*;
 
 use leo_ast::{NetworkName, NodeBuilder, Program, Stub};
-use leo_compiler::{Compiled, Compiler, CompilerOptions};
+use leo_compiler::{AleoSourceMap, Compiled, Compiler, CompilerOptions};
 use leo_package::{ABI_FILENAME, Package};
 use leo_span::Symbol;
 
@@ -294,18 +294,20 @@ fn handle_build(command: &LeoBuild, context: Context) -> Result<<LeoBuild as Com
                         if is_main { rename_target.clone() } else { None },
                     )?;
 
-                    // Write this unit's compiled bytecode. ABI and interface ABIs are
-                    // emitted only for the main program; tests deliberately skip them.
+                    // Write this unit's compiled bytecode and ABI. A test's ABI describes the
+                    // parameters `leo test` generates inputs for; interface ABIs are emitted
+                    // only for the main program.
                     let primary_path = package.unit_bytecode_path(&unit_name);
                     if written.insert(unit_key.clone()) {
                         ensure_parent_dir(&primary_path)?;
                         std::fs::write(&primary_path, &compiled.primary.bytecode)
                             .map_err(crate::errors::failed_to_load_instructions)?;
+                        write_source_map(&package, &unit_name, &compiled.primary.source_map)?;
+                        let abi_path = package.unit_abi_path(&unit_name);
+                        let abi_json = serde_json::to_string_pretty(&compiled.primary.abi)
+                            .map_err(|e| crate::errors::failed_to_serialize_abi(e.to_string()))?;
+                        std::fs::write(&abi_path, abi_json).map_err(crate::errors::failed_to_write_abi)?;
                         if is_main {
-                            let abi_path = package.unit_abi_path(&unit_name);
-                            let abi_json = serde_json::to_string_pretty(&compiled.primary.abi)
-                                .map_err(|e| crate::errors::failed_to_serialize_abi(e.to_string()))?;
-                            std::fs::write(&abi_path, abi_json).map_err(crate::errors::failed_to_write_abi)?;
                             tracing::info!("✅ Generated ABI for program '{unit_name}'.");
                             let interfaces_directory = package.unit_interfaces_directory(&unit_name);
                             write_interface_abis(&interfaces_directory, &compiled.interfaces)?;
@@ -320,6 +322,7 @@ fn handle_build(command: &LeoBuild, context: Context) -> Result<<LeoBuild as Com
                             ensure_parent_dir(&import_path)?;
                             std::fs::write(&import_path, &import.bytecode)
                                 .map_err(crate::errors::failed_to_load_instructions)?;
+                            write_source_map(&package, &import.name, &import.source_map)?;
 
                             let import_abi_path = package.unit_abi_path(&import.name);
                             let import_abi_json = serde_json::to_string_pretty(&import.abi)
@@ -440,6 +443,7 @@ fn handle_build(command: &LeoBuild, context: Context) -> Result<<LeoBuild as Com
         ensure_parent_dir(&primary_path)?;
         std::fs::write(&primary_path, &compiled.primary.bytecode)
             .map_err(crate::errors::failed_to_load_instructions)?;
+        write_source_map(&package, &unit_name, &compiled.primary.source_map)?;
         let abi_path = package.unit_abi_path(&unit_name);
         let abi_json = serde_json::to_string_pretty(&compiled.primary.abi)
             .map_err(|e| crate::errors::failed_to_serialize_abi(e.to_string()))?;
@@ -806,6 +810,15 @@ fn write_interface_abis(interfaces_dir: &Path, interfaces: &[leo_abi::interfaces
     Ok(())
 }
 
+/// Writes the source map for a unit's bytecode next to it, so failures can be traced back to Leo code.
+fn write_source_map(package: &Package, unit_name: &str, source_map: &AleoSourceMap) -> Result<()> {
+    let source_map = source_map.relative_to(&package.base_directory);
+    let source_map_json = serde_json::to_string(&source_map).map_err(crate::errors::failed_to_write_source_map)?;
+    std::fs::write(package.unit_source_map_path(unit_name), source_map_json)
+        .map_err(crate::errors::failed_to_write_source_map)?;
+    Ok(())
+}
+
 /// Ensure the parent directory of `path` exists, creating it if necessary.
 fn ensure_parent_dir(path: &Path) -> Result<()> {
     if let Some(parent) = path.parent() {
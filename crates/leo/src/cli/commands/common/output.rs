@@ -268,6 +268,8 @@ pub struct ExecuteOutput {
 pub struct TestOutput {
     pub passed: usize,
     pub failed: usize,
+    /// Tests left unrun once `--fail-fast` stopped the run.
+    pub skipped: usize,
     pub tests: Vec<TestResult>,
 }
 
@@ -276,8 +278,11 @@ pub struct TestOutput {
 pub struct TestResult {
     pub name: String,
     pub passed: bool,
+    pub skipped: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// The time spent running the test's cases, in seconds.
+    pub duration_secs: f64,
 }
 
 /// Output for `leo synthesize`.
@@ -53,6 +53,31 @@ pub fn print_program_source(id: &str, edition: Option<leo_package::Edition>) {
     }
 }
 
+/// Loads the source map `leo build` wrote next to a unit's bytecode.
+///
+/// A missing or unreadable map only means failures can't be traced back to Leo code, so it yields `None`.
+pub fn load_source_map(package: &leo_package::Package, unit_name: &str) -> Option<leo_compiler::AleoSourceMap> {
+    let source_map = std::fs::read_to_string(package.unit_source_map_path(unit_name)).ok()?;
+    serde_json::from_str::<leo_compiler::AleoSourceMap>(&source_map)
+        .ok()
+        .map(|map| map.resolve(&package.base_directory))
+}
+
+/// Appends the Leo location of the failing instruction to a snarkVM error from calling
+/// `program_name/function_name`, when the package has a source map for that program.
+pub fn annotate_failure(
+    package: Option<&leo_package::Package>,
+    program_name: &str,
+    function_name: &str,
+    error: impl std::fmt::Display,
+) -> String {
+    let message = error.to_string();
+    match package.and_then(|package| load_source_map(package, program_name)) {
+        Some(source_map) => source_map.annotate(&message, Some(function_name)),
+        None => message,
+    }
+}
+
 /// Checks if any programs violate edition/constructor requirements.
 ///
 /// Programs at edition 0 without a constructor cannot be executed after ConsensusVersion::V8.
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::NetworkName;
+use leo_debugger::{Breakpoints, Console, DapServer, DebugProgram, Debugger, Execution, FinalizeOutcome, render_value};
+
+use std::{
+    io::{BufReader, stdin, stdout},
+    path::Path,
+};
+
+/// Step through a Leo function, either at an interactive prompt or from an editor over the Debug Adapter Protocol.
+#[derive(Parser, Debug)]
+pub struct LeoDebug {
+    #[clap(
+        name = "NAME",
+        help = "The name of the function to debug, e.g `helloworld.aleo::main` or `main`.",
+        default_value = "main"
+    )]
+    pub(crate) name: String,
+    #[clap(name = "INPUTS", help = "The function inputs, e.g. `1u32` or `{ owner: aleo1..., amount: 5u64 }`.")]
+    pub(crate) inputs: Vec<String>,
+    #[clap(
+        long = "break",
+        short = 'b',
+        help = "Set a breakpoint at `[FILE:]LINE`; the file defaults to `src/main.leo`. May be repeated."
+    )]
+    pub(crate) breakpoints: Vec<String>,
+    #[clap(long, help = "Pause before the first statement instead of running to the first breakpoint.")]
+    pub(crate) stop_on_entry: bool,
+    #[clap(
+        long,
+        help = "Serve the Debug Adapter Protocol on stdin and stdout instead of showing a prompt. \
+            The function, inputs, and breakpoints then come from the editor's launch request."
+    )]
+    pub(crate) dap: bool,
+    #[clap(
+        long,
+        help = "The address reported as the signer and top-level caller. Defaults to the development account."
+    )]
+    pub(crate) signer: Option<String>,
+    #[clap(long, help = "The value `block.height` returns during finalize.", default_value = "0")]
+    pub(crate) block_height: u32,
+    #[clap(long, help = "The value `block.timestamp` returns during finalize.", default_value = "0")]
+    pub(crate) block_timestamp: i64,
+    #[clap(flatten)]
+    pub(crate) env_override: EnvOptions,
+}
+
+impl Command for LeoDebug {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // The debugger analyzes the source directly, so no build is needed beforehand.
+        let package_root = context.dir()?;
+        let entry_file = package_root.join("src").join("main.leo");
+        if !entry_file.exists() {
+            return Err(crate::errors::custom(format!(
+                "No program entry file at `{}`. Run `leo debug` from the root of a program package.",
+                entry_file.display()
+            ))
+            .into());
+        }
+
+        // Get the network, defaulting to `TestnetV0` if none is specified.
+        let network = get_network(&self.env_override.network).unwrap_or_else(|_| {
+            if !self.dap {
+                println!("⚠️ No network specified, defaulting to 'testnet'.");
+            }
+            NetworkName::TestnetV0
+        });
+
+        let mut debugger = Debugger::new(DebugProgram::load(&package_root, &entry_file, network)?);
+        if let Some(signer) = &self.signer {
+            debugger.environment.signer = signer
+                .parse()
+                .map_err(|()| crate::errors::custom(format!("`{signer}` is not a valid signer address.")))?;
+        }
+        debugger.environment.block_height = self.block_height;
+        debugger.environment.block_timestamp = self.block_timestamp;
+        for spec in &self.breakpoints {
+            let (path, line) = Breakpoints::parse_spec(spec, Path::new("src/main.leo"))?;
+            debugger.breakpoints.insert(path, line);
+        }
+
+        if self.dap { debug_over_dap(debugger) } else { debug_in_console(self, debugger) }
+    }
+}
+
+/// Runs the requested function at an interactive prompt on the terminal.
+fn debug_in_console(command: LeoDebug, mut debugger: Debugger) -> Result<()> {
+    let function = debugger.program().resolve_function(&command.name)?;
+    let inputs = debugger.parse_inputs(&function, &command.inputs)?;
+
+    println!("🐞 Debugging `{function}`. Type `help` at the prompt for commands.");
+    let mut console = Console::new(stdin().lock(), stdout());
+    match debugger.run(&function, inputs, &mut console, command.stop_on_entry)? {
+        Some(execution) => println!("{}", describe(&execution)),
+        None => println!("Debugging stopped."),
+    }
+    Ok(())
+}
+
+/// Serves one debugging session to an editor over stdin and stdout.
+fn debug_over_dap(mut debugger: Debugger) -> Result<()> {
+    let mut server = DapServer::new(BufReader::new(stdin()), stdout());
+    let launch = server.handshake(&mut debugger.breakpoints)?;
+
+    let result =
+        debugger.program().resolve_function(launch.function.as_deref().unwrap_or("main")).and_then(|function| {
+            let inputs = debugger.parse_inputs(&function, &launch.inputs.unwrap_or_default())?;
+            debugger.run(&function, inputs, &mut server, launch.stop_on_entry)
+        });
+
+    let exit_code = match &result {
+        Ok(Some(execution)) => {
+            server.output(&describe(execution))?;
+            i32::from(matches!(execution.finalize, FinalizeOutcome::Rejected(_)))
+        }
+        Ok(None) => 0,
+        Err(error) => {
+            server.output(&error.to_string())?;
+            1
+        }
+    };
+    server.finish(exit_code)
+}
+
+/// Summarizes a completed run for display.
+fn describe(execution: &Execution) -> String {
+    let output = format!("➡️  Output: {}", render_value(&execution.output));
+    match &execution.finalize {
+        FinalizeOutcome::Skipped => output,
+        FinalizeOutcome::Accepted => format!("{output}\n✅ Finalize succeeded."),
+        FinalizeOutcome::Rejected(error) => {
+            format!("{output}\n❌ Finalize was rejected; its state changes were rolled back.\n{error}")
+        }
+    }
+}
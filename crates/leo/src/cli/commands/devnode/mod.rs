@@ -17,6 +17,8 @@
 mod advance;
 pub mod logger;
 pub mod rest;
+mod revert;
+mod snapshot;
 mod start;
 
 use super::*;
@@ -39,6 +41,16 @@ pub enum DevnodeCommands {
         #[clap(flatten)]
         command: advance::Advance,
     },
+    #[clap(name = "snapshot", about = "Take a snapshot of the ledger to revert to later")]
+    Snapshot {
+        #[clap(flatten)]
+        command: snapshot::Snapshot,
+    },
+    #[clap(name = "revert", about = "Revert the ledger to a snapshot")]
+    Revert {
+        #[clap(flatten)]
+        command: revert::Revert,
+    },
 }
 
 /// Command for initializing and creating blocks for a local client node.
@@ -76,5 +88,22 @@ fn handle_devnode(devnode_command: LeoDevnode, context: Context) -> Result<<LeoD
             command.apply(context, private_key)
         }
         DevnodeCommands::Advance { command } => command.apply(context, ()),
+        DevnodeCommands::Snapshot { command } => command.apply(context, ()),
+        DevnodeCommands::Revert { command } => command.apply(context, ()),
+    }
+}
+
+// Sends `payload` to the snapshot endpoint at `path` of the Devnode running at `socket_addr`.
+fn post_to_devnode(socket_addr: &str, path: &str, payload: serde_json::Value) -> Result<rest::Snapshot> {
+    let response = reqwest::blocking::Client::new()
+        .post(format!("http://{socket_addr}/testnet/{path}"))
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .send()
+        .map_err(|e| crate::errors::custom(format!("Failed to reach the Devnode at {socket_addr}: {e}")))?;
+    if !response.status().is_success() {
+        let message = response.text().unwrap_or_default();
+        return Err(crate::errors::custom(format!("The Devnode rejected the request: {message}")).into());
     }
+    Ok(response.json().map_err(|e| crate::errors::custom(format!("Invalid response from the Devnode: {e}")))?)
 }
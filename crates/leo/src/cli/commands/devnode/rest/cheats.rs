@@ -0,0 +1,310 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Dev-only routes that change the ledger state directly, enabled with `leo devnode start --cheats`.
+use super::*;
+
+use snarkvm::{
+    prelude::{Address, Execution, Fee, Identifier, Literal, Plaintext, Transaction, U64, VM, Value, execution_cost},
+    synthesizer::program::FinalizeStoreTrait,
+};
+
+use axum::Json;
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::str::FromStr;
+
+/// A mapping entry set or deleted by a cheat rather than by a transaction.
+///
+/// Such writes are not part of any block, so they are recorded to be replayed when reverting to a snapshot.
+#[derive(Clone)]
+pub(crate) struct MappingWrite<N: Network> {
+    /// The height of the latest block when the write was made.
+    pub height: u32,
+    pub program_id: ProgramID<N>,
+    pub mapping: Identifier<N>,
+    pub key: Plaintext<N>,
+    /// The value written, or `None` if the entry was deleted.
+    pub value: Option<Value<N>>,
+}
+
+impl<N: Network> MappingWrite<N> {
+    /// Writes the entry to the finalize store of `vm`.
+    pub(crate) fn apply<C: ConsensusStorage<N>>(&self, vm: &VM<N, C>) -> anyhow::Result<()> {
+        let store = vm.finalize_store();
+        match &self.value {
+            Some(value) => {
+                store.update_key_value(self.program_id, self.mapping, self.key.clone(), value.clone())?;
+            }
+            None => {
+                if store.get_value_confirmed(self.program_id, self.mapping, &self.key)?.is_some() {
+                    store.remove_key_value(self.program_id, self.mapping, &self.key)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The request object for `fund` and `mint`.
+#[derive(Clone, Deserialize, Serialize)]
+pub(crate) struct FundRequest {
+    /// The address to credit.
+    address: String,
+    /// The amount in microcredits.
+    amount: u64,
+}
+
+/// The request object for `set_mapping`.
+#[derive(Clone, Deserialize, Serialize)]
+pub(crate) struct SetMappingRequest {
+    program: String,
+    mapping: String,
+    key: String,
+    /// The value to write; the entry is deleted if not set.
+    value: Option<String>,
+}
+
+/// The request object for `impersonate`.
+#[derive(Clone, Deserialize, Serialize)]
+pub(crate) struct ImpersonateRequest {
+    private_key: String,
+}
+
+/// The request object for `execute_as`.
+#[derive(Clone, Deserialize, Serialize)]
+pub(crate) struct ExecuteAsRequest {
+    /// The address of an impersonated account.
+    signer: String,
+    program: String,
+    function: String,
+    #[serde(default)]
+    inputs: Vec<String>,
+}
+
+/// Parses `input` as a `T`, reporting the request `field` it came from.
+fn parse<T: FromStr>(field: &str, input: &str) -> Result<T, RestError>
+where
+    T::Err: std::fmt::Display,
+{
+    input.parse().map_err(|e| RestError::unprocessable_entity(anyhow!("Invalid {field} '{input}': {e}")))
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// POST /{network}/cheats/fund
+    ///
+    /// Adds `amount` microcredits to the public balance of `address`.
+    pub(crate) async fn cheat_fund(
+        State(rest): State<Self>,
+        Json(req): Json<FundRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        rest.check_cheats()?;
+        let address: Address<N> = parse("address", &req.address)?;
+        let program_id: ProgramID<N> = parse("program", "credits.aleo")?;
+        let mapping: Identifier<N> = parse("mapping", "account")?;
+        let key = Plaintext::from(Literal::Address(address));
+
+        let balance = rest
+            .write_mapping(move |ledger| {
+                let balance = match ledger.vm().finalize_store().get_value_confirmed(program_id, mapping, &key)? {
+                    Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                    _ => 0,
+                };
+                let balance = balance.checked_add(req.amount).ok_or_else(|| {
+                    RestError::unprocessable_entity(anyhow!("The balance of {address} would overflow"))
+                })?;
+                let value = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(balance))));
+                let write =
+                    MappingWrite { height: ledger.latest_height(), program_id, mapping, key, value: Some(value) };
+                Ok((write, balance))
+            })
+            .await?;
+
+        Ok(ErasedJson::new(json!({ "address": address, "balance": balance })))
+    }
+
+    /// POST /{network}/cheats/mint
+    ///
+    /// Sends `amount` microcredits from the devnode's account to a new private record owned by `address`,
+    /// with a `credits.aleo/transfer_public_to_private` transaction.
+    pub(crate) async fn cheat_mint(
+        State(rest): State<Self>,
+        Json(req): Json<FundRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        rest.check_cheats()?;
+        let address: Address<N> = parse("address", &req.address)?;
+        let inputs = vec![
+            Value::Plaintext(Plaintext::from(Literal::Address(address))),
+            parse("amount", &format!("{}u64", req.amount))?,
+        ];
+
+        let signer = rest.private_key;
+        let builder = rest.clone();
+        let tx = tokio::task::spawn_blocking(move || {
+            builder.execute_without_proof(&signer, "credits.aleo", "transfer_public_to_private", &inputs)
+        })
+        .await
+        .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))??;
+        let tx_id = tx.id();
+        rest.add_transaction(tx).await?;
+
+        Ok(ErasedJson::new(tx_id))
+    }
+
+    /// POST /{network}/cheats/mapping
+    ///
+    /// Sets the entry `key` of a mapping of a deployed program to `value`, or deletes it if `value` is not set.
+    pub(crate) async fn cheat_set_mapping(
+        State(rest): State<Self>,
+        Json(req): Json<SetMappingRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        rest.check_cheats()?;
+        let program_id: ProgramID<N> = parse("program", &req.program)?;
+        let mapping: Identifier<N> = parse("mapping", &req.mapping)?;
+        let key: Plaintext<N> = parse("key", &req.key)?;
+        let value: Option<Value<N>> = req.value.as_deref().map(|value| parse("value", value)).transpose()?;
+
+        let written = value.clone();
+        rest.write_mapping(move |ledger| {
+            let program = ledger
+                .get_program(program_id)
+                .map_err(|_| RestError::not_found(anyhow!("Program `{program_id}` is not deployed")))?;
+            if !program.contains_mapping(&mapping) {
+                return Err(RestError::not_found(anyhow!("Program `{program_id}` has no mapping `{mapping}`")));
+            }
+            Ok((MappingWrite { height: ledger.latest_height(), program_id, mapping, key, value: written }, ()))
+        })
+        .await?;
+
+        Ok(ErasedJson::new(value))
+    }
+
+    /// POST /{network}/cheats/impersonate
+    ///
+    /// Lets `execute_as` sign transactions as the account of `private_key`, with the devnode paying their fees.
+    pub(crate) async fn cheat_impersonate(
+        State(rest): State<Self>,
+        Json(req): Json<ImpersonateRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        rest.check_cheats()?;
+        let private_key: PrivateKey<N> = parse("private key", &req.private_key)?;
+        let address = Address::try_from(&private_key).map_err(RestError::unprocessable_entity)?;
+        rest.impersonated.lock().insert(address, private_key);
+
+        Ok(ErasedJson::new(address))
+    }
+
+    /// POST /{network}/cheats/execute
+    ///
+    /// Executes `function` of `program` as the impersonated account `signer`, without a proof, and adds the
+    /// transaction to the ledger as a broadcast one would be.
+    pub(crate) async fn cheat_execute_as(
+        State(rest): State<Self>,
+        Json(req): Json<ExecuteAsRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        rest.check_cheats()?;
+        let address: Address<N> = parse("signer", &req.signer)?;
+        let signer = rest.impersonated.lock().get(&address).copied().ok_or_else(|| {
+            RestError::bad_request(anyhow!(
+                "{address} is not impersonated; POST its private key to `cheats/impersonate`"
+            ))
+        })?;
+        let inputs = req.inputs.iter().map(|input| parse::<Value<N>>("input", input)).collect::<Result<Vec<_>, _>>()?;
+
+        let builder = rest.clone();
+        let tx = tokio::task::spawn_blocking(move || {
+            builder.execute_without_proof(&signer, &req.program, &req.function, &inputs)
+        })
+        .await
+        .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))??;
+        let tx_id = tx.id();
+        rest.add_transaction(tx).await?;
+
+        Ok(ErasedJson::new(tx_id))
+    }
+
+    /// Returns an error unless the devnode was started with `--cheats`.
+    fn check_cheats(&self) -> Result<(), RestError> {
+        if self.cheats {
+            Ok(())
+        } else {
+            Err(RestError::bad_request(anyhow!("Cheats are disabled; start the devnode with `--cheats`")))
+        }
+    }
+
+    /// Applies the write `prepare` makes from the current ledger and records it, so that reverting to a later
+    /// snapshot replays it, returning what else `prepare` returned.
+    ///
+    /// The write is prepared and applied while no block is added and no revert runs, so it is neither made from
+    /// stale state nor lost.
+    async fn write_mapping<T: Send + 'static>(
+        &self,
+        prepare: impl FnOnce(&Ledger<N, C>) -> Result<(MappingWrite<N>, T), RestError> + Send + 'static,
+    ) -> Result<T, RestError> {
+        let rest = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let _updating = rest.updating.lock();
+            let ledger = rest.ledger();
+            let (write, output) = prepare(&ledger)?;
+            write
+                .apply(ledger.vm())
+                .map_err(|e| RestError::unprocessable_entity(e.context("Failed to write mapping")))?;
+            if rest.has_subscribers() {
+                let MappingWrite { height, program_id, mapping, key, value } = write.clone();
+                rest.publish(Event::Mapping { height, program: program_id, mapping, key, value });
+            }
+            rest.cheat_writes.lock().push(write);
+            Ok(output)
+        })
+        .await
+        .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))?
+    }
+
+    /// Builds a transaction executing `function` of `program` as `signer` without a proof, with the fee paid
+    /// publicly by the devnode's account.
+    fn execute_without_proof(
+        &self,
+        signer: &PrivateKey<N>,
+        program: &str,
+        function: &str,
+        inputs: &[Value<N>],
+    ) -> Result<Transaction<N>, RestError> {
+        let ledger = self.ledger();
+        let vm = ledger.vm();
+        let rng = &mut rand::rng();
+
+        let authorization = vm
+            .authorize(signer, program, function, inputs.iter(), rng)
+            .map_err(|e| RestError::unprocessable_entity(anyhow!("Failed to authorize {program}/{function}: {e}")))?;
+        let state_root = ledger.latest_state_root();
+        let execution = Execution::from(authorization.transitions().values().cloned(), state_root, None)?;
+
+        let consensus_version = N::CONSENSUS_VERSION(ledger.latest_height())?;
+        let (cost, _) = execution_cost(vm.process(), &execution, consensus_version)?;
+        let fee_authorization =
+            vm.authorize_fee_public(&self.private_key, cost, 0, authorization.to_execution_id()?, rng)?;
+        let (_, fee_transition) = fee_authorization
+            .transitions()
+            .into_iter()
+            .next()
+            .ok_or_else(|| RestError::internal_server_error(anyhow!("The fee authorization has no transition")))?;
+        let fee = Fee::from(fee_transition, state_root, None)?;
+
+        Ok(Transaction::from_execution(execution, Some(fee))?)
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The event stream pushed to clients of `GET /{network}/events` as Server-Sent Events.
+use super::*;
+
+use snarkvm::prelude::{
+    Block,
+    Identifier,
+    Plaintext,
+    Value,
+    block::{ConfirmedTransaction, Transition},
+};
+
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use tokio_stream::{
+    Stream,
+    StreamExt,
+    wrappers::{BroadcastStream, errors::BroadcastStreamRecvError},
+};
+
+use anyhow::anyhow;
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+
+/// The number of events buffered for each subscriber before the oldest are dropped.
+pub(crate) const EVENT_CAPACITY: usize = 1024;
+
+/// An event pushed to subscribers.
+#[derive(Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case", bound = "")]
+pub(crate) enum Event<N: Network> {
+    /// A block was added to the ledger.
+    Block {
+        height: u32,
+        hash: N::BlockHash,
+        /// The IDs of the transactions accepted or rejected in the block, as they were broadcast.
+        transactions: Vec<N::TransactionID>,
+        aborted: Vec<N::TransactionID>,
+    },
+    /// A transaction was accepted or rejected in a block.
+    Transaction {
+        height: u32,
+        id: N::TransactionID,
+        status: TransactionStatus,
+        kind: TransactionKind,
+        transitions: Vec<TransitionInfo<N>>,
+    },
+    /// A transaction was aborted, and so left out of the block.
+    Abort { height: u32, id: N::TransactionID, reason: String, transitions: Vec<TransitionInfo<N>> },
+    /// A mapping entry was set by a block or a cheat, or deleted if `value` is `None`.
+    Mapping { height: u32, program: ProgramID<N>, mapping: Identifier<N>, key: Plaintext<N>, value: Option<Value<N>> },
+    /// The ledger was reverted to a snapshot.
+    Revert { snapshot: u32, height: u32 },
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TransactionStatus {
+    Accepted,
+    Rejected,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TransactionKind {
+    Deploy,
+    Execute,
+}
+
+/// A transition of a transaction, as reported in events.
+#[derive(Clone, Serialize)]
+#[serde(bound = "")]
+pub(crate) struct TransitionInfo<N: Network> {
+    id: N::TransitionID,
+    program: ProgramID<N>,
+    function: Identifier<N>,
+}
+
+impl<N: Network> From<&Transition<N>> for TransitionInfo<N> {
+    fn from(transition: &Transition<N>) -> Self {
+        Self { id: *transition.id(), program: *transition.program_id(), function: *transition.function_name() }
+    }
+}
+
+impl<N: Network> Event<N> {
+    /// The name of the event in the stream.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Block { .. } => "block",
+            Self::Transaction { .. } => "transaction",
+            Self::Abort { .. } => "abort",
+            Self::Mapping { .. } => "mapping",
+            Self::Revert { .. } => "revert",
+        }
+    }
+}
+
+/// The `subscribe` query object.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct EventQuery {
+    /// Only send the events involving this program.
+    program: Option<String>,
+    /// Only send the updates of this mapping of `program`.
+    mapping: Option<String>,
+}
+
+/// The events a subscriber receives, besides those for blocks and reverts, which all subscribers receive.
+#[derive(Clone)]
+pub(crate) struct EventFilter<N: Network> {
+    program: Option<ProgramID<N>>,
+    mapping: Option<Identifier<N>>,
+}
+
+impl<N: Network> EventFilter<N> {
+    fn parse(query: &EventQuery) -> Result<Self, RestError> {
+        let program = query
+            .program
+            .as_deref()
+            .map(|program| {
+                program.parse().map_err(|e: anyhow::Error| {
+                    RestError::unprocessable_entity(e.context(format!("Invalid program ID '{program}'")))
+                })
+            })
+            .transpose()?;
+        let mapping = query
+            .mapping
+            .as_deref()
+            .map(|mapping| {
+                mapping.parse().map_err(|e: anyhow::Error| {
+                    RestError::unprocessable_entity(e.context(format!("Invalid mapping name '{mapping}'")))
+                })
+            })
+            .transpose()?;
+        if mapping.is_some() && program.is_none() {
+            return Err(RestError::bad_request(anyhow!("Subscribing to a mapping requires its `program`")));
+        }
+        Ok(Self { program, mapping })
+    }
+
+    /// Returns whether the subscriber receives `event`.
+    fn matches(&self, event: &Event<N>) -> bool {
+        let involves = |transitions: &[TransitionInfo<N>]| {
+            self.mapping.is_none() && self.program.is_none_or(|id| transitions.iter().any(|t| t.program == id))
+        };
+        match event {
+            Event::Block { .. } | Event::Revert { .. } => true,
+            Event::Transaction { transitions, .. } | Event::Abort { transitions, .. } => involves(transitions),
+            Event::Mapping { program, mapping, .. } => {
+                self.program.is_none_or(|id| id == *program) && self.mapping.is_none_or(|name| name == *mapping)
+            }
+        }
+    }
+}
+
+/// The ledger state needed to describe a block's events, read before the block is added.
+pub(crate) struct BlockWatch<N: Network> {
+    /// The events of the transactions aborted from the block.
+    aborts: Vec<Event<N>>,
+    /// The entries of the mappings the block may update.
+    mappings: Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// GET /{network}/events?program={program}&mapping={mapping}
+    ///
+    /// Streams events as they happen, with the event type as the SSE event name and the event as JSON data.
+    pub(crate) async fn subscribe(
+        State(rest): State<Self>,
+        Query(query): Query<EventQuery>,
+    ) -> Result<Sse<impl Stream<Item = Result<SseEvent, Infallible>>>, RestError> {
+        let filter = EventFilter::parse(&query)?;
+        let stream = BroadcastStream::new(rest.events.subscribe()).filter_map(move |event| match event {
+            Ok(event) if filter.matches(&event) => {
+                SseEvent::default().event(event.name()).json_data(&*event).ok().map(Ok)
+            }
+            Ok(_) => None,
+            // Tell the subscriber how many events it missed by not keeping up.
+            Err(BroadcastStreamRecvError::Lagged(missed)) => {
+                Some(Ok(SseEvent::default().event("lagged").data(missed.to_string())))
+            }
+        });
+        Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+    }
+
+    /// Whether any client is subscribed to events, which are only built if so.
+    pub(crate) fn has_subscribers(&self) -> bool {
+        self.events.receiver_count() > 0
+    }
+
+    /// Sends `event` to the subscribers.
+    pub(crate) fn publish(&self, event: Event<N>) {
+        // Sending only fails if there are no subscribers.
+        let _ = self.events.send(Arc::new(event));
+    }
+
+    /// Reads the state needed to describe the events of `block`, which was prepared from `txs` and is about to
+    /// be added to `ledger`.
+    pub(crate) fn watch_block(
+        &self,
+        ledger: &Ledger<N, C>,
+        block: &Block<N>,
+        txs: &[Transaction<N>],
+    ) -> anyhow::Result<BlockWatch<N>> {
+        // Only aborted transactions are checked again, to find out why they were aborted.
+        let mut aborts = Vec::new();
+        for id in block.aborted_transaction_ids() {
+            let Some(tx) = txs.iter().find(|tx| tx.id() == *id) else { continue };
+            let reason = match ledger.vm().check_transaction(tx, None, &mut rand::rng()) {
+                Err(e) => e.to_string(),
+                Ok(()) => "The transaction could not be finalized in the block".to_string(),
+            };
+            let transitions = tx.transitions().map(TransitionInfo::from).collect();
+            aborts.push(Event::Abort { height: block.height(), id: *id, reason, transitions });
+        }
+
+        // Finalize only updates the mappings of the programs in the block's transitions, including `credits.aleo`
+        // for the fees.
+        let programs: IndexSet<ProgramID<N>> = block
+            .transactions()
+            .iter()
+            .flat_map(|confirmed| confirmed.transaction().transitions().map(|transition| *transition.program_id()))
+            .collect();
+        let mut mappings = Vec::new();
+        for program_id in programs {
+            let program = ledger.get_program(program_id)?;
+            for name in program.mappings().keys() {
+                let entries = ledger.vm().finalize_store().get_mapping_confirmed(program_id, *name)?;
+                mappings.push((program_id, *name, entries));
+            }
+        }
+
+        Ok(BlockWatch { aborts, mappings })
+    }
+
+    /// Sends the events of `block`, which has been added to `ledger`, to the subscribers.
+    pub(crate) fn publish_block(
+        &self,
+        ledger: &Ledger<N, C>,
+        block: &Block<N>,
+        watch: BlockWatch<N>,
+    ) -> anyhow::Result<()> {
+        let height = block.height();
+        let mut events = Vec::new();
+        let mut transactions = Vec::new();
+        for confirmed in block.transactions().iter() {
+            let id = confirmed.to_unconfirmed_transaction_id()?;
+            let status =
+                if confirmed.is_accepted() { TransactionStatus::Accepted } else { TransactionStatus::Rejected };
+            let kind = match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::RejectedDeploy(..) => {
+                    TransactionKind::Deploy
+                }
+                ConfirmedTransaction::AcceptedExecute(..) | ConfirmedTransaction::RejectedExecute(..) => {
+                    TransactionKind::Execute
+                }
+            };
+            let transitions = confirmed.to_unconfirmed_transaction()?.transitions().map(TransitionInfo::from).collect();
+            transactions.push(id);
+            events.push(Event::Transaction { height, id, status, kind, transitions });
+        }
+        events.extend(watch.aborts);
+
+        // Compare each mapping's entries to those before the block.
+        for (program, mapping, before) in watch.mappings {
+            let mut before: IndexMap<String, Value<N>> =
+                before.into_iter().map(|(key, value)| (key.to_string(), value)).collect();
+            for (key, value) in ledger.vm().finalize_store().get_mapping_confirmed(program, mapping)? {
+                if before.shift_remove(&key.to_string()).is_none_or(|old| old != value) {
+                    events.push(Event::Mapping { height, program, mapping, key, value: Some(value) });
+                }
+            }
+            for key in before.into_keys() {
+                let key = key.parse()?;
+                events.push(Event::Mapping { height, program, mapping, key, value: None });
+            }
+        }
+
+        self.publish(Event::Block {
+            height,
+            hash: block.hash(),
+            transactions,
+            aborted: block.aborted_transaction_ids().clone(),
+        });
+        events.into_iter().for_each(|event| self.publish(event));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm::prelude::TestnetV0;
+
+    fn filter(program: Option<&str>, mapping: Option<&str>) -> Result<EventFilter<TestnetV0>, RestError> {
+        EventFilter::parse(&EventQuery { program: program.map(String::from), mapping: mapping.map(String::from) })
+    }
+
+    fn mapping_event(program: &str, mapping: &str) -> Event<TestnetV0> {
+        Event::Mapping {
+            height: 1,
+            program: program.parse().unwrap(),
+            mapping: mapping.parse().unwrap(),
+            key: "1u8".parse().unwrap(),
+            value: None,
+        }
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let revert = Event::Revert { snapshot: 0, height: 1 };
+        let all = filter(None, None).unwrap();
+        assert!(all.matches(&revert));
+        assert!(all.matches(&mapping_event("token.aleo", "balances")));
+
+        let program = filter(Some("token.aleo"), None).unwrap();
+        assert!(program.matches(&revert));
+        assert!(program.matches(&mapping_event("token.aleo", "balances")));
+        assert!(!program.matches(&mapping_event("credits.aleo", "account")));
+
+        let mapping = filter(Some("token.aleo"), Some("balances")).unwrap();
+        assert!(mapping.matches(&mapping_event("token.aleo", "balances")));
+        assert!(!mapping.matches(&mapping_event("token.aleo", "supply")));
+
+        assert!(filter(None, Some("balances")).is_err());
+        assert!(filter(Some("token"), None).is_err());
+    }
+}
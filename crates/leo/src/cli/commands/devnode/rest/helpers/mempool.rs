@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The transactions waiting for the next block, in the order they will be added to it.
+#[derive(Debug)]
+pub struct Mempool<I, T> {
+    pending: Vec<(I, T)>,
+}
+
+impl<I, T> Default for Mempool<I, T> {
+    fn default() -> Self {
+        Self { pending: Vec::new() }
+    }
+}
+
+impl<I: Copy + PartialEq, T> Mempool<I, T> {
+    /// Adds the transaction `tx` with the given `id` to the end of the mempool.
+    pub fn push(&mut self, id: I, tx: T) {
+        self.pending.push((id, tx));
+    }
+
+    /// The pending transactions, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &(I, T)> {
+        self.pending.iter()
+    }
+
+    /// Removes all pending transactions.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Removes and returns the transactions with the given `ids`, in that order, or all of them if `ids` is `None`.
+    ///
+    /// Returns the first ID that is not pending, or is given twice, without removing anything.
+    pub fn take(&mut self, ids: Option<&[I]>) -> Result<Vec<T>, I> {
+        let taken = match ids {
+            Some(ids) => self.extract(ids)?,
+            None => std::mem::take(&mut self.pending),
+        };
+        Ok(taken.into_iter().map(|(_, tx)| tx).collect())
+    }
+
+    /// Moves the transactions with the given `ids` to the front of the mempool, in that order, keeping the order of
+    /// the others after them.
+    ///
+    /// Returns the first ID that is not pending, or is given twice, without moving anything.
+    pub fn reorder(&mut self, ids: &[I]) -> Result<(), I> {
+        let mut front = self.extract(ids)?;
+        front.append(&mut self.pending);
+        self.pending = front;
+        Ok(())
+    }
+
+    /// Removes the entries with the given `ids`, returning them in that order.
+    fn extract(&mut self, ids: &[I]) -> Result<Vec<(I, T)>, I> {
+        let mut positions = Vec::with_capacity(ids.len());
+        for id in ids {
+            match self.pending.iter().position(|(pending, _)| pending == id) {
+                Some(position) if !positions.contains(&position) => positions.push(position),
+                _ => return Err(*id),
+            }
+        }
+
+        let mut slots: Vec<_> = std::mem::take(&mut self.pending).into_iter().map(Some).collect();
+        let extracted = positions.iter().filter_map(|position| slots[*position].take()).collect();
+        self.pending = slots.into_iter().flatten().collect();
+        Ok(extracted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mempool(ids: &[u32]) -> Mempool<u32, String> {
+        let mut mempool = Mempool::default();
+        ids.iter().for_each(|id| mempool.push(*id, format!("tx{id}")));
+        mempool
+    }
+
+    fn ids(mempool: &Mempool<u32, String>) -> Vec<u32> {
+        mempool.iter().map(|(id, _)| *id).collect()
+    }
+
+    #[test]
+    fn test_reorder_moves_to_front() {
+        let mut mempool = mempool(&[1, 2, 3, 4]);
+        mempool.reorder(&[3, 1]).unwrap();
+        assert_eq!(ids(&mempool), [3, 1, 2, 4]);
+
+        // Unknown and repeated IDs are rejected without changing the order.
+        assert_eq!(mempool.reorder(&[2, 5]), Err(5));
+        assert_eq!(mempool.reorder(&[4, 4]), Err(4));
+        assert_eq!(ids(&mempool), [3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut mempool = mempool(&[1, 2, 3, 4]);
+        assert_eq!(mempool.take(Some(&[4, 2])), Ok(vec!["tx4".to_string(), "tx2".to_string()]));
+        assert_eq!(ids(&mempool), [1, 3]);
+
+        assert_eq!(mempool.take(Some(&[2])), Err(2));
+        assert_eq!(mempool.take(None), Ok(vec!["tx1".to_string(), "tx3".to_string()]));
+        assert!(ids(&mempool).is_empty());
+    }
+}
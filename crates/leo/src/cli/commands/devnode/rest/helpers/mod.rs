@@ -17,5 +17,11 @@
 mod error;
 pub(crate) use error::*;
 
+mod mempool;
+pub(crate) use mempool::*;
+
 mod path;
 pub(crate) use path::Path;
+
+mod snapshots;
+pub(crate) use snapshots::*;
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use serde::{Deserialize, Serialize};
+
+/// A checkpoint of the ledger that the devnode can revert to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Snapshot {
+    /// The snapshot's ID, unique for the lifetime of the devnode.
+    pub id: u32,
+    /// The height of the latest block when the snapshot was taken.
+    pub height: u32,
+    /// The number of mapping writes made by cheats when the snapshot was taken, which a revert replays.
+    #[serde(skip)]
+    pub writes: usize,
+}
+
+/// The snapshots taken of the ledger, oldest first.
+#[derive(Debug, Default)]
+pub struct Snapshots {
+    next_id: u32,
+    taken: Vec<Snapshot>,
+}
+
+impl Snapshots {
+    /// Records a snapshot of the ledger at `height`, after the first `writes` mapping writes made by cheats.
+    pub fn take(&mut self, height: u32, writes: usize) -> Snapshot {
+        let snapshot = Snapshot { id: self.next_id, height, writes };
+        self.next_id += 1;
+        self.taken.push(snapshot);
+        snapshot
+    }
+
+    /// The snapshots that can be reverted to.
+    pub fn all(&self) -> &[Snapshot] {
+        &self.taken
+    }
+
+    /// The snapshot with the given `id`, or the latest snapshot if `id` is `None`.
+    pub fn get(&self, id: Option<u32>) -> Option<Snapshot> {
+        match id {
+            Some(id) => self.taken.iter().find(|snapshot| snapshot.id == id).copied(),
+            None => self.taken.last().copied(),
+        }
+    }
+
+    /// Forgets the snapshots taken after `snapshot`, once the ledger has been reverted to it.
+    /// The snapshot itself is kept, so the ledger can be reverted to it again.
+    pub fn revert_to(&mut self, snapshot: Snapshot) {
+        self.taken.retain(|taken| taken.id <= snapshot.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revert_forgets_later_snapshots() {
+        let mut snapshots = Snapshots::default();
+        let first = snapshots.take(16, 0);
+        let second = snapshots.take(20, 1);
+        snapshots.take(25, 1);
+
+        assert_eq!(snapshots.get(None).map(|snapshot| snapshot.height), Some(25));
+        assert_eq!(snapshots.get(Some(second.id)), Some(second));
+
+        snapshots.revert_to(second);
+        assert_eq!(snapshots.all(), [first, second]);
+
+        // IDs are not reused once a snapshot is forgotten.
+        assert_eq!(snapshots.take(21, 1).id, 3);
+        assert_eq!(snapshots.get(Some(2)), None);
+    }
+}
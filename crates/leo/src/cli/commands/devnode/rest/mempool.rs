@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Routes to inspect and rearrange the transactions waiting for the next block.
+use super::*;
+
+use super::events::{TransactionKind, TransitionInfo};
+
+use axum::Json;
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+/// A transaction waiting for the next block.
+#[derive(Clone, Serialize)]
+#[serde(bound = "")]
+pub(crate) struct PendingTransaction<N: Network> {
+    id: N::TransactionID,
+    kind: TransactionKind,
+    transitions: Vec<TransitionInfo<N>>,
+}
+
+/// The request object for `reorder_mempool` and `drop_from_mempool`.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub(crate) struct MempoolRequest<N: Network> {
+    transactions: Vec<N::TransactionID>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// GET /{network}/mempool
+    ///
+    /// Lists the transactions waiting for the next block, in the order they will be added to it.
+    pub(crate) async fn get_mempool(State(rest): State<Self>) -> ErasedJson {
+        ErasedJson::new(rest.pending_transactions())
+    }
+
+    /// POST /{network}/mempool/reorder
+    ///
+    /// Moves the given pending `transactions` to the front of the mempool, in that order, and lists the mempool.
+    pub(crate) async fn reorder_mempool(
+        State(rest): State<Self>,
+        Json(req): Json<MempoolRequest<N>>,
+    ) -> Result<ErasedJson, RestError> {
+        rest.mempool.lock().reorder(&req.transactions).map_err(not_pending)?;
+        Ok(ErasedJson::new(rest.pending_transactions()))
+    }
+
+    /// POST /{network}/mempool/drop
+    ///
+    /// Removes the given pending `transactions`, so that they are never added to a block.
+    pub(crate) async fn drop_from_mempool(
+        State(rest): State<Self>,
+        Json(req): Json<MempoolRequest<N>>,
+    ) -> Result<ErasedJson, RestError> {
+        rest.mempool.lock().take(Some(&req.transactions)).map_err(not_pending)?;
+        Ok(ErasedJson::new(req.transactions))
+    }
+
+    /// Describes the pending transactions, in order.
+    fn pending_transactions(&self) -> Vec<PendingTransaction<N>> {
+        self.mempool
+            .lock()
+            .iter()
+            .map(|(id, tx)| PendingTransaction {
+                id: *id,
+                kind: if tx.is_deploy() { TransactionKind::Deploy } else { TransactionKind::Execute },
+                transitions: tx.transitions().map(TransitionInfo::from).collect(),
+            })
+            .collect()
+    }
+}
+
+/// The error for a transaction ID that is not pending, or is given twice.
+pub(crate) fn not_pending<I: std::fmt::Display>(id: I) -> RestError {
+    RestError::unprocessable_entity(anyhow!("Transaction '{id}' is not in the mempool, or is given more than once"))
+}
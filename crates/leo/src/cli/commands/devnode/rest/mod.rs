@@ -17,13 +17,25 @@
 #![forbid(unsafe_code)]
 
 mod helpers;
+pub(crate) use helpers::Snapshot;
 use helpers::*;
 
+mod cheats;
+use cheats::MappingWrite;
+
+mod events;
+use events::{EVENT_CAPACITY, Event};
+
+mod mempool;
+
 mod routes;
 
+mod trace;
+pub(crate) use trace::{MappingAccess, Trace};
+
 use snarkvm::{
     console::{program::ProgramID, types::Field},
-    prelude::{Ledger, Network, PrivateKey, Transaction, store::ConsensusStorage},
+    prelude::{Address, Ledger, Network, PrivateKey, Transaction, store::ConsensusStorage},
 };
 
 use super::*;
@@ -39,41 +51,77 @@ use axum::{
 };
 use axum_extra::response::ErasedJson;
 
-use parking_lot::Mutex;
+use indexmap::IndexMap;
+use parking_lot::{Mutex, RwLock};
 use std::{
     net::SocketAddr,
     sync::{Arc, atomic::AtomicUsize},
+    time::Duration,
+};
+use tokio::{
+    net::TcpListener,
+    sync::broadcast,
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
 };
-use tokio::{net::TcpListener, task::JoinHandle};
 // use tower::util::ServiceExt;
 use tower_governor::{GovernorLayer, governor::GovernorConfigBuilder};
 use tower_http::{
     cors::{Any, CorsLayer},
     trace::TraceLayer,
 };
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// The API version prefixes.
 pub const API_VERSION_V1: &str = "v1";
 pub const API_VERSION_V2: &str = "v2";
 
+/// Loads a fresh ledger holding only the genesis block.
+pub type LedgerLoader<N, C> = Arc<dyn Fn() -> anyhow::Result<Ledger<N, C>> + Send + Sync>;
+
+/// When the devnode creates blocks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlockCreation {
+    /// A block is created for each broadcast transaction.
+    OnBroadcast,
+    /// Blocks are only created when requested, holding the transactions broadcast since the last one.
+    Manual,
+    /// A block is created at every interval, holding the transactions broadcast since the last one.
+    Interval(Duration),
+}
+
 /// A REST API server for the ledger.
 #[derive(Clone)]
 pub struct Rest<N: Network, C: ConsensusStorage<N>> {
-    /// The ledger.
-    ledger: Ledger<N, C>,
-    /// A buffer for pending transactions to be added to the next block.
-    buffer: Arc<Mutex<Vec<Transaction<N>>>>,
+    /// The ledger, replaced by a rebuilt one when reverting to a snapshot.
+    ledger: Arc<RwLock<Ledger<N, C>>>,
+    /// Loads the ledger a revert rebuilds the chain on, if the ledger can be reverted.
+    loader: Option<LedgerLoader<N, C>>,
+    /// The snapshots taken of the ledger.
+    snapshots: Arc<Mutex<Snapshots>>,
+    /// The pending transactions to be added to the next block.
+    mempool: Arc<Mutex<Mempool<N::TransactionID, Transaction<N>>>>,
+    /// Held while the ledger changes: while a block is added, a cheat writes a mapping, or a revert rebuilds the
+    /// ledger. Changes therefore happen one at a time, and none is made to a ledger a revert is replacing.
+    updating: Arc<Mutex<()>>,
     /// The server handles.
     handles: Arc<Mutex<Vec<JoinHandle<()>>>>,
     /// The number of ongoing deploy transaction verifications via REST.
     num_verifying_deploys: Arc<AtomicUsize>,
     /// The number of ongoing execute transaction verifications via REST.
     num_verifying_executions: Arc<AtomicUsize>,
-    /// Whether manual block creation is enabled.
-    manual_block_creation: bool,
+    /// When blocks are created.
+    block_creation: BlockCreation,
     /// The Private Key used for block creation.
     private_key: PrivateKey<N>,
+    /// Whether the dev-only `/cheats` routes are enabled.
+    cheats: bool,
+    /// The mapping writes made by cheats, in order.
+    cheat_writes: Arc<Mutex<Vec<MappingWrite<N>>>>,
+    /// The accounts that cheats can execute transactions as.
+    impersonated: Arc<Mutex<IndexMap<Address<N>, PrivateKey<N>>>>,
+    /// The sender of the events streamed to subscribers.
+    events: broadcast::Sender<Arc<Event<N>>>,
 }
 
 impl<N: Network, C: 'static + ConsensusStorage<N>> Rest<N, C> {
@@ -82,27 +130,45 @@ impl<N: Network, C: 'static + ConsensusStorage<N>> Rest<N, C> {
         rest_ip: SocketAddr,
         rest_rps: u32,
         ledger: Ledger<N, C>,
-        manual_block_creation: bool,
+        block_creation: BlockCreation,
         private_key: PrivateKey<N>,
+        loader: Option<LedgerLoader<N, C>>,
+        cheats: bool,
     ) -> Result<Self> {
         // Initialize the server.
         let mut server = Self {
-            ledger,
-            buffer: Arc::new(Mutex::new(Vec::new())),
+            ledger: Arc::new(RwLock::new(ledger)),
+            loader,
+            snapshots: Default::default(),
+            mempool: Default::default(),
+            updating: Default::default(),
             handles: Default::default(),
             num_verifying_deploys: Default::default(),
             num_verifying_executions: Default::default(),
-            manual_block_creation,
+            block_creation,
             private_key,
+            cheats,
+            cheat_writes: Default::default(),
+            impersonated: Default::default(),
+            events: broadcast::channel(EVENT_CAPACITY).0,
         };
         // Spawn the server.
         server.spawn_server(rest_ip, rest_rps).await?;
+        // Spawn the block creation task, if blocks are created at an interval.
+        if let BlockCreation::Interval(period) = block_creation {
+            server.spawn_miner(period);
+        }
         // Return the server.
         Ok(server)
     }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// The current ledger.
+    fn ledger(&self) -> Ledger<N, C> {
+        self.ledger.read().clone()
+    }
+
     fn build_routes(&self, rest_rps: u32) -> axum::Router {
         let cors = CorsLayer::new()
             .allow_origin(Any)
@@ -147,11 +213,32 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
             .route("/block/{height_or_hash}/transactions", get(Self::get_block_transactions))
             .route("/block/create", post(Self::create_block))
 
+            // GET and POST ../snapshot..
+            .route("/snapshot", post(Self::create_snapshot))
+            .route("/snapshots", get(Self::get_snapshots))
+            .route("/snapshot/revert", post(Self::revert_snapshot))
+
+            // POST ../cheats/..
+            .route("/cheats/fund", post(Self::cheat_fund))
+            .route("/cheats/mint", post(Self::cheat_mint))
+            .route("/cheats/mapping", post(Self::cheat_set_mapping))
+            .route("/cheats/impersonate", post(Self::cheat_impersonate))
+            .route("/cheats/execute", post(Self::cheat_execute_as))
+
+            // GET and POST ../mempool..
+            .route("/mempool", get(Self::get_mempool))
+            .route("/mempool/reorder", post(Self::reorder_mempool))
+            .route("/mempool/drop", post(Self::drop_from_mempool))
+
+            // GET ../events
+            .route("/events", get(Self::subscribe))
+
             // GET and POST ../transaction/..
             .route("/transaction/broadcast", post(Self::transaction_broadcast))
             .route("/transaction/confirmed/{id}", get(Self::get_confirmed_transaction))
             .route("/transaction/unconfirmed/{id}", get(Self::get_unconfirmed_transaction))
             .route("/transaction/{id}", get(Self::get_transaction))
+            .route("/transaction/{id}/trace", get(Self::get_transaction_trace))
 
             // GET ../find/..
             .route("/find/blockHash/{tx_id}", get(Self::find_block_hash))
@@ -193,6 +280,28 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
             .layer(governor_layer)
     }
 
+    /// Creates a block holding the pending transactions every `period`, starting one `period` from now.
+    fn spawn_miner(&mut self, period: Duration) {
+        let rest = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut ticker = interval(period);
+            // If creating a block takes longer than `period`, wait a full `period` before creating the next one.
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick completes immediately.
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let rest = rest.clone();
+                match tokio::task::spawn_blocking(move || rest.mine(None)).await {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => warn!("Failed to create a block: {e:?}"),
+                    Err(e) => warn!("The block creation task panicked: {e}"),
+                }
+            }
+        });
+        self.handles.lock().push(handle);
+    }
+
     async fn spawn_server(&mut self, rest_ip: SocketAddr, rest_rps: u32) -> Result<()> {
         // Log the REST rate limit per IP.
         debug!("REST rate limit per IP - {rest_rps} RPS");
@@ -16,7 +16,18 @@
 use super::*;
 
 use snarkvm::{
-    prelude::{ConsensusVersion, Identifier, LimitedWriter, Plaintext, Program, ToBytes, Transaction, VM, Value},
+    prelude::{
+        Block,
+        ConsensusVersion,
+        Identifier,
+        LimitedWriter,
+        Plaintext,
+        Program,
+        ToBytes,
+        Transaction,
+        VM,
+        Value,
+    },
     synthesizer::program::{FinalizeGlobalState, StackTrait},
 };
 
@@ -84,30 +95,40 @@ pub(crate) struct Commitments {
 
 /// The request object for creating a new block.
 #[derive(Clone, Deserialize, Serialize)]
-pub(crate) struct CreateBlockRequest {
+#[serde(bound = "")]
+pub(crate) struct CreateBlockRequest<N: Network> {
     /// number of blocks to create.
     pub num_blocks: Option<u32>,
+    /// The pending transactions to add to the first block, in order, instead of all of them.
+    pub transactions: Option<Vec<N::TransactionID>>,
+}
+
+/// The request object for reverting to a snapshot.
+#[derive(Clone, Deserialize, Serialize)]
+pub(crate) struct RevertRequest {
+    /// The snapshot to revert to, the latest one if not set.
+    pub id: Option<u32>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
     /// Get /<network>/consensus_version
     pub(crate) async fn get_consensus_version(State(rest): State<Self>) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(N::CONSENSUS_VERSION(rest.ledger.latest_height())? as u16))
+        Ok(ErasedJson::new(N::CONSENSUS_VERSION(rest.ledger().latest_height())? as u16))
     }
 
     /// GET /<network>/block/height/latest
     pub(crate) async fn get_block_height_latest(State(rest): State<Self>) -> ErasedJson {
-        ErasedJson::new(rest.ledger.latest_height())
+        ErasedJson::new(rest.ledger().latest_height())
     }
 
     /// GET /<network>/block/hash/latest
     pub(crate) async fn get_block_hash_latest(State(rest): State<Self>) -> ErasedJson {
-        ErasedJson::new(rest.ledger.latest_hash())
+        ErasedJson::new(rest.ledger().latest_hash())
     }
 
     /// GET /<network>/block/latest
     pub(crate) async fn get_block_latest(State(rest): State<Self>) -> ErasedJson {
-        ErasedJson::new(rest.ledger.latest_block())
+        ErasedJson::new(rest.ledger().latest_block())
     }
 
     /// GET /<network>/block/{height}
@@ -119,9 +140,9 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         // Manually parse the height or the height of the hash, axum doesn't support different types
         // for the same path param.
         let block = if let Ok(height) = height_or_hash.parse::<u32>() {
-            rest.ledger.get_block(height).with_context(|| "Failed to get block by height")?
+            rest.ledger().get_block(height).with_context(|| "Failed to get block by height")?
         } else if let Ok(hash) = height_or_hash.parse::<N::BlockHash>() {
-            rest.ledger.get_block_by_hash(&hash).with_context(|| "Failed to get block by hash")?
+            rest.ledger().get_block_by_hash(&hash).with_context(|| "Failed to get block by hash")?
         } else {
             return Err(RestError::bad_request(anyhow!(
                 "invalid input, it is neither a block height nor a block hash"
@@ -155,10 +176,11 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         }
 
         // Prepare a closure for the blocking work.
+        let ledger = rest.ledger();
         let get_json_blocks = move || -> Result<ErasedJson, RestError> {
             let blocks = (start_height..end_height)
                 .into_par_iter()
-                .map(|height| rest.ledger.get_block(height))
+                .map(|height| ledger.get_block(height))
                 .collect::<Result<Vec<_>, _>>()?;
 
             Ok(ErasedJson::new(blocks))
@@ -182,7 +204,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(hash): Path<N::BlockHash>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.get_height(&hash)?))
+        Ok(ErasedJson::new(rest.ledger().get_height(&hash)?))
     }
 
     /// GET /<network>/block/{height}/header
@@ -190,7 +212,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(height): Path<u32>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.get_header(height)?))
+        Ok(ErasedJson::new(rest.ledger().get_header(height)?))
     }
 
     /// GET /<network>/block/{height}/transactions
@@ -198,7 +220,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(height): Path<u32>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.get_transactions(height)?))
+        Ok(ErasedJson::new(rest.ledger().get_transactions(height)?))
     }
 
     /// GET /<network>/transaction/{transactionID}
@@ -207,7 +229,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         Path(tx_id): Path<N::TransactionID>,
     ) -> Result<ErasedJson, RestError> {
         // Ledger returns a generic anyhow::Error, so checking the message is the only way to parse it.
-        Ok(ErasedJson::new(rest.ledger.get_transaction(tx_id).map_err(|err| {
+        Ok(ErasedJson::new(rest.ledger().get_transaction(tx_id).map_err(|err| {
             if err.to_string().contains("Missing") { RestError::not_found(err) } else { RestError::from(err) }
         })?))
     }
@@ -218,7 +240,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         Path(tx_id): Path<N::TransactionID>,
     ) -> Result<ErasedJson, RestError> {
         // Ledger returns a generic anyhow::Error, so checking the message is the only way to parse it.
-        Ok(ErasedJson::new(rest.ledger.get_confirmed_transaction(tx_id).map_err(|err| {
+        Ok(ErasedJson::new(rest.ledger().get_confirmed_transaction(tx_id).map_err(|err| {
             if err.to_string().contains("Missing") { RestError::not_found(err) } else { RestError::from(err) }
         })?))
     }
@@ -229,7 +251,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         Path(tx_id): Path<N::TransactionID>,
     ) -> Result<ErasedJson, RestError> {
         // Ledger returns a generic anyhow::Error, so checking the message is the only way to parse it.
-        Ok(ErasedJson::new(rest.ledger.get_unconfirmed_transaction(&tx_id).map_err(|err| {
+        Ok(ErasedJson::new(rest.ledger().get_unconfirmed_transaction(&tx_id).map_err(|err| {
             if err.to_string().contains("Missing") { RestError::not_found(err) } else { RestError::from(err) }
         })?))
     }
@@ -242,11 +264,11 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         metadata: Query<Metadata>,
     ) -> Result<ErasedJson, RestError> {
         // Get the program from the ledger.
-        let program = rest.ledger.get_program(id).with_context(|| format!("Failed to find program `{id}`"))?;
+        let program = rest.ledger().get_program(id).with_context(|| format!("Failed to find program `{id}`"))?;
         // Check if metadata is requested and return the program with metadata if so.
         if metadata.metadata.unwrap_or(false) {
             // Get the edition of the program.
-            let edition = rest.ledger.get_latest_edition_for_program(&id)?;
+            let edition = rest.ledger().get_latest_edition_for_program(&id)?;
             return rest.return_program_with_metadata(program, edition);
         }
         // Return the program without metadata.
@@ -262,7 +284,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
     ) -> Result<ErasedJson, RestError> {
         // Get the program from the ledger.
         match rest
-            .ledger
+            .ledger()
             .try_get_program_for_edition(&id, edition)
             .with_context(|| format!("Failed get program `{id}` for edition {edition}"))?
         {
@@ -283,12 +305,12 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
     fn return_program_with_metadata(&self, program: Program<N>, edition: u16) -> Result<ErasedJson, RestError> {
         let id = program.id();
         // Get the transaction ID associated with the program and edition.
-        let tx_id = self.ledger.find_latest_transaction_id_from_program_id_and_edition(id, edition)?;
+        let tx_id = self.ledger().find_latest_transaction_id_from_program_id_and_edition(id, edition)?;
         // Get the optional program owner associated with the program.
         // Note: The owner is only available after `ConsensusVersion::V9`.
         let program_owner = match &tx_id {
             Some(tid) => self
-                .ledger
+                .ledger()
                 .vm()
                 .block_store()
                 .transaction_store()
@@ -310,7 +332,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(id): Path<ProgramID<N>>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.get_latest_edition_for_program(&id)?))
+        Ok(ErasedJson::new(rest.ledger().get_latest_edition_for_program(&id)?))
     }
 
     /// GET /<network>/program/{programID}/mappings
@@ -318,7 +340,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(id): Path<ProgramID<N>>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.vm().finalize_store().get_mapping_names_confirmed(&id)?))
+        Ok(ErasedJson::new(rest.ledger().vm().finalize_store().get_mapping_names_confirmed(&id)?))
     }
 
     /// GET /<network>/program/{programID}/mapping/{mappingName}/{mappingKey}
@@ -329,13 +351,13 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         metadata: Query<Metadata>,
     ) -> Result<ErasedJson, RestError> {
         // Retrieve the mapping value.
-        let mapping_value = rest.ledger.vm().finalize_store().get_value_confirmed(id, name, &key)?;
+        let mapping_value = rest.ledger().vm().finalize_store().get_value_confirmed(id, name, &key)?;
 
         // Check if metadata is requested and return the value with metadata if so.
         if metadata.metadata.unwrap_or(false) {
             return Ok(ErasedJson::new(json!({
                 "data": mapping_value,
-                "height": rest.ledger.latest_height(),
+                "height": rest.ledger().latest_height(),
             })));
         }
 
@@ -357,10 +379,10 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         }
 
         // Retrieve the latest height.
-        let height = rest.ledger.latest_height();
+        let height = rest.ledger().latest_height();
 
         // Retrieve all the mapping values from the mapping.
-        match tokio::task::spawn_blocking(move || rest.ledger.vm().finalize_store().get_mapping_confirmed(id, name))
+        match tokio::task::spawn_blocking(move || rest.ledger().vm().finalize_store().get_mapping_confirmed(id, name))
             .await
         {
             Ok(Ok(mapping_values)) => {
@@ -385,7 +407,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(commitment): Path<Field<N>>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.get_state_path_for_commitment(&commitment)?))
+        Ok(ErasedJson::new(rest.ledger().get_state_path_for_commitment(&commitment)?))
     }
 
     /// GET /<network>/statePaths?commitments=cm1,cm2,...
@@ -418,12 +440,12 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(ErasedJson::new(rest.ledger.get_state_paths_for_commitments(&commitments)?))
+        Ok(ErasedJson::new(rest.ledger().get_state_paths_for_commitments(&commitments)?))
     }
 
     /// GET /<network>/stateRoot/latest
     pub(crate) async fn get_state_root_latest(State(rest): State<Self>) -> ErasedJson {
-        ErasedJson::new(rest.ledger.latest_state_root())
+        ErasedJson::new(rest.ledger().latest_state_root())
     }
 
     /// GET /<network>/stateRoot/{height}
@@ -431,7 +453,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(height): Path<u32>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.get_state_root(height)?))
+        Ok(ErasedJson::new(rest.ledger().get_state_root(height)?))
     }
 
     /// GET /<network>/find/blockHash/{transactionID}
@@ -439,7 +461,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(tx_id): Path<N::TransactionID>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.find_block_hash(&tx_id)?))
+        Ok(ErasedJson::new(rest.ledger().find_block_hash(&tx_id)?))
     }
 
     /// GET /<network>/find/blockHeight/{stateRoot}
@@ -447,7 +469,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(state_root): Path<N::StateRoot>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.find_block_height_from_state_root(state_root)?))
+        Ok(ErasedJson::new(rest.ledger().find_block_height_from_state_root(state_root)?))
     }
 
     /// GET /<network>/find/transactionID/deployment/{programID}
@@ -455,7 +477,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(program_id): Path<ProgramID<N>>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.find_latest_transaction_id_from_program_id(&program_id)?))
+        Ok(ErasedJson::new(rest.ledger().find_latest_transaction_id_from_program_id(&program_id)?))
     }
 
     /// GET /<network>/find/transactionID/deployment/{programID}/{edition}
@@ -463,7 +485,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path((program_id, edition)): Path<(ProgramID<N>, u16)>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.find_latest_transaction_id_from_program_id_and_edition(&program_id, edition)?))
+        Ok(ErasedJson::new(rest.ledger().find_latest_transaction_id_from_program_id_and_edition(&program_id, edition)?))
     }
 
     /// GET /<network>/find/transactionID/{transitionID}
@@ -471,7 +493,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(transition_id): Path<N::TransitionID>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.find_transaction_id_from_transition_id(&transition_id)?))
+        Ok(ErasedJson::new(rest.ledger().find_transaction_id_from_transition_id(&transition_id)?))
     }
 
     /// GET /<network>/find/transitionID/{inputOrOutputID}
@@ -479,7 +501,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         State(rest): State<Self>,
         Path(input_or_output_id): Path<Field<N>>,
     ) -> Result<ErasedJson, RestError> {
-        Ok(ErasedJson::new(rest.ledger.find_transition_id(&input_or_output_id)?))
+        Ok(ErasedJson::new(rest.ledger().find_transition_id(&input_or_output_id)?))
     }
 
     // /// POST /<network>/transaction/broadcast
@@ -541,7 +563,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
             }
             // Perform the check.
             let res = rest
-                .ledger
+                .ledger()
                 .check_transaction_basic(&tx, None, &mut rand::rng())
                 .map_err(|err| RestError::unprocessable_entity(err.context("Invalid transaction")));
 
@@ -550,33 +572,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
             // Propagate error if any.
             res?;
         }
-        // Create a block with the transaction if the manual block creation feature is not enabled.
-        if !rest.manual_block_creation {
-            // Clone the ledger for the blocking task
-            let ledger = rest.ledger.clone();
-            // Wrap blocking operations in spawn_blocking
-            let new_block = tokio::task::spawn_blocking(move || {
-                ledger
-                    .prepare_advance_to_next_beacon_block(&rest.private_key, vec![], vec![], vec![tx], &mut rand::rng())
-                    .map_err(|e| anyhow!("{e}"))
-            })
-            .await
-            .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))??;
-
-            // Advance to the next block.
-            tokio::task::spawn_blocking(move || {
-                rest.ledger.advance_to_next_block(&new_block).map_err(|e| anyhow!("{e}"))
-            })
-            .await
-            .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))??;
-            return Ok((StatusCode::OK, ErasedJson::new(tx_id)));
-        }
-
-        // Add the transaction to the Rest buffer.
-        {
-            let mut buffer = rest.buffer.lock();
-            buffer.push(tx);
-        }
+        rest.add_transaction(tx).await?;
 
         Ok((StatusCode::OK, ErasedJson::new(tx_id)))
     }
@@ -597,7 +593,7 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         let inputs = parse_view_inputs(&raw_inputs)?;
 
         let outputs = match tokio::task::spawn_blocking(move || {
-            rest.ledger.vm().evaluate_view_at_height(program_id, view_name, inputs, height)
+            rest.ledger().vm().evaluate_view_at_height(program_id, view_name, inputs, height)
         })
         .await
         {
@@ -632,7 +628,8 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         let inputs = parse_view_inputs(&raw_inputs)?;
 
         let (outputs, height) = match tokio::task::spawn_blocking(move || {
-            let block = rest.ledger.latest_block();
+            let ledger = rest.ledger();
+            let block = ledger.latest_block();
             let height = block.height();
 
             let block_timestamp =
@@ -647,8 +644,8 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
                 None,
             )?;
 
-            let stack = rest.ledger.vm().process().get_stack(program_id)?;
-            let outputs = stack.evaluate_view(state, rest.ledger.vm().finalize_store(), &view_name, inputs)?;
+            let stack = ledger.vm().process().get_stack(program_id)?;
+            let outputs = stack.evaluate_view(state, ledger.vm().finalize_store(), &view_name, inputs)?;
 
             Ok::<_, anyhow::Error>((outputs, height))
         })
@@ -676,9 +673,12 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
     }
 
     /// POST /{network}/create_block
+    ///
+    /// Creates `num_blocks` blocks, the first holding the pending `transactions`, or all pending transactions if
+    /// not set.
     pub(crate) async fn create_block(
         State(rest): State<Self>,
-        Json(req): Json<CreateBlockRequest>,
+        Json(req): Json<CreateBlockRequest<N>>,
     ) -> Result<ErasedJson, RestError> {
         // Determine the number of blocks to create.
         let num_blocks = req.num_blocks.unwrap_or(1);
@@ -686,30 +686,11 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
         // Iterate and create the specified number of blocks.
         // Return the last created block.
         let last_block = tokio::task::spawn_blocking(move || -> Result<ErasedJson, RestError> {
-            let mut last_block = None;
-
-            // Take all unconfirmed transactions from the buffer.
-            let mut unconfirmed_txs = Some({
-                let mut buffer = rest.buffer.lock();
-                buffer.drain(..).collect()
-            });
-
-            for _ in 0..num_blocks {
-                let txs = unconfirmed_txs.take().unwrap_or_default();
-
-                // Prepare the new block.  Note that transactions in the buffer are added to the first block.
-                // If there are no transactions left in the buffer, create an empty block.
-                let new_block = rest
-                    .ledger
-                    .prepare_advance_to_next_beacon_block(&rest.private_key, vec![], vec![], txs, &mut rand::rng())
-                    .map_err(|e| RestError::internal_server_error(anyhow!("Failed to prepare block: {}", e)))?;
-
-                // Update the ledger to the new block.
-                rest.ledger
-                    .advance_to_next_block(&new_block)
-                    .map_err(|e| RestError::internal_server_error(anyhow!("Failed to advance block: {}", e)))?;
-
-                last_block = Some(new_block);
+            // Note that pending transactions are added to the first block.
+            let mut last_block = Some(rest.mine(req.transactions.as_deref())?);
+
+            for _ in 1..num_blocks {
+                last_block = Some(rest.advance(vec![])?);
             }
 
             Ok(ErasedJson::new(last_block.unwrap()))
@@ -719,4 +700,151 @@ impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
 
         Ok(last_block)
     }
+
+    /// Adds `tx` to the ledger in a new block, or, unless a block is created for each broadcast, to the mempool of
+    /// transactions waiting for the next block.
+    pub(crate) async fn add_transaction(self, tx: Transaction<N>) -> Result<(), RestError> {
+        tokio::task::spawn_blocking(move || {
+            if self.block_creation != BlockCreation::OnBroadcast {
+                // Wait out a revert, which drops the pending transactions.
+                let _updating = self.updating.lock();
+                self.mempool.lock().push(tx.id(), tx);
+                return Ok(());
+            }
+            self.advance(vec![tx]).map(|_| ())
+        })
+        .await
+        .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))?
+    }
+
+    /// Creates a block holding the pending transactions with the given `ids`, in that order, or all pending
+    /// transactions if `ids` is `None`.
+    pub(crate) fn mine(&self, ids: Option<&[N::TransactionID]>) -> Result<Block<N>, RestError> {
+        let _updating = self.updating.lock();
+        let txs = self.mempool.lock().take(ids).map_err(mempool::not_pending)?;
+        self.add_block(&self.ledger(), txs)
+    }
+
+    /// Creates a block holding `txs` and adds it to the ledger.
+    fn advance(&self, txs: Vec<Transaction<N>>) -> Result<Block<N>, RestError> {
+        let _updating = self.updating.lock();
+        self.add_block(&self.ledger(), txs)
+    }
+
+    /// Creates a block holding `txs` and adds it to `ledger`, sending its events to any subscribers.
+    /// The caller holds `updating`.
+    fn add_block(&self, ledger: &Ledger<N, C>, txs: Vec<Transaction<N>>) -> Result<Block<N>, RestError> {
+        // The transactions are only needed again to describe the block's events.
+        let watched = self.has_subscribers().then(|| txs.clone());
+        let block = ledger
+            .prepare_advance_to_next_beacon_block(&self.private_key, vec![], vec![], txs, &mut rand::rng())
+            .map_err(|e| RestError::internal_server_error(anyhow!("Failed to prepare block: {}", e)))?;
+        let watch = watched.and_then(|txs| {
+            self.watch_block(ledger, &block, &txs).inspect_err(|e| warn!("Failed to watch block: {e}")).ok()
+        });
+
+        ledger
+            .advance_to_next_block(&block)
+            .map_err(|e| RestError::internal_server_error(anyhow!("Failed to advance block: {}", e)))?;
+
+        if let Some(watch) = watch
+            && let Err(e) = self.publish_block(ledger, &block, watch)
+        {
+            warn!("Failed to publish the events of block {}: {e}", block.height());
+        }
+        Ok(block)
+    }
+
+    /// POST /{network}/snapshot
+    pub(crate) async fn create_snapshot(State(rest): State<Self>) -> Result<ErasedJson, RestError> {
+        rest.check_revertible()?;
+        let snapshot = tokio::task::spawn_blocking(move || {
+            // The height and the cheat writes are read together, so the snapshot sees no change between them.
+            let _updating = rest.updating.lock();
+            let height = rest.ledger().latest_height();
+            let writes = rest.cheat_writes.lock().len();
+            rest.snapshots.lock().take(height, writes)
+        })
+        .await
+        .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))?;
+        Ok(ErasedJson::new(snapshot))
+    }
+
+    /// GET /{network}/snapshots
+    pub(crate) async fn get_snapshots(State(rest): State<Self>) -> ErasedJson {
+        ErasedJson::new(rest.snapshots.lock().all())
+    }
+
+    /// POST /{network}/snapshot/revert
+    ///
+    /// Rebuilds the ledger up to the snapshot's height by replaying the blocks before it onto a fresh ledger,
+    /// dropping any unconfirmed transactions. Mapping writes made by cheats before the snapshot are replayed at the
+    /// height they were made at. Snapshots taken after it are forgotten.
+    ///
+    /// Blocks and cheat writes requested during the revert wait for it to finish, and are then made on the
+    /// reverted ledger.
+    pub(crate) async fn revert_snapshot(
+        State(rest): State<Self>,
+        Json(req): Json<RevertRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        let loader = rest.check_revertible()?;
+        let snapshot = tokio::task::spawn_blocking(move || {
+            let _updating = rest.updating.lock();
+            let snapshot = rest.snapshots.lock().get(req.id).ok_or_else(|| {
+                RestError::not_found(match req.id {
+                    Some(id) => anyhow!("No snapshot with ID {id} exists"),
+                    None => anyhow!("No snapshot has been taken"),
+                })
+            })?;
+
+            let writes = rest.cheat_writes.lock()[..snapshot.writes].to_vec();
+            let reverted = Self::replay(&loader, &rest.ledger(), snapshot.height, writes)?;
+
+            *rest.ledger.write() = reverted;
+            rest.mempool.lock().clear();
+            rest.cheat_writes.lock().truncate(snapshot.writes);
+            rest.snapshots.lock().revert_to(snapshot);
+            rest.publish(Event::Revert { snapshot: snapshot.id, height: snapshot.height });
+            Ok::<_, RestError>(snapshot)
+        })
+        .await
+        .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))??;
+        Ok(ErasedJson::new(snapshot))
+    }
+
+    /// Rebuilds the ledger up to `height` on a fresh one from `loader`, by replaying the blocks of `current` and
+    /// the mapping `writes` made by cheats, each after the block at the height it was made at.
+    pub(crate) fn replay(
+        loader: &LedgerLoader<N, C>,
+        current: &Ledger<N, C>,
+        height: u32,
+        writes: Vec<MappingWrite<N>>,
+    ) -> Result<Ledger<N, C>, RestError> {
+        let ledger =
+            loader().map_err(|e| RestError::internal_server_error(e.context("Failed to load a fresh ledger")))?;
+        let mut writes = writes.into_iter().peekable();
+        for height in 0..=height {
+            if height > 0 {
+                let block = current.get_block(height)?;
+                ledger
+                    .advance_to_next_block(&block)
+                    .map_err(|e| RestError::internal_server_error(anyhow!("Failed to replay block {height}: {e}")))?;
+            }
+            while let Some(write) = writes.next_if(|write| write.height == height) {
+                write.apply(ledger.vm()).map_err(|e| {
+                    RestError::internal_server_error(e.context(format!("Failed to replay a cheat at height {height}")))
+                })?;
+            }
+        }
+        Ok(ledger)
+    }
+
+    /// Returns the loader a revert rebuilds the ledger with, or an error if the ledger is persisted.
+    fn check_revertible(&self) -> Result<LedgerLoader<N, C>, RestError> {
+        self.loader.clone().ok_or_else(|| {
+            RestError::bad_request(anyhow!(
+                "Snapshots are only supported by in-memory devnodes; start the devnode without `--storage`"
+            ))
+        })
+    }
 }
@@ -0,0 +1,391 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Traces of the finalize logic of confirmed transactions, served at `GET /{network}/transaction/{id}/trace`.
+use super::*;
+
+use snarkvm::{
+    prelude::{
+        Block,
+        ConsensusVersion,
+        Execution,
+        Future,
+        Identifier,
+        Plaintext,
+        Register,
+        VM,
+        Value,
+        block::{Output, Transition},
+    },
+    synthesizer::{
+        process::FinalizeRegisters,
+        program::{CallOperator, Command, FinalizeGlobalState, Operand, RegistersTrait, StackTrait},
+    },
+};
+
+use anyhow::{anyhow, bail};
+use serde::{Deserialize, Serialize};
+
+/// The trace of the finalize logic of a transaction, re-run on the state the transaction was finalized on.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct Trace {
+    /// The ID the transaction was broadcast with.
+    pub transaction: String,
+    /// The height of the block the transaction was confirmed in.
+    pub height: u32,
+    /// Whether the transaction was `accepted` or `rejected`.
+    pub status: String,
+    /// The finalize commands that ran, in order.
+    pub steps: Vec<TraceStep>,
+    /// The command that failed, if any, which is why a transaction is rejected.
+    pub failure: Option<TraceFailure>,
+}
+
+/// A finalize command that ran.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct TraceStep {
+    pub program: String,
+    /// The function whose finalize logic the command belongs to.
+    pub function: String,
+    /// The number of `await`s the finalize logic is nested in.
+    pub depth: usize,
+    /// The index of the command in the finalize logic.
+    pub index: usize,
+    pub command: String,
+    /// The mapping entry the command read or wrote, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access: Option<MappingAccess>,
+    /// The function whose finalize logic the command awaited, as `program/function`, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub awaited: Option<String>,
+}
+
+/// A mapping read or write.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub(crate) enum MappingAccess {
+    /// A `get` or `get.or_use`, with the value found, or `None` if the entry does not exist.
+    Get {
+        program: String,
+        mapping: String,
+        key: String,
+        value: Option<String>,
+    },
+    Contains {
+        program: String,
+        mapping: String,
+        key: String,
+        found: bool,
+    },
+    Set {
+        program: String,
+        mapping: String,
+        key: String,
+        value: String,
+    },
+    Remove {
+        program: String,
+        mapping: String,
+        key: String,
+    },
+}
+
+/// The finalize command that failed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct TraceFailure {
+    pub program: String,
+    pub function: String,
+    pub index: usize,
+    pub command: String,
+    pub error: String,
+}
+
+/// Re-runs finalize logic command by command against a ledger's finalize store, recording what each command does.
+struct Tracer<'a, N: Network, C: ConsensusStorage<N>> {
+    vm: &'a VM<N, C>,
+    state: FinalizeGlobalState,
+    /// The transitions of the execution, whose futures are awaited in the order they were called.
+    transitions: Vec<&'a Transition<N>>,
+    /// The number of finalize blocks started, which seeds their randomness as snarkVM does.
+    nonce: u64,
+    steps: Vec<TraceStep>,
+    failure: Option<TraceFailure>,
+}
+
+impl<'a, N: Network, C: ConsensusStorage<N>> Tracer<'a, N, C> {
+    fn new(vm: &'a VM<N, C>, state: FinalizeGlobalState, execution: &'a Execution<N>) -> Self {
+        Self { vm, state, transitions: execution.transitions().collect(), nonce: 0, steps: Vec::new(), failure: None }
+    }
+
+    /// Runs the finalize logic of the execution, returning `false` if a command failed.
+    fn run_execution(&mut self) -> anyhow::Result<bool> {
+        let Some(root) = self.transitions.pop() else { bail!("The execution has no transitions") };
+        let Some(Output::Future(_, Some(future))) = root.outputs().last() else {
+            // The transaction has no finalize logic to trace.
+            return Ok(true);
+        };
+        self.run_future(future, *root.id(), 0)
+    }
+
+    /// Runs the finalize logic of `future`, which was output by the transition `transition_id`.
+    fn run_future(&mut self, future: &Future<N>, transition_id: N::TransitionID, depth: usize) -> anyhow::Result<bool> {
+        let program_id = *future.program_id();
+        let function_name = *future.function_name();
+        let stack = self.vm.process().get_stack(program_id)?;
+        let Some(finalize) = stack.program().get_function_ref(&function_name)?.finalize_logic() else {
+            bail!("`{program_id}/{function_name}` has no finalize logic");
+        };
+
+        let mut registers = FinalizeRegisters::new(
+            self.state,
+            Some(transition_id),
+            function_name,
+            stack.get_finalize_types(&function_name)?.clone(),
+            Some(self.nonce),
+        );
+        self.nonce += 1;
+        for (input, argument) in finalize.inputs().iter().zip(future.arguments()) {
+            registers.store(&*stack, input.register(), Value::from(argument.clone()))?;
+        }
+
+        let commands = finalize.commands();
+        let mut counter = 0;
+        while counter < commands.len() {
+            let command = &commands[counter];
+            let mut step = TraceStep {
+                program: program_id.to_string(),
+                function: function_name.to_string(),
+                depth,
+                index: counter,
+                command: command.to_string(),
+                access: None,
+                awaited: None,
+            };
+            let load = |operand: &Operand<N>| registers.load(&*stack, operand);
+
+            // Branches and awaits are run here, as snarkVM does, rather than by the commands themselves.
+            let branch = match command {
+                Command::BranchEq(branch) => Some((load(branch.first())? == load(branch.second())?, branch.position())),
+                Command::BranchNeq(branch) => {
+                    Some((load(branch.first())? != load(branch.second())?, branch.position()))
+                }
+                _ => None,
+            };
+            if let Some((taken, position)) = branch {
+                self.steps.push(step);
+                counter = if taken { finalize.positions()[position] } else { counter + 1 };
+                continue;
+            }
+            if let Command::Await(await_) = command {
+                let Value::Future(awaited) = load(&Operand::Register(await_.register().clone()))? else {
+                    bail!("`{}` does not hold a future", await_.register());
+                };
+                let Some(position) = self.transitions.iter().position(|transition| {
+                    transition.program_id() == awaited.program_id()
+                        && transition.function_name() == awaited.function_name()
+                }) else {
+                    bail!("No transition outputs the future of `{}/{}`", awaited.program_id(), awaited.function_name());
+                };
+                let transition = self.transitions.remove(position);
+                step.awaited = Some(format!("{}/{}", awaited.program_id(), awaited.function_name()));
+                self.steps.push(step);
+                if !self.run_future(&awaited, *transition.id(), depth + 1)? {
+                    return Ok(false);
+                }
+                counter += 1;
+                continue;
+            }
+
+            // Read the operands of a mapping access before the command can overwrite them.
+            let result = mapping_operands(command, program_id, &*stack, &registers).and_then(|operands| {
+                command.finalize(&*stack, self.vm.finalize_store(), &mut registers)?;
+                Ok(operands)
+            });
+            let operands = match result {
+                Ok(operands) => operands,
+                Err(error) => {
+                    self.failure = Some(TraceFailure {
+                        program: program_id.to_string(),
+                        function: function_name.to_string(),
+                        index: counter,
+                        command: command.to_string(),
+                        error: error.to_string(),
+                    });
+                    return Ok(false);
+                }
+            };
+
+            if let Some(((owner, name), key, value)) = operands {
+                let read = |destination: &Register<N>| registers.load(&*stack, &Operand::Register(destination.clone()));
+                let (program, mapping, key_string) = (owner.to_string(), name.to_string(), key.to_string());
+                step.access = Some(match command {
+                    Command::Get(get) => MappingAccess::Get {
+                        program,
+                        mapping,
+                        key: key_string,
+                        value: Some(read(get.destination())?.to_string()),
+                    },
+                    // The destination holds the default if the entry does not exist, so look the entry up instead.
+                    Command::GetOrUse(_) => MappingAccess::Get {
+                        program,
+                        mapping,
+                        key: key_string,
+                        value: self
+                            .vm
+                            .finalize_store()
+                            .get_value_speculative(owner, name, &key)?
+                            .map(|value| value.to_string()),
+                    },
+                    Command::Contains(contains) => MappingAccess::Contains {
+                        program,
+                        mapping,
+                        key: key_string,
+                        found: read(contains.destination())?.to_string() == "true",
+                    },
+                    Command::Set(_) => MappingAccess::Set {
+                        program,
+                        mapping,
+                        key: key_string,
+                        value: value.map(|value| value.to_string()).unwrap_or_default(),
+                    },
+                    _ => MappingAccess::Remove { program, mapping, key: key_string },
+                });
+            }
+            self.steps.push(step);
+            counter += 1;
+        }
+        Ok(true)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// GET /{network}/transaction/{transactionID}/trace
+    ///
+    /// Re-runs the finalize logic of a confirmed transaction on a copy of the ledger as it was when the
+    /// transaction was finalized, reporting each command with the mapping entries it read or wrote, the
+    /// finalize logic it awaited, and the command that failed, if any.
+    pub(crate) async fn get_transaction_trace(
+        State(rest): State<Self>,
+        Path(tx_id): Path<N::TransactionID>,
+    ) -> Result<ErasedJson, RestError> {
+        let loader = rest.loader.clone().ok_or_else(|| {
+            RestError::bad_request(anyhow!(
+                "Traces are only supported by in-memory devnodes; start the devnode without `--storage`"
+            ))
+        })?;
+        let current = rest.ledger();
+        let writes = rest.cheat_writes.lock().clone();
+        let trace = tokio::task::spawn_blocking(move || {
+            // Find the block the transaction was confirmed in, and its place in the block.
+            let (block, index) = (1..=current.latest_height())
+                .rev()
+                .map(|height| current.get_block(height))
+                .find_map(|block| {
+                    let block = block.ok()?;
+                    let index = block
+                        .transactions()
+                        .iter()
+                        .position(|confirmed| confirmed.to_unconfirmed_transaction_id().is_ok_and(|id| id == tx_id))?;
+                    Some((block, index))
+                })
+                .ok_or_else(|| RestError::not_found(anyhow!("Transaction '{tx_id}' is not in a block")))?;
+
+            // Rebuild the ledger as it was before the block, including the cheats made since.
+            let height = block.height();
+            let writes = writes.into_iter().take_while(|write| write.height < height).collect();
+            let ledger = Self::replay(&loader, &current, height - 1, writes)?;
+            let state = finalize_state(&block)?;
+
+            // The transactions before it in the block were finalized first. Their fees are not, which only
+            // matters to transactions reading the `credits.aleo/account` balances of the fee payers.
+            let confirmed = block.transactions().iter().collect::<Vec<_>>();
+            for earlier in confirmed[..index].iter().filter(|confirmed| confirmed.is_accepted()) {
+                if let Transaction::Execute(_, _, execution, _) = earlier.transaction() {
+                    Tracer::new(ledger.vm(), state, execution).run_execution()?;
+                }
+            }
+
+            let transaction = confirmed[index].to_unconfirmed_transaction()?;
+            let (steps, failure) = match &transaction {
+                Transaction::Execute(_, _, execution, _) => {
+                    let mut tracer = Tracer::new(ledger.vm(), state, execution);
+                    tracer.run_execution()?;
+                    (tracer.steps, tracer.failure)
+                }
+                _ => (Vec::new(), None),
+            };
+            Ok::<_, RestError>(Trace {
+                transaction: tx_id.to_string(),
+                height,
+                status: if confirmed[index].is_accepted() { "accepted" } else { "rejected" }.to_string(),
+                steps,
+                failure,
+            })
+        })
+        .await
+        .map_err(|e| RestError::internal_server_error(anyhow!("Task panicked: {}", e)))??;
+
+        Ok(ErasedJson::new(trace))
+    }
+}
+
+/// The mapping a command accesses, with the key and, for `set`, the value.
+type MappingOperands<N> = ((ProgramID<N>, Identifier<N>), Plaintext<N>, Option<Value<N>>);
+
+/// The operands of the mapping access of `command`, or `None` if it does not access a mapping.
+fn mapping_operands<N: Network>(
+    command: &Command<N>,
+    program_id: ProgramID<N>,
+    stack: &impl StackTrait<N>,
+    registers: &FinalizeRegisters<N>,
+) -> anyhow::Result<Option<MappingOperands<N>>> {
+    let mapping = |operator: &CallOperator<N>| match operator {
+        CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+        CallOperator::Resource(name) => (program_id, *name),
+    };
+    let key = |operand: &Operand<N>| -> anyhow::Result<Plaintext<N>> {
+        match registers.load(stack, operand)? {
+            Value::Plaintext(key) => Ok(key),
+            value => bail!("The mapping key '{value}' is not a plaintext"),
+        }
+    };
+    Ok(match command {
+        Command::Get(get) => Some((mapping(get.mapping()), key(get.key())?, None)),
+        Command::GetOrUse(get) => Some((mapping(get.mapping()), key(get.key())?, None)),
+        Command::Contains(contains) => Some((mapping(contains.mapping()), key(contains.key())?, None)),
+        Command::Set(set) => {
+            Some(((program_id, *set.mapping_name()), key(set.key())?, Some(registers.load(stack, set.value())?)))
+        }
+        Command::Remove(remove) => Some(((program_id, *remove.mapping_name()), key(remove.key())?, None)),
+        _ => None,
+    })
+}
+
+/// The global state the transactions of `block` were finalized with.
+fn finalize_state<N: Network>(block: &Block<N>) -> anyhow::Result<FinalizeGlobalState> {
+    let height = block.height();
+    let block_timestamp =
+        (height >= N::CONSENSUS_HEIGHT(ConsensusVersion::V12).unwrap_or_default()).then_some(block.timestamp());
+    FinalizeGlobalState::new::<N>(
+        block.round(),
+        height,
+        block_timestamp,
+        block.cumulative_weight(),
+        block.cumulative_proof_target(),
+        block.previous_hash(),
+        None,
+    )
+}
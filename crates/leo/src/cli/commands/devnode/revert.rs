@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use serde_json::json;
+
+// Revert the Devnode ledger to a snapshot.
+#[derive(Parser, Debug)]
+#[group(id = "revert_args")]
+pub struct Revert {
+    #[clap(help = "The ID of the snapshot to revert to. Defaults to the latest snapshot")]
+    pub id: Option<u32>,
+    #[clap(long, help = "devnode REST API server address", default_value = "127.0.0.1:3030")]
+    pub(crate) socket_addr: String,
+}
+
+impl Command for Revert {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _context: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _context: Context, _: Self::Input) -> Result<Self::Output> {
+        let snapshot = post_to_devnode(&self.socket_addr, "snapshot/revert", json!({ "id": self.id }))?;
+        println!("⏪ Reverted to snapshot {} at block height {}.", snapshot.id, snapshot.height);
+        Ok(())
+    }
+}
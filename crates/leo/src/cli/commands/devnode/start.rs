@@ -16,7 +16,12 @@
 
 use super::{logger::initialize_terminal_logger, *};
 use serde_json::json;
-use std::{net::SocketAddr, path::PathBuf};
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use aleo_std_storage::StorageMode;
 use snarkvm::{
@@ -32,7 +37,7 @@ use snarkvm::{
     },
 };
 
-use crate::cli::commands::devnode::rest::Rest;
+use crate::cli::commands::devnode::rest::{BlockCreation, LedgerLoader, Rest};
 
 // Command for starting the Devnode server.
 #[derive(Parser, Debug)]
@@ -50,12 +55,21 @@ pub struct Start {
     /// Enable manual block creation mode.
     #[clap(short = 'm', long, help = "disables automatic block creation after broadcast")]
     pub(crate) manual_block_creation: bool,
+    /// Create a block every this many seconds, holding the transactions broadcast since the last one.
+    #[clap(long, help = "create a block every this many seconds instead of after each broadcast", value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "manual_block_creation")]
+    pub(crate) block_interval: Option<u64>,
     /// Optional flag for persisting the ledger to disk. If not set, the ledger will be stored in memory and will not persist across restarts.
     #[clap(short = 's', long, help = "directory for ledger persistence", num_args = 0..=1, default_missing_value = "devnode")]
     pub(crate) storage: Option<PathBuf>,
     /// If set alongside --storage, clears the ledger directory before starting.
     #[clap(short = 'c', long, help = "Remove existing devnode storage before starting", requires = "storage")]
     pub(crate) clear_storage: bool,
+    /// Start from a copy of a ledger persisted with --storage, kept in memory so the original is left untouched.
+    #[clap(long, help = "start from a copy of the ledger persisted in this directory", conflicts_with = "storage")]
+    pub(crate) fork_from: Option<PathBuf>,
+    /// Enable the dev-only `/cheats` REST routes, which fund accounts, write mappings, and impersonate signers.
+    #[clap(long, help = "enable the dev-only /cheats REST routes")]
+    pub(crate) cheats: bool,
 }
 
 impl Command for Start {
@@ -83,6 +97,11 @@ async fn start_devnode(command: Start, private_key: Option<String>) -> Result<()
     println!("Starting the Devnode server...");
     // Load the private key from the command line or environment variable, and start the server.
     let private_key = resolve_private_key(&private_key)?;
+    let block_creation = match command.block_interval {
+        Some(seconds) => BlockCreation::Interval(Duration::from_secs(seconds)),
+        None if command.manual_block_creation => BlockCreation::Manual,
+        None => BlockCreation::OnBroadcast,
+    };
     initialize_terminal_logger(command.verbosity).expect("Failed to initialize logger");
 
     // Parse the listener address.
@@ -98,6 +117,12 @@ async fn start_devnode(command: Start, private_key: Option<String>) -> Result<()
         // This genesis block is stored in $TMPDIR when running snarkos start --dev 0 --dev-num-validators N
         Block::from_bytes_le(include_bytes!("resources/genesis_8d710d7e2_40val_snarkos_dev_network.bin"))?
     };
+    if let Some(source) = &command.fork_from {
+        // The copy of the persisted ledger must outlive the devnode.
+        let (ledger, _copy) = fork_ledger(source, genesis_block.clone()).await?;
+        let loader = memory_loader(genesis_block);
+        return run_devnode(socket_addr, ledger, block_creation, private_key, Some(loader), command.cheats).await;
+    }
     match command.storage {
         Some(path) => {
             if command.clear_storage && path.exists() {
@@ -124,38 +149,93 @@ async fn start_devnode(command: Start, private_key: Option<String>) -> Result<()
                 tokio::task::spawn_blocking(move || Ledger::load(genesis_block, storage_mode))
                     .await
                     .map_err(|e| crate::errors::custom(format!("Failed to load ledger: {e}")))??;
-            run_devnode(socket_addr, ledger, command.manual_block_creation, private_key).await?
+            run_devnode(socket_addr, ledger, block_creation, private_key, None, command.cheats).await?
         }
         None => {
-            let storage_mode = StorageMode::new_test(None);
-            let ledger: Ledger<TestnetV0, ConsensusMemory<TestnetV0>> =
-                tokio::task::spawn_blocking(move || Ledger::load(genesis_block, storage_mode))
-                    .await
-                    .map_err(|e| crate::errors::custom(format!("Failed to load ledger: {e}")))??;
-            run_devnode(socket_addr, ledger, command.manual_block_creation, private_key).await?
+            let loader = memory_loader(genesis_block);
+            let load = loader.clone();
+            let ledger = tokio::task::spawn_blocking(move || load())
+                .await
+                .map_err(|e| crate::errors::custom(format!("Failed to load ledger: {e}")))??;
+            run_devnode(socket_addr, ledger, block_creation, private_key, Some(loader), command.cheats).await?
         }
     }
 
     Ok(())
 }
 
+/// Loads fresh in-memory ledgers from `genesis_block`, which reverting to a snapshot replays blocks onto.
+fn memory_loader(genesis_block: Block<TestnetV0>) -> LedgerLoader<TestnetV0, ConsensusMemory<TestnetV0>> {
+    Arc::new(move || Ledger::load(genesis_block.clone(), StorageMode::new_test(None)))
+}
+
+/// Copies the ledger persisted in `source` to a temporary directory and replays its blocks onto an in-memory
+/// ledger, so that neither the forked devnode nor one still running on `source` disturbs the other.
+///
+/// Returns the ledger along with the temporary directory, which is removed when dropped.
+async fn fork_ledger(
+    source: &Path,
+    genesis_block: Block<TestnetV0>,
+) -> Result<(Ledger<TestnetV0, ConsensusMemory<TestnetV0>>, tempfile::TempDir)> {
+    if !source.is_dir() {
+        return Err(crate::errors::custom(format!("No ledger directory found at '{}'", source.display())).into());
+    }
+    let copy = tempfile::tempdir()
+        .map_err(|e| crate::errors::custom(format!("Failed to create a directory for the fork: {e}")))?;
+    copy_directory(source, copy.path())?;
+    println!("Forking the ledger at: {}", source.display());
+
+    let storage_mode = StorageMode::Custom(copy.path().to_path_buf());
+    let ledger = tokio::task::spawn_blocking(move || -> Result<_> {
+        let persisted: Ledger<TestnetV0, ConsensusDB<TestnetV0>> = Ledger::load(genesis_block.clone(), storage_mode)?;
+        let ledger: Ledger<TestnetV0, ConsensusMemory<TestnetV0>> =
+            Ledger::load(genesis_block, StorageMode::new_test(None))?;
+        for height in 1..=persisted.latest_height() {
+            ledger.advance_to_next_block(&persisted.get_block(height)?)?;
+        }
+        Ok(ledger)
+    })
+    .await
+    .map_err(|e| crate::errors::custom(format!("Failed to fork ledger: {e}")))??;
+    println!("Forked the ledger at block height {}", ledger.latest_height());
+    Ok((ledger, copy))
+}
+
+/// Copies the files of the directory `source` into `target`.
+fn copy_directory(source: &Path, target: &Path) -> Result<()> {
+    for entry in walkdir::WalkDir::new(source) {
+        let entry = entry.map_err(|e| crate::errors::custom(format!("Failed to read entry: {e}")))?;
+        let Ok(relative) = entry.path().strip_prefix(source) else { continue };
+        let destination = target.join(relative);
+        let copied = if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&destination)
+        } else {
+            std::fs::copy(entry.path(), &destination).map(|_| ())
+        };
+        copied.map_err(|e| crate::errors::custom(format!("Failed to copy '{}': {e}", entry.path().display())))?;
+    }
+    Ok(())
+}
+
 async fn run_devnode<C: 'static + ConsensusStorage<TestnetV0>>(
     socket_addr: SocketAddr,
     ledger: Ledger<TestnetV0, C>,
-    manual_block_creation: bool,
+    block_creation: BlockCreation,
     private_key: PrivateKey<TestnetV0>,
+    loader: Option<LedgerLoader<TestnetV0, C>>,
+    cheats: bool,
 ) -> Result<()> {
     let rps = 999999999;
 
     // Record the height before handing the ledger off, so we know how far to advance.
     let current_height = ledger.latest_height();
 
-    Rest::start(socket_addr, rps, ledger, manual_block_creation, private_key)
+    Rest::start(socket_addr, rps, ledger, block_creation, private_key, loader, cheats)
         .await
         .expect("Failed to start the REST API server");
     println!("Server running on http://{socket_addr}");
 
-    if !manual_block_creation {
+    if block_creation != BlockCreation::Manual {
         let last_height = TEST_CONSENSUS_VERSION_HEIGHTS.last().unwrap().1;
         let blocks_to_advance = last_height.saturating_sub(current_height);
         if blocks_to_advance > 0 {
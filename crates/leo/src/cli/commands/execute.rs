@@ -428,11 +428,11 @@ fn handle_execute<A: Aleo>(
         println!("\n⚙️ Generating transaction WITHOUT a proof for {program_name}/{function_name}...");
         vm.process()
             .authorize::<A, _>(&private_key, &program_name, &function_name, inputs.iter(), rng)
-            .map_err(|e| anyhow::anyhow!("{e}"))?
+            .map_err(|e| anyhow::anyhow!("{}", annotate_failure(package.as_ref(), &program_name, &function_name, e)))?
     } else {
         println!("\n⚙️ Executing {program_name}/{function_name}...");
         vm.authorize(&private_key, &program_name, &function_name, inputs.iter(), rng)
-            .map_err(|e| anyhow::anyhow!("{e}"))?
+            .map_err(|e| anyhow::anyhow!("{}", annotate_failure(package.as_ref(), &program_name, &function_name, e)))?
     };
 
     // Estimate and display execution cost.
@@ -463,7 +463,10 @@ fn handle_execute<A: Aleo>(
         let transaction = Transaction::from_execution(execution, Some(fee))?;
 
         // Evaluate the transaction to get the response.
-        let response = vm.process().evaluate::<A>(authorization).map_err(|e| anyhow::anyhow!("{e}"))?;
+        let response = vm
+            .process()
+            .evaluate::<A>(authorization)
+            .map_err(|e| anyhow::anyhow!("{}", annotate_failure(package.as_ref(), &program_name, &function_name, e)))?;
 
         ("transaction", Box::new(transaction), response)
     } else {
@@ -488,8 +491,9 @@ fn handle_execute<A: Aleo>(
         };
 
         // Execute with the existing authorization (no re-authorization).
-        let (transaction, response) =
-            vm.execute_authorization_with_response(authorization, fee_authorization, Some(&query), rng)?;
+        let (transaction, response) = vm
+            .execute_authorization_with_response(authorization, fee_authorization, Some(&query), rng)
+            .map_err(|e| anyhow::anyhow!("{}", annotate_failure(package.as_ref(), &program_name, &function_name, e)))?;
         ("transaction", Box::new(transaction), response)
     };
 
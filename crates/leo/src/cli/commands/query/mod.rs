@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::NetworkName;
-use leo_package::{fetch_from_network, verify_valid_program};
+use leo_package::{Package, fetch_from_network, verify_valid_program};
 
 use super::*;
 
@@ -81,6 +81,20 @@ fn handle_query(
 ) -> Result<<LeoQuery as Command>::Output> {
     let recursive = context.recursive;
     let network_retries = query.env_override.network_retries;
+    // Traces point at the Leo code of programs built in the current package.
+    let trace = matches!(&query.command, QueryCommands::Transaction { command } if command.trace);
+    let package = trace
+        .then(|| {
+            Package::from_directory_no_graph(
+                context.dir().ok()?,
+                context.home().ok()?,
+                query.env_override.network,
+                query.env_override.endpoint.as_deref(),
+                network_retries,
+            )
+            .ok()
+        })
+        .flatten();
     let (program, output) = match query.command {
         QueryCommands::Block { command } => (None, command.apply(context, ())?),
         QueryCommands::Transaction { command } => (None, command.apply(context, ())?),
@@ -115,7 +129,11 @@ fn handle_query(
     let result = fetch_from_network(&url, network_retries)?;
     if !recursive {
         tracing::info!("✅ Successfully retrieved data from '{url}'.\n");
-        println!("{result}\n");
+        if trace {
+            println!("{}\n", transaction::render_trace(&result, package.as_ref())?);
+        } else {
+            println!("{result}\n");
+        }
     }
 
     // Verify that the source file parses into a valid Aleo program.
@@ -15,8 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::commands::devnode::rest::{MappingAccess, Trace};
 
 use clap::Parser;
+use indexmap::IndexMap;
+use leo_package::Package;
+use std::fmt::Write as _;
 
 /// Query transaction information.
 #[derive(Parser, Debug)]
@@ -40,6 +44,12 @@ pub struct LeoTransaction {
     pub(crate) confirmed: bool,
     #[arg(short, long, help = "Get the original (unconfirmed) transaction", group = "mode")]
     pub(crate) unconfirmed: bool,
+    #[arg(
+        long,
+        help = "Re-run the finalize logic of a confirmed transaction on a devnode and trace it",
+        group = "mode"
+    )]
+    pub(crate) trace: bool,
     #[arg(
         value_name = "INPUT_OR_OUTPUT_ID",
         long,
@@ -95,6 +105,8 @@ impl Command for LeoTransaction {
                 format!("transaction/confirmed/{id}")
             } else if self.unconfirmed {
                 format!("transaction/unconfirmed/{id}")
+            } else if self.trace {
+                format!("transaction/{id}/trace")
             } else {
                 format!("transaction/{id}")
             }
@@ -105,3 +117,67 @@ impl Command for LeoTransaction {
         Ok(url)
     }
 }
+
+/// Renders a trace fetched from a devnode, pointing each command at the Leo code it was compiled from when
+/// `package` has a source map for its program.
+pub(super) fn render_trace(json: &str, package: Option<&Package>) -> Result<String> {
+    let trace: Trace =
+        serde_json::from_str(json).map_err(|e| crate::errors::custom(format!("Failed to parse the trace: {e}")))?;
+
+    // Load each program's source map once.
+    let mut source_maps = IndexMap::new();
+    let mut source = |program: &str, function: &str, index: usize| {
+        let source_map = source_maps
+            .entry(program.to_string())
+            .or_insert_with(|| package.and_then(|package| load_source_map(package, program)));
+        source_map.as_ref()?.entry(&format!("finalize {function}"), index).map(|entry| entry.source.clone())
+    };
+
+    let mut out = format!("Transaction {} was {} in block {}.\n", trace.transaction, trace.status, trace.height);
+    let mut block = None;
+    for step in &trace.steps {
+        let indent = "  ".repeat(step.depth + 1);
+        if block != Some((&step.program, &step.function, step.depth)) {
+            block = Some((&step.program, &step.function, step.depth));
+            let _ = writeln!(out, "\n{indent}finalize {}/{}", step.program, step.function);
+        }
+        let _ = write!(out, "{indent}  {:>3}: {}", step.index, step.command);
+        if let Some(location) = source(&step.program, &step.function, step.index) {
+            let _ = write!(out, "  ({}:{})", location.file, location.line);
+        }
+        out.push('\n');
+        let access = match &step.access {
+            Some(MappingAccess::Get { program, mapping, key, value }) => {
+                let value = value.as_deref().unwrap_or("(none)");
+                Some(format!("read {program}/{mapping}[{key}] = {value}"))
+            }
+            Some(MappingAccess::Contains { program, mapping, key, found }) => {
+                Some(format!("check {program}/{mapping}[{key}] exists = {found}"))
+            }
+            Some(MappingAccess::Set { program, mapping, key, value }) => {
+                Some(format!("write {program}/{mapping}[{key}] = {value}"))
+            }
+            Some(MappingAccess::Remove { program, mapping, key }) => Some(format!("remove {program}/{mapping}[{key}]")),
+            None => step.awaited.as_ref().map(|awaited| format!("await {awaited}")),
+        };
+        if let Some(access) = access {
+            let _ = writeln!(out, "{indent}       {access}");
+        }
+    }
+
+    match &trace.failure {
+        Some(failure) => {
+            let _ = write!(
+                out,
+                "\nFailed at command {} of finalize {}/{}: {}\n  {}",
+                failure.index, failure.program, failure.function, failure.command, failure.error
+            );
+            if let Some(location) = source(&failure.program, &failure.function, failure.index) {
+                let _ = write!(out, "\n{}", location.render());
+            }
+        }
+        None if trace.steps.is_empty() => out.push_str("\nThe transaction has no finalize logic."),
+        None => out.push_str("\nThe finalize logic ran to completion."),
+    }
+    Ok(out)
+}
@@ -307,13 +307,14 @@ fn handle_run<A: Aleo>(
     }
 
     // Evaluate the program and get a response.
-    let authorization = vm
-        .authorize(&private_key, program_id, function_id, inputs.iter(), rng)
-        .map_err(|e| crate::errors::custom(format!("Failed to authorize execution: {e}")))?;
-    let response = vm
-        .process()
-        .evaluate::<A>(authorization)
-        .map_err(|e| crate::errors::custom(format!("Failed to evaluate program: {e}")))?;
+    let authorization = vm.authorize(&private_key, program_id, function_id, inputs.iter(), rng).map_err(|e| {
+        let message = annotate_failure(package.as_ref(), &program_name, &function_name, e);
+        crate::errors::custom(format!("Failed to authorize execution: {message}"))
+    })?;
+    let response = vm.process().evaluate::<A>(authorization).map_err(|e| {
+        let message = annotate_failure(package.as_ref(), &program_name, &function_name, e);
+        crate::errors::custom(format!("Failed to evaluate program: {message}"))
+    })?;
 
     // Collect outputs.
     let outputs: Vec<String> = response.outputs().iter().map(|o| o.to_string()).collect();
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Selecting which tests `leo test` runs.
+//!
+//! Tests are selected by their qualified name, e.g. `test_token.aleo/test_transfer` or
+//! `scenarios/vesting`, and by the tags they carry, written `@test(tags = "slow, vm")` on a test
+//! function and `"tags": ["slow"]` in a scenario file.
+
+use regex::Regex;
+
+/// Matches a qualified test name, either by substring or, with `--regex`, by regular expression.
+enum Pattern {
+    Substring(String),
+    Regex(Regex),
+}
+
+impl Pattern {
+    fn new(pattern: &str, regex: bool) -> Result<Self, String> {
+        if !regex {
+            return Ok(Self::Substring(pattern.to_string()));
+        }
+        Regex::new(pattern).map(Self::Regex).map_err(|e| format!("`{pattern}` is not a valid regular expression: {e}"))
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Substring(pattern) => name.contains(pattern.as_str()),
+            Self::Regex(regex) => regex.is_match(name),
+        }
+    }
+}
+
+/// Which tests to run.
+pub(super) struct TestFilter {
+    /// Tests must match this, when set.
+    include: Option<Pattern>,
+    /// Tests matching any of these are skipped.
+    exclude: Vec<Pattern>,
+    /// Tests must carry one of these tags, when any are given.
+    tags: Vec<String>,
+    /// Tests carrying any of these tags are skipped.
+    skip_tags: Vec<String>,
+}
+
+impl TestFilter {
+    /// A filter selecting the tests that match `include`, unless empty, and none of `exclude`, read as
+    /// regular expressions when `regex` is set, and that carry one of `tags`, if any, and none of `skip_tags`.
+    pub(super) fn new(
+        include: &str,
+        exclude: &[String],
+        regex: bool,
+        tags: &[String],
+        skip_tags: &[String],
+    ) -> Result<Self, String> {
+        Ok(Self {
+            include: (!include.is_empty()).then(|| Pattern::new(include, regex)).transpose()?,
+            exclude: exclude.iter().map(|pattern| Pattern::new(pattern, regex)).collect::<Result<_, _>>()?,
+            tags: tags.to_vec(),
+            skip_tags: skip_tags.to_vec(),
+        })
+    }
+
+    /// Whether the test named `name`, carrying `tags`, runs.
+    pub(super) fn matches(&self, name: &str, tags: &[String]) -> bool {
+        self.include.as_ref().is_none_or(|pattern| pattern.matches(name))
+            && !self.exclude.iter().any(|pattern| pattern.matches(name))
+            && (self.tags.is_empty() || tags.iter().any(|tag| self.tags.contains(tag)))
+            && !tags.iter().any(|tag| self.skip_tags.contains(tag))
+    }
+}
+
+/// Reads the `tags` argument of `@test`, a comma-separated list.
+pub(super) fn parse_tags(tags: &str) -> Vec<String> {
+    tags.split(',').map(str::trim).filter(|tag| !tag.is_empty()).map(str::to_string).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSFER: &str = "test_token.aleo/test_transfer";
+
+    /// Verifies names are matched by substring, or by regular expression with `--regex`, and exclusions win.
+    #[test]
+    fn names_are_matched_and_excluded() {
+        let filter = TestFilter::new("transfer", &[], false, &[], &[]).unwrap();
+        assert!(filter.matches(TRANSFER, &[]));
+        assert!(!filter.matches("test_token.aleo/test_mint", &[]));
+
+        let filter = TestFilter::new("^test_token\\.aleo/", &["mint$".to_string()], true, &[], &[]).unwrap();
+        assert!(filter.matches(TRANSFER, &[]));
+        assert!(!filter.matches("test_token.aleo/test_mint", &[]));
+        assert!(!filter.matches("scenarios/transfer", &[]));
+
+        assert!(TestFilter::new("(", &[], true, &[], &[]).is_err());
+    }
+
+    /// Verifies `--tag` requires one of the given tags and `--skip-tag` skips any test carrying one.
+    #[test]
+    fn tags_select_and_skip_tests() {
+        let tags = parse_tags(" slow, vm ,");
+        assert_eq!(tags, ["slow", "vm"]);
+
+        let filter = TestFilter::new("", &[], false, &["vm".to_string()], &[]).unwrap();
+        assert!(filter.matches(TRANSFER, &tags));
+        assert!(!filter.matches(TRANSFER, &[]));
+
+        let filter = TestFilter::new("", &[], false, &[], &["slow".to_string()]).unwrap();
+        assert!(!filter.matches(TRANSFER, &tags));
+        assert!(filter.matches(TRANSFER, &[]));
+    }
+}
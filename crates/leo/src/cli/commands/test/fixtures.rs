@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fixtures and expectations for `@test` functions.
+//!
+//! A test can set the mapping and storage entries its programs start from, and state the output
+//! it must return, the message it must fail with, and the entries its programs must hold once it
+//! has run. The output and failure message can be written on the annotations, as
+//! `@test(output = "...")` and `@should_fail(message = "...")`; everything else goes in a fixture
+//! file next to the test, `tests/test_token.fixtures.json`, keyed by test function name.
+
+use leo_ast::const_eval::Value;
+use leo_compiler::run;
+
+use snarkvm::prelude::{Identifier, Plaintext, ProgramID, TestnetV0, Value as SvmValue};
+
+use colored::Colorize as _;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::str::FromStr as _;
+
+/// The extension of a test file's fixture file, which replaces the `.leo` of the test file.
+pub(super) const FIXTURE_EXTENSION: &str = "fixtures.json";
+
+/// The fixtures of every test in a test file, by test function name.
+pub(super) type FixtureFile = IndexMap<String, Fixture>;
+
+/// The fixture of one test, as written in a fixture file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct Fixture {
+    /// Mapping entries written before the test runs.
+    pub(super) mappings: Vec<MappingEntry>,
+    /// Storage variables set before the test runs.
+    pub(super) storage: Vec<StorageEntry>,
+    /// The output the test must return.
+    pub(super) output: Option<String>,
+    /// Text the failure of a `@should_fail` test must contain.
+    pub(super) fails_with: Option<String>,
+    /// Mapping entries the test must leave behind.
+    pub(super) expect_mappings: Vec<MappingEntry>,
+    /// Storage variables the test must leave behind.
+    pub(super) expect_storage: Vec<StorageEntry>,
+}
+
+/// A mapping entry in a fixture file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct MappingEntry {
+    /// The program declaring the mapping; the program under test if left out.
+    program: Option<String>,
+    mapping: String,
+    key: String,
+    /// The value of the entry, or `null` for an entry that must be absent.
+    value: Option<String>,
+}
+
+/// A storage variable in a fixture file. Storage vectors are not supported.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct StorageEntry {
+    /// The program declaring the variable; the program under test if left out.
+    program: Option<String>,
+    variable: String,
+    /// The value of the variable, or `null` for a variable that must be unset.
+    value: Option<String>,
+}
+
+/// What a test sets up before it runs and checks once it has.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Expectations {
+    /// Entries written to the finalize store before the test runs.
+    pub(super) seeds: Vec<run::SeedMapping>,
+    /// The output the test must return.
+    output: Option<String>,
+    /// Text the failure of a `@should_fail` test must contain.
+    message: Option<String>,
+    /// Entries the finalize store must hold once the test has run.
+    entries: Vec<ExpectedEntry>,
+}
+
+/// A finalize-store entry a test must leave behind.
+#[derive(Clone, Debug)]
+struct ExpectedEntry {
+    /// How the entry is reported, e.g. `token.aleo/balances[aleo1...]`.
+    label: String,
+    key: run::MappingKey,
+    /// The value the entry must hold, or `None` if it must be absent.
+    value: Option<String>,
+}
+
+impl Expectations {
+    /// Resolves the `fixture` and annotation keys of a test against `program`, the program under test.
+    pub(super) fn new(
+        fixture: Fixture,
+        output: Option<&String>,
+        message: Option<&String>,
+        should_fail: bool,
+        program: &str,
+    ) -> Result<Self, String> {
+        let output = match (output, fixture.output) {
+            (Some(_), Some(_)) => return Err("`output` is set both on `@test` and in the fixture file".to_string()),
+            (output, fixture) => output.cloned().or(fixture),
+        };
+        let message = match (message, fixture.fails_with) {
+            (Some(_), Some(_)) => {
+                return Err("the failure message is set both on `@should_fail` and in the fixture file".to_string());
+            }
+            (message, fixture) => message.cloned().or(fixture),
+        };
+        if output.is_some() && should_fail {
+            return Err("a `@should_fail` test returns no output to check".to_string());
+        }
+        if message.is_some() && !should_fail {
+            return Err("`fails_with` applies only to `@should_fail` tests".to_string());
+        }
+
+        let mut seeds = Vec::new();
+        for entry in fixture.mappings.into_iter().map(|entry| entry.resolve(program)) {
+            let (label, key, value) = entry?;
+            let value = value.ok_or_else(|| format!("`{label}` is written before the test runs, so needs a value"))?;
+            seeds.push(run::SeedMapping { program: key.program, mapping: key.mapping, key: key.key, value });
+        }
+        for entry in fixture.storage.into_iter().map(|entry| entry.resolve(program)) {
+            let (label, key, value) = entry?;
+            let value = value.ok_or_else(|| format!("`{label}` is set before the test runs, so needs a value"))?;
+            seeds.push(run::SeedMapping { program: key.program, mapping: key.mapping, key: key.key, value });
+        }
+
+        let entries = fixture
+            .expect_mappings
+            .into_iter()
+            .map(|entry| entry.resolve(program))
+            .chain(fixture.expect_storage.into_iter().map(|entry| entry.resolve(program)))
+            .map(|entry| entry.map(|(label, key, value)| ExpectedEntry { label, key, value }))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { seeds, output, message, entries })
+    }
+
+    /// The entries to read back once the test has run, in the order [`Expectations::diff`] takes their values.
+    pub(super) fn read_mappings(&self) -> Vec<run::MappingKey> {
+        self.entries.iter().map(|entry| entry.key.clone()).collect()
+    }
+
+    /// Why a case that failed with `status` and `output` didn't fail with the expected message, if it didn't.
+    pub(super) fn check_failure(&self, status: &run::ExecutionStatus, output: &Value) -> Option<String> {
+        // A case that halts before it reaches the ledger reports why in its output.
+        self.check_message(&format!("{status} -- {output}"))
+    }
+
+    /// Why a case that failed with `failure` didn't fail with the expected message, if it didn't.
+    pub(super) fn check_message(&self, failure: &str) -> Option<String> {
+        let message = self.message.as_ref()?;
+        (!failure.contains(message.as_str()))
+            .then(|| format!("Test failed with `{failure}`, which does not contain `{message}`."))
+    }
+
+    /// Whether the test sets up or checks finalize-store entries.
+    pub(super) fn has_entries(&self) -> bool {
+        !self.seeds.is_empty() || !self.entries.is_empty()
+    }
+
+    /// Compares `output`, if the test returned one, and the values of the entries read back after the
+    /// test ran with their expected values, reporting each mismatch as a diff.
+    pub(super) fn diff(&self, output: Option<&Value>, mappings: &[Option<Value>]) -> Vec<String> {
+        let output = self.output.as_ref().and_then(|expected| diff("output", Some(expected), output));
+        let entries = self
+            .entries
+            .iter()
+            .zip(mappings)
+            .filter_map(|(entry, actual)| diff(&entry.label, entry.value.as_ref(), actual.as_ref()));
+        output.into_iter().chain(entries).collect()
+    }
+}
+
+impl MappingEntry {
+    /// The label, key, and value of the entry.
+    fn resolve(self, program: &str) -> Result<(String, run::MappingKey, Option<String>), String> {
+        let program = self.program.unwrap_or_else(|| program.to_string());
+        let label = format!("{program}/{}[{}]", self.mapping, self.key);
+        check_names(&program, &self.mapping).map_err(|e| format!("`{label}`: {e}"))?;
+        Plaintext::<TestnetV0>::from_str(&self.key).map_err(|e| format!("`{label}` has an invalid key: {e}"))?;
+        check_value(&label, self.value.as_deref())?;
+        Ok((label, run::MappingKey { program: Some(program), mapping: self.mapping, key: self.key }, self.value))
+    }
+}
+
+impl StorageEntry {
+    /// The label, key, and value of the entry, a storage variable being lowered to a mapping with the single key
+    /// `false`.
+    fn resolve(self, program: &str) -> Result<(String, run::MappingKey, Option<String>), String> {
+        let program = self.program.unwrap_or_else(|| program.to_string());
+        let label = format!("{program}/{}", self.variable);
+        check_names(&program, &self.variable).map_err(|e| format!("`{label}`: {e}"))?;
+        check_value(&label, self.value.as_deref())?;
+        let key =
+            run::MappingKey { program: Some(program), mapping: format!("{}__", self.variable), key: "false".into() };
+        Ok((label, key, self.value))
+    }
+}
+
+/// Checks that `program` and `name` are a valid program ID and identifier.
+fn check_names(program: &str, name: &str) -> Result<(), String> {
+    ProgramID::<TestnetV0>::from_str(program).map_err(|e| format!("invalid program: {e}"))?;
+    Identifier::<TestnetV0>::from_str(name).map_err(|e| format!("invalid name: {e}"))?;
+    Ok(())
+}
+
+/// Checks that `value`, if any, is a valid value for the entry `label`.
+fn check_value(label: &str, value: Option<&str>) -> Result<(), String> {
+    match value {
+        Some(value) => SvmValue::<TestnetV0>::from_str(value)
+            .map(|_| ())
+            .map_err(|e| format!("`{label}` has an invalid value: {e}")),
+        None => Ok(()),
+    }
+}
+
+/// Compares the `expected` value of `label`, written as `leo run` prints it, with the `actual` one,
+/// `None` on either side being an absent entry, and renders a mismatch as a diff.
+fn diff(label: &str, expected: Option<&String>, actual: Option<&Value>) -> Option<String> {
+    let matches = match (expected, actual) {
+        (None, None) => true,
+        // Values that don't parse, like tuples of composite values, are compared as written.
+        (Some(expected), Some(actual)) => match expected.parse::<Value>() {
+            Ok(expected) => expected == *actual,
+            Err(()) => normalize(expected) == normalize(&actual.to_string()),
+        },
+        _ => false,
+    };
+    if matches {
+        return None;
+    }
+    let show = |value: Option<String>| value.unwrap_or_else(|| "(absent)".to_string());
+    Some(format!(
+        "  {label}\n    {} {}\n    {} {}",
+        "- expected:".red(),
+        show(expected.cloned()),
+        "+ actual:  ".green(),
+        show(actual.map(ToString::to_string)),
+    ))
+}
+
+/// `text` without whitespace.
+fn normalize(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(json: &str) -> Fixture {
+        serde_json::from_str(json).unwrap()
+    }
+
+    /// Verifies fixture entries resolve against the program under test, storage variables lowering to their mapping.
+    #[test]
+    fn fixture_entries_resolve() {
+        let json = r#"{
+            "mappings": [{ "mapping": "balances", "key": "0u8", "value": "100u64" }],
+            "storage": [{ "program": "other.aleo", "variable": "counter", "value": "1u32" }],
+            "expect_mappings": [{ "mapping": "balances", "key": "1u8", "value": null }]
+        }"#;
+        let expectations = Expectations::new(fixture(json), None, None, false, "token.aleo").unwrap();
+
+        let seeds = expectations.seeds.iter().map(|s| (s.program.clone().unwrap(), s.mapping.as_str(), s.key.as_str()));
+        assert_eq!(seeds.collect::<Vec<_>>(), vec![
+            ("token.aleo".to_string(), "balances", "0u8"),
+            ("other.aleo".to_string(), "counter__", "false"),
+        ]);
+        assert_eq!(expectations.entries[0].label, "token.aleo/balances[1u8]");
+        assert_eq!(expectations.entries[0].value, None);
+    }
+
+    /// Verifies invalid fixtures are rejected before any test runs.
+    #[test]
+    fn invalid_fixtures_are_rejected() {
+        let seed_without_value = r#"{ "mappings": [{ "mapping": "balances", "key": "0u8", "value": null }] }"#;
+        assert!(Expectations::new(fixture(seed_without_value), None, None, false, "token.aleo").is_err());
+
+        let invalid_key = r#"{ "expect_mappings": [{ "mapping": "balances", "key": "0", "value": "1u8" }] }"#;
+        assert!(Expectations::new(fixture(invalid_key), None, None, false, "token.aleo").is_err());
+
+        let output = r#"{ "output": "1u8" }"#;
+        assert!(Expectations::new(fixture(output), Some(&"1u8".to_string()), None, false, "token.aleo").is_err());
+        assert!(Expectations::new(Fixture::default(), None, Some(&"overflow".to_string()), false, "t.aleo").is_err());
+
+        assert!(serde_json::from_str::<Fixture>(r#"{ "outputs": "1u8" }"#).is_err());
+    }
+
+    /// Verifies only mismatched values are reported, with absent entries on either side.
+    #[test]
+    fn diff_reports_mismatches() {
+        let value = |text: &str| text.parse::<Value>().unwrap();
+        assert_eq!(diff("output", Some(&"5u32".to_string()), Some(&value("5u32"))), None);
+        assert_eq!(diff("output", Some(&"{ a: 1u8 }".to_string()), Some(&value("{a:1u8}"))), None);
+        assert_eq!(diff("entry", None, None), None);
+
+        let mismatch = diff("output", Some(&"5u32".to_string()), Some(&value("6u32"))).unwrap();
+        assert!(mismatch.contains("5u32") && mismatch.contains("6u32"));
+        assert!(diff("entry", Some(&"1u8".to_string()), None).unwrap().contains("(absent)"));
+    }
+}
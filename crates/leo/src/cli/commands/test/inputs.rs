@@ -0,0 +1,543 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Inputs for `@test` functions that take parameters.
+//!
+//! A test's inputs either come from the `inputs` table on its annotation, one case per row, or
+//! are generated from its parameter types in the test program's ABI. Generated inputs that make
+//! a test fail are shrunk toward the simplest failing values before they are reported.
+//!
+//! Values are written as `leo run` takes them, so optionals appear in their lowered form,
+//! `{ is_some: true, val: 5u8 }`.
+
+use leo_abi::{Int, Plaintext, Primitive, Program as Abi, UInt};
+use leo_ast::{Annotation, TEST_PRIVATE_KEY};
+use leo_package::bare_unit_name;
+
+use snarkvm::prelude::{Address, Field, PrivateKey, TestnetV0};
+
+use indexmap::IndexMap;
+use std::{fmt, str::FromStr as _};
+
+/// The number of cases generated for a test whose annotation does not set `runs`.
+pub(super) const DEFAULT_RUNS: usize = 16;
+
+/// The most rounds spent shrinking a failing case. Each round runs its candidates on a fresh ledger.
+pub(super) const MAX_SHRINK_ROUNDS: usize = 64;
+
+/// The most candidates tried in one shrinking round.
+const MAX_SHRINK_CANDIDATES: usize = 32;
+
+/// A parameter type that inputs can be generated for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum Shape {
+    Boolean,
+    Signed { ty: Int, min: i128, max: i128 },
+    Unsigned { ty: UInt, min: u128, max: u128 },
+    Field,
+    Scalar,
+    Address,
+    Array { element: Box<Shape>, length: u32 },
+    Struct { fields: Vec<(String, Shape)> },
+    Optional(Box<Shape>),
+}
+
+/// A generated input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum Value {
+    Boolean(bool),
+    Signed(i128, Int),
+    Unsigned(u128, UInt),
+    Field(u128),
+    Scalar(u128),
+    /// The address of an account: `0` is the default test account, any other index one derived from it.
+    Address(u64),
+    Array(Vec<Value>),
+    Struct(Vec<(String, Value)>),
+    /// An optional in its lowered form. `val` is the zero value of its type when `is_some` is false.
+    Optional {
+        is_some: bool,
+        val: Box<Value>,
+    },
+}
+
+impl Shape {
+    /// Resolves `ty`, as written in the ABI of `program`, against the ABIs of the package.
+    pub(super) fn resolve(ty: &Plaintext, program: &str, abis: &IndexMap<String, Abi>) -> Result<Self, String> {
+        Ok(match ty {
+            Plaintext::Primitive(Primitive::Boolean) => Shape::Boolean,
+            Plaintext::Primitive(Primitive::Int(ty)) => {
+                let bits = int_bits(*ty);
+                Shape::Signed { ty: *ty, min: i128::MIN >> (128 - bits), max: i128::MAX >> (128 - bits) }
+            }
+            Plaintext::Primitive(Primitive::UInt(ty)) => {
+                Shape::Unsigned { ty: *ty, min: 0, max: u128::MAX >> (128 - uint_bits(*ty)) }
+            }
+            Plaintext::Primitive(Primitive::Field) => Shape::Field,
+            Plaintext::Primitive(Primitive::Scalar) => Shape::Scalar,
+            Plaintext::Primitive(Primitive::Address) => Shape::Address,
+            Plaintext::Primitive(primitive @ (Primitive::Group | Primitive::Identifier | Primitive::Signature)) => {
+                let name = format!("{primitive:?}").to_lowercase();
+                return Err(format!("`{name}` values cannot be generated; list them in an `inputs` table instead"));
+            }
+            Plaintext::Array(array) => {
+                Shape::Array { element: Box::new(Self::resolve(&array.element, program, abis)?), length: array.length }
+            }
+            Plaintext::Struct(reference) => {
+                let program = reference.program.as_deref().map(bare_unit_name).unwrap_or(program);
+                let name = reference.path.join("::");
+                let definition = abis
+                    .get(program)
+                    .and_then(|abi| abi.structs.iter().find(|definition| definition.path == reference.path))
+                    .ok_or_else(|| format!("struct `{name}` is not in the ABI of `{program}`"))?;
+                let fields = definition
+                    .fields
+                    .iter()
+                    .map(|field| Ok((field.name.clone(), Self::resolve(&field.ty, program, abis)?)))
+                    .collect::<Result<_, String>>()?;
+                Shape::Struct { fields }
+            }
+            Plaintext::Optional(optional) => Shape::Optional(Box::new(Self::resolve(&optional.0, program, abis)?)),
+        })
+    }
+
+    /// Narrows an integer shape to `range`, written `lo..hi`, `lo..=hi`, `lo..`, or `..hi`.
+    pub(super) fn bounded(self, range: &str) -> Result<Self, String> {
+        let (lo, hi, inclusive) = match range.split_once("..=") {
+            Some((lo, hi)) => (lo.trim(), hi.trim(), true),
+            None => {
+                let (lo, hi) = range.split_once("..").ok_or_else(|| format!("`{range}` is not a range"))?;
+                (lo.trim(), hi.trim(), false)
+            }
+        };
+        let empty = || format!("`{range}` is empty or out of range for the parameter's type");
+        match self {
+            Shape::Signed { ty, min, max } => {
+                let suffix = format!("{ty:?}").to_lowercase();
+                let lo = if lo.is_empty() { min } else { parse_bound::<i128>(lo, &suffix)? };
+                let hi = match (hi.is_empty(), inclusive) {
+                    (true, _) => max,
+                    (false, true) => parse_bound::<i128>(hi, &suffix)?,
+                    (false, false) => parse_bound::<i128>(hi, &suffix)?.checked_sub(1).ok_or_else(empty)?,
+                };
+                if lo < min || hi > max || lo > hi {
+                    return Err(empty());
+                }
+                Ok(Shape::Signed { ty, min: lo, max: hi })
+            }
+            Shape::Unsigned { ty, min, max } => {
+                let suffix = format!("{ty:?}").to_lowercase();
+                let lo = if lo.is_empty() { min } else { parse_bound::<u128>(lo, &suffix)? };
+                let hi = match (hi.is_empty(), inclusive) {
+                    (true, _) => max,
+                    (false, true) => parse_bound::<u128>(hi, &suffix)?,
+                    (false, false) => parse_bound::<u128>(hi, &suffix)?.checked_sub(1).ok_or_else(empty)?,
+                };
+                if lo < min || hi > max || lo > hi {
+                    return Err(empty());
+                }
+                Ok(Shape::Unsigned { ty, min: lo, max: hi })
+            }
+            _ => Err(format!("`{range}` bounds a parameter that is not an integer")),
+        }
+    }
+
+    /// The simplest value of the shape.
+    fn zero(&self) -> Value {
+        match self {
+            Shape::Boolean => Value::Boolean(false),
+            Shape::Signed { ty, min, max } => Value::Signed(0i128.clamp(*min, *max), *ty),
+            Shape::Unsigned { ty, min, .. } => Value::Unsigned(*min, *ty),
+            Shape::Field => Value::Field(0),
+            Shape::Scalar => Value::Scalar(0),
+            Shape::Address => Value::Address(0),
+            Shape::Array { element, length } => Value::Array((0..*length).map(|_| element.zero()).collect()),
+            Shape::Struct { fields } => {
+                Value::Struct(fields.iter().map(|(name, shape)| (name.clone(), shape.zero())).collect())
+            }
+            Shape::Optional(inner) => Value::Optional { is_some: false, val: Box::new(inner.zero()) },
+        }
+    }
+}
+
+/// A small deterministic random number generator (SplitMix64), so that a seed reproduces the same
+/// inputs on every platform and release.
+pub(super) struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// A generator for the test named `name`, so that the inputs of one test don't depend on which others run.
+    pub(super) fn new(seed: u64, name: &str) -> Self {
+        // FNV-1a.
+        let hash = name
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3));
+        Self { state: seed ^ hash }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64())
+    }
+
+    /// A number in `0..n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// A uniformly chosen number in `0..=span`.
+    fn up_to(&mut self, span: u128) -> u128 {
+        match span.checked_add(1) {
+            Some(n) => self.next_u128() % n,
+            None => self.next_u128(),
+        }
+    }
+
+    fn pick<T: Copy>(&mut self, choices: &[T]) -> T {
+        choices[self.below(choices.len() as u64) as usize]
+    }
+
+    /// Generates one input for each of `shapes`.
+    pub(super) fn generate_row(&mut self, shapes: &[Shape]) -> Vec<Value> {
+        shapes.iter().map(|shape| self.generate(shape)).collect()
+    }
+
+    /// Generates a value of `shape`. A quarter of integers are edge values and another quarter are
+    /// small, since those find most overflow and off-by-one bugs; the rest span the whole range.
+    fn generate(&mut self, shape: &Shape) -> Value {
+        match shape {
+            Shape::Boolean => Value::Boolean(self.below(2) == 1),
+            Shape::Signed { ty, min, max } => {
+                let value = match self.below(4) {
+                    0 => self.pick(&[*min, *max, 0, 1, -1, min.saturating_add(1), max.saturating_sub(1)]),
+                    1 => self.below(512) as i128 - 256,
+                    _ => min.wrapping_add_unsigned(self.up_to(max.abs_diff(*min))),
+                };
+                Value::Signed(value.clamp(*min, *max), *ty)
+            }
+            Shape::Unsigned { ty, min, max } => {
+                let value = match self.below(4) {
+                    0 => self.pick(&[*min, *max, 0, 1, min.saturating_add(1), max.saturating_sub(1)]),
+                    1 => min.saturating_add(u128::from(self.below(256))),
+                    _ => min + self.up_to(max - min),
+                };
+                Value::Unsigned(value.clamp(*min, *max), *ty)
+            }
+            Shape::Field => Value::Field(self.generate_element()),
+            Shape::Scalar => Value::Scalar(self.generate_element()),
+            Shape::Address => Value::Address(if self.below(4) == 0 { 0 } else { 1 + self.below(1023) }),
+            Shape::Array { element, length } => Value::Array((0..*length).map(|_| self.generate(element)).collect()),
+            Shape::Struct { fields } => {
+                Value::Struct(fields.iter().map(|(name, shape)| (name.clone(), self.generate(shape))).collect())
+            }
+            Shape::Optional(inner) => match self.below(4) {
+                0 => inner.zero().into_optional(false),
+                _ => self.generate(inner).into_optional(true),
+            },
+        }
+    }
+
+    /// A field or scalar element. Both moduli exceed `u128::MAX`, so any `u128` is valid.
+    fn generate_element(&mut self) -> u128 {
+        match self.below(4) {
+            0 => self.pick(&[0, 1]),
+            1 => u128::from(self.below(256)),
+            _ => self.next_u128(),
+        }
+    }
+}
+
+impl Value {
+    fn into_optional(self, is_some: bool) -> Value {
+        Value::Optional { is_some, val: Box::new(self) }
+    }
+
+    /// Simpler values of `shape` to try in place of this one, simplest first.
+    fn shrink(&self, shape: &Shape) -> Vec<Value> {
+        match (self, shape) {
+            (Value::Boolean(true), _) => vec![Value::Boolean(false)],
+            (Value::Signed(value, ty), Shape::Signed { min, max, .. }) => {
+                toward_signed(*value, 0i128.clamp(*min, *max))
+                    .into_iter()
+                    .map(|value| Value::Signed(value, *ty))
+                    .collect()
+            }
+            (Value::Unsigned(value, ty), Shape::Unsigned { min, .. }) => {
+                toward_unsigned(*value, *min).into_iter().map(|value| Value::Unsigned(value, *ty)).collect()
+            }
+            (Value::Field(value), _) => toward_unsigned(*value, 0).into_iter().map(Value::Field).collect(),
+            (Value::Scalar(value), _) => toward_unsigned(*value, 0).into_iter().map(Value::Scalar).collect(),
+            (Value::Address(index), _) => {
+                toward_unsigned(u128::from(*index), 0).into_iter().map(|index| Value::Address(index as u64)).collect()
+            }
+            (Value::Array(elements), Shape::Array { element, .. }) => {
+                let shapes = vec![element.as_ref().clone(); elements.len()];
+                shrink_each(elements, &shapes).into_iter().map(Value::Array).collect()
+            }
+            (Value::Struct(values), Shape::Struct { fields }) => {
+                let (names, values): (Vec<_>, Vec<_>) = values.iter().cloned().unzip();
+                let shapes = fields.iter().map(|(_, shape)| shape.clone()).collect::<Vec<_>>();
+                shrink_each(&values, &shapes)
+                    .into_iter()
+                    .map(|values| Value::Struct(names.iter().cloned().zip(values).collect()))
+                    .collect()
+            }
+            (Value::Optional { is_some: true, val }, Shape::Optional(inner)) => {
+                let shrunk = val.shrink(inner).into_iter().map(|val| val.into_optional(true));
+                std::iter::once(inner.zero().into_optional(false)).chain(shrunk).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    /// Writes the value as `leo run` takes it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Boolean(value) => write!(f, "{value}"),
+            Value::Signed(value, ty) => write!(f, "{value}{}", format!("{ty:?}").to_lowercase()),
+            Value::Unsigned(value, ty) => write!(f, "{value}{}", format!("{ty:?}").to_lowercase()),
+            Value::Field(value) => write!(f, "{value}field"),
+            Value::Scalar(value) => write!(f, "{value}scalar"),
+            Value::Address(index) => write!(f, "{}", account(*index)),
+            Value::Array(elements) => {
+                write!(f, "[{}]", elements.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))
+            }
+            Value::Struct(fields) => {
+                let fields = fields.iter().map(|(name, value)| format!("{name}: {value}")).collect::<Vec<_>>();
+                write!(f, "{{ {} }}", fields.join(", "))
+            }
+            Value::Optional { is_some, val } => write!(f, "{{ is_some: {is_some}, val: {val} }}"),
+        }
+    }
+}
+
+/// Rows of simpler inputs to try in place of the failing `row`, with the candidates for each
+/// parameter interleaved so that every parameter is shrunk in each round.
+pub(super) fn shrink_row(row: &[Value], shapes: &[Shape]) -> Vec<Vec<Value>> {
+    let mut candidates = shrink_each(row, shapes);
+    candidates.truncate(MAX_SHRINK_CANDIDATES);
+    candidates
+}
+
+/// Copies of `values` with one of them replaced by a simpler value, interleaved across positions.
+fn shrink_each(values: &[Value], shapes: &[Shape]) -> Vec<Vec<Value>> {
+    let mut per_position = values
+        .iter()
+        .zip(shapes)
+        .enumerate()
+        .map(|(position, (value, shape))| {
+            value
+                .shrink(shape)
+                .into_iter()
+                .map(|simpler| {
+                    let mut candidate = values.to_vec();
+                    candidate[position] = simpler;
+                    candidate
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+        })
+        .collect::<Vec<_>>();
+
+    let mut candidates = Vec::new();
+    loop {
+        let before = candidates.len();
+        candidates.extend(per_position.iter_mut().filter_map(Iterator::next));
+        if candidates.len() == before {
+            return candidates;
+        }
+    }
+}
+
+/// Values from `target` toward `value`, excluding `value`: `target` itself, then ever closer halfway points.
+fn toward_unsigned(value: u128, target: u128) -> Vec<u128> {
+    let mut distance = value.abs_diff(target);
+    let mut candidates = Vec::new();
+    while distance > 0 {
+        candidates.push(if value > target { value - distance } else { value + distance });
+        distance /= 2;
+    }
+    candidates
+}
+
+/// Values from `target` toward `value`, excluding `value`: `target` itself, then ever closer halfway points.
+fn toward_signed(value: i128, target: i128) -> Vec<i128> {
+    let mut distance = value.abs_diff(target);
+    let mut candidates = Vec::new();
+    while distance > 0 {
+        candidates.push(if value > target {
+            value.wrapping_sub_unsigned(distance)
+        } else {
+            value.wrapping_add_unsigned(distance)
+        });
+        distance /= 2;
+    }
+    candidates
+}
+
+/// The address of the account with `index`: the default test account for `0`, otherwise one derived from `index`.
+fn account(index: u64) -> String {
+    let private_key = match index {
+        0 => PrivateKey::<TestnetV0>::from_str(TEST_PRIVATE_KEY),
+        _ => PrivateKey::<TestnetV0>::try_from(Field::<TestnetV0>::from_u64(index)),
+    };
+    private_key.and_then(Address::try_from).map(|address| address.to_string()).unwrap_or_default()
+}
+
+fn int_bits(ty: Int) -> u32 {
+    match ty {
+        Int::I8 => 8,
+        Int::I16 => 16,
+        Int::I32 => 32,
+        Int::I64 => 64,
+        Int::I128 => 128,
+    }
+}
+
+fn uint_bits(ty: UInt) -> u32 {
+    match ty {
+        UInt::U8 => 8,
+        UInt::U16 => 16,
+        UInt::U32 => 32,
+        UInt::U64 => 64,
+        UInt::U128 => 128,
+    }
+}
+
+/// Parses an integer bound, which may carry its type's `suffix` and `_` separators.
+fn parse_bound<T: std::str::FromStr>(text: &str, suffix: &str) -> Result<T, String> {
+    let digits = text.strip_suffix(suffix).unwrap_or(text).replace('_', "");
+    digits.parse().map_err(|_| format!("`{text}` is not a `{suffix}` bound"))
+}
+
+/// Parses an `inputs` table: a list of rows, each a list of `arity` values.
+pub(super) fn parse_table(text: &str, arity: usize) -> Result<Vec<Vec<String>>, String> {
+    let rows = Annotation::split_list(text).ok_or_else(|| format!("`{text}` is not a list of rows"))?;
+    if rows.is_empty() {
+        return Err("the table has no rows".to_string());
+    }
+    rows.into_iter()
+        .map(|row| {
+            let values = Annotation::split_list(row).ok_or_else(|| format!("row `{row}` is not a list of values"))?;
+            if values.len() != arity {
+                return Err(format!("row `{row}` has {} values, but the test takes {arity}", values.len()));
+            }
+            Ok(values.into_iter().map(str::to_string).collect())
+        })
+        .collect()
+}
+
+/// Parses `bounds`, written `name: range, ...`, into the range of each named parameter.
+pub(super) fn parse_bounds(text: &str, parameters: &[String]) -> Result<IndexMap<String, String>, String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|bound| !bound.is_empty())
+        .map(|bound| {
+            let (name, range) = bound.split_once(':').ok_or_else(|| format!("`{bound}` is not `name: range`"))?;
+            let name = name.trim();
+            if !parameters.iter().any(|parameter| parameter == name) {
+                return Err(format!("`{name}` in `bounds` is not a parameter of the test"));
+            }
+            Ok((name.to_string(), range.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn u8_shape() -> Shape {
+        Shape::Unsigned { ty: UInt::U8, min: 0, max: 255 }
+    }
+
+    /// Verifies an `inputs` table splits into rows of values, keeping nested arrays and structs whole.
+    #[test]
+    fn parse_table_splits_nested_values() {
+        let rows = parse_table("[[1u8, [2u8, 3u8]], [4u8, { x: 5u8, y: [6u8] }],]", 2).unwrap();
+        assert_eq!(rows, vec![vec!["1u8", "[2u8, 3u8]"], vec!["4u8", "{ x: 5u8, y: [6u8] }"]]);
+
+        assert!(parse_table("[[1u8]]", 2).unwrap_err().contains("has 1 values"));
+        assert!(parse_table("[1u8, 2u8]", 1).is_err());
+        assert!(parse_table("[[1u8, [2u8]]", 2).is_err());
+        assert!(parse_table("[]", 0).is_err());
+    }
+
+    /// Verifies bounds narrow integer shapes and reject empty or out-of-range ranges.
+    #[test]
+    fn bounds_narrow_integers() {
+        assert_eq!(u8_shape().bounded("1..=10").unwrap(), Shape::Unsigned { ty: UInt::U8, min: 1, max: 10 });
+        assert_eq!(u8_shape().bounded("..100u8").unwrap(), Shape::Unsigned { ty: UInt::U8, min: 0, max: 99 });
+        assert!(u8_shape().bounded("5..5").is_err());
+        assert!(u8_shape().bounded("0..=256").is_err());
+        assert!(Shape::Field.bounded("0..1").is_err());
+
+        let i8_shape = Shape::Signed { ty: Int::I8, min: -128, max: 127 };
+        assert_eq!(i8_shape.bounded("-5..").unwrap(), Shape::Signed { ty: Int::I8, min: -5, max: 127 });
+    }
+
+    /// Verifies generated values respect bounds and that a seed reproduces the same values.
+    #[test]
+    fn generation_is_bounded_and_reproducible() {
+        let shapes = vec![
+            Shape::Unsigned { ty: UInt::U16, min: 10, max: 20 },
+            Shape::Signed { ty: Int::I8, min: -128, max: 127 },
+            Shape::Array { element: Box::new(Shape::Optional(Box::new(Shape::Boolean))), length: 3 },
+        ];
+        let rows = |seed| {
+            let mut generator = Generator::new(seed, "test.aleo/prop");
+            (0..64).map(|_| generator.generate_row(&shapes)).collect::<Vec<_>>()
+        };
+        let first = rows(7);
+        assert_eq!(first, rows(7));
+        assert_ne!(first, rows(8));
+        for row in &first {
+            let Value::Unsigned(value, _) = row[0] else { panic!("expected an unsigned value") };
+            assert!((10..=20).contains(&value));
+            let Value::Array(elements) = &row[2] else { panic!("expected an array") };
+            assert_eq!(elements.len(), 3);
+        }
+    }
+
+    /// Verifies shrinking walks a failing case down to the smallest value that still fails.
+    #[test]
+    fn shrinking_finds_the_smallest_failing_value() {
+        let shapes = vec![Shape::Boolean, u8_shape()];
+        // The "test" fails whenever the second input is at least 37.
+        let fails = |row: &[Value]| matches!(row[1], Value::Unsigned(value, _) if value >= 37);
+
+        let mut row = vec![Value::Boolean(true), Value::Unsigned(200, UInt::U8)];
+        while let Some(smaller) = shrink_row(&row, &shapes).into_iter().find(|candidate| fails(candidate)) {
+            row = smaller;
+        }
+        assert_eq!(row, vec![Value::Boolean(false), Value::Unsigned(37, UInt::U8)]);
+        assert_eq!(row.iter().map(ToString::to_string).collect::<Vec<_>>(), ["false", "37u8"]);
+
+        let optional = Value::Unsigned(9, UInt::U8).into_optional(true);
+        let candidates = optional.shrink(&Shape::Optional(Box::new(u8_shape())));
+        assert_eq!(candidates[0].to_string(), "{ is_some: false, val: 0u8 }");
+    }
+}
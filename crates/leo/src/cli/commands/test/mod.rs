@@ -0,0 +1,1063 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod filter;
+mod fixtures;
+mod inputs;
+mod report;
+mod scenario;
+
+use super::*;
+
+use leo_ast::{NetworkName, NodeBuilder, Stub, TEST_PRIVATE_KEY};
+use leo_compiler::{AleoSourceMap, Compiler, Trace, run};
+use leo_debugger::{ChainState, Coverage, DebugProgram, Debugger, Environment, Execution, FinalizeOutcome, Unattended};
+use leo_package::{Package, ProgramData, bare_unit_name};
+use leo_span::{Symbol, sym};
+
+use snarkvm::prelude::{CanaryV0, MainnetV0, TestnetV0};
+
+use colored::Colorize as _;
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Test a leo program.
+#[derive(Parser, Debug)]
+pub struct LeoTest {
+    #[clap(
+        name = "TEST_NAME",
+        help = "If specified, run only tests whose qualified name matches against this string.",
+        default_value = ""
+    )]
+    pub(crate) test_name: String,
+
+    #[clap(long, help = "Run all tests with full proof generation.", default_value = "false")]
+    pub(crate) prove: bool,
+
+    #[clap(
+        long,
+        help = "Report which statements, branches, and functions of the program the tests execute. \
+            Writes `lcov.info` and `index.html` to `build/<program>/coverage/`."
+    )]
+    pub(crate) coverage: bool,
+
+    #[clap(
+        long,
+        help = "Seed for generated test inputs and the test ledger. Defaults to a random seed when any test \
+            generates its inputs."
+    )]
+    pub(crate) seed: Option<u64>,
+
+    #[clap(long, help = "Match TEST_NAME and --exclude against qualified test names as regular expressions.")]
+    pub(crate) regex: bool,
+
+    #[clap(long, help = "Skip tests whose qualified name matches this string. May be repeated.")]
+    pub(crate) exclude: Vec<String>,
+
+    #[clap(
+        long = "tag",
+        help = "Run only tests carrying this tag, set with `@test(tags = \"...\")`. May be repeated."
+    )]
+    pub(crate) tags: Vec<String>,
+
+    #[clap(long = "skip-tag", help = "Skip tests carrying this tag. May be repeated.")]
+    pub(crate) skip_tags: Vec<String>,
+
+    #[clap(
+        long,
+        help = "The number of worker threads running ledger tests, each deploying the programs to a ledger of its \
+            own. Defaults to the number of CPUs."
+    )]
+    pub(crate) jobs: Option<usize>,
+
+    #[clap(long, help = "Stop running tests once one fails.")]
+    pub(crate) fail_fast: bool,
+
+    #[clap(long, help = "Write a JUnit XML report of the results to this file.")]
+    pub(crate) junit: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+    #[clap(flatten)]
+    pub(crate) env_override: EnvOptions,
+}
+
+impl Command for LeoTest {
+    type Input = <LeoBuild as Command>::Output;
+    type Output = TestOutput;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        let mut options = self.compiler_options.clone();
+        options.build_tests = true;
+        (LeoBuild { env_override: self.env_override.clone(), options, rename: None }).execute(context)
+    }
+
+    fn apply(self, _: Context, input: Self::Input) -> Result<Self::Output> {
+        let junit = self.junit.clone();
+        let output = handle_test(self, input)?;
+        if let Some(path) = junit {
+            write_junit(&path, &output)?;
+        }
+        Ok(output)
+    }
+
+    fn execute(self, context: Context) -> Result<Self::Output> {
+        // Check for workspace mode before falling through to the default
+        // prelude+apply flow, because test needs to build and test each
+        // member independently.
+        match context.resolve_targets()? {
+            Some((_, targets)) if targets.len() > 1 => {
+                let mut aggregate = TestOutput::default();
+                for target in &targets {
+                    let member_name = target.file_name().and_then(|n| n.to_str()).unwrap_or("?");
+                    println!("\n--- workspace member '{member_name}' ---");
+
+                    let member_ctx = context.with_path(target.clone());
+
+                    // Build with tests.
+                    let mut opts = self.compiler_options.clone();
+                    opts.build_tests = true;
+                    let package = (LeoBuild { env_override: self.env_override.clone(), options: opts, rename: None })
+                        .execute(member_ctx)?;
+
+                    // Run tests for this member.
+                    let member_test = LeoTest {
+                        test_name: self.test_name.clone(),
+                        prove: self.prove,
+                        coverage: self.coverage,
+                        seed: self.seed,
+                        regex: self.regex,
+                        exclude: self.exclude.clone(),
+                        tags: self.tags.clone(),
+                        skip_tags: self.skip_tags.clone(),
+                        jobs: self.jobs,
+                        fail_fast: self.fail_fast,
+                        junit: None,
+                        compiler_options: self.compiler_options.clone(),
+                        env_override: self.env_override.clone(),
+                    };
+                    let result = handle_test(member_test, package)?;
+                    aggregate.passed += result.passed;
+                    aggregate.failed += result.failed;
+                    aggregate.skipped += result.skipped;
+                    aggregate.tests.extend(result.tests);
+                    if self.fail_fast && aggregate.failed > 0 {
+                        break;
+                    }
+                }
+                // One report covers every member, with a test suite per program.
+                if let Some(path) = &self.junit {
+                    write_junit(path, &aggregate)?;
+                }
+                Ok(aggregate)
+            }
+            _ => {
+                // Single target or no workspace - use the normal flow.
+                let input = self.prelude(context.clone())?;
+                let span = self.log_span();
+                let span = span.enter();
+                let out = self.apply(context, input);
+                drop(span);
+                out
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TestFunction {
+    program: String,
+    function: String,
+    should_fail: bool,
+    private_key: Option<String>,
+    /// Whether the test runs in the interpreter, as a `@test(mode = "native")` test, rather than on a ledger.
+    native: bool,
+    /// The names of the test's parameters.
+    parameters: Vec<String>,
+    inputs: TestInputs,
+    /// The fixtures the test runs from and the results it must produce.
+    expectations: fixtures::Expectations,
+}
+
+/// Where the inputs of a test come from.
+#[derive(Clone)]
+enum TestInputs {
+    /// The rows of an `inputs` table, each run as its own case. A test without parameters has a single empty row.
+    Table(Vec<Vec<String>>),
+    /// `runs` cases generated from the parameter types, with integer parameters narrowed by `bounds`.
+    Generated { runs: usize, bounds: IndexMap<String, String> },
+}
+
+impl TestFunction {
+    /// The name results are reported under, e.g. `test_token.aleo/test_transfer`.
+    fn name(&self) -> String {
+        format!("{}.aleo/{}", self.program, self.function)
+    }
+
+    /// A case running the test with `input`.
+    fn case(&self, input: Vec<String>) -> run::Case {
+        run::Case {
+            program_name: format!("{}.aleo", self.program),
+            function: self.function.clone(),
+            private_key: self.private_key.clone(),
+            input,
+            seed_mapping: self.expectations.seeds.clone(),
+            read_mappings: self.expectations.read_mappings(),
+            linked_inputs: Vec::new(),
+            advance_blocks: 0,
+        }
+    }
+}
+
+/// Discover `@test`-annotated entry point functions from the compiled package.
+///
+/// Walks the Leo source files, parses them, and extracts functions with the
+/// `@test` annotation that are entry points (transitions), along with where
+/// the inputs for their parameters come from and what they are expected to do.
+/// Also returns the test programs whose tests all run natively, which need no deployment.
+fn discover_test_functions(
+    package: &Package,
+    filter: &filter::TestFilter,
+    network: NetworkName,
+) -> Result<(Vec<TestFunction>, IndexSet<Symbol>)> {
+    use indexmap::IndexMap;
+    use leo_ast::NodeBuilder;
+    use leo_compiler::Compiler;
+    use leo_errors::Handler;
+    use std::rc::Rc;
+
+    let mut test_functions = Vec::new();
+    let mut native_only = IndexSet::new();
+
+    for unit in &package.compilation_units {
+        let ProgramData::SourcePath { directory, source } = &unit.data else {
+            continue;
+        };
+
+        let handler = Handler::default();
+        let node_builder = Rc::new(NodeBuilder::default());
+
+        let mut compiler =
+            Compiler::new(None, unit.kind.is_test(), handler, node_builder, None, IndexMap::new(), network);
+
+        // A test is a single standalone file; its `tests/` siblings are independent programs,
+        // so parse only this file rather than scanning the directory for modules.
+        let ast = if unit.kind.is_test() {
+            compiler.parse_program_from_file(source)
+        } else {
+            compiler.parse_program_from_directory(source, directory.join("src"))
+        };
+        let ast = match ast {
+            Ok(ast) => ast,
+            Err(_) => continue,
+        };
+
+        // A test file's fixtures sit next to it, e.g. `tests/test_token.fixtures.json`.
+        let fixture_path = source.with_extension(fixtures::FIXTURE_EXTENSION);
+        let mut fixture_file = match fs::read_to_string(&fixture_path) {
+            Ok(json) if unit.kind.is_test() => serde_json::from_str::<fixtures::FixtureFile>(&json).map_err(|e| {
+                crate::errors::custom(format!("Failed to read fixtures from {}: {e}", fixture_path.display()))
+            })?,
+            _ => fixtures::FixtureFile::new(),
+        };
+
+        for scope in ast.program_scopes.values() {
+            let program_name = scope.program_id.name.to_string();
+
+            for (_, function) in &scope.functions {
+                let has_test = function.annotations.iter().any(|a| a.identifier.name == sym::test);
+                if !has_test {
+                    continue;
+                }
+
+                if !function.variant.is_entry() {
+                    continue;
+                }
+
+                let annotation = function.annotations.iter().find(|a| a.identifier.name == sym::test);
+                let get = |key: Symbol| annotation.and_then(|a| a.map.get(&key));
+
+                let qualified = format!("{program_name}.aleo/{}", function.identifier);
+                let tags = get(sym::tags).map(|tags| filter::parse_tags(tags)).unwrap_or_default();
+                if !filter.matches(&qualified, &tags) {
+                    continue;
+                }
+
+                let should_fail = function.annotations.iter().any(|a| a.identifier.name == sym::should_fail);
+                let private_key = get(sym::private_key).cloned();
+                let native = get(sym::mode).is_some_and(|mode| mode == "native");
+                let should_fail_annotation =
+                    function.annotations.iter().find(|a| a.identifier.name == sym::should_fail);
+                let message = should_fail_annotation.and_then(|a| a.map.get(&sym::message));
+
+                let parameters = function.input.iter().map(|input| input.identifier.to_string()).collect::<Vec<_>>();
+                let invalid = |reason: String| {
+                    crate::errors::invalid_test_inputs(
+                        format_args!("{program_name}.aleo/{}", function.identifier),
+                        reason,
+                    )
+                };
+                let inputs = match get(sym::inputs) {
+                    Some(_) if get(sym::runs).is_some() || get(sym::bounds).is_some() => {
+                        let reason = "`runs` and `bounds` apply to generated inputs, not to an `inputs` table";
+                        return Err(invalid(reason.to_string()).into());
+                    }
+                    Some(table) => TestInputs::Table(inputs::parse_table(table, parameters.len()).map_err(invalid)?),
+                    None if parameters.is_empty() => TestInputs::Table(vec![Vec::new()]),
+                    None => {
+                        let runs = match get(sym::runs) {
+                            Some(runs) => runs
+                                .parse::<usize>()
+                                .ok()
+                                .filter(|runs| *runs > 0)
+                                .ok_or_else(|| invalid(format!("`runs = \"{runs}\"` is not a positive number")))?,
+                            None => inputs::DEFAULT_RUNS,
+                        };
+                        let bounds = match get(sym::bounds) {
+                            Some(bounds) => inputs::parse_bounds(bounds, &parameters).map_err(invalid)?,
+                            None => IndexMap::new(),
+                        };
+                        TestInputs::Generated { runs, bounds }
+                    }
+                };
+                if native && matches!(inputs, TestInputs::Generated { .. }) {
+                    return Err(invalid("native tests take their inputs from an `inputs` table".to_string()).into());
+                }
+
+                let fixture = fixture_file.shift_remove(&function.identifier.to_string()).unwrap_or_default();
+                let expectations = fixtures::Expectations::new(
+                    fixture,
+                    get(sym::output),
+                    message,
+                    should_fail,
+                    &package.manifest.program,
+                )
+                .map_err(|reason| {
+                    crate::errors::invalid_test_fixture(
+                        format_args!("{program_name}.aleo/{}", function.identifier),
+                        reason,
+                    )
+                })?;
+                if native && expectations.has_entries() {
+                    return Err(crate::errors::invalid_test_fixture(
+                        format_args!("{program_name}.aleo/{}", function.identifier),
+                        "native tests read and write mappings and storage in the test itself",
+                    )
+                    .into());
+                }
+
+                test_functions.push(TestFunction {
+                    program: program_name.clone(),
+                    function: function.identifier.to_string(),
+                    should_fail,
+                    private_key,
+                    native,
+                    parameters,
+                    inputs,
+                    expectations,
+                });
+            }
+        }
+
+        // Native tests are stripped from the bytecode, so a test program with only native tests has nothing to run.
+        let mut tests = ast.program_scopes.values().flat_map(|scope| &scope.functions).filter(|(_, f)| f.is_test());
+        if unit.kind.is_test() && tests.clone().next().is_some() && tests.all(|(_, f)| f.is_native_test()) {
+            native_only.insert(unit.name);
+        }
+
+        // Catch fixtures left behind for a test that was renamed or removed.
+        let is_test = |name: &String| {
+            ast.program_scopes.values().flat_map(|scope| &scope.functions).any(|(symbol, function)| {
+                symbol.to_string() == *name && function.annotations.iter().any(|a| a.identifier.name == sym::test)
+            })
+        };
+        if let Some(name) = fixture_file.keys().find(|name| !is_test(name)) {
+            return Err(crate::errors::custom(format!(
+                "{} has fixtures for `{name}`, which is not a `@test` function.",
+                fixture_path.display()
+            ))
+            .into());
+        }
+    }
+
+    Ok((test_functions, native_only))
+}
+
+fn handle_test(command: LeoTest, package: Package) -> Result<TestOutput> {
+    if package.compilation_units.last().map(|p| p.kind.is_library()).unwrap_or(false) {
+        return Err(crate::errors::custom("`leo test` is not supported for library packages.").into());
+    }
+
+    // Get the private key.
+    let _private_key = PrivateKey::<TestnetV0>::from_str(TEST_PRIVATE_KEY)?;
+
+    let network = command.env_override.network.unwrap_or(NetworkName::TestnetV0);
+    let filter =
+        filter::TestFilter::new(&command.test_name, &command.exclude, command.regex, &command.tags, &command.skip_tags)
+            .map_err(crate::errors::custom)?;
+    let (test_functions, native_only) = discover_test_functions(&package, &filter, network)?;
+
+    let credits = Symbol::intern("credits.aleo");
+
+    // Get bytecode and name for all programs, either directly or from the filesystem if they were compiled.
+    let programs: Vec<run::Program> = package
+        .compilation_units
+        .iter()
+        .filter_map(|unit| {
+            // Skip credits.aleo so we don't try to deploy it again.
+            if unit.name == credits {
+                return None;
+            }
+            // Libraries have no bytecode - their consts are inlined into the main program.
+            if unit.kind.is_library() || native_only.contains(&unit.name) {
+                return None;
+            }
+            let (bytecode, source_map) = match &unit.data {
+                ProgramData::Bytecode(c) => (c.clone(), None),
+                ProgramData::SourcePath { .. } => {
+                    // This was not a network dependency, so get its bytecode from its build directory.
+                    let aleo_path = package.unit_bytecode_path(&unit.name.to_string());
+                    let bytecode = fs::read_to_string(&aleo_path)
+                        .unwrap_or_else(|e| panic!("Failed to read Aleo file at {}: {}", aleo_path.display(), e));
+                    // The source map lets failures point at the Leo line that caused them.
+                    (bytecode, load_source_map(&package, &unit.name.to_string()))
+                }
+            };
+            Some(run::Program { bytecode, name: unit.name.to_string(), source_map })
+        })
+        .collect();
+
+    let generates = test_functions.iter().any(|test| matches!(test.inputs, TestInputs::Generated { .. }));
+    let seed = command.seed.unwrap_or_else(|| if generates { rand::random() } else { 0 });
+    if generates {
+        println!("Generating test inputs with seed {seed}.");
+    }
+    let config =
+        run::Config { seed, start_height: None, programs, skip_proving: !command.prove, trace: command.coverage };
+
+    let abis = if generates { load_abis(&package)? } else { IndexMap::new() };
+    let plans = test_functions.iter().map(|test| TestPlan::new(test, &abis, seed)).collect::<Result<Vec<_>>>()?;
+
+    let options = BuildOptions { no_std: package.manifest.no_std, ..command.compiler_options.clone() };
+    let mut native = NativeTests::new(&package, &test_functions, &options, network, command.coverage)?;
+    let scenarios = load_scenarios(&package, &filter, &config.programs)?;
+
+    let jobs = command.jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, usize::from)).max(1);
+    let ledger_tests = test_functions.iter().enumerate().filter(|(_, test)| !test.native).map(|(index, _)| index);
+    let shards = shard(ledger_tests, &plans, jobs);
+    // Set once a test fails, to stop the run under `--fail-fast`.
+    let failed = AtomicBool::new(false);
+    let stop = command.fail_fast.then_some(&failed);
+
+    // Ledger tests run on worker threads while native tests and scenarios run on this one.
+    let mut trace = Trace::default();
+    let (mut case_runs, scenario_runs) = std::thread::scope(|scope| -> Result<_> {
+        let (config, tests, plans) = (&config, &test_functions, &plans);
+        let workers = shards
+            .iter()
+            .enumerate()
+            .map(|(index, shard)| {
+                // The workers log under the command's span, as the logger requires one.
+                let span = tracing::Span::current();
+                // Deploying and executing programs needs the stack a main thread gets.
+                std::thread::Builder::new()
+                    .name(format!("leo-test-{index}"))
+                    .stack_size(8 * 1024 * 1024)
+                    .spawn_scoped(scope, move || span.in_scope(|| run_shard(config, tests, plans, shard, stop)))
+                    .map_err(|e| crate::errors::custom(format!("Failed to start a test worker: {e}")))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut case_runs = IndexMap::new();
+        for (index, (test, plan)) in tests.iter().zip(plans).enumerate() {
+            if test.native && !stop.is_some_and(|stop| stop.load(Ordering::Relaxed)) {
+                let runs = native.run(&package, test, &plan.rows)?;
+                if runs.iter().any(|run| run.failure.is_some()) {
+                    failed.store(true, Ordering::Relaxed);
+                }
+                case_runs.insert(index, runs);
+            }
+        }
+        let scenario_runs = run_scenarios(config, scenarios, stop, &mut trace)?;
+
+        for worker in workers {
+            case_runs.extend(worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))?);
+        }
+        Ok((case_runs, scenario_runs))
+    })?;
+
+    let mut results = Vec::new();
+    for (index, (test, plan)) in test_functions.iter().zip(&plans).enumerate() {
+        let runs = case_runs.swap_remove(&index).unwrap_or_default();
+        for run in &runs {
+            trace.extend(&run.trace);
+        }
+        let duration = runs.iter().map(|run| run.duration).sum();
+
+        let failure = match runs.iter().position(|run| run.failure.is_some()) {
+            Some(index) => {
+                let message = runs[index].failure.clone().unwrap_or_default();
+                Some(match &plan.generated {
+                    Some((shapes, values)) => {
+                        println!("Shrinking the failing inputs of {}...", test.name());
+                        let (row, message) = shrink_failure(&config, test, shapes, values[index].clone(), message)?;
+                        format!("{message}\n    with inputs ({}), seed {seed}", render(&row).join(", "))
+                    }
+                    None if test.parameters.is_empty() => message,
+                    None => format!("{message}\n    with inputs ({})", plan.rows[index].join(", ")),
+                })
+            }
+            // A test `--fail-fast` stopped before all of its cases ran.
+            None if runs.len() < plan.rows.len() => {
+                results.push(TestRun { duration, ..TestRun::skipped(test.name()) });
+                continue;
+            }
+            None => None,
+        };
+        results.push(TestRun { name: test.name(), failure, duration, skipped: false });
+    }
+    results.extend(scenario_runs);
+
+    // Report results.
+    let skipped = results.iter().filter(|result| result.skipped).count();
+    let total = results.len() - skipped;
+    let total_passed = results.iter().filter(|result| !result.skipped && result.failure.is_none()).count();
+
+    let mut tests = Vec::new();
+
+    if let Some(coverage) = native.coverage.take() {
+        report_coverage(&package, coverage, &trace, &config.programs, &options, network)?;
+    }
+
+    if total == 0 {
+        println!("No tests run.");
+    } else {
+        println!("{total_passed} / {total} tests passed.");
+        let failed = "FAILED".bold().red();
+        let passed = "PASSED".bold().green();
+
+        for result in &results {
+            let (name, secs) = (&result.name, result.duration.as_secs_f64());
+            match &result.failure {
+                _ if result.skipped => {}
+                Some(err_str) => println!("{failed}: {name:<30} ({secs:.2}s) | {err_str}"),
+                None => println!("{passed}: {name} ({secs:.2}s)"),
+            }
+            tests.push(TestResult {
+                name: name.clone(),
+                passed: !result.skipped && result.failure.is_none(),
+                skipped: result.skipped,
+                error: result.failure.clone(),
+                duration_secs: secs,
+            });
+        }
+        if skipped > 0 {
+            println!("{skipped} tests were not run after the first failure (--fail-fast).");
+        }
+    }
+
+    Ok(TestOutput { passed: total_passed, failed: total - total_passed, skipped, tests })
+}
+
+/// The result of a test or scenario.
+struct TestRun {
+    name: String,
+    /// Why the test failed, if it did.
+    failure: Option<String>,
+    /// The time spent running its cases.
+    duration: Duration,
+    /// Whether `--fail-fast` stopped the run before the test finished.
+    skipped: bool,
+}
+
+impl TestRun {
+    /// A test that `--fail-fast` stopped before it ran.
+    fn skipped(name: String) -> Self {
+        Self { name, failure: None, duration: Duration::ZERO, skipped: true }
+    }
+}
+
+/// The result of a single case of a test.
+struct CaseRun {
+    /// Why the case fails its test, if it does.
+    failure: Option<String>,
+    duration: Duration,
+    /// The instructions the case ran on the ledger, under `--coverage`.
+    trace: Trace,
+}
+
+/// Splits the ledger tests, indices into the tests, among at most `jobs` shards, giving each test to the shard
+/// with the fewest cases so far.
+fn shard(tests: impl Iterator<Item = usize>, plans: &[TestPlan], jobs: usize) -> Vec<Vec<usize>> {
+    let mut shards = vec![(0, Vec::new()); jobs];
+    for index in tests {
+        let (cases, shard) = shards.iter_mut().min_by_key(|(cases, _)| *cases).expect("there is at least one job");
+        *cases += plans[index].rows.len();
+        shard.push(index);
+    }
+    shards.into_iter().map(|(_, shard)| shard).filter(|shard| !shard.is_empty()).collect()
+}
+
+/// Runs the cases of the tests at `shard`, indices into `tests`, deploying the programs to a ledger of the
+/// shard's own, and returns the runs of each test's cases by index.
+///
+/// With `stop`, under `--fail-fast`, a failing case sets it, and no shard starts another case once it is set.
+fn run_shard(
+    config: &run::Config,
+    tests: &[TestFunction],
+    plans: &[TestPlan],
+    shard: &[usize],
+    stop: Option<&AtomicBool>,
+) -> Result<IndexMap<usize, Vec<CaseRun>>> {
+    // Each case runs on its own ledger, so that no case sees the state another one left behind.
+    let mut owners = Vec::new();
+    let mut cases = Vec::new();
+    for &index in shard {
+        owners.extend(std::iter::repeat_n(index, plans[index].rows.len()));
+        cases.extend(plans[index].rows.iter().map(|row| vec![tests[index].case(row.clone())]));
+    }
+
+    let mut runs: IndexMap<usize, Vec<CaseRun>> = shard.iter().map(|index| (*index, Vec::new())).collect();
+    // Symbols are interned per thread, so the outcomes are checked here, and only text leaves the thread.
+    leo_span::create_session_if_not_set_then(|_| {
+        run::run_with_ledger_until(config, &cases, |case_set, outcomes| {
+            let test = &tests[owners[case_set]];
+            let failure =
+                outcomes.first().cloned().and_then(|outcome| failure(outcome, test.should_fail, &test.expectations));
+            if let Some(stop) = stop.filter(|_| failure.is_some()) {
+                stop.store(true, Ordering::Relaxed);
+            }
+            let duration = outcomes.iter().map(|outcome| outcome.duration).sum();
+            let mut trace = Trace::default();
+            for outcome in outcomes {
+                trace.extend(&outcome.trace);
+            }
+            runs[&owners[case_set]].push(CaseRun { failure, duration, trace });
+            !stop.is_some_and(|stop| stop.load(Ordering::Relaxed))
+        })
+    })?;
+    Ok(runs)
+}
+
+/// Writes the JUnit XML report of `output` to `path`.
+fn write_junit(path: &Path, output: &TestOutput) -> Result<()> {
+    fs::write(path, report::junit(output))
+        .map_err(|e| crate::errors::custom(format!("Failed to write the JUnit report to {}: {e}", path.display())))?;
+    Ok(())
+}
+
+/// The cases of one test.
+struct TestPlan {
+    /// The inputs of each case.
+    rows: Vec<Vec<String>>,
+    /// For generated inputs, the parameter shapes and the values of each case, so a failing case can be shrunk.
+    generated: Option<(Vec<inputs::Shape>, Vec<Vec<inputs::Value>>)>,
+}
+
+impl TestPlan {
+    /// Lays out the cases of `test`, generating its inputs from `seed` and its parameter types in `abis`.
+    fn new(test: &TestFunction, abis: &IndexMap<String, leo_abi::Program>, seed: u64) -> Result<Self> {
+        let (runs, bounds) = match &test.inputs {
+            TestInputs::Table(rows) => return Ok(Self { rows: rows.clone(), generated: None }),
+            TestInputs::Generated { runs, bounds } => (*runs, bounds),
+        };
+        let invalid = |reason: String| crate::errors::invalid_test_inputs(test.name(), reason);
+        let function = abis
+            .get(&test.program)
+            .and_then(|abi| abi.functions.iter().find(|function| function.name == test.function))
+            .ok_or_else(|| invalid("the test program's ABI was not found".to_string()))?;
+        let shapes = function
+            .inputs
+            .iter()
+            .zip(&test.parameters)
+            .map(|(input, name)| {
+                let leo_abi::FunctionInput::Plaintext { ty, .. } = input else {
+                    return Err(format!("`{name}` is a record, which cannot be generated; use an `inputs` table"));
+                };
+                let shape = inputs::Shape::resolve(ty, &test.program, abis).map_err(|e| format!("`{name}`: {e}"))?;
+                match bounds.get(name) {
+                    Some(range) => shape.bounded(range).map_err(|e| format!("`{name}`: {e}")),
+                    None => Ok(shape),
+                }
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(invalid)?;
+
+        let mut generator = inputs::Generator::new(seed, &test.name());
+        let values = (0..runs).map(|_| generator.generate_row(&shapes)).collect::<Vec<_>>();
+        Ok(Self { rows: values.iter().map(|row| render(row)).collect(), generated: Some((shapes, values)) })
+    }
+}
+
+/// Runs `@test(mode = "native")` tests in the interpreter behind `leo debug`, where the
+/// `std::test` cheatcodes take effect. Each test program is analyzed once.
+struct NativeTests {
+    /// The programs the test programs are analyzed against; empty when no test is native.
+    stubs: IndexMap<Symbol, Stub>,
+    options: BuildOptions,
+    network: NetworkName,
+    debuggers: IndexMap<Symbol, Debugger>,
+    /// What the tests run of the primary program, under `--coverage`.
+    coverage: Option<Coverage>,
+}
+
+impl NativeTests {
+    fn new(
+        package: &Package,
+        tests: &[TestFunction],
+        options: &BuildOptions,
+        network: NetworkName,
+        coverage: bool,
+    ) -> Result<Self> {
+        let stubs = if tests.iter().any(|test| test.native) {
+            coverage_stubs(package, options, network)?
+        } else {
+            IndexMap::new()
+        };
+        let coverage = coverage.then(Coverage::default);
+        Ok(Self { stubs, options: options.clone(), network, debuggers: IndexMap::new(), coverage })
+    }
+
+    /// Runs each row of `test` from fresh chain state and the test's environment.
+    fn run(&mut self, package: &Package, test: &TestFunction, rows: &[Vec<String>]) -> Result<Vec<CaseRun>> {
+        let Some(unit) =
+            package.compilation_units.iter().find(|unit| bare_unit_name(&unit.name.to_string()) == test.program)
+        else {
+            return Err(crate::errors::custom(format!("The program of test `{}` was not found.", test.name())).into());
+        };
+        let ProgramData::SourcePath { source, .. } = &unit.data else {
+            return Err(crate::errors::custom(format!("Test `{}` has no Leo source to run.", test.name())).into());
+        };
+        if !self.debuggers.contains_key(&unit.name) {
+            let program =
+                DebugProgram::analyze_test(source, self.stubs.clone(), self.options.clone().into(), self.network)?;
+            if let (Some(coverage), Some(primary)) = (&mut self.coverage, package.primary_unit()) {
+                coverage.track(&program, primary.name);
+            }
+            self.debuggers.insert(unit.name, Debugger::new(program));
+        }
+        let debugger = &mut self.debuggers[&unit.name];
+        let function = debugger.program().resolve_function(&test.function)?;
+
+        let mut runs = Vec::new();
+        for row in rows {
+            let started = Instant::now();
+            debugger.environment = test_environment(test)?;
+            debugger.state = ChainState::for_program(debugger.program());
+            debugger.coverage = self.coverage.take();
+            let result = debugger
+                .parse_inputs(&function, row)
+                .and_then(|inputs| debugger.run(&function, inputs, &mut Unattended, false));
+            self.coverage = debugger.coverage.take();
+            let failure = native_failure(result, test);
+            runs.push(CaseRun { failure, duration: started.elapsed(), trace: Trace::default() });
+        }
+        Ok(runs)
+    }
+}
+
+/// The environment a case of `test` starts in: the default one, signed by the test's account.
+fn test_environment(test: &TestFunction) -> Result<Environment> {
+    let mut environment = Environment::default();
+    if let Some(key) = &test.private_key {
+        environment.signer = Address::try_from(PrivateKey::<TestnetV0>::from_str(key)?)?
+            .to_string()
+            .parse()
+            .map_err(|()| crate::errors::custom(format!("Invalid signer for test `{}`.", test.function)))?;
+    }
+    Ok(environment)
+}
+
+/// Why the interpreter run of a native test fails the test, or `None` if the test passes.
+///
+/// A rejected finalize fails the case, as it would on a ledger.
+fn native_failure(result: Result<Option<Execution>>, test: &TestFunction) -> Option<String> {
+    let outcome = match result {
+        Ok(Some(Execution { finalize: FinalizeOutcome::Rejected(error), .. })) | Err(error) => Err(error.to_string()),
+        Ok(Some(execution)) => Ok(execution.output),
+        Ok(None) => Err("The run was terminated.".to_string()),
+    };
+    match (outcome, test.should_fail) {
+        (Ok(_), true) => Some("Test succeeded when failure was expected.".to_string()),
+        (Err(error), true) => test.expectations.check_message(&error),
+        (Err(error), false) => Some(error),
+        (Ok(output), false) => {
+            let diffs = test.expectations.diff(Some(&output), &[]);
+            (!diffs.is_empty()).then(|| format!("Expected results not met:\n{}", diffs.join("\n")))
+        }
+    }
+}
+
+/// Why the outcome of a case fails its test, or `None` if the test passes.
+///
+/// Beyond the status the test expects, the case must return the expected output or fail with the
+/// expected message, and leave the expected entries in the finalize store.
+fn failure(outcome: run::ExecutionOutcome, should_fail: bool, expectations: &fixtures::Expectations) -> Option<String> {
+    let run::ExecutionOutcome { outcome, status, mappings, .. } = outcome;
+    let output = match (&status, should_fail) {
+        (run::ExecutionStatus::Accepted, false) => Some(&outcome.output),
+        (run::ExecutionStatus::Accepted, true) => return Some("Test succeeded when failure was expected.".to_string()),
+        (_, true) => {
+            if let Some(mismatch) = expectations.check_failure(&status, &outcome.output) {
+                return Some(mismatch);
+            }
+            None
+        }
+        (_, false) => return Some(format!("{} -- {}", status, outcome.output)),
+    };
+    let diffs = expectations.diff(output, &mappings);
+    (!diffs.is_empty()).then(|| format!("Expected results not met:\n{}", diffs.join("\n")))
+}
+
+/// Shrinks `row`, generated inputs that fail `test` with `message`, toward the simplest inputs that still fail it.
+///
+/// Each round runs every candidate on its own ledger and keeps the first, and so simplest, that fails.
+fn shrink_failure(
+    config: &run::Config,
+    test: &TestFunction,
+    shapes: &[inputs::Shape],
+    mut row: Vec<inputs::Value>,
+    mut message: String,
+) -> Result<(Vec<inputs::Value>, String)> {
+    // The candidates are not runs of the test, so they are not traced for coverage.
+    let config = &run::Config { trace: false, ..config.clone() };
+    for _ in 0..inputs::MAX_SHRINK_ROUNDS {
+        let candidates = inputs::shrink_row(&row, shapes);
+        if candidates.is_empty() {
+            break;
+        }
+        let cases = candidates.iter().map(|candidate| vec![test.case(render(candidate))]).collect::<Vec<_>>();
+        let outcomes = run::run_with_ledger(config, &cases)?;
+        let simpler = candidates.into_iter().zip(outcomes).find_map(|(candidate, outcomes)| {
+            let reason = outcomes
+                .into_iter()
+                .next()
+                .and_then(|outcome| failure(outcome, test.should_fail, &test.expectations))?;
+            Some((candidate, reason))
+        });
+        match simpler {
+            Some(simpler) => (row, message) = simpler,
+            None => break,
+        }
+    }
+    Ok((row, message))
+}
+
+/// Reads the scenarios of the package that `filter` selects by their names, e.g. `scenarios/transfer`, and tags.
+fn load_scenarios(
+    package: &Package,
+    filter: &filter::TestFilter,
+    programs: &[run::Program],
+) -> Result<Vec<(String, scenario::ScenarioPlan)>> {
+    let deployed = programs
+        .iter()
+        .map(|program| format!("{}.aleo", bare_unit_name(&program.name)))
+        .chain(["credits.aleo".to_string()])
+        .collect::<Vec<_>>();
+
+    let mut scenarios = Vec::new();
+    for path in package.scenario_files() {
+        let name = format!("scenarios/{}", path.file_stem().unwrap_or_default().to_string_lossy());
+        let json = fs::read_to_string(&path)
+            .map_err(|e| crate::errors::custom(format!("Failed to read scenario {}: {e}", path.display())))?;
+        let scenario: scenario::Scenario =
+            serde_json::from_str(&json).map_err(|e| crate::errors::invalid_scenario(&name, e))?;
+        if !filter.matches(&name, &scenario.tags) {
+            continue;
+        }
+        let plan = scenario
+            .plan(&deployed, &package.manifest.program)
+            .map_err(|e| crate::errors::invalid_scenario(&name, e))?;
+        scenarios.push((name, plan));
+    }
+    Ok(scenarios)
+}
+
+/// Runs each scenario on its own ledger and reports the first of its steps that fails, adding the instructions
+/// the steps ran to `trace`.
+///
+/// Scenarios that start at the same height share a run, which deploys the programs once for all of them. Once `stop`
+/// is set, the scenarios left are skipped.
+fn run_scenarios(
+    config: &run::Config,
+    scenarios: Vec<(String, scenario::ScenarioPlan)>,
+    stop: Option<&AtomicBool>,
+    trace: &mut Trace,
+) -> Result<Vec<TestRun>> {
+    let mut by_height: IndexMap<Option<u32>, Vec<(String, scenario::ScenarioPlan)>> = IndexMap::new();
+    for (name, plan) in scenarios {
+        by_height.entry(plan.start_height).or_default().push((name, plan));
+    }
+
+    let mut results = Vec::new();
+    for (start_height, scenarios) in by_height {
+        if stop.is_some_and(|stop| stop.load(Ordering::Relaxed)) {
+            results.extend(scenarios.into_iter().map(|(name, _)| TestRun::skipped(name)));
+            continue;
+        }
+        let config = run::Config { start_height, ..config.clone() };
+        let case_sets = scenarios
+            .iter()
+            .map(|(_, plan)| plan.steps.iter().map(|step| step.case.clone()).collect())
+            .collect::<Vec<_>>();
+        let mut runs = Vec::new();
+        run::run_with_ledger_until(&config, &case_sets, |index, outcomes| {
+            let (name, plan) = &scenarios[index];
+            let failure = plan.steps.iter().zip(outcomes).find_map(|(step, outcome)| {
+                let reason = failure(outcome.clone(), step.should_fail, &step.expectations)?;
+                Some(format!("{}: {reason}", step.label))
+            });
+            if failure.is_some()
+                && let Some(stop) = stop
+            {
+                stop.store(true, Ordering::Relaxed);
+            }
+            let duration = outcomes.iter().map(|outcome| outcome.duration).sum();
+            for outcome in outcomes {
+                trace.extend(&outcome.trace);
+            }
+            runs.push(TestRun { name: name.clone(), failure, duration, skipped: false });
+            stop.is_none_or(|stop| !stop.load(Ordering::Relaxed))
+        })?;
+        let ran = runs.len();
+        results.extend(runs);
+        results.extend(scenarios.into_iter().skip(ran).map(|(name, _)| TestRun::skipped(name)));
+    }
+    Ok(results)
+}
+
+/// Writes generated inputs as `leo run` takes them.
+fn render(row: &[inputs::Value]) -> Vec<String> {
+    row.iter().map(ToString::to_string).collect()
+}
+
+/// The ABIs written by the build, by bare program name.
+fn load_abis(package: &Package) -> Result<IndexMap<String, leo_abi::Program>> {
+    let mut abis = IndexMap::new();
+    for unit in &package.compilation_units {
+        let name = unit.name.to_string();
+        let Ok(json) = fs::read_to_string(package.unit_abi_path(&name)) else {
+            continue;
+        };
+        let abi = serde_json::from_str(&json)
+            .map_err(|e| crate::errors::custom(format!("Failed to read the ABI of `{name}`: {e}")))?;
+        abis.insert(bare_unit_name(&name).to_string(), abi);
+    }
+    Ok(abis)
+}
+
+/// Reports the statements, branch arms, and calls of the primary program that the tests executed:
+/// `coverage` from native tests, and `trace` from the ledger, attributed to the Leo source through the source
+/// maps of `programs`. Prints a summary and writes lcov and HTML reports.
+fn report_coverage(
+    package: &Package,
+    mut coverage: Coverage,
+    trace: &Trace,
+    programs: &[run::Program],
+    options: &BuildOptions,
+    network: NetworkName,
+) -> Result<()> {
+    let Some(primary) = package.primary_unit() else {
+        return Ok(());
+    };
+    if let ProgramData::SourcePath { directory, source } = &primary.data {
+        let mut stubs = coverage_stubs(package, options, network)?;
+        stubs.shift_remove(&primary.name);
+        let program =
+            DebugProgram::analyze(source, directory.join("src"), &leo_compiler::DiskFileSource, stubs, network)?;
+        coverage.track(&program, primary.name);
+    }
+    let source_maps = programs.iter().filter_map(|program| program.source_map.clone()).collect::<Vec<AleoSourceMap>>();
+    coverage.record_trace(trace, &source_maps);
+
+    if coverage.is_empty() {
+        println!("No coverage recorded.");
+        return Ok(());
+    }
+
+    let directory = package.unit_coverage_directory(&primary.name.to_string());
+    fs::create_dir_all(&directory).map_err(crate::errors::failed_to_write_coverage)?;
+    fs::write(directory.join("lcov.info"), coverage.lcov()).map_err(crate::errors::failed_to_write_coverage)?;
+    fs::write(directory.join("index.html"), coverage.html()).map_err(crate::errors::failed_to_write_coverage)?;
+
+    println!("\n{}", coverage.summary());
+    println!("📊 Coverage reports written to {}.", directory.display());
+    Ok(())
+}
+
+/// Stubs for every non-test unit of the package, to analyze a test program against.
+///
+/// Unlike the stubs `leo build` compiles against, local programs keep their full Leo source,
+/// so that tests calling into them can be interpreted.
+fn coverage_stubs(package: &Package, options: &BuildOptions, network: NetworkName) -> Result<IndexMap<Symbol, Stub>> {
+    let handler = Handler::default();
+    let node_builder = Rc::new(NodeBuilder::default());
+    let mut stubs = IndexMap::new();
+
+    for unit in package.compilation_units.iter().filter(|unit| !unit.kind.is_test()) {
+        let stub: Stub = match &unit.data {
+            ProgramData::Bytecode(bytecode) => match network {
+                NetworkName::MainnetV0 => {
+                    leo_disassembler::disassemble_from_str_unchecked::<MainnetV0>(unit.name, bytecode)
+                }
+                NetworkName::TestnetV0 => {
+                    leo_disassembler::disassemble_from_str_unchecked::<TestnetV0>(unit.name, bytecode)
+                }
+                NetworkName::CanaryV0 => {
+                    leo_disassembler::disassemble_from_str_unchecked::<CanaryV0>(unit.name, bytecode)
+                }
+            }?
+            .into(),
+            ProgramData::SourcePath { directory, source } => {
+                let mut compiler = Compiler::new(
+                    Some(unit.name.to_string()),
+                    false,
+                    handler.clone(),
+                    Rc::clone(&node_builder),
+                    Some(options.clone().into()),
+                    IndexMap::new(),
+                    network,
+                );
+                let source_directory = directory.join("src");
+                if unit.kind.is_library() {
+                    let library = compiler.parse_library_from_directory(unit.name, source, source_directory)?;
+                    let mut stub: Stub = library.into();
+                    for node in package.dep_graph.nodes() {
+                        if package.dep_graph.neighbors(node).any(|dep| dep == &unit.name) {
+                            stub.add_parent(*node);
+                        }
+                    }
+                    stub
+                } else {
+                    compiler.parse_program_from_directory(source, source_directory)?.into()
+                }
+            }
+        };
+        stubs.insert(unit.name, stub);
+    }
+
+    Ok(stubs)
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! JUnit XML reports of `leo test` runs, for CI services.
+//!
+//! The report has a test suite per program, named after the part of each test name before the
+//! `/`, so scenarios share the `scenarios` suite.
+
+use crate::cli::commands::{TestOutput, TestResult};
+
+use indexmap::IndexMap;
+use std::fmt::Write as _;
+
+/// Renders `output` as a JUnit XML report.
+pub(super) fn junit(output: &TestOutput) -> String {
+    let mut suites: IndexMap<&str, Vec<&TestResult>> = IndexMap::new();
+    for test in &output.tests {
+        let suite = test.name.split_once('/').map_or(test.name.as_str(), |(suite, _)| suite);
+        suites.entry(suite).or_default().push(test);
+    }
+    let time = |tests: &[&TestResult]| tests.iter().map(|test| test.duration_secs).sum::<f64>();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let total_time = time(&output.tests.iter().collect::<Vec<_>>());
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"leo test\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{total_time:.3}\">",
+        output.tests.len(),
+        output.failed,
+        output.skipped,
+    );
+    for (suite, tests) in &suites {
+        let failures = tests.iter().filter(|test| !test.passed && !test.skipped).count();
+        let skipped = tests.iter().filter(|test| test.skipped).count();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" skipped=\"{skipped}\" time=\"{:.3}\">",
+            escape(suite),
+            tests.len(),
+            time(tests),
+        );
+        for test in tests {
+            let name = test.name.split_once('/').map_or(test.name.as_str(), |(_, name)| name);
+            let _ = write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                escape(name),
+                escape(suite),
+                test.duration_secs
+            );
+            match &test.error {
+                _ if test.skipped => xml.push_str(">\n      <skipped/>\n    </testcase>\n"),
+                Some(error) if !test.passed => {
+                    let message = error.lines().next().unwrap_or_default();
+                    let _ = write!(
+                        xml,
+                        ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                        escape(message),
+                        escape(error)
+                    );
+                }
+                _ => xml.push_str("/>\n"),
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// `text` with the characters XML reserves escaped, and the ANSI colour codes of diffs removed.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{1b}' => {
+                // Skip a colour code, e.g. `\x1b[31m`.
+                chars.by_ref().find(|c| c.is_ascii_alphabetic());
+            }
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, passed: bool, skipped: bool, error: Option<&str>) -> TestResult {
+        TestResult { name: name.into(), passed, skipped, error: error.map(Into::into), duration_secs: 0.25 }
+    }
+
+    /// Verifies tests are grouped into a suite per program, with failures escaped and skipped tests marked.
+    #[test]
+    fn junit_groups_tests_by_program() {
+        let output = TestOutput {
+            passed: 1,
+            failed: 1,
+            skipped: 1,
+            tests: vec![
+                result("test_token.aleo/test_mint", true, false, None),
+                result(
+                    "test_token.aleo/test_burn",
+                    false,
+                    false,
+                    Some("expected <1u64>\n  \u{1b}[31m- expected\u{1b}[0m"),
+                ),
+                result("scenarios/vesting", false, true, None),
+            ],
+        };
+        let xml = junit(&output);
+
+        assert!(xml.contains("<testsuites name=\"leo test\" tests=\"3\" failures=\"1\" skipped=\"1\" time=\"0.750\">"));
+        assert!(
+            xml.contains(
+                "<testsuite name=\"test_token.aleo\" tests=\"2\" failures=\"1\" skipped=\"0\" time=\"0.500\">"
+            )
+        );
+        assert!(xml.contains("<testcase name=\"test_mint\" classname=\"test_token.aleo\" time=\"0.250\"/>"));
+        assert!(
+            xml.contains("<failure message=\"expected &lt;1u64&gt;\">expected &lt;1u64&gt;\n  - expected</failure>")
+        );
+        assert!(xml.contains("<testcase name=\"vesting\" classname=\"scenarios\" time=\"0.250\">\n      <skipped/>"));
+    }
+}
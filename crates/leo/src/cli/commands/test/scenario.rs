@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Scenario tests, read from `tests/scenarios/*.json`.
+//!
+//! A scenario is an ordered list of steps run on one ledger. A step either calls an entry point,
+//! as any account and with its own fixtures and expectations, or adds empty blocks to move
+//! `block.height` forward. A call can take the outputs of an earlier call as inputs, written
+//! `$name.0` for the first output of the step named `name`, so a record one step creates can be
+//! spent by the next.
+
+use super::fixtures::{Expectations, Fixture, MappingEntry, StorageEntry};
+
+use leo_compiler::run;
+
+use snarkvm::prelude::{PrivateKey, TestnetV0};
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::str::FromStr as _;
+
+/// A scenario, as written in a scenario file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct Scenario {
+    /// The block height the ledger starts at, before the programs are deployed; the height of the
+    /// latest consensus version if left out.
+    start_height: Option<u32>,
+    /// Tags for `--tag` and `--skip-tag` to select the scenario by.
+    #[serde(default)]
+    pub(super) tags: Vec<String>,
+    steps: Vec<Step>,
+}
+
+/// A step of a scenario.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Step {
+    Advance(Advance),
+    Call(Call),
+}
+
+/// A step adding empty blocks before the next call.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Advance {
+    advance: u32,
+}
+
+/// A step calling an entry point.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Call {
+    /// The entry point called, e.g. `token.aleo/transfer_public`.
+    call: String,
+    /// The name later steps refer to the outputs of this one by.
+    name: Option<String>,
+    /// The key the call is signed with; the default test account if left out.
+    private_key: Option<String>,
+    #[serde(default)]
+    inputs: Vec<String>,
+    #[serde(default)]
+    should_fail: bool,
+    #[serde(default)]
+    mappings: Vec<MappingEntry>,
+    #[serde(default)]
+    storage: Vec<StorageEntry>,
+    output: Option<String>,
+    fails_with: Option<String>,
+    #[serde(default)]
+    expect_mappings: Vec<MappingEntry>,
+    #[serde(default)]
+    expect_storage: Vec<StorageEntry>,
+}
+
+/// A scenario laid out as the cases of a single case set.
+pub(super) struct ScenarioPlan {
+    pub(super) start_height: Option<u32>,
+    pub(super) steps: Vec<PlannedStep>,
+}
+
+/// A call of a scenario and what it must do.
+pub(super) struct PlannedStep {
+    /// How the step is reported, e.g. `step 3 (token.aleo/transfer_public)`.
+    pub(super) label: String,
+    pub(super) should_fail: bool,
+    pub(super) expectations: Expectations,
+    pub(super) case: run::Case,
+}
+
+impl Scenario {
+    /// Lays out the calls of the scenario, checking them against `programs`, the programs deployed
+    /// for the tests, and resolving fixtures against `program`, the program under test.
+    pub(super) fn plan(self, programs: &[String], program: &str) -> Result<ScenarioPlan, String> {
+        let mut steps = Vec::new();
+        // The case of each named step, by name.
+        let mut names = IndexMap::new();
+        let mut advance_blocks = 0u32;
+
+        for (index, step) in self.steps.into_iter().enumerate() {
+            let call = match step {
+                Step::Advance(Advance { advance }) => {
+                    advance_blocks = advance_blocks.saturating_add(advance);
+                    continue;
+                }
+                Step::Call(call) => call,
+            };
+            let label = format!("step {} ({})", index + 1, call.call);
+            let invalid = |reason: String| format!("{label}: {reason}");
+
+            let (program_name, function) = call
+                .call
+                .rsplit_once('/')
+                .filter(|(program_name, _)| programs.iter().any(|deployed| deployed == program_name))
+                .ok_or_else(|| invalid("call a deployed program's entry point, e.g. `token.aleo/mint`".to_string()))?;
+            if let Some(key) = &call.private_key {
+                PrivateKey::<TestnetV0>::from_str(key).map_err(|e| invalid(format!("invalid private key: {e}")))?;
+            }
+
+            let mut linked_inputs = Vec::new();
+            for (position, input) in call.inputs.iter().enumerate() {
+                let Some(reference) = input.strip_prefix('$') else {
+                    continue;
+                };
+                let (case, output) = reference
+                    .split_once('.')
+                    .and_then(|(name, output)| Some((*names.get(name)?, output.parse::<usize>().ok()?)))
+                    .ok_or_else(|| {
+                        invalid(format!("`{input}` does not name an output of an earlier step, e.g. `$mint.0`"))
+                    })?;
+                linked_inputs.push(run::LinkedInput { position, case, output });
+            }
+            if let Some(name) = &call.name
+                && names.insert(name.clone(), steps.len()).is_some()
+            {
+                return Err(invalid(format!("another step is already named `{name}`")));
+            }
+
+            let fixture = Fixture {
+                mappings: call.mappings,
+                storage: call.storage,
+                output: call.output,
+                fails_with: call.fails_with,
+                expect_mappings: call.expect_mappings,
+                expect_storage: call.expect_storage,
+            };
+            let expectations = Expectations::new(fixture, None, None, call.should_fail, program).map_err(invalid)?;
+            let case = run::Case {
+                program_name: program_name.to_string(),
+                function: function.to_string(),
+                private_key: call.private_key,
+                input: call.inputs,
+                seed_mapping: expectations.seeds.clone(),
+                read_mappings: expectations.read_mappings(),
+                linked_inputs,
+                advance_blocks: std::mem::take(&mut advance_blocks),
+            };
+            steps.push(PlannedStep { label, should_fail: call.should_fail, expectations, case });
+        }
+
+        if steps.is_empty() {
+            return Err("the scenario has no calls".to_string());
+        }
+        if advance_blocks > 0 {
+            return Err("the scenario ends by advancing blocks, with no call left to run".to_string());
+        }
+        Ok(ScenarioPlan { start_height: self.start_height, steps })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(json: &str) -> Result<ScenarioPlan, String> {
+        let scenario: Scenario = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        scenario.plan(&["token.aleo".to_string()], "token.aleo")
+    }
+
+    /// Verifies advances carry over to the next call and output references link to the named step.
+    #[test]
+    fn plan_links_outputs_and_advances() {
+        let json = r#"{
+            "start_height": 20,
+            "steps": [
+                { "call": "token.aleo/mint", "name": "minted", "inputs": ["100u64"] },
+                { "advance": 3 },
+                { "advance": 2 },
+                { "call": "token.aleo/burn", "inputs": ["$minted.0"], "should_fail": true }
+            ]
+        }"#;
+        let plan = plan(json).unwrap();
+
+        assert_eq!(plan.start_height, Some(20));
+        assert_eq!(plan.steps[0].case.advance_blocks, 0);
+        let burn = &plan.steps[1];
+        assert_eq!(burn.label, "step 4 (token.aleo/burn)");
+        assert_eq!(burn.case.advance_blocks, 5);
+        assert!(burn.should_fail);
+        let link = &burn.case.linked_inputs[0];
+        assert_eq!((link.position, link.case, link.output), (0, 0, 0));
+    }
+
+    /// Verifies scenarios that call unknown programs, refer to later steps, or end by advancing are rejected.
+    #[test]
+    fn invalid_scenarios_are_rejected() {
+        assert!(plan(r#"{ "steps": [{ "call": "other.aleo/mint" }] }"#).is_err());
+        assert!(plan(r#"{ "steps": [{ "call": "token.aleo/burn", "inputs": ["$minted.0"] }] }"#).is_err());
+        assert!(plan(r#"{ "steps": [{ "call": "token.aleo/mint" }, { "advance": 1 }] }"#).is_err());
+        assert!(plan(r#"{ "steps": [{ "advance": 1, "call": "token.aleo/mint" }] }"#).is_err());
+        assert!(plan(r#"{ "steps": [] }"#).is_err());
+    }
+}
@@ -335,3 +335,31 @@ pub(crate) fn missing_constructor(program: impl Display) -> Backtraced {
     Backtraced::error(CODE_PREFIX, CODE_MASK + 70, format!("program `{program}` must declare a constructor"))
         .with_help("Add a constructor such as `@noupgrade constructor() {}` before deploying the program.")
 }
+
+pub(crate) fn failed_to_write_source_map(error: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 71, format!("failed to write source map file: {error}"))
+        .with_help("Verify the build output directory exists, is writable, and has enough free space.")
+}
+
+pub(crate) fn failed_to_write_coverage(error: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 72, format!("failed to write coverage report: {error}"))
+        .with_help("Verify the build output directory is writable and has enough free space.")
+}
+
+pub(crate) fn invalid_test_inputs(test: impl Display, reason: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 73, format!("invalid inputs for test `{test}`: {reason}")).with_help(
+        "Write `inputs` as a list of rows with one value per parameter, e.g. `@test(inputs = \"[[1u32, 2u32], [3u32, 4u32]]\")`, or leave it out to generate inputs from the parameter types.",
+    )
+}
+
+pub(crate) fn invalid_test_fixture(test: impl Display, reason: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 74, format!("invalid fixture for test `{test}`: {reason}")).with_help(
+        "Write mapping keys and values as `leo run` takes them, e.g. `aleo1...` or `100u64`, and check that the fixture file names mappings and storage variables the program declares.",
+    )
+}
+
+pub(crate) fn invalid_scenario(scenario: impl Display, reason: impl Display) -> Backtraced {
+    Backtraced::error(CODE_PREFIX, CODE_MASK + 75, format!("invalid scenario `{scenario}`: {reason}")).with_help(
+        "A scenario is a JSON object with a list of `steps`, each either `{ \"advance\": 10 }` or a call like `{ \"call\": \"token.aleo/mint\", \"inputs\": [\"100u64\"] }`.",
+    )
+}
@@ -274,6 +274,8 @@ fn filter_stdout(data: &str) -> String {
         (Regex::new(r"Diff in .*?([^/]+\.leo)").unwrap(), "Diff in SOURCE_DIRECTORY/$1"),
         // Normalize dynamic devnode ports back to 3030 for stable expectations.
         (Regex::new(r"http://localhost:\d+").unwrap(), "http://localhost:3030"),
+        // `leo test` reports how long each test took.
+        (Regex::new(r"(?m)^((?:PASSED|FAILED): .*) \([0-9]+\.[0-9]{2}s\)").unwrap(), "$1 (X.XXs)"),
         // Normalize `leo --version` output: replace commit hash, branch, and features with placeholders.
         (
             Regex::new(r"(leo \d+\.\d+\.\d+) \([a-f0-9]+ [^\)]+\) features=\[[^\]]*\]").unwrap(),
@@ -361,6 +363,7 @@ fn filter_json_file(data: &str) -> String {
         (Regex::new(r#""circuit_id":\s*"[a-fA-F0-9]+""#).unwrap(), r#""circuit_id": "XXXXXX""#),
         (Regex::new(r#""prover_size":\s*[0-9]+"#).unwrap(), r#""prover_size": 0"#),
         (Regex::new(r#""verifier_size":\s*[0-9]+"#).unwrap(), r#""verifier_size": 0"#),
+        (Regex::new(r#""duration_secs":\s*[0-9.eE+-]+"#).unwrap(), r#""duration_secs": 0.0"#),
         // Normalize dynamic devnode ports back to 3030 for stable expectations.
         (Regex::new(r"http://localhost:\d+").unwrap(), "http://localhost:3030"),
     ];
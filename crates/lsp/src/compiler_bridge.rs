@@ -26,6 +26,8 @@
 //! kinds.
 
 use crate::{
+    completion_index::{CompletionEntry, CompletionIndex, CompletionKind, LocalCompletion, ValueShape},
+    declaration_text,
     document_store::{AnalysisBucket, DocumentSnapshot, DocumentViewSnapshot, OpenFileOverlay},
     features::{diagnostics::CompilerDiagnostic, lsp_range::hash_text, semantic_tokens::encode_tokens},
     project_model::ProjectContext,
@@ -33,6 +35,11 @@ use crate::{
         CachedDocumentView,
         CachedPackageAnalysis,
         FileRange,
+        HoverEntry,
+        HoverIndex,
+        InlayHintEntry,
+        InlayHintIndex,
+        InlayKind,
         OccurrenceRole,
         SemanticIndex,
         SemanticKind,
@@ -62,6 +69,7 @@ use leo_ast::{
     DefinitionStatement,
     DynamicOpExpression,
     DynamicOpKind,
+    Expression,
     Function,
     FunctionPrototype,
     Identifier,
@@ -83,10 +91,11 @@ use leo_ast::{
     Stub,
     Type,
     UnitVisitor,
+    const_eval::{self, Value},
 };
 use leo_compiler::{Compiler, FrontendAnalysis, load_import_stubs_for_package_with_file_source};
 use leo_errors::Handler;
-use leo_passes::{SymbolTable, TypeTable, VariableType};
+use leo_passes::{SymbolTable, TypeTable, VariableSymbol, VariableType};
 use leo_span::{
     Symbol,
     create_session_if_not_set_then,
@@ -108,6 +117,8 @@ use std::{
 
 /// Maximum worker-local dependency-stub packages retained at once.
 const MAX_PACKAGE_ANALYSIS_CACHE_ENTRIES: usize = 8;
+/// Maximum `const` indirections followed when folding an inlay hint value.
+const MAX_CONST_EVAL_DEPTH: usize = 64;
 
 /// Worker result for a package-analysis job.
 #[derive(Debug, Clone)]
@@ -205,10 +216,15 @@ enum WatchedPathStamp {
 /// `occurrences` is `None` when the frontend bailed before walking the AST;
 /// the caller falls back to syntax-only highlighting but still publishes the
 /// buffered diagnostics. Span resolution happens here, inside the live Leo
-/// session, because the source map is gone once the session ends.
+/// session, because the source map is gone once the session ends. Hover text,
+/// completion candidates, and inlay hints are rendered in the same session for
+/// the same reason.
 #[derive(Debug)]
 struct CompilerOutput {
     occurrences: Option<Vec<SymbolOccurrence>>,
+    hovers: Vec<HoverEntry>,
+    completions: CompletionIndex,
+    inlay_hints: Vec<InlayHintEntry>,
     fingerprints: HashMap<PathBuf, SourceFingerprint>,
     diagnostic_entries: Vec<crate::features::diagnostics::DiagnosticEntry>,
 }
@@ -290,6 +306,9 @@ pub fn analyze_package_snapshot(
             SemanticSource::SyntaxOnly,
             HashMap::new(),
             Vec::new(),
+            Vec::new(),
+            CompletionIndex::default(),
+            Vec::new(),
         );
     }
 
@@ -302,40 +321,82 @@ pub fn analyze_package_snapshot(
             SemanticSource::SyntaxOnly,
             HashMap::new(),
             Vec::new(),
+            Vec::new(),
+            CompletionIndex::default(),
+            Vec::new(),
         );
     }
 
-    let (occurrences, lexical_tokens, source, fingerprints, diagnostic_entries) = match compiler_occurrences {
-        Some(CompilerOutput { occurrences: Some(occurrences), fingerprints, diagnostic_entries }) => {
-            let mut merged_fingerprints = syntax.fingerprints;
-            merged_fingerprints.extend(fingerprints);
-            (
-                merge_occurrences(syntax.occurrences, occurrences),
-                syntax.tokens,
-                SemanticSource::CompilerEnhanced,
-                merged_fingerprints,
+    let (occurrences, lexical_tokens, source, fingerprints, diagnostic_entries, hovers, completions, inlay_hints) =
+        match compiler_occurrences {
+            Some(CompilerOutput {
+                occurrences: Some(occurrences),
+                hovers,
+                completions,
+                inlay_hints,
+                fingerprints,
                 diagnostic_entries,
-            )
-        }
-        Some(CompilerOutput { occurrences: None, diagnostic_entries, .. }) => {
-            // The compiler buffered diagnostics but did not produce usable
-            // AST occurrences (typical when the parser bailed). Use the
-            // package-fallback syntax tokens — they cover the full module
-            // listing — and still publish the buffered diagnostics so users
-            // see the parse error.
-            let syntax = syntax_semantics::collect_package_fallback(snapshot);
-            (syntax.occurrences, syntax.tokens, SemanticSource::SyntaxOnly, syntax.fingerprints, diagnostic_entries)
-        }
-        None => {
-            // Compiler analysis declined to run at all (no usable file
-            // source). Fall back to syntax tokens with no diagnostics;
-            // "compiler unavailable" is not a user-facing condition.
-            let syntax = syntax_semantics::collect_package_fallback(snapshot);
-            (syntax.occurrences, syntax.tokens, SemanticSource::SyntaxOnly, syntax.fingerprints, Vec::new())
-        }
-    };
+            }) => {
+                let mut merged_fingerprints = syntax.fingerprints;
+                merged_fingerprints.extend(fingerprints);
+                (
+                    merge_occurrences(syntax.occurrences, occurrences),
+                    syntax.tokens,
+                    SemanticSource::CompilerEnhanced,
+                    merged_fingerprints,
+                    diagnostic_entries,
+                    hovers,
+                    completions,
+                    inlay_hints,
+                )
+            }
+            Some(CompilerOutput { occurrences: None, diagnostic_entries, .. }) => {
+                // The compiler buffered diagnostics but did not produce usable
+                // AST occurrences (typical when the parser bailed). Use the
+                // package-fallback syntax tokens — they cover the full module
+                // listing — and still publish the buffered diagnostics so users
+                // see the parse error.
+                let syntax = syntax_semantics::collect_package_fallback(snapshot);
+                (
+                    syntax.occurrences,
+                    syntax.tokens,
+                    SemanticSource::SyntaxOnly,
+                    syntax.fingerprints,
+                    diagnostic_entries,
+                    Vec::new(),
+                    CompletionIndex::default(),
+                    Vec::new(),
+                )
+            }
+            None => {
+                // Compiler analysis declined to run at all (no usable file
+                // source). Fall back to syntax tokens with no diagnostics;
+                // "compiler unavailable" is not a user-facing condition.
+                let syntax = syntax_semantics::collect_package_fallback(snapshot);
+                (
+                    syntax.occurrences,
+                    syntax.tokens,
+                    SemanticSource::SyntaxOnly,
+                    syntax.fingerprints,
+                    Vec::new(),
+                    Vec::new(),
+                    CompletionIndex::default(),
+                    Vec::new(),
+                )
+            }
+        };
 
-    package_analysis(snapshot, occurrences, lexical_tokens, source, fingerprints, diagnostic_entries)
+    package_analysis(
+        snapshot,
+        occurrences,
+        lexical_tokens,
+        source,
+        fingerprints,
+        diagnostic_entries,
+        hovers,
+        completions,
+        inlay_hints,
+    )
 }
 
 /// Compatibility helper retained for PR 2 tests and callers.
@@ -362,8 +423,10 @@ pub fn build_document_view(snapshot: &DocumentViewSnapshot, package: Arc<CachedP
     CachedDocumentView { key: snapshot.key.clone(), encoded_tokens }
 }
 
-/// Lower merged occurrences and lowered diagnostic entries into a shared
-/// package index, document view, and immutable diagnostic set.
+/// Lower merged occurrences, hover texts, completion candidates, inlay hints,
+/// and lowered diagnostic entries into a shared package index, document view,
+/// and immutable diagnostic set.
+#[allow(clippy::too_many_arguments)]
 fn package_analysis(
     snapshot: &DocumentSnapshot,
     mut occurrences: Vec<SymbolOccurrence>,
@@ -371,9 +434,25 @@ fn package_analysis(
     source: SemanticSource,
     recorded_fingerprints: HashMap<PathBuf, SourceFingerprint>,
     diagnostic_entries: Vec<crate::features::diagnostics::DiagnosticEntry>,
+    mut hovers: Vec<HoverEntry>,
+    mut completions: CompletionIndex,
+    mut inlay_hints: Vec<InlayHintEntry>,
 ) -> PackageWorkerAnalysis {
     let package_source_files = PackageSourceFiles::from_snapshot(snapshot);
     retain_in_scope_occurrences(&mut occurrences, &package_source_files);
+    // Hovers are only ever requested from editor buffers, so dependency-stub
+    // renderings would just pin memory for the life of the package entry.
+    hovers.retain(|hover| package_source_files.contains(hover.range.path.as_ref()));
+    inlay_hints.retain(|hint| package_source_files.contains(hint.path.as_ref()));
+    completions.locals.retain(|local| package_source_files.contains(local.path.as_ref()));
+    // Record the analyzed buffer lengths so completion can stretch scopes
+    // while the user keeps typing past this analysis.
+    for overlay in snapshot.open_overlays.iter() {
+        completions.analyzed_lengths.insert(overlay.path.as_ref().clone(), overlay.text.len() as u32);
+    }
+    if let Some(file_path) = snapshot.file_path.as_ref() {
+        completions.analyzed_lengths.insert(file_path.as_ref().clone(), snapshot.text.len() as u32);
+    }
 
     let (index, analyzed_files) = SemanticIndex::build(
         &occurrences,
@@ -387,6 +466,8 @@ fn package_analysis(
         |path| open_line_index(snapshot.open_overlays.as_ref(), path),
         |path| package_source_files.contains(path),
     );
+    let hovers = Arc::new(HoverIndex::build(&hovers, &index));
+    let inlay_hints = Arc::new(InlayHintIndex::build(&inlay_hints, &index));
     let index = Arc::new(index);
     let diagnostics = Arc::new(crate::features::diagnostics::DiagnosticSet {
         key: snapshot.package_key.clone(),
@@ -398,6 +479,9 @@ fn package_analysis(
         analyzed_files: Arc::new(analyzed_files),
         source,
         diagnostics,
+        hovers,
+        completions: Arc::new(completions),
+        inlay_hints,
     });
 
     let package_tokens =
@@ -509,6 +593,9 @@ fn compiler_occurrences(
                         let diagnostic_entries = lower_compiler_diagnostics(&synthetic, &[], trigger_path.as_ref());
                         return Ok::<_, String>(CompilerOutput {
                             occurrences: None,
+                            hovers: Vec::new(),
+                            completions: CompletionIndex::default(),
+                            inlay_hints: Vec::new(),
                             fingerprints: file_source.fingerprints_with(&[]),
                             diagnostic_entries,
                         });
@@ -540,6 +627,9 @@ fn compiler_occurrences(
                 check_snapshot_current(snapshot).map_err(|error| error.to_string())?;
                 Ok::<_, String>(CompilerOutput {
                     occurrences: outcome.occurrences,
+                    hovers: outcome.hovers,
+                    completions: outcome.completions,
+                    inlay_hints: outcome.inlay_hints,
                     fingerprints: file_source.fingerprints_with(import_stubs.fingerprints.as_ref()),
                     diagnostic_entries,
                 })
@@ -570,6 +660,9 @@ fn compiler_occurrences(
                 check_snapshot_current(snapshot).map_err(|error| error.to_string())?;
                 Ok::<_, String>(CompilerOutput {
                     occurrences: outcome.occurrences,
+                    hovers: outcome.hovers,
+                    completions: outcome.completions,
+                    inlay_hints: outcome.inlay_hints,
                     fingerprints: file_source.fingerprints_with(&[]),
                     diagnostic_entries,
                 })
@@ -644,6 +737,9 @@ fn compiler_input(snapshot: &DocumentSnapshot) -> Option<CompilerInput> {
 /// `errors` and `warnings` may still be populated in that case.
 struct CompilerAnalysisOutcome {
     occurrences: Option<Vec<SymbolOccurrence>>,
+    hovers: Vec<HoverEntry>,
+    completions: CompletionIndex,
+    inlay_hints: Vec<InlayHintEntry>,
     errors: Vec<leo_errors::LeoError>,
     warnings: Vec<leo_errors::LeoWarning>,
 }
@@ -685,13 +781,15 @@ fn run_compiler_analysis(
     // Collect AST-derived occurrences when the frontend ran to completion;
     // diagnostics flow through the handler regardless.
     let mut returned_error: Option<leo_errors::LeoError> = None;
-    let occurrences = match frontend_result {
+    let (occurrences, hovers, completions, inlay_hints) = match frontend_result {
         Ok(FrontendAnalysis { ast, symbol_table, type_table }) => {
-            Some(CompilerSemanticCollector::new(symbol_table, type_table, program_roots).collect(ast))
+            let CollectedSemantics { occurrences, hovers, locals, inlay_hints } =
+                CompilerSemanticCollector::new(symbol_table, type_table, program_roots).collect(ast);
+            (Some(occurrences), hovers, completion_index(ast, symbol_table, locals), inlay_hints)
         }
         Err(error) => {
             returned_error = Some(error);
-            None
+            (None, Vec::new(), CompletionIndex::default(), Vec::new())
         }
     };
 
@@ -707,7 +805,7 @@ fn run_compiler_analysis(
         errors.push(returned);
     }
 
-    CompilerAnalysisOutcome { occurrences, errors, warnings }
+    CompilerAnalysisOutcome { occurrences, hovers, completions, inlay_hints, errors, warnings }
 }
 
 /// File source that serves all open same-package buffers and records read fingerprints.
@@ -1017,6 +1115,40 @@ struct LocalBinding {
     token_kind: SemanticKind,
     /// Whether the binding should also carry the readonly modifier.
     readonly: bool,
+    /// Hover text rendered at the declaration and reused by every reference.
+    hover: Arc<str>,
+    /// Folded value of a `const` binding, reused when const arguments and
+    /// loop bounds refer to it.
+    value: Option<Value>,
+}
+
+/// Editor-facing rendering of a local binding, computed when it is bound.
+struct LocalDescription {
+    /// Leo source rendering such as `let total: u64` or `public amount: u64`.
+    code: String,
+    /// Declared or inferred type, when known.
+    type_: Option<Type>,
+    /// Folded value, for `const` bindings whose initializer is constant.
+    value: Option<Value>,
+}
+
+impl LocalDescription {
+    /// Describe a binding by its rendered source and optional type.
+    fn new(code: String, type_: Option<&Type>) -> Self {
+        Self { code, type_: type_.cloned(), value: None }
+    }
+}
+
+/// Everything the collector lowers out of one compiler frontend run.
+struct CollectedSemantics {
+    /// Semantic occurrences for navigation and highlighting.
+    occurrences: Vec<SymbolOccurrence>,
+    /// Hover texts anchored to identifier ranges.
+    hovers: Vec<HoverEntry>,
+    /// Local bindings with the scope ranges that can complete them.
+    locals: Vec<LocalCompletion>,
+    /// Inlay hints for bindings, call arguments, const arguments, and loops.
+    inlay_hints: Vec<InlayHintEntry>,
 }
 
 /// Walks compiler frontend state and turns declarations and references into a
@@ -1027,15 +1159,21 @@ struct LocalBinding {
 /// compiler `Location`s for upcoming navigation features.
 struct CompilerSemanticCollector<'a> {
     symbol_table: &'a SymbolTable,
-    #[allow(dead_code)]
     type_table: &'a TypeTable,
     occurrences: Vec<SymbolOccurrence>,
+    hovers: Vec<HoverEntry>,
+    locals: Vec<LocalCompletion>,
+    inlay_hints: Vec<InlayHintEntry>,
+    /// Initializers of every global `const`, used to fold inlay hint values.
+    global_consts: HashMap<Location, &'a Expression>,
     program_roots: ProgramRoots,
     current_program: Symbol,
     current_program_root: Option<Arc<PathBuf>>,
     stub_depth: usize,
     current_module: Vec<Symbol>,
     local_scopes: Vec<HashMap<Symbol, LocalBinding>>,
+    /// Source range of each entry in `local_scopes`, when it maps to a real file.
+    scope_ranges: Vec<Option<FileRange>>,
     owner_stack: Vec<Option<Location>>,
 }
 
@@ -1046,38 +1184,190 @@ impl<'a> CompilerSemanticCollector<'a> {
             symbol_table,
             type_table,
             occurrences: Vec::new(),
+            hovers: Vec::new(),
+            locals: Vec::new(),
+            inlay_hints: Vec::new(),
+            global_consts: HashMap::new(),
             current_program_root: program_roots.current.clone(),
             program_roots,
             current_program: Symbol::intern(""),
             stub_depth: 0,
             current_module: Vec::new(),
             local_scopes: Vec::new(),
+            scope_ranges: Vec::new(),
             owner_stack: Vec::new(),
         }
     }
 
-    /// Walk the AST and return every semantic occurrence discovered in it.
-    fn collect(mut self, ast: &'a Ast) -> Vec<SymbolOccurrence> {
+    /// Walk the AST and return every semantic occurrence, hover text, local
+    /// binding, and inlay hint discovered in it.
+    fn collect(mut self, ast: &'a Ast) -> CollectedSemantics {
+        self.global_consts = global_consts(ast);
         match ast {
             Ast::Program(program) => self.visit_program(program),
             Ast::Library(library) => self.visit_library(library),
         }
-        self.occurrences
+        CollectedSemantics {
+            occurrences: self.occurrences,
+            hovers: self.hovers,
+            locals: self.locals,
+            inlay_hints: self.inlay_hints,
+        }
+    }
+
+    /// Attach rendered hover markdown to an identifier range.
+    fn add_hover(&mut self, range: &FileRange, contents: impl Into<Arc<str>>) {
+        self.hovers.push(HoverEntry { range: range.clone(), contents: contents.into() });
+    }
+
+    /// Attach an inlay hint to the source under `span`.
+    ///
+    /// Parameter names go before the argument; every other kind follows the
+    /// node it annotates.
+    fn add_inlay_hint(&mut self, span: leo_span::Span, kind: InlayKind, label: String) {
+        // Stub bodies never appear in an editor buffer.
+        if self.stub_depth > 0 {
+            return;
+        }
+        if let Some(range) = span_to_file_range(span) {
+            let offset = if kind == InlayKind::Parameter { range.start } else { range.end };
+            self.inlay_hints.push(InlayHintEntry { path: range.path, offset, label: label.into(), kind });
+        }
+    }
+
+    /// Hint the inferred type after a binding written without an annotation.
+    fn add_type_hint(&mut self, identifier: &Identifier, type_: Option<&Type>) {
+        if let Some(type_) = type_
+            && !matches!(type_, Type::Err | Type::Numeric)
+        {
+            self.add_inlay_hint(identifier.span, InlayKind::Type, format!(": {type_}"));
+        }
+    }
+
+    /// Hint the parameter name before each argument of a call to a known function.
+    ///
+    /// Arguments that are already a path named like the parameter are skipped.
+    fn add_parameter_hints(&mut self, input: &CallExpression) {
+        let location = input
+            .function
+            .try_global_location()
+            .cloned()
+            .unwrap_or_else(|| self.current_item_location(input.function.identifier().name));
+        let Some(function) = self.symbol_table.lookup_function(self.current_program, &location) else {
+            return;
+        };
+        let names = function.function.input.iter().map(|parameter| parameter.identifier.name).collect::<Vec<_>>();
+        for (argument, name) in input.arguments.iter().zip(names) {
+            if let Expression::Path(path) = argument
+                && path.identifier().name == name
+            {
+                continue;
+            }
+            self.add_inlay_hint(argument.span(), InlayKind::Parameter, format!("{name}:"));
+        }
+    }
+
+    /// Hint the folded value after each const generic argument that is not already a literal.
+    fn add_const_argument_hints(&mut self, arguments: &[Expression]) {
+        for argument in arguments {
+            if matches!(argument, Expression::Literal(_)) {
+                continue;
+            }
+            if let Some(value) = self.const_value(argument, 0) {
+                self.add_inlay_hint(argument.span(), InlayKind::ConstValue, format!("= {value}"));
+            }
+        }
+    }
+
+    /// Hint how many times loop unrolling will emit the body of `input`.
+    ///
+    /// Loops whose bounds do not fold to constants here, such as bounds that
+    /// depend on a const parameter, get no hint: each instantiation may differ.
+    fn add_loop_count_hint(&mut self, input: &IterationStatement) {
+        let Some(start) = self.const_value(&input.start, 0).and_then(|value| value.as_i128()) else {
+            return;
+        };
+        let Some(stop) = self.const_value(&input.stop, 0).and_then(|value| value.as_i128()) else {
+            return;
+        };
+        let Some(count) = stop.checked_sub(start).and_then(|count| count.checked_add(i128::from(input.inclusive)))
+        else {
+            return;
+        };
+        let count = count.max(0);
+        let label = if count == 1 { "1 iteration".to_owned() } else { format!("{count} iterations") };
+        self.add_inlay_hint(input.stop.span(), InlayKind::LoopCount, label);
+    }
+
+    /// Fold `expression` to a value the way const propagation would.
+    ///
+    /// Only literals, `const` bindings, and unary, binary, and cast operations
+    /// over them fold; anything else has no single compile-time value here.
+    fn const_value(&self, expression: &Expression, depth: usize) -> Option<Value> {
+        if depth > MAX_CONST_EVAL_DEPTH {
+            return None;
+        }
+        match expression {
+            Expression::Literal(literal) => {
+                let type_ = self.type_table.get(&literal.id()).map(|type_| match type_ {
+                    Type::Optional(optional) => *optional.inner,
+                    type_ => type_,
+                });
+                const_eval::literal_to_value(literal, &type_).ok()
+            }
+            Expression::Path(path) => {
+                if let Some(symbol) = path.try_local_symbol()
+                    && let Some(binding) = self.local_scopes.iter().rev().find_map(|scope| scope.get(&symbol))
+                {
+                    return binding.value.clone();
+                }
+                let initializer = self.global_consts.get(path.try_global_location()?)?;
+                self.const_value(initializer, depth + 1)
+            }
+            Expression::Unary(unary) => {
+                let value = self.const_value(&unary.receiver, depth + 1)?;
+                const_eval::evaluate_unary(unary.span, unary.op, &value, &self.type_table.get(&unary.id())).ok()
+            }
+            Expression::Binary(binary) => {
+                let left = self.const_value(&binary.left, depth + 1)?;
+                let right = self.const_value(&binary.right, depth + 1)?;
+                const_eval::evaluate_binary(binary.span, binary.op, &left, &right, &self.type_table.get(&binary.id()))
+                    .ok()
+            }
+            Expression::Cast(cast) => self.const_value(&cast.expression, depth + 1)?.cast(&cast.type_),
+            _ => None,
+        }
     }
 
-    /// Push a new lexical scope for local bindings.
-    fn push_scope(&mut self) {
+    /// Push a new lexical scope for local bindings covering `span`.
+    fn push_scope(&mut self, span: leo_span::Span) {
         self.local_scopes.push(HashMap::new());
+        self.scope_ranges.push(span_to_file_range(span));
     }
 
     /// Pop the current lexical scope.
     fn pop_scope(&mut self) {
         self.local_scopes.pop();
+        self.scope_ranges.pop();
     }
 
     /// Record a local declaration and make later local-path references resolve to it.
-    fn bind_local(&mut self, identifier: &Identifier, token_kind: SemanticKind, readonly: bool) {
+    ///
+    /// `description` renders the binding as Leo source, e.g. `let x: u32`;
+    /// references reuse it so every occurrence shows the declared or inferred
+    /// type, and completion offers it anywhere inside the enclosing scope.
+    fn bind_local(
+        &mut self,
+        identifier: &Identifier,
+        token_kind: SemanticKind,
+        readonly: bool,
+        description: LocalDescription,
+    ) {
         if let Some(range) = span_to_file_range(identifier.span) {
+            let hover = Arc::<str>::from(declaration_text::markdown(&description.code, None));
+            self.add_hover(&range, Arc::clone(&hover));
+            let value = description.value.clone();
+            self.add_local_completion(identifier, &range, token_kind, readonly, description);
             self.occurrences.push(SymbolOccurrence {
                 range: range.clone(),
                 identity: SymbolIdentity::Local { declaration: range.clone() },
@@ -1086,11 +1376,94 @@ impl<'a> CompilerSemanticCollector<'a> {
                 readonly,
             });
             if let Some(scope) = self.local_scopes.last_mut() {
-                scope.insert(identifier.name, LocalBinding { declaration: range, token_kind, readonly });
+                scope.insert(identifier.name, LocalBinding { declaration: range, token_kind, readonly, hover, value });
             }
         }
     }
 
+    /// Offer a local binding to completion inside its enclosing scope.
+    fn add_local_completion(
+        &mut self,
+        identifier: &Identifier,
+        range: &FileRange,
+        token_kind: SemanticKind,
+        readonly: bool,
+        description: LocalDescription,
+    ) {
+        // Stub bodies never appear in an editor buffer.
+        if self.stub_depth > 0 {
+            return;
+        }
+        let Some(Some(scope)) = self.scope_ranges.last() else {
+            return;
+        };
+        if scope.path != range.path {
+            return;
+        }
+        let kind = match (token_kind, readonly) {
+            (SemanticKind::Parameter, true) => CompletionKind::Constant,
+            (SemanticKind::Parameter, false) => CompletionKind::Parameter,
+            (_, true) => CompletionKind::Constant,
+            (_, false) => CompletionKind::Variable,
+        };
+        let shape = description.type_.as_ref().and_then(|type_| self.value_shape(type_));
+        let mut entry = CompletionEntry::new(identifier.name.to_string(), kind, Some(description.code));
+        entry.shape = shape;
+        self.locals.push(LocalCompletion {
+            path: Arc::clone(&range.path),
+            scope_start: scope.start,
+            scope_end: scope.end,
+            visible_from: range.end,
+            entry,
+        });
+    }
+
+    /// Classify a type for chained `.` completion, resolving composites to their location.
+    fn value_shape(&self, type_: &Type) -> Option<ValueShape> {
+        value_shape(type_, |name| self.current_item_location(name))
+    }
+
+    /// Bind every const parameter of a generic item as a readonly local.
+    fn bind_const_parameters(&mut self, parameters: &[leo_ast::ConstParameter]) {
+        parameters.iter().for_each(|parameter| {
+            self.visit_type(&parameter.type_);
+            let description = LocalDescription::new(format!("const {parameter}"), Some(&parameter.type_));
+            self.bind_local(&parameter.identifier, SemanticKind::Parameter, true, description);
+        });
+    }
+
+    /// Bind every runtime input of a function or prototype, keeping its mode in the hover.
+    fn bind_inputs(&mut self, inputs: &[leo_ast::Input]) {
+        inputs.iter().for_each(|parameter| {
+            self.visit_type(&parameter.type_);
+            let description = LocalDescription::new(parameter.to_string(), Some(&parameter.type_));
+            self.bind_local(&parameter.identifier, SemanticKind::Parameter, false, description);
+        });
+    }
+
+    /// Render hover markdown for a composite member reached through `owner`.
+    ///
+    /// Falls back to the expression type from the type table when the owner is
+    /// not a concrete struct or record, e.g. for interface record prototypes.
+    fn member_access_hover(
+        &self,
+        owner: Option<&Location>,
+        name: Symbol,
+        fallback_type: Option<Type>,
+    ) -> Option<String> {
+        let composite = owner.and_then(|owner| {
+            self.symbol_table
+                .lookup_struct(self.current_program, owner)
+                .or_else(|| self.symbol_table.lookup_record(self.current_program, owner))
+        });
+        if let Some(composite) = composite
+            && let Some(member) = composite.members.iter().find(|member| member.identifier.name == name)
+        {
+            return Some(member_hover(composite, member));
+        }
+        fallback_type.map(|type_| declaration_text::markdown(&format!("{name}: {type_}"), None))
+    }
+
     /// Return the nearest enclosing owner used for member identities.
     fn current_owner(&self) -> Option<Location> {
         self.owner_stack.iter().rev().find_map(Clone::clone)
@@ -1310,6 +1683,7 @@ impl<'a> CompilerSemanticCollector<'a> {
         // order so the emitted token kind reflects the declaration we actually
         // found, not just the surface syntax of the path.
         if let Some(function) = self.symbol_table.lookup_function(self.current_program, &location) {
+            self.add_hover(&range, function_hover(&function.function));
             self.add_global_occurrence(
                 range,
                 location.clone(),
@@ -1322,6 +1696,7 @@ impl<'a> CompilerSemanticCollector<'a> {
         }
 
         if let Some(interface) = self.symbol_table.lookup_interface(self.current_program, &location) {
+            self.add_hover(&range, interface_hover(interface));
             self.add_global_occurrence(
                 range,
                 location.clone(),
@@ -1338,6 +1713,7 @@ impl<'a> CompilerSemanticCollector<'a> {
             .lookup_struct(self.current_program, &location)
             .or_else(|| self.symbol_table.lookup_record(self.current_program, &location))
         {
+            self.add_hover(&range, composite_hover(composite));
             self.add_global_occurrence(
                 range,
                 location.clone(),
@@ -1351,6 +1727,7 @@ impl<'a> CompilerSemanticCollector<'a> {
 
         if let Some(variable) = self.symbol_table.lookup_global(self.current_program, &location) {
             let (token_kind, readonly) = variable_symbol_semantics(variable.declaration);
+            self.add_hover(&range, global_variable_hover(path.identifier().name, variable));
             self.add_global_occurrence(
                 range,
                 location,
@@ -1376,6 +1753,8 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
         self.visit_path(&input.function, &());
         input.const_arguments.iter().for_each(|expr| self.visit_expression(expr, &()));
         input.arguments.iter().for_each(|expr| self.visit_expression(expr, &()));
+        self.add_const_argument_hints(&input.const_arguments);
+        self.add_parameter_hints(input);
     }
 
     /// Visit a composite literal, recording field names as member references.
@@ -1389,7 +1768,14 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
         let owner = self.member_owner_from_composite_init(input);
         self.visit_path(&input.path, &());
         input.const_arguments.iter().for_each(|expr| self.visit_expression(expr, &()));
+        self.add_const_argument_hints(&input.const_arguments);
         for CompositeFieldInitializer { identifier, expression, .. } in &input.members {
+            let fallback_type = expression.as_ref().and_then(|expression| self.type_table.get(&expression.id()));
+            if let Some(range) = span_to_file_range(identifier.span)
+                && let Some(hover) = self.member_access_hover(owner.as_ref(), identifier.name, fallback_type)
+            {
+                self.add_hover(&range, hover);
+            }
             self.add_member_occurrence(owner.clone(), identifier, OccurrenceRole::Reference, false, None);
             if let Some(expression) = expression {
                 self.visit_expression(expression, &());
@@ -1407,6 +1793,7 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
         // references, plus any const-generic arguments they carry.
         self.visit_path(&input.path, &());
         input.const_arguments.iter().for_each(|expr| self.visit_expression(expr, &()));
+        self.add_const_argument_hints(&input.const_arguments);
     }
 
     /// Visit dynamic operations whose target identity cannot be fully resolved.
@@ -1442,6 +1829,12 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
         // contribute their own occurrences before the property reference.
         let owner = self.member_owner_from_expression(&input.inner);
         self.visit_expression(&input.inner, &());
+        if let Some(range) = span_to_file_range(input.name.span)
+            && let Some(hover) =
+                self.member_access_hover(owner.as_ref(), input.name.name, self.type_table.get(&input.id()))
+        {
+            self.add_hover(&range, hover);
+        }
         self.add_member_occurrence(owner, &input.name, OccurrenceRole::Reference, false, None);
     }
 
@@ -1465,6 +1858,7 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
         let Some(range) = span_to_file_range(input.identifier().span) else {
             return;
         };
+        self.add_hover(&range, binding.hover);
         self.occurrences.push(SymbolOccurrence {
             range,
             identity: SymbolIdentity::Local { declaration: binding.declaration },
@@ -1477,7 +1871,7 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
     /// Visit a block inside a new lexical scope.
     fn visit_block(&mut self, input: &leo_ast::Block) {
         // Blocks introduce lexical scope for definitions created inside them.
-        self.push_scope();
+        self.push_scope(input.span);
         input.statements.iter().for_each(|statement| self.visit_statement(statement));
         self.pop_scope();
     }
@@ -1492,6 +1886,11 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
             // Top-level consts participate in global path resolution, so they
             // must use a global identity instead of the local-binding fallback.
             if let Some(range) = span_to_file_range(input.place.span) {
+                let code = format!("const {}: {}", input.place, input.type_);
+                self.add_hover(
+                    &range,
+                    declaration_text::markdown(&code, declaration_text::doc_comment(input.span).as_deref()),
+                );
                 self.add_global_occurrence(
                     range.clone(),
                     self.current_item_location(input.place.name),
@@ -1502,7 +1901,10 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
                 );
             }
         } else {
-            self.bind_local(&input.place, SemanticKind::Variable, true);
+            let code = format!("const {}: {}", input.place, input.type_);
+            let mut description = LocalDescription::new(code, Some(&input.type_));
+            description.value = self.const_value(&input.value, 0);
+            self.bind_local(&input.place, SemanticKind::Variable, true, description);
         }
     }
 
@@ -1513,11 +1915,31 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
         }
         self.visit_expression(&input.value, &());
         // Definitions can destructure multiple identifiers, but each bound name
-        // still becomes its own local semantic declaration.
+        // still becomes its own local semantic declaration. Unannotated bindings
+        // take their hover type from the type checker's view of the initializer,
+        // and only they get an inlay type hint.
+        let inferred = input.type_.is_none();
+        let type_ = input.type_.clone().or_else(|| self.type_table.get(&input.value.id()));
         match &input.place {
-            DefinitionPlace::Single(identifier) => self.bind_local(identifier, SemanticKind::Variable, false),
+            DefinitionPlace::Single(identifier) => {
+                if inferred {
+                    self.add_type_hint(identifier, type_.as_ref());
+                }
+                let description = LocalDescription::new(local_hover("let", identifier, type_.as_ref()), type_.as_ref());
+                self.bind_local(identifier, SemanticKind::Variable, false, description);
+            }
             DefinitionPlace::Multiple(identifiers) => {
-                identifiers.iter().for_each(|identifier| self.bind_local(identifier, SemanticKind::Variable, false));
+                for (index, identifier) in identifiers.iter().enumerate() {
+                    let element = match type_.as_ref() {
+                        Some(Type::Tuple(tuple)) => tuple.elements().get(index),
+                        _ => None,
+                    };
+                    if inferred {
+                        self.add_type_hint(identifier, element);
+                    }
+                    let description = LocalDescription::new(local_hover("let", identifier, element), element);
+                    self.bind_local(identifier, SemanticKind::Variable, false, description);
+                }
             }
         }
     }
@@ -1529,9 +1951,15 @@ impl<'a> AstVisitor for CompilerSemanticCollector<'a> {
         }
         self.visit_expression(&input.start, &());
         self.visit_expression(&input.stop, &());
+        self.add_loop_count_hint(input);
         // Loop variables are rebound for the duration of the loop body only.
-        self.push_scope();
-        self.bind_local(&input.variable, SemanticKind::Variable, true);
+        self.push_scope(input.span);
+        let type_ = input.type_.clone().or_else(|| self.type_table.get(&input.start.id()));
+        if input.type_.is_none() {
+            self.add_type_hint(&input.variable, type_.as_ref());
+        }
+        let description = LocalDescription::new(local_hover("for", &input.variable, type_.as_ref()), type_.as_ref());
+        self.bind_local(&input.variable, SemanticKind::Variable, true, description);
         self.visit_block(&input.block);
         self.pop_scope();
     }
@@ -1644,6 +2072,7 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // push that owner before walking members.
         if let Some(range) = span_to_file_range(input.identifier.span) {
             let location = self.current_item_location(input.identifier.name);
+            self.add_hover(&range, composite_hover(input));
             self.add_global_occurrence(
                 range.clone(),
                 location.clone(),
@@ -1657,12 +2086,12 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
             self.owner_stack.push(None);
         }
 
-        self.push_scope();
-        input.const_parameters.iter().for_each(|parameter| {
-            self.visit_type(&parameter.type_);
-            self.bind_local(&parameter.identifier, SemanticKind::Parameter, true);
-        });
+        self.push_scope(input.span);
+        self.bind_const_parameters(&input.const_parameters);
         input.members.iter().for_each(|member| {
+            if let Some(range) = span_to_file_range(member.identifier.span) {
+                self.add_hover(&range, member_hover(input, member));
+            }
             self.add_current_member_occurrence(
                 &member.identifier,
                 OccurrenceRole::Declaration,
@@ -1679,6 +2108,11 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
     fn visit_mapping(&mut self, input: &Mapping) {
         // Mappings surface to the editor like property-like global declarations.
         if let Some(range) = span_to_file_range(input.identifier.span) {
+            let code = declaration_text::mapping_text(input.identifier, &input.key_type, &input.value_type);
+            self.add_hover(
+                &range,
+                declaration_text::markdown(&code, declaration_text::doc_comment(input.span).as_deref()),
+            );
             self.add_global_occurrence(
                 range.clone(),
                 self.current_item_location(input.identifier.name),
@@ -1697,6 +2131,11 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // Storage declarations are highlighted the same way as other
         // property-shaped global state.
         if let Some(range) = span_to_file_range(input.identifier.span) {
+            let code = declaration_text::storage_text(input.identifier, &input.type_);
+            self.add_hover(
+                &range,
+                declaration_text::markdown(&code, declaration_text::doc_comment(input.span).as_deref()),
+            );
             self.add_global_occurrence(
                 range.clone(),
                 self.current_item_location(input.identifier.name),
@@ -1714,6 +2153,11 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // Interface mapping prototypes mirror concrete mapping declarations for
         // semantic-token purposes, but without executable bodies.
         if let Some(range) = span_to_file_range(input.identifier.span) {
+            let code = declaration_text::mapping_text(input.identifier, &input.key_type, &input.value_type);
+            self.add_hover(
+                &range,
+                declaration_text::markdown(&code, declaration_text::doc_comment(input.span).as_deref()),
+            );
             self.add_global_occurrence(
                 range.clone(),
                 self.owned_item_location(input.identifier.name),
@@ -1732,6 +2176,11 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // Interface storage prototypes still contribute property declarations
         // even though no backing storage exists in this source file.
         if let Some(range) = span_to_file_range(input.identifier.span) {
+            let code = declaration_text::storage_text(input.identifier, &input.type_);
+            self.add_hover(
+                &range,
+                declaration_text::markdown(&code, declaration_text::doc_comment(input.span).as_deref()),
+            );
             self.add_global_occurrence(
                 range.clone(),
                 self.owned_item_location(input.identifier.name),
@@ -1749,6 +2198,7 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // Function parameters introduce the outermost lexical scope for the
         // function body, before nested blocks add their own scopes.
         if let Some(range) = span_to_file_range(input.identifier.span) {
+            self.add_hover(&range, function_hover(input));
             self.add_global_occurrence(
                 range.clone(),
                 self.current_item_location(input.identifier.name),
@@ -1759,15 +2209,9 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
             );
         }
 
-        self.push_scope();
-        input.const_parameters.iter().for_each(|parameter| {
-            self.visit_type(&parameter.type_);
-            self.bind_local(&parameter.identifier, SemanticKind::Parameter, true);
-        });
-        input.input.iter().for_each(|parameter| {
-            self.visit_type(&parameter.type_);
-            self.bind_local(&parameter.identifier, SemanticKind::Parameter, false);
-        });
+        self.push_scope(input.span);
+        self.bind_const_parameters(&input.const_parameters);
+        self.bind_inputs(&input.input);
         input.output.iter().for_each(|output| self.visit_type(&output.type_));
         self.visit_type(&input.output_type);
         self.visit_block(&input.block);
@@ -1780,6 +2224,7 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // though they are prototype declarations rather than full definitions.
         if let Some(range) = span_to_file_range(input.identifier.span) {
             let location = self.current_item_location(input.identifier.name);
+            self.add_hover(&range, interface_hover(input));
             self.add_global_occurrence(
                 range.clone(),
                 location.clone(),
@@ -1807,6 +2252,11 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // even though there is no executable body to visit.
         if let Some(range) = span_to_file_range(input.identifier.span) {
             let location = self.owned_item_location(input.identifier.name);
+            let code = declaration_text::function_prototype_signature(input);
+            self.add_hover(
+                &range,
+                declaration_text::markdown(&code, declaration_text::doc_comment(input.span).as_deref()),
+            );
             self.add_global_occurrence(
                 range.clone(),
                 location,
@@ -1817,15 +2267,9 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
             );
         }
 
-        self.push_scope();
-        input.const_parameters.iter().for_each(|parameter| {
-            self.visit_type(&parameter.type_);
-            self.bind_local(&parameter.identifier, SemanticKind::Parameter, true);
-        });
-        input.input.iter().for_each(|parameter| {
-            self.visit_type(&parameter.type_);
-            self.bind_local(&parameter.identifier, SemanticKind::Parameter, false);
-        });
+        self.push_scope(input.span);
+        self.bind_const_parameters(&input.const_parameters);
+        self.bind_inputs(&input.input);
         input.output.iter().for_each(|output| self.visit_type(&output.type_));
         self.visit_type(&input.output_type);
         self.pop_scope();
@@ -1836,6 +2280,11 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         // Record members inherit the record prototype as their semantic owner.
         if let Some(range) = span_to_file_range(input.identifier.span) {
             let location = self.owned_item_location(input.identifier.name);
+            let code = declaration_text::record_prototype_layout(input);
+            self.add_hover(
+                &range,
+                declaration_text::markdown(&code, declaration_text::doc_comment(input.span).as_deref()),
+            );
             self.add_global_occurrence(
                 range.clone(),
                 location.clone(),
@@ -1850,6 +2299,13 @@ impl<'a> UnitVisitor for CompilerSemanticCollector<'a> {
         }
 
         input.members.iter().for_each(|member| {
+            if let Some(range) = span_to_file_range(member.identifier.span) {
+                let code = format!("record {}\n{}", input.identifier, declaration_text::member_text(member));
+                self.add_hover(
+                    &range,
+                    declaration_text::markdown(&code, declaration_text::doc_comment(member.span).as_deref()),
+                );
+            }
             self.add_current_member_occurrence(
                 &member.identifier,
                 OccurrenceRole::Declaration,
@@ -1874,6 +2330,346 @@ fn variable_symbol_semantics(declaration: VariableType) -> (SemanticKind, bool)
     }
 }
 
+/// Render hover markdown for a function declaration and its doc comment.
+fn function_hover(function: &Function) -> String {
+    declaration_text::markdown(
+        &declaration_text::function_signature(function),
+        declaration_text::doc_comment(function.span).as_deref(),
+    )
+}
+
+/// Render hover markdown for a struct or record layout and its doc comment.
+fn composite_hover(composite: &Composite) -> String {
+    declaration_text::markdown(
+        &declaration_text::composite_layout(composite),
+        declaration_text::doc_comment(composite.span).as_deref(),
+    )
+}
+
+/// Render hover markdown for one composite member, naming its owner.
+fn member_hover(composite: &Composite, member: &leo_ast::Member) -> String {
+    let keyword = if composite.is_record { "record" } else { "struct" };
+    let code = format!("{keyword} {}\n{}", composite.identifier, declaration_text::member_text(member));
+    declaration_text::markdown(&code, declaration_text::doc_comment(member.span).as_deref())
+}
+
+/// Render hover markdown for an interface header and its doc comment.
+fn interface_hover(interface: &Interface) -> String {
+    declaration_text::markdown(
+        &declaration_text::interface_header(interface),
+        declaration_text::doc_comment(interface.span).as_deref(),
+    )
+}
+
+/// Render hover markdown for a global const or storage item found through the symbol table.
+fn global_variable_hover(name: Symbol, variable: &VariableSymbol) -> String {
+    let code = match (variable.declaration, variable.type_.as_ref()) {
+        (VariableType::Storage, Some(type_)) => declaration_text::storage_text(name, type_),
+        (VariableType::Storage, None) => format!("storage {name}"),
+        (_, Some(type_)) => format!("const {name}: {type_}"),
+        (_, None) => format!("const {name}"),
+    };
+    declaration_text::markdown(&code, declaration_text::doc_comment(variable.span).as_deref())
+}
+
+/// Render a local binding such as `let total: u64`, omitting unknown types.
+fn local_hover(keyword: &str, identifier: &Identifier, type_: Option<&Type>) -> String {
+    match type_ {
+        Some(type_) => format!("{keyword} {identifier}: {type_}"),
+        None => format!("{keyword} {identifier}"),
+    }
+}
+
+/// Map every global `const` in `ast` and its imported stubs to its initializer.
+fn global_consts(ast: &Ast) -> HashMap<Location, &Expression> {
+    let mut consts = HashMap::new();
+    match ast {
+        Ast::Program(program) => program_consts(program, &mut consts),
+        Ast::Library(library) => library_consts(library, &mut consts),
+    }
+    consts
+}
+
+/// Collect the global `const`s of a program, its modules, and its source stubs.
+fn program_consts<'a>(program: &'a Program, consts: &mut HashMap<Location, &'a Expression>) {
+    for scope in program.program_scopes.values() {
+        for (_, declaration) in &scope.consts {
+            consts
+                .insert(Location::new(scope.program_id.as_symbol(), vec![declaration.place.name]), &declaration.value);
+        }
+    }
+    program.modules.values().for_each(|module| module_consts(module, consts));
+    for stub in program.stubs.values() {
+        match stub {
+            Stub::FromLeo { program, .. } => program_consts(program, consts),
+            Stub::FromLibrary { library, .. } => library_consts(library, consts),
+            Stub::FromAleo { .. } => {}
+        }
+    }
+}
+
+/// Collect the global `const`s of a library and its modules.
+fn library_consts<'a>(library: &'a leo_ast::Library, consts: &mut HashMap<Location, &'a Expression>) {
+    for (_, declaration) in &library.consts {
+        consts.insert(Location::new(library.name, vec![declaration.place.name]), &declaration.value);
+    }
+    library.modules.values().for_each(|module| module_consts(module, consts));
+}
+
+/// Collect the `const`s declared in one module under its full module path.
+fn module_consts<'a>(module: &'a Module, consts: &mut HashMap<Location, &'a Expression>) {
+    for (_, declaration) in &module.consts {
+        let mut path = module.path.clone();
+        path.push(declaration.place.name);
+        consts.insert(Location::new(module.unit_name, path), &declaration.value);
+    }
+}
+
+/// Classify a type for chained `.` completion.
+///
+/// Composite paths resolve through the type checker's global location when
+/// present, and otherwise through `fallback`, which maps a bare name to its
+/// location in the enclosing module.
+fn value_shape(type_: &Type, fallback: impl FnOnce(Symbol) -> Location) -> Option<ValueShape> {
+    match type_ {
+        Type::Composite(composite) => {
+            let location = composite
+                .path
+                .try_global_location()
+                .cloned()
+                .unwrap_or_else(|| fallback(composite.path.identifier().name));
+            Some(ValueShape::Composite(location.to_string().into()))
+        }
+        Type::Mapping(_) => Some(ValueShape::Mapping),
+        Type::Vector(_) => Some(ValueShape::Vector),
+        Type::Optional(_) => Some(ValueShape::Optional),
+        _ => None,
+    }
+}
+
+/// Classify a storage declaration: containers keep their own operations and
+/// singleton reads yield an optional.
+fn storage_shape(type_: &Type, fallback: impl FnOnce(Symbol) -> Location) -> Option<ValueShape> {
+    match value_shape(type_, fallback) {
+        Some(shape @ (ValueShape::Mapping | ValueShape::Vector)) => Some(shape),
+        _ => Some(ValueShape::Optional),
+    }
+}
+
+/// Lower the symbol table into completion candidates for the analyzed unit.
+///
+/// Items of the analyzed program are offered unqualified or through their
+/// module path, library items through `library::module::`, and items of
+/// imported programs through `program.aleo::`. Items another unit did not
+/// `export` are skipped because the analyzed unit cannot name them.
+fn completion_index(ast: &Ast, symbol_table: &SymbolTable, locals: Vec<LocalCompletion>) -> CompletionIndex {
+    let current = match ast {
+        Ast::Program(program) => program.program_scopes.values().next().map(|scope| scope.program_id.as_symbol()),
+        Ast::Library(library) => Some(library.name),
+    };
+    let mut builder = CompletionIndexBuilder {
+        current,
+        index: CompletionIndex { locals, ..CompletionIndex::default() },
+        modules: HashSet::new(),
+    };
+
+    for (location, function) in symbol_table.iter_functions() {
+        let function = &function.function;
+        if !builder.is_visible(location, function.is_exported) {
+            continue;
+        }
+        let mut entry = CompletionEntry::new(
+            function.identifier.name.to_string(),
+            CompletionKind::Function,
+            Some(declaration_text::function_signature(function)),
+        );
+        entry.documentation = declaration_text::doc_comment(function.span).map(Arc::from);
+        entry.signature = Some(Arc::new(declaration_text::function_call_signature(function)));
+        builder.add_path_item(location, entry);
+    }
+
+    for (location, composite) in symbol_table.iter_structs().chain(symbol_table.iter_records()) {
+        let key: Arc<str> = location.to_string().into();
+        let fallback_module = |name: Symbol| {
+            let mut path = location.module_path().to_vec();
+            path.push(name);
+            Location::new(location.program, path)
+        };
+        let members = composite
+            .members
+            .iter()
+            .map(|member| {
+                let mut entry = CompletionEntry::new(
+                    member.identifier.name.to_string(),
+                    CompletionKind::Field,
+                    Some(declaration_text::member_text(member)),
+                );
+                entry.documentation = declaration_text::doc_comment(member.span).map(Arc::from);
+                entry.shape = value_shape(&member.type_, fallback_module);
+                entry
+            })
+            .collect();
+        builder.index.members.insert(key, members);
+
+        if !builder.is_visible(location, composite.is_exported) {
+            continue;
+        }
+        let kind = if composite.is_record { CompletionKind::Record } else { CompletionKind::Struct };
+        let header = if composite.is_record { "record" } else { "struct" };
+        let mut entry = CompletionEntry::new(
+            composite.identifier.name.to_string(),
+            kind,
+            Some(format!("{header} {}", composite.identifier)),
+        );
+        entry.documentation = declaration_text::doc_comment(composite.span).map(Arc::from);
+        builder.add_path_item(location, entry);
+    }
+
+    for (location, variable) in symbol_table.iter_globals() {
+        if !builder.is_visible(location, variable.is_exported) {
+            continue;
+        }
+        let Some(name) = location.path.last().copied() else {
+            continue;
+        };
+        let fallback = |name: Symbol| Location::new(location.program, vec![name]);
+        let (kind, detail, shape) = match (variable.declaration, variable.type_.as_ref()) {
+            (VariableType::Storage, Some(type_ @ Type::Mapping(_))) => {
+                (CompletionKind::Mapping, Some(declaration_text::storage_text(name, type_)), Some(ValueShape::Mapping))
+            }
+            (VariableType::Storage, Some(type_)) => (
+                CompletionKind::Storage,
+                Some(declaration_text::storage_text(name, type_)),
+                storage_shape(type_, fallback),
+            ),
+            (VariableType::Storage, None) => (CompletionKind::Storage, None, None),
+            (_, Some(type_)) => {
+                (CompletionKind::Constant, Some(format!("const {name}: {type_}")), value_shape(type_, fallback))
+            }
+            (_, None) => (CompletionKind::Constant, None, None),
+        };
+        let mut entry = CompletionEntry::new(name.to_string(), kind, detail);
+        entry.documentation = declaration_text::doc_comment(variable.span).map(Arc::from);
+        entry.shape = shape;
+        builder.add_path_item(location, entry);
+    }
+
+    let mut parents = Vec::new();
+    for (location, interface) in symbol_table.iter_interfaces() {
+        let fallback = |name: Symbol| Location::new(location.program, vec![name]);
+        let mut items = Vec::new();
+        for (_, prototype) in &interface.functions {
+            let mut entry = CompletionEntry::new(
+                prototype.identifier.name.to_string(),
+                CompletionKind::Function,
+                Some(declaration_text::function_prototype_signature(prototype)),
+            );
+            entry.documentation = declaration_text::doc_comment(prototype.span).map(Arc::from);
+            entry.signature = Some(Arc::new(declaration_text::function_prototype_call_signature(prototype)));
+            items.push(entry);
+        }
+        for mapping in &interface.mappings {
+            let mut entry = CompletionEntry::new(
+                mapping.identifier.name.to_string(),
+                CompletionKind::Mapping,
+                Some(declaration_text::mapping_text(mapping.identifier, &mapping.key_type, &mapping.value_type)),
+            );
+            entry.shape = Some(ValueShape::Mapping);
+            items.push(entry);
+        }
+        for storage in &interface.storages {
+            let mut entry = CompletionEntry::new(
+                storage.identifier.name.to_string(),
+                CompletionKind::Storage,
+                Some(declaration_text::storage_text(storage.identifier, &storage.type_)),
+            );
+            entry.shape = storage_shape(&storage.type_, fallback);
+            items.push(entry);
+        }
+        let name: Arc<str> = interface.identifier.name.to_string().into();
+        for (_, parent) in &interface.parents {
+            if let Type::Composite(parent) = parent {
+                parents.push((Arc::clone(&name), Arc::<str>::from(parent.path.identifier().name.to_string())));
+            }
+        }
+        builder.index.interfaces.entry(name).or_default().extend(items);
+
+        if builder.is_visible(location, interface.is_exported) {
+            let mut entry = CompletionEntry::new(
+                interface.identifier.name.to_string(),
+                CompletionKind::Interface,
+                Some(declaration_text::interface_header(interface)),
+            );
+            entry.documentation = declaration_text::doc_comment(interface.span).map(Arc::from);
+            builder.add_path_item(location, entry);
+        }
+    }
+
+    // Dynamic calls through a child interface may reach any inherited item.
+    // Repeat until no interface grows so multi-level hierarchies flatten too.
+    let interfaces = &mut builder.index.interfaces;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (child, parent) in &parents {
+            let inherited = interfaces.get(parent).cloned().unwrap_or_default();
+            let items = interfaces.entry(Arc::clone(child)).or_default();
+            for entry in inherited {
+                if !items.iter().any(|item| item.label == entry.label) {
+                    items.push(entry);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    builder.index
+}
+
+/// Accumulates [`CompletionIndex`] path entries and the module names leading to them.
+struct CompletionIndexBuilder {
+    /// Program or library being analyzed; its items need no unit qualifier.
+    current: Option<Symbol>,
+    /// Index under construction.
+    index: CompletionIndex,
+    /// `(qualifier, module)` pairs already offered, to avoid duplicates.
+    modules: HashSet<(String, String)>,
+}
+
+impl CompletionIndexBuilder {
+    /// Return whether the analyzed unit may name an item at `location`.
+    fn is_visible(&self, location: &Location, is_exported: Option<bool>) -> bool {
+        !location.is_dynamic() && (Some(location.program) == self.current || is_exported != Some(false))
+    }
+
+    /// Return the `::` qualifier segments that name items in `location`'s module.
+    fn qualifier(&self, location: &Location) -> Vec<String> {
+        let module = location.module_path().iter().map(ToString::to_string);
+        if Some(location.program) == self.current {
+            module.collect()
+        } else {
+            std::iter::once(location.program.to_string()).chain(module).collect()
+        }
+    }
+
+    /// Register `entry` under its qualifier and every module name leading to it.
+    fn add_path_item(&mut self, location: &Location, entry: CompletionEntry) {
+        let qualifier = self.qualifier(location);
+        for depth in 0..qualifier.len() {
+            let parent = qualifier[..depth].join("::");
+            let module = qualifier[depth].clone();
+            if self.modules.insert((parent.clone(), module.clone())) {
+                self.index.paths.entry(parent.into()).or_default().push(CompletionEntry::new(
+                    module,
+                    CompletionKind::Module,
+                    None,
+                ));
+            }
+        }
+        self.index.paths.entry(qualifier.join("::").into()).or_default().push(entry);
+    }
+}
+
 /// Return the source declaration range for an imported dependency stub.
 fn stub_program_declaration_range(stub: &Stub) -> Option<FileRange> {
     match stub {
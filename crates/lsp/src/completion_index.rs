@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Snapshot-safe completion candidates lowered from compiler state.
+//!
+//! The worker fills a [`CompletionIndex`] while the compiler session is still
+//! alive: every item reachable through a `::` path, every struct and record
+//! member, every interface item reachable through `Interface@(target)::`, and
+//! every local binding with the byte range of the scope that can see it. The
+//! completion feature then answers cursor queries from this table alone.
+//!
+//! Completion usually runs against a buffer the user is halfway through
+//! typing, which rarely type-checks. The routing thread therefore keeps the
+//! last compiler-enhanced index per package around, and local scopes carry
+//! enough offset information to tolerate the edits made since then.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Coarse shape of a value, as far as `.` member completion is concerned.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ValueShape {
+    /// A struct or record, keyed by its compiler location (`program/path`).
+    Composite(Arc<str>),
+    /// A mapping, completing to mapping operations.
+    Mapping,
+    /// A storage vector, completing to vector operations.
+    Vector,
+    /// An optional value such as a singleton storage read.
+    Optional,
+}
+
+/// Editor-facing category of one completion candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompletionKind {
+    /// A library, program, submodule, or intrinsic namespace.
+    Module,
+    /// A callable function or function prototype.
+    Function,
+    /// A struct declaration.
+    Struct,
+    /// A record declaration.
+    Record,
+    /// An interface declaration.
+    Interface,
+    /// A global or local constant, including const parameters.
+    Constant,
+    /// A mutable local binding.
+    Variable,
+    /// A function input.
+    Parameter,
+    /// A struct or record member.
+    Field,
+    /// A mapping declaration.
+    Mapping,
+    /// A storage declaration.
+    Storage,
+    /// A storage or optional operation invoked with `.`.
+    Method,
+}
+
+/// One completion candidate with its rendered signature and documentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionEntry {
+    /// Text inserted into the buffer.
+    pub label: Arc<str>,
+    /// Candidate category.
+    pub kind: CompletionKind,
+    /// One-line Leo rendering such as a signature or `name: type`.
+    pub detail: Option<Arc<str>>,
+    /// Markdown documentation recovered from `///` comments.
+    pub documentation: Option<Arc<str>>,
+    /// Shape of the value this candidate evaluates to, for chained member access.
+    pub shape: Option<ValueShape>,
+    /// Parameter layout of a callable candidate, for signature help.
+    pub signature: Option<Arc<CallSignature>>,
+}
+
+impl CompletionEntry {
+    /// Build a candidate without documentation or a value shape.
+    pub fn new(label: impl Into<Arc<str>>, kind: CompletionKind, detail: Option<String>) -> Self {
+        Self {
+            label: label.into(),
+            kind,
+            detail: detail.map(Arc::from),
+            documentation: None,
+            shape: None,
+            signature: None,
+        }
+    }
+}
+
+/// Callable header split into the pieces signature help highlights.
+///
+/// Rendering it with [`fmt::Display`] yields the one-line signature shown on
+/// hover, e.g. `fn sum::[N: u32](values: [u32; N]) -> u32`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallSignature {
+    /// Keyword and name, e.g. `final fn settle` or `fn BHP256::hash_to_field`.
+    pub prefix: String,
+    /// Const generic parameters as `(name, type)`; the type is empty for intrinsic type parameters.
+    pub const_parameters: Vec<(String, String)>,
+    /// Parameters as written in source, e.g. `public amount: u64`.
+    pub parameters: Vec<String>,
+    /// Return type; `None` when the callable returns nothing.
+    pub output: Option<String>,
+    /// Whether the last parameter may repeat, rendered as a trailing `, ..`.
+    pub variadic: bool,
+}
+
+impl fmt::Display for CallSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.prefix)?;
+        if !self.const_parameters.is_empty() {
+            let parameters = self
+                .const_parameters
+                .iter()
+                .map(|(name, type_)| if type_.is_empty() { name.clone() } else { format!("{name}: {type_}") })
+                .collect::<Vec<_>>();
+            write!(f, "::[{}]", parameters.join(", "))?;
+        }
+        let variadic = if self.variadic { ", .." } else { "" };
+        write!(f, "({}{variadic})", self.parameters.join(", "))?;
+        if let Some(output) = &self.output {
+            write!(f, " -> {output}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A local binding and the scope region that can see it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalCompletion {
+    /// File containing the binding.
+    pub path: Arc<PathBuf>,
+    /// Start offset of the enclosing block or function.
+    pub scope_start: u32,
+    /// End offset of the enclosing block or function.
+    pub scope_end: u32,
+    /// End offset of the binding identifier; the name is usable after it.
+    pub visible_from: u32,
+    /// Candidate offered while the binding is in scope.
+    pub entry: CompletionEntry,
+}
+
+/// Completion candidates for one package analysis.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionIndex {
+    /// Items reachable through a `::` qualifier, keyed by the joined qualifier; `""` holds unqualified names.
+    pub paths: HashMap<Arc<str>, Vec<CompletionEntry>>,
+    /// Members of each struct or record, keyed by the composite location.
+    pub members: HashMap<Arc<str>, Vec<CompletionEntry>>,
+    /// Items reachable through `Interface@(target)::`, keyed by interface name.
+    pub interfaces: HashMap<Arc<str>, Vec<CompletionEntry>>,
+    /// Local bindings in every analyzed package file.
+    pub locals: Vec<LocalCompletion>,
+    /// Text length of each file at analysis time, used to stretch stale scopes.
+    pub analyzed_lengths: HashMap<PathBuf, u32>,
+}
+
+impl CompletionIndex {
+    /// Return whether compiler analysis contributed any candidates.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty() && self.locals.is_empty()
+    }
+
+    /// Return the items reachable through `qualifier`, e.g. `["std", "hash"]`.
+    pub fn path_items(&self, qualifier: &[&str]) -> &[CompletionEntry] {
+        self.paths.get(qualifier.join("::").as_str()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Return the items reachable through `Interface@(target)::`.
+    pub fn interface_items(&self, interface: &str) -> &[CompletionEntry] {
+        self.interfaces.get(interface).map_or(&[], Vec::as_slice)
+    }
+
+    /// Return the members of a struct or record.
+    pub fn composite_members(&self, key: &str) -> &[CompletionEntry] {
+        self.members.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Return the locals visible at `offset` in `path`, innermost binding first.
+    ///
+    /// `current_len` is the length of the buffer being completed. When it
+    /// differs from the analyzed length, scope ends after the cursor are
+    /// shifted by the difference so an index computed before the latest
+    /// keystrokes still covers the cursor.
+    pub fn locals_at(&self, path: &Path, offset: u32, current_len: u32) -> Vec<&CompletionEntry> {
+        let analyzed_len = self.analyzed_lengths.get(path).copied().unwrap_or(current_len);
+        let delta = i64::from(current_len) - i64::from(analyzed_len);
+
+        let mut visible = self
+            .locals
+            .iter()
+            .filter(|local| local.path.as_path() == path)
+            .filter(|local| {
+                let scope_end = i64::from(local.scope_end) + delta.max(0);
+                local.scope_start <= offset && local.visible_from <= offset && i64::from(offset) <= scope_end
+            })
+            .collect::<Vec<_>>();
+        // Later and more deeply nested bindings shadow earlier ones.
+        visible.sort_by(|left, right| {
+            right.scope_start.cmp(&left.scope_start).then_with(|| right.visible_from.cmp(&left.visible_from))
+        });
+
+        let mut seen = std::collections::HashSet::new();
+        visible
+            .into_iter()
+            .filter(|local| seen.insert(Arc::clone(&local.entry.label)))
+            .map(|local| &local.entry)
+            .collect()
+    }
+
+    /// Follow a `.` receiver chain such as `token.owner` to the shape of its final value.
+    ///
+    /// The first segment resolves through the locals visible at `offset`, then
+    /// through unqualified globals; later segments walk composite members.
+    pub fn receiver_shape(&self, path: &Path, offset: u32, current_len: u32, chain: &[String]) -> Option<ValueShape> {
+        let (first, rest) = chain.split_first()?;
+        let mut shape = self
+            .locals_at(path, offset, current_len)
+            .into_iter()
+            .chain(self.path_items(&[]))
+            .find(|entry| entry.label.as_ref() == first)?
+            .shape
+            .clone()?;
+        for member in rest {
+            let ValueShape::Composite(key) = shape else {
+                return None;
+            };
+            shape = self.composite_members(&key).iter().find(|entry| entry.label.as_ref() == member)?.shape.clone()?;
+        }
+        Some(shape)
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Leo source renderings of compiler declarations.
+//!
+//! The AST `Display` impls print whole definitions, bodies included, and are
+//! tuned for compiler diagnostics. Editor surfaces want one-line signatures and
+//! compact field layouts instead, plus the `///` doc comment the parser drops.
+//! Every helper here must run inside the Leo session that produced the AST,
+//! because type paths and doc comments are resolved through session globals.
+
+use crate::completion_index::CallSignature;
+use leo_ast::{
+    Composite,
+    ConstParameter,
+    Function,
+    FunctionPrototype,
+    Input,
+    Interface,
+    Member,
+    Mode,
+    Output,
+    RecordPrototype,
+    Type,
+    Variant,
+};
+use leo_span::{Span, with_session_globals};
+
+/// Render a function header such as `fn transfer(public to: address, amount: u64) -> Token`.
+pub fn function_signature(function: &Function) -> String {
+    let prefix = if function.is_exported == Some(true) { "export " } else { "" };
+    format!("{prefix}{}", function_call_signature(function))
+}
+
+/// Render an interface function prototype header.
+pub fn function_prototype_signature(prototype: &FunctionPrototype) -> String {
+    function_prototype_call_signature(prototype).to_string()
+}
+
+/// Split a function header into the pieces signature help highlights.
+pub fn function_call_signature(function: &Function) -> CallSignature {
+    call_signature(
+        function.variant,
+        &function.identifier.to_string(),
+        &function.const_parameters,
+        &function.input,
+        &function.output,
+    )
+}
+
+/// Split an interface function prototype header into the pieces signature help highlights.
+pub fn function_prototype_call_signature(prototype: &FunctionPrototype) -> CallSignature {
+    call_signature(
+        prototype.variant,
+        &prototype.identifier.to_string(),
+        &prototype.const_parameters,
+        &prototype.input,
+        &prototype.output,
+    )
+}
+
+/// Build the shared `fn name::[..](..) -> ..` shape for functions and prototypes.
+fn call_signature(
+    variant: Variant,
+    name: &str,
+    const_parameters: &[ConstParameter],
+    inputs: &[Input],
+    outputs: &[Output],
+) -> CallSignature {
+    let keyword = match variant {
+        Variant::FinalFn => "final fn",
+        Variant::Fn | Variant::EntryPoint => "fn",
+        Variant::Finalize => "finalize",
+        Variant::View => "view fn",
+    };
+    let output = match outputs {
+        [] => None,
+        [output] if matches!(output.type_, Type::Unit) => None,
+        [output] => Some(output.to_string()),
+        outputs => Some(format!("({})", join(outputs, ", "))),
+    };
+    CallSignature {
+        prefix: format!("{keyword} {name}"),
+        const_parameters: const_parameters
+            .iter()
+            .map(|parameter| (parameter.identifier.to_string(), parameter.type_.to_string()))
+            .collect(),
+        parameters: inputs.iter().map(ToString::to_string).collect(),
+        output,
+        variadic: false,
+    }
+}
+
+/// Render a struct or record with one field per line.
+pub fn composite_layout(composite: &Composite) -> String {
+    let prefix = if composite.is_exported == Some(true) { "export " } else { "" };
+    let keyword = if composite.is_record { "record" } else { "struct" };
+    let mut header = format!("{prefix}{keyword} {}", composite.identifier);
+    if !composite.const_parameters.is_empty() {
+        header.push_str(&format!("::[{}]", join(&composite.const_parameters, ", ")));
+    }
+    member_block(header, &composite.members)
+}
+
+/// Render an interface record prototype with its required fields.
+pub fn record_prototype_layout(prototype: &RecordPrototype) -> String {
+    member_block(format!("record {}", prototype.identifier), &prototype.members)
+}
+
+/// Render `header { field, ... }`, collapsing to `header {}` for empty bodies.
+fn member_block(header: String, members: &[Member]) -> String {
+    if members.is_empty() {
+        return format!("{header} {{}}");
+    }
+    let fields = members.iter().map(|member| format!("    {},\n", member_text(member))).collect::<String>();
+    format!("{header} {{\n{fields}}}")
+}
+
+/// Render one composite member as written in source, e.g. `public amount: u64`.
+pub fn member_text(member: &Member) -> String {
+    // `Member`'s own `Display` drops the colon after moded fields.
+    match member.mode {
+        Mode::None => format!("{}: {}", member.identifier, member.type_),
+        mode => format!("{mode} {}: {}", member.identifier, member.type_),
+    }
+}
+
+/// Render an interface header including any parent interfaces.
+pub fn interface_header(interface: &Interface) -> String {
+    let prefix = if interface.is_exported == Some(true) { "export " } else { "" };
+    if interface.parents.is_empty() {
+        format!("{prefix}interface {}", interface.identifier)
+    } else {
+        format!(
+            "{prefix}interface {}: {}",
+            interface.identifier,
+            interface.parents.iter().map(|(_, parent)| parent.to_string()).collect::<Vec<_>>().join(" + ")
+        )
+    }
+}
+
+/// Render a mapping declaration without its trailing semicolon.
+pub fn mapping_text(name: impl std::fmt::Display, key: &Type, value: &Type) -> String {
+    format!("mapping {name}: {key} => {value}")
+}
+
+/// Render a storage declaration, spelling mapping-typed globals as mappings.
+pub fn storage_text(name: impl std::fmt::Display, type_: &Type) -> String {
+    match type_ {
+        Type::Mapping(mapping) => mapping_text(name, &mapping.key, &mapping.value),
+        type_ => format!("storage {name}: {type_}"),
+    }
+}
+
+/// Join displayable items with a separator.
+fn join<T: std::fmt::Display>(items: &[T], separator: &str) -> String {
+    items.iter().map(ToString::to_string).collect::<Vec<_>>().join(separator)
+}
+
+/// Recover the `///` doc comment written directly above a declaration.
+///
+/// Annotation lines such as `@test` may sit between the comment and the item,
+/// so they are skipped; any other line ends the comment block.
+pub fn doc_comment(span: Span) -> Option<String> {
+    if span.is_dummy() {
+        return None;
+    }
+
+    with_session_globals(|session| {
+        let source_file = session.source_map.find_source_file(span.lo)?;
+        if span.lo > source_file.absolute_end {
+            return None;
+        }
+        let start = source_file.relative_offset(span.lo) as usize;
+        let before = source_file.src.get(..start)?;
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        let mut lines = Vec::new();
+        for line in before[..line_start].lines().rev() {
+            let line = line.trim();
+            if let Some(doc) = line.strip_prefix("///") {
+                lines.push(doc.strip_prefix(' ').unwrap_or(doc).trim_end().to_owned());
+            } else if !line.starts_with('@') {
+                break;
+            }
+        }
+        if lines.is_empty() {
+            return None;
+        }
+        lines.reverse();
+        Some(lines.join("\n"))
+    })
+}
+
+/// Wrap rendered Leo source in a fenced block and append optional markdown docs.
+pub fn markdown(code: &str, docs: Option<&str>) -> String {
+    match docs {
+        Some(docs) => format!("```leo\n{code}\n```\n\n---\n\n{docs}"),
+        None => format!("```leo\n{code}\n```"),
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Quick fixes for compiler diagnostics.
+//!
+//! Every fix comes from a structured suggestion the compiler pass attached to
+//! its diagnostic, lowered to source ranges while the session source map was
+//! still alive; nothing is recovered from message text. The routing thread
+//! only answers from a package analysis that is fresh for the open buffers,
+//! because a stale range would apply the edit to the wrong text.
+
+use crate::{
+    features::diagnostics::{DiagnosticClientCapabilitySnapshot, DiagnosticEntry, entry_to_lsp_diagnostic},
+    project_model::path_to_file_uri,
+};
+use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse, Range, TextEdit, WorkspaceEdit};
+use serde_json::Value;
+use std::{collections::HashMap, path::Path};
+
+/// Build the quick fixes for diagnostics in `path` that overlap `range`.
+///
+/// `only` is the client's kind filter; an empty result is returned when it
+/// excludes quick fixes. A diagnostic with a single fix marks it preferred so
+/// editors can apply it with one keystroke.
+pub(crate) fn quick_fixes(
+    entries: &[DiagnosticEntry],
+    path: &Path,
+    range: Range,
+    only: Option<&[CodeActionKind]>,
+    capabilities: &DiagnosticClientCapabilitySnapshot,
+) -> CodeActionResponse {
+    if only.is_some_and(|kinds| !kinds.iter().any(includes_quick_fix)) {
+        return Vec::new();
+    }
+
+    let mut actions = Vec::new();
+    for entry in entries {
+        if entry.synthetic || entry.fixes.is_empty() || entry.path.as_path() != path || !overlaps(entry, range) {
+            continue;
+        }
+        let diagnostic = entry_to_lsp_diagnostic(entry, capabilities);
+        'fixes: for fix in entry.fixes.iter() {
+            let mut changes = HashMap::<_, Vec<TextEdit>>::new();
+            for edit in fix.edits.iter() {
+                let Some(uri) = path_to_file_uri(edit.path.as_path()) else {
+                    continue 'fixes;
+                };
+                changes
+                    .entry(uri)
+                    .or_default()
+                    .push(TextEdit { range: edit.range.to_lsp_range(), new_text: edit.new_text.as_ref().to_owned() });
+            }
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title.as_ref().to_owned(),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }),
+                is_preferred: Some(entry.fixes.len() == 1),
+                ..CodeAction::default()
+            }));
+        }
+    }
+    actions
+}
+
+/// Serialize code actions into the standard LSP response payload.
+pub fn response_value(actions: CodeActionResponse) -> Value {
+    serde_json::to_value(actions).expect("CodeActionResponse should serialize")
+}
+
+/// Whether the requested kind `kind` is `quickfix` or one of its parents.
+fn includes_quick_fix(kind: &CodeActionKind) -> bool {
+    let quick_fix = CodeActionKind::QUICKFIX;
+    let quick_fix = quick_fix.as_str();
+    kind.as_str().is_empty()
+        || quick_fix == kind.as_str()
+        || quick_fix.strip_prefix(kind.as_str()).is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// Whether `entry` touches `range`; touching endpoints count, so a cursor at
+/// either edge of a diagnostic still offers its fixes.
+fn overlaps(entry: &DiagnosticEntry, range: Range) -> bool {
+    let start = (entry.range.start_line, entry.range.start_character);
+    let end = (entry.range.end_line, entry.range.end_character);
+    start <= (range.end.line, range.end.character) && (range.start.line, range.start.character) <= end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quick_fixes;
+    use crate::features::diagnostics::{
+        DiagnosticClientCapabilitySnapshot,
+        DiagnosticEntry,
+        DiagnosticFix,
+        DiagnosticFixEdit,
+        DiagnosticRange,
+        DiagnosticRelatedEntry,
+        DiagnosticSeverityInternal,
+    };
+    use lsp_types::{CodeActionKind, CodeActionOrCommand, Position, Range};
+    use std::{path::PathBuf, sync::Arc};
+
+    /// Build an error entry on line `line` carrying one fix per title.
+    fn entry(path: &Arc<PathBuf>, line: u32, titles: &[&str]) -> DiagnosticEntry {
+        let range = DiagnosticRange { start_line: line, start_character: 4, end_line: line, end_character: 8 };
+        let fixes = titles
+            .iter()
+            .map(|title| DiagnosticFix {
+                title: Arc::from(*title),
+                edits: Arc::from(vec![DiagnosticFixEdit {
+                    path: Arc::clone(path),
+                    range,
+                    new_text: Arc::from("fixed"),
+                }]),
+            })
+            .collect::<Vec<_>>();
+        DiagnosticEntry {
+            path: Arc::clone(path),
+            range,
+            severity: DiagnosticSeverityInternal::Error,
+            message: Arc::from("boom"),
+            related: Arc::from(Vec::<DiagnosticRelatedEntry>::new()),
+            synthetic: false,
+            fixes: Arc::from(fixes),
+        }
+    }
+
+    /// Titles and preference flags of `actions`, in order.
+    fn titles(actions: &[CodeActionOrCommand]) -> Vec<(String, Option<bool>)> {
+        actions
+            .iter()
+            .map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => (action.title.clone(), action.is_preferred),
+                CodeActionOrCommand::Command(command) => (command.title.clone(), None),
+            })
+            .collect()
+    }
+
+    /// Verifies only fixes of diagnostics overlapping the request range are offered.
+    #[test]
+    fn offers_fixes_overlapping_range() {
+        let path = Arc::new(PathBuf::from("/tmp/main.leo"));
+        let entries = [entry(&path, 1, &["Cast to `u32`"]), entry(&path, 3, &["First", "Second"])];
+        let capabilities = DiagnosticClientCapabilitySnapshot::default();
+
+        let line = |line| Range::new(Position::new(line, 0), Position::new(line, 6));
+        let actions = quick_fixes(&entries, &path, line(1), None, &capabilities);
+        assert_eq!(titles(&actions), [("Cast to `u32`".to_owned(), Some(true))]);
+
+        let actions = quick_fixes(&entries, &path, line(3), None, &capabilities);
+        assert_eq!(titles(&actions), [("First".to_owned(), Some(false)), ("Second".to_owned(), Some(false))]);
+
+        assert!(quick_fixes(&entries, &path, line(2), None, &capabilities).is_empty());
+        let other = PathBuf::from("/tmp/other.leo");
+        assert!(quick_fixes(&entries, &other, line(1), None, &capabilities).is_empty());
+    }
+
+    /// Verifies the client's `only` filter includes quick fixes by kind prefix.
+    #[test]
+    fn honors_requested_kinds() {
+        let path = Arc::new(PathBuf::from("/tmp/main.leo"));
+        let entries = [entry(&path, 0, &["Fix"])];
+        let capabilities = DiagnosticClientCapabilitySnapshot::default();
+        let range = Range::new(Position::new(0, 5), Position::new(0, 5));
+        let with_only =
+            |kinds: &[CodeActionKind]| quick_fixes(&entries, &path, range, Some(kinds), &capabilities).len();
+
+        assert_eq!(with_only(&[CodeActionKind::QUICKFIX]), 1);
+        assert_eq!(with_only(&[CodeActionKind::EMPTY]), 1);
+        assert_eq!(with_only(&[CodeActionKind::REFACTOR]), 0);
+        assert_eq!(with_only(&[CodeActionKind::new("quick")]), 0);
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Completion resolution for Leo LSP.
+//!
+//! The buffer being completed is usually mid-edit and rarely parses, so the
+//! cursor context is recovered by scanning the raw text backwards from the
+//! cursor rather than from an AST. The context then selects candidates from a
+//! compiler-lowered [`CompletionIndex`], which may come from an analysis a few
+//! keystrokes older than the buffer.
+
+use crate::{
+    completion_index::{CompletionEntry, CompletionIndex, CompletionKind, ValueShape},
+    document_store::DocumentViewKey,
+};
+use lsp_types::{
+    CompletionItem,
+    CompletionItemKind,
+    CompletionResponse,
+    Documentation,
+    MarkupContent,
+    MarkupKind,
+    Position,
+    Uri,
+};
+use serde_json::Value;
+use std::{collections::HashSet, path::PathBuf, sync::Arc};
+
+/// Cursor query captured before any async package-analysis wait.
+#[derive(Debug, Clone)]
+pub struct CompletionQuery {
+    /// Requesting document URI, retained so pending requests can be cleared on close.
+    pub uri: Uri,
+    /// Native path for the document where the cursor started.
+    pub file_path: Arc<PathBuf>,
+    /// Original LSP position, kept for parity with the other cursor queries.
+    #[allow(dead_code)]
+    pub position: Position,
+    /// UTF-8 byte offset resolved from `position` before any async wait.
+    pub offset: u32,
+    /// Exact open-buffer text that produced `offset`.
+    pub text: Arc<str>,
+    /// Freshness key for the document view active when the request arrived.
+    pub view_key: DocumentViewKey,
+}
+
+/// Syntactic position of the cursor, recovered from the text before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CompletionContext {
+    /// A bare identifier position: locals, top-level items, and namespaces.
+    Scope,
+    /// After `a.b.`; holds the receiver chain.
+    Member(Vec<String>),
+    /// After `a::b::`; holds the qualifier segments.
+    Path(Vec<String>),
+    /// After `Interface@(target)::`; holds the interface name.
+    Interface(String),
+}
+
+/// Mapping operations as `(name, parameters, return type, documentation)`.
+const MAPPING_OPERATIONS: &[(&str, &str, Option<&str>, &str)] = &[
+    ("get", "key: K", Some("V"), "Returns the value stored under `key`, halting if it is absent."),
+    ("get_or_use", "key: K, default: V", Some("V"), "Returns the value stored under `key`, or `default` if absent."),
+    ("set", "key: K, value: V", None, "Stores `value` under `key`."),
+    ("contains", "key: K", Some("bool"), "Returns whether a value is stored under `key`."),
+    ("remove", "key: K", None, "Removes the value stored under `key`."),
+];
+
+/// Storage vector operations as `(name, parameters, return type, documentation)`.
+const VECTOR_OPERATIONS: &[(&str, &str, Option<&str>, &str)] = &[
+    ("push", "value: T", None, "Appends `value` to the end of the vector."),
+    ("pop", "", Some("T?"), "Removes and returns the last element, or `none` if the vector is empty."),
+    ("len", "", Some("u32"), "Returns the number of elements."),
+    ("get", "index: u32", Some("T?"), "Returns the element at `index`, or `none` if out of bounds."),
+    ("set", "index: u32, value: T", None, "Overwrites the element at `index`, halting if out of bounds."),
+    ("swap_remove", "index: u32", Some("T"), "Removes the element at `index`, replacing it with the last element."),
+    ("clear", "", None, "Removes every element."),
+];
+
+/// Optional value operations as `(name, parameters, return type, documentation)`.
+const OPTIONAL_OPERATIONS: &[(&str, &str, Option<&str>, &str)] = &[
+    ("unwrap", "", Some("T"), "Returns the contained value, halting if it is `none`."),
+    ("unwrap_or", "default: T", Some("T"), "Returns the contained value, or `default` if it is `none`."),
+];
+
+/// Resolve completion candidates for a query against a compiler-lowered index.
+pub fn resolve(query: &CompletionQuery, index: &CompletionIndex) -> Option<CompletionResponse> {
+    let entries = match context(&query.text, query.offset as usize)? {
+        CompletionContext::Scope => {
+            let locals = index.locals_at(query.file_path.as_ref(), query.offset, query.text.len() as u32);
+            let mut entries = locals.into_iter().cloned().collect::<Vec<_>>();
+            entries.extend(index.path_items(&[]).iter().cloned());
+            entries.extend(
+                ["Mapping", "Vector"].into_iter().map(|name| CompletionEntry::new(name, CompletionKind::Module, None)),
+            );
+            entries
+        }
+        CompletionContext::Member(chain) => {
+            match index.receiver_shape(query.file_path.as_ref(), query.offset, query.text.len() as u32, &chain)? {
+                ValueShape::Composite(key) => index.composite_members(&key).to_vec(),
+                ValueShape::Mapping => operations("", MAPPING_OPERATIONS),
+                ValueShape::Vector => operations("", VECTOR_OPERATIONS),
+                ValueShape::Optional => operations("", OPTIONAL_OPERATIONS),
+            }
+        }
+        CompletionContext::Path(qualifier) => match qualifier.as_slice() {
+            [namespace] if namespace == "Mapping" => operations("mapping: mapping K => V", MAPPING_OPERATIONS),
+            [namespace] if namespace == "Vector" => operations("vector: [T]", VECTOR_OPERATIONS),
+            _ => index.path_items(&qualifier.iter().map(String::as_str).collect::<Vec<_>>()).to_vec(),
+        },
+        CompletionContext::Interface(name) => index.interface_items(&name).to_vec(),
+    };
+
+    // Locals come first, so they shadow same-named items from outer scopes.
+    let mut seen = HashSet::new();
+    let items = entries
+        .into_iter()
+        .filter(|entry| seen.insert(Arc::clone(&entry.label)))
+        .map(completion_item)
+        .collect::<Vec<_>>();
+    (!items.is_empty()).then_some(CompletionResponse::Array(items))
+}
+
+/// Serialize a feature result into the standard LSP response payload.
+pub fn response_value(result: Option<CompletionResponse>) -> Value {
+    match result {
+        Some(response) => serde_json::to_value(response).expect("CompletionResponse should serialize"),
+        None => Value::Null,
+    }
+}
+
+/// Render an operation table. A non-empty `receiver` selects the static
+/// `Mapping::get(m, key)` spelling over the method spelling `m.get(key)`.
+fn operations(receiver: &str, table: &[(&str, &str, Option<&str>, &str)]) -> Vec<CompletionEntry> {
+    table
+        .iter()
+        .map(|(name, parameters, output, docs)| {
+            let parameters = match (receiver, *parameters) {
+                ("", parameters) => parameters.to_owned(),
+                (receiver, "") => receiver.to_owned(),
+                (receiver, parameters) => format!("{receiver}, {parameters}"),
+            };
+            let detail = match output {
+                Some(output) => format!("fn {name}({parameters}) -> {output}"),
+                None => format!("fn {name}({parameters})"),
+            };
+            let kind = if receiver.is_empty() { CompletionKind::Method } else { CompletionKind::Function };
+            let mut entry = CompletionEntry::new(*name, kind, Some(detail));
+            entry.documentation = Some(Arc::from(*docs));
+            entry
+        })
+        .collect()
+}
+
+/// Lower a completion entry into the LSP item shape.
+fn completion_item(entry: CompletionEntry) -> CompletionItem {
+    let kind = match entry.kind {
+        CompletionKind::Module => CompletionItemKind::MODULE,
+        CompletionKind::Function => CompletionItemKind::FUNCTION,
+        CompletionKind::Struct | CompletionKind::Record => CompletionItemKind::STRUCT,
+        CompletionKind::Interface => CompletionItemKind::INTERFACE,
+        CompletionKind::Constant => CompletionItemKind::CONSTANT,
+        CompletionKind::Variable | CompletionKind::Parameter => CompletionItemKind::VARIABLE,
+        CompletionKind::Field => CompletionItemKind::FIELD,
+        CompletionKind::Mapping | CompletionKind::Storage => CompletionItemKind::PROPERTY,
+        CompletionKind::Method => CompletionItemKind::METHOD,
+    };
+    CompletionItem {
+        label: entry.label.to_string(),
+        kind: Some(kind),
+        detail: entry.detail.map(|detail| detail.to_string()),
+        documentation: entry.documentation.map(|docs| {
+            Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value: docs.to_string() })
+        }),
+        ..CompletionItem::default()
+    }
+}
+
+/// Return whether `byte` may appear in a Leo identifier.
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// Classify the cursor position from the text before `offset`.
+///
+/// Returns `None` inside line comments and after receivers the scanner cannot
+/// name, such as call results or numeric literals.
+pub(crate) fn context(text: &str, offset: usize) -> Option<CompletionContext> {
+    let before = text.as_bytes().get(..offset.min(text.len()))?;
+    let line_start = before.iter().rposition(|byte| *byte == b'\n').map_or(0, |newline| newline + 1);
+    if before[line_start..].windows(2).any(|pair| pair == b"//") {
+        return None;
+    }
+
+    let mut end = before.len();
+    while end > 0 && is_ident_byte(before[end - 1]) {
+        end -= 1;
+    }
+    let rest = &before[..end];
+
+    if rest.ends_with(b"::") {
+        let rest = &rest[..rest.len() - 2];
+        if rest.ends_with(b")") {
+            return interface_context(rest);
+        }
+        let qualifier = trailing_run(rest, |byte| is_ident_byte(byte) || byte == b':' || byte == b'.');
+        let segments = qualifier.split("::").map(str::to_owned).collect::<Vec<_>>();
+        if segments.iter().any(|segment| segment.is_empty() || segment.starts_with(|c: char| c.is_ascii_digit())) {
+            return None;
+        }
+        return Some(CompletionContext::Path(segments));
+    }
+
+    if let Some(rest) = rest.strip_suffix(b".") {
+        let chain = trailing_run(rest, |byte| is_ident_byte(byte) || byte == b'.');
+        let segments = chain.split('.').map(str::to_owned).collect::<Vec<_>>();
+        if segments.iter().any(|segment| segment.is_empty() || segment.starts_with(|c: char| c.is_ascii_digit())) {
+            return None;
+        }
+        return Some(CompletionContext::Member(segments));
+    }
+
+    Some(CompletionContext::Scope)
+}
+
+/// Recover `Name` from text ending in `Name@(target)`.
+fn interface_context(rest: &[u8]) -> Option<CompletionContext> {
+    let mut depth = 0usize;
+    let mut open = None;
+    for (position, byte) in rest.iter().enumerate().rev() {
+        match byte {
+            b')' => depth += 1,
+            b'(' => {
+                depth -= 1;
+                if depth == 0 {
+                    open = Some(position);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let before_call = rest[..open?].strip_suffix(b"@")?;
+    let name = trailing_run(before_call, is_ident_byte);
+    (!name.is_empty()).then(|| CompletionContext::Interface(name.to_owned()))
+}
+
+/// Return the longest suffix of `bytes` whose bytes all satisfy `accept`.
+fn trailing_run(bytes: &[u8], accept: impl Fn(u8) -> bool) -> &str {
+    let start = bytes.iter().rposition(|byte| !accept(*byte)).map_or(0, |position| position + 1);
+    // Every accepted byte is ASCII, so the suffix is valid UTF-8.
+    std::str::from_utf8(&bytes[start..]).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompletionContext, context};
+
+    /// Return the context at the `$` marker in `source`.
+    fn context_at(source: &str) -> Option<CompletionContext> {
+        let offset = source.find('$').expect("cursor marker");
+        context(&source.replacen('$', "", 1), offset)
+    }
+
+    /// Verifies bare identifier positions complete from scope.
+    #[test]
+    fn bare_identifiers_complete_from_scope() {
+        assert_eq!(context_at("fn main() { let x = amo$ }"), Some(CompletionContext::Scope));
+        assert_eq!(context_at("fn main() { $"), Some(CompletionContext::Scope));
+    }
+
+    /// Verifies `.` chains keep every receiver segment and the partial member is ignored.
+    #[test]
+    fn member_access_keeps_receiver_chain() {
+        assert_eq!(
+            context_at("let x = token.owner.inner.am$"),
+            Some(CompletionContext::Member(vec!["token".into(), "owner".into(), "inner".into()]))
+        );
+        assert_eq!(context_at("balances.$"), Some(CompletionContext::Member(vec!["balances".into()])));
+        assert_eq!(context_at("let x = foo().$"), None);
+        assert_eq!(context_at("let x = 1.$"), None);
+    }
+
+    /// Verifies `::` qualifiers split into segments, including program ids.
+    #[test]
+    fn path_qualifiers_split_on_double_colon() {
+        assert_eq!(
+            context_at("let h = std::hash::bh$"),
+            Some(CompletionContext::Path(vec!["std".into(), "hash".into()]))
+        );
+        assert_eq!(context_at("token.aleo::$"), Some(CompletionContext::Path(vec!["token.aleo".into()])));
+        assert_eq!(context_at("Mapping::$"), Some(CompletionContext::Path(vec!["Mapping".into()])));
+    }
+
+    /// Verifies dynamic interface calls resolve to the interface name across nested parens.
+    #[test]
+    fn dynamic_calls_complete_interface_items() {
+        assert_eq!(context_at("Bank@(target)::$"), Some(CompletionContext::Interface("Bank".into())));
+        assert_eq!(
+            context_at("oracle.aleo::Oracle@(pick(a, b))::get$"),
+            Some(CompletionContext::Interface("Oracle".into()))
+        );
+        assert_eq!(context_at("(target)::$"), None);
+    }
+
+    /// Verifies line comments suppress completion.
+    #[test]
+    fn comments_suppress_completion() {
+        assert_eq!(context_at("let x = 1; // see balances.$"), None);
+    }
+}
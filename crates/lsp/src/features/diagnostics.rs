@@ -41,7 +41,7 @@ pub(crate) struct DiagnosticRange {
 }
 
 impl DiagnosticRange {
-    fn to_lsp_range(self) -> Range {
+    pub(crate) fn to_lsp_range(self) -> Range {
         Range::new(
             Position::new(self.start_line, self.start_character),
             Position::new(self.end_line, self.end_character),
@@ -74,6 +74,8 @@ pub(crate) struct DiagnosticEntry {
     /// Synthetic entries lack a usable source span and are pinned to the
     /// saved trigger document by the publish path.
     pub(crate) synthetic: bool,
+    /// Machine-applicable fixes lowered from the compiler's suggestions.
+    pub(crate) fixes: Arc<[DiagnosticFix]>,
 }
 
 /// One secondary label keyed by file path and UTF-16 range.
@@ -84,6 +86,21 @@ pub(crate) struct DiagnosticRelatedEntry {
     pub(crate) message: Arc<str>,
 }
 
+/// One compiler suggestion whose edits all resolved to real source ranges.
+#[derive(Debug, Clone)]
+pub(crate) struct DiagnosticFix {
+    pub(crate) title: Arc<str>,
+    pub(crate) edits: Arc<[DiagnosticFixEdit]>,
+}
+
+/// One text replacement keyed by file path and UTF-16 range.
+#[derive(Debug, Clone)]
+pub(crate) struct DiagnosticFixEdit {
+    pub(crate) path: Arc<PathBuf>,
+    pub(crate) range: DiagnosticRange,
+    pub(crate) new_text: Arc<str>,
+}
+
 /// Immutable package-keyed diagnostic set shared with the routing thread.
 #[derive(Debug, Clone)]
 pub(crate) struct DiagnosticSet {
@@ -233,6 +250,27 @@ fn lower_view(
             })
         })
         .collect::<Vec<_>>();
+    // A suggestion is dropped whole when any of its edits fails to resolve;
+    // applying only part of a fix could leave the source worse off.
+    let fixes = view
+        .suggestions
+        .iter()
+        .filter_map(|suggestion| {
+            let edits = suggestion
+                .edits()
+                .iter()
+                .map(|(span, replacement)| {
+                    let span = resolve_range(*span)?;
+                    Some(DiagnosticFixEdit {
+                        path: span.path,
+                        range: span.range,
+                        new_text: Arc::from(replacement.as_str()),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(DiagnosticFix { title: Arc::from(suggestion.message()), edits: Arc::from(edits) })
+        })
+        .collect::<Vec<_>>();
     let message = Arc::from(build_message(&view).as_str());
 
     match view.span.and_then(resolve_span) {
@@ -243,6 +281,7 @@ fn lower_view(
             message,
             related: Arc::from(related),
             synthetic: false,
+            fixes: Arc::from(fixes),
         }),
         None => trigger_path.map(|trigger| DiagnosticEntry {
             path: Arc::clone(trigger),
@@ -251,6 +290,7 @@ fn lower_view(
             message,
             related: Arc::from(related),
             synthetic: true,
+            fixes: Arc::from(Vec::<DiagnosticFix>::new()),
         }),
     }
 }
@@ -267,6 +307,7 @@ fn make_synthetic_entry(path: Arc<PathBuf>, message: String, severity: Diagnosti
         message: Arc::from(message.as_str()),
         related: Arc::from(Vec::<DiagnosticRelatedEntry>::new()),
         synthetic: true,
+        fixes: Arc::from(Vec::<DiagnosticFix>::new()),
     }
 }
 
@@ -290,6 +331,14 @@ fn resolve_span(span: Span) -> Option<ResolvedSpan> {
     if span.is_dummy() {
         return None;
     }
+    resolve_range(span)
+}
+
+/// Resolve a span without rejecting the dummy span.
+///
+/// Suggestion edits may legitimately insert at absolute offset zero, the
+/// start of the first file in the source map.
+fn resolve_range(span: Span) -> Option<ResolvedSpan> {
     with_session_globals(|session| {
         let start_file = session.source_map.find_source_file(span.lo)?;
         if span.hi > start_file.absolute_end {
@@ -463,6 +512,7 @@ mod tests {
             message: Arc::from(message),
             related: Arc::from(Vec::<DiagnosticRelatedEntry>::new()),
             synthetic: false,
+            fixes: Arc::from(Vec::<DiagnosticFix>::new()),
         }
     }
 }
@@ -0,0 +1,352 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Document outline for Leo LSP.
+//!
+//! The outline is read straight from the Rowan syntax tree, so it never waits
+//! on package analysis and stays available while a buffer does not
+//! type-check. Workspace symbol search flattens the same outline for every
+//! file it visits.
+
+use crate::{
+    features::lsp_range::byte_range_to_lsp_range,
+    project_model::ProjectContext,
+    syntax_semantics::parse_document,
+};
+use leo_parser_rowan::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken};
+use line_index::LineIndex;
+use lsp_types::{DocumentSymbol, DocumentSymbolResponse, SymbolKind};
+use serde_json::Value;
+use std::{path::PathBuf, sync::Arc};
+
+/// One outline entry with byte ranges, before conversion to LSP positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineSymbol {
+    /// Display name, e.g. `token.aleo`, `transfer`, or `utils::math`.
+    pub name: String,
+    /// Editor-facing symbol category.
+    pub kind: SymbolKind,
+    /// One-line header such as `fn transfer(to: address) -> Token`.
+    pub detail: Option<String>,
+    /// Byte range of the whole declaration, without leading trivia.
+    pub range: (u32, u32),
+    /// Byte range of the declared name.
+    pub selection: (u32, u32),
+    /// Nested declarations such as program items or struct members.
+    pub children: Vec<OutlineSymbol>,
+}
+
+/// Build the outline for one Leo source file.
+///
+/// Files below the package source directory other than the entry file are
+/// submodules; their items are nested under a module symbol named after the
+/// module path, e.g. `utils::math`.
+pub fn outline(
+    text: &str,
+    file_path: Option<&Arc<PathBuf>>,
+    project: Option<&Arc<ProjectContext>>,
+) -> Vec<OutlineSymbol> {
+    let Some(tree) = parse_document(text, file_path, project) else {
+        return Vec::new();
+    };
+    let symbols = collect_items(&tree);
+
+    let module = file_path.zip(project).and_then(|(path, project)| module_path(path, project));
+    match module {
+        Some(name) => {
+            let len = text.len() as u32;
+            vec![OutlineSymbol {
+                name,
+                kind: SymbolKind::MODULE,
+                detail: None,
+                range: (0, len),
+                selection: (0, 0),
+                children: symbols,
+            }]
+        }
+        None => symbols,
+    }
+}
+
+/// Convert an outline to nested LSP document symbols.
+pub fn document_symbols(symbols: &[OutlineSymbol], line_index: &LineIndex) -> Vec<DocumentSymbol> {
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let range = byte_range_to_lsp_range(line_index, symbol.range.0, symbol.range.1)?;
+            let selection_range = byte_range_to_lsp_range(line_index, symbol.selection.0, symbol.selection.1)?;
+            #[allow(deprecated)]
+            Some(DocumentSymbol {
+                name: symbol.name.clone(),
+                detail: symbol.detail.clone(),
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                range,
+                selection_range,
+                children: Some(document_symbols(&symbol.children, line_index)),
+            })
+        })
+        .collect()
+}
+
+/// Serialize a document-symbol result into the standard LSP response payload.
+pub fn response_value(symbols: Vec<DocumentSymbol>) -> Value {
+    serde_json::to_value(DocumentSymbolResponse::Nested(symbols)).expect("DocumentSymbolResponse should serialize")
+}
+
+/// Return the `::`-joined module path for a non-entry package file.
+fn module_path(path: &Arc<PathBuf>, project: &Arc<ProjectContext>) -> Option<String> {
+    if path == &project.entry_file {
+        return None;
+    }
+    let relative = path.strip_prefix(project.source_directory.as_path()).ok()?.with_extension("");
+    let segments = relative.iter().map(|segment| segment.to_string_lossy().into_owned()).collect::<Vec<_>>();
+    (!segments.is_empty()).then(|| segments.join("::"))
+}
+
+/// Collect the declarations below `node`, looking through wrapper nodes.
+fn collect_items(node: &SyntaxNode) -> Vec<OutlineSymbol> {
+    let mut symbols = Vec::new();
+    for child in node.children() {
+        match child.kind() {
+            SyntaxKind::PROGRAM_DECL => symbols.extend(program_symbol(&child)),
+            kind if item_kind(kind).is_some() => symbols.extend(item_symbol(&child)),
+            // Function bodies and parameter lists never hold outline items.
+            SyntaxKind::BLOCK | SyntaxKind::PARAM_LIST => {}
+            _ => symbols.extend(collect_items(&child)),
+        }
+    }
+    symbols
+}
+
+/// Map an item node kind to its outline category.
+fn item_kind(kind: SyntaxKind) -> Option<SymbolKind> {
+    Some(match kind {
+        SyntaxKind::FUNCTION_DEF
+        | SyntaxKind::FINAL_FN_DEF
+        | SyntaxKind::VIEW_FN_DEF
+        | SyntaxKind::FN_PROTOTYPE_DEF => SymbolKind::FUNCTION,
+        SyntaxKind::CONSTRUCTOR_DEF => SymbolKind::CONSTRUCTOR,
+        SyntaxKind::STRUCT_DEF | SyntaxKind::RECORD_DEF | SyntaxKind::RECORD_PROTOTYPE_DEF => SymbolKind::STRUCT,
+        SyntaxKind::ENUM_DEF => SymbolKind::ENUM,
+        SyntaxKind::ENUM_VARIANT => SymbolKind::ENUM_MEMBER,
+        SyntaxKind::STRUCT_MEMBER
+        | SyntaxKind::STRUCT_MEMBER_PUBLIC
+        | SyntaxKind::STRUCT_MEMBER_PRIVATE
+        | SyntaxKind::STRUCT_MEMBER_CONSTANT => SymbolKind::FIELD,
+        SyntaxKind::MAPPING_DEF | SyntaxKind::STORAGE_DEF => SymbolKind::PROPERTY,
+        SyntaxKind::GLOBAL_CONST => SymbolKind::CONSTANT,
+        SyntaxKind::INTERFACE_DEF => SymbolKind::INTERFACE,
+        SyntaxKind::IMPL_DEF => SymbolKind::OBJECT,
+        _ => return None,
+    })
+}
+
+/// Build the `name.aleo` namespace symbol for a program block.
+fn program_symbol(node: &SyntaxNode) -> Option<OutlineSymbol> {
+    let name = name_token(node)?;
+    let network = direct_tokens(node).find(|token| token.kind() == SyntaxKind::KW_ALEO);
+    let selection_end = network.as_ref().unwrap_or(&name).text_range().end().into();
+    let display = match &network {
+        Some(network) => format!("{}.{}", name.text(), network.text()),
+        None => name.text().to_owned(),
+    };
+    Some(OutlineSymbol {
+        name: display,
+        kind: SymbolKind::NAMESPACE,
+        detail: Some("program".to_owned()),
+        range: trimmed_range(node)?,
+        selection: (name.text_range().start().into(), selection_end),
+        children: collect_items(node),
+    })
+}
+
+/// Build the symbol for one item node recognized by [`item_kind`].
+fn item_symbol(node: &SyntaxNode) -> Option<OutlineSymbol> {
+    let kind = item_kind(node.kind())?;
+    let name = match node.kind() {
+        SyntaxKind::CONSTRUCTOR_DEF => direct_tokens(node).find(|token| token.kind() == SyntaxKind::KW_CONSTRUCTOR)?,
+        _ => name_token(node)?,
+    };
+    let detail = match node.kind() {
+        SyntaxKind::STRUCT_DEF => Some("struct".to_owned()),
+        SyntaxKind::ENUM_DEF => Some("enum".to_owned()),
+        SyntaxKind::RECORD_DEF | SyntaxKind::RECORD_PROTOTYPE_DEF => Some("record".to_owned()),
+        SyntaxKind::INTERFACE_DEF => Some("interface".to_owned()),
+        SyntaxKind::IMPL_DEF => Some("impl".to_owned()),
+        _ => Some(header_text(node)).filter(|header| !header.is_empty()),
+    };
+    let children = match node.kind() {
+        SyntaxKind::STRUCT_DEF
+        | SyntaxKind::RECORD_DEF
+        | SyntaxKind::ENUM_DEF
+        | SyntaxKind::IMPL_DEF
+        | SyntaxKind::INTERFACE_DEF => collect_items(node),
+        _ => Vec::new(),
+    };
+    Some(OutlineSymbol {
+        name: name.text().to_owned(),
+        kind,
+        detail,
+        range: trimmed_range(node)?,
+        selection: (name.text_range().start().into(), name.text_range().end().into()),
+        children,
+    })
+}
+
+/// Iterate the tokens that are direct children of `node`.
+fn direct_tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+    node.children_with_tokens().filter_map(SyntaxElement::into_token)
+}
+
+/// Return the declared name, the first identifier directly under the item.
+fn name_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    direct_tokens(node).find(|token| token.kind() == SyntaxKind::IDENT)
+}
+
+/// Return the byte range of `node` without its leading and trailing trivia.
+fn trimmed_range(node: &SyntaxNode) -> Option<(u32, u32)> {
+    let mut tokens =
+        node.descendants_with_tokens().filter_map(SyntaxElement::into_token).filter(|token| !token.kind().is_trivia());
+    let first = tokens.next()?;
+    let last = tokens.last().unwrap_or_else(|| first.clone());
+    Some((first.text_range().start().into(), last.text_range().end().into()))
+}
+
+/// Render the declaration header up to its body, initializer, or terminator.
+///
+/// Annotations are skipped and whitespace is collapsed, so a multi-line
+/// parameter list still renders as one line.
+fn header_text(node: &SyntaxNode) -> String {
+    let mut text = String::new();
+    for element in node.children_with_tokens() {
+        match &element {
+            SyntaxElement::Node(child) if child.kind() == SyntaxKind::ANNOTATION => continue,
+            SyntaxElement::Node(child) if child.kind() == SyntaxKind::BLOCK => break,
+            SyntaxElement::Node(child) => text.push_str(&child.to_string()),
+            SyntaxElement::Token(token) => match token.kind() {
+                SyntaxKind::SEMICOLON | SyntaxKind::COMMA | SyntaxKind::EQ | SyntaxKind::L_BRACE => break,
+                kind if kind.is_trivia() => text.push(' '),
+                _ => text.push_str(token.text()),
+            },
+        }
+    }
+    // Undo the spacing left behind by multi-line parameter lists.
+    text.split_whitespace().collect::<Vec<_>>().join(" ").replace("( ", "(").replace(" )", ")").replace(",)", ")")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OutlineSymbol, outline};
+    use lsp_types::SymbolKind;
+
+    /// Flatten an outline into `(depth, name, kind, detail)` rows.
+    fn rows(symbols: &[OutlineSymbol], depth: usize, out: &mut Vec<(usize, String, SymbolKind, Option<String>)>) {
+        for symbol in symbols {
+            out.push((depth, symbol.name.clone(), symbol.kind, symbol.detail.clone()));
+            rows(&symbol.children, depth + 1, out);
+        }
+    }
+
+    /// Verifies program items nest under the program and carry one-line headers.
+    #[test]
+    fn program_items_nest_under_program() {
+        let source = "\
+program token.aleo {
+    record Token {
+        owner: address,
+        public amount: u64,
+    }
+    mapping balances: address => u64;
+    storage counter: u64;
+
+    @noupgrade
+    constructor() {}
+
+    fn mint(
+        receiver: address,
+        amount: u64,
+    ) -> Token {
+        return Token { owner: receiver, amount };
+    }
+
+    final fn settle(owner: address) {}
+}
+
+const LIMIT: u64 = 10u64;
+
+interface Bank {
+    fn deposit(amount: u64);
+}
+";
+        let symbols = outline(source, None, None);
+        let mut out = Vec::new();
+        rows(&symbols, 0, &mut out);
+        let expected: Vec<(usize, &str, SymbolKind, Option<&str>)> = vec![
+            (0, "token.aleo", SymbolKind::NAMESPACE, Some("program")),
+            (1, "Token", SymbolKind::STRUCT, Some("record")),
+            (2, "owner", SymbolKind::FIELD, Some("owner: address")),
+            (2, "amount", SymbolKind::FIELD, Some("public amount: u64")),
+            (1, "balances", SymbolKind::PROPERTY, Some("mapping balances: address => u64")),
+            (1, "counter", SymbolKind::PROPERTY, Some("storage counter: u64")),
+            (1, "constructor", SymbolKind::CONSTRUCTOR, Some("constructor()")),
+            (1, "mint", SymbolKind::FUNCTION, Some("fn mint(receiver: address, amount: u64) -> Token")),
+            (1, "settle", SymbolKind::FUNCTION, Some("final fn settle(owner: address)")),
+            (0, "LIMIT", SymbolKind::CONSTANT, Some("const LIMIT: u64")),
+            (0, "Bank", SymbolKind::INTERFACE, Some("interface")),
+            (1, "deposit", SymbolKind::FUNCTION, Some("fn deposit(amount: u64)")),
+        ];
+        let expected = expected
+            .into_iter()
+            .map(|(depth, name, kind, detail)| (depth, name.to_owned(), kind, detail.map(str::to_owned)))
+            .collect::<Vec<_>>();
+        assert_eq!(out, expected);
+    }
+
+    /// Verifies the functions of an `impl` block nest under it.
+    #[test]
+    fn impl_functions_nest_under_impl() {
+        let source = "struct Point { x: u32 }\n\nimpl Point {\n    fn sum(self) -> u32 { return self.x; }\n}\n";
+        let symbols = outline(source, None, None);
+        let mut out = Vec::new();
+        rows(&symbols, 0, &mut out);
+        let expected: Vec<(usize, &str, SymbolKind, Option<&str>)> = vec![
+            (0, "Point", SymbolKind::STRUCT, Some("struct")),
+            (1, "x", SymbolKind::FIELD, Some("x: u32")),
+            (0, "Point", SymbolKind::OBJECT, Some("impl")),
+            (1, "sum", SymbolKind::FUNCTION, Some("fn sum(self) -> u32")),
+        ];
+        let expected = expected
+            .into_iter()
+            .map(|(depth, name, kind, detail)| (depth, name.to_owned(), kind, detail.map(str::to_owned)))
+            .collect::<Vec<_>>();
+        assert_eq!(out, expected);
+    }
+
+    /// Verifies ranges skip leading trivia and selections cover only the name.
+    #[test]
+    fn ranges_cover_declaration_and_name() {
+        let source = "program demo.aleo {\n    // helper\n    fn add(a: u32) -> u32 { return a; }\n}\n";
+        let symbols = outline(source, None, None);
+        let program = &symbols[0];
+        assert_eq!(&source[program.selection.0 as usize..program.selection.1 as usize], "demo.aleo");
+
+        let add = &program.children[0];
+        assert_eq!(&source[add.selection.0 as usize..add.selection.1 as usize], "add");
+        assert_eq!(&source[add.range.0 as usize..add.range.1 as usize], "fn add(a: u32) -> u32 { return a; }");
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Document, range, and on-type formatting through `leo-fmt`.
+//!
+//! The formatter is zero-configuration and always formats a whole file, so
+//! every request formats the full buffer in-process and diffs the result by
+//! line. Range and on-type requests keep only the hunks that touch the
+//! requested lines. Client formatting options such as tab size are ignored;
+//! Leo code has one canonical layout.
+//!
+//! A buffer that fails to lex or parse is never formatted: the request fails
+//! with the first parse error instead of returning edits that could drop
+//! source text.
+
+use crate::features::lsp_range::{byte_range_to_lsp_range, position_to_offset};
+use leo_parser_rowan::{SyntaxKind, parse_main};
+use line_index::{LineIndex, TextSize};
+use lsp_types::{Position, Range, TextEdit};
+use serde_json::Value;
+use similar::{DiffTag, TextDiff};
+
+/// Character that triggers on-type formatting of the block it closes.
+pub const ON_TYPE_FIRST_TRIGGER: &str = "}";
+/// Additional on-type trigger that formats the statement it ends.
+pub const ON_TYPE_MORE_TRIGGERS: &[&str] = &[";"];
+
+/// Format the whole document.
+pub fn format_document(text: &str, line_index: &LineIndex) -> Result<Vec<TextEdit>, String> {
+    format_lines(text, line_index, None)
+}
+
+/// Format only the lines touched by `range`.
+pub fn format_range(text: &str, line_index: &LineIndex, range: Range) -> Result<Vec<TextEdit>, String> {
+    format_lines(text, line_index, Some((range.start.line, range.end.line)))
+}
+
+/// Format after `ch` was typed just before `position`.
+///
+/// A closing brace formats the block it closes; a semicolon formats its own line.
+pub fn format_on_type(
+    text: &str,
+    line_index: &LineIndex,
+    position: Position,
+    ch: &str,
+) -> Result<Vec<TextEdit>, String> {
+    let Some(offset) = position_to_offset(line_index, position) else {
+        return Ok(Vec::new());
+    };
+    let lines = match ch {
+        ON_TYPE_FIRST_TRIGGER => closed_block_lines(text, line_index, offset),
+        _ => None,
+    };
+    format_lines(text, line_index, Some(lines.unwrap_or((position.line, position.line))))
+}
+
+/// Serialize formatting edits into the standard LSP response payload.
+pub fn response_value(edits: Vec<TextEdit>) -> Value {
+    serde_json::to_value(edits).expect("TextEdit list should serialize")
+}
+
+/// Format `text` and return the line hunks that intersect `lines`, or all hunks.
+fn format_lines(text: &str, line_index: &LineIndex, lines: Option<(u32, u32)>) -> Result<Vec<TextEdit>, String> {
+    let formatted = leo_fmt::try_format_source(text).map_err(|errors| {
+        let Some(error) = errors.first() else {
+            return "document has syntax errors; not formatting".to_owned();
+        };
+        let position = line_index.line_col(TextSize::from(u32::from(error.range.start())));
+        format!(
+            "document has syntax errors; not formatting: {} at {}:{}",
+            error.message,
+            position.line + 1,
+            position.col + 1
+        )
+    })?;
+    if formatted == text {
+        return Ok(Vec::new());
+    }
+
+    let old_starts = line_starts(text);
+    let new_starts = line_starts(&formatted);
+    let diff = TextDiff::from_lines(text, formatted.as_str());
+    let mut edits = Vec::new();
+    for op in diff.ops() {
+        let (tag, old, new) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            continue;
+        }
+        // A pure insertion touches the line it is inserted before.
+        let (first, last) = (old.start as u32, old.end.max(old.start + 1) as u32 - 1);
+        if lines.is_some_and(|(start, end)| last < start || first > end) {
+            continue;
+        }
+        let Some(range) = byte_range_to_lsp_range(line_index, old_starts[old.start], old_starts[old.end]) else {
+            continue;
+        };
+        let new_text = formatted[new_starts[new.start] as usize..new_starts[new.end] as usize].to_owned();
+        edits.push(TextEdit { range, new_text });
+    }
+    Ok(edits)
+}
+
+/// Return the byte offset where each line starts, plus the text length.
+fn line_starts(text: &str) -> Vec<u32> {
+    let mut starts = vec![0];
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        offset += line.len() as u32;
+        starts.push(offset);
+    }
+    starts
+}
+
+/// Return the first and last line of the block closed by the `}` before `offset`.
+fn closed_block_lines(text: &str, line_index: &LineIndex, offset: u32) -> Option<(u32, u32)> {
+    let tree = parse_main(text).ok()?;
+    let brace =
+        tree.token_at_offset(offset.checked_sub(1)?.into()).find(|token| token.kind() == SyntaxKind::R_BRACE)?;
+    let block = brace.parent()?.text_range();
+    let line = |offset: u32| line_index.line_col(TextSize::from(offset)).line;
+    Some((line(block.start().into()), line(block.end().into())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_document, format_on_type, format_range};
+    use line_index::LineIndex;
+    use lsp_types::{Position, Range, TextEdit};
+
+    /// Apply non-overlapping edits to `text`, last edit first.
+    fn apply(text: &str, mut edits: Vec<TextEdit>) -> String {
+        let line_index = LineIndex::new(text);
+        edits.sort_by_key(|edit| std::cmp::Reverse((edit.range.start.line, edit.range.start.character)));
+        let mut text = text.to_owned();
+        for edit in edits {
+            let start = super::position_to_offset(&line_index, edit.range.start).unwrap() as usize;
+            let end = super::position_to_offset(&line_index, edit.range.end).unwrap() as usize;
+            text.replace_range(start..end, &edit.new_text);
+        }
+        text
+    }
+
+    /// Verifies whole-document edits reproduce `leo-fmt` output exactly.
+    #[test]
+    fn document_edits_match_formatter() {
+        let source = "program test.aleo{\nfn main(a:u32)->u32{\nlet b=a+1u32;\nreturn b;}\n}\n";
+        let edits = format_document(source, &LineIndex::new(source)).expect("formats");
+        assert_eq!(apply(source, edits), leo_fmt::format_source(source));
+
+        let formatted = leo_fmt::format_source(source);
+        assert!(format_document(&formatted, &LineIndex::new(&formatted)).expect("formats").is_empty());
+    }
+
+    /// Verifies range and on-type edits leave lines outside the request untouched.
+    #[test]
+    fn range_edits_stay_inside_requested_lines() {
+        let source = "program test.aleo {\n    fn a() -> u32 {\n        return  1u32;\n    }\n\n    fn b() -> u32 {\n        return  2u32;\n    }\n}\n";
+        let line_index = LineIndex::new(source);
+        let range = Range::new(Position::new(6, 0), Position::new(6, 0));
+        let formatted = apply(source, format_range(source, &line_index, range).expect("formats"));
+        assert!(formatted.contains("return  1u32;"));
+        assert!(formatted.contains("return 2u32;"));
+
+        let edits = format_on_type(source, &line_index, Position::new(3, 5), "}").expect("formats");
+        let formatted = apply(source, edits);
+        assert!(formatted.contains("return 1u32;"));
+        assert!(formatted.contains("return  2u32;"));
+    }
+
+    /// Verifies unparseable buffers fail instead of producing edits.
+    #[test]
+    fn syntax_errors_refuse_to_format() {
+        let source = "program test.aleo {\n    fn main() -> u32 {\n        return 1u32\n}\n";
+        let error = format_document(source, &LineIndex::new(source)).expect_err("refuses");
+        assert!(error.starts_with("document has syntax errors"), "{error}");
+    }
+}
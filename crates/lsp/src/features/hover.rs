@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Hover resolution for Leo LSP.
+//!
+//! Hover text is rendered by the worker while the compiler session that owns
+//! the AST and type table is still alive. This module only looks up the
+//! innermost rendered entry under the cursor and converts it to the LSP shape,
+//! so answering a hover never touches compiler state.
+
+use crate::{
+    document_store::DocumentViewKey,
+    features::lsp_range::compact_range_to_origin_lsp_range,
+    semantics::CachedPackageAnalysis,
+};
+use line_index::LineIndex;
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Uri};
+use serde_json::Value;
+use std::{path::PathBuf, sync::Arc};
+
+/// Cursor query captured before any async package-analysis wait.
+#[derive(Debug, Clone)]
+pub struct HoverQuery {
+    /// Requesting document URI, retained so pending requests can be cleared on close.
+    pub uri: Uri,
+    /// Native path for the document where the cursor started.
+    pub file_path: Arc<PathBuf>,
+    /// Original LSP position, kept for parity with the other cursor queries.
+    #[allow(dead_code)]
+    pub position: Position,
+    /// UTF-8 byte offset resolved from `position` before any async wait.
+    pub offset: u32,
+    /// Line index for the exact open-buffer text that produced `offset`.
+    pub line_index: Arc<LineIndex>,
+    /// Freshness key for the document view active when the request arrived.
+    pub view_key: DocumentViewKey,
+}
+
+/// Resolve a hover query against a fresh package analysis.
+pub fn resolve(query: &HoverQuery, package: &CachedPackageAnalysis) -> Option<Hover> {
+    let file = package.index.file_id(query.file_path.as_ref())?;
+    let (range, contents) = package.hovers.hover_at(file, query.offset)?;
+    let range = compact_range_to_origin_lsp_range(range, package.analyzed_files.as_ref(), &query.line_index);
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value: contents.to_owned() }),
+        range,
+    })
+}
+
+/// Serialize a feature result into the standard LSP response payload.
+pub fn response_value(result: Option<Hover>) -> Value {
+    match result {
+        Some(hover) => serde_json::to_value(hover).expect("Hover should serialize"),
+        None => Value::Null,
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Inlay hint resolution for Leo LSP.
+//!
+//! Hints are rendered by the worker while the compiler session is alive:
+//! inferred types of unannotated bindings, parameter names at call sites,
+//! values of const generic arguments, and the iteration count of loops whose
+//! bounds are compile-time constants. This module only selects the hints in
+//! the requested range and converts them to the LSP shape.
+
+use crate::{
+    document_store::DocumentViewKey,
+    features::lsp_range::compact_range_to_origin_lsp_range,
+    semantics::{CachedPackageAnalysis, CompactInlayHint, CompactRange, InlayKind},
+};
+use line_index::LineIndex;
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range, TextEdit, Uri};
+use serde_json::Value;
+use std::{path::PathBuf, sync::Arc};
+
+/// Range query captured before any async package-analysis wait.
+#[derive(Debug, Clone)]
+pub struct InlayHintQuery {
+    /// Requesting document URI, retained so pending requests can be cleared on close.
+    pub uri: Uri,
+    /// Native path for the requesting document.
+    pub file_path: Arc<PathBuf>,
+    /// UTF-8 byte offset of the start of the requested range.
+    pub start: u32,
+    /// UTF-8 byte offset of the end of the requested range.
+    pub end: u32,
+    /// Line index for the exact open-buffer text that produced the offsets.
+    pub line_index: Arc<LineIndex>,
+    /// Freshness key for the document view active when the request arrived.
+    pub view_key: DocumentViewKey,
+}
+
+/// Resolve an inlay hint query against a fresh package analysis.
+pub fn resolve(query: &InlayHintQuery, package: &CachedPackageAnalysis) -> Vec<InlayHint> {
+    let Some(file) = package.index.file_id(query.file_path.as_ref()) else {
+        return Vec::new();
+    };
+    package
+        .inlay_hints
+        .hints_in(file, query.start, query.end)
+        .iter()
+        .filter_map(|hint| {
+            let anchor = CompactRange { file: hint.file, start: hint.offset, end: hint.offset };
+            let range = compact_range_to_origin_lsp_range(anchor, package.analyzed_files.as_ref(), &query.line_index)?;
+            Some(to_lsp_inlay_hint(hint, range))
+        })
+        .collect()
+}
+
+/// Serialize inlay hints into the standard LSP response payload.
+pub fn response_value(hints: Vec<InlayHint>) -> Value {
+    serde_json::to_value(hints).expect("InlayHint list should serialize")
+}
+
+/// Convert one cached hint anchored at `range.start` into the LSP shape.
+///
+/// Type hints carry an edit that writes the annotation into the source, so
+/// editors can accept them with a double click.
+fn to_lsp_inlay_hint(hint: &CompactInlayHint, range: Range) -> InlayHint {
+    let label = hint.label.as_ref().to_owned();
+    let (kind, padding_left, padding_right, text_edits) = match hint.kind {
+        InlayKind::Type => (
+            Some(InlayHintKind::TYPE),
+            None,
+            None,
+            Some(vec![TextEdit { range: Range::new(range.start, range.start), new_text: label.clone() }]),
+        ),
+        InlayKind::Parameter => (Some(InlayHintKind::PARAMETER), None, Some(true), None),
+        InlayKind::ConstValue | InlayKind::LoopCount => (None, Some(true), None, None),
+    };
+    InlayHint {
+        position: range.start,
+        label: InlayHintLabel::String(label),
+        kind,
+        text_edits,
+        tooltip: None,
+        padding_left,
+        padding_right,
+        data: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_lsp_inlay_hint;
+    use crate::semantics::{
+        CompactInlayHint,
+        FileRange,
+        InlayHintEntry,
+        InlayHintIndex,
+        InlayKind,
+        OccurrenceRole,
+        SemanticIndex,
+        SemanticKind,
+        SourceFingerprint,
+        SymbolIdentity,
+        SymbolOccurrence,
+    };
+    use lsp_types::{InlayHintKind, InlayHintLabel, Position, Range};
+    use std::{path::PathBuf, sync::Arc};
+
+    /// Verifies range queries return only the hints anchored inside the requested file range.
+    #[test]
+    fn hints_in_selects_requested_range() {
+        let path = Arc::new(PathBuf::from("/tmp/main.leo"));
+        let other = Arc::new(PathBuf::from("/tmp/other.leo"));
+        let occurrences = [&path, &other]
+            .into_iter()
+            .map(|path| SymbolOccurrence {
+                range: FileRange::new(Arc::clone(path), 0, 1).expect("non-empty range"),
+                identity: SymbolIdentity::Unknown,
+                role: OccurrenceRole::Reference,
+                token_kind: SemanticKind::Variable,
+                readonly: false,
+            })
+            .collect::<Vec<_>>();
+        let (index, _) = SemanticIndex::build(&occurrences, |_| SourceFingerprint::Volatile, |_| None, |_| true);
+        let entry = |path: &Arc<PathBuf>, offset, label: &str| InlayHintEntry {
+            path: Arc::clone(path),
+            offset,
+            label: Arc::from(label),
+            kind: InlayKind::Type,
+        };
+        let hints = InlayHintIndex::build(
+            &[
+                entry(&path, 30, ": u8"),
+                entry(&path, 10, ": u32"),
+                entry(&other, 10, ": bool"),
+                entry(&path, 10, ": u32"),
+            ],
+            &index,
+        );
+        let file = index.file_id(path.as_ref()).expect("interned");
+
+        let labels =
+            |start, end| hints.hints_in(file, start, end).iter().map(|hint| hint.label.to_string()).collect::<Vec<_>>();
+        assert_eq!(labels(0, 100), [": u32", ": u8"]);
+        assert_eq!(labels(10, 10), [": u32"]);
+        assert_eq!(labels(11, 29), Vec::<String>::new());
+    }
+
+    /// Verifies each hint kind maps to its LSP kind, padding, and edit.
+    #[test]
+    fn hint_kinds_map_to_lsp_shape() {
+        let range = Range::new(Position::new(2, 13), Position::new(2, 13));
+        let hint = |kind, label: &str| {
+            to_lsp_inlay_hint(&CompactInlayHint { file: 0, offset: 0, label: Arc::from(label), kind }, range)
+        };
+
+        let type_hint = hint(InlayKind::Type, ": u32");
+        assert_eq!(type_hint.kind, Some(InlayHintKind::TYPE));
+        assert_eq!(type_hint.text_edits.expect("edit")[0].new_text, ": u32");
+
+        let parameter = hint(InlayKind::Parameter, "amount:");
+        assert_eq!(parameter.kind, Some(InlayHintKind::PARAMETER));
+        assert_eq!(parameter.padding_right, Some(true));
+        assert!(parameter.text_edits.is_none());
+
+        let count = hint(InlayKind::LoopCount, "4 iterations");
+        assert_eq!(count.kind, None);
+        assert_eq!(count.padding_left, Some(true));
+        assert!(matches!(count.label, InlayHintLabel::String(label) if label == "4 iterations"));
+    }
+}
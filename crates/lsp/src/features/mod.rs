@@ -21,10 +21,22 @@
 //! feature modules consume snapshot-safe semantic data and return LSP-ready
 //! values.
 
+/// Quick fixes backed by structured compiler suggestions.
+pub mod code_actions;
+/// Completion query resolution over compiler-lowered candidates.
+pub mod completion;
 /// Diagnostic lowering and LSP wire-format conversion.
 pub mod diagnostics;
+/// Syntax-only document outline.
+pub mod document_symbols;
+/// Document, range, and on-type formatting through `leo-fmt`.
+pub mod formatting;
 /// Go-to-definition query resolution.
 pub mod goto_definition;
+/// Hover query resolution over compiler-rendered declaration text.
+pub mod hover;
+/// Inlay hints for inferred types, parameter names, const values, and loop counts.
+pub mod inlay_hints;
 /// Shared LSP range and URI conversion helpers.
 pub mod lsp_range;
 /// Find-all-references query resolution.
@@ -33,3 +45,7 @@ pub mod references;
 pub mod rename;
 /// Semantic token capability wiring and wire-format encoding helpers.
 pub mod semantic_tokens;
+/// Signature help for calls, including intrinsics.
+pub mod signature_help;
+/// Fuzzy symbol search across workspace packages and dependency stubs.
+pub mod workspace_symbols;
@@ -120,6 +120,9 @@ mod tests {
                 analyzed_files: Arc::new(analyzed_files),
                 source: SemanticSource::CompilerEnhanced,
                 diagnostics: Arc::new(crate::features::diagnostics::DiagnosticSet::empty(key)),
+                hovers: Arc::default(),
+                completions: Arc::default(),
+                inlay_hints: Arc::default(),
             },
             view_key,
             path,
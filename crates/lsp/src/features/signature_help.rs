@@ -0,0 +1,374 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Signature help resolution for Leo LSP.
+//!
+//! Like completion, signature help runs while the call is still being typed,
+//! so the enclosing call is recovered by scanning the raw text backwards from
+//! the cursor. The callee is classified with the completion scanner and then
+//! resolved against user functions and interface prototypes in the
+//! [`CompletionIndex`], or against the compiler's intrinsic table.
+
+use crate::{
+    completion_index::{CallSignature, CompletionEntry, CompletionIndex, ValueShape},
+    document_store::DocumentViewKey,
+    features::completion::{CompletionContext, context},
+};
+use leo_ast::Intrinsic;
+use leo_span::{Symbol, create_session_if_not_set_then, sym};
+use lsp_types::{
+    Documentation,
+    MarkupContent,
+    MarkupKind,
+    ParameterInformation,
+    ParameterLabel,
+    Position,
+    SignatureHelp,
+    SignatureInformation,
+    Uri,
+};
+use serde_json::Value;
+use std::{path::PathBuf, sync::Arc};
+
+/// Cursor query captured before any async package-analysis wait.
+#[derive(Debug, Clone)]
+pub struct SignatureHelpQuery {
+    /// Requesting document URI, retained so pending requests can be cleared on close.
+    pub uri: Uri,
+    /// Native path for the document where the cursor started.
+    pub file_path: Arc<PathBuf>,
+    /// Original LSP position, kept for parity with the other cursor queries.
+    #[allow(dead_code)]
+    pub position: Position,
+    /// UTF-8 byte offset resolved from `position` before any async wait.
+    pub offset: u32,
+    /// Exact open-buffer text that produced `offset`.
+    pub text: Arc<str>,
+    /// Freshness key for the document view active when the request arrived.
+    pub view_key: DocumentViewKey,
+}
+
+/// Innermost call whose argument list contains the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CallSite {
+    /// Syntactic position of the callee, classified like a completion context.
+    callee: CompletionContext,
+    /// Name of the called function or method.
+    name: String,
+    /// Const or type arguments written in `::[..]` before the argument list.
+    const_arguments: Vec<String>,
+    /// Zero-based index of the argument the cursor is in.
+    argument: usize,
+}
+
+/// Resolve signature help for a query against a compiler-lowered index.
+pub fn resolve(query: &SignatureHelpQuery, index: &CompletionIndex) -> Option<SignatureHelp> {
+    let site = call_site(&query.text, query.offset as usize)?;
+    let (signature, documentation) = match &site.callee {
+        CompletionContext::Scope => {
+            user_signature(index.path_items(&[]), &site.name).or_else(|| intrinsic_signature(None, &site.name))?
+        }
+        CompletionContext::Path(qualifier) => {
+            let intrinsic = match qualifier.as_slice() {
+                [module] => intrinsic_signature(Some(module), &site.name),
+                _ => None,
+            };
+            let qualifier = qualifier.iter().map(String::as_str).collect::<Vec<_>>();
+            intrinsic.or_else(|| user_signature(index.path_items(&qualifier), &site.name))?
+        }
+        CompletionContext::Interface(interface) => user_signature(index.interface_items(interface), &site.name)?,
+        CompletionContext::Member(chain) => {
+            let shape = index.receiver_shape(query.file_path.as_ref(), query.offset, query.text.len() as u32, chain);
+            method_signature(shape, &site.name)?
+        }
+    };
+    Some(signature_help(substitute_const_arguments(signature, &site.const_arguments), documentation, site.argument))
+}
+
+/// Serialize a feature result into the standard LSP response payload.
+pub fn response_value(result: Option<SignatureHelp>) -> Value {
+    match result {
+        Some(help) => serde_json::to_value(help).expect("SignatureHelp should serialize"),
+        None => Value::Null,
+    }
+}
+
+/// Find the signature of a user function or interface prototype named `name`.
+fn user_signature(entries: &[CompletionEntry], name: &str) -> Option<(CallSignature, Option<Arc<str>>)> {
+    let entry = entries.iter().find(|entry| entry.label.as_ref() == name && entry.signature.is_some())?;
+    Some((entry.signature.as_deref()?.clone(), entry.documentation.clone()))
+}
+
+/// Resolve `Module::name`, or a raw `_name` call when `module` is `None`, to its intrinsic signature.
+fn intrinsic_signature(module: Option<&str>, name: &str) -> Option<(CallSignature, Option<Arc<str>>)> {
+    create_session_if_not_set_then(|_| {
+        let symbol = match module {
+            Some(module) => Intrinsic::convert_path_symbols(Symbol::intern(module), Symbol::intern(name))?,
+            None => Symbol::intern(name),
+        };
+        let intrinsic = Intrinsic::from_symbol(symbol, &[])?;
+        let prefix = match module {
+            Some(module) => format!("fn {module}::{name}"),
+            None => format!("fn {name}"),
+        };
+        Some((lower_intrinsic(&intrinsic, prefix, false), None))
+    })
+}
+
+/// Resolve `receiver.name(..)` to an intrinsic method, dropping the receiver parameter.
+///
+/// A known receiver shape selects the storage module; otherwise modules are
+/// tried in the order the parser desugars method calls.
+fn method_signature(shape: Option<ValueShape>, name: &str) -> Option<(CallSignature, Option<Arc<str>>)> {
+    create_session_if_not_set_then(|_| {
+        let modules = match shape {
+            Some(ValueShape::Mapping) => vec![sym::Mapping],
+            Some(ValueShape::Vector) => vec![sym::Vector],
+            Some(ValueShape::Optional) => vec![sym::Optional],
+            Some(ValueShape::Composite(_)) => return None,
+            None => vec![sym::signature, sym::Final, sym::Optional, sym::Vector, sym::Mapping],
+        };
+        let method = Symbol::intern(name);
+        let symbol = modules.into_iter().find_map(|module| Intrinsic::convert_path_symbols(module, method))?;
+        let intrinsic = Intrinsic::from_symbol(symbol, &[])?;
+        Some((lower_intrinsic(&intrinsic, format!("fn {name}"), true), None))
+    })
+}
+
+/// Lower an intrinsic signature, optionally dropping the method-call receiver.
+fn lower_intrinsic(intrinsic: &Intrinsic, prefix: String, skip_receiver: bool) -> CallSignature {
+    let signature = intrinsic.signature();
+    CallSignature {
+        prefix,
+        const_parameters: signature.type_parameters.iter().map(|name| (name.to_string(), String::new())).collect(),
+        parameters: signature
+            .parameters
+            .iter()
+            .skip(usize::from(skip_receiver))
+            .map(|(name, type_)| format!("{name}: {type_}"))
+            .collect(),
+        output: (signature.output != "()").then_some(signature.output),
+        variadic: signature.variadic,
+    }
+}
+
+/// Replace const parameter names with the arguments written at the call site.
+///
+/// This shows the monomorphized parameter types, e.g. `[u32; 3]` for
+/// `values: [u32; N]` called as `sum::[3](..)`.
+fn substitute_const_arguments(mut signature: CallSignature, arguments: &[String]) -> CallSignature {
+    for ((name, _), argument) in signature.const_parameters.iter().zip(arguments) {
+        for parameter in &mut signature.parameters {
+            *parameter = replace_word(parameter, name, argument);
+        }
+        if let Some(output) = &mut signature.output {
+            *output = replace_word(output, name, argument);
+        }
+    }
+    signature
+}
+
+/// Replace whole-identifier occurrences of `word` in `text`.
+fn replace_word(text: &str, word: &str, replacement: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+        let end = rest[start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(rest.len(), |len| start + len);
+        result.push_str(&rest[..start]);
+        result.push_str(if &rest[start..end] == word { replacement } else { &rest[start..end] });
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Lower a call signature into the LSP shape with UTF-16 parameter offsets.
+fn signature_help(signature: CallSignature, documentation: Option<Arc<str>>, argument: usize) -> SignatureHelp {
+    let label = signature.to_string();
+    let utf16_len = |text: &str| text.encode_utf16().count() as u32;
+
+    // Parameters follow the `(` after the prefix and any `::[..]` list.
+    let mut cursor =
+        label[signature.prefix.len()..].find('(').map_or(label.len(), |open| signature.prefix.len() + open + 1);
+    let parameters = signature
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let start = utf16_len(&label[..cursor]);
+            let end = start + utf16_len(parameter);
+            cursor += parameter.len() + ", ".len();
+            ParameterInformation { label: ParameterLabel::LabelOffsets([start, end]), documentation: None }
+        })
+        .collect::<Vec<_>>();
+
+    let active = if signature.variadic { argument.min(parameters.len().saturating_sub(1)) } else { argument };
+    let active = active as u32;
+    SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: documentation.map(|docs| {
+                Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value: docs.to_string() })
+            }),
+            parameters: Some(parameters),
+            active_parameter: Some(active),
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active),
+    }
+}
+
+/// Recover the innermost call enclosing `offset` from the text before it.
+///
+/// Returns `None` when the cursor is not inside an argument list, for example
+/// after the statement ends or inside a block or struct literal.
+fn call_site(text: &str, offset: usize) -> Option<CallSite> {
+    let before = text.as_bytes().get(..offset.min(text.len()))?;
+
+    let mut depth = 0usize;
+    let mut argument = 0usize;
+    let mut open = None;
+    for (position, byte) in before.iter().enumerate().rev() {
+        match byte {
+            b')' | b']' | b'}' => depth += 1,
+            b'(' | b'[' | b'{' if depth > 0 => depth -= 1,
+            b'(' => {
+                open = Some(position);
+                break;
+            }
+            // The cursor sits in an array inside some argument; commas so far were its elements.
+            b'[' => argument = 0,
+            b'{' | b';' => return None,
+            b',' if depth == 0 => argument += 1,
+            _ => {}
+        }
+    }
+
+    let mut end = open?;
+    let mut const_arguments = Vec::new();
+    if before[..end].ends_with(b"]") {
+        let close = end - 1;
+        let mut depth = 0usize;
+        let start = (0..=close).rev().find(|&position| {
+            match before[position] {
+                b']' => depth += 1,
+                b'[' => depth -= 1,
+                _ => {}
+            }
+            depth == 0
+        })?;
+        if !before[..start].ends_with(b"::") {
+            return None;
+        }
+        const_arguments = split_arguments(std::str::from_utf8(&before[start + 1..close]).ok()?);
+        end = start - 2;
+    }
+
+    let name_start = before[..end].iter().rposition(|byte| !is_ident_byte(*byte)).map_or(0, |position| position + 1);
+    let name = std::str::from_utf8(&before[name_start..end]).ok()?;
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let callee = context(text, end)?;
+    Some(CallSite { callee, name: name.to_owned(), const_arguments, argument })
+}
+
+/// Split a `::[..]` argument list on its top-level commas.
+fn split_arguments(text: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (position, c) in text.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                arguments.push(text[start..position].trim().to_owned());
+                start = position + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = text[start..].trim();
+    if !last.is_empty() {
+        arguments.push(last.to_owned());
+    }
+    arguments
+}
+
+/// Return whether `byte` may appear in a Leo identifier.
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CallSite, call_site, replace_word};
+    use crate::features::completion::CompletionContext;
+
+    /// Return the call site at the `$` marker in `source`.
+    fn site_at(source: &str) -> Option<CallSite> {
+        let offset = source.find('$').expect("cursor marker");
+        call_site(&source.replacen('$', "", 1), offset)
+    }
+
+    /// Verifies top-level commas select the active argument while nested ones are skipped.
+    #[test]
+    fn commas_count_only_at_call_depth() {
+        let site = site_at("let x = transfer(a, foo(b, c), [d, e], $").expect("call site");
+        assert_eq!(site.callee, CompletionContext::Scope);
+        assert_eq!(site.name, "transfer");
+        assert_eq!(site.argument, 3);
+
+        let site = site_at("let x = transfer(a, foo(b, $").expect("call site");
+        assert_eq!(site.name, "foo");
+        assert_eq!(site.argument, 1);
+    }
+
+    /// Verifies const arguments, paths, methods, and dynamic calls classify the callee.
+    #[test]
+    fn callee_forms_are_classified() {
+        let site = site_at("let s = sum::[3, u8](values$").expect("call site");
+        assert_eq!(site.name, "sum");
+        assert_eq!(site.const_arguments, vec!["3".to_owned(), "u8".to_owned()]);
+
+        let site = site_at("let h = BHP256::hash_to_field($").expect("call site");
+        assert_eq!(site.callee, CompletionContext::Path(vec!["BHP256".into()]));
+
+        let site = site_at("balances.set(owner, $").expect("call site");
+        assert_eq!(site.callee, CompletionContext::Member(vec!["balances".into()]));
+        assert_eq!(site.argument, 1);
+
+        let site = site_at("Bank@(target)::withdraw($").expect("call site");
+        assert_eq!(site.callee, CompletionContext::Interface("Bank".into()));
+    }
+
+    /// Verifies positions outside an argument list produce no call site.
+    #[test]
+    fn no_call_outside_arguments() {
+        assert_eq!(site_at("let x = foo(a); let y = $"), None);
+        assert_eq!(site_at("fn main(a: u32) { $"), None);
+        assert_eq!(site_at("let p = Point { x: foo(1), $"), None);
+        assert_eq!(site_at("let t = (a, $"), None);
+    }
+
+    /// Verifies const parameters are replaced only as whole identifiers.
+    #[test]
+    fn const_arguments_replace_whole_words() {
+        assert_eq!(replace_word("values: [u32; N], NN: u8", "N", "3"), "values: [u32; 3], NN: u8");
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Workspace-wide fuzzy symbol search for Leo LSP.
+//!
+//! The search walks every package reachable from the editor: the packages of
+//! open documents, the editor workspace folders, every member of a
+//! `workspace.json` above them, and their dependencies. Leo dependencies are
+//! outlined from source; `.aleo` bytecode stubs are scanned line by line for
+//! their declarations. Open buffers win over disk so unsaved edits are found.
+//!
+//! The walk reads from disk, so it runs on the response pool and checks its
+//! cancellation flag between files.
+
+use crate::{
+    features::{
+        document_symbols::{OutlineSymbol, outline},
+        lsp_range::byte_range_to_lsp_range,
+    },
+    project_model::{ProjectModel, path_to_file_uri},
+    syntax_semantics::dependency_source_path,
+};
+use leo_package::{MANIFEST_FILENAME, Manifest, Workspace};
+use line_index::LineIndex;
+use lsp_types::{Location, OneOf, SymbolKind, WorkspaceSymbol, WorkspaceSymbolResponse};
+use serde_json::Value;
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// Maximum number of symbols returned for one query.
+const MAX_WORKSPACE_SYMBOLS: usize = 256;
+
+/// Workspace symbol query captured on the routing thread.
+#[derive(Debug, Clone)]
+pub struct WorkspaceSymbolQuery {
+    /// Text typed by the user; matched as a case-insensitive subsequence.
+    pub query: String,
+    /// Package roots of open documents and editor workspace folders.
+    pub roots: Vec<PathBuf>,
+    /// Open-buffer text keyed by native path, preferred over disk reads.
+    pub open_texts: HashMap<PathBuf, Arc<str>>,
+}
+
+/// One matched symbol and its ranking score.
+struct Candidate {
+    /// Fuzzy-match score; higher ranks first.
+    score: u32,
+    /// Symbol returned to the client.
+    symbol: WorkspaceSymbol,
+}
+
+/// Run a workspace symbol query; returns `None` once `cancel` is set.
+pub fn resolve(query: &WorkspaceSymbolQuery, cancel: &AtomicBool) -> Option<Vec<WorkspaceSymbol>> {
+    let mut model = ProjectModel::default();
+    let mut pending = package_roots(&query.roots);
+    let mut visited = BTreeSet::new();
+    let mut candidates = Vec::new();
+
+    while let Some(root) = pending.pop() {
+        let root = root.canonicalize().unwrap_or(root);
+        if !visited.insert(root.clone()) {
+            continue;
+        }
+        if cancel.load(Ordering::SeqCst) {
+            return None;
+        }
+        let Some(project) = model.resolve_project_context(&root.join(MANIFEST_FILENAME)) else {
+            continue;
+        };
+
+        for path in leo_files(project.source_directory.as_path()) {
+            if cancel.load(Ordering::SeqCst) {
+                return None;
+            }
+            let text = match query.open_texts.get(&path) {
+                Some(text) => Arc::clone(text),
+                None => match fs::read_to_string(&path) {
+                    Ok(text) => Arc::from(text),
+                    Err(_) => continue,
+                },
+            };
+            let path = Arc::new(path);
+            let symbols = outline(&text, Some(&path), Some(&project));
+            append_outline_matches(&query.query, &path, &text, &symbols, None, &mut candidates);
+        }
+
+        let manifest = Manifest::read_from_file(project.manifest_path.as_path()).ok();
+        for dependency in manifest.and_then(|manifest| manifest.dependencies).unwrap_or_default() {
+            if let Some((_, dependency_root)) = dependency_source_path(&root, &dependency) {
+                pending.push(dependency_root);
+                continue;
+            }
+            let Some(path) = dependency.path.as_deref() else {
+                continue;
+            };
+            if path.extension().is_some_and(|extension| extension == "aleo") {
+                append_stub_matches(&query.query, &root.join(path), &mut candidates);
+            }
+        }
+    }
+
+    candidates
+        .sort_by(|left, right| right.score.cmp(&left.score).then_with(|| left.symbol.name.cmp(&right.symbol.name)));
+    candidates.truncate(MAX_WORKSPACE_SYMBOLS);
+    Some(candidates.into_iter().map(|candidate| candidate.symbol).collect())
+}
+
+/// Serialize a workspace-symbol result into the standard LSP response payload.
+pub fn response_value(symbols: Vec<WorkspaceSymbol>) -> Value {
+    serde_json::to_value(WorkspaceSymbolResponse::Nested(symbols)).expect("WorkspaceSymbolResponse should serialize")
+}
+
+/// Score `candidate` against `query` as a case-insensitive subsequence match.
+///
+/// Consecutive matches, matches at word starts, and a matching first
+/// character score higher, so `tr` ranks `transfer` above `get_rate`. An
+/// empty query matches everything with the lowest score.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let candidate = candidate.chars().collect::<Vec<_>>();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous = None;
+    for wanted in query.chars().filter(|ch| !ch.is_whitespace()) {
+        let found = (position..candidate.len()).find(|&index| candidate[index].eq_ignore_ascii_case(&wanted))?;
+        score += 1;
+        if found == 0 {
+            score += 8;
+        } else if matches!(candidate[found - 1], '_' | ':' | '.') || candidate[found].is_ascii_uppercase() {
+            score += 4;
+        }
+        if previous.is_some_and(|previous| previous + 1 == found) {
+            score += 3;
+        }
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+/// Collect every package root reachable from the editor roots.
+fn package_roots(roots: &[PathBuf]) -> Vec<PathBuf> {
+    let mut packages = BTreeSet::new();
+    for root in roots {
+        if root.join(MANIFEST_FILENAME).is_file() {
+            packages.insert(root.canonicalize().unwrap_or_else(|_| root.clone()));
+        }
+        if let Ok(Some(workspace)) = Workspace::discover(root) {
+            packages.extend(workspace.member_paths);
+        }
+    }
+    packages.into_iter().rev().collect()
+}
+
+/// Return every `.leo` file below `directory`, in a stable order.
+fn leo_files(directory: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut directories = vec![directory.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                directories.push(path);
+            } else if path.extension().is_some_and(|extension| extension == "leo") {
+                files.push(path.canonicalize().unwrap_or(path));
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Append the outline symbols that match `query`, flattening nested ones.
+///
+/// Struct and record members are skipped; they are searchable through their
+/// owning type.
+fn append_outline_matches(
+    query: &str,
+    path: &Path,
+    text: &str,
+    symbols: &[OutlineSymbol],
+    container: Option<&str>,
+    candidates: &mut Vec<Candidate>,
+) {
+    let line_index = LineIndex::new(text);
+    let Some(uri) = path_to_file_uri(path) else {
+        return;
+    };
+    let mut stack = symbols.iter().map(|symbol| (symbol, container.map(str::to_owned))).collect::<Vec<_>>();
+    while let Some((symbol, container)) = stack.pop() {
+        stack.extend(symbol.children.iter().map(|child| (child, Some(symbol.name.clone()))));
+        if symbol.kind == SymbolKind::FIELD {
+            continue;
+        }
+        let Some(score) = fuzzy_score(query, &symbol.name) else {
+            continue;
+        };
+        let Some(range) = byte_range_to_lsp_range(&line_index, symbol.selection.0, symbol.selection.1) else {
+            continue;
+        };
+        candidates.push(Candidate {
+            score,
+            symbol: WorkspaceSymbol {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                tags: None,
+                container_name: container,
+                location: OneOf::Left(Location { uri: uri.clone(), range }),
+                data: None,
+            },
+        });
+    }
+}
+
+/// Append the declarations of a `.aleo` bytecode stub that match `query`.
+fn append_stub_matches(query: &str, path: &Path, candidates: &mut Vec<Candidate>) {
+    let Ok(text) = fs::read_to_string(path) else {
+        return;
+    };
+    append_outline_matches(query, path, &text, &stub_outline(&text), None, candidates);
+}
+
+/// Outline a bytecode stub: the program and its functions, closures, records, structs, and mappings.
+fn stub_outline(text: &str) -> Vec<OutlineSymbol> {
+    let mut program: Option<OutlineSymbol> = None;
+    let mut items = Vec::new();
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let indent = (line.len() - trimmed.len()) as u32;
+        let mut words = trimmed.split_whitespace();
+        if let (Some(keyword), Some(name)) = (words.next(), words.next()) {
+            let name = name.trim_end_matches([':', ';']);
+            let kind = match keyword {
+                "program" => Some(SymbolKind::NAMESPACE),
+                "function" | "closure" => Some(SymbolKind::FUNCTION),
+                "record" | "struct" => Some(SymbolKind::STRUCT),
+                "mapping" => Some(SymbolKind::PROPERTY),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                let start = offset + indent + keyword.len() as u32 + 1;
+                let symbol = OutlineSymbol {
+                    name: name.to_owned(),
+                    kind,
+                    detail: Some(keyword.to_owned()),
+                    range: (offset + indent, offset + line.trim_end().len() as u32),
+                    selection: (start, start + name.len() as u32),
+                    children: Vec::new(),
+                };
+                if kind == SymbolKind::NAMESPACE {
+                    program = Some(symbol);
+                } else {
+                    items.push(symbol);
+                }
+            }
+        }
+        offset += line.len() as u32;
+    }
+
+    match program {
+        Some(mut program) => {
+            program.children = items;
+            vec![program]
+        }
+        None => items,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzzy_score, stub_outline};
+
+    /// Verifies subsequence matching is case-insensitive and rewards prefixes and word starts.
+    #[test]
+    fn fuzzy_score_ranks_prefix_and_word_starts() {
+        assert!(fuzzy_score("xfer", "transfer").is_none());
+        assert!(fuzzy_score("TRF", "transfer").is_some());
+        assert_eq!(fuzzy_score("", "transfer"), Some(0));
+
+        let prefix = fuzzy_score("tr", "transfer").unwrap();
+        let inner = fuzzy_score("tr", "get_rate").unwrap();
+        assert!(prefix > inner);
+
+        let word_start = fuzzy_score("ba", "get_balance").unwrap();
+        let scattered = fuzzy_score("ba", "cabal").unwrap();
+        assert!(word_start > scattered);
+    }
+
+    /// Verifies bytecode stubs are outlined by declaration keyword with exact name ranges.
+    #[test]
+    fn stub_outline_lists_declarations() {
+        let text = "program credits.aleo;\n\nmapping account:\n    key as address.public;\n\nrecord credits:\n    owner as address.private;\n\nfunction transfer_public:\n    input r0 as address.public;\n";
+        let symbols = stub_outline(text);
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "credits.aleo");
+
+        let names = symbols[0].children.iter().map(|symbol| symbol.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["account", "credits", "transfer_public"]);
+        let transfer = &symbols[0].children[2];
+        assert_eq!(&text[transfer.selection.0 as usize..transfer.selection.1 as usize], "transfer_public");
+    }
+}
@@ -20,6 +20,8 @@
 //! binary together with a testable server runner for in-process tests.
 
 mod compiler_bridge;
+mod completion_index;
+mod declaration_text;
 mod document_store;
 mod features;
 mod panic_boundary;
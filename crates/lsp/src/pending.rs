@@ -18,19 +18,19 @@
 
 //! Shared drain abstractions for routing-thread pending-request state.
 //!
-//! Five pending-request structs (`semantic_tokens`, `definitions`,
-//! `references`, `rename`, `prepare_rename`) all surface the same lifecycle
-//! events: `didClose`, package eviction, bucket invalidation, and worker
-//! panic. Each event drains pending entries on one of three dimensions —
-//! URI, `PackageAnalysisKey`, or `AnalysisBucket` — then replies to the
-//! drained requests. Without a shared abstraction, every fan-out helper in
-//! `server.rs` repeats five copies of the same `drain_* + send_*` sequence.
+//! Eight pending-request structs (`semantic_tokens`, `definitions`,
+//! `references`, `rename`, `prepare_rename`, `hover`, `completion`,
+//! `signature_help`) all surface the same lifecycle events: `didClose`,
+//! package eviction, bucket invalidation, and worker panic. Each event drains pending entries on one of three
+//! dimensions — URI, `PackageAnalysisKey`, or `AnalysisBucket` — then replies
+//! to the drained requests. Without a shared abstraction, every fan-out helper in
+//! `server.rs` repeats eight copies of the same `drain_* + send_*` sequence.
 //!
 //! `PendingFeature` and `PendingRequest` collapse that to one generic helper.
 //! The trait is generic over the per-feature request type so each implementor
 //! keeps its own struct shape (cancel flag for references/rename; bare
-//! `RequestId` for semantic tokens; query-only for definitions and
-//! prepare-rename) without least-common-denominator dispatch.
+//! `RequestId` for semantic tokens; query-only for definitions, hover, completion,
+//! signature help and prepare-rename) without least-common-denominator dispatch.
 //!
 //! Per-feature operations that are not a four-dimension drain (semantic
 //! tokens' `take_key`, references' and rename's `mark_undispatched`) stay on
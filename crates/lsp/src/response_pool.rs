@@ -21,7 +21,8 @@
 //! Package analysis stores compact byte ranges, not retained file text. This
 //! pool keeps disk re-read, fingerprint verification, and UTF-16 range
 //! conversion off the routing thread while sending completions back through the
-//! single JSON-RPC writer.
+//! single JSON-RPC writer. Workspace symbol search walks package sources on
+//! disk for the same reason.
 
 use crate::{
     document_store::{DocumentStore, PackageAnalysisKey},
@@ -29,6 +30,11 @@ use crate::{
         lsp_range::{byte_range_to_lsp_range, compact_range_to_location_with_line_index, read_verified_disk_text},
         references::{ReferenceQuery, resolve_targets, response_value as references_response_value},
         rename::{RenameError, RenameQuery, resolve_targets as resolve_rename_targets},
+        workspace_symbols::{
+            WorkspaceSymbolQuery,
+            resolve as resolve_workspace_symbols,
+            response_value as workspace_symbols_response_value,
+        },
     },
     project_model::path_to_file_uri,
     semantics::{CachedPackageAnalysis, FileId, SourceFingerprint},
@@ -101,6 +107,15 @@ pub enum ResponseJob {
         /// Cancellation flag shared with the routing thread.
         cancel: Arc<AtomicBool>,
     },
+    /// Search every reachable package for symbols matching a query.
+    WorkspaceSymbols {
+        /// Original JSON-RPC request ID.
+        id: RequestId,
+        /// Query text, package roots, and open-buffer text captured by the routing thread.
+        query: Box<WorkspaceSymbolQuery>,
+        /// Cancellation flag shared with the routing thread.
+        cancel: Arc<AtomicBool>,
+    },
     /// Ask a worker to stop after completing any current job.
     Shutdown,
 }
@@ -147,6 +162,15 @@ pub enum ResponseCompletion {
         /// Final rename response or error.
         result: RenameResult,
     },
+    /// Completed workspace symbol search.
+    WorkspaceSymbols {
+        /// Original JSON-RPC request ID.
+        id: RequestId,
+        /// Cancellation token captured at dispatch, used to reject reused IDs.
+        cancel: Arc<AtomicBool>,
+        /// Final response payload or error.
+        result: ResponseResult,
+    },
 }
 
 /// Prepared response payload returned to the routing thread.
@@ -302,6 +326,22 @@ fn spawn_worker(
                             result,
                         });
                     }
+                    ResponseJob::WorkspaceSymbols { id, query, cancel } => {
+                        let completion_cancel = Arc::clone(&cancel);
+                        let result = catch_unwind(AssertUnwindSafe(|| resolve_workspace_symbols(&query, &cancel)));
+                        let result = match result {
+                            Ok(Some(symbols)) => ResponseResult::Ok(workspace_symbols_response_value(symbols)),
+                            Ok(None) => continue,
+                            Err(_) => ResponseResult::InternalError(
+                                "workspace symbol search panicked; see server logs for details".to_owned(),
+                            ),
+                        };
+                        let _ = completion_tx.send(ResponseCompletion::WorkspaceSymbols {
+                            id,
+                            cancel: completion_cancel,
+                            result,
+                        });
+                    }
                     ResponseJob::Shutdown => break,
                 }
             }
@@ -760,6 +760,174 @@ impl SemanticIndex {
     }
 }
 
+/// Markdown hover text recorded for one source range by the compiler collector.
+///
+/// Entries are keyed by the same identifier ranges as [`SymbolOccurrence`]s so
+/// hover answers line up with the token the editor highlights.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HoverEntry {
+    /// File-relative source range the hover applies to.
+    pub range: FileRange,
+    /// Rendered markdown: a `leo` code block plus any doc comment.
+    pub contents: Arc<str>,
+}
+
+/// Compact hover table cached alongside the package [`SemanticIndex`].
+///
+/// References to the same declaration render identical markdown, so the table
+/// stores each distinct text once and maps ranges to text slots.
+#[derive(Debug, Clone, Default)]
+pub struct HoverIndex {
+    /// Hover ranges sorted by file, start, and end offset.
+    pub ranges: Arc<[CompactRange]>,
+    /// Text slot for each entry in `ranges`.
+    pub text_ids: Arc<[u32]>,
+    /// Deduplicated markdown hover texts.
+    pub texts: Arc<[Arc<str>]>,
+}
+
+impl HoverIndex {
+    /// Lower worker hover entries into compact ranges over `index`'s file IDs.
+    ///
+    /// Entries in files the semantic index never interned are dropped: without
+    /// an occurrence there is nothing for a cursor query to land on.
+    pub fn build(entries: &[HoverEntry], index: &SemanticIndex) -> Self {
+        let mut text_ids = HashMap::<Arc<str>, u32>::new();
+        let mut texts = Vec::<Arc<str>>::new();
+        let mut slots = Vec::<(CompactRange, u32)>::with_capacity(entries.len());
+
+        for entry in entries {
+            let Some(file) = index.file_id(entry.range.path.as_ref()) else {
+                continue;
+            };
+            let text_id = *text_ids.entry(Arc::clone(&entry.contents)).or_insert_with(|| {
+                texts.push(Arc::clone(&entry.contents));
+                (texts.len() - 1) as u32
+            });
+            slots.push((CompactRange { file, start: entry.range.start, end: entry.range.end }, text_id));
+        }
+
+        slots.sort_by(|(left, _), (right, _)| {
+            left.file.cmp(&right.file).then_with(|| left.start.cmp(&right.start)).then_with(|| left.end.cmp(&right.end))
+        });
+        // The collector can visit the same identifier twice (for example a
+        // struct-literal shorthand field); keep the first rendering.
+        slots.dedup_by(|(left, _), (right, _)| left == right);
+
+        Self {
+            ranges: slots.iter().map(|(range, _)| *range).collect(),
+            text_ids: slots.iter().map(|(_, text_id)| *text_id).collect(),
+            texts: Arc::from(texts),
+        }
+    }
+
+    /// Return the innermost hover range and text under a cursor byte offset.
+    ///
+    /// Like [`SemanticIndex::occurrence_at`], a cursor immediately after an
+    /// identifier still hits it.
+    pub fn hover_at(&self, file: FileId, offset: u32) -> Option<(CompactRange, &str)> {
+        let first = self.ranges.partition_point(|range| range.file < file);
+        let mut best = None::<usize>;
+        for (index, range) in self.ranges[first..].iter().enumerate() {
+            if range.file != file || range.start > offset {
+                break;
+            }
+            let contains = range.start <= offset && offset < range.end;
+            let at_end = range.start < offset && offset == range.end;
+            if !(contains || at_end) {
+                continue;
+            }
+            best = match best {
+                Some(current) if range_len(self.ranges[first + current]) <= range_len(*range) => Some(current),
+                _ => Some(index),
+            };
+        }
+
+        best.map(|index| {
+            let index = first + index;
+            (self.ranges[index], self.texts[self.text_ids[index] as usize].as_ref())
+        })
+    }
+}
+
+/// What an inlay hint annotates, which decides its LSP kind and padding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InlayKind {
+    /// Inferred type after an unannotated binding, rendered as `: T`.
+    Type,
+    /// Parameter name before a call argument, rendered as `name:`.
+    Parameter,
+    /// Resolved value after a const generic argument, rendered as `= value`.
+    ConstValue,
+    /// Iteration count after the bounds of a loop that will be unrolled.
+    LoopCount,
+}
+
+/// Inline annotation recorded at one source offset by the compiler collector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlayHintEntry {
+    /// File the hint is rendered in.
+    pub path: Arc<PathBuf>,
+    /// UTF-8 byte offset the hint is anchored to.
+    pub offset: u32,
+    /// Rendered label, without padding.
+    pub label: Arc<str>,
+    /// What the hint annotates.
+    pub kind: InlayKind,
+}
+
+/// An [`InlayHintEntry`] lowered onto the package [`SemanticIndex`]'s file IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactInlayHint {
+    /// Interned analyzed-file ID.
+    pub file: FileId,
+    /// UTF-8 byte offset the hint is anchored to.
+    pub offset: u32,
+    /// Rendered label, without padding.
+    pub label: Arc<str>,
+    /// What the hint annotates.
+    pub kind: InlayKind,
+}
+
+/// Inlay hint table cached alongside the package [`SemanticIndex`].
+#[derive(Debug, Clone, Default)]
+pub struct InlayHintIndex {
+    /// Hints sorted by file and offset.
+    pub hints: Arc<[CompactInlayHint]>,
+}
+
+impl InlayHintIndex {
+    /// Lower worker inlay hints onto `index`'s file IDs, dropping files it never interned.
+    pub fn build(entries: &[InlayHintEntry], index: &SemanticIndex) -> Self {
+        let mut hints = entries
+            .iter()
+            .filter_map(|entry| {
+                Some(CompactInlayHint {
+                    file: index.file_id(entry.path.as_ref())?,
+                    offset: entry.offset,
+                    label: Arc::clone(&entry.label),
+                    kind: entry.kind,
+                })
+            })
+            .collect::<Vec<_>>();
+        hints.sort_by(|left, right| {
+            left.file
+                .cmp(&right.file)
+                .then_with(|| left.offset.cmp(&right.offset))
+                .then_with(|| left.kind.cmp(&right.kind))
+        });
+        hints.dedup();
+        Self { hints: Arc::from(hints) }
+    }
+
+    /// Return the hints of `file` anchored inside the inclusive byte range `start..=end`.
+    pub fn hints_in(&self, file: FileId, start: u32, end: u32) -> &[CompactInlayHint] {
+        let first = self.hints.partition_point(|hint| (hint.file, hint.offset) < (file, start));
+        let last = self.hints.partition_point(|hint| (hint.file, hint.offset) <= (file, end));
+        &self.hints[first..last.max(first)]
+    }
+}
+
 /// Package-level semantic analysis shared by navigation, semantic tokens, and
 /// diagnostics. All three are package-keyed and invalidated together when the
 /// bucket generation changes.
@@ -776,6 +944,12 @@ pub struct CachedPackageAnalysis {
     pub source: SemanticSource,
     /// Diagnostics collected during this analysis; always present, possibly empty.
     pub diagnostics: Arc<crate::features::diagnostics::DiagnosticSet>,
+    /// Compiler-rendered hover texts; empty when only syntax analysis ran.
+    pub hovers: Arc<HoverIndex>,
+    /// Compiler-lowered completion candidates; empty when only syntax analysis ran.
+    pub completions: Arc<crate::completion_index::CompletionIndex>,
+    /// Compiler-rendered inlay hints; empty when only syntax analysis ran.
+    pub inlay_hints: Arc<InlayHintIndex>,
 }
 
 /// Small per-document semantic-token view built from a package analysis.
@@ -24,18 +24,38 @@
 //! freshness, ordering, and single-writer JSON-RPC response semantics.
 
 use crate::{
+    completion_index::CompletionIndex,
     document_store::{AnalysisBucket, DocumentStore, DocumentViewKey, PackageAnalysisKey},
     features::{
+        code_actions::{quick_fixes, response_value as code_actions_response_value},
+        completion::{CompletionQuery, resolve as resolve_completion, response_value as completion_response_value},
         diagnostics::{DiagnosticClientCapabilitySnapshot, DiagnosticEntry, DiagnosticSet, entry_to_lsp_diagnostic},
+        document_symbols::{document_symbols, outline, response_value as document_symbols_response_value},
+        formatting::{
+            ON_TYPE_FIRST_TRIGGER,
+            ON_TYPE_MORE_TRIGGERS,
+            format_document,
+            format_on_type,
+            format_range,
+            response_value as formatting_response_value,
+        },
         goto_definition::{
             DefinitionQuery,
             resolve as resolve_definition,
             response_value as definition_response_value,
         },
+        hover::{HoverQuery, resolve as resolve_hover, response_value as hover_response_value},
+        inlay_hints::{InlayHintQuery, resolve as resolve_inlay_hints, response_value as inlay_hints_response_value},
         lsp_range::{byte_range_to_lsp_range, position_to_offset},
         references::ReferenceQuery,
         rename::{PrepareRenameQuery, RenameQuery, prepare_rename_target, validate_new_name},
         semantic_tokens::{capability as semantic_tokens_capability, empty_response_value, response_value},
+        signature_help::{
+            SignatureHelpQuery,
+            resolve as resolve_signature_help,
+            response_value as signature_help_response_value,
+        },
+        workspace_symbols::WorkspaceSymbolQuery,
     },
     panic_boundary::catch_unwind,
     pending::{PendingFeature, PendingRequest, cancel_drained},
@@ -56,14 +76,28 @@ use anyhow::{Context, Result};
 use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response, ResponseError};
 use lsp_types::{
     CancelParams,
+    CodeActionKind,
+    CodeActionOptions,
+    CodeActionParams,
+    CodeActionProviderCapability,
+    CompletionOptions,
+    CompletionParams,
     Diagnostic,
     DidChangeTextDocumentParams,
     DidCloseTextDocumentParams,
     DidOpenTextDocumentParams,
     DidSaveTextDocumentParams,
+    DocumentFormattingParams,
+    DocumentOnTypeFormattingOptions,
+    DocumentOnTypeFormattingParams,
+    DocumentRangeFormattingParams,
+    DocumentSymbolParams,
     GotoDefinitionParams,
+    HoverParams,
+    HoverProviderCapability,
     InitializeParams,
     InitializeResult,
+    InlayHintParams,
     NumberOrString,
     OneOf,
     PrepareRenameResponse,
@@ -76,14 +110,18 @@ use lsp_types::{
     SemanticTokensParams,
     ServerCapabilities,
     ServerInfo,
+    SignatureHelpOptions,
+    SignatureHelpParams,
     TextDocumentContentChangeEvent,
     TextDocumentPositionParams,
     TextDocumentSyncCapability,
     TextDocumentSyncKind,
     TextDocumentSyncOptions,
     TextDocumentSyncSaveOptions,
+    TextEdit,
     Uri,
     WorkDoneProgressOptions,
+    WorkspaceSymbolParams,
 };
 use serde_json::Value;
 use std::{
@@ -129,6 +167,26 @@ const TEXT_DOCUMENT_REFERENCES: &str = "textDocument/references";
 const TEXT_DOCUMENT_RENAME: &str = "textDocument/rename";
 /// LSP prepare-rename request method.
 const TEXT_DOCUMENT_PREPARE_RENAME: &str = "textDocument/prepareRename";
+/// LSP hover request method.
+const TEXT_DOCUMENT_HOVER: &str = "textDocument/hover";
+/// LSP completion request method.
+const TEXT_DOCUMENT_COMPLETION: &str = "textDocument/completion";
+/// LSP signature help request method.
+const TEXT_DOCUMENT_SIGNATURE_HELP: &str = "textDocument/signatureHelp";
+/// LSP document outline request method.
+const TEXT_DOCUMENT_DOCUMENT_SYMBOL: &str = "textDocument/documentSymbol";
+/// LSP workspace symbol search request method.
+const WORKSPACE_SYMBOL: &str = "workspace/symbol";
+/// LSP whole-document formatting request method.
+const TEXT_DOCUMENT_FORMATTING: &str = "textDocument/formatting";
+/// LSP range formatting request method.
+const TEXT_DOCUMENT_RANGE_FORMATTING: &str = "textDocument/rangeFormatting";
+/// LSP on-type formatting request method.
+const TEXT_DOCUMENT_ON_TYPE_FORMATTING: &str = "textDocument/onTypeFormatting";
+/// LSP code action request method.
+const TEXT_DOCUMENT_CODE_ACTION: &str = "textDocument/codeAction";
+/// LSP inlay hint request method.
+const TEXT_DOCUMENT_INLAY_HINT: &str = "textDocument/inlayHint";
 /// Maximum package analyses retained on the routing thread.
 const MAX_PACKAGE_CACHE_ENTRIES: usize = 8;
 /// Maximum pending go-to-definition requests across all packages.
@@ -147,6 +205,22 @@ const MAX_PENDING_RENAMES_PER_KEY: usize = 16;
 const MAX_PENDING_PREPARE_RENAMES: usize = 128;
 /// Maximum pending prepare-rename requests waiting on one package key.
 const MAX_PENDING_PREPARE_RENAMES_PER_KEY: usize = 16;
+/// Maximum pending hover requests across all packages.
+const MAX_PENDING_HOVERS: usize = 128;
+/// Maximum pending hover requests waiting on one package key.
+const MAX_PENDING_HOVERS_PER_KEY: usize = 16;
+/// Maximum pending completion requests across all packages.
+const MAX_PENDING_COMPLETIONS: usize = 128;
+/// Maximum pending completion requests waiting on one package key.
+const MAX_PENDING_COMPLETIONS_PER_KEY: usize = 16;
+/// Maximum pending signature help requests across all packages.
+const MAX_PENDING_SIGNATURE_HELPS: usize = 128;
+/// Maximum pending signature help requests waiting on one package key.
+const MAX_PENDING_SIGNATURE_HELPS_PER_KEY: usize = 16;
+/// Maximum pending inlay hint requests across all packages.
+const MAX_PENDING_INLAY_HINTS: usize = 128;
+/// Maximum pending inlay hint requests waiting on one package key.
+const MAX_PENDING_INLAY_HINTS_PER_KEY: usize = 16;
 
 /// In-memory state for one running `leo-lsp` server instance.
 ///
@@ -169,6 +243,11 @@ struct ServerState {
     reference_requests: ReferencesRequestState,
     rename_requests: RenameRequestState,
     prepare_rename_requests: PrepareRenameRequestState,
+    hover_requests: HoverRequestState,
+    completion_requests: CompletionRequestState,
+    signature_help_requests: SignatureHelpRequestState,
+    inlay_hint_requests: InlayHintRequestState,
+    workspace_symbol_requests: WorkspaceSymbolRequestState,
     /// Diagnostic publish bookkeeping that enforces the staleness invariant.
     diagnostics: DiagnosticPublishState,
     /// Snapshot of the client's diagnostic capabilities captured at initialize.
@@ -214,6 +293,16 @@ struct AnalysisCaches {
     in_flight_packages: HashSet<PackageAnalysisKey>,
     /// Document views scheduled or running on the worker.
     in_flight_views: HashSet<DocumentViewKey>,
+    /// Last non-empty completion index per open bucket. Survives bucket
+    /// invalidation so completion keeps working while the buffer is broken.
+    completion_fallbacks: HashMap<AnalysisBucket, Arc<CompletionIndex>>,
+}
+
+/// Workspace symbol searches running on the response pool.
+#[derive(Debug, Default)]
+struct WorkspaceSymbolRequestState {
+    /// Cancellation flag for each in-flight search, keyed by request ID.
+    in_flight: HashMap<RequestId, Arc<AtomicBool>>,
 }
 
 /// Pending semantic-token requests keyed by exact document-view freshness.
@@ -243,6 +332,78 @@ struct PendingDefinitionRequest {
     query: DefinitionQuery,
 }
 
+/// Pending hover requests keyed by package analysis.
+#[derive(Debug, Default)]
+struct HoverRequestState {
+    /// Waiters grouped by package analysis, each preserving its own cursor query.
+    pending_by_package: HashMap<PackageAnalysisKey, Vec<PendingHoverRequest>>,
+    /// Reverse lookup used to remove a cancelled request from its package queue.
+    pending_owner: HashMap<RequestId, PackageAnalysisKey>,
+}
+
+/// One pending hover request with its own cursor query preserved.
+#[derive(Debug, Clone)]
+struct PendingHoverRequest {
+    /// Original LSP request ID to answer once package analysis is available.
+    id: RequestId,
+    /// Cursor and freshness state captured when the request arrived.
+    query: HoverQuery,
+}
+
+/// Pending completion requests keyed by package analysis.
+#[derive(Debug, Default)]
+struct CompletionRequestState {
+    /// Waiters grouped by package analysis, each preserving its own cursor query.
+    pending_by_package: HashMap<PackageAnalysisKey, Vec<PendingCompletionRequest>>,
+    /// Reverse lookup used to remove a cancelled request from its package queue.
+    pending_owner: HashMap<RequestId, PackageAnalysisKey>,
+}
+
+/// One pending completion request with its own cursor query preserved.
+#[derive(Debug, Clone)]
+struct PendingCompletionRequest {
+    /// Original LSP request ID to answer once package analysis is available.
+    id: RequestId,
+    /// Cursor, buffer text, and freshness state captured when the request arrived.
+    query: CompletionQuery,
+}
+
+/// Pending signature help requests keyed by package analysis.
+#[derive(Debug, Default)]
+struct SignatureHelpRequestState {
+    /// Waiters grouped by package analysis, each preserving its own cursor query.
+    pending_by_package: HashMap<PackageAnalysisKey, Vec<PendingSignatureHelpRequest>>,
+    /// Reverse lookup used to remove a cancelled request from its package queue.
+    pending_owner: HashMap<RequestId, PackageAnalysisKey>,
+}
+
+/// One pending signature help request with its own cursor query preserved.
+#[derive(Debug, Clone)]
+struct PendingSignatureHelpRequest {
+    /// Original LSP request ID to answer once package analysis is available.
+    id: RequestId,
+    /// Cursor, buffer text, and freshness state captured when the request arrived.
+    query: SignatureHelpQuery,
+}
+
+/// Pending inlay hint requests keyed by package analysis.
+#[derive(Debug, Default)]
+struct InlayHintRequestState {
+    /// Waiters grouped by package analysis, each preserving its own range query.
+    pending_by_package: HashMap<PackageAnalysisKey, Vec<PendingInlayHintRequest>>,
+    /// Reverse lookup used to remove a cancelled request from its package queue.
+    pending_owner: HashMap<RequestId, PackageAnalysisKey>,
+}
+
+/// One pending inlay hint request with its own range query preserved.
+#[derive(Debug, Clone)]
+struct PendingInlayHintRequest {
+    /// Original LSP request ID to answer once package analysis is available.
+    id: RequestId,
+    /// Range, line index, and freshness state captured when the request arrived.
+    query: InlayHintQuery,
+}
+
 /// Pending references requests keyed by package analysis.
 #[derive(Debug, Default)]
 struct ReferencesRequestState {
@@ -345,6 +506,11 @@ fn run_with_hooks(connection: Connection, hooks: TestHooks) -> Result<ExitCode>
         reference_requests: ReferencesRequestState::default(),
         rename_requests: RenameRequestState::default(),
         prepare_rename_requests: PrepareRenameRequestState::default(),
+        hover_requests: HoverRequestState::default(),
+        completion_requests: CompletionRequestState::default(),
+        signature_help_requests: SignatureHelpRequestState::default(),
+        inlay_hint_requests: InlayHintRequestState::default(),
+        workspace_symbol_requests: WorkspaceSymbolRequestState::default(),
         diagnostics: DiagnosticPublishState::default(),
         diagnostic_capabilities,
         client_definition_link_support,
@@ -458,6 +624,65 @@ impl ServerState {
                     serde_json::from_value(params).context("failed to deserialize textDocument/prepareRename")?;
                 self.handle_prepare_rename(connection, request_id, params)
             }
+            TEXT_DOCUMENT_HOVER => {
+                let params: HoverParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/hover")?;
+                self.handle_hover(connection, request_id, params)
+            }
+            TEXT_DOCUMENT_COMPLETION => {
+                let params: CompletionParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/completion")?;
+                self.handle_completion(connection, request_id, params)
+            }
+            TEXT_DOCUMENT_SIGNATURE_HELP => {
+                let params: SignatureHelpParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/signatureHelp")?;
+                self.handle_signature_help(connection, request_id, params)
+            }
+            TEXT_DOCUMENT_DOCUMENT_SYMBOL => {
+                let params: DocumentSymbolParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/documentSymbol")?;
+                self.handle_document_symbol(connection, request_id, params)
+            }
+            WORKSPACE_SYMBOL => {
+                let params: WorkspaceSymbolParams =
+                    serde_json::from_value(params).context("failed to deserialize workspace/symbol")?;
+                self.handle_workspace_symbol(request_id, params);
+                Ok(())
+            }
+            TEXT_DOCUMENT_FORMATTING => {
+                let params: DocumentFormattingParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/formatting")?;
+                let uri = params.text_document.uri;
+                self.handle_formatting(connection, request_id, &uri, format_document)
+            }
+            TEXT_DOCUMENT_RANGE_FORMATTING => {
+                let params: DocumentRangeFormattingParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/rangeFormatting")?;
+                let uri = params.text_document.uri;
+                self.handle_formatting(connection, request_id, &uri, |text, line_index| {
+                    format_range(text, line_index, params.range)
+                })
+            }
+            TEXT_DOCUMENT_ON_TYPE_FORMATTING => {
+                let params: DocumentOnTypeFormattingParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/onTypeFormatting")?;
+                let uri = params.text_document_position.text_document.uri;
+                let position = params.text_document_position.position;
+                self.handle_formatting(connection, request_id, &uri, |text, line_index| {
+                    format_on_type(text, line_index, position, &params.ch)
+                })
+            }
+            TEXT_DOCUMENT_CODE_ACTION => {
+                let params: CodeActionParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/codeAction")?;
+                self.handle_code_action(connection, request_id, params)
+            }
+            TEXT_DOCUMENT_INLAY_HINT => {
+                let params: InlayHintParams =
+                    serde_json::from_value(params).context("failed to deserialize textDocument/inlayHint")?;
+                self.handle_inlay_hint(connection, request_id, params)
+            }
             _ => {
                 tracing::debug!(method, "request is not implemented");
                 send_error_response(connection, request_id, METHOD_NOT_FOUND, "method not found")
@@ -711,6 +936,7 @@ impl ServerState {
         self.clear_diagnostics_for_did_close(connection, &uri);
         self.documents.close(&uri);
         self.scheduler.set_open_buckets(self.documents.open_buckets());
+        self.analysis.retain_completion_fallbacks(&self.documents.open_buckets());
         if let Err(error) =
             send_ok_responses(connection, self.semantic_token_requests.clear_uri(&uri), empty_response_value())
         {
@@ -722,6 +948,18 @@ impl ServerState {
         if let Err(error) = send_reference_nulls(connection, self.reference_requests.clear_uri(&uri)) {
             tracing::error!(uri = uri.as_str(), error = %error, "failed to flush references close responses");
         }
+        if let Err(error) = send_hover_nulls(connection, self.hover_requests.clear_uri(&uri)) {
+            tracing::error!(uri = uri.as_str(), error = %error, "failed to flush hover close responses");
+        }
+        if let Err(error) = send_completion_nulls(connection, self.completion_requests.clear_uri(&uri)) {
+            tracing::error!(uri = uri.as_str(), error = %error, "failed to flush completion close responses");
+        }
+        if let Err(error) = send_signature_help_nulls(connection, self.signature_help_requests.clear_uri(&uri)) {
+            tracing::error!(uri = uri.as_str(), error = %error, "failed to flush signature help close responses");
+        }
+        if let Err(error) = send_inlay_hint_nulls(connection, self.inlay_hint_requests.clear_uri(&uri)) {
+            tracing::error!(uri = uri.as_str(), error = %error, "failed to flush inlay hint close responses");
+        }
         // Rename and prepare-rename reply with `RequestCanceled` on close
         // because the user closing the document is backing out of the
         // action, not the server stating "not renameable".
@@ -787,6 +1025,37 @@ impl ServerState {
                 ErrorCode::RequestCanceled as i32,
                 "prepare-rename request cancelled",
             )
+        } else if self.hover_requests.remove_pending_request(&request_id).is_some() {
+            send_error_response(connection, request_id, ErrorCode::RequestCanceled as i32, "hover request cancelled")
+        } else if self.completion_requests.remove_pending_request(&request_id).is_some() {
+            send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "completion request cancelled",
+            )
+        } else if self.signature_help_requests.remove_pending_request(&request_id).is_some() {
+            send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "signature help request cancelled",
+            )
+        } else if self.inlay_hint_requests.remove_pending_request(&request_id).is_some() {
+            send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "inlay hint request cancelled",
+            )
+        } else if let Some(cancel) = self.workspace_symbol_requests.in_flight.remove(&request_id) {
+            cancel.store(true, Ordering::SeqCst);
+            send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "workspace symbol request cancelled",
+            )
         } else {
             Ok(())
         }
@@ -813,6 +1082,10 @@ impl ServerState {
                     self.answer_pending_references(&key);
                     self.answer_pending_prepare_renames(connection, &key);
                     self.answer_pending_renames(&key);
+                    self.answer_pending_hovers(connection, &key);
+                    self.answer_pending_completions(connection, &key);
+                    self.answer_pending_signature_helps(connection, &key);
+                    self.answer_pending_inlay_hints(connection, &key);
                     self.enqueue_pending_document_views_for_package(&key);
                     // Diagnostics are published last so any pending save
                     // marker observes the freshly cached package result.
@@ -952,6 +1225,24 @@ impl ServerState {
                     tracing::error!(error = %error, "failed to send rename response");
                 }
             }
+            ResponseCompletion::WorkspaceSymbols { id, cancel, result } => {
+                let Some(pending) = self.workspace_symbol_requests.in_flight.get(&id) else {
+                    return;
+                };
+                if !Arc::ptr_eq(pending, &cancel) {
+                    return;
+                }
+                self.workspace_symbol_requests.in_flight.remove(&id);
+                let send_result = match result {
+                    ResponseResult::Ok(value) => send_ok_response(connection, id, value),
+                    ResponseResult::InternalError(message) => {
+                        send_error_response(connection, id, INTERNAL_ERROR, message)
+                    }
+                };
+                if let Err(error) = send_result {
+                    tracing::error!(error = %error, "failed to send workspace symbol response");
+                }
+            }
         }
     }
 
@@ -1049,21 +1340,16 @@ impl ServerState {
         }
     }
 
-    /// Answer or queue one find-all-references request.
-    fn handle_references(
-        &mut self,
-        connection: &Connection,
-        request_id: RequestId,
-        params: ReferenceParams,
-    ) -> Result<()> {
-        let uri = params.text_document_position.text_document.uri;
-        let position = params.text_document_position.position;
+    /// Answer or queue one hover request.
+    fn handle_hover(&mut self, connection: &Connection, request_id: RequestId, params: HoverParams) -> Result<()> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
         let Some(document) = self.documents.open_document(&uri) else {
             return send_ok_response(connection, request_id, Value::Null);
         };
-        if document.file_path.is_none() {
+        let Some(file_path) = document.file_path.clone() else {
             return send_ok_response(connection, request_id, Value::Null);
-        }
+        };
         let Some(offset) = position_to_offset(document.line_index.as_ref(), position) else {
             return send_ok_response(connection, request_id, Value::Null);
         };
@@ -1071,50 +1357,52 @@ impl ServerState {
             return send_ok_response(connection, request_id, Value::Null);
         };
 
+        let query = HoverQuery {
+            uri: uri.clone(),
+            file_path,
+            position,
+            offset,
+            line_index: Arc::clone(&document.line_index),
+            view_key: view_key.clone(),
+        };
+
+        if let Some(package) = self.analysis.packages.get(&view_key.package) {
+            return send_ok_response(connection, request_id, hover_response_value(resolve_hover(&query, package)));
+        }
+
         if self.analysis.failed_packages.contains(&view_key.package) {
             return send_error_response(
                 connection,
                 request_id,
                 INTERNAL_ERROR,
-                "references analysis panicked; see server logs for details",
+                "hover analysis panicked; see server logs for details",
             );
         }
 
-        let package = self.analysis.packages.get(&view_key.package).cloned();
-        let query = ReferenceQuery {
-            offset,
-            position,
-            view_key: view_key.clone(),
-            include_declaration: params.context.include_declaration,
-        };
-        let cancel = Arc::new(AtomicBool::new(false));
-        let dispatched = package.is_some();
-        if !self.reference_requests.queue(query, request_id.clone(), Arc::clone(&cancel), dispatched) {
-            return send_error_response(
+        if self.hover_requests.queue(query, request_id.clone()) {
+            // Hover text is rendered into the package analysis, so waiters
+            // share the definition path's package-granularity queueing.
+            self.ensure_package_analysis(&view_key.package, &uri);
+            Ok(())
+        } else {
+            send_error_response(
                 connection,
                 request_id,
                 ErrorCode::RequestCanceled as i32,
-                "too many pending references requests",
-            );
-        }
-
-        if let Some(package) = package {
-            self.dispatch_reference_job(request_id, view_key.package, package, cancel);
-        } else {
-            self.ensure_package_analysis(&view_key.package, &uri);
+                "too many pending hover requests",
+            )
         }
-        Ok(())
     }
 
-    /// Answer or queue one prepare-rename request.
-    fn handle_prepare_rename(
+    /// Answer or queue one completion request.
+    fn handle_completion(
         &mut self,
         connection: &Connection,
         request_id: RequestId,
-        params: TextDocumentPositionParams,
+        params: CompletionParams,
     ) -> Result<()> {
-        let uri = params.text_document.uri;
-        let position = params.position;
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
         let Some(document) = self.documents.open_document(&uri) else {
             return send_ok_response(connection, request_id, Value::Null);
         };
@@ -1124,62 +1412,67 @@ impl ServerState {
         let Some(offset) = position_to_offset(document.line_index.as_ref(), position) else {
             return send_ok_response(connection, request_id, Value::Null);
         };
-        let line_index = Arc::clone(&document.line_index);
         let Some(view_key) = self.documents.document_view_key(&uri) else {
             return send_ok_response(connection, request_id, Value::Null);
         };
 
+        let query = CompletionQuery {
+            uri: uri.clone(),
+            file_path,
+            position,
+            offset,
+            text: Arc::clone(&document.text),
+            view_key: view_key.clone(),
+        };
+
+        if let Some(package) = self.analysis.packages.get(&view_key.package) {
+            let index = self.analysis.completion_index(package);
+            return send_ok_response(
+                connection,
+                request_id,
+                completion_response_value(resolve_completion(&query, &index)),
+            );
+        }
+
         if self.analysis.failed_packages.contains(&view_key.package) {
             return send_error_response(
                 connection,
                 request_id,
                 INTERNAL_ERROR,
-                "prepare-rename analysis panicked; see server logs for details",
+                "completion analysis panicked; see server logs for details",
             );
         }
 
-        let query = PrepareRenameQuery { offset, position, view_key: view_key.clone() };
-
-        if let Some(package) = self.analysis.packages.get(&view_key.package).cloned() {
-            let value =
-                prepare_rename_response_value(&query, package.as_ref(), file_path.as_ref(), line_index.as_ref());
-            return send_ok_response(connection, request_id, value);
-        }
-
-        if !self.prepare_rename_requests.queue(query, request_id.clone()) {
-            return send_error_response(
+        if self.completion_requests.queue(query, request_id.clone()) {
+            // Candidates are lowered into the package analysis, so waiters
+            // share the hover path's package-granularity queueing.
+            self.ensure_package_analysis(&view_key.package, &uri);
+            Ok(())
+        } else {
+            send_error_response(
                 connection,
                 request_id,
                 ErrorCode::RequestCanceled as i32,
-                "too many pending prepare-rename requests",
-            );
+                "too many pending completion requests",
+            )
         }
-        self.ensure_package_analysis(&view_key.package, &uri);
-        Ok(())
     }
 
-    /// Answer or queue one rename request.
-    fn handle_rename(&mut self, connection: &Connection, request_id: RequestId, params: RenameParams) -> Result<()> {
-        let uri = params.text_document_position.text_document.uri;
-        let position = params.text_document_position.position;
-        let new_name = params.new_name;
-
-        // Validate the new name synchronously before allocating any pending-state
-        // slot or pool resources. Statically unrenameable inputs reject inline.
-        if let Err(error) = validate_new_name(&new_name) {
-            let crate::features::rename::RenameError::InvalidIdentifier(message) = error else {
-                // validate_new_name only ever returns InvalidIdentifier.
-                unreachable!("validate_new_name returned non-identifier error");
-            };
-            return send_error_response(connection, request_id, REQUEST_FAILED, message);
-        }
-
+    /// Answer or queue one signature help request.
+    fn handle_signature_help(
+        &mut self,
+        connection: &Connection,
+        request_id: RequestId,
+        params: SignatureHelpParams,
+    ) -> Result<()> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
         let Some(document) = self.documents.open_document(&uri) else {
             return send_ok_response(connection, request_id, Value::Null);
         };
-        if document.file_path.is_none() {
+        let Some(file_path) = document.file_path.clone() else {
             return send_ok_response(connection, request_id, Value::Null);
-        }
+        };
         let Some(offset) = position_to_offset(document.line_index.as_ref(), position) else {
             return send_ok_response(connection, request_id, Value::Null);
         };
@@ -1187,42 +1480,383 @@ impl ServerState {
             return send_ok_response(connection, request_id, Value::Null);
         };
 
-        if self.analysis.failed_packages.contains(&view_key.package) {
-            return send_error_response(
+        let query = SignatureHelpQuery {
+            uri: uri.clone(),
+            file_path,
+            position,
+            offset,
+            text: Arc::clone(&document.text),
+            view_key: view_key.clone(),
+        };
+
+        if let Some(package) = self.analysis.packages.get(&view_key.package) {
+            let index = self.analysis.completion_index(package);
+            return send_ok_response(
                 connection,
                 request_id,
-                INTERNAL_ERROR,
-                "rename analysis panicked; see server logs for details",
+                signature_help_response_value(resolve_signature_help(&query, &index)),
             );
         }
 
-        let package = self.analysis.packages.get(&view_key.package).cloned();
-        let query = RenameQuery { offset, position, view_key: view_key.clone(), new_name };
-        let cancel = Arc::new(AtomicBool::new(false));
-        let dispatched = package.is_some();
-        if !self.rename_requests.queue(query, request_id.clone(), Arc::clone(&cancel), dispatched) {
+        if self.analysis.failed_packages.contains(&view_key.package) {
             return send_error_response(
                 connection,
                 request_id,
-                ErrorCode::RequestCanceled as i32,
-                "too many pending rename requests",
+                INTERNAL_ERROR,
+                "signature help analysis panicked; see server logs for details",
             );
         }
 
-        if let Some(package) = package {
-            self.dispatch_rename_job(request_id, view_key.package, package, cancel);
-        } else {
+        if self.signature_help_requests.queue(query, request_id.clone()) {
+            // Call signatures ride on the completion index, so waiters share
+            // the completion path's package-granularity queueing.
             self.ensure_package_analysis(&view_key.package, &uri);
-        }
-        Ok(())
-    }
-
-    /// Ensure the package and document-view analysis needed for a token request is queued.
-    fn ensure_analysis_for_view(&mut self, view_key: &DocumentViewKey) {
-        if let Some(package) = self.analysis.packages.get(&view_key.package).cloned() {
-            self.ensure_document_view(view_key, package);
+            Ok(())
         } else {
-            self.ensure_package_analysis(&view_key.package, &view_key.uri);
+            send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "too many pending signature help requests",
+            )
+        }
+    }
+
+    /// Answer or queue one inlay hint request.
+    fn handle_inlay_hint(
+        &mut self,
+        connection: &Connection,
+        request_id: RequestId,
+        params: InlayHintParams,
+    ) -> Result<()> {
+        let uri = params.text_document.uri;
+        let Some(document) = self.documents.open_document(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let Some(file_path) = document.file_path.clone() else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let Some(start) = position_to_offset(document.line_index.as_ref(), params.range.start) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        // Clients commonly request a viewport whose end lies past the last
+        // line; clamp it to the end of the buffer instead of answering empty.
+        let end = position_to_offset(document.line_index.as_ref(), params.range.end)
+            .unwrap_or_else(|| u32::try_from(document.text.len()).unwrap_or(u32::MAX));
+        let Some(view_key) = self.documents.document_view_key(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+
+        let query = InlayHintQuery {
+            uri: uri.clone(),
+            file_path,
+            start,
+            end,
+            line_index: Arc::clone(&document.line_index),
+            view_key: view_key.clone(),
+        };
+
+        if let Some(package) = self.analysis.packages.get(&view_key.package) {
+            return send_ok_response(
+                connection,
+                request_id,
+                inlay_hints_response_value(resolve_inlay_hints(&query, package)),
+            );
+        }
+
+        if self.analysis.failed_packages.contains(&view_key.package) {
+            return send_error_response(
+                connection,
+                request_id,
+                INTERNAL_ERROR,
+                "inlay hint analysis panicked; see server logs for details",
+            );
+        }
+
+        if self.inlay_hint_requests.queue(query, request_id.clone()) {
+            // Hints are rendered into the package analysis, so waiters share
+            // the hover path's package-granularity queueing.
+            self.ensure_package_analysis(&view_key.package, &uri);
+            Ok(())
+        } else {
+            send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "too many pending inlay hint requests",
+            )
+        }
+    }
+
+    /// Answer one document outline request from the open buffer's syntax tree.
+    fn handle_document_symbol(
+        &mut self,
+        connection: &Connection,
+        request_id: RequestId,
+        params: DocumentSymbolParams,
+    ) -> Result<()> {
+        let Some(document) = self.documents.open_document(&params.text_document.uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let symbols = outline(document.text.as_ref(), document.file_path.as_ref(), document.project.as_ref());
+        let symbols = document_symbols(&symbols, document.line_index.as_ref());
+        send_ok_response(connection, request_id, document_symbols_response_value(symbols))
+    }
+
+    /// Answer one formatting request against the open buffer.
+    ///
+    /// Buffers with syntax errors fail with `RequestFailed` rather than
+    /// returning edits, so a half-typed file is never rewritten.
+    fn handle_formatting(
+        &self,
+        connection: &Connection,
+        request_id: RequestId,
+        uri: &Uri,
+        format: impl FnOnce(&str, &line_index::LineIndex) -> std::result::Result<Vec<TextEdit>, String>,
+    ) -> Result<()> {
+        let Some(document) = self.documents.open_document(uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        match format(document.text.as_ref(), document.line_index.as_ref()) {
+            Ok(edits) => send_ok_response(connection, request_id, formatting_response_value(edits)),
+            Err(message) => send_error_response(connection, request_id, REQUEST_FAILED, message),
+        }
+    }
+
+    /// Answer one code action request from the cached package diagnostics.
+    ///
+    /// Quick fixes are only read from a package analysis that every open
+    /// sibling still agrees on; after an unsaved edit the response is empty
+    /// until the next analysis, so no edit targets outdated ranges.
+    fn handle_code_action(
+        &self,
+        connection: &Connection,
+        request_id: RequestId,
+        params: CodeActionParams,
+    ) -> Result<()> {
+        let uri = &params.text_document.uri;
+        let path = self.documents.open_document(uri).and_then(|document| document.file_path.clone());
+        let package = self
+            .documents
+            .package_key(uri)
+            .filter(|key| self.package_freshness_ok(key))
+            .and_then(|key| self.analysis.packages.get(&key));
+        let actions = match (path, package) {
+            (Some(path), Some(package)) => quick_fixes(
+                &package.diagnostics.entries,
+                path.as_path(),
+                params.range,
+                params.context.only.as_deref(),
+                &self.diagnostic_capabilities,
+            ),
+            _ => Vec::new(),
+        };
+        send_ok_response(connection, request_id, code_actions_response_value(actions))
+    }
+
+    /// Hand one workspace symbol search to the response pool.
+    fn handle_workspace_symbol(&mut self, request_id: RequestId, params: WorkspaceSymbolParams) {
+        let mut roots = self.workspace_roots.clone();
+        let mut open_texts = HashMap::new();
+        for (_, document) in self.documents.iter_open() {
+            if let Some(project) = document.project.as_ref() {
+                roots.push(project.package_root.as_ref().clone());
+            }
+            if let Some(path) = document.file_path.as_ref() {
+                open_texts.insert(path.as_ref().clone(), Arc::clone(&document.text));
+            }
+        }
+        roots.sort();
+        roots.dedup();
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        // A reused request ID replaces the older search; its completion fails
+        // the `Arc` identity check and is dropped.
+        if let Some(previous) = self.workspace_symbol_requests.in_flight.insert(request_id.clone(), Arc::clone(&cancel))
+        {
+            previous.store(true, Ordering::SeqCst);
+        }
+        self.response_pool.submit(ResponseJob::WorkspaceSymbols {
+            id: request_id,
+            query: Box::new(WorkspaceSymbolQuery { query: params.query, roots, open_texts }),
+            cancel,
+        });
+    }
+
+    /// Answer or queue one find-all-references request.
+    fn handle_references(
+        &mut self,
+        connection: &Connection,
+        request_id: RequestId,
+        params: ReferenceParams,
+    ) -> Result<()> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let Some(document) = self.documents.open_document(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        if document.file_path.is_none() {
+            return send_ok_response(connection, request_id, Value::Null);
+        }
+        let Some(offset) = position_to_offset(document.line_index.as_ref(), position) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let Some(view_key) = self.documents.document_view_key(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+
+        if self.analysis.failed_packages.contains(&view_key.package) {
+            return send_error_response(
+                connection,
+                request_id,
+                INTERNAL_ERROR,
+                "references analysis panicked; see server logs for details",
+            );
+        }
+
+        let package = self.analysis.packages.get(&view_key.package).cloned();
+        let query = ReferenceQuery {
+            offset,
+            position,
+            view_key: view_key.clone(),
+            include_declaration: params.context.include_declaration,
+        };
+        let cancel = Arc::new(AtomicBool::new(false));
+        let dispatched = package.is_some();
+        if !self.reference_requests.queue(query, request_id.clone(), Arc::clone(&cancel), dispatched) {
+            return send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "too many pending references requests",
+            );
+        }
+
+        if let Some(package) = package {
+            self.dispatch_reference_job(request_id, view_key.package, package, cancel);
+        } else {
+            self.ensure_package_analysis(&view_key.package, &uri);
+        }
+        Ok(())
+    }
+
+    /// Answer or queue one prepare-rename request.
+    fn handle_prepare_rename(
+        &mut self,
+        connection: &Connection,
+        request_id: RequestId,
+        params: TextDocumentPositionParams,
+    ) -> Result<()> {
+        let uri = params.text_document.uri;
+        let position = params.position;
+        let Some(document) = self.documents.open_document(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let Some(file_path) = document.file_path.clone() else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let Some(offset) = position_to_offset(document.line_index.as_ref(), position) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let line_index = Arc::clone(&document.line_index);
+        let Some(view_key) = self.documents.document_view_key(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+
+        if self.analysis.failed_packages.contains(&view_key.package) {
+            return send_error_response(
+                connection,
+                request_id,
+                INTERNAL_ERROR,
+                "prepare-rename analysis panicked; see server logs for details",
+            );
+        }
+
+        let query = PrepareRenameQuery { offset, position, view_key: view_key.clone() };
+
+        if let Some(package) = self.analysis.packages.get(&view_key.package).cloned() {
+            let value =
+                prepare_rename_response_value(&query, package.as_ref(), file_path.as_ref(), line_index.as_ref());
+            return send_ok_response(connection, request_id, value);
+        }
+
+        if !self.prepare_rename_requests.queue(query, request_id.clone()) {
+            return send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "too many pending prepare-rename requests",
+            );
+        }
+        self.ensure_package_analysis(&view_key.package, &uri);
+        Ok(())
+    }
+
+    /// Answer or queue one rename request.
+    fn handle_rename(&mut self, connection: &Connection, request_id: RequestId, params: RenameParams) -> Result<()> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let new_name = params.new_name;
+
+        // Validate the new name synchronously before allocating any pending-state
+        // slot or pool resources. Statically unrenameable inputs reject inline.
+        if let Err(error) = validate_new_name(&new_name) {
+            let crate::features::rename::RenameError::InvalidIdentifier(message) = error else {
+                // validate_new_name only ever returns InvalidIdentifier.
+                unreachable!("validate_new_name returned non-identifier error");
+            };
+            return send_error_response(connection, request_id, REQUEST_FAILED, message);
+        }
+
+        let Some(document) = self.documents.open_document(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        if document.file_path.is_none() {
+            return send_ok_response(connection, request_id, Value::Null);
+        }
+        let Some(offset) = position_to_offset(document.line_index.as_ref(), position) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+        let Some(view_key) = self.documents.document_view_key(&uri) else {
+            return send_ok_response(connection, request_id, Value::Null);
+        };
+
+        if self.analysis.failed_packages.contains(&view_key.package) {
+            return send_error_response(
+                connection,
+                request_id,
+                INTERNAL_ERROR,
+                "rename analysis panicked; see server logs for details",
+            );
+        }
+
+        let package = self.analysis.packages.get(&view_key.package).cloned();
+        let query = RenameQuery { offset, position, view_key: view_key.clone(), new_name };
+        let cancel = Arc::new(AtomicBool::new(false));
+        let dispatched = package.is_some();
+        if !self.rename_requests.queue(query, request_id.clone(), Arc::clone(&cancel), dispatched) {
+            return send_error_response(
+                connection,
+                request_id,
+                ErrorCode::RequestCanceled as i32,
+                "too many pending rename requests",
+            );
+        }
+
+        if let Some(package) = package {
+            self.dispatch_rename_job(request_id, view_key.package, package, cancel);
+        } else {
+            self.ensure_package_analysis(&view_key.package, &uri);
+        }
+        Ok(())
+    }
+
+    /// Ensure the package and document-view analysis needed for a token request is queued.
+    fn ensure_analysis_for_view(&mut self, view_key: &DocumentViewKey) {
+        if let Some(package) = self.analysis.packages.get(&view_key.package).cloned() {
+            self.ensure_document_view(view_key, package);
+        } else {
+            self.ensure_package_analysis(&view_key.package, &view_key.uri);
         }
     }
 
@@ -1279,6 +1913,60 @@ impl ServerState {
         }
     }
 
+    /// Resolve all queued hover requests waiting on one package analysis.
+    fn answer_pending_hovers(&mut self, connection: &Connection, key: &PackageAnalysisKey) {
+        let Some(package) = self.analysis.packages.get(key).cloned() else {
+            return;
+        };
+        for pending in self.hover_requests.take_package(key) {
+            let value = hover_response_value(resolve_hover(&pending.query, package.as_ref()));
+            if let Err(error) = send_ok_response(connection, pending.id, value) {
+                tracing::error!(error = %error, "failed to send hover response");
+            }
+        }
+    }
+
+    /// Resolve all queued completion requests waiting on one package analysis.
+    fn answer_pending_completions(&mut self, connection: &Connection, key: &PackageAnalysisKey) {
+        let Some(package) = self.analysis.packages.get(key) else {
+            return;
+        };
+        let index = self.analysis.completion_index(package);
+        for pending in self.completion_requests.take_package(key) {
+            let value = completion_response_value(resolve_completion(&pending.query, &index));
+            if let Err(error) = send_ok_response(connection, pending.id, value) {
+                tracing::error!(error = %error, "failed to send completion response");
+            }
+        }
+    }
+
+    /// Resolve all queued signature help requests waiting on one package analysis.
+    fn answer_pending_signature_helps(&mut self, connection: &Connection, key: &PackageAnalysisKey) {
+        let Some(package) = self.analysis.packages.get(key) else {
+            return;
+        };
+        let index = self.analysis.completion_index(package);
+        for pending in self.signature_help_requests.take_package(key) {
+            let value = signature_help_response_value(resolve_signature_help(&pending.query, &index));
+            if let Err(error) = send_ok_response(connection, pending.id, value) {
+                tracing::error!(error = %error, "failed to send signature help response");
+            }
+        }
+    }
+
+    /// Resolve all queued inlay hint requests waiting on one package analysis.
+    fn answer_pending_inlay_hints(&mut self, connection: &Connection, key: &PackageAnalysisKey) {
+        let Some(package) = self.analysis.packages.get(key).cloned() else {
+            return;
+        };
+        for pending in self.inlay_hint_requests.take_package(key) {
+            let value = inlay_hints_response_value(resolve_inlay_hints(&pending.query, package.as_ref()));
+            if let Err(error) = send_ok_response(connection, pending.id, value) {
+                tracing::error!(error = %error, "failed to send inlay hint response");
+            }
+        }
+    }
+
     /// Dispatch queued references requests unblocked by a cached package.
     fn answer_pending_references(&mut self, key: &PackageAnalysisKey) {
         let Some(package) = self.analysis.packages.get(key).cloned() else {
@@ -1462,6 +2150,37 @@ impl ServerState {
             ),
             "prepare-rename bucket waiters",
         );
+        log_drain(
+            cancel_drained(connection, self.hover_requests.drain_bucket(bucket), code, format!("hover {message}")),
+            "hover bucket waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.completion_requests.drain_bucket(bucket),
+                code,
+                format!("completion {message}"),
+            ),
+            "completion bucket waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.signature_help_requests.drain_bucket(bucket),
+                code,
+                format!("signature help {message}"),
+            ),
+            "signature help bucket waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.inlay_hint_requests.drain_bucket(bucket),
+                code,
+                format!("inlay hint {message}"),
+            ),
+            "inlay hint bucket waiters",
+        );
     }
 
     /// Fail every pending waiter tied to one package analysis key with `INTERNAL_ERROR`.
@@ -1522,6 +2241,42 @@ impl ServerState {
             ),
             "prepare-rename panic waiters",
         );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.hover_requests.drain_package(key),
+                INTERNAL_ERROR,
+                format!("hover {message}"),
+            ),
+            "hover panic waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.completion_requests.drain_package(key),
+                INTERNAL_ERROR,
+                format!("completion {message}"),
+            ),
+            "completion panic waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.signature_help_requests.drain_package(key),
+                INTERNAL_ERROR,
+                format!("signature help {message}"),
+            ),
+            "signature help panic waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.inlay_hint_requests.drain_package(key),
+                INTERNAL_ERROR,
+                format!("inlay hint {message}"),
+            ),
+            "inlay hint panic waiters",
+        );
     }
 
     /// Cancel every pending waiter tied to one package analysis key.
@@ -1537,40 +2292,71 @@ impl ServerState {
                 connection,
                 self.semantic_token_requests.drain_package(key),
                 code,
-                format!("semantic token {message}"),
+                format!("semantic token {message}"),
+            ),
+            "semantic token package waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.definition_requests.drain_package(key),
+                code,
+                format!("definition {message}"),
+            ),
+            "definition package waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.reference_requests.drain_package(key),
+                code,
+                format!("references {message}"),
+            ),
+            "references package waiters",
+        );
+        log_drain(
+            cancel_drained(connection, self.rename_requests.drain_package(key), code, format!("rename {message}")),
+            "rename package waiters",
+        );
+        log_drain(
+            cancel_drained(
+                connection,
+                self.prepare_rename_requests.drain_package(key),
+                code,
+                format!("prepare-rename {message}"),
             ),
-            "semantic token package waiters",
+            "prepare-rename package waiters",
+        );
+        log_drain(
+            cancel_drained(connection, self.hover_requests.drain_package(key), code, format!("hover {message}")),
+            "hover package waiters",
         );
         log_drain(
             cancel_drained(
                 connection,
-                self.definition_requests.drain_package(key),
+                self.completion_requests.drain_package(key),
                 code,
-                format!("definition {message}"),
+                format!("completion {message}"),
             ),
-            "definition package waiters",
+            "completion package waiters",
         );
         log_drain(
             cancel_drained(
                 connection,
-                self.reference_requests.drain_package(key),
+                self.signature_help_requests.drain_package(key),
                 code,
-                format!("references {message}"),
+                format!("signature help {message}"),
             ),
-            "references package waiters",
-        );
-        log_drain(
-            cancel_drained(connection, self.rename_requests.drain_package(key), code, format!("rename {message}")),
-            "rename package waiters",
+            "signature help package waiters",
         );
         log_drain(
             cancel_drained(
                 connection,
-                self.prepare_rename_requests.drain_package(key),
+                self.inlay_hint_requests.drain_package(key),
                 code,
-                format!("prepare-rename {message}"),
+                format!("inlay hint {message}"),
             ),
-            "prepare-rename package waiters",
+            "inlay hint package waiters",
         );
     }
 
@@ -1785,116 +2571,423 @@ impl AnalysisCaches {
         self.in_flight_views.retain(|key| &key.package.bucket != bucket);
     }
 
-    /// Return a cached document view only when the embedded key still matches.
-    fn document_view(&self, key: &DocumentViewKey) -> Option<&CachedDocumentView> {
-        self.document_views.get(&key.uri).filter(|view| &view.key == key)
+    /// Return a cached document view only when the embedded key still matches.
+    fn document_view(&self, key: &DocumentViewKey) -> Option<&CachedDocumentView> {
+        self.document_views.get(&key.uri).filter(|view| &view.key == key)
+    }
+
+    /// Store a package analysis and enforce the routing-thread LRU cap.
+    fn store_package(&mut self, package: Arc<CachedPackageAnalysis>) -> Vec<PackageAnalysisKey> {
+        self.failed_packages.remove(&package.key);
+        if !package.completions.is_empty() {
+            self.completion_fallbacks.insert(package.key.bucket.clone(), Arc::clone(&package.completions));
+        }
+        if !self.packages.contains_key(&package.key) {
+            self.package_order.push_back(package.key.clone());
+        }
+        self.packages.insert(package.key.clone(), package);
+        let mut evicted = Vec::new();
+        // Bound package analyses separately from worker stub caches. This keeps
+        // the routing thread from retaining package-sized indexes for closed or
+        // long-idle generations.
+        while self.package_order.len() > MAX_PACKAGE_CACHE_ENTRIES {
+            if let Some(oldest) = self.package_order.pop_front() {
+                self.packages.remove(&oldest);
+                self.failed_packages.remove(&oldest);
+                evicted.push(oldest);
+            }
+        }
+        evicted
+    }
+
+    /// Return the completion index for a package, falling back to the bucket's
+    /// last compiler-enhanced index when this generation did not type-check.
+    fn completion_index(&self, package: &CachedPackageAnalysis) -> Arc<CompletionIndex> {
+        if package.completions.is_empty()
+            && let Some(fallback) = self.completion_fallbacks.get(&package.key.bucket)
+        {
+            return Arc::clone(fallback);
+        }
+        Arc::clone(&package.completions)
+    }
+
+    /// Drop completion fallbacks for buckets that no longer have open documents.
+    fn retain_completion_fallbacks(&mut self, open_buckets: &HashSet<AnalysisBucket>) {
+        self.completion_fallbacks.retain(|bucket, _| open_buckets.contains(bucket));
+    }
+
+    /// Remember that current package analysis failed so repeated requests fail fast.
+    fn store_failed_package(&mut self, key: PackageAnalysisKey) {
+        self.failed_packages.retain(|failed| failed.bucket != key.bucket);
+        self.failed_packages.insert(key);
+    }
+}
+
+impl SemanticTokenRequestState {
+    /// Queue a semantic-token waiter for an exact document-view key.
+    fn queue(&mut self, key: DocumentViewKey, request_id: RequestId) {
+        self.pending_by_key.entry(key.clone()).or_default().push(request_id.clone());
+        self.pending_owner.insert(request_id, key);
+    }
+
+    /// Remove one pending semantic-token request by request ID.
+    fn remove_pending_request(&mut self, request_id: &RequestId) -> bool {
+        let Some(key) = self.pending_owner.remove(request_id) else {
+            return false;
+        };
+        if let Some(queue) = self.pending_by_key.get_mut(&key) {
+            queue.retain(|pending| pending != request_id);
+            if queue.is_empty() {
+                self.pending_by_key.remove(&key);
+            }
+        }
+        true
+    }
+
+    /// Drain every semantic-token waiter for a closed URI.
+    fn clear_uri(&mut self, uri: &Uri) -> Vec<RequestId> {
+        let keys = self.pending_by_key.keys().filter(|key| &key.uri == uri).cloned().collect::<Vec<_>>();
+        keys.into_iter().flat_map(|key| self.take_key(&key)).collect()
+    }
+
+    /// Drain every semantic-token waiter for an exact document-view key.
+    fn take_key(&mut self, key: &DocumentViewKey) -> Vec<RequestId> {
+        let Some(requests) = self.pending_by_key.remove(key) else {
+            return Vec::new();
+        };
+        for request_id in &requests {
+            self.pending_owner.remove(request_id);
+        }
+        requests
+    }
+
+    /// Drain every semantic-token waiter blocked on one package key.
+    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<RequestId> {
+        let keys = self.keys_for_package(package);
+        keys.into_iter().flat_map(|key| self.take_key(&key)).collect()
+    }
+
+    /// Drain every semantic-token waiter blocked on one analysis bucket.
+    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<RequestId> {
+        let keys = self.pending_by_key.keys().filter(|key| &key.package.bucket == bucket).cloned().collect::<Vec<_>>();
+        keys.into_iter().flat_map(|key| self.take_key(&key)).collect()
+    }
+
+    /// Return document-view keys waiting on one package key.
+    fn keys_for_package(&self, package: &PackageAnalysisKey) -> Vec<DocumentViewKey> {
+        self.pending_by_key.keys().filter(|key| &key.package == package).cloned().collect()
+    }
+}
+
+impl DefinitionRequestState {
+    /// Queue a definition request, enforcing global and per-package caps.
+    fn queue(&mut self, query: DefinitionQuery, request_id: RequestId) -> bool {
+        // Definition requests are cheap individually but can otherwise pile up
+        // behind one slow package analysis. Cap both global and per-package
+        // waiters so a client burst cannot turn into unbounded retained queries.
+        if self.pending_owner.len() >= MAX_PENDING_DEFINITIONS {
+            return false;
+        }
+        let package = query.view_key.package.clone();
+        let queue = self.pending_by_package.entry(package.clone()).or_default();
+        if queue.len() >= MAX_PENDING_DEFINITIONS_PER_KEY {
+            return false;
+        }
+        queue.push(PendingDefinitionRequest { id: request_id.clone(), query });
+        self.pending_owner.insert(request_id, package);
+        true
+    }
+
+    /// Remove one pending definition request by request ID.
+    fn remove_pending_request(&mut self, request_id: &RequestId) -> Option<PendingDefinitionRequest> {
+        let package = self.pending_owner.remove(request_id)?;
+        let queue = self.pending_by_package.get_mut(&package)?;
+        let index = queue.iter().position(|pending| &pending.id == request_id)?;
+        let pending = queue.remove(index);
+        if queue.is_empty() {
+            self.pending_by_package.remove(&package);
+        }
+        Some(pending)
+    }
+
+    /// Drain definition requests whose source document has closed.
+    fn clear_uri(&mut self, uri: &Uri) -> Vec<PendingDefinitionRequest> {
+        let packages = self.pending_by_package.keys().cloned().collect::<Vec<_>>();
+        let mut cleared = Vec::new();
+        for package in packages {
+            let Some(queue) = self.pending_by_package.get_mut(&package) else {
+                continue;
+            };
+            let mut index = 0;
+            while index < queue.len() {
+                if &queue[index].query.uri == uri {
+                    let pending = queue.remove(index);
+                    self.pending_owner.remove(&pending.id);
+                    cleared.push(pending);
+                } else {
+                    index += 1;
+                }
+            }
+            if queue.is_empty() {
+                self.pending_by_package.remove(&package);
+            }
+        }
+        cleared
+    }
+
+    /// Drain definition requests waiting on one package key.
+    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<PendingDefinitionRequest> {
+        let Some(requests) = self.pending_by_package.remove(package) else {
+            return Vec::new();
+        };
+        for request in &requests {
+            self.pending_owner.remove(&request.id);
+        }
+        requests
+    }
+
+    /// Drain definition requests waiting on any package key in a bucket.
+    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingDefinitionRequest> {
+        let packages =
+            self.pending_by_package.keys().filter(|package| &package.bucket == bucket).cloned().collect::<Vec<_>>();
+        packages.into_iter().flat_map(|package| self.take_package(&package)).collect()
+    }
+}
+
+impl HoverRequestState {
+    /// Queue a hover request, enforcing global and per-package caps.
+    fn queue(&mut self, query: HoverQuery, request_id: RequestId) -> bool {
+        if self.pending_owner.len() >= MAX_PENDING_HOVERS {
+            return false;
+        }
+        let package = query.view_key.package.clone();
+        let queue = self.pending_by_package.entry(package.clone()).or_default();
+        if queue.len() >= MAX_PENDING_HOVERS_PER_KEY {
+            return false;
+        }
+        queue.push(PendingHoverRequest { id: request_id.clone(), query });
+        self.pending_owner.insert(request_id, package);
+        true
+    }
+
+    /// Remove one pending hover request by request ID.
+    fn remove_pending_request(&mut self, request_id: &RequestId) -> Option<PendingHoverRequest> {
+        let package = self.pending_owner.remove(request_id)?;
+        let queue = self.pending_by_package.get_mut(&package)?;
+        let index = queue.iter().position(|pending| &pending.id == request_id)?;
+        let pending = queue.remove(index);
+        if queue.is_empty() {
+            self.pending_by_package.remove(&package);
+        }
+        Some(pending)
+    }
+
+    /// Drain hover requests whose source document has closed.
+    fn clear_uri(&mut self, uri: &Uri) -> Vec<PendingHoverRequest> {
+        let packages = self.pending_by_package.keys().cloned().collect::<Vec<_>>();
+        let mut cleared = Vec::new();
+        for package in packages {
+            let Some(queue) = self.pending_by_package.get_mut(&package) else {
+                continue;
+            };
+            let mut index = 0;
+            while index < queue.len() {
+                if &queue[index].query.uri == uri {
+                    let pending = queue.remove(index);
+                    self.pending_owner.remove(&pending.id);
+                    cleared.push(pending);
+                } else {
+                    index += 1;
+                }
+            }
+            if queue.is_empty() {
+                self.pending_by_package.remove(&package);
+            }
+        }
+        cleared
+    }
+
+    /// Drain hover requests waiting on one package key.
+    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<PendingHoverRequest> {
+        let Some(requests) = self.pending_by_package.remove(package) else {
+            return Vec::new();
+        };
+        for request in &requests {
+            self.pending_owner.remove(&request.id);
+        }
+        requests
+    }
+
+    /// Drain hover requests waiting on any package key in a bucket.
+    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingHoverRequest> {
+        let packages =
+            self.pending_by_package.keys().filter(|package| &package.bucket == bucket).cloned().collect::<Vec<_>>();
+        packages.into_iter().flat_map(|package| self.take_package(&package)).collect()
+    }
+}
+
+impl CompletionRequestState {
+    /// Queue a completion request, enforcing global and per-package caps.
+    fn queue(&mut self, query: CompletionQuery, request_id: RequestId) -> bool {
+        if self.pending_owner.len() >= MAX_PENDING_COMPLETIONS {
+            return false;
+        }
+        let package = query.view_key.package.clone();
+        let queue = self.pending_by_package.entry(package.clone()).or_default();
+        if queue.len() >= MAX_PENDING_COMPLETIONS_PER_KEY {
+            return false;
+        }
+        queue.push(PendingCompletionRequest { id: request_id.clone(), query });
+        self.pending_owner.insert(request_id, package);
+        true
+    }
+
+    /// Remove one pending completion request by request ID.
+    fn remove_pending_request(&mut self, request_id: &RequestId) -> Option<PendingCompletionRequest> {
+        let package = self.pending_owner.remove(request_id)?;
+        let queue = self.pending_by_package.get_mut(&package)?;
+        let index = queue.iter().position(|pending| &pending.id == request_id)?;
+        let pending = queue.remove(index);
+        if queue.is_empty() {
+            self.pending_by_package.remove(&package);
+        }
+        Some(pending)
     }
 
-    /// Store a package analysis and enforce the routing-thread LRU cap.
-    fn store_package(&mut self, package: Arc<CachedPackageAnalysis>) -> Vec<PackageAnalysisKey> {
-        self.failed_packages.remove(&package.key);
-        if !self.packages.contains_key(&package.key) {
-            self.package_order.push_back(package.key.clone());
-        }
-        self.packages.insert(package.key.clone(), package);
-        let mut evicted = Vec::new();
-        // Bound package analyses separately from worker stub caches. This keeps
-        // the routing thread from retaining package-sized indexes for closed or
-        // long-idle generations.
-        while self.package_order.len() > MAX_PACKAGE_CACHE_ENTRIES {
-            if let Some(oldest) = self.package_order.pop_front() {
-                self.packages.remove(&oldest);
-                self.failed_packages.remove(&oldest);
-                evicted.push(oldest);
+    /// Drain completion requests whose source document has closed.
+    fn clear_uri(&mut self, uri: &Uri) -> Vec<PendingCompletionRequest> {
+        let packages = self.pending_by_package.keys().cloned().collect::<Vec<_>>();
+        let mut cleared = Vec::new();
+        for package in packages {
+            let Some(queue) = self.pending_by_package.get_mut(&package) else {
+                continue;
+            };
+            let mut index = 0;
+            while index < queue.len() {
+                if &queue[index].query.uri == uri {
+                    let pending = queue.remove(index);
+                    self.pending_owner.remove(&pending.id);
+                    cleared.push(pending);
+                } else {
+                    index += 1;
+                }
+            }
+            if queue.is_empty() {
+                self.pending_by_package.remove(&package);
             }
         }
-        evicted
+        cleared
     }
 
-    /// Remember that current package analysis failed so repeated requests fail fast.
-    fn store_failed_package(&mut self, key: PackageAnalysisKey) {
-        self.failed_packages.retain(|failed| failed.bucket != key.bucket);
-        self.failed_packages.insert(key);
+    /// Drain completion requests waiting on one package key.
+    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<PendingCompletionRequest> {
+        let Some(requests) = self.pending_by_package.remove(package) else {
+            return Vec::new();
+        };
+        for request in &requests {
+            self.pending_owner.remove(&request.id);
+        }
+        requests
     }
-}
 
-impl SemanticTokenRequestState {
-    /// Queue a semantic-token waiter for an exact document-view key.
-    fn queue(&mut self, key: DocumentViewKey, request_id: RequestId) {
-        self.pending_by_key.entry(key.clone()).or_default().push(request_id.clone());
-        self.pending_owner.insert(request_id, key);
+    /// Drain completion requests waiting on any package key in a bucket.
+    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingCompletionRequest> {
+        let packages =
+            self.pending_by_package.keys().filter(|package| &package.bucket == bucket).cloned().collect::<Vec<_>>();
+        packages.into_iter().flat_map(|package| self.take_package(&package)).collect()
     }
+}
 
-    /// Remove one pending semantic-token request by request ID.
-    fn remove_pending_request(&mut self, request_id: &RequestId) -> bool {
-        let Some(key) = self.pending_owner.remove(request_id) else {
+impl SignatureHelpRequestState {
+    /// Queue a signature help request, enforcing global and per-package caps.
+    fn queue(&mut self, query: SignatureHelpQuery, request_id: RequestId) -> bool {
+        if self.pending_owner.len() >= MAX_PENDING_SIGNATURE_HELPS {
+            return false;
+        }
+        let package = query.view_key.package.clone();
+        let queue = self.pending_by_package.entry(package.clone()).or_default();
+        if queue.len() >= MAX_PENDING_SIGNATURE_HELPS_PER_KEY {
             return false;
-        };
-        if let Some(queue) = self.pending_by_key.get_mut(&key) {
-            queue.retain(|pending| pending != request_id);
-            if queue.is_empty() {
-                self.pending_by_key.remove(&key);
-            }
         }
+        queue.push(PendingSignatureHelpRequest { id: request_id.clone(), query });
+        self.pending_owner.insert(request_id, package);
         true
     }
 
-    /// Drain every semantic-token waiter for a closed URI.
-    fn clear_uri(&mut self, uri: &Uri) -> Vec<RequestId> {
-        let keys = self.pending_by_key.keys().filter(|key| &key.uri == uri).cloned().collect::<Vec<_>>();
-        keys.into_iter().flat_map(|key| self.take_key(&key)).collect()
+    /// Remove one pending signature help request by request ID.
+    fn remove_pending_request(&mut self, request_id: &RequestId) -> Option<PendingSignatureHelpRequest> {
+        let package = self.pending_owner.remove(request_id)?;
+        let queue = self.pending_by_package.get_mut(&package)?;
+        let index = queue.iter().position(|pending| &pending.id == request_id)?;
+        let pending = queue.remove(index);
+        if queue.is_empty() {
+            self.pending_by_package.remove(&package);
+        }
+        Some(pending)
     }
 
-    /// Drain every semantic-token waiter for an exact document-view key.
-    fn take_key(&mut self, key: &DocumentViewKey) -> Vec<RequestId> {
-        let Some(requests) = self.pending_by_key.remove(key) else {
+    /// Drain signature help requests whose source document has closed.
+    fn clear_uri(&mut self, uri: &Uri) -> Vec<PendingSignatureHelpRequest> {
+        let packages = self.pending_by_package.keys().cloned().collect::<Vec<_>>();
+        let mut cleared = Vec::new();
+        for package in packages {
+            let Some(queue) = self.pending_by_package.get_mut(&package) else {
+                continue;
+            };
+            let mut index = 0;
+            while index < queue.len() {
+                if &queue[index].query.uri == uri {
+                    let pending = queue.remove(index);
+                    self.pending_owner.remove(&pending.id);
+                    cleared.push(pending);
+                } else {
+                    index += 1;
+                }
+            }
+            if queue.is_empty() {
+                self.pending_by_package.remove(&package);
+            }
+        }
+        cleared
+    }
+
+    /// Drain signature help requests waiting on one package key.
+    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<PendingSignatureHelpRequest> {
+        let Some(requests) = self.pending_by_package.remove(package) else {
             return Vec::new();
         };
-        for request_id in &requests {
-            self.pending_owner.remove(request_id);
+        for request in &requests {
+            self.pending_owner.remove(&request.id);
         }
         requests
     }
 
-    /// Drain every semantic-token waiter blocked on one package key.
-    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<RequestId> {
-        let keys = self.keys_for_package(package);
-        keys.into_iter().flat_map(|key| self.take_key(&key)).collect()
-    }
-
-    /// Drain every semantic-token waiter blocked on one analysis bucket.
-    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<RequestId> {
-        let keys = self.pending_by_key.keys().filter(|key| &key.package.bucket == bucket).cloned().collect::<Vec<_>>();
-        keys.into_iter().flat_map(|key| self.take_key(&key)).collect()
-    }
-
-    /// Return document-view keys waiting on one package key.
-    fn keys_for_package(&self, package: &PackageAnalysisKey) -> Vec<DocumentViewKey> {
-        self.pending_by_key.keys().filter(|key| &key.package == package).cloned().collect()
+    /// Drain signature help requests waiting on any package key in a bucket.
+    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingSignatureHelpRequest> {
+        let packages =
+            self.pending_by_package.keys().filter(|package| &package.bucket == bucket).cloned().collect::<Vec<_>>();
+        packages.into_iter().flat_map(|package| self.take_package(&package)).collect()
     }
 }
 
-impl DefinitionRequestState {
-    /// Queue a definition request, enforcing global and per-package caps.
-    fn queue(&mut self, query: DefinitionQuery, request_id: RequestId) -> bool {
-        // Definition requests are cheap individually but can otherwise pile up
-        // behind one slow package analysis. Cap both global and per-package
-        // waiters so a client burst cannot turn into unbounded retained queries.
-        if self.pending_owner.len() >= MAX_PENDING_DEFINITIONS {
+impl InlayHintRequestState {
+    /// Queue an inlay hint request, enforcing global and per-package caps.
+    fn queue(&mut self, query: InlayHintQuery, request_id: RequestId) -> bool {
+        if self.pending_owner.len() >= MAX_PENDING_INLAY_HINTS {
             return false;
         }
         let package = query.view_key.package.clone();
         let queue = self.pending_by_package.entry(package.clone()).or_default();
-        if queue.len() >= MAX_PENDING_DEFINITIONS_PER_KEY {
+        if queue.len() >= MAX_PENDING_INLAY_HINTS_PER_KEY {
             return false;
         }
-        queue.push(PendingDefinitionRequest { id: request_id.clone(), query });
+        queue.push(PendingInlayHintRequest { id: request_id.clone(), query });
         self.pending_owner.insert(request_id, package);
         true
     }
 
-    /// Remove one pending definition request by request ID.
-    fn remove_pending_request(&mut self, request_id: &RequestId) -> Option<PendingDefinitionRequest> {
+    /// Remove one pending inlay hint request by request ID.
+    fn remove_pending_request(&mut self, request_id: &RequestId) -> Option<PendingInlayHintRequest> {
         let package = self.pending_owner.remove(request_id)?;
         let queue = self.pending_by_package.get_mut(&package)?;
         let index = queue.iter().position(|pending| &pending.id == request_id)?;
@@ -1905,8 +2998,8 @@ impl DefinitionRequestState {
         Some(pending)
     }
 
-    /// Drain definition requests whose source document has closed.
-    fn clear_uri(&mut self, uri: &Uri) -> Vec<PendingDefinitionRequest> {
+    /// Drain inlay hint requests whose source document has closed.
+    fn clear_uri(&mut self, uri: &Uri) -> Vec<PendingInlayHintRequest> {
         let packages = self.pending_by_package.keys().cloned().collect::<Vec<_>>();
         let mut cleared = Vec::new();
         for package in packages {
@@ -1930,8 +3023,8 @@ impl DefinitionRequestState {
         cleared
     }
 
-    /// Drain definition requests waiting on one package key.
-    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<PendingDefinitionRequest> {
+    /// Drain inlay hint requests waiting on one package key.
+    fn take_package(&mut self, package: &PackageAnalysisKey) -> Vec<PendingInlayHintRequest> {
         let Some(requests) = self.pending_by_package.remove(package) else {
             return Vec::new();
         };
@@ -1941,8 +3034,8 @@ impl DefinitionRequestState {
         requests
     }
 
-    /// Drain definition requests waiting on any package key in a bucket.
-    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingDefinitionRequest> {
+    /// Drain inlay hint requests waiting on any package key in a bucket.
+    fn take_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingInlayHintRequest> {
         let packages =
             self.pending_by_package.keys().filter(|package| &package.bucket == bucket).cloned().collect::<Vec<_>>();
         packages.into_iter().flat_map(|package| self.take_package(&package)).collect()
@@ -2220,6 +3313,34 @@ impl PendingRequest for PendingDefinitionRequest {
     }
 }
 
+impl PendingRequest for PendingHoverRequest {
+    /// Return the JSON-RPC request ID this hover waiter answers.
+    fn id(&self) -> &RequestId {
+        &self.id
+    }
+}
+
+impl PendingRequest for PendingCompletionRequest {
+    /// Return the JSON-RPC request ID this completion waiter answers.
+    fn id(&self) -> &RequestId {
+        &self.id
+    }
+}
+
+impl PendingRequest for PendingSignatureHelpRequest {
+    /// Return the JSON-RPC request ID this signature help waiter answers.
+    fn id(&self) -> &RequestId {
+        &self.id
+    }
+}
+
+impl PendingRequest for PendingInlayHintRequest {
+    /// Return the JSON-RPC request ID this inlay hint waiter answers.
+    fn id(&self) -> &RequestId {
+        &self.id
+    }
+}
+
 impl PendingRequest for PendingReferencesRequest {
     /// Return the JSON-RPC request ID this references waiter answers.
     fn id(&self) -> &RequestId {
@@ -2293,6 +3414,86 @@ impl PendingFeature for DefinitionRequestState {
     }
 }
 
+impl PendingFeature for HoverRequestState {
+    /// Pending hover waiters retain their original cursor query.
+    type Request = PendingHoverRequest;
+
+    /// Drain hover waiters whose source document has closed.
+    fn drain_uri(&mut self, uri: &Uri) -> Vec<PendingHoverRequest> {
+        self.clear_uri(uri)
+    }
+
+    /// Drain hover waiters blocked on one package key.
+    fn drain_package(&mut self, key: &PackageAnalysisKey) -> Vec<PendingHoverRequest> {
+        self.take_package(key)
+    }
+
+    /// Drain hover waiters blocked on one analysis bucket.
+    fn drain_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingHoverRequest> {
+        self.take_bucket(bucket)
+    }
+}
+
+impl PendingFeature for CompletionRequestState {
+    /// Pending completion waiters retain their original cursor query.
+    type Request = PendingCompletionRequest;
+
+    /// Drain completion waiters whose source document has closed.
+    fn drain_uri(&mut self, uri: &Uri) -> Vec<PendingCompletionRequest> {
+        self.clear_uri(uri)
+    }
+
+    /// Drain completion waiters blocked on one package key.
+    fn drain_package(&mut self, key: &PackageAnalysisKey) -> Vec<PendingCompletionRequest> {
+        self.take_package(key)
+    }
+
+    /// Drain completion waiters blocked on one analysis bucket.
+    fn drain_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingCompletionRequest> {
+        self.take_bucket(bucket)
+    }
+}
+
+impl PendingFeature for SignatureHelpRequestState {
+    /// Pending signature help waiters retain their original cursor query.
+    type Request = PendingSignatureHelpRequest;
+
+    /// Drain signature help waiters whose source document has closed.
+    fn drain_uri(&mut self, uri: &Uri) -> Vec<PendingSignatureHelpRequest> {
+        self.clear_uri(uri)
+    }
+
+    /// Drain signature help waiters blocked on one package key.
+    fn drain_package(&mut self, key: &PackageAnalysisKey) -> Vec<PendingSignatureHelpRequest> {
+        self.take_package(key)
+    }
+
+    /// Drain signature help waiters blocked on one analysis bucket.
+    fn drain_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingSignatureHelpRequest> {
+        self.take_bucket(bucket)
+    }
+}
+
+impl PendingFeature for InlayHintRequestState {
+    /// Pending inlay hint waiters retain their original range query.
+    type Request = PendingInlayHintRequest;
+
+    /// Drain inlay hint waiters whose source document has closed.
+    fn drain_uri(&mut self, uri: &Uri) -> Vec<PendingInlayHintRequest> {
+        self.clear_uri(uri)
+    }
+
+    /// Drain inlay hint waiters blocked on one package key.
+    fn drain_package(&mut self, key: &PackageAnalysisKey) -> Vec<PendingInlayHintRequest> {
+        self.take_package(key)
+    }
+
+    /// Drain inlay hint waiters blocked on one analysis bucket.
+    fn drain_bucket(&mut self, bucket: &AnalysisBucket) -> Vec<PendingInlayHintRequest> {
+        self.take_bucket(bucket)
+    }
+}
+
 impl PendingFeature for ReferencesRequestState {
     /// Pending references waiters carry a cancel flag observed by the response pool.
     type Request = PendingReferencesRequest;
@@ -2411,6 +3612,30 @@ fn server_capabilities() -> ServerCapabilities {
             prepare_provider: Some(true),
             work_done_progress_options: WorkDoneProgressOptions::default(),
         })),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![".".to_owned(), ":".to_owned()]),
+            ..Default::default()
+        }),
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".to_owned(), ",".to_owned()]),
+            retrigger_characters: Some(vec![")".to_owned()]),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        }),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        document_range_formatting_provider: Some(OneOf::Left(true)),
+        document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+            first_trigger_character: ON_TYPE_FIRST_TRIGGER.to_owned(),
+            more_trigger_character: Some(ON_TYPE_MORE_TRIGGERS.iter().map(|ch| (*ch).to_owned()).collect()),
+        }),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+            resolve_provider: None,
+        })),
+        inlay_hint_provider: Some(OneOf::Left(true)),
         ..Default::default()
     }
 }
@@ -2507,6 +3732,38 @@ fn send_definition_nulls(connection: &Connection, requests: Vec<PendingDefinitio
     Ok(())
 }
 
+/// Send successful `null` hover responses for requests orphaned by close.
+fn send_hover_nulls(connection: &Connection, requests: Vec<PendingHoverRequest>) -> Result<()> {
+    for request in requests {
+        send_ok_response(connection, request.id, Value::Null)?;
+    }
+    Ok(())
+}
+
+/// Send successful `null` completion responses for requests orphaned by close.
+fn send_completion_nulls(connection: &Connection, requests: Vec<PendingCompletionRequest>) -> Result<()> {
+    for request in requests {
+        send_ok_response(connection, request.id, Value::Null)?;
+    }
+    Ok(())
+}
+
+/// Send successful `null` signature help responses for requests orphaned by close.
+fn send_signature_help_nulls(connection: &Connection, requests: Vec<PendingSignatureHelpRequest>) -> Result<()> {
+    for request in requests {
+        send_ok_response(connection, request.id, Value::Null)?;
+    }
+    Ok(())
+}
+
+/// Send successful `null` inlay hint responses for requests orphaned by close.
+fn send_inlay_hint_nulls(connection: &Connection, requests: Vec<PendingInlayHintRequest>) -> Result<()> {
+    for request in requests {
+        send_ok_response(connection, request.id, Value::Null)?;
+    }
+    Ok(())
+}
+
 /// Send successful `null` references responses for requests orphaned by close.
 fn send_reference_nulls(connection: &Connection, requests: Vec<PendingReferencesRequest>) -> Result<()> {
     for request in requests {
@@ -2689,6 +3946,11 @@ mod tests {
             reference_requests: super::ReferencesRequestState::default(),
             rename_requests: super::RenameRequestState::default(),
             prepare_rename_requests: super::PrepareRenameRequestState::default(),
+            hover_requests: super::HoverRequestState::default(),
+            completion_requests: super::CompletionRequestState::default(),
+            signature_help_requests: super::SignatureHelpRequestState::default(),
+            inlay_hint_requests: super::InlayHintRequestState::default(),
+            workspace_symbol_requests: super::WorkspaceSymbolRequestState::default(),
             diagnostics: super::DiagnosticPublishState::default(),
             diagnostic_capabilities: super::DiagnosticClientCapabilitySnapshot::default(),
             client_definition_link_support: false,
@@ -2745,6 +4007,7 @@ mod tests {
     ) -> super::DiagnosticSet {
         use crate::features::diagnostics::{
             DiagnosticEntry,
+            DiagnosticFix,
             DiagnosticRange,
             DiagnosticRelatedEntry,
             DiagnosticSet,
@@ -2758,6 +4021,7 @@ mod tests {
             message: Arc::from("boom"),
             related: Arc::from(Vec::<DiagnosticRelatedEntry>::new()),
             synthetic,
+            fixes: Arc::from(Vec::<DiagnosticFix>::new()),
         };
         DiagnosticSet { key, entries: Arc::from(vec![entry]) }
     }
@@ -3119,6 +4383,11 @@ mod tests {
             reference_requests: super::ReferencesRequestState::default(),
             rename_requests: super::RenameRequestState::default(),
             prepare_rename_requests: super::PrepareRenameRequestState::default(),
+            hover_requests: super::HoverRequestState::default(),
+            completion_requests: super::CompletionRequestState::default(),
+            signature_help_requests: super::SignatureHelpRequestState::default(),
+            inlay_hint_requests: super::InlayHintRequestState::default(),
+            workspace_symbol_requests: super::WorkspaceSymbolRequestState::default(),
             diagnostics: super::DiagnosticPublishState::default(),
             diagnostic_capabilities: super::DiagnosticClientCapabilitySnapshot::default(),
             client_definition_link_support: false,
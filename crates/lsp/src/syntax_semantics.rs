@@ -116,10 +116,7 @@ fn collect_parts(
     open_overlays: &[OpenFileOverlay],
     target_mode: ProgramTargetMode,
 ) -> SyntaxSemantics {
-    let parse = match choose_syntax_parser(file_path, project) {
-        SyntaxParser::Main => parse_main(text),
-        SyntaxParser::Module => parse_module(text),
-    };
+    let parse = parse_document(text, file_path, project);
 
     let document_path = current_document_path(file_path);
     let (program_targets, fingerprints) =
@@ -134,6 +131,19 @@ fn collect_parts(
     semantics
 }
 
+/// Parse a document with the Rowan entry point that matches its role in the package.
+pub(crate) fn parse_document(
+    text: &str,
+    file_path: Option<&Arc<PathBuf>>,
+    project: Option<&Arc<ProjectContext>>,
+) -> Option<SyntaxNode> {
+    let parse = match choose_syntax_parser(file_path, project) {
+        SyntaxParser::Main => parse_main(text),
+        SyntaxParser::Module => parse_module(text),
+    };
+    parse.ok()
+}
+
 /// Choose the Rowan parser entry point that best matches this snapshot.
 fn choose_syntax_parser(file_path: Option<&Arc<PathBuf>>, project: Option<&Arc<ProjectContext>>) -> SyntaxParser {
     if let Some(project) = project {
@@ -275,7 +285,7 @@ fn add_local_dependency_program_targets(
 }
 
 /// Return the package-resolved source path for a local dependency.
-fn dependency_source_path(package_root: &Path, dependency: &Dependency) -> Option<(PathBuf, PathBuf)> {
+pub(crate) fn dependency_source_path(package_root: &Path, dependency: &Dependency) -> Option<(PathBuf, PathBuf)> {
     // Resolve workspace deps to local paths before checking location.
     let dependency = if dependency.location == Location::Workspace {
         resolve_workspace_dependency(package_root, dependency.clone()).ok()?
@@ -570,6 +580,8 @@ fn classify_syntax_token(token: &SyntaxToken) -> Option<(SemanticKind, Occurrenc
                 | SyntaxKind::CONSTRUCTOR_DEF
                 | SyntaxKind::STRUCT_DEF
                 | SyntaxKind::RECORD_DEF
+                | SyntaxKind::ENUM_DEF
+                | SyntaxKind::IMPL_DEF
                 | SyntaxKind::INTERFACE_DEF
                 | SyntaxKind::MAPPING_DEF
                 | SyntaxKind::STORAGE_DEF
@@ -589,6 +601,7 @@ fn classify_syntax_token(token: &SyntaxToken) -> Option<(SemanticKind, Occurrenc
                 | SyntaxKind::FOR_INCLUSIVE_STMT
                 | SyntaxKind::FIELD_EXPR
                 | SyntaxKind::CALL_EXPR
+                | SyntaxKind::METHOD_CALL_EXPR
                 | SyntaxKind::TYPE_PATH
                 | SyntaxKind::STRUCT_EXPR
                 | SyntaxKind::STRUCT_LOCATOR_EXPR
@@ -606,11 +619,14 @@ fn classify_syntax_token(token: &SyntaxToken) -> Option<(SemanticKind, Occurrenc
         {
             Some((SemanticKind::Function, OccurrenceRole::Declaration, false))
         }
-        SyntaxKind::STRUCT_DEF | SyntaxKind::RECORD_DEF
-            if token_after_keyword(token, &[SyntaxKind::KW_STRUCT, SyntaxKind::KW_RECORD]) =>
+        SyntaxKind::STRUCT_DEF | SyntaxKind::RECORD_DEF | SyntaxKind::ENUM_DEF
+            if token_after_keyword(token, &[SyntaxKind::KW_STRUCT, SyntaxKind::KW_RECORD, SyntaxKind::KW_ENUM]) =>
         {
             Some((SemanticKind::Type, OccurrenceRole::Declaration, false))
         }
+        SyntaxKind::IMPL_DEF if token_after_keyword(token, &[SyntaxKind::KW_IMPL]) => {
+            Some((SemanticKind::Type, OccurrenceRole::Reference, false))
+        }
         SyntaxKind::INTERFACE_DEF if token_after_keyword(token, &[SyntaxKind::KW_INTERFACE]) => {
             Some((SemanticKind::Interface, OccurrenceRole::Declaration, false))
         }
@@ -650,6 +666,9 @@ fn classify_syntax_token(token: &SyntaxToken) -> Option<(SemanticKind, Occurrenc
         SyntaxKind::CALL_EXPR if is_final_path_segment(token) => {
             Some((SemanticKind::Function, OccurrenceRole::Reference, false))
         }
+        SyntaxKind::METHOD_CALL_EXPR if token_after_keyword(token, &[SyntaxKind::DOT]) => {
+            Some((SemanticKind::Function, OccurrenceRole::Reference, false))
+        }
         SyntaxKind::TYPE_PATH | SyntaxKind::STRUCT_EXPR | SyntaxKind::STRUCT_LOCATOR_EXPR
             if is_final_path_segment(token) =>
         {
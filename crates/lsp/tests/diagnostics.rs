@@ -21,7 +21,7 @@
 //! diagnostics, edits clear stale ranges before any new publish, malformed
 //! full-sync change payloads block cached republish, and the initialize
 //! handshake advertises save support but withholds the pull-diagnostics
-//! provider.
+//! provider. Code actions are read back from the same saved diagnostics.
 
 mod common;
 
@@ -525,6 +525,155 @@ fn worker_panic_clears_bucket_diagnostics() {
     shutdown(paniced, 99);
 }
 
+/// Verifies quick fixes attached by the compiler come back as code actions after a save.
+#[test]
+fn code_actions_offer_compiler_quick_fixes() {
+    let source = concat!(
+        "program demo.aleo {\n",
+        "    fn main(a: u8) -> u32 {\n",
+        "        let b: u32 = 1u8;\n",
+        "        return a;\n",
+        "    }\n",
+        "    @noupgrade\n",
+        "    constructor() {}\n",
+        "}\n",
+    );
+    let (_tempdir, uri) = write_test_package(source);
+
+    let mut server = TestServer::spawn(&[("RUST_LOG", "debug")]);
+    initialize(&mut server);
+    open_document(&mut server, &uri, source);
+    wait_for_worker_completion(&server);
+    did_save(&mut server, &uri);
+    wait_for_diagnostics(&mut server, WAIT_TIMEOUT, has_non_empty_diagnostics)
+        .expect("expected non-empty diagnostic publish");
+
+    let request = |server: &mut TestServer, id: i64, line: u32| {
+        server.request(
+            id,
+            "textDocument/codeAction",
+            json!({
+                "textDocument": { "uri": uri },
+                "range": { "start": { "line": line, "character": 0 }, "end": { "line": line, "character": 40 } },
+                "context": { "diagnostics": [] },
+            }),
+        )
+    };
+
+    let literal = request(&mut server, 2, 2);
+    let actions = literal["result"].as_array().unwrap_or_else(|| panic!("expected actions: {literal}"));
+    assert_eq!(actions.len(), 1, "unexpected actions: {literal}");
+    assert_eq!(actions[0]["title"], json!("Change the literal suffix to `u32`"));
+    assert_eq!(actions[0]["kind"], json!("quickfix"));
+    assert_eq!(actions[0]["isPreferred"], json!(true));
+    assert_eq!(
+        actions[0]["edit"]["changes"][uri.as_str()],
+        json!([{
+            "range": { "start": { "line": 2, "character": 22 }, "end": { "line": 2, "character": 24 } },
+            "newText": "u32",
+        }])
+    );
+
+    let cast = request(&mut server, 3, 3);
+    let actions = cast["result"].as_array().unwrap_or_else(|| panic!("expected actions: {cast}"));
+    assert_eq!(actions.len(), 1, "unexpected actions: {cast}");
+    assert_eq!(actions[0]["title"], json!("Cast to `u32`"));
+    assert_eq!(actions[0]["edit"]["changes"][uri.as_str()][0]["newText"], json!("a as u32"));
+
+    let quickfix_excluded = server.request(
+        4,
+        "textDocument/codeAction",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": { "start": { "line": 2, "character": 0 }, "end": { "line": 3, "character": 0 } },
+            "context": { "diagnostics": [], "only": ["refactor"] },
+        }),
+    );
+    assert_eq!(quickfix_excluded["result"], json!([]));
+
+    shutdown(server, 99);
+}
+
+/// Verifies inlay hints cover inferred types, parameter names, const generic values, and loop counts.
+#[test]
+fn inlay_hints_render_compiler_facts() {
+    let source = concat!(
+        "program demo.aleo {\n",
+        "    const SIZE: u32 = 4u32;\n",
+        "    fn main(a: u32) -> u32 {\n",
+        "        let total = add(a, 1u32);\n",
+        "        let scaled = scale::[SIZE](total);\n",
+        "        for i: u32 in 0u32..SIZE {\n",
+        "            scaled += i;\n",
+        "        }\n",
+        "        return scaled;\n",
+        "    }\n",
+        "    @noupgrade\n",
+        "    constructor() {}\n",
+        "}\n",
+        "\n",
+        "export fn add(lhs: u32, rhs: u32) -> u32 {\n",
+        "    return lhs + rhs;\n",
+        "}\n",
+        "\n",
+        "export fn scale::[N: u32](value: u32) -> u32 {\n",
+        "    return value * N;\n",
+        "}\n",
+    );
+    let (_tempdir, uri) = write_test_package(source);
+
+    let mut server = TestServer::spawn(&[("RUST_LOG", "debug")]);
+    initialize(&mut server);
+    open_document(&mut server, &uri, source);
+    wait_for_worker_completion(&server);
+
+    // The requested end lies past the last line and is clamped to the buffer.
+    let response = server.request(
+        2,
+        "textDocument/inlayHint",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": { "start": { "line": 0, "character": 0 }, "end": { "line": 40, "character": 0 } },
+        }),
+    );
+    let hints = response["result"].as_array().unwrap_or_else(|| panic!("expected hints: {response}"));
+    let hint = |label: &str, line: u32, character: u32| {
+        hints
+            .iter()
+            .find(|hint| {
+                hint["label"] == json!(label) && hint["position"] == json!({ "line": line, "character": character })
+            })
+            .unwrap_or_else(|| panic!("missing `{label}` at {line}:{character}: {response}"))
+    };
+
+    let total = hint(": u32", 3, 17);
+    assert_eq!(total["kind"], json!(1));
+    assert_eq!(total["textEdits"][0]["newText"], json!(": u32"));
+    assert_eq!(hint("lhs:", 3, 24)["kind"], json!(2));
+    hint("rhs:", 3, 27);
+    hint(": u32", 4, 18);
+    hint("= 4u32", 4, 33);
+    hint("value:", 4, 35);
+    hint("4 iterations", 5, 33);
+    assert!(
+        hints.iter().all(|hint| hint["position"]["line"] != json!(5) || hint["label"] != json!(": u32")),
+        "annotated loop variable should not get a type hint: {response}"
+    );
+
+    // A range covering only the loop header returns only its hints.
+    let loop_only = server.request(
+        3,
+        "textDocument/inlayHint",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": { "start": { "line": 5, "character": 0 }, "end": { "line": 6, "character": 0 } },
+        }),
+    );
+    assert_eq!(loop_only["result"].as_array().map(Vec::len), Some(1), "unexpected hints: {loop_only}");
+
+    shutdown(server, 99);
+}
+
 /// Predicate: `params.diagnostics` is a non-empty array.
 fn has_non_empty_diagnostics(params: &Value) -> bool {
     params["diagnostics"].as_array().map(|entries| !entries.is_empty()).unwrap_or(false)
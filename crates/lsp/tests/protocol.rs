@@ -17,8 +17,8 @@
 //! End-to-end protocol coverage for the `leo-lsp` binary.
 //!
 //! These tests drive the server through JSON-RPC requests and notifications so
-//! lifecycle, malformed-message handling, semantic tokens, definitions, and
-//! references are validated through the same transport surface used by editors.
+//! lifecycle, malformed-message handling, semantic tokens, definitions,
+//! references, and hovers are validated through the same transport surface used by editors.
 
 mod common;
 
@@ -170,6 +170,19 @@ fn initialize_shutdown_exit_round_trip() {
     assert_eq!(initialize["result"]["capabilities"]["definitionProvider"], json!(true));
     assert_eq!(initialize["result"]["capabilities"]["referencesProvider"], json!(true));
     assert_eq!(initialize["result"]["capabilities"]["renameProvider"]["prepareProvider"], json!(true));
+    assert_eq!(initialize["result"]["capabilities"]["hoverProvider"], json!(true));
+    assert_eq!(initialize["result"]["capabilities"]["completionProvider"]["triggerCharacters"], json!([".", ":"]));
+    assert_eq!(initialize["result"]["capabilities"]["signatureHelpProvider"]["triggerCharacters"], json!(["(", ","]));
+    assert_eq!(initialize["result"]["capabilities"]["documentSymbolProvider"], json!(true));
+    assert_eq!(initialize["result"]["capabilities"]["workspaceSymbolProvider"], json!(true));
+    assert_eq!(initialize["result"]["capabilities"]["documentFormattingProvider"], json!(true));
+    assert_eq!(initialize["result"]["capabilities"]["documentRangeFormattingProvider"], json!(true));
+    assert_eq!(
+        initialize["result"]["capabilities"]["documentOnTypeFormattingProvider"],
+        json!({ "firstTriggerCharacter": "}", "moreTriggerCharacter": [";"] })
+    );
+    assert_eq!(initialize["result"]["capabilities"]["codeActionProvider"]["codeActionKinds"], json!(["quickfix"]));
+    assert_eq!(initialize["result"]["capabilities"]["inlayHintProvider"], json!(true));
     assert_eq!(
         initialize["result"]["capabilities"]["semanticTokensProvider"]["legend"]["tokenTypes"],
         json!([
@@ -1492,3 +1505,346 @@ fn rename_returns_null_for_unopened_document() {
     let (status, stderr) = server.finish();
     assert!(status.success(), "stderr:\n{stderr}");
 }
+
+/// Send a hover request and return the raw JSON response.
+fn request_hover(
+    server: &mut TestServer,
+    id: i64,
+    document_uri: &Uri,
+    source: &str,
+    needle: &str,
+    occurrence: usize,
+) -> Value {
+    server.request(
+        id,
+        "textDocument/hover",
+        json!({
+            "textDocument": { "uri": document_uri },
+            "position": position_json(source, needle, occurrence),
+        }),
+    )
+}
+
+/// Verifies hover renders function signatures with docs, inferred local types, and member layouts.
+#[test]
+fn hover_returns_signatures_types_and_doc_comments() {
+    let source = concat!(
+        "struct Point {\n",
+        "    /// Horizontal offset.\n",
+        "    x_coordinate: u32,\n",
+        "}\n\n",
+        "/// Adds the point's offset to `base`.\n",
+        "fn shift(point_value: Point, base: u32) -> u32 {\n",
+        "    return base + point_value.x_coordinate;\n",
+        "}\n\n",
+        "program demo.aleo {\n",
+        "    fn main(public seed: u32) -> u32 {\n",
+        "        let origin = Point { x_coordinate: seed };\n",
+        "        let moved = shift(origin, seed);\n",
+        "        return moved;\n",
+        "    }\n",
+        "    @noupgrade constructor() {}\n",
+        "}\n",
+    );
+    let (_tempdir, document_uri, _canonical) = write_test_package(source);
+
+    let mut server = TestServer::spawn(&[("RUST_LOG", "debug")]);
+    initialize(&mut server);
+    server.notify("initialized", json!({}));
+    open_document(&mut server, &document_uri, source);
+
+    let function = request_hover(&mut server, 2, &document_uri, source, "shift", 1);
+    assert_eq!(function["result"]["contents"]["kind"], json!("markdown"), "bad hover: {function}");
+    assert_eq!(
+        function["result"]["contents"]["value"],
+        json!(
+            "```leo\nfn shift(point_value: Point, base: u32) -> u32\n```\n\n---\n\nAdds the point's offset to `base`."
+        ),
+        "bad function hover: {function}"
+    );
+    assert_eq!(function["result"]["range"], range_json(source, "shift", 1));
+
+    let local = request_hover(&mut server, 3, &document_uri, source, "moved", 1);
+    assert_eq!(local["result"]["contents"]["value"], json!("```leo\nlet moved: u32\n```"), "bad local hover: {local}");
+
+    let input = request_hover(&mut server, 4, &document_uri, source, "seed", 2);
+    assert_eq!(
+        input["result"]["contents"]["value"],
+        json!("```leo\npublic seed: u32\n```"),
+        "bad input hover: {input}"
+    );
+
+    let member = request_hover(&mut server, 5, &document_uri, source, "x_coordinate", 1);
+    assert_eq!(
+        member["result"]["contents"]["value"],
+        json!("```leo\nstruct Point\nx_coordinate: u32\n```\n\n---\n\nHorizontal offset."),
+        "bad member hover: {member}"
+    );
+
+    let keyword = request_hover(&mut server, 6, &document_uri, source, "return", 0);
+    assert_eq!(keyword["result"], Value::Null, "keywords should not hover: {keyword}");
+
+    let shutdown = server.request(7, "shutdown", Value::Null);
+    assert_eq!(shutdown["result"], Value::Null);
+    server.notify("exit", json!({}));
+    let (status, stderr) = server.finish();
+    assert!(status.success(), "stderr:\n{stderr}");
+}
+
+/// Send one completion request at the selected occurrence of a source substring.
+fn request_completion(
+    server: &mut TestServer,
+    id: i64,
+    document_uri: &Uri,
+    source: &str,
+    needle: &str,
+    occurrence: usize,
+) -> Value {
+    server.request(
+        id,
+        "textDocument/completion",
+        json!({
+            "textDocument": { "uri": document_uri },
+            "position": position_json(source, needle, occurrence),
+        }),
+    )
+}
+
+/// Return the labels of a completion response in server order.
+fn completion_labels(response: &Value) -> Vec<String> {
+    response["result"]
+        .as_array()
+        .unwrap_or_else(|| panic!("expected completion items: {response}"))
+        .iter()
+        .map(|item| item["label"].as_str().expect("completion label").to_owned())
+        .collect()
+}
+
+/// Verifies completion offers scoped locals and items, composite members, and
+/// keeps answering from the last good analysis while the buffer is broken.
+#[test]
+fn completion_suggests_locals_members_and_items() {
+    let source = concat!(
+        "struct Point {\n",
+        "    /// Horizontal offset.\n",
+        "    x_coordinate: u32,\n",
+        "}\n\n",
+        "program demo.aleo {\n",
+        "    mapping balances: address => u64;\n\n",
+        "    fn main(public seed: u32) -> u32 {\n",
+        "        let origin = Point { x_coordinate: seed };\n",
+        "        let total = origin.x_coordinate + seed;\n",
+        "        return total;\n",
+        "    }\n",
+        "    @noupgrade constructor() {}\n",
+        "}\n",
+    );
+    let (_tempdir, document_uri, _canonical) = write_test_package(source);
+
+    let mut server = TestServer::spawn(&[("RUST_LOG", "debug")]);
+    initialize(&mut server);
+    server.notify("initialized", json!({}));
+    open_document(&mut server, &document_uri, source);
+
+    let scope = request_completion(&mut server, 2, &document_uri, source, "total;", 0);
+    let labels = completion_labels(&scope);
+    for expected in ["total", "origin", "seed", "Point", "balances", "main", "Mapping"] {
+        assert!(labels.iter().any(|label| label == expected), "missing {expected} in {labels:?}");
+    }
+    assert!(labels.iter().position(|label| label == "total") < labels.iter().position(|label| label == "Point"));
+
+    let member = request_completion(&mut server, 3, &document_uri, source, "x_coordinate + seed", 0);
+    assert_eq!(completion_labels(&member), vec!["x_coordinate".to_owned()]);
+    assert_eq!(member["result"][0]["detail"], json!("x_coordinate: u32"), "bad member: {member}");
+    assert_eq!(member["result"][0]["documentation"]["value"], json!("Horizontal offset."));
+
+    let broken = source.replace("return total;", "return origin.;");
+    server.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": document_uri, "version": 2 },
+            "contentChanges": [{ "text": broken }],
+        }),
+    );
+    let stale = request_completion(&mut server, 4, &document_uri, &broken, ";\n    }", 0);
+    assert_eq!(completion_labels(&stale), vec!["x_coordinate".to_owned()], "bad fallback: {stale}");
+
+    let shutdown = server.request(5, "shutdown", Value::Null);
+    assert_eq!(shutdown["result"], Value::Null);
+    server.notify("exit", json!({}));
+    let (status, stderr) = server.finish();
+    assert!(status.success(), "stderr:\n{stderr}");
+}
+
+/// Send one signature help request at the selected occurrence of a source substring.
+fn request_signature_help(
+    server: &mut TestServer,
+    id: i64,
+    document_uri: &Uri,
+    source: &str,
+    needle: &str,
+    occurrence: usize,
+) -> Value {
+    server.request(
+        id,
+        "textDocument/signatureHelp",
+        json!({
+            "textDocument": { "uri": document_uri },
+            "position": position_json(source, needle, occurrence),
+        }),
+    )
+}
+
+/// Verifies signature help renders monomorphized user signatures and intrinsic
+/// signatures, and tracks the argument under the cursor.
+#[test]
+fn signature_help_tracks_active_parameter() {
+    let source = concat!(
+        "/// Adds `bias` to the first value.\n",
+        "fn sum::[N: u32](values: [u32; N], bias: u32) -> u32 {\n",
+        "    return values[0u32] + bias;\n",
+        "}\n\n",
+        "program demo.aleo {\n",
+        "    fn main(public seed: u32) -> field {\n",
+        "        let total = sum::[2u32]([seed, seed], seed);\n",
+        "        return BHP256::hash_to_field(total);\n",
+        "    }\n",
+        "    @noupgrade constructor() {}\n",
+        "}\n",
+    );
+    let (_tempdir, document_uri, _canonical) = write_test_package(source);
+
+    let mut server = TestServer::spawn(&[("RUST_LOG", "debug")]);
+    initialize(&mut server);
+    server.notify("initialized", json!({}));
+    open_document(&mut server, &document_uri, source);
+
+    let user = request_signature_help(&mut server, 2, &document_uri, source, "seed);", 0);
+    let signature = &user["result"]["signatures"][0];
+    let label = signature["label"].as_str().unwrap_or_else(|| panic!("expected signature: {user}"));
+    assert_eq!(label, "fn sum::[N: u32](values: [u32; 2u32], bias: u32) -> u32");
+    assert_eq!(user["result"]["activeParameter"], json!(1));
+    assert_eq!(signature["documentation"]["value"], json!("Adds `bias` to the first value."));
+    let bias = label.find("bias: u32").expect("bias parameter") as u64;
+    assert_eq!(signature["parameters"][1]["label"], json!([bias, bias + "bias: u32".len() as u64]));
+
+    let intrinsic = request_signature_help(&mut server, 3, &document_uri, source, "total);", 0);
+    assert_eq!(
+        intrinsic["result"]["signatures"][0]["label"],
+        json!("fn BHP256::hash_to_field(value: T) -> field"),
+        "bad intrinsic: {intrinsic}"
+    );
+    assert_eq!(intrinsic["result"]["activeParameter"], json!(0));
+
+    let outside = request_signature_help(&mut server, 4, &document_uri, source, "return BHP256", 0);
+    assert_eq!(outside["result"], Value::Null);
+
+    let shutdown = server.request(5, "shutdown", Value::Null);
+    assert_eq!(shutdown["result"], Value::Null);
+    server.notify("exit", json!({}));
+    let (status, stderr) = server.finish();
+    assert!(status.success(), "stderr:\n{stderr}");
+}
+
+/// Verifies the document outline nests program items, and workspace symbol
+/// search finds items in unopened submodules with their module as container.
+#[test]
+fn document_and_workspace_symbols_cover_package() {
+    let source = concat!(
+        "program demo.aleo {\n",
+        "    struct Point {\n",
+        "        x: u32,\n",
+        "    }\n",
+        "    mapping points: address => Point;\n",
+        "    fn main(public a: u32) -> u32 {\n",
+        "        return a;\n",
+        "    }\n",
+        "    @noupgrade constructor() {}\n",
+        "}\n",
+    );
+    let (tempdir, document_uri, _canonical) = write_test_package(source);
+    let module_path = tempdir.path().join("example").join("src").join("utils.leo");
+    fs::write(&module_path, "fn scale_point(value: u32) -> u32 {\n    return value * 2u32;\n}\n")
+        .expect("write module");
+
+    let mut server = TestServer::spawn(&[("RUST_LOG", "debug")]);
+    initialize(&mut server);
+    server.notify("initialized", json!({}));
+    open_document(&mut server, &document_uri, source);
+
+    let outline = server.request(2, "textDocument/documentSymbol", json!({ "textDocument": { "uri": document_uri } }));
+    let program = &outline["result"][0];
+    assert_eq!(program["name"], json!("demo.aleo"), "bad outline: {outline}");
+    let children = program["children"].as_array().expect("program children");
+    let names = children.iter().map(|child| child["name"].as_str().unwrap_or_default()).collect::<Vec<_>>();
+    assert_eq!(names, ["Point", "points", "main", "constructor"]);
+    assert_eq!(children[0]["children"][0]["detail"], json!("x: u32"));
+    assert_eq!(children[2]["detail"], json!("fn main(public a: u32) -> u32"));
+    assert_eq!(children[2]["selectionRange"]["start"], position_json(source, "main", 0));
+
+    let search = server.request(3, "workspace/symbol", json!({ "query": "scpt" }));
+    let symbols = search["result"].as_array().unwrap_or_else(|| panic!("expected symbols: {search}"));
+    let scale = symbols.iter().find(|symbol| symbol["name"] == json!("scale_point")).expect("module function");
+    assert_eq!(scale["containerName"], json!("utils"));
+    assert_eq!(scale["location"]["uri"], json!(file_uri(&module_path.canonicalize().expect("canonical module"))));
+    assert!(symbols.iter().all(|symbol| symbol["name"] != json!("main")), "unexpected match: {search}");
+
+    let shutdown = server.request(4, "shutdown", Value::Null);
+    assert_eq!(shutdown["result"], Value::Null);
+    server.notify("exit", json!({}));
+    let (status, stderr) = server.finish();
+    assert!(status.success(), "stderr:\n{stderr}");
+}
+
+/// Verifies formatting returns edits that reproduce `leo-fmt` output and
+/// fails with `RequestFailed` instead of editing a buffer with syntax errors.
+#[test]
+fn formatting_applies_leo_fmt_and_rejects_syntax_errors() {
+    let source = concat!(
+        "program demo.aleo {\n",
+        "    @noupgrade\n",
+        "    constructor() {}\n\n",
+        "    fn main(a:u32)->u32{\n",
+        "        return a;\n",
+        "    }\n",
+        "}\n",
+    );
+    let (_tempdir, document_uri, _canonical) = write_test_package(source);
+
+    let mut server = TestServer::spawn(&[("RUST_LOG", "debug")]);
+    initialize(&mut server);
+    server.notify("initialized", json!({}));
+    open_document(&mut server, &document_uri, source);
+
+    let options = json!({ "tabSize": 4, "insertSpaces": true });
+    let formatted = server.request(
+        2,
+        "textDocument/formatting",
+        json!({ "textDocument": { "uri": document_uri }, "options": options }),
+    );
+    let edits = formatted["result"].as_array().unwrap_or_else(|| panic!("expected edits: {formatted}"));
+    assert_eq!(edits.len(), 1, "unexpected edits: {formatted}");
+    assert_eq!(edits[0]["range"], range_json(source, "    fn main(a:u32)->u32{\n", 0));
+    assert_eq!(edits[0]["newText"], json!("    fn main(a: u32) -> u32 {\n"));
+
+    let broken = "program demo.aleo {\n    fn main(a: u32) -> u32 {\n        return a\n}\n";
+    server.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": document_uri, "version": 2 },
+            "contentChanges": [{ "text": broken }],
+        }),
+    );
+    let rejected = server.request(
+        3,
+        "textDocument/formatting",
+        json!({ "textDocument": { "uri": document_uri }, "options": options }),
+    );
+    assert_eq!(rejected["error"]["code"], json!(-32803), "expected RequestFailed: {rejected}");
+
+    let shutdown = server.request(4, "shutdown", Value::Null);
+    assert_eq!(shutdown["result"], Value::Null);
+    server.notify("exit", json!({}));
+    let (status, stderr) = server.finish();
+    assert!(status.success(), "stderr:\n{stderr}");
+}
@@ -126,8 +126,14 @@ pub const ABI_FILENAME: &str = "abi.json";
 /// Name of the per-unit subdirectory holding interface ABI JSON files.
 pub const INTERFACES_DIRNAME: &str = "interfaces";
 
+/// Name of the per-unit subdirectory holding `leo test --coverage` reports.
+pub const COVERAGE_DIRNAME: &str = "coverage";
+
 pub const TESTS_DIRECTORY: &str = "tests";
 
+/// Name of the subdirectory of `tests/` holding `leo test` scenario files.
+pub const SCENARIOS_DIRNAME: &str = "scenarios";
+
 /// Maximum allowed program size in bytes.
 pub const MAX_PROGRAM_SIZE: usize =
     <snarkvm::prelude::TestnetV0 as snarkvm::prelude::Network>::MAX_PROGRAM_SIZE.last().unwrap().1;
@@ -100,6 +100,18 @@ impl Package {
         self.unit_build_directory(name).join(format!("{bare}.aleo"))
     }
 
+    /// Path to the source map for a unit's compiled bytecode: `build/<name>/<name>.aleo.map`.
+    /// Only units compiled from Leo source have one.
+    pub fn unit_source_map_path(&self, name: &str) -> PathBuf {
+        let bare = bare_unit_name(name);
+        self.unit_build_directory(name).join(format!("{bare}.aleo.map"))
+    }
+
+    /// The directory `leo test --coverage` writes a unit's coverage reports to: `build/<name>/coverage/`.
+    pub fn unit_coverage_directory(&self, name: &str) -> PathBuf {
+        self.unit_build_directory(name).join(COVERAGE_DIRNAME)
+    }
+
     /// Path to a unit's Leo ABI: `build/<name>/abi.json`.
     pub fn unit_abi_path(&self, name: &str) -> PathBuf {
         self.unit_build_directory(name).join(ABI_FILENAME)
@@ -319,6 +331,14 @@ impl Package {
         data.into_iter()
     }
 
+    /// The scenario files of the package, `tests/scenarios/*.json`, in name order.
+    pub fn scenario_files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> =
+            Self::files_with_extension(&self.tests_directory().join(SCENARIOS_DIRNAME), "json").collect();
+        files.sort();
+        files
+    }
+
     fn files_with_extension(path: &Path, extension: &'static str) -> impl Iterator<Item = PathBuf> {
         path.read_dir()
             .ok()
@@ -309,6 +309,10 @@ fn ident_to_kind(s: &str) -> SyntaxKind {
         "else" => KW_ELSE,
         "for" => KW_FOR,
         "in" => KW_IN,
+        "while" => KW_WHILE,
+        "break" => KW_BREAK,
+        "continue" => KW_CONTINUE,
+        "match" => KW_MATCH,
         "return" => KW_RETURN,
         // Declaration keywords
         "let" => KW_LET,
@@ -320,6 +324,8 @@ fn ident_to_kind(s: &str) -> SyntaxKind {
         "fn" => KW_FN,
         "Fn" => KW_FN_UPPER,
         "struct" => KW_STRUCT,
+        "enum" => KW_ENUM,
+        "impl" => KW_IMPL,
         "constructor" => KW_CONSTRUCTOR,
         "interface" => KW_INTERFACE,
         // Program structure keywords
@@ -662,6 +668,40 @@ mod tests {
         "#]]);
     }
 
+    #[test]
+    fn lex_enum_match_keywords() {
+        check_lex("enum match", expect![[r#"
+            KW_ENUM "enum"
+            WHITESPACE " "
+            KW_MATCH "match"
+            EOF ""
+        "#]]);
+    }
+
+    #[test]
+    fn lex_loop_keywords() {
+        check_lex("while break continue", expect![[r#"
+            KW_WHILE "while"
+            WHITESPACE " "
+            KW_BREAK "break"
+            WHITESPACE " "
+            KW_CONTINUE "continue"
+            EOF ""
+        "#]]);
+    }
+
+    #[test]
+    fn lex_impl_keywords() {
+        check_lex("impl self Self", expect![[r#"
+            KW_IMPL "impl"
+            WHITESPACE " "
+            KW_SELF "self"
+            WHITESPACE " "
+            KW_SELF_UPPER "Self"
+            EOF ""
+        "#]]);
+    }
+
     #[test]
     fn lex_type_keywords() {
         check_lex("u8 u16 u32 u64 u128 i8 i16 i32 i64 i128", expect![[r#"
@@ -297,7 +297,7 @@ impl Parser<'_, '_> {
         Some(m.complete(self, TERNARY_EXPR))
     }
 
-    /// Parse member access: `expr.field`, `expr.0` (tuple index), or `expr.method(args)`.
+    /// Parse member access: `expr.field`, `expr.0` (tuple index), or `expr.method[::[N]](args)`.
     fn parse_member_access(&mut self, lhs: CompletedMarker) -> Option<CompletedMarker> {
         let m = lhs.precede(self);
         self.bump_any(); // .
@@ -318,9 +318,16 @@ impl Parser<'_, '_> {
             return Some(m.complete(self, FIELD_EXPR));
         }
 
+        // Optional const generic args of a method call: `expr.method::[N](args)`.
+        let has_const_args = self.at(COLON_COLON) && self.nth(1) == L_BRACKET;
+        if has_const_args {
+            self.bump_any(); // ::
+            self.parse_const_generic_args_bracket();
+        }
+
         // If followed by `(`, this is a method call — parse args inline.
-        if self.at(L_PAREN) {
-            self.bump_any(); // (
+        if self.at(L_PAREN) || has_const_args {
+            self.expect(L_PAREN);
             if !self.at(R_PAREN) {
                 if self.parse_expr().is_none() && !self.at(R_PAREN) && !self.at(COMMA) {
                     self.error_recover("expected argument expression", EXPR_RECOVERY);
@@ -451,15 +458,12 @@ impl Parser<'_, '_> {
             // Array expression
             L_BRACKET => self.parse_array_expr(),
 
-            // Identifier, path, or struct literal
-            IDENT | KW_FINAL_UPPER => self.parse_ident_expr(opts),
+            // Identifier, path, or struct literal; `Self` names the type of an enclosing `impl` block.
+            IDENT | KW_FINAL_UPPER | KW_SELF_UPPER => self.parse_ident_expr(opts),
 
             // `self` access
             KW_SELF => self.parse_self_expr(),
 
-            // `Self` is reserved for future use
-            KW_SELF_UPPER => self.parse_self_upper_expr(),
-
             // Block expressions (block, network)
             KW_BLOCK => self.parse_block_access(),
             KW_NETWORK => self.parse_network_access(),
@@ -467,6 +471,9 @@ impl Parser<'_, '_> {
             // Async block expression: `final { ... }`
             KW_FINAL => self.parse_final_block_expr(),
 
+            // Match expression: `match x { Foo::A => 1u32, _ => 0u32 }`
+            KW_MATCH => self.parse_match(MATCH_EXPR),
+
             _ => {
                 self.error_unexpected(self.current(), &[
                     "an identifier",
@@ -820,50 +827,6 @@ impl Parser<'_, '_> {
         Some(m.complete(self, BLOCK_KW_EXPR))
     }
 
-    /// Parse a use of the reserved `Self` keyword.
-    ///
-    /// `Self` is reserved and always rejected by the AST layer, but users typically write it in
-    /// path position (`Self::foo::bar(...)`). Consume the trailing `::segment` chain and any
-    /// call parens so a single reserved-keyword error is emitted instead of a cascade of "expected
-    /// `;`, found `::`" recovery errors from the outer parser.
-    fn parse_self_upper_expr(&mut self) -> Option<CompletedMarker> {
-        let m = self.start();
-        self.bump_any(); // Self
-
-        while self.eat(COLON_COLON) {
-            if self.at(L_BRACKET) {
-                self.parse_const_generic_args_bracket();
-                break;
-            } else if self.at(LT) {
-                self.parse_const_generic_args_angle();
-                break;
-            } else if self.at(IDENT) {
-                self.bump_any();
-            } else {
-                break;
-            }
-        }
-        if self.at(L_PAREN) {
-            self.bump_any(); // (
-            if !self.at(R_PAREN) {
-                if self.parse_expr().is_none() && !self.at(R_PAREN) && !self.at(COMMA) {
-                    self.error_recover("expected argument expression", EXPR_RECOVERY);
-                }
-                while self.eat(COMMA) {
-                    if self.at(R_PAREN) {
-                        break;
-                    }
-                    if self.parse_expr().is_none() && !self.at(R_PAREN) && !self.at(COMMA) {
-                        self.error_recover("expected argument expression", EXPR_RECOVERY);
-                    }
-                }
-            }
-            self.expect(R_PAREN);
-        }
-
-        Some(m.complete(self, SELF_UPPER_EXPR))
-    }
-
     /// Parse `network.id` access.
     fn parse_network_access(&mut self) -> Option<CompletedMarker> {
         let m = self.start();
@@ -2155,6 +2118,49 @@ mod tests {
         "#]]);
     }
 
+    #[test]
+    fn parse_expr_method_call_const_args() {
+        check_expr("m.get_row::[2](x)", expect![[r#"
+            ROOT@0..17
+              METHOD_CALL_EXPR@0..17
+                PATH_EXPR@0..1
+                  IDENT@0..1 "m"
+                DOT@1..2 "."
+                IDENT@2..9 "get_row"
+                COLON_COLON@9..11 "::"
+                CONST_ARG_LIST@11..14
+                  L_BRACKET@11..12 "["
+                  LITERAL_INT@12..13
+                    INTEGER@12..13 "2"
+                  R_BRACKET@13..14 "]"
+                L_PAREN@14..15 "("
+                PATH_EXPR@15..16
+                  IDENT@15..16 "x"
+                R_PAREN@16..17 ")"
+        "#]]);
+    }
+
+    #[test]
+    fn parse_expr_self_upper_call() {
+        check_expr("Self::new(1u32)", expect![[r#"
+            ROOT@0..15
+              CALL_EXPR@0..15
+                PATH_EXPR@0..9
+                  KW_SELF_UPPER@0..4 "Self"
+                  COLON_COLON@4..6 "::"
+                  IDENT@6..9 "new"
+                L_PAREN@9..10 "("
+                LITERAL_INT@10..14
+                  INTEGER@10..14 "1u32"
+                R_PAREN@14..15 ")"
+        "#]]);
+    }
+
+    #[test]
+    fn parse_expr_self_upper_struct() {
+        check_expr_no_errors("Self { x: self.x }");
+    }
+
     // =========================================================================
     // Final Expression (1h)
     // =========================================================================
@@ -2271,4 +2277,27 @@ mod tests {
                   INTEGER@10..11 "2"
         "#]]);
     }
+
+    // =========================================================================
+    // Match Expressions
+    // =========================================================================
+
+    #[test]
+    fn parse_expr_match() {
+        check_expr_no_errors("match s { Shape::Circle(r) => r * 2u32, Shape::Empty => 0u32, _ => 1u32 }");
+        check_expr_no_errors("1u32 + match s { Shape::Circle(r) => r, _ => 0u32, }");
+    }
+
+    #[test]
+    fn parse_expr_match_missing_comma() {
+        let input = "match s { Shape::A => 1u32 Shape::B => 2u32 }";
+        let (tokens, _) = lex(input);
+        let mut parser = Parser::new(input, &tokens);
+        let root = parser.start();
+        parser.parse_expr();
+        parser.skip_trivia();
+        root.complete(&mut parser, ROOT);
+        let parse: Parse = parser.finish(vec![]);
+        assert!(parse.errors().iter().any(|e| e.message == "expected ','"), "{:#?}", parse.syntax());
+    }
 }
@@ -20,7 +20,8 @@
 //! - Imports
 //! - Program declarations
 //! - Functions, transitions, and inline functions
-//! - Structs and records
+//! - Structs, records, and enums
+//! - Impl blocks
 //! - Mappings and storage
 //! - Global constants
 
@@ -31,13 +32,15 @@ impl Parser<'_, '_> {
     /// Recovery set for struct/record fields.
     const FIELD_RECOVERY: &'static [SyntaxKind] = &[COMMA, R_BRACE, KW_PUBLIC, KW_PRIVATE, KW_CONSTANT];
     /// Tokens that can start a module-level item (for error recovery).
-    const MODULE_ITEM_RECOVERY: &'static [SyntaxKind] = &[KW_EXPORT, KW_CONST, KW_STRUCT, KW_FN, KW_FINAL, AT];
+    const MODULE_ITEM_RECOVERY: &'static [SyntaxKind] =
+        &[KW_EXPORT, KW_CONST, KW_STRUCT, KW_ENUM, KW_IMPL, KW_FN, KW_FINAL, AT];
     /// Expected items within a `program { ... }` block.
     const PROGRAM_ITEM_EXPECTED: &'static [SyntaxKind] = &[
         R_BRACE,
         AT,
         KW_RECORD,
         KW_STRUCT,
+        KW_ENUM,
         KW_FN,
         KW_FINAL,
         KW_CONST,
@@ -58,6 +61,8 @@ impl Parser<'_, '_> {
         KW_CONST,
         KW_STRUCT,
         KW_RECORD,
+        KW_ENUM,
+        KW_IMPL,
         KW_FN,
         KW_FINAL,
         KW_MAPPING,
@@ -66,6 +71,8 @@ impl Parser<'_, '_> {
         KW_INTERFACE,
         AT,
     ];
+    /// Recovery set for enum variants.
+    const VARIANT_RECOVERY: &'static [SyntaxKind] = &[COMMA, R_BRACE];
 
     /// Consume an optional visibility modifier keyword (`public`, `private`, or `constant`).
     /// Returns the keyword kind that was consumed, or `None`.
@@ -112,7 +119,8 @@ impl Parser<'_, '_> {
                 }
                 // Module-level items at top level (for module files and
                 // multi-section test files with `// --- Next Module:` separators).
-                KW_EXPORT | KW_CONST | KW_STRUCT | KW_FN | KW_FINAL | AT | KW_INTERFACE | KW_VIEW => {
+                KW_EXPORT | KW_CONST | KW_STRUCT | KW_ENUM | KW_IMPL | KW_FN | KW_FINAL | AT | KW_INTERFACE
+                | KW_VIEW => {
                     if self.parse_module_item().is_none() {
                         self.error_and_bump("expected module item");
                     }
@@ -125,6 +133,8 @@ impl Parser<'_, '_> {
                         KW_EXPORT,
                         KW_CONST,
                         KW_STRUCT,
+                        KW_ENUM,
+                        KW_IMPL,
                         KW_FN,
                         KW_FINAL,
                         KW_VIEW,
@@ -138,7 +148,7 @@ impl Parser<'_, '_> {
 
     /// Parse module-level items.
     ///
-    /// Module files contain only `const`, `struct`, and `fn` declarations
+    /// Module files contain only `const`, `struct`, `enum`, and `fn` declarations
     /// (with optional annotations). No `import` or `program` blocks.
     pub fn parse_module_items(&mut self) {
         loop {
@@ -151,13 +161,13 @@ impl Parser<'_, '_> {
             self.erroring = false;
 
             if self.parse_module_item().is_none() {
-                self.error("expected `const`, `struct`, or `fn` in module");
+                self.error("expected `const`, `struct`, `enum`, or `fn` in module");
                 self.recover(Self::MODULE_ITEM_RECOVERY);
             }
         }
     }
 
-    /// Parse a single module-level item: `const`, `struct`, `interface` or `fn`.
+    /// Parse a single module-level item: `const`, `struct`, `enum`, `impl`, `interface` or `fn`.
     ///
     /// The leading `export` becomes a child token of the resulting item.
     /// Annotations are handled inside each item parser.
@@ -167,11 +177,13 @@ impl Parser<'_, '_> {
         match head {
             KW_CONST => self.parse_global_const(),
             KW_STRUCT => self.parse_composite_def(STRUCT_DEF),
+            KW_ENUM => self.parse_enum_def(),
+            KW_IMPL => self.parse_impl_def(),
             KW_INTERFACE => self.parse_interface_def(),
             AT | KW_FN | KW_FINAL | KW_VIEW => self.parse_function_or_constructor(false),
             _ => {
                 if self.at(KW_EXPORT) {
-                    self.error("expected `fn`, `struct`, `const`, or `interface` after `export`");
+                    self.error("expected `fn`, `struct`, `enum`, `const`, or `interface` after `export`");
                     self.bump_any();
                 }
                 None
@@ -260,6 +272,9 @@ impl Parser<'_, '_> {
             AT => self.parse_function_or_constructor(true),
             KW_STRUCT => self.parse_composite_def(STRUCT_DEF),
             KW_RECORD => self.parse_composite_def(RECORD_DEF),
+            KW_ENUM => self.parse_enum_def(),
+            // Rejected by the AST converter, which asks for the block to be moved to the top level.
+            KW_IMPL => self.parse_impl_def(),
             KW_MAPPING => self.parse_mapping_def(),
             KW_STORAGE => self.parse_storage_def(),
             KW_CONST => self.parse_global_const(),
@@ -437,6 +452,103 @@ impl Parser<'_, '_> {
         }
     }
 
+    /// Parse an enum definition: `[export] enum Name { A, B(Type, ...), ... }`.
+    fn parse_enum_def(&mut self) -> Option<CompletedMarker> {
+        let m = self.start();
+        let _ = self.eat(KW_EXPORT);
+        self.bump_any(); // enum
+
+        // Name
+        self.skip_trivia();
+        if self.at(IDENT) {
+            self.bump_any();
+        } else {
+            self.error("expected enum name");
+            self.recover(Self::STRUCT_NAME_RECOVERY);
+            return Some(m.complete(self, ERROR));
+        }
+
+        // Variants
+        self.expect(L_BRACE);
+        while !self.at(R_BRACE) && !self.at_eof() {
+            // Skip trivia before starting marker so variant span starts at identifier
+            self.skip_trivia();
+            let variant = self.start();
+
+            if self.at(IDENT) {
+                self.bump_any();
+            } else {
+                variant.abandon(self);
+                self.error_recover("expected variant name", Self::VARIANT_RECOVERY);
+                if self.eat(COMMA) {
+                    continue;
+                }
+                break;
+            }
+
+            // Optional payload types: `(Type, ...)`
+            if self.eat(L_PAREN) {
+                while !self.at(R_PAREN) && !self.at_eof() {
+                    if self.parse_type().is_none() {
+                        self.error_recover("expected type", &[COMMA, R_PAREN, R_BRACE]);
+                    }
+                    if !self.eat(COMMA) {
+                        break;
+                    }
+                }
+                self.expect(R_PAREN);
+            }
+            variant.complete(self, ENUM_VARIANT);
+
+            // Comma or end of variants.
+            if !self.eat(COMMA) && !self.at(R_BRACE) && !self.at_eof() {
+                self.error("expected ','");
+                // Clear erroring so the next variant can report its own errors.
+                self.erroring = false;
+            }
+        }
+        self.expect(R_BRACE);
+
+        Some(m.complete(self, ENUM_DEF))
+    }
+
+    /// Parse an impl block: `impl Name[::[N: u32]] { fn ... }`.
+    ///
+    /// Any function item is accepted here; the AST converter rejects everything but plain `fn`s.
+    fn parse_impl_def(&mut self) -> Option<CompletedMarker> {
+        let m = self.start();
+        self.bump_any(); // impl
+
+        // Name of the extended type
+        self.skip_trivia();
+        if self.at(IDENT) {
+            self.bump_any();
+        } else {
+            self.error("expected type name");
+            self.recover(Self::STRUCT_NAME_RECOVERY);
+            return Some(m.complete(self, ERROR));
+        }
+
+        // Optional const generic parameters: ::[N: u32]
+        if self.at(COLON_COLON) && self.nth(1) == L_BRACKET {
+            self.bump_any(); // ::
+            self.parse_const_param_list();
+        }
+
+        // Associated functions
+        self.expect(L_BRACE);
+        while !self.at(R_BRACE) && !self.at_eof() {
+            // Clear error state so each function gets fresh error reporting.
+            self.erroring = false;
+            if self.parse_function_or_constructor(false).is_none() {
+                self.recover(&[AT, KW_EXPORT, KW_FN, KW_FINAL, KW_VIEW, R_BRACE]);
+            }
+        }
+        self.expect(R_BRACE);
+
+        Some(m.complete(self, IMPL_DEF))
+    }
+
     /// Parse a mapping definition: `mapping name: Key => Value;`
     fn parse_mapping_def(&mut self) -> Option<CompletedMarker> {
         let m = self.start();
@@ -693,7 +805,7 @@ impl Parser<'_, '_> {
         m.complete(self, PARENT_LIST);
     }
 
-    /// Parse a single parameter: `[visibility] name: Type`
+    /// Parse a single parameter: `[visibility] name: Type`, or the receiver `self` of a method.
     fn parse_param(&mut self) {
         let m = self.start();
         self.skip_trivia();
@@ -703,16 +815,20 @@ impl Parser<'_, '_> {
 
         // Name
         self.skip_trivia();
-        if self.at(IDENT) {
-            self.bump_any();
+        if self.eat(KW_SELF) {
+            // The type of `self` is implied by the enclosing `impl` block.
         } else {
-            self.error("expected parameter name");
-        }
+            if self.at(IDENT) {
+                self.bump_any();
+            } else {
+                self.error("expected parameter name");
+            }
 
-        // Type
-        self.expect(COLON);
-        if self.parse_type().is_none() {
-            self.error_recover("expected parameter type", PARAM_RECOVERY);
+            // Type
+            self.expect(COLON);
+            if self.parse_type().is_none() {
+                self.error_recover("expected parameter type", PARAM_RECOVERY);
+            }
         }
 
         let kind = match vis {
@@ -1688,6 +1804,109 @@ mod tests {
         "#]]);
     }
 
+    // =========================================================================
+    // Enums
+    // =========================================================================
+
+    #[test]
+    fn parse_enum_def() {
+        check_file("enum E { A, B(u8) }", expect![[r#"
+            ROOT@0..19
+              ENUM_DEF@0..19
+                KW_ENUM@0..4 "enum"
+                WHITESPACE@4..5 " "
+                IDENT@5..6 "E"
+                WHITESPACE@6..7 " "
+                L_BRACE@7..8 "{"
+                WHITESPACE@8..9 " "
+                ENUM_VARIANT@9..10
+                  IDENT@9..10 "A"
+                COMMA@10..11 ","
+                WHITESPACE@11..12 " "
+                ENUM_VARIANT@12..17
+                  IDENT@12..13 "B"
+                  L_PAREN@13..14 "("
+                  TYPE_PRIMITIVE@14..16
+                    KW_U8@14..16 "u8"
+                  R_PAREN@16..17 ")"
+                WHITESPACE@17..18 " "
+                R_BRACE@18..19 "}"
+        "#]]);
+    }
+
+    #[test]
+    fn parse_enum_in_program() {
+        check_file_no_errors(
+            "program test.aleo { enum Shape { Empty, Circle(u32), Rect(u32, u32,), } fn main() -> u8 { return 0u8; } }",
+        );
+    }
+
+    #[test]
+    fn parse_enum_missing_comma() {
+        let input = "enum E { A B }";
+        let (tokens, _) = lex(input);
+        let mut parser = Parser::new(input, &tokens);
+        let root = parser.start();
+        parser.parse_file_items();
+        root.complete(&mut parser, ROOT);
+        let parse: Parse = parser.finish(vec![]);
+        assert_eq!(parse.errors().len(), 1, "expected one error for a missing ',' between variants");
+    }
+
+    #[test]
+    fn parse_impl_def() {
+        check_file("impl P { fn x(self) -> Self { } }", expect![[r#"
+            ROOT@0..33
+              IMPL_DEF@0..33
+                KW_IMPL@0..4 "impl"
+                WHITESPACE@4..5 " "
+                IDENT@5..6 "P"
+                WHITESPACE@6..7 " "
+                L_BRACE@7..8 "{"
+                FUNCTION_DEF@8..31
+                  WHITESPACE@8..9 " "
+                  KW_FN@9..11 "fn"
+                  WHITESPACE@11..12 " "
+                  IDENT@12..13 "x"
+                  PARAM_LIST@13..19
+                    L_PAREN@13..14 "("
+                    PARAM@14..18
+                      KW_SELF@14..18 "self"
+                    R_PAREN@18..19 ")"
+                  WHITESPACE@19..20 " "
+                  ARROW@20..22 "->"
+                  WHITESPACE@22..23 " "
+                  TYPE_PATH@23..28
+                    KW_SELF_UPPER@23..27 "Self"
+                    WHITESPACE@27..28 " "
+                  BLOCK@28..31
+                    L_BRACE@28..29 "{"
+                    WHITESPACE@29..30 " "
+                    R_BRACE@30..31 "}"
+                WHITESPACE@31..32 " "
+                R_BRACE@32..33 "}"
+        "#]]);
+    }
+
+    #[test]
+    fn parse_impl_const_generic() {
+        check_file_no_errors(
+            "struct M::[N: u32] { a: [u32; N] } impl M::[N: u32] { fn sum(self, b: M::[N]) -> u32 { return 0u32; } }",
+        );
+    }
+
+    #[test]
+    fn parse_impl_missing_name() {
+        let input = "impl { fn f() {} } fn g() {}";
+        let (tokens, _) = lex(input);
+        let mut parser = Parser::new(input, &tokens);
+        let root = parser.start();
+        parser.parse_file_items();
+        root.complete(&mut parser, ROOT);
+        let parse: Parse = parser.finish(vec![]);
+        assert_eq!(parse.errors()[0].message, "expected type name");
+    }
+
     // =========================================================================
     // Annotation Error Cases (3i)
     // =========================================================================
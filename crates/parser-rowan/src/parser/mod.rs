@@ -299,6 +299,23 @@ impl<'t, 's> Parser<'t, 's> {
         &self.source[self.byte_offset..self.byte_offset + len]
     }
 
+    /// Get the text of the token `n` non-trivia tokens ahead.
+    pub fn nth_text(&self, n: usize) -> &'s str {
+        let mut offset = self.byte_offset;
+        let mut count = 0;
+        for token in &self.tokens[self.pos.min(self.tokens.len())..] {
+            let len = token.len as usize;
+            if !token.kind.is_trivia() {
+                if count == n {
+                    return &self.source[offset..offset + len];
+                }
+                count += 1;
+            }
+            offset += len;
+        }
+        ""
+    }
+
     /// Get the `TextRange` covering the current non-trivia token.
     ///
     /// Walks past trivia to find the next meaningful token and returns its
@@ -576,12 +593,29 @@ impl<'t, 's> Parser<'t, 's> {
 // =============================================================================
 
 /// Tokens that can start a statement (for recovery).
-pub(crate) const STMT_RECOVERY: &[SyntaxKind] =
-    &[KW_LET, KW_CONST, KW_RETURN, KW_IF, KW_FOR, KW_ASSERT, KW_ASSERT_EQ, KW_ASSERT_NEQ, L_BRACE, R_BRACE, SEMICOLON];
+pub(crate) const STMT_RECOVERY: &[SyntaxKind] = &[
+    KW_LET,
+    KW_CONST,
+    KW_RETURN,
+    KW_IF,
+    KW_FOR,
+    KW_WHILE,
+    KW_BREAK,
+    KW_CONTINUE,
+    KW_MATCH,
+    KW_ASSERT,
+    KW_ASSERT_EQ,
+    KW_ASSERT_NEQ,
+    L_BRACE,
+    R_BRACE,
+    SEMICOLON,
+];
 
 /// Tokens that can start a top-level item (for recovery).
-pub(crate) const ITEM_RECOVERY: &[SyntaxKind] =
-    &[KW_IMPORT, KW_PROGRAM, KW_FN, KW_STRUCT, KW_RECORD, KW_MAPPING, KW_STORAGE, KW_CONST, KW_FINAL, AT, R_BRACE];
+pub(crate) const ITEM_RECOVERY: &[SyntaxKind] = &[
+    KW_IMPORT, KW_PROGRAM, KW_FN, KW_STRUCT, KW_RECORD, KW_ENUM, KW_IMPL, KW_MAPPING, KW_STORAGE, KW_CONST, KW_FINAL,
+    AT, R_BRACE,
+];
 
 /// Tokens that indicate we should stop expression recovery.
 pub(crate) const EXPR_RECOVERY: &[SyntaxKind] = &[
@@ -595,6 +629,7 @@ pub(crate) const EXPR_RECOVERY: &[SyntaxKind] = &[
     KW_RETURN,
     KW_IF,
     KW_FOR,
+    KW_WHILE,
     KW_ASSERT,
     KW_ASSERT_EQ,
     KW_ASSERT_NEQ,
@@ -19,7 +19,7 @@
 //! This module implements parsing for all Leo statement forms:
 //! - Let and const bindings
 //! - Assignments (including compound assignments)
-//! - Control flow (if, for)
+//! - Control flow (if, for, while, match, break, continue)
 //! - Return statements
 //! - Assert statements
 //! - Expression statements
@@ -29,6 +29,8 @@ use super::{CompletedMarker, EXPR_RECOVERY, Parser, STMT_RECOVERY, expressions::
 use crate::syntax_kind::{SyntaxKind, SyntaxKind::*};
 
 impl Parser<'_, '_> {
+    /// Recovery tokens for match arms.
+    const MATCH_ARM_RECOVERY: &'static [SyntaxKind] = &[FAT_ARROW, COMMA, R_BRACE];
     /// Recovery tokens for pattern parsing.
     const PATTERN_RECOVERY: &'static [SyntaxKind] = &[COMMA, R_PAREN, COLON, EQ];
 
@@ -60,6 +62,12 @@ impl Parser<'_, '_> {
             KW_RETURN => self.parse_return_stmt(),
             KW_IF => self.parse_if_stmt(),
             KW_FOR => self.parse_for_stmt(),
+            // Only `@bound` annotates a statement; other annotations are rejected as expressions.
+            AT if self.nth(1) == IDENT && self.nth_text(1) == "bound" => self.parse_while_stmt(),
+            KW_WHILE => self.parse_while_stmt(),
+            KW_BREAK => self.parse_jump_stmt(BREAK_STMT),
+            KW_CONTINUE => self.parse_jump_stmt(CONTINUE_STMT),
+            KW_MATCH => self.parse_match(MATCH_STMT),
             KW_ASSERT => self.parse_assert_stmt(),
             KW_ASSERT_EQ => self.parse_assert_eq_stmt(),
             KW_ASSERT_NEQ => self.parse_assert_neq_stmt(),
@@ -217,6 +225,146 @@ impl Parser<'_, '_> {
         Some(m.complete(self, kind))
     }
 
+    /// Parse a bounded while statement: `@bound(N) while cond { }`
+    fn parse_while_stmt(&mut self) -> Option<CompletedMarker> {
+        let m = self.start();
+
+        // Bound annotation - required, but a missing one is reported during conversion
+        // so that the rest of the loop still parses.
+        if self.eat(AT) {
+            self.skip_trivia();
+            if self.at(IDENT) && self.current_text() == "bound" {
+                self.bump_any();
+            } else {
+                self.error_unexpected(self.current(), &["'bound'"]);
+                // Still consume the name for recovery.
+                if self.at(IDENT) {
+                    self.bump_any();
+                }
+            }
+            self.expect(L_PAREN);
+            if self.parse_expr().is_none() {
+                self.error_recover("expected loop bound", EXPR_RECOVERY);
+            }
+            self.expect(R_PAREN);
+        }
+
+        self.expect(KW_WHILE);
+
+        // Parse condition (no struct literals to avoid ambiguity)
+        if self.parse_expr_with_opts(ExprOpts::no_struct()).is_none() {
+            self.error_recover("expected condition", EXPR_RECOVERY);
+        }
+
+        // Body - recover if missing
+        if self.parse_block().is_none() && !self.at_eof() {
+            self.error_recover("expected block", STMT_RECOVERY);
+        }
+
+        Some(m.complete(self, WHILE_STMT))
+    }
+
+    /// Parse a `break;` or `continue;` statement.
+    fn parse_jump_stmt(&mut self, kind: SyntaxKind) -> Option<CompletedMarker> {
+        let m = self.start();
+        self.bump_any(); // break | continue
+        self.recover_statement_terminator();
+        Some(m.complete(self, kind))
+    }
+
+    /// Parse a match statement or expression: `match expr { Foo::A(x) => ..., _ => ... }`.
+    ///
+    /// The arms of a `MATCH_STMT` have block bodies and may be separated by commas; the arms of a
+    /// `MATCH_EXPR` have expression bodies and must be.
+    pub(crate) fn parse_match(&mut self, kind: SyntaxKind) -> Option<CompletedMarker> {
+        let m = self.start();
+        self.bump_any(); // match
+
+        // Parse the scrutinee (no struct literals, the `{` starts the arms)
+        if self.parse_expr_with_opts(ExprOpts::no_struct()).is_none() {
+            self.error_recover("expected expression", EXPR_RECOVERY);
+        }
+
+        if !self.expect(L_BRACE) {
+            return Some(m.complete(self, kind));
+        }
+
+        while !self.at(R_BRACE) && !self.at_eof() {
+            // Clear error state so each arm gets fresh error reporting.
+            self.erroring = false;
+            self.skip_trivia();
+            let arm = self.start();
+
+            self.parse_match_pattern();
+            self.expect(FAT_ARROW);
+            if kind == MATCH_STMT {
+                if self.parse_block().is_none() {
+                    self.error_recover("expected block", Self::MATCH_ARM_RECOVERY);
+                }
+            } else if self.parse_expr().is_none() {
+                self.error_recover("expected expression", Self::MATCH_ARM_RECOVERY);
+            }
+            arm.complete(self, MATCH_ARM);
+
+            if !self.eat(COMMA) && kind == MATCH_EXPR && !self.at(R_BRACE) && !self.at_eof() {
+                self.error("expected ','");
+            }
+        }
+
+        self.expect(R_BRACE);
+        Some(m.complete(self, kind))
+    }
+
+    /// Parse the pattern of a match arm: `_`, `Foo::A`, or `Foo::B(x, _)`.
+    fn parse_match_pattern(&mut self) {
+        self.skip_trivia();
+
+        match self.current() {
+            UNDERSCORE => {
+                let m = self.start();
+                self.bump_any();
+                m.complete(self, WILDCARD_PATTERN);
+            }
+            IDENT => {
+                let m = self.start();
+                self.bump_any();
+                while self.at(COLON_COLON) && self.nth(1) == IDENT {
+                    self.bump_any(); // ::
+                    self.bump_any(); // IDENT
+                }
+
+                // Payload bindings: `(a, _, c)`
+                if self.eat(L_PAREN) {
+                    while !self.at(R_PAREN) && !self.at_eof() {
+                        self.skip_trivia();
+                        match self.current() {
+                            IDENT => {
+                                let binding = self.start();
+                                self.bump_any();
+                                binding.complete(self, IDENT_PATTERN);
+                            }
+                            UNDERSCORE => {
+                                let binding = self.start();
+                                self.bump_any();
+                                binding.complete(self, WILDCARD_PATTERN);
+                            }
+                            _ => self.error_recover("expected binding", &[COMMA, R_PAREN, FAT_ARROW, R_BRACE]),
+                        }
+                        if !self.eat(COMMA) {
+                            break;
+                        }
+                    }
+                    self.expect(R_PAREN);
+                }
+
+                m.complete(self, VARIANT_PATTERN);
+            }
+            // Leave a missing pattern to the `=>` check so the arm body still parses.
+            FAT_ARROW => self.error("expected pattern"),
+            _ => self.error_recover("expected pattern", Self::MATCH_ARM_RECOVERY),
+        }
+    }
+
     /// Parse an assert statement: `assert(cond);`
     fn parse_assert_stmt(&mut self) -> Option<CompletedMarker> {
         let m = self.start();
@@ -1050,4 +1198,84 @@ mod tests {
                 SEMICOLON@6..7 ";"
         "#]]);
     }
+
+    // =========================================================================
+    // Match Statements
+    // =========================================================================
+
+    #[test]
+    fn parse_stmt_match_wildcard() {
+        check_stmt("match x { _ => {} }", expect![[r#"
+            ROOT@0..19
+              MATCH_STMT@0..19
+                KW_MATCH@0..5 "match"
+                WHITESPACE@5..6 " "
+                PATH_EXPR@6..8
+                  IDENT@6..7 "x"
+                  WHITESPACE@7..8 " "
+                L_BRACE@8..9 "{"
+                WHITESPACE@9..10 " "
+                MATCH_ARM@10..17
+                  WILDCARD_PATTERN@10..11
+                    UNDERSCORE@10..11 "_"
+                  WHITESPACE@11..12 " "
+                  FAT_ARROW@12..14 "=>"
+                  BLOCK@14..17
+                    WHITESPACE@14..15 " "
+                    L_BRACE@15..16 "{"
+                    R_BRACE@16..17 "}"
+                WHITESPACE@17..18 " "
+                R_BRACE@18..19 "}"
+        "#]]);
+    }
+
+    fn parse_stmt_errors(input: &str) -> (String, usize) {
+        let (tokens, _) = lex(input);
+        let mut parser = Parser::new(input, &tokens);
+        let root = parser.start();
+        parser.parse_stmt();
+        parser.skip_trivia();
+        root.complete(&mut parser, ROOT);
+        let parse: Parse = parser.finish(vec![]);
+        (format!("{:#?}", parse.syntax()), parse.errors().len())
+    }
+
+    #[test]
+    fn parse_stmt_match_variants() {
+        let (tree, errors) =
+            parse_stmt_errors("match s { Shape::Rect(w, _) => { return w; } Shape::Empty => {}, _ => {} }");
+        assert_eq!(errors, 0, "{tree}");
+        assert_eq!(tree.matches("MATCH_ARM@").count(), 3, "{tree}");
+        assert_eq!(tree.matches("VARIANT_PATTERN@").count(), 2, "{tree}");
+        assert!(tree.contains("IDENT_PATTERN@"), "{tree}");
+    }
+
+    #[test]
+    fn parse_stmt_match_missing_pattern() {
+        // The arm body still parses after a missing pattern.
+        let (tree, errors) = parse_stmt_errors("match s { => { return; } }");
+        assert_eq!(errors, 1, "{tree}");
+        assert!(tree.contains("BLOCK@"), "{tree}");
+    }
+
+    // =========================================================================
+    // While, Break and Continue Statements
+    // =========================================================================
+
+    #[test]
+    fn parse_stmt_while() {
+        let (tree, errors) = parse_stmt_errors("@bound(8u32) while i < n { if done { break; } continue; }");
+        assert_eq!(errors, 0, "{tree}");
+        assert!(tree.contains("WHILE_STMT@"), "{tree}");
+        assert!(tree.contains("BREAK_STMT@"), "{tree}");
+        assert!(tree.contains("CONTINUE_STMT@"), "{tree}");
+    }
+
+    #[test]
+    fn parse_stmt_while_unknown_annotation() {
+        // The loop body still parses after a misspelled bound annotation.
+        let (tree, errors) = parse_stmt_errors("@limit(8u32) while c { break; }");
+        assert_eq!(errors, 1, "{tree}");
+        assert!(tree.contains("BREAK_STMT@"), "{tree}");
+    }
 }
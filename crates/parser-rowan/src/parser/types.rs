@@ -106,8 +106,8 @@ impl Parser<'_, '_> {
             KW_DYN => self.parse_dyn_record_type(),
             // Primitive type keywords
             _ if self.at_primitive_type() => self.parse_primitive_type(),
-            // Named/Composite type: Foo, Foo::[N], program.aleo::Type
-            IDENT => self.parse_named_type(),
+            // Named/Composite type: Foo, Foo::[N], program.aleo::Type, Self
+            IDENT | KW_SELF_UPPER => self.parse_named_type(),
             _ => None,
         }
     }
@@ -302,8 +302,9 @@ impl Parser<'_, '_> {
     /// - Paths: `Foo::Bar`
     /// - Const generics: `Foo::[N]` or `Foo::<N>`
     /// - Locators: `program.aleo::Type`
+    /// - `Self`, the type extended by an enclosing `impl` block
     fn parse_named_type(&mut self) -> Option<CompletedMarker> {
-        if !self.at(IDENT) {
+        if !self.at(IDENT) && !self.at(KW_SELF_UPPER) {
             return None;
         }
 
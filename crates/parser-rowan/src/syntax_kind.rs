@@ -165,6 +165,14 @@ define_syntax_kinds! {
     KW_FOR,
     /// `in`
     KW_IN,
+    /// `while`
+    KW_WHILE,
+    /// `break`
+    KW_BREAK,
+    /// `continue`
+    KW_CONTINUE,
+    /// `match`
+    KW_MATCH,
     /// `return`
     KW_RETURN,
 
@@ -187,6 +195,10 @@ define_syntax_kinds! {
     KW_FN_UPPER,
     /// `struct`
     KW_STRUCT,
+    /// `enum`
+    KW_ENUM,
+    /// `impl`
+    KW_IMPL,
     /// `constructor`
     KW_CONSTRUCTOR,
     /// `interface`
@@ -395,6 +407,12 @@ define_syntax_kinds! {
     STRUCT_DEF,
     /// Record definition.
     RECORD_DEF,
+    /// Enum definition: `enum Foo { A, B(u32) }`
+    ENUM_DEF,
+    /// Enum variant declaration: `B(u32)`
+    ENUM_VARIANT,
+    /// Impl block: `impl Foo { fn bar(self) { } }`
+    IMPL_DEF,
     /// Struct member declaration.
     STRUCT_MEMBER,
     /// Public struct member: `public name: Type`
@@ -467,6 +485,14 @@ define_syntax_kinds! {
     FOR_STMT,
     /// Inclusive for loop: `for i in 0..=10 { }`
     FOR_INCLUSIVE_STMT,
+    /// Bounded while loop: `@bound(10) while x < y { }`
+    WHILE_STMT,
+    /// Break statement: `break;`
+    BREAK_STMT,
+    /// Continue statement: `continue;`
+    CONTINUE_STMT,
+    /// Match statement: `match x { Foo::A => { } _ => { } }`
+    MATCH_STMT,
     /// Block: `{ ... }`
     BLOCK,
     /// Assert statement: `assert(...);`
@@ -485,6 +511,10 @@ define_syntax_kinds! {
     TUPLE_PATTERN,
     /// Wildcard pattern: `_`
     WILDCARD_PATTERN,
+    /// Enum variant pattern: `Foo::A` or `Foo::B(x, _)`
+    VARIANT_PATTERN,
+    /// Match arm: `Foo::A => ...`
+    MATCH_ARM,
 
     // ==========================================================================
     // Composite Nodes - Expressions
@@ -505,6 +535,8 @@ define_syntax_kinds! {
     CAST_EXPR,
     /// Ternary expression: `a ? b : c`
     TERNARY_EXPR,
+    /// Match expression: `match x { Foo::A => 1u32, _ => 0u32 }`
+    MATCH_EXPR,
     /// Array literal: `[1, 2, 3]`
     ARRAY_EXPR,
     /// Tuple literal: `(1, 2, 3)`
@@ -527,8 +559,6 @@ define_syntax_kinds! {
     PROGRAM_REF_EXPR,
     /// Self expression: `self`
     SELF_EXPR,
-    /// `Self` expression.
-    SELF_UPPER_EXPR,
     /// Block keyword expression: `block`
     BLOCK_KW_EXPR,
     /// Network keyword expression: `network`
@@ -634,6 +664,10 @@ impl SyntaxKind {
                 | KW_ELSE
                 | KW_FOR
                 | KW_IN
+                | KW_WHILE
+                | KW_BREAK
+                | KW_CONTINUE
+                | KW_MATCH
                 | KW_RETURN
                 | KW_LET
                 | KW_CONST
@@ -643,6 +677,8 @@ impl SyntaxKind {
                 | KW_VIEW
                 | KW_FN_UPPER
                 | KW_STRUCT
+                | KW_ENUM
+                | KW_IMPL
                 | KW_CONSTRUCTOR
                 | KW_INTERFACE
                 | KW_PROGRAM
@@ -744,6 +780,7 @@ impl SyntaxKind {
                     | INDEX_EXPR
                     | CAST_EXPR
                     | TERNARY_EXPR
+                    | MATCH_EXPR
                     | ARRAY_EXPR
                     | REPEAT_EXPR
                     | TUPLE_EXPR
@@ -753,7 +790,6 @@ impl SyntaxKind {
                     | PATH_LOCATOR_EXPR
                     | PROGRAM_REF_EXPR
                     | SELF_EXPR
-                    | SELF_UPPER_EXPR
                     | BLOCK_KW_EXPR
                     | NETWORK_KW_EXPR
                     | PAREN_EXPR
@@ -775,6 +811,10 @@ impl SyntaxKind {
                 | IF_STMT
                 | FOR_STMT
                 | FOR_INCLUSIVE_STMT
+                | WHILE_STMT
+                | BREAK_STMT
+                | CONTINUE_STMT
+                | MATCH_STMT
                 | BLOCK
                 | ASSERT_STMT
                 | ASSERT_EQ_STMT
@@ -900,6 +940,10 @@ impl SyntaxKind {
             KW_ELSE => "'else'",
             KW_FOR => "'for'",
             KW_IN => "'in'",
+            KW_WHILE => "'while'",
+            KW_BREAK => "'break'",
+            KW_CONTINUE => "'continue'",
+            KW_MATCH => "'match'",
             KW_RETURN => "'return'",
 
             // Declaration keywords
@@ -911,6 +955,8 @@ impl SyntaxKind {
             KW_FN => "'fn'",
             KW_FN_UPPER => "'Fn'",
             KW_STRUCT => "'struct'",
+            KW_ENUM => "'enum'",
+            KW_IMPL => "'impl'",
             KW_CONSTRUCTOR => "'constructor'",
             KW_INTERFACE => "'interface'",
 
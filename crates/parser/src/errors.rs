@@ -180,6 +180,12 @@ pub(crate) fn reserved_identifier(name: impl Display, span: leo_span::Span) -> F
         .with_help(format!("Rename this identifier. `{name}` is reserved by the language for an upcoming feature."))
 }
 
+pub(crate) fn while_loop_missing_bound(span: leo_span::Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 59, "`while` loops must declare an iteration bound", span).with_help(
+        "Annotate the loop with its maximum number of iterations, e.g. `@bound(16u32) while cond { ... }`. The bound must be known at compile time.",
+    )
+}
+
 // Parser warnings
 
 pub(crate) fn record_prototype_redundant(record_name: impl Display, span: leo_span::Span) -> Formatted {
@@ -359,6 +359,9 @@ impl<'a> ConversionContext<'a> {
                 IDENT => {
                     path_components.push(self.to_identifier(&token));
                 }
+                KW_SELF_UPPER => {
+                    path_components.push(self.self_upper_to_identifier(&token));
+                }
                 // Skip punctuation
                 COLON_COLON | L_BRACKET | R_BRACKET | LT | GT | COMMA | INTEGER => {}
                 kind if kind.is_trivia() => {}
@@ -367,13 +370,18 @@ impl<'a> ConversionContext<'a> {
         }
 
         // Extract const arguments from CONST_ARG_LIST child node
-        let (_type_parameters, const_arguments) = self.extract_const_arg_list(node)?;
+        let (_type_parameters, mut const_arguments) = self.extract_const_arg_list(node)?;
 
         // The last component is the type name, rest are path segments.
         // Path span covers only the identifier tokens, not the const arg list.
         let name = path_components.pop().expect("TYPE_PATH should have at least one identifier");
         let path_span =
             if let Some(first) = path_components.first() { Span::new(first.span.lo, name.span.hi) } else { name.span };
+
+        // A bare `Self` in a generic `impl` block stands for the type with the block's const parameters.
+        if const_arguments.is_empty() && starts_with_self_upper(node) {
+            const_arguments = self.impl_const_arguments(node, path_span);
+        }
         let path = leo_ast::Path::new(None, path_components, name, path_span, self.builder.next_id());
         Ok(leo_ast::CompositeType { path, const_arguments }.into())
     }
@@ -550,6 +558,7 @@ impl<'a> ConversionContext<'a> {
             INDEX_EXPR => self.index_expr_to_expression(node)?,
             CAST_EXPR => self.cast_expr_to_expression(node)?,
             TERNARY_EXPR => self.ternary_expr_to_expression(node)?,
+            MATCH_EXPR => self.match_expr_to_expression(node)?,
             ARRAY_EXPR => self.array_expr_to_expression(node)?,
             REPEAT_EXPR => self.repeat_expr_to_expression(node)?,
             TUPLE_EXPR => self.tuple_expr_to_expression(node)?,
@@ -558,13 +567,14 @@ impl<'a> ConversionContext<'a> {
             PATH_EXPR => self.path_expr_to_expression(node)?,
             PATH_LOCATOR_EXPR => self.path_locator_expr_to_expression(node)?,
             PROGRAM_REF_EXPR => self.program_ref_expr_to_expression(node)?,
+            // Inside an `impl` block, `self` is the receiver of a method.
+            SELF_EXPR if enclosing_impl(node).is_some() => {
+                let name = leo_ast::Identifier { name: sym::SelfLower, span, id: self.builder.next_id() };
+                leo_ast::Path::new(None, Vec::new(), name, span, self.builder.next_id()).into()
+            }
             SELF_EXPR => self.error_removed_context_keyword(node, sym::SelfLower),
             BLOCK_KW_EXPR => self.error_removed_context_keyword(node, sym::block),
             NETWORK_KW_EXPR => self.error_removed_context_keyword(node, sym::network),
-            SELF_UPPER_EXPR => {
-                self.handler.emit_err(crate::errors::reserved_identifier("Self", self.trimmed_span(node)));
-                self.error_expression(span)
-            }
             PAREN_EXPR => {
                 // Parenthesized expression - just unwrap
                 if let Some(inner) = children(node).find(|n| n.kind().is_expression()) {
@@ -868,7 +878,12 @@ impl<'a> ConversionContext<'a> {
 
         // Extract type parameters and const arguments from CONST_ARG_LIST.
         // In the rowan CST, CONST_ARG_LIST is a child of the PATH_EXPR callee node.
-        let (type_parameters, const_arguments) = self.extract_const_arg_list(&callee_node)?;
+        let (type_parameters, mut const_arguments) = self.extract_const_arg_list(&callee_node)?;
+
+        // `Self::f(..)` in a generic `impl` block passes the block's const parameters on to `f`.
+        if starts_with_self_upper(&callee_node) {
+            const_arguments.splice(0..0, self.impl_const_arguments(&callee_node, function.span()));
+        }
 
         // If the path has exactly one qualifier (e.g. `group::to_x_coordinate`),
         // try to canonicalize to an intrinsic. Non-intrinsic qualified calls
@@ -1051,6 +1066,20 @@ impl<'a> ConversionContext<'a> {
         // Remaining expression children are the arguments.
         let mut args: Vec<_> = expr_children.map(|n| self.to_expression(&n)).collect::<Result<Vec<_>>>()?;
 
+        // Const arguments, as in `m.row::[2]()`, can only be passed to a method of the receiver's type.
+        let (_type_parameters, const_arguments) = self.extract_const_arg_list(node)?;
+        if !const_arguments.is_empty() {
+            return Ok(leo_ast::MethodCallExpression {
+                receiver,
+                name: method_name,
+                const_arguments,
+                arguments: args,
+                span,
+                id,
+            }
+            .into());
+        }
+
         // Check for known methods that map to unary/binary operations or intrinsics
         if args.is_empty() {
             if let Some(op) = leo_ast::UnaryOperation::from_symbol(method_name.name) {
@@ -1098,6 +1127,20 @@ impl<'a> ConversionContext<'a> {
             }
         }
 
+        // Any other name is a method of the receiver's type, which is resolved by the type checker.
+        // Literals have no such methods, and an error placeholder name has already been reported.
+        if !matches!(receiver, leo_ast::Expression::Literal(_)) && method_name.name != Symbol::intern("_error") {
+            return Ok(leo_ast::MethodCallExpression {
+                receiver,
+                name: method_name,
+                const_arguments,
+                arguments: args,
+                span,
+                id,
+            }
+            .into());
+        }
+
         // Unknown method call - emit error
         self.handler.emit_err(crate::errors::invalid_method_call(receiver, method_name, args.len(), span));
         Ok(self.error_expression(span))
@@ -1144,7 +1187,8 @@ impl<'a> ConversionContext<'a> {
         // Get the inner expression and its CST kind (used for special-access dispatch).
         let (inner, first_child_kind) = match children(node).find(|n| n.kind().is_expression()) {
             Some(n) => {
-                let kind = n.kind();
+                // Inside an `impl` block, `self.x` accesses a field of the receiver like any other value.
+                let kind = if n.kind() == SELF_EXPR && enclosing_impl(&n).is_some() { PATH_EXPR } else { n.kind() };
                 let lowered = if matches!(kind, SELF_EXPR | BLOCK_KW_EXPR | NETWORK_KW_EXPR) {
                     self.error_expression(self.trimmed_span(&n))
                 } else {
@@ -1269,6 +1313,31 @@ impl<'a> ConversionContext<'a> {
         Ok(leo_ast::CastExpression { expression, type_, span, id }.into())
     }
 
+    /// Convert a MATCH_EXPR node to a MatchExpression.
+    fn match_expr_to_expression(&self, node: &SyntaxNode) -> Result<leo_ast::Expression> {
+        debug_assert_eq!(node.kind(), MATCH_EXPR);
+        let span = self.content_span(node);
+        let id = self.builder.next_id();
+
+        let scrutinee = self.require_expression(node, "scrutinee in match expression")?;
+
+        let arms = children(node)
+            .filter(|n| n.kind() == MATCH_ARM)
+            .map(|arm| {
+                let pattern = self.match_arm_pattern(&arm);
+                let expression = self.require_expression(&arm, "expression in match arm")?;
+                Ok(leo_ast::MatchArm {
+                    pattern,
+                    expression,
+                    span: self.non_trivia_span(&arm),
+                    id: self.builder.next_id(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(leo_ast::MatchExpression { scrutinee, arms, span, id }.into())
+    }
+
     /// Convert a TERNARY_EXPR node to a TernaryExpression.
     fn ternary_expr_to_expression(&self, node: &SyntaxNode) -> Result<leo_ast::Expression> {
         debug_assert_eq!(node.kind(), TERNARY_EXPR);
@@ -1389,7 +1458,10 @@ impl<'a> ConversionContext<'a> {
             .and_then(|n| children(&n).find(|c| c.kind().is_expression()))
             .map(|n| self.to_expression(&n).map(Box::new))
             .transpose()?;
-        let (_type_parameters, const_arguments) = self.extract_const_arg_list(node)?;
+        let (_type_parameters, mut const_arguments) = self.extract_const_arg_list(node)?;
+        if const_arguments.is_empty() && starts_with_self_upper(node) {
+            const_arguments = self.impl_const_arguments(node, path.span());
+        }
         Ok(leo_ast::CompositeExpression { path, const_arguments, members, base, span, id }.into())
     }
 
@@ -1412,8 +1484,10 @@ impl<'a> ConversionContext<'a> {
             if token.kind() == L_BRACE {
                 break;
             }
-            if token.kind() == IDENT {
-                path_components.push(self.to_identifier(&token));
+            match token.kind() {
+                IDENT => path_components.push(self.to_identifier(&token)),
+                KW_SELF_UPPER => path_components.push(self.self_upper_to_identifier(&token)),
+                _ => {}
             }
         }
 
@@ -1576,6 +1650,46 @@ impl<'a> ConversionContext<'a> {
         self.error_expression(span)
     }
 
+    /// Convert a `Self` token to the name of the type extended by the enclosing `impl` block.
+    ///
+    /// In an interface, `Self` stands for the type that satisfies it, and is kept as is. Elsewhere
+    /// `Self` is reserved, so an error is emitted instead.
+    fn self_upper_to_identifier(&self, token: &SyntaxToken) -> leo_ast::Identifier {
+        let span = self.token_span(token);
+        let type_name = token
+            .parent()
+            .and_then(|parent| enclosing_impl(&parent))
+            .and_then(|impl_node| tokens(&impl_node).find(|t| t.kind() == IDENT));
+        match type_name {
+            Some(type_name) => {
+                leo_ast::Identifier { name: Symbol::intern(type_name.text()), span, id: self.builder.next_id() }
+            }
+            None if token.parent().and_then(|parent| enclosing_interface(&parent)).is_some() => {
+                leo_ast::Identifier { name: sym::SelfUpper, span, id: self.builder.next_id() }
+            }
+            None => {
+                self.handler.emit_err(crate::errors::reserved_identifier("Self", span));
+                self.error_identifier(span)
+            }
+        }
+    }
+
+    /// The const parameters of the `impl` block enclosing `node` as const arguments, so that `Self`
+    /// in `impl Foo::[N: u32] { .. }` stands for `Foo::[N]`. Empty outside of a generic `impl` block.
+    fn impl_const_arguments(&self, node: &SyntaxNode, span: Span) -> Vec<leo_ast::Expression> {
+        let Some(param_list) = enclosing_impl(node).and_then(|n| children(&n).find(|n| n.kind() == CONST_PARAM_LIST))
+        else {
+            return Vec::new();
+        };
+        children(&param_list)
+            .filter(|n| n.kind() == CONST_PARAM)
+            .map(|n| {
+                let name = leo_ast::Identifier { span, ..self.require_ident(&n, "const parameter name") };
+                leo_ast::Path::new(None, Vec::new(), name, span, self.builder.next_id()).into()
+            })
+            .collect()
+    }
+
     /// Convert a FINAL_EXPR node to an Expression.
     fn final_expr_to_expression(&self, node: &SyntaxNode) -> Result<leo_ast::Expression> {
         debug_assert_eq!(node.kind(), FINAL_EXPR);
@@ -1631,6 +1745,7 @@ impl<'a> ConversionContext<'a> {
                         path_components.push(self.to_identifier(&token));
                     }
                 }
+                KW_SELF_UPPER => path_components.push(self.self_upper_to_identifier(&token)),
                 kind => {
                     if let Some(name) = keyword_to_path_symbol(kind) {
                         path_components.push(leo_ast::Identifier {
@@ -1671,6 +1786,10 @@ impl<'a> ConversionContext<'a> {
             COMPOUND_ASSIGN_STMT => self.compound_assign_to_statement(node)?,
             IF_STMT => self.if_stmt_to_statement(node)?,
             FOR_STMT | FOR_INCLUSIVE_STMT => self.for_stmt_to_statement(node)?,
+            WHILE_STMT => self.while_stmt_to_statement(node)?,
+            BREAK_STMT => leo_ast::BreakStatement { span, id }.into(),
+            CONTINUE_STMT => leo_ast::ContinueStatement { span, id }.into(),
+            MATCH_STMT => self.match_stmt_to_statement(node)?,
             BLOCK => self.to_block(node)?.into(),
             ASSERT_STMT => {
                 let expression = self.require_expression(node, "expression in assert")?;
@@ -1951,6 +2070,69 @@ impl<'a> ConversionContext<'a> {
         Ok(leo_ast::ConditionalStatement { condition, then, otherwise, span, id }.into())
     }
 
+    /// Convert a MATCH_STMT node to a MatchStatement.
+    fn match_stmt_to_statement(&self, node: &SyntaxNode) -> Result<leo_ast::Statement> {
+        debug_assert_eq!(node.kind(), MATCH_STMT);
+        let span = self.to_span(node);
+        let id = self.builder.next_id();
+
+        let scrutinee = self.require_expression(node, "scrutinee in match statement")?;
+
+        let arms = children(node)
+            .filter(|n| n.kind() == MATCH_ARM)
+            .map(|arm| {
+                let pattern = self.match_arm_pattern(&arm);
+                let block = match children(&arm).find(|n| n.kind() == BLOCK) {
+                    Some(n) => self.to_block(&n)?,
+                    None => {
+                        let span = self.to_span(&arm);
+                        self.emit_unexpected_str("block in match arm", arm.text(), span);
+                        self.error_block(span)
+                    }
+                };
+                Ok(leo_ast::MatchStatementArm {
+                    pattern,
+                    block,
+                    span: self.non_trivia_span(&arm),
+                    id: self.builder.next_id(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(leo_ast::MatchStatement { scrutinee, arms, span, id }.into())
+    }
+
+    /// Convert the pattern of a MATCH_ARM node to a MatchPattern.
+    fn match_arm_pattern(&self, arm: &SyntaxNode) -> leo_ast::MatchPattern {
+        let Some(node) = children(arm).find(|n| matches!(n.kind(), WILDCARD_PATTERN | VARIANT_PATTERN)) else {
+            // Parse errors already emitted by emit_parse_errors().
+            return leo_ast::MatchPattern::Wildcard;
+        };
+        if node.kind() == WILDCARD_PATTERN {
+            return leo_ast::MatchPattern::Wildcard;
+        }
+
+        // The path is made of the IDENT tokens of the pattern; the bindings are child nodes.
+        let mut components = tokens(&node).filter(|t| t.kind() == IDENT).map(|t| self.to_identifier(&t)).collect_vec();
+        let span = self.trimmed_span(&node);
+        let name = components.pop().unwrap_or_else(|| self.error_identifier(span));
+        let path_span = components.first().map_or(name.span, |first| first.span + name.span);
+        let path = leo_ast::Path::new(None, components, name, path_span, self.builder.next_id());
+
+        let bindings = children(&node)
+            .filter(|n| matches!(n.kind(), IDENT_PATTERN | WILDCARD_PATTERN))
+            .map(|n| {
+                (n.kind() == IDENT_PATTERN).then(|| {
+                    let ident = self.require_ident(&n, "identifier in pattern");
+                    self.validate_definition_identifier(&ident);
+                    ident
+                })
+            })
+            .collect();
+
+        leo_ast::MatchPattern::Variant { path, bindings }
+    }
+
     /// Convert a FOR_STMT or FOR_INCLUSIVE_STMT node to an IterationStatement.
     fn for_stmt_to_statement(&self, node: &SyntaxNode) -> Result<leo_ast::Statement> {
         debug_assert!(matches!(node.kind(), FOR_STMT | FOR_INCLUSIVE_STMT));
@@ -1995,6 +2177,45 @@ impl<'a> ConversionContext<'a> {
         Ok(leo_ast::IterationStatement { variable, type_, start, stop, inclusive, block, span, id }.into())
     }
 
+    /// Convert a WHILE_STMT node to a WhileStatement.
+    fn while_stmt_to_statement(&self, node: &SyntaxNode) -> Result<leo_ast::Statement> {
+        debug_assert_eq!(node.kind(), WHILE_STMT);
+        let span = self.to_span(node);
+        let id = self.builder.next_id();
+
+        let mut exprs = children(node).filter(|n| n.kind().is_expression());
+
+        // The `@bound(N)` annotation, if present, precedes the condition.
+        let bound = if tokens(node).any(|t| t.kind() == AT) {
+            exprs.next().map(|n| self.to_expression(&n)).transpose()?
+        } else {
+            None
+        };
+        let bound = bound.unwrap_or_else(|| {
+            self.handler.emit_err(crate::errors::while_loop_missing_bound(span));
+            self.error_expression(span)
+        });
+
+        let condition = match exprs.next() {
+            Some(n) => self.to_expression(&n)?,
+            None => {
+                self.emit_unexpected_str("condition in while statement", node.text(), span);
+                self.error_expression(span)
+            }
+        };
+
+        // Get body block
+        let block = match children(node).find(|n| n.kind() == BLOCK) {
+            Some(block_node) => self.to_block(&block_node)?,
+            None => {
+                self.emit_unexpected_str("block in while statement", node.text(), span);
+                self.error_block(span)
+            }
+        };
+
+        Ok(leo_ast::WhileStatement { condition, bound, block, span, id }.into())
+    }
+
     // =========================================================================
     // Item/Program Conversions
     // =========================================================================
@@ -2036,6 +2257,20 @@ impl<'a> ConversionContext<'a> {
                 let composite = self.to_composite(item, is_in_program_block)?;
                 composites.push((composite.identifier.name, composite));
             }
+            ENUM_DEF => {
+                if is_in_program_block {
+                    let span = self.non_trivia_span(item);
+                    self.handler.emit_err(
+                        crate::errors::custom(
+                            "`enum` definitions are not allowed inside a `program { ... }` block.",
+                            span,
+                        )
+                        .with_help("Move the declaration outside the `program` block, to the top level of the file."),
+                    );
+                }
+                let composite = self.to_enum(item, is_in_program_block)?;
+                composites.push((composite.identifier.name, composite));
+            }
             GLOBAL_CONST => {
                 if is_in_program_block {
                     let span = self.non_trivia_span(item);
@@ -2064,6 +2299,14 @@ impl<'a> ConversionContext<'a> {
                 let interface = self.to_interface(item, is_in_program_block)?;
                 interfaces.push((interface.identifier.name, interface));
             }
+            // Top-level `impl` blocks are attached to their types by `attach_impls`.
+            IMPL_DEF if is_in_program_block => {
+                let span = self.non_trivia_span(item);
+                self.handler.emit_err(
+                    crate::errors::custom("`impl` blocks are not allowed inside a `program { ... }` block.", span)
+                        .with_help("Move the declaration outside the `program` block, to the top level of the file."),
+                );
+            }
             _ => {}
         }
         Ok(())
@@ -2088,6 +2331,10 @@ impl<'a> ConversionContext<'a> {
                     let composite = self.to_composite(item, false)?;
                     structs.push((composite.identifier.name, composite));
                 }
+                ENUM_DEF => {
+                    let composite = self.to_enum(item, false)?;
+                    structs.push((composite.identifier.name, composite));
+                }
                 FUNCTION_DEF => {
                     // `is_in_program_block = false` so the variant is always `Fn` (not EntryPoint).
                     let func = self.to_function(item, false)?;
@@ -2097,6 +2344,7 @@ impl<'a> ConversionContext<'a> {
                     let interface = self.to_interface(item, false)?;
                     interfaces.push((interface.identifier.name, interface));
                 }
+                // `impl` blocks are attached to their types by `attach_impls`.
                 _ => {}
             }
         } else if item.kind() == VIEW_FN_DEF {
@@ -2108,7 +2356,7 @@ impl<'a> ConversionContext<'a> {
             // A recognized program-only item appeared in a library file.
             let span = self.to_span(item);
             self.handler.emit_err(crate::errors::custom(
-                "Only `const` declarations, `struct` definitions, `fn` functions, and `interface` definitions are allowed in a library.",
+                "Only `const` declarations, `struct` and `enum` definitions, `fn` functions, and `interface` definitions are allowed in a library.",
                 span,
             ));
         }
@@ -2149,6 +2397,8 @@ impl<'a> ConversionContext<'a> {
             }
         }
 
+        self.attach_impls(node, &mut composites)?;
+
         // Sort functions: entry points first
         functions.sort_by_key(|func| if func.1.variant.is_entry() { 0u8 } else { 1u8 });
 
@@ -2237,6 +2487,8 @@ impl<'a> ConversionContext<'a> {
             return Err(crate::errors::missing_program_declaration(self.to_span(node)).into());
         };
 
+        self.attach_impls(node, &mut composites)?;
+
         // Sort functions: entry points first
         functions.sort_by_key(|func| if func.1.variant.is_entry() { 0u8 } else { 1u8 });
 
@@ -2273,6 +2525,7 @@ impl<'a> ConversionContext<'a> {
         for child in children(node) {
             self.collect_library_item(&child, &mut consts, &mut structs, &mut functions, &mut interfaces)?;
         }
+        self.attach_impls(node, &mut structs)?;
 
         Ok(leo_ast::Library {
             name,
@@ -2285,6 +2538,87 @@ impl<'a> ConversionContext<'a> {
         })
     }
 
+    /// Convert the top-level IMPL_DEF children of `node` and add their functions to the composites they implement.
+    ///
+    /// An `impl` block must be in the same file as its struct or record, and repeat its const parameters.
+    fn attach_impls(&self, node: &SyntaxNode, composites: &mut [(Symbol, leo_ast::Composite)]) -> Result<()> {
+        for impl_node in children(node).filter(|n| n.kind() == IMPL_DEF) {
+            // A missing type name has already been reported by the rowan parser.
+            let Some(name_token) = tokens(&impl_node).find(|t| t.kind() == IDENT) else {
+                continue;
+            };
+            let type_name = self.to_identifier(&name_token);
+
+            let Some((_, composite)) = composites.iter_mut().find(|(name, _)| *name == type_name.name) else {
+                self.handler.emit_err(
+                    crate::errors::custom(
+                        format!("Cannot find a struct or record `{type_name}` to implement."),
+                        type_name.span,
+                    )
+                    .with_help("An `impl` block must be declared in the same file as its struct or record."),
+                );
+                continue;
+            };
+            if composite.variants.is_some() {
+                self.handler.emit_err(crate::errors::custom(
+                    format!("`impl` blocks are only allowed for structs and records, and `{type_name}` is an enum."),
+                    type_name.span,
+                ));
+                continue;
+            }
+
+            let const_parameters = self.extract_const_parameters(&impl_node)?;
+            if const_parameters.len() != composite.const_parameters.len()
+                || const_parameters
+                    .iter()
+                    .zip(&composite.const_parameters)
+                    .any(|(param, expected)| !param.type_.types_equivalent(&expected.type_))
+            {
+                let span = self.non_trivia_span(&impl_node);
+                self.handler.emit_err(
+                    crate::errors::custom(
+                        format!("The `impl` block for `{type_name}` must declare the same const parameters as `{type_name}`."),
+                        span,
+                    )
+                    .with_help(format!("Write `impl {type_name}::[...]` with the const parameters of `{type_name}`, in order.")),
+                );
+                continue;
+            }
+
+            for item in children(&impl_node) {
+                match item.kind() {
+                    FUNCTION_DEF => {
+                        if has_export(&item) {
+                            self.handler.emit_err(
+                                crate::errors::custom(
+                                    "Functions in an `impl` block cannot be marked `export`.",
+                                    self.non_trivia_span(&item),
+                                )
+                                .with_help(format!("They are exported together with `{type_name}`.")),
+                            );
+                        }
+                        let mut function = self.to_function(&item, false)?;
+                        let name =
+                            leo_ast::Composite::associated_function_name(type_name.name, function.identifier.name);
+                        function.identifier.name = name;
+                        // The function is generic over the const parameters of its type, followed by its own.
+                        function.const_parameters.splice(0..0, self.extract_const_parameters(&impl_node)?);
+                        function.is_exported = composite.is_exported;
+                        composite.functions.push((name, function));
+                    }
+                    FINAL_FN_DEF | VIEW_FN_DEF | CONSTRUCTOR_DEF => {
+                        self.handler.emit_err(crate::errors::custom(
+                            "Only `fn` functions are allowed in an `impl` block.",
+                            self.non_trivia_span(&item),
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Extract a ProgramId from an IMPORT node. Guarantees `network` is always present.
     fn import_to_program_id(&self, node: &SyntaxNode) -> Result<leo_ast::ProgramId> {
         debug_assert_eq!(node.kind(), IMPORT);
@@ -2579,10 +2913,16 @@ impl<'a> ConversionContext<'a> {
     fn param_list_to_inputs(&self, node: &SyntaxNode) -> Result<Vec<leo_ast::Input>> {
         debug_assert_eq!(node.kind(), PARAM_LIST);
 
-        children(node)
+        let inputs = children(node)
             .filter(|n| matches!(n.kind(), PARAM | PARAM_PUBLIC | PARAM_PRIVATE | PARAM_CONSTANT))
             .map(|n| self.param_to_input(&n))
-            .collect()
+            .collect::<Result<Vec<_>>>()?;
+
+        for input in inputs.iter().skip(1).filter(|input| input.identifier.name == sym::SelfLower) {
+            self.handler.emit_err(crate::errors::custom("`self` must be the first parameter of a method.", input.span));
+        }
+
+        Ok(inputs)
     }
 
     /// Convert a PARAM node to an Input.
@@ -2593,6 +2933,25 @@ impl<'a> ConversionContext<'a> {
 
         let mode = node_kind_to_mode(node.kind());
 
+        // The receiver `self` of a method, whose type is the one extended by the enclosing `impl` block, or `Self`
+        // in an interface.
+        if let Some(token) = tokens(node).find(|t| t.kind() == KW_SELF) {
+            let name_span = self.token_span(&token);
+            let identifier = leo_ast::Identifier { name: sym::SelfLower, span: name_span, id: self.builder.next_id() };
+            let type_ = if enclosing_impl(node).is_some() || enclosing_interface(node).is_some() {
+                let type_name = self.self_upper_to_identifier(&token);
+                let path = leo_ast::Path::new(None, Vec::new(), type_name, name_span, self.builder.next_id());
+                leo_ast::CompositeType { path, const_arguments: self.impl_const_arguments(node, name_span) }.into()
+            } else {
+                self.handler.emit_err(crate::errors::custom(
+                    "`self` parameters are only allowed in the functions of an `impl` block or an interface.",
+                    span,
+                ));
+                leo_ast::Type::Err
+            };
+            return Ok(leo_ast::Input { identifier, mode, type_, span, id });
+        }
+
         let identifier = self.require_ident(node, "parameter name");
         self.validate_identifier(&identifier);
 
@@ -2655,7 +3014,59 @@ impl<'a> ConversionContext<'a> {
 
         let is_exported = if is_record || is_in_program_block { None } else { Some(has_export(node)) };
 
-        Ok(leo_ast::Composite { is_exported, identifier, const_parameters, members, is_record, span, id })
+        Ok(leo_ast::Composite {
+            is_exported,
+            identifier,
+            const_parameters,
+            members,
+            is_record,
+            variants: None,
+            functions: Vec::new(),
+            span,
+            id,
+        })
+    }
+
+    /// Convert an ENUM_DEF node to a Composite with variants.
+    fn to_enum(&self, node: &SyntaxNode, is_in_program_block: bool) -> Result<leo_ast::Composite> {
+        debug_assert_eq!(node.kind(), ENUM_DEF);
+        let span = self.non_trivia_span(node);
+        let id = self.builder.next_id();
+
+        let identifier = self.require_ident(node, "enum name");
+        self.validate_identifier(&identifier);
+
+        let variants = children(node)
+            .filter(|n| n.kind() == ENUM_VARIANT)
+            .map(|n| {
+                let identifier = self.require_ident(&n, "variant name");
+                self.validate_identifier(&identifier);
+                let types = children(&n)
+                    .filter(|n| n.kind().is_type())
+                    .map(|n| self.to_type(&n))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(leo_ast::EnumVariant {
+                    identifier,
+                    types,
+                    span: self.non_trivia_span(&n),
+                    id: self.builder.next_id(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let is_exported = if is_in_program_block { None } else { Some(has_export(node)) };
+
+        Ok(leo_ast::Composite {
+            is_exported,
+            identifier,
+            const_parameters: Vec::new(),
+            members: Vec::new(),
+            is_record: false,
+            variants: Some(variants),
+            functions: Vec::new(),
+            span,
+            id,
+        })
     }
 
     /// Convert a STRUCT_MEMBER node to a Member.
@@ -3220,6 +3631,21 @@ fn tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> + '_ {
     node.children_with_tokens().filter_map(|elem| elem.into_token()).filter(|t| !t.kind().is_trivia())
 }
 
+/// The `impl` block `node` is nested in, if any.
+fn enclosing_impl(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.ancestors().find(|n| n.kind() == IMPL_DEF)
+}
+
+/// The interface enclosing `node`, if any.
+fn enclosing_interface(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.ancestors().find(|n| n.kind() == INTERFACE_DEF)
+}
+
+/// True when the first token of `node` is `Self`.
+fn starts_with_self_upper(node: &SyntaxNode) -> bool {
+    first_non_trivia_token(node).is_some_and(|t| t.kind() == KW_SELF_UPPER)
+}
+
 /// True when `node` carries a direct `export` keyword child.
 fn has_export(node: &SyntaxNode) -> bool {
     tokens(node).any(|t| t.kind() == KW_EXPORT)
@@ -3382,7 +3808,7 @@ fn compute_module_key(name: &FileName, root_dir: Option<&std::path::Path>) -> Op
 
 /// Returns `true` for syntax node kinds that are valid inside a library (`lib.leo`).
 fn is_library_item(kind: SyntaxKind) -> bool {
-    matches!(kind, GLOBAL_CONST | STRUCT_DEF | FUNCTION_DEF | INTERFACE_DEF)
+    matches!(kind, GLOBAL_CONST | STRUCT_DEF | ENUM_DEF | FUNCTION_DEF | INTERFACE_DEF)
 }
 
 /// Returns `true` for syntax node kinds that are valid inside a program (`main.leo`).
@@ -3395,6 +3821,7 @@ fn is_program_item(kind: SyntaxKind) -> bool {
             | VIEW_FN_DEF
             | STRUCT_DEF
             | RECORD_DEF
+            | ENUM_DEF
             | INTERFACE_DEF
             | MAPPING_DEF
             | STORAGE_DEF
@@ -177,6 +177,20 @@ impl CrossLayerTaintVisitor<'_> {
                     }
                 }
             }
+            Expression::Match(match_) => {
+                // The bindings of an arm hold parts of the scrutinee, so the scrutinee's taint covers them.
+                self.collect_taint_recursive(&match_.scrutinee, taint);
+                for arm in &match_.arms {
+                    self.collect_taint_recursive(&arm.expression, taint);
+                }
+            }
+            Expression::MethodCall(call) => {
+                // Conservative, as for calls: if the receiver or any argument is tainted, the return is tainted.
+                self.collect_taint_recursive(&call.receiver, taint);
+                for arg in &call.arguments {
+                    self.collect_taint_recursive(arg, taint);
+                }
+            }
             // Leaf / irrelevant nodes.
             Expression::Literal(_) | Expression::Unit(_) | Expression::Err(_) | Expression::Async(_) => {}
         }
@@ -274,6 +288,20 @@ impl CrossLayerTaintVisitor<'_> {
             | Expression::Unit(_)
             | Expression::Err(_)
             | Expression::Async(_) => {}
+            Expression::Match(m) => {
+                self.collect_future_call_sources(&m.scrutinee, sources);
+                for arm in &m.arms {
+                    self.collect_future_call_sources(&arm.expression, sources);
+                }
+            }
+            // Methods are helper `fn`s of the current program, so only the receiver and arguments can hold
+            // external calls.
+            Expression::MethodCall(c) => {
+                self.collect_future_call_sources(&c.receiver, sources);
+                for arg in &c.arguments {
+                    self.collect_future_call_sources(arg, sources);
+                }
+            }
         }
     }
 
@@ -376,7 +404,33 @@ impl CrossLayerTaintVisitor<'_> {
                 self.check_expression_for_taint(&iter.stop);
                 self.check_async_block_for_taint(&iter.block);
             }
+            Statement::Match(match_) => {
+                self.check_expression_for_taint(&match_.scrutinee);
+
+                // As for conditionals, the scrutinee decides which arm runs, so its taint is implicit in every arm.
+                let scrutinee_taint = self.collect_taint(&match_.scrutinee);
+                let saved_implicit = self.implicit_taint.clone();
+                self.implicit_taint.merge(&scrutinee_taint);
+
+                let saved_taint = self.taint_map.clone();
+                let mut merged = IndexMap::new();
+                for arm in &match_.arms {
+                    self.taint_map = saved_taint.clone();
+                    self.bind_match_pattern(&arm.pattern, &scrutinee_taint);
+                    self.check_async_block_for_taint(&arm.block);
+                    merged = merge_taint_maps(merged, std::mem::take(&mut self.taint_map));
+                }
+                self.taint_map = if match_.arms.is_empty() { saved_taint } else { merged };
+
+                self.implicit_taint = saved_implicit;
+            }
             Statement::Return(ret) => self.check_expression_for_taint(&ret.expression),
+            Statement::While(while_) => {
+                self.check_expression_for_taint(&while_.bound);
+                self.check_expression_for_taint(&while_.condition);
+                self.check_async_block_for_taint(&while_.block);
+            }
+            Statement::Break(_) | Statement::Continue(_) => {}
         }
     }
 
@@ -474,9 +528,35 @@ impl CrossLayerTaintVisitor<'_> {
                     DynamicOpKind::Read { .. } => {}
                 }
             }
+            Expression::Match(match_) => {
+                self.check_expression_for_taint(&match_.scrutinee);
+                for arm in &match_.arms {
+                    self.check_expression_for_taint(&arm.expression);
+                }
+            }
+            Expression::MethodCall(call) => {
+                self.check_expression_for_taint(&call.receiver);
+                for arg in &call.arguments {
+                    self.check_expression_for_taint(arg);
+                }
+            }
             Expression::Literal(_) | Expression::Unit(_) | Expression::Err(_) | Expression::Async(_) => {}
         }
     }
+
+    /// Taints the names bound by `pattern` with the taint of the scrutinee they are taken from.
+    fn bind_match_pattern(&mut self, pattern: &MatchPattern, scrutinee_taint: &TaintInfo) {
+        let mut taint = scrutinee_taint.clone();
+        taint.merge(&self.implicit_taint);
+        if !taint.is_tainted() {
+            return;
+        }
+        if let MatchPattern::Variant { bindings, .. } = pattern {
+            for binding in bindings.iter().flatten() {
+                self.taint_map.insert(binding.name, taint.clone());
+            }
+        }
+    }
 }
 
 impl AstVisitor for CrossLayerTaintVisitor<'_> {
@@ -609,6 +689,31 @@ impl AstVisitor for CrossLayerTaintVisitor<'_> {
         self.implicit_taint = saved_implicit;
     }
 
+    fn visit_match_statement(&mut self, input: &MatchStatement) {
+        self.visit_expression(&input.scrutinee, &Default::default());
+
+        // As for conditionals, assignments inside any arm of a match on a tainted value are implicitly tainted.
+        let scrutinee_taint = self.collect_taint(&input.scrutinee);
+        let saved_implicit = self.implicit_taint.clone();
+        self.implicit_taint.merge(&scrutinee_taint);
+
+        // Visit each arm from the taint map before the match, then union-merge the results.
+        let saved_taint = self.taint_map.clone();
+        let mut merged = IndexMap::new();
+        for arm in &input.arms {
+            self.taint_map = saved_taint.clone();
+            if self.in_transition {
+                self.bind_match_pattern(&arm.pattern, &scrutinee_taint);
+            }
+            self.visit_block(&arm.block);
+            merged = merge_taint_maps(merged, std::mem::take(&mut self.taint_map));
+        }
+        self.taint_map = if input.arms.is_empty() { saved_taint } else { merged };
+
+        // Restore implicit taint to pre-match state.
+        self.implicit_taint = saved_implicit;
+    }
+
     fn visit_async(&mut self, input: &AsyncExpression, _additional: &Self::AdditionalInput) -> Self::Output {
         if !self.in_transition {
             return;
@@ -116,6 +116,9 @@ fn classify_intrinsic(i: &Intrinsic) -> Option<Op> {
         // Transition-only. Unreachable in a finalize context; earlier
         // passes reject it there.
         DynamicCall => None,
+
+        // Cheatcodes only appear in native tests, which the analysis skips.
+        TestSetCaller | TestSetSigner | TestWarp | TestExpectRevert | TestSnapshot | TestRestore => None,
     }
 }
 
@@ -257,7 +260,19 @@ impl Summarizer<'_> {
                 self.summarize_expr(&it.stop, prog, s);
                 s.merge(self.summarize_block(&it.block, prog));
             }
+            Statement::Match(m) => {
+                self.summarize_expr(&m.scrutinee, prog, s);
+                for arm in &m.arms {
+                    s.merge(self.summarize_block(&arm.block, prog));
+                }
+            }
             Statement::Return(r) => self.summarize_expr(&r.expression, prog, s),
+            Statement::While(w) => {
+                self.summarize_expr(&w.bound, prog, s);
+                self.summarize_expr(&w.condition, prog, s);
+                s.merge(self.summarize_block(&w.block, prog));
+            }
+            Statement::Break(_) | Statement::Continue(_) => {}
         }
     }
 
@@ -361,6 +376,19 @@ impl Summarizer<'_> {
                 self.summarize_expr(&r.count, prog, s);
             }
             Expression::Async(a) => s.merge(self.summarize_block(&a.block, prog)),
+            Expression::Match(m) => {
+                self.summarize_expr(&m.scrutinee, prog, s);
+                for arm in &m.arms {
+                    self.summarize_expr(&arm.expression, prog, s);
+                }
+            }
+            // Methods are regular helper `fn`s, so only the receiver and arguments can have effects.
+            Expression::MethodCall(c) => {
+                self.summarize_expr(&c.receiver, prog, s);
+                for arg in &c.arguments {
+                    self.summarize_expr(arg, prog, s);
+                }
+            }
             Expression::Literal(_) | Expression::Unit(_) | Expression::Err(_) => {}
         }
     }
@@ -478,8 +506,23 @@ impl<'a> Scanner<'a> {
             Statement::Const(d) => self.scan_expr(&d.value, post),
             Statement::Definition(d) => self.scan_expr(&d.value, post),
             Statement::Expression(e) => self.scan_expr(&e.expression, post),
-            Statement::Iteration(it) => self.scan_loop(it, post),
+            Statement::Iteration(it) => {
+                let post = self.scan_expr(&it.start, post);
+                let post = self.scan_expr(&it.stop, post);
+                self.scan_loop(&it.block, it.variable.span(), it.span, post)
+            }
+            // Like a conditional, every arm starts from the post-state after the scrutinee.
+            Statement::Match(m) => {
+                let post = self.scan_expr(&m.scrutinee, post);
+                m.arms.iter().map(|arm| self.scan_block(&arm.block, post)).reduce(Self::merge).unwrap_or(post)
+            }
             Statement::Return(r) => self.scan_expr(&r.expression, post),
+            Statement::While(w) => {
+                let post = self.scan_expr(&w.bound, post);
+                let post = self.scan_expr(&w.condition, post);
+                self.scan_loop(&w.block, w.condition.span(), w.span, post)
+            }
+            Statement::Break(_) | Statement::Continue(_) => post,
         }
     }
 
@@ -518,33 +561,31 @@ impl<'a> Scanner<'a> {
         }
     }
 
-    fn scan_loop(&mut self, it: &IterationStatement, post: Option<Span>) -> Option<Span> {
-        let post = self.scan_expr(&it.start, post);
-        let post = self.scan_expr(&it.stop, post);
-
+    /// Scans the body `block` of a loop spanning `span`, whose header has already been scanned. A violation across
+    /// iterations is reported at `header_span`.
+    fn scan_loop(&mut self, block: &Block, header_span: Span, span: Span, post: Option<Span>) -> Option<Span> {
         // Iteration i's non-interactions come after iteration i-1's
         // interaction, so a body containing both violates CEI.
         let body_summary = {
             let mut s = Summary::default();
             let prog = self.program;
             let mut summarizer = self.summarizer();
-            for st in &it.block.statements {
+            for st in &block.statements {
                 summarizer.summarize_stmt(st, prog, &mut s);
             }
             s
         };
         if body_summary.interacts && (body_summary.reads || body_summary.writes) {
-            let span = it.variable.span();
-            self.emit(span, Warning::Loop, cei_analyzer::cei_violation_in_loop(span));
+            self.emit(header_span, Warning::Loop, cei_analyzer::cei_violation_in_loop(header_span));
         }
 
         // The loop-level warning above covers the cross-iteration hazard; the
         // body scan below still surfaces within-iteration violations at their
         // precise statement locations.
-        let after = self.scan_block(&it.block, post);
+        let after = self.scan_block(block, post);
 
         // If the body performs an interaction, downstream code is post.
-        if body_summary.interacts { after.or(Some(it.span)) } else { after }
+        if body_summary.interacts { after.or(Some(span)) } else { after }
     }
 
     fn scan_expr(&mut self, e: &Expression, post: Option<Span>) -> Option<Span> {
@@ -614,6 +655,15 @@ impl<'a> Scanner<'a> {
                     post
                 }
             }
+            Expression::Match(m) => {
+                let post = self.scan_expr(&m.scrutinee, post);
+                m.arms.iter().map(|arm| self.scan_expr(&arm.expression, post)).reduce(Self::merge).unwrap_or(post)
+            }
+            // Methods are regular helper `fn`s, which can't touch state or interact.
+            Expression::MethodCall(c) => {
+                let post = self.scan_expr(&c.receiver, post);
+                self.scan_args(&c.arguments, post)
+            }
             Expression::Literal(_) | Expression::Unit(_) | Expression::Err(_) => post,
         }
     }
@@ -97,6 +97,12 @@ impl CodeGeneratingVisitor<'_> {
                 panic!("`AsyncExpression`s should not be in the AST at this phase of compilation.")
             }
             Expression::Err(..) => panic!("`ErrExpression`s should not be in the AST at this phase of compilation."),
+            Expression::Match(..) => {
+                panic!("`MatchExpression`s should not be in the AST at this phase of compilation.")
+            }
+            Expression::MethodCall(..) => {
+                panic!("`MethodCallExpression`s should not be in the AST at this phase of compilation.")
+            }
             Expression::TupleAccess(..) => panic!("Tuple accesses should not appear in the AST at this point."),
             Expression::Unit(..) => panic!("`UnitExpression`s should not be visited during code generation."),
         }
@@ -1131,6 +1137,16 @@ impl CodeGeneratingVisitor<'_> {
                 | Intrinsic::DynamicGetOrUse => {
                     unreachable!("Dynamic dispatch intrinsics are handled in visit_intrinsic")
                 }
+                Intrinsic::TestSetCaller
+                | Intrinsic::TestSetSigner
+                | Intrinsic::TestWarp
+                | Intrinsic::TestExpectRevert
+                | Intrinsic::TestSnapshot
+                | Intrinsic::TestRestore => {
+                    panic!(
+                        "Native tests, the only code using cheatcodes, should have been pruned before code generation"
+                    )
+                }
             };
             // Add the instruction to the list of instructions.
             instructions.extend(instruction);
@@ -19,13 +19,20 @@ use crate::{Bytecode, CompiledPrograms, Pass};
 use itertools::Itertools;
 use leo_ast::{Ast, Mode, ProgramId};
 use leo_errors::Result;
+use leo_span::Span;
 
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
 
 mod expression;
 
 mod program;
 
+mod source_map;
+pub use source_map::*;
+
 mod statement;
 
 mod type_;
@@ -52,19 +59,23 @@ pub struct GeneratedPrograms {
 }
 
 impl GeneratedPrograms {
-    /// Serializes all programs to bytecode strings.
+    /// Serializes all programs to bytecode strings, along with their source maps.
     pub fn into_compiled(self) -> CompiledPrograms {
-        let primary_bytecode = self.primary.map(|p| p.to_string()).unwrap_or_default();
+        let (primary_bytecode, primary_source_map) =
+            self.primary.map(|p| p.to_bytecode_with_source_map()).unwrap_or_default();
         let import_bytecodes = self
             .imports
             .into_iter()
-            .map(|(name, program)| Bytecode { program_name: name, bytecode: program.to_string() })
+            .map(|(name, program)| {
+                let (bytecode, source_map) = program.to_bytecode_with_source_map();
+                Bytecode { program_name: name, bytecode, source_map }
+            })
             .collect();
-        CompiledPrograms { primary_bytecode, import_bytecodes }
+        CompiledPrograms { primary_bytecode, primary_source_map, import_bytecodes }
     }
 
     /// Returns mutable references to all statement lists across all functions/closures/finalizes/constructors.
-    pub fn for_each_statement_list(&mut self, mut f: impl FnMut(&mut Vec<AleoStmt>, usize)) {
+    pub fn for_each_statement_list(&mut self, mut f: impl FnMut(&mut AleoStatements, usize)) {
         for (_, program) in &mut self.imports {
             program.for_each_statement_list(&mut f);
         }
@@ -121,7 +132,7 @@ pub struct AleoProgram {
 impl AleoProgram {
     /// Calls `f` for each statement list in the program (closures, functions, finalizes, constructor).
     /// The second argument to `f` is the number of input registers for that function.
-    pub fn for_each_statement_list(&mut self, f: &mut impl FnMut(&mut Vec<AleoStmt>, usize)) {
+    pub fn for_each_statement_list(&mut self, f: &mut impl FnMut(&mut AleoStatements, usize)) {
         for functional in &mut self.functions {
             match functional {
                 AleoFunctional::Closure(c) => {
@@ -259,11 +270,84 @@ impl Display for AleoMapping {
     }
 }
 
+/// A list of Aleo instructions, each paired with the span of the Leo code it was generated from.
+///
+/// Dereferences to the instructions; the methods that add or remove instructions keep the spans aligned.
+#[derive(Debug, Default)]
+pub struct AleoStatements {
+    stmts: Vec<AleoStmt>,
+    spans: Vec<Span>,
+}
+
+impl AleoStatements {
+    /// Instructions that were all generated from the Leo code at `span`.
+    pub fn spanned(stmts: Vec<AleoStmt>, span: Span) -> Self {
+        let spans = vec![span; stmts.len()];
+        Self { stmts, spans }
+    }
+
+    /// The span of each instruction, in order.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    pub fn push(&mut self, stmt: AleoStmt, span: Span) {
+        self.stmts.push(stmt);
+        self.spans.push(span);
+    }
+
+    pub fn insert(&mut self, index: usize, stmt: AleoStmt, span: Span) {
+        self.stmts.insert(index, stmt);
+        self.spans.insert(index, span);
+    }
+
+    pub fn extend(&mut self, other: Self) {
+        self.stmts.extend(other.stmts);
+        self.spans.extend(other.spans);
+    }
+
+    pub fn remove(&mut self, index: usize) -> AleoStmt {
+        self.spans.remove(index);
+        self.stmts.remove(index)
+    }
+
+    /// Keeps only the instructions for which `keep` returns true, visiting each once in order.
+    pub fn retain(&mut self, mut keep: impl FnMut(&AleoStmt) -> bool) {
+        let stmts = std::mem::take(&mut self.stmts);
+        let spans = std::mem::take(&mut self.spans);
+        (self.stmts, self.spans) = stmts.into_iter().zip(spans).filter(|(stmt, _)| keep(stmt)).unzip();
+    }
+}
+
+impl Deref for AleoStatements {
+    type Target = [AleoStmt];
+
+    fn deref(&self) -> &Self::Target {
+        &self.stmts
+    }
+}
+
+impl DerefMut for AleoStatements {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.stmts
+    }
+}
+
+impl FromIterator<AleoStatements> for AleoStatements {
+    fn from_iter<I: IntoIterator<Item = AleoStatements>>(iter: I) -> Self {
+        let mut statements = Self::default();
+        for other in iter {
+            statements.extend(other);
+        }
+        statements
+    }
+}
+
 #[derive(Debug)]
 pub struct AleoClosure {
     name: String,
     inputs: Vec<AleoInput>,
-    statements: Vec<AleoStmt>,
+    statements: AleoStatements,
 }
 impl Display for AleoClosure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -271,7 +355,7 @@ impl Display for AleoClosure {
         for input in &self.inputs {
             write!(f, "{}", input)?;
         }
-        for stm in &self.statements {
+        for stm in self.statements.iter() {
             write!(f, "{}", stm)?;
         }
         Ok(())
@@ -326,7 +410,7 @@ impl Display for AleoFunctional {
 pub struct AleoView {
     name: String,
     inputs: Vec<AleoInput>,
-    statements: Vec<AleoStmt>,
+    statements: AleoStatements,
 }
 impl Display for AleoView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -334,7 +418,7 @@ impl Display for AleoView {
         for input in &self.inputs {
             write!(f, "{}", input)?;
         }
-        for stm in &self.statements {
+        for stm in self.statements.iter() {
             write!(f, "{}", stm)?;
         }
         Ok(())
@@ -345,7 +429,7 @@ impl Display for AleoView {
 pub struct AleoFunction {
     name: String,
     inputs: Vec<AleoInput>,
-    statements: Vec<AleoStmt>,
+    statements: AleoStatements,
     finalize: Option<AleoFinalize>,
 }
 impl Display for AleoFunction {
@@ -354,7 +438,7 @@ impl Display for AleoFunction {
         for input in &self.inputs {
             write!(f, "{}", input)?;
         }
-        for stm in &self.statements {
+        for stm in self.statements.iter() {
             write!(f, "{}", stm)?;
         }
         if let Some(finalize) = &self.finalize {
@@ -368,7 +452,7 @@ impl Display for AleoFunction {
 pub struct AleoFinalize {
     caller_name: String,
     inputs: Vec<AleoInput>,
-    statements: Vec<AleoStmt>,
+    statements: AleoStatements,
 }
 impl Display for AleoFinalize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -376,7 +460,7 @@ impl Display for AleoFinalize {
         for input in &self.inputs {
             write!(f, "{}", input)?;
         }
-        for stm in &self.statements {
+        for stm in self.statements.iter() {
             write!(f, "{}", stm)?;
         }
         Ok(())
@@ -401,12 +485,12 @@ impl Display for AleoInput {
 
 #[derive(Debug)]
 pub struct AleoConstructor {
-    statements: Vec<AleoStmt>,
+    statements: AleoStatements,
 }
 impl Display for AleoConstructor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "constructor:")?;
-        for stm in &self.statements {
+        for stm in self.statements.iter() {
             write!(f, "{}", stm)?;
         }
         Ok(())
@@ -310,7 +310,7 @@ impl<'a> CodeGeneratingVisitor<'a> {
             // A closure or finalize body with only outputs has no real instructions, which is
             // invalid in Aleo; insert a no-op `assert.eq true true` so the body parses. View
             // bodies accept zero commands at the snarkVM level, so they don't need this.
-            statements.insert(0, AleoStmt::AssertEq(AleoExpr::Bool(true), AleoExpr::Bool(true)));
+            statements.insert(0, AleoStmt::AssertEq(AleoExpr::Bool(true), AleoExpr::Bool(true)), function.span);
         }
 
         // Check the write command limit for finalize blocks.
@@ -375,10 +375,13 @@ impl<'a> CodeGeneratingVisitor<'a> {
         let constructor = match &upgrade_variant {
             // This is the expected snarkVM constructor bytecode for a program that is only upgradable by a fixed admin.
             UpgradeVariant::Admin { address } => AleoConstructor {
-                statements: vec![AleoStmt::AssertEq(
-                    AleoExpr::RawName("program_owner".to_string()),
-                    AleoExpr::RawName(address.to_string()),
-                )],
+                statements: AleoStatements::spanned(
+                    vec![AleoStmt::AssertEq(
+                        AleoExpr::RawName("program_owner".to_string()),
+                        AleoExpr::RawName(address.to_string()),
+                    )],
+                    span,
+                ),
             },
 
             UpgradeVariant::Checksum { mapping, key, .. } => {
@@ -395,23 +398,33 @@ impl<'a> CodeGeneratingVisitor<'a> {
                 // This is the required snarkVM constructor bytecode for a program that is only upgradable
                 // if the new program's checksum matches the one declared in a pre-determined mapping.
                 AleoConstructor {
-                    statements: vec![
-                        AleoStmt::BranchEq(
-                            AleoExpr::RawName("edition".to_string()),
-                            AleoExpr::U16(0),
-                            "end".to_string(),
-                        ),
-                        AleoStmt::Get(AleoExpr::RawName(map_name), AleoExpr::RawName(key.to_string()), AleoReg::R(0)),
-                        AleoStmt::AssertEq(AleoExpr::RawName("checksum".to_string()), AleoExpr::Reg(AleoReg::R(0))),
-                        AleoStmt::Position("end".to_string()),
-                    ],
+                    statements: AleoStatements::spanned(
+                        vec![
+                            AleoStmt::BranchEq(
+                                AleoExpr::RawName("edition".to_string()),
+                                AleoExpr::U16(0),
+                                "end".to_string(),
+                            ),
+                            AleoStmt::Get(
+                                AleoExpr::RawName(map_name),
+                                AleoExpr::RawName(key.to_string()),
+                                AleoReg::R(0),
+                            ),
+                            AleoStmt::AssertEq(AleoExpr::RawName("checksum".to_string()), AleoExpr::Reg(AleoReg::R(0))),
+                            AleoStmt::Position("end".to_string()),
+                        ],
+                        span,
+                    ),
                 }
             }
             UpgradeVariant::Custom => AleoConstructor { statements: self.visit_block(&constructor.block) },
             UpgradeVariant::NoUpgrade => {
                 // This is the expected snarkVM constructor bytecode for a program that is not upgradable.
                 AleoConstructor {
-                    statements: vec![AleoStmt::AssertEq(AleoExpr::RawName("edition".to_string()), AleoExpr::U16(0))],
+                    statements: AleoStatements::spanned(
+                        vec![AleoStmt::AssertEq(AleoExpr::RawName("edition".to_string()), AleoExpr::U16(0))],
+                        span,
+                    ),
                 }
             }
         };
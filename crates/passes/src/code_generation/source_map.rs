@@ -0,0 +1,322 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Source maps from generated Aleo instructions back to the Leo code they came from.
+//!
+//! The compiler writes one next to each program's bytecode, as `<name>.aleo.map`, with the paths of
+//! the package's own sources relative to the package.
+//! When snarkVM reports a failing instruction, [`AleoSourceMap::annotate`] appends
+//! the Leo location of that instruction, with a snippet, to the message.
+
+use super::{AleoFunctional, AleoProgram, AleoStatements, AleoStmt};
+
+use leo_span::{
+    Span,
+    source_map::{FileName, LineContents},
+    with_session_globals,
+};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::LazyLock,
+};
+
+/// Matches the instruction snarkVM names in an evaluation error, e.g. `Instruction (assert.eq r1 true;) at index 1`.
+static FAILED_INSTRUCTION_REGEX: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"(?:Instruction|instruction|command) \((.+?;)\)(?: at index (\d+))?").unwrap());
+
+/// A map from the instructions of one generated Aleo program to the Leo source they were generated from.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AleoSourceMap {
+    /// The program the map describes, e.g. `hello.aleo`.
+    pub program: String,
+    /// One entry per instruction with a known origin, in bytecode order.
+    pub entries: Vec<SourceMapEntry>,
+}
+
+/// The origin of a single Aleo instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceMapEntry {
+    /// The block containing the instruction, e.g. `function main`, `finalize main`, or `constructor`.
+    pub block: String,
+    /// The index of the instruction within its block, counting as snarkVM does.
+    pub index: usize,
+    /// The 1-based line of the instruction in the bytecode file.
+    pub bytecode_line: usize,
+    /// The instruction as it appears in the bytecode.
+    pub instruction: String,
+    /// Where the instruction came from in the Leo source.
+    pub source: SourceLocation,
+}
+
+/// A range of Leo source, with 1-based lines and columns.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// The source file, or a description for sources not read from disk.
+    pub file: String,
+    /// The line the range starts on.
+    pub line: usize,
+    /// The column the range starts at, counted in characters.
+    pub column: usize,
+    /// The line the range ends on.
+    pub end_line: usize,
+    /// The column just past the end of the range.
+    pub end_column: usize,
+}
+
+impl AleoSourceMap {
+    /// Finds the instruction a snarkVM error `message` is about.
+    ///
+    /// snarkVM names the instruction and its index but not its block, so candidates in `function`
+    /// (the function that was called) win ties. When the message names several nested instructions,
+    /// the innermost one is used.
+    pub fn locate(&self, message: &str, function: Option<&str>) -> Option<&SourceMapEntry> {
+        let captures = FAILED_INSTRUCTION_REGEX.captures_iter(message).last()?;
+        let instruction = normalize(&captures[1]);
+        let index = captures.get(2).and_then(|index| index.as_str().parse::<usize>().ok());
+
+        let in_function = |entry: &SourceMapEntry| {
+            function.is_some_and(|function| entry.block.split_once(' ').is_some_and(|(_, name)| name == function))
+        };
+        self.entries
+            .iter()
+            .filter(|entry| index.is_none_or(|index| entry.index == index))
+            .map(|entry| ((normalize(&entry.instruction) == instruction, in_function(entry)), entry))
+            .filter(|((text_matches, _), _)| *text_matches || index.is_some())
+            .max_by_key(|(score, entry)| (*score, std::cmp::Reverse(entry.bytecode_line)))
+            .map(|(_, entry)| entry)
+    }
+
+    /// Finds the instruction at `index` in `block`, e.g. `finalize main`.
+    pub fn entry(&self, block: &str, index: usize) -> Option<&SourceMapEntry> {
+        self.entries.iter().find(|entry| entry.block == block && entry.index == index)
+    }
+
+    /// Makes the paths of the source files relative to `root`, e.g. `./src/main.leo` or `../token/src/main.leo`
+    /// for a sibling dependency, so that the map stays valid wherever the packages are moved.
+    pub fn relative_to(&self, root: &Path) -> Self {
+        let mut source_map = self.clone();
+        for entry in &mut source_map.entries {
+            let path = Path::new(&entry.source.file);
+            if !path.is_absolute() {
+                continue;
+            }
+            // Climb out of `root` until `path` is beneath it.
+            let mut parents = 0;
+            let mut base = Some(root);
+            while let Some(directory) = base {
+                if let Ok(rest) = path.strip_prefix(directory) {
+                    let prefix = if parents == 0 { PathBuf::from(".") } else { (0..parents).map(|_| "..").collect() };
+                    entry.source.file = prefix.join(rest).display().to_string();
+                    break;
+                }
+                base = directory.parent();
+                parents += 1;
+            }
+        }
+        source_map
+    }
+
+    /// Resolves the paths [`Self::relative_to`] made relative against `root`.
+    pub fn resolve(mut self, root: &Path) -> Self {
+        for entry in &mut self.entries {
+            let path = Path::new(&entry.source.file);
+            if !path.starts_with(".") && !path.starts_with("..") {
+                continue;
+            }
+            let mut resolved = root.to_path_buf();
+            for component in path.components() {
+                match component {
+                    Component::CurDir => {}
+                    Component::ParentDir => {
+                        resolved.pop();
+                    }
+                    component => resolved.push(component),
+                }
+            }
+            entry.source.file = resolved.display().to_string();
+        }
+        self
+    }
+
+    /// Appends the Leo location of the instruction a snarkVM error `message` is about, if it can be found.
+    pub fn annotate(&self, message: &str, function: Option<&str>) -> String {
+        match self.locate(message, function) {
+            Some(entry) => format!("{message}\n{}", entry.source.render()),
+            None => message.to_string(),
+        }
+    }
+}
+
+impl SourceLocation {
+    /// Resolves `span` against the session's source map, or `None` if it does not point into a source.
+    fn from_span(span: Span) -> Option<Self> {
+        if span.is_dummy() {
+            return None;
+        }
+        with_session_globals(|session| {
+            let source_file = session.source_map.find_source_file(span.lo)?;
+            if span.hi < span.lo || span.hi > source_file.absolute_end {
+                return None;
+            }
+            let (line, column) = source_file.line_col(span.lo);
+            let (end_line, end_column) = source_file.line_col(span.hi);
+            let file = match &source_file.name {
+                FileName::Real(path) => path.display().to_string(),
+                FileName::Custom(name) => name.clone(),
+            };
+            Some(Self {
+                file,
+                line: line as usize + 1,
+                column: column as usize + 1,
+                end_line: end_line as usize + 1,
+                end_column: end_column as usize + 1,
+            })
+        })
+    }
+
+    /// Renders the location, followed by the underlined source if the file can still be read.
+    pub fn render(&self) -> String {
+        let path = Path::new(&self.file);
+        let shown = std::env::current_dir().ok().and_then(|dir| path.strip_prefix(dir).ok()).unwrap_or(path);
+        let header = format!("    --> {}:{}:{}", shown.display(), self.line, self.column);
+
+        let Ok(source) = std::fs::read_to_string(path) else {
+            return header;
+        };
+        let lines = source.lines().collect::<Vec<_>>();
+        let Some(selected) = lines.get(self.line.saturating_sub(1)..self.end_line.min(lines.len())) else {
+            return header;
+        };
+        if selected.is_empty() {
+            return header;
+        }
+        // `LineContents` counts in characters from the start of the first selected line.
+        let preceding = selected[..selected.len() - 1].iter().map(|line| line.chars().count() + 1).sum::<usize>();
+        let contents = selected.join("\n");
+        let snippet = LineContents {
+            contents: &contents,
+            line: self.line - 1,
+            start: self.column - 1,
+            end: preceding + self.end_column - 1,
+        };
+        format!("{header}\n{}", snippet.to_string().trim_end())
+    }
+}
+
+impl AleoProgram {
+    /// Serializes the program to bytecode, along with a source map for its instructions.
+    pub fn to_bytecode_with_source_map(&self) -> (String, AleoSourceMap) {
+        let bytecode = self.to_string();
+        // Instructions print one per line and in order, so each one is the next line with its text.
+        let mut lines = bytecode.lines().enumerate();
+        let mut entries = Vec::new();
+        for (block, statements) in self.blocks() {
+            let instructions = statements
+                .iter()
+                .zip(statements.spans())
+                .filter(|(statement, _)| !matches!(statement, AleoStmt::Output(..)));
+            for (index, (statement, span)) in instructions.enumerate() {
+                let instruction = statement.to_string().trim().to_string();
+                let Some((line, _)) = lines.find(|(_, line)| line.trim() == instruction) else {
+                    break;
+                };
+                if let Some(source) = SourceLocation::from_span(*span) {
+                    entries.push(SourceMapEntry {
+                        block: block.clone(),
+                        index,
+                        bytecode_line: line + 1,
+                        instruction,
+                        source,
+                    });
+                }
+            }
+        }
+        (bytecode, AleoSourceMap { program: self.program_id.to_string(), entries })
+    }
+
+    /// The instruction blocks of the program with their bytecode headers, in bytecode order.
+    fn blocks(&self) -> Vec<(String, &AleoStatements)> {
+        let mut blocks = Vec::new();
+        for functional in &self.functions {
+            match functional {
+                AleoFunctional::Closure(closure) => {
+                    blocks.push((format!("closure {}", closure.name), &closure.statements))
+                }
+                AleoFunctional::Function(function) => {
+                    blocks.push((format!("function {}", function.name), &function.statements));
+                    if let Some(finalize) = &function.finalize {
+                        blocks.push((format!("finalize {}", finalize.caller_name), &finalize.statements));
+                    }
+                }
+                AleoFunctional::Finalize(finalize) => {
+                    blocks.push((format!("finalize {}", finalize.caller_name), &finalize.statements))
+                }
+                AleoFunctional::View(view) => blocks.push((format!("view {}", view.name), &view.statements)),
+            }
+        }
+        if let Some(constructor) = &self.constructor {
+            blocks.push(("constructor".to_string(), &constructor.statements));
+        }
+        blocks
+    }
+}
+
+/// Collapses runs of whitespace so instruction text compares equal however it was spaced.
+fn normalize(instruction: &str) -> String {
+    instruction.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(block: &str, index: usize, instruction: &str, line: usize) -> SourceMapEntry {
+        SourceMapEntry {
+            block: block.to_string(),
+            index,
+            bytecode_line: line,
+            instruction: instruction.to_string(),
+            source: SourceLocation { file: "main.leo".to_string(), line, column: 9, end_line: line, end_column: 20 },
+        }
+    }
+
+    /// Verifies a snarkVM failure resolves to the innermost instruction, preferring the called function's block.
+    #[test]
+    fn locate_prefers_innermost_instruction_in_called_function() {
+        let map = AleoSourceMap {
+            program: "test.aleo".to_string(),
+            entries: vec![
+                entry("closure helper", 1, "assert.eq r1 true;", 4),
+                entry("function main", 0, "call helper r0 into r1;", 9),
+                entry("function main", 1, "assert.eq r1 true;", 10),
+            ],
+        };
+
+        let message = "Stack evaluation failed: Instruction (assert.eq r1 true;) at index 1 failed: 'assert.eq' failed";
+        assert_eq!(map.locate(message, Some("main")).unwrap().source.line, 10);
+        assert_eq!(map.locate(message, Some("helper")).unwrap().source.line, 4);
+
+        let nested = "Instruction (call helper r0 into r1;) at index 0 failed: Instruction (assert.eq r1  true;) at index 1 failed";
+        assert_eq!(map.locate(nested, Some("other")).unwrap().source.line, 4);
+
+        assert!(map.locate("Failed to parse input", Some("main")).is_none());
+        assert_eq!(map.entry("function main", 1).unwrap().source.line, 10);
+        assert!(map.entry("finalize main", 1).is_none());
+        assert_eq!(map.annotate("Failed to parse input", Some("main")), "Failed to parse input");
+    }
+}
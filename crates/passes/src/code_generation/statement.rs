@@ -28,6 +28,7 @@ use leo_ast::{
     ExpressionStatement,
     IterationStatement,
     Mode,
+    Node,
     Output,
     ReturnStatement,
     Statement,
@@ -38,20 +39,28 @@ use leo_ast::{
 use indexmap::IndexMap;
 
 impl CodeGeneratingVisitor<'_> {
-    fn visit_statement(&mut self, input: &Statement) -> Vec<AleoStmt> {
-        match input {
+    fn visit_statement(&mut self, input: &Statement) -> AleoStatements {
+        let instructions = match input {
+            // Nested statements carry their own spans.
+            Statement::Block(stmt) => return self.visit_block(stmt),
+            Statement::Conditional(stmt) => return self.visit_conditional(stmt),
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => vec![self.visit_assign(stmt)],
-            Statement::Block(stmt) => self.visit_block(stmt),
-            Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Const(_) => {
                 panic!("`ConstStatement`s should not be in the AST at this phase of compilation.")
             }
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => vec![self.visit_iteration(stmt)],
+            Statement::Match(..) => {
+                panic!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Return(stmt) => self.visit_return(stmt),
-        }
+            Statement::Break(..) | Statement::Continue(..) | Statement::While(..) => {
+                panic!("Loops should be unrolled before this phase of compilation.")
+            }
+        };
+        AleoStatements::spanned(instructions, input.span())
     }
 
     fn visit_assert(&mut self, input: &AssertStatement) -> Vec<AleoStmt> {
@@ -200,7 +209,7 @@ impl CodeGeneratingVisitor<'_> {
         panic!("AssignStatement's should not exist in SSA form.")
     }
 
-    fn visit_conditional(&mut self, _input: &ConditionalStatement) -> Vec<AleoStmt> {
+    fn visit_conditional(&mut self, _input: &ConditionalStatement) -> AleoStatements {
         // Note that this unwrap is safe because we set the variant before traversing the function.
         if !self.variant.unwrap().is_finalize() {
             panic!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
@@ -226,30 +235,33 @@ impl CodeGeneratingVisitor<'_> {
             self.conditional_depth += 1;
 
             // Create a `branch` instruction.
-            let (condition, mut instructions) = self.visit_expression(&_input.condition);
+            let (condition, condition_instructions) = self.visit_expression(&_input.condition);
             let condition = condition.expect("Trying to branch on an empty expression");
-            instructions.push(AleoStmt::BranchEq(condition, AleoExpr::Bool(false), end_then_label.clone()));
+            let mut instructions = AleoStatements::spanned(condition_instructions, _input.condition.span());
+            instructions.push(
+                AleoStmt::BranchEq(condition, AleoExpr::Bool(false), end_then_label.clone()),
+                _input.condition.span(),
+            );
 
             // Visit the `then` block.
             instructions.extend(self.visit_block(&_input.then));
             // If the `otherwise` block is present, add a branch instruction to jump to the end of the `otherwise` block.
             if has_otherwise {
-                instructions.push(AleoStmt::BranchEq(
-                    AleoExpr::Bool(true),
-                    AleoExpr::Bool(true),
-                    end_otherwise_label.clone(),
-                ));
+                instructions.push(
+                    AleoStmt::BranchEq(AleoExpr::Bool(true), AleoExpr::Bool(true), end_otherwise_label.clone()),
+                    _input.span,
+                );
             }
 
             // Add a label for the end of the `then` block.
-            instructions.push(AleoStmt::Position(end_then_label));
+            instructions.push(AleoStmt::Position(end_then_label), _input.span);
 
             // Visit the `otherwise` block.
             if let Some(else_block) = &_input.otherwise {
                 // Visit the `otherwise` block.
                 instructions.extend(self.visit_statement(else_block));
                 // Add a label for the end of the `otherwise` block.
-                instructions.push(AleoStmt::Position(end_otherwise_label));
+                instructions.push(AleoStmt::Position(end_otherwise_label), _input.span);
             }
 
             // Decrement the conditional depth.
@@ -263,8 +275,8 @@ impl CodeGeneratingVisitor<'_> {
         panic!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
-    pub(crate) fn visit_block(&mut self, input: &Block) -> Vec<AleoStmt> {
+    pub(crate) fn visit_block(&mut self, input: &Block) -> AleoStatements {
         // For each statement in the block, visit it and add its instructions to the list.
-        input.statements.iter().flat_map(|stmt| self.visit_statement(stmt)).collect()
+        input.statements.iter().map(|stmt| self.visit_statement(stmt)).collect()
     }
 }
@@ -154,7 +154,8 @@ impl CodeGeneratingVisitor<'_> {
     ///
     /// # Behavior
     /// - If the path is a single valid Leo identifier (`[a-zA-Z][a-zA-Z0-9_]*`), it's returned as-is.
-    /// - If the last segment matches `Name::[args]` (e.g. `Vec3::[3, 4]`), it's converted to a legal identifier using hashing.
+    /// - If the last segment matches `Name::[args]` (e.g. `Vec3::[3, 4]`) or `Type::name` (e.g. `Point::sum`, optionally
+    ///   followed by `::[args]`), it's converted to a legal identifier using hashing.
     /// - If the path has multiple segments, and all segments are valid identifiers except the last one (which may be `Name::[args]`),
     ///   it's hashed using the last segment as base.
     /// - Returns `None` if:
@@ -242,14 +243,19 @@ impl CodeGeneratingVisitor<'_> {
             return Some(generate_hashed_name(path, &(truncated_prefix.to_owned() + "__")));
         }
 
-        // === Case 3: Matches special form like `path::to::Name::[3, 4]` ===
-        let re = regex::Regex::new(r#"^([a-zA-Z_][\w]*)(?:::\[.*?\])?$"#).unwrap();
+        // === Case 3: Matches special form like `path::to::Name::[3, 4]` or `path::to::Type::name::[3]` ===
+        //
+        // The `Type::name` form is the name of a function declared in an `impl` block of `Type`.
+        let re = regex::Regex::new(r#"^(?:([a-zA-Z_][\w]*)::)?([a-zA-Z_][\w]*)(?:::\[.*?\])?$"#).unwrap();
 
         if let Some(captures) = re.captures(&last) {
-            let ident = captures.get(1)?.as_str();
+            let ident = match captures.get(1) {
+                Some(type_name) => format!("{}_{}", type_name.as_str(), captures.get(2)?.as_str()),
+                None => captures.get(2)?.as_str().to_string(),
+            };
 
             // The produced name here will be of the form: `<last>__AYMqiUeJeQN`.
-            return Some(generate_hashed_name(path, ident));
+            return Some(generate_hashed_name(path, &ident));
         }
 
         // === Case 4: Matches special form like `path::to::Name?` (last always ends with `?`) ===
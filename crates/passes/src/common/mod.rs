@@ -80,6 +80,10 @@ fn contains_non_discardable_operation(expr: &Expression, state: &CompilerState)
                     .filter_map(|init| init.expression.as_ref())
                     .any(|member| contains_non_discardable_operation(member, state))
         }
+        Expression::Match(expr) => {
+            contains_non_discardable_operation(&expr.scrutinee, state)
+                || expr.arms.iter().any(|arm| contains_non_discardable_operation(&arm.expression, state))
+        }
         Expression::MemberAccess(expr) => contains_non_discardable_operation(&expr.inner, state),
         Expression::Repeat(expr) => {
             contains_non_discardable_operation(&expr.expr, state)
@@ -100,6 +104,7 @@ fn contains_non_discardable_operation(expr: &Expression, state: &CompilerState)
         | Expression::DynamicOp(_)
         | Expression::Err(_)
         | Expression::Literal(_)
+        | Expression::MethodCall(_)
         | Expression::Unit(_) => false,
     }
 }
@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{AstReconstructor, Block, Expression, IterationStatement, Node as _, Statement, UnitReconstructor};
+use leo_ast::{
+    AstReconstructor,
+    Block,
+    Expression,
+    IterationStatement,
+    MatchArm,
+    MatchExpression,
+    MatchStatement,
+    MatchStatementArm,
+    Node as _,
+    Statement,
+    UnitReconstructor,
+};
 
 use crate::CompilerState;
 
@@ -24,8 +36,8 @@ use crate::CompilerState;
 /// - **Rename identifiers**: to systematically rename identifiers throughout the AST.
 /// - **Expression interpolation**: such as substituting arguments into function bodies.
 ///
-/// During reconstruction, node IDs for blocks and loop bodies are regenerated using a `NodeBuilder` to ensure
-/// that the resulting scopes are different from the original. This avoids issues with stale or conflicting
+/// During reconstruction, node IDs for blocks, loop bodies and match arms are regenerated using a `NodeBuilder` to
+/// ensure that the resulting scopes are different from the original. This avoids issues with stale or conflicting
 /// parent-child relationships between scopes.
 ///
 /// The replacement function (`replace`) is applied early in expression reconstruction. If it produces a new
@@ -79,6 +91,8 @@ where
                 Expression::Err(err) => self.reconstruct_err(err, &()),
                 Expression::Path(path) => self.reconstruct_path(path, &()),
                 Expression::Literal(value) => self.reconstruct_literal(value, &()),
+                Expression::Match(match_) => self.reconstruct_match(*match_, &()),
+                Expression::MethodCall(call) => self.reconstruct_method_call(*call, &()),
                 Expression::MemberAccess(access) => self.reconstruct_member_access(*access, &()),
                 Expression::Repeat(repeat) => self.reconstruct_repeat(*repeat, &()),
                 Expression::Ternary(ternary) => self.reconstruct_ternary(*ternary, &()),
@@ -115,6 +129,46 @@ where
         )
     }
 
+    fn reconstruct_match(&mut self, input: MatchExpression, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        (
+            MatchExpression {
+                scrutinee: self.reconstruct_expression(input.scrutinee, &()).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        expression: self.reconstruct_expression(arm.expression, &()).0,
+                        id: self.state.node_builder.next_id(),
+                        ..arm
+                    })
+                    .collect(),
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            MatchStatement {
+                scrutinee: self.reconstruct_expression(input.scrutinee, &()).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchStatementArm {
+                        block: self.reconstruct_block(arm.block).0,
+                        id: self.state.node_builder.next_id(),
+                        ..arm
+                    })
+                    .collect(),
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
         (
             IterationStatement {
@@ -268,6 +268,11 @@ impl SymbolTable {
         self.interfaces.iter()
     }
 
+    /// Iterator over all the global variables (consts, mappings, and storage) in this program.
+    pub fn iter_globals(&self) -> impl Iterator<Item = (&Location, &VariableSymbol)> {
+        self.globals.iter()
+    }
+
     /// Access a struct by this location if it exists and is accessible from the compilation unit `current_unit`.
     pub fn lookup_struct(&self, current_unit: Symbol, loc: &Location) -> Option<&Composite> {
         if self.is_visible(current_unit, &loc.program) { self.structs.get(loc) } else { None }
@@ -394,6 +399,25 @@ impl SymbolTable {
         new_id
     }
 
+    /// Moves the scopes `children` under the existing scope `new_parent`.
+    ///
+    /// Used when statements are wrapped in a newly created block, so that the scopes they own
+    /// are entered from that block from then on.
+    pub fn reparent_scopes(&mut self, children: &[NodeID], new_parent: NodeID) {
+        let new_parent_table = self.all_locals.get(&new_parent).expect("New parent scope must exist.").clone();
+
+        for child in children {
+            let child_table = self.all_locals.get(child).expect("Child scope must exist.");
+            let old_parent = child_table.inner.borrow_mut().parent.replace(new_parent);
+
+            if let Some(old_parent_table) = old_parent.and_then(|id| self.all_locals.get(&id)) {
+                old_parent_table.inner.borrow_mut().children.retain(|id| id != child);
+            }
+
+            new_parent_table.inner.borrow_mut().children.push(*child);
+        }
+    }
+
     /// Enter the parent scope of the current scope (or the global scope if there is no local parent scope).
     pub fn enter_parent(&mut self) {
         let parent: Option<NodeID> = self.local.as_ref().and_then(|table| table.inner.borrow().parent);
@@ -510,6 +534,16 @@ impl SymbolTable {
         Ok(())
     }
 
+    /// Remove the struct at this location, if there is one.
+    pub fn remove_struct(&mut self, location: &Location) -> Option<Composite> {
+        self.structs.shift_remove(location)
+    }
+
+    /// Remove the function at this location, if there is one.
+    pub fn remove_function(&mut self, location: &Location) -> Option<FunctionSymbol> {
+        self.functions.shift_remove(location)
+    }
+
     /// Insert an interface at this location.
     pub fn insert_interface(&mut self, location: Location, int: Interface) -> Result<()> {
         self.check_shadow_global(&location, int.identifier.span)?;
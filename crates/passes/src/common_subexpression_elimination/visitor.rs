@@ -115,7 +115,9 @@ impl CommonSubexpressionEliminatingVisitor<'_> {
             | Expression::Call(_)
             | Expression::Cast(_)
             | Expression::Err(_)
+            | Expression::Match(_)
             | Expression::MemberAccess(_)
+            | Expression::MethodCall(_)
             | Expression::Repeat(_)
             | Expression::Composite(_)
             | Expression::Ternary(_)
@@ -245,6 +247,10 @@ impl CommonSubexpressionEliminatingVisitor<'_> {
 
             Expression::TupleAccess(_) => panic!("Tuple access expressions should not exist in this pass."),
 
+            Expression::Match(_) => panic!("Match expressions should not exist in this pass."),
+
+            Expression::MethodCall(_) => panic!("Method call expressions should not exist in this pass."),
+
             Expression::DynamicOp(op) => {
                 self.try_atom(&mut op.target_program)?;
                 if let Some(ref mut network) = op.network {
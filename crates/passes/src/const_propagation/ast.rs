@@ -62,6 +62,8 @@ impl AstReconstructor for ConstPropagationVisitor<'_> {
             Expression::Err(err) => self.reconstruct_err(err, &()),
             Expression::Path(path) => self.reconstruct_path(path, &()),
             Expression::Literal(value) => self.reconstruct_literal(value, &()),
+            Expression::Match(match_) => self.reconstruct_match(*match_, &()),
+            Expression::MethodCall(call) => self.reconstruct_method_call(*call, &()),
             Expression::MemberAccess(access) => self.reconstruct_member_access(*access, &()),
             Expression::Repeat(repeat) => self.reconstruct_repeat(*repeat, &()),
             Expression::Ternary(ternary) => self.reconstruct_ternary(*ternary, &()),
@@ -138,6 +140,21 @@ impl AstReconstructor for ConstPropagationVisitor<'_> {
         }
     }
 
+    fn reconstruct_match(
+        &mut self,
+        mut input: MatchExpression,
+        _additional: &(),
+    ) -> (Expression, Self::AdditionalOutput) {
+        // Enums are only lowered later, so a match never has a value here.
+        input.scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        for arm in input.arms.iter_mut() {
+            let expression = std::mem::take(&mut arm.expression);
+            arm.expression = self.in_scope(arm.id, |slf| slf.reconstruct_expression(expression, &()).0);
+        }
+
+        (input.into(), None)
+    }
+
     fn reconstruct_array_access(
         &mut self,
         input: ArrayAccess,
@@ -552,6 +569,16 @@ impl AstReconstructor for ConstPropagationVisitor<'_> {
         (Statement::Conditional(conditional), None)
     }
 
+    fn reconstruct_match_statement(&mut self, mut input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        input.scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        for arm in input.arms.iter_mut() {
+            let block = std::mem::take(&mut arm.block);
+            arm.block = self.in_scope(arm.id, |slf| slf.reconstruct_block(block).0);
+        }
+
+        (Statement::Match(input), None)
+    }
+
     fn reconstruct_const(&mut self, mut input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
         if matches!(input.type_, Type::Optional(_)) {
             return (input.into(), None);
@@ -348,11 +348,17 @@ impl AstReconstructor for DestructuringVisitor<'_> {
 
         // Reconstruct the statements in the block, accumulating any additional statements.
         for statement in block.statements {
+            let span = statement.span();
             let (reconstructed_statement, additional_statements) = self.reconstruct_statement(statement);
+            // Statements introduced for `statement` inherit its span, so source maps point back at it.
+            let start = statements.len();
             statements.extend(additional_statements);
             if !reconstructed_statement.is_removable() {
                 statements.push(reconstructed_statement);
             }
+            for statement in statements[start..].iter_mut().filter(|statement| statement.span().is_dummy()) {
+                statement.set_span(span);
+            }
         }
 
         (Block { statements, ..block }, Default::default())
@@ -509,14 +515,9 @@ impl AstReconstructor for DestructuringVisitor<'_> {
             }
             (s @ Single(..), rhs, _) => {
                 // This isn't a tuple. Just build the definition again.
-                let stmt = DefinitionStatement {
-                    place: s,
-                    type_: None,
-                    value: rhs,
-                    span: Default::default(),
-                    id: definition.id,
-                }
-                .into();
+                let stmt =
+                    DefinitionStatement { place: s, type_: None, value: rhs, span: definition.span, id: definition.id }
+                        .into();
                 (stmt, statements)
             }
             (Multiple(_), _, _) => panic!("A definition with multiple identifiers must have tuple type"),
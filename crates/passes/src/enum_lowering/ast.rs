@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{EnumLoweringVisitor, payload_field};
+use crate::Replacer;
+
+use leo_ast::*;
+
+use indexmap::IndexMap;
+
+impl AstReconstructor for EnumLoweringVisitor<'_> {
+    type AdditionalInput = ();
+    type AdditionalOutput = ();
+
+    /* Expressions */
+    fn reconstruct_call(&mut self, input: CallExpression, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        let arguments = input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg, &()).0).collect();
+
+        // A variant with a payload, e.g. `Shape::Circle(r)`.
+        if let Some(variant) = self.lookup_variant(&input.function) {
+            return (self.lower_variant(&input.function, variant, arguments, input.span), ());
+        }
+
+        let const_arguments =
+            input.const_arguments.into_iter().map(|arg| self.reconstruct_expression(arg, &()).0).collect();
+        (CallExpression { const_arguments, arguments, ..input }.into(), ())
+    }
+
+    fn reconstruct_path(&mut self, input: Path, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        // A variant without a payload, e.g. `Shape::Empty`.
+        match self.lookup_variant(&input) {
+            Some(variant) => (self.lower_variant(&input, variant, vec![], input.span()), ()),
+            None => (input.into(), ()),
+        }
+    }
+
+    fn reconstruct_match(&mut self, input: MatchExpression, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let scrutinee = self.bind_scrutinee(scrutinee);
+        self.check_last_arm(&scrutinee, input.arms.iter().map(|arm| &arm.pattern));
+
+        // Build the ternaries from the last arm, which is taken if no other arm is.
+        let mut lowered: Option<Expression> = None;
+        for arm in input.arms.into_iter().rev() {
+            let mut expression = self.reconstruct_expression(arm.expression, &()).0;
+            let condition = match arm.pattern {
+                MatchPattern::Wildcard => None,
+                MatchPattern::Variant { path, bindings } => {
+                    let (_, index) = self.lookup_variant(&path).expect("type checking guarantees the variant exists");
+
+                    // Replace the bindings with the payload fields they bind.
+                    let mut replacements = IndexMap::new();
+                    for (j, binding) in bindings.iter().enumerate() {
+                        if let Some(binding) = binding {
+                            let field = self.field(&scrutinee, payload_field(index, j), binding.span);
+                            replacements.insert(binding.name, field);
+                        }
+                    }
+                    if !replacements.is_empty() {
+                        let replace = |expr: &Expression| match expr {
+                            Expression::Path(path) => path
+                                .try_local_symbol()
+                                .and_then(|symbol| replacements.get(&symbol).cloned())
+                                .unwrap_or_else(|| expr.clone()),
+                            _ => expr.clone(),
+                        };
+                        let replaced = Replacer::new(replace, false /* refresh IDs */, self.state)
+                            .reconstruct_expression(expression, &())
+                            .0;
+                        // A binding may be used more than once, so give each use its own node IDs.
+                        expression = self.copy(&replaced);
+                    }
+
+                    Some(self.tag_is(&scrutinee, index, path.span()))
+                }
+            };
+
+            lowered = Some(match (lowered, condition) {
+                (Some(if_false), Some(condition)) => TernaryExpression {
+                    condition,
+                    if_true: expression,
+                    if_false,
+                    span: input.span,
+                    id: self.state.node_builder.next_id(),
+                }
+                .into(),
+                _ => expression,
+            });
+        }
+
+        (lowered.expect("type checking guarantees that a match has an arm"), ())
+    }
+
+    /* Statements */
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        // Scrutinees bound while reconstructing a statement are defined right before it.
+        let outer_prologue = self.prologue.replace(Vec::new());
+
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(self.prologue.as_mut().expect("set above"));
+            statements.push(statement);
+        }
+
+        self.prologue = outer_prologue;
+
+        (Block { statements, ..input }, ())
+    }
+
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition, &()).0;
+        let then = self.reconstruct_block(input.then).0;
+
+        let otherwise = input.otherwise.map(|otherwise| {
+            // The scrutinees of an else-if condition must only be evaluated if the else branch is taken.
+            let outer_prologue = self.prologue.replace(Vec::new());
+            let statement = self.reconstruct_statement(*otherwise).0;
+            let mut statements = std::mem::replace(&mut self.prologue, outer_prologue).unwrap_or_default();
+            if statements.is_empty() {
+                Box::new(statement)
+            } else {
+                let span = statement.span();
+                statements.push(statement);
+                Box::new(Block { statements, span, id: self.state.node_builder.next_id() }.into())
+            }
+        });
+
+        (ConditionalStatement { condition, then, otherwise, ..input }.into(), ())
+    }
+
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let scrutinee = self.bind_scrutinee(scrutinee);
+        self.check_last_arm(&scrutinee, input.arms.iter().map(|arm| &arm.pattern));
+
+        // Build the conditionals from the last arm, which is taken if no other arm is.
+        let mut lowered: Option<Statement> = None;
+        for arm in input.arms.into_iter().rev() {
+            let mut block = self.reconstruct_block(arm.block).0;
+            let condition = match arm.pattern {
+                MatchPattern::Wildcard => None,
+                MatchPattern::Variant { path, bindings } => {
+                    let (_, index) = self.lookup_variant(&path).expect("type checking guarantees the variant exists");
+
+                    // Define the bindings at the top of the arm.
+                    let mut definitions = Vec::with_capacity(bindings.len());
+                    for (j, binding) in bindings.into_iter().enumerate() {
+                        if let Some(binding) = binding {
+                            let field = self.field(&scrutinee, payload_field(index, j), binding.span);
+                            definitions.push(self.state.assigner.simple_definition(
+                                binding,
+                                field,
+                                self.state.node_builder.next_id(),
+                            ));
+                        }
+                    }
+                    block.statements.splice(0..0, definitions);
+
+                    Some(self.tag_is(&scrutinee, index, path.span()))
+                }
+            };
+
+            lowered = Some(match (lowered, condition) {
+                (Some(otherwise), Some(condition)) => ConditionalStatement {
+                    condition,
+                    then: block,
+                    otherwise: Some(Box::new(otherwise)),
+                    span: arm.span,
+                    id: self.state.node_builder.next_id(),
+                }
+                .into(),
+                _ => block.into(),
+            });
+        }
+
+        (lowered.expect("type checking guarantees that a match has an arm"), ())
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Performs lowering of enums and `match` expressions and statements.
+//!
+//! Every enum is rewritten into a struct with a `tag: u8` field holding the index of the variant, followed by one
+//! field per payload value of each variant, named `v{variant}_{index}`. Constructing a variant sets the tag and its
+//! own payload fields, and sets the payload fields of all other variants to their zero value.
+//!
+//! ### Example
+//!
+//! ```leo
+//! enum Shape { Empty, Circle(u32) }
+//!
+//! let s: Shape = Shape::Circle(2u32);
+//! ```
+//!
+//! is lowered to:
+//!
+//! ```leo
+//! struct Shape { tag: u8, v1_0: u32 }
+//!
+//! let s: Shape = Shape { tag: 1u8, v1_0: 2u32 };
+//! ```
+//!
+//! A `match` statement is lowered to a chain of conditionals on the tag, with its bindings defined at the top of
+//! each arm, and a `match` expression to a chain of ternaries with its bindings replaced by the payload fields:
+//!
+//! ```leo
+//! match s {
+//!     Shape::Circle(r) => { x = r; }
+//!     _ => {}
+//! }
+//! let area = match s { Shape::Circle(r) => r * r, Shape::Empty => 0u32 };
+//! ```
+//!
+//! is lowered to:
+//!
+//! ```leo
+//! if s.tag == 1u8 { let r = s.v1_0; x = r; } else {}
+//! assert(s.tag <= 1u8);
+//! let area = s.tag == 1u8 ? s.v1_0 * s.v1_0 : 0u32;
+//! ```
+//!
+//! The last arm is taken if no other arm is. Unless it is a wildcard, the tag is first asserted to name a variant,
+//! as above, so that a value with an invalid tag halts rather than taking it. A scrutinee that is not a path is
+//! first bound to a fresh variable, so that it is evaluated once.
+//!
+//! After this pass, no enums, variants or `match`es remain in the program.
+
+use crate::{
+    GlobalItemsCollection,
+    GlobalVarsCollection,
+    Pass,
+    PathResolution,
+    SymbolTable,
+    TypeChecking,
+    TypeCheckingInput,
+};
+
+use leo_ast::{Ast, UnitReconstructor as _};
+use leo_errors::Result;
+
+use indexmap::IndexMap;
+
+mod ast;
+
+mod program;
+
+mod visitor;
+use visitor::*;
+pub use visitor::{payload_field, tag_field};
+
+pub struct EnumLowering;
+
+impl Pass for EnumLowering {
+    type Input = TypeCheckingInput;
+    type Output = ();
+
+    const NAME: &str = "EnumLowering";
+
+    fn do_pass(input: TypeCheckingInput, state: &mut crate::CompilerState) -> Result<Self::Output> {
+        let ast = std::mem::take(&mut state.ast);
+        let mut visitor = EnumLoweringVisitor { state, composites: IndexMap::new(), prologue: None };
+
+        // Register every composite first, so that variants and zero values can be built for enums and structs
+        // defined anywhere, including libraries and imported programs.
+        match &ast {
+            Ast::Program(program) => visitor.collect_composites_from_program(program),
+            Ast::Library(library) => visitor.collect_composites_from_library(library),
+        }
+
+        // Nothing to lower without enums.
+        if !visitor.composites.values().any(|composite| composite.is_enum()) {
+            visitor.state.ast = ast;
+            return Ok(());
+        }
+
+        let ast = match ast {
+            Ast::Program(program) => Ast::Program(visitor.reconstruct_program(program)),
+            Ast::Library(library) => Ast::Library(visitor.reconstruct_library(library)),
+        };
+
+        visitor.state.handler.last_err()?;
+        visitor.state.ast = ast;
+
+        // We need to recreate the symbol table and run type checking again because this pass replaces enums with
+        // structs and `match`es with conditionals.
+        visitor.state.symbol_table = SymbolTable::default();
+        GlobalVarsCollection::do_pass((), state)?;
+        PathResolution::do_pass((), state)?;
+        GlobalItemsCollection::do_pass((), state)?;
+        TypeChecking::do_pass(input, state)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{EnumLoweringVisitor, lowered_fields};
+use crate::common::{library_composites, program_composites};
+use leo_ast::{AleoProgram, Composite, Identifier, Library, Location, Member, Mode, Program, Stub, UnitReconstructor};
+
+impl EnumLoweringVisitor<'_> {
+    /// Registers every composite defined in `input` and in its stubs in `self.composites`.
+    pub fn collect_composites_from_program(&mut self, input: &Program) {
+        for (loc, c) in program_composites(input) {
+            self.composites.entry(loc).or_insert_with(|| c.clone());
+        }
+        for (_, stub) in &input.stubs {
+            self.collect_composites_from_stub(stub);
+        }
+    }
+
+    pub fn collect_composites_from_library(&mut self, input: &Library) {
+        for (loc, c) in library_composites(input) {
+            self.composites.entry(loc).or_insert_with(|| c.clone());
+        }
+        for (_, stub) in &input.stubs {
+            self.collect_composites_from_stub(stub);
+        }
+    }
+
+    pub fn collect_composites_from_aleo_program(&mut self, input: &AleoProgram) {
+        let program = input.stub_id.as_symbol();
+        for (name, c) in &input.composites {
+            self.composites.entry(Location::new(program, vec![*name])).or_insert_with(|| c.clone());
+        }
+    }
+
+    pub fn collect_composites_from_stub(&mut self, stub: &Stub) {
+        match stub {
+            Stub::FromLeo { program, .. } => self.collect_composites_from_program(program),
+            Stub::FromAleo { program, .. } => self.collect_composites_from_aleo_program(program),
+            Stub::FromLibrary { library, .. } => self.collect_composites_from_library(library),
+        }
+    }
+}
+
+impl UnitReconstructor for EnumLoweringVisitor<'_> {
+    fn reconstruct_composite(&mut self, input: Composite) -> Composite {
+        if !input.is_enum() {
+            return input;
+        }
+
+        // Payload types are left as is: an enum type names the struct it is lowered to.
+        let members = lowered_fields(&input)
+            .into_iter()
+            .map(|(name, type_)| Member {
+                mode: Mode::None,
+                identifier: Identifier::new(name, self.state.node_builder.next_id()),
+                type_,
+                span: input.span,
+                id: self.state.node_builder.next_id(),
+            })
+            .collect();
+
+        Composite { members, variants: None, ..input }
+    }
+}
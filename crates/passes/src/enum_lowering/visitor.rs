@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CompilerState, Replacer};
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+pub struct EnumLoweringVisitor<'a> {
+    pub state: &'a mut CompilerState,
+    // All composites visible to this pass, keyed by owning unit and path, as they were before lowering.
+    pub composites: IndexMap<Location, Composite>,
+    // The statements to insert before the statement being reconstructed, or `None` outside of a block.
+    pub prologue: Option<Vec<Statement>>,
+}
+
+/// The name of the field holding the index of the variant of a lowered enum.
+pub fn tag_field() -> Symbol {
+    Symbol::intern("tag")
+}
+
+/// The name of the field holding the payload value at `index` of the variant at `variant` of a lowered enum.
+pub fn payload_field(variant: usize, index: usize) -> Symbol {
+    Symbol::intern(&format!("v{variant}_{index}"))
+}
+
+/// Returns the names and types of the fields of `composite` once lowered.
+pub fn lowered_fields(composite: &Composite) -> Vec<(Symbol, Type)> {
+    match &composite.variants {
+        Some(variants) => std::iter::once((tag_field(), Type::Integer(IntegerType::U8)))
+            .chain(variants.iter().enumerate().flat_map(|(i, variant)| {
+                variant.types.iter().enumerate().map(move |(j, type_)| (payload_field(i, j), type_.clone()))
+            }))
+            .collect(),
+        None => composite.members.iter().map(|member| (member.identifier.name, member.type_.clone())).collect(),
+    }
+}
+
+impl EnumLoweringVisitor<'_> {
+    /// Returns the location of the enum and the index of the variant, if `path` names a variant of an enum.
+    pub fn lookup_variant(&self, path: &Path) -> Option<(Location, usize)> {
+        let location = path.try_global_location()?;
+        let (variant, enum_path) = location.path.split_last()?;
+        let enum_location = Location::new(location.program, enum_path.to_vec());
+        let index =
+            self.composites.get(&enum_location)?.variants.as_ref()?.iter().position(|v| v.name() == *variant)?;
+        Some((enum_location, index))
+    }
+
+    /// Lowers the construction of the variant at `index` of the enum at `enum_location`, named by `path`, with
+    /// `arguments` as its payload.
+    pub fn lower_variant(
+        &self,
+        path: &Path,
+        (enum_location, index): (Location, usize),
+        mut arguments: Vec<Expression>,
+        span: Span,
+    ) -> Expression {
+        let node_builder = &self.state.node_builder;
+        let variants = self.composites[&enum_location].variants.as_ref().expect("`lookup_variant` only returns enums");
+
+        let mut members = vec![CompositeFieldInitializer {
+            identifier: Identifier::new(tag_field(), node_builder.next_id()),
+            expression: Some(Literal::integer(IntegerType::U8, index.to_string(), span, node_builder.next_id()).into()),
+            span,
+            id: node_builder.next_id(),
+        }];
+        arguments.reverse();
+        for (i, variant) in variants.iter().enumerate() {
+            for (j, type_) in variant.types.iter().enumerate() {
+                let expression = if i == index {
+                    arguments.pop().expect("type checking guarantees the number of arguments")
+                } else {
+                    self.zero(type_, span)
+                };
+                members.push(CompositeFieldInitializer {
+                    identifier: Identifier::new(payload_field(i, j), node_builder.next_id()),
+                    expression: Some(expression),
+                    span,
+                    id: node_builder.next_id(),
+                });
+            }
+        }
+
+        // The enum is named as the variant names it, i.e. `Shape` for `Shape::Circle`.
+        let mut qualifier = path.qualifier().to_vec();
+        let identifier = qualifier.pop().unwrap_or(*path.identifier());
+        let path = Path::new(path.user_program().copied(), qualifier, identifier, path.span(), node_builder.next_id())
+            .to_global(enum_location);
+
+        CompositeExpression { path, const_arguments: vec![], members, base: None, span, id: node_builder.next_id() }
+            .into()
+    }
+
+    /// Returns the zero value of `type_`, with enums zeroed as the structs they are lowered to.
+    pub fn zero(&self, type_: &Type, span: Span) -> Expression {
+        let composites = &self.composites;
+        let composite_lookup = |loc: &Location| {
+            composites
+                .get(loc)
+                .map(lowered_fields)
+                .unwrap_or_else(|| panic!("no composite registered for {loc:?}; every composite is collected first"))
+        };
+        Expression::zero(type_, span, &self.state.node_builder, &composite_lookup)
+            .expect("type checking guarantees that payload types have a zero value")
+    }
+
+    /// Returns an expression reading the scrutinee of a `match`.
+    ///
+    /// Inside a block, a scrutinee that is not a path is bound to a fresh variable first, so that it is evaluated
+    /// once. Elsewhere, i.e. in global constants, it is evaluated at compile time and is returned as is.
+    pub fn bind_scrutinee(&mut self, scrutinee: Expression) -> Expression {
+        if matches!(scrutinee, Expression::Path(_)) || self.prologue.is_none() {
+            return scrutinee;
+        }
+        let name = self.state.assigner.unique_symbol("$match", "$");
+        let ident = Identifier::new(name, self.state.node_builder.next_id());
+        let stmt = self.state.assigner.simple_definition(ident, scrutinee, self.state.node_builder.next_id());
+        self.prologue.as_mut().expect("checked above").push(stmt);
+        Path::from(ident).to_local().into()
+    }
+
+    /// Inside a block, asserts before the statement being reconstructed that the tag of `scrutinee`, a value of the
+    /// enum matched by `arms`, names one of its variants. This checks the last arm of a `match` without a wildcard,
+    /// which is taken if no other arm is.
+    pub fn check_last_arm<'p>(&mut self, scrutinee: &Expression, patterns: impl Iterator<Item = &'p MatchPattern>) {
+        // Outside of a block, the scrutinee is evaluated at compile time, so its tag is always valid.
+        if self.prologue.is_none() {
+            return;
+        }
+        let paths: Option<Vec<&Path>> = patterns
+            .map(|pattern| match pattern {
+                MatchPattern::Wildcard => None,
+                MatchPattern::Variant { path, .. } => Some(path),
+            })
+            .collect();
+        let Some(&path) = paths.as_ref().and_then(|paths| paths.first()) else {
+            return;
+        };
+        let (enum_location, _) = self.lookup_variant(path).expect("type checking guarantees the variant exists");
+        let count =
+            self.composites[&enum_location].variants.as_ref().expect("`lookup_variant` only returns enums").len();
+
+        let span = path.span();
+        let tag = self.field(scrutinee, tag_field(), span);
+        let node_builder = &self.state.node_builder;
+        let condition = BinaryExpression {
+            left: tag,
+            right: Literal::integer(IntegerType::U8, (count - 1).to_string(), span, node_builder.next_id()).into(),
+            op: BinaryOperation::Lte,
+            span,
+            id: node_builder.next_id(),
+        };
+        let assert =
+            AssertStatement { variant: AssertVariant::Assert(condition.into()), span, id: node_builder.next_id() };
+        self.prologue.as_mut().expect("checked above").push(assert.into());
+    }
+
+    /// Returns a copy of `expression` with fresh node IDs.
+    pub fn copy(&mut self, expression: &Expression) -> Expression {
+        Replacer::new(|expr: &Expression| expr.clone(), true /* refresh IDs */, self.state)
+            .reconstruct_expression(expression.clone(), &())
+            .0
+    }
+
+    /// Returns the expression `scrutinee.tag == index`.
+    pub fn tag_is(&mut self, scrutinee: &Expression, index: usize, span: Span) -> Expression {
+        let tag = self.field(scrutinee, tag_field(), span);
+        let node_builder = &self.state.node_builder;
+        BinaryExpression {
+            left: tag,
+            right: Literal::integer(IntegerType::U8, index.to_string(), span, node_builder.next_id()).into(),
+            op: BinaryOperation::Eq,
+            span,
+            id: node_builder.next_id(),
+        }
+        .into()
+    }
+
+    /// Returns the expression `scrutinee.name`.
+    pub fn field(&mut self, scrutinee: &Expression, name: Symbol, span: Span) -> Expression {
+        let inner = self.copy(scrutinee);
+        let node_builder = &self.state.node_builder;
+        MemberAccess { inner, name: Identifier::new(name, node_builder.next_id()), span, id: node_builder.next_id() }
+            .into()
+    }
+}
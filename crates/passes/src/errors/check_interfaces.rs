@@ -272,3 +272,21 @@ pub(crate) fn duplicate_interface_member(
     Formatted::error(CODE_PREFIX, CODE_MASK + 15, message, span)
         .with_help("Rename or remove one of the declarations. Member names must be unique within an interface.")
 }
+
+pub(crate) fn unsatisfied_bound(
+    type_: impl Display,
+    parameter: impl Display,
+    bound: impl Display,
+    reason: impl Display,
+    span: Span,
+) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 16,
+        format!("`{type_}` does not satisfy the bound `{parameter}: {bound}`: {reason}"),
+        span,
+    )
+    .with_help(format!(
+        "Declare the functions required by `{bound}` in an `impl {type_}` block, with the signatures declared by `{bound}`."
+    ))
+}
@@ -21,4 +21,5 @@ pub(crate) mod compiler;
 pub(crate) mod loop_unroller;
 pub(crate) mod name_validation;
 pub(crate) mod static_analyzer;
+pub(crate) mod suggestions;
 pub(crate) mod type_checker;
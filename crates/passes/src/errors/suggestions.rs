@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Machine-applicable fixes attached to pass diagnostics.
+//!
+//! Editor tooling offers these as quick fixes. Every helper reads the source
+//! text it edits, so it returns `None` whenever the span does not map to real
+//! source (e.g. nodes synthesized by earlier passes) rather than guess.
+
+use leo_ast::{Block, IntegerType, Type};
+use leo_errors::Suggestion;
+use leo_span::{Span, Symbol, with_session_globals};
+
+use std::fmt::Display;
+
+/// One level of indentation in canonically formatted Leo code.
+const INDENT: &str = "    ";
+
+/// Cast the expression under `span` to `expected`.
+///
+/// Only offered between numeric types, where `as` is defined in both directions.
+pub(crate) fn cast(actual: &Type, expected: &Type, span: Span) -> Option<Suggestion> {
+    let numeric = |type_: &Type| matches!(type_, Type::Integer(_) | Type::Field | Type::Group | Type::Scalar);
+    if !numeric(actual) || !numeric(expected) {
+        return None;
+    }
+    let source = source_of(span)?;
+    let operand = if needs_parentheses(&source) { format!("({source})") } else { source };
+    Some(Suggestion::replace(format!("Cast to `{expected}`"), span, format!("{operand} as {expected}")))
+}
+
+/// Replace the suffix of the integer literal under `span` with the `expected` type.
+pub(crate) fn literal_suffix(found: IntegerType, expected: &Type, span: Span) -> Option<Suggestion> {
+    if !matches!(expected, Type::Integer(_) | Type::Field | Type::Group | Type::Scalar) {
+        return None;
+    }
+    let suffix = found.to_string();
+    if !source_of(span)?.ends_with(&suffix) {
+        return None;
+    }
+    let suffix_span = Span::new(span.hi - suffix.len() as u32, span.hi);
+    Some(Suggestion::replace(format!("Change the literal suffix to `{expected}`"), suffix_span, expected))
+}
+
+/// Append a placeholder `return` to the end of `body`.
+pub(crate) fn return_placeholder(output: &Type, body: &Block) -> Option<Suggestion> {
+    let value = placeholder_value(output)?;
+    let statement = format!("return {value};");
+    let message = format!("Add `{statement}` at the end of the function");
+    let source = source_of(body.span)?;
+    let inner = source.strip_prefix('{')?.strip_suffix('}')?;
+    match inner.rsplit_once('\n') {
+        Some((_, indent)) if indent.chars().all(|ch| ch == ' ' || ch == '\t') => {
+            let position = body.span.hi - 1 - indent.len() as u32;
+            Some(Suggestion::insert(message, position, format!("{indent}{INDENT}{statement}\n")))
+        }
+        Some(_) => None,
+        None => {
+            let separator = if inner.ends_with(' ') { "" } else { " " };
+            Some(Suggestion::insert(message, body.span.hi - 1, format!("{separator}{statement} ")))
+        }
+    }
+}
+
+/// Give the bare `return` statement under `span` a placeholder value.
+pub(crate) fn return_value(output: &Type, span: Span) -> Option<Suggestion> {
+    let value = placeholder_value(output)?;
+    if !source_of(span)?.starts_with("return") {
+        return None;
+    }
+    Some(Suggestion::insert(format!("Return `{value}`"), span.lo + "return".len() as u32, format!(" {value}")))
+}
+
+/// Run every `Final` in `finals` at the start of `body`.
+pub(crate) fn run_finals(finals: &[impl Display], body: &Block) -> Option<Suggestion> {
+    let calls = finals.iter().map(|name| format!("{name}.run();")).collect::<Vec<_>>();
+    let message = match finals {
+        [name] => format!("Run `{name}`"),
+        _ => "Run the remaining `Final`s".to_owned(),
+    };
+    let source = source_of(body.span)?;
+    let inner = source.strip_prefix('{')?.strip_suffix('}')?;
+    let start = body.span.lo + 1;
+    let suggestion = match inner.rsplit_once('\n') {
+        Some((_, indent)) if indent.chars().all(|ch| ch == ' ' || ch == '\t') => {
+            let text = calls.iter().map(|call| format!("\n{indent}{INDENT}{call}")).collect::<String>();
+            Suggestion::insert(message, start, text)
+        }
+        Some(_) => return None,
+        None if inner.trim().is_empty() => {
+            Suggestion::replace(message, Span::new(start, body.span.hi - 1), format!(" {} ", calls.join(" ")))
+        }
+        None => Suggestion::insert(message, start, format!(" {}", calls.join(" "))),
+    };
+    Some(suggestion)
+}
+
+/// Import `program` in the file that declares the program scope at `program_span`.
+///
+/// The import goes before the first existing import, or before the `program`
+/// declaration when there are none.
+pub(crate) fn import_program(program: Symbol, program_span: Span) -> Option<Suggestion> {
+    let name = program.to_string();
+    if !name.ends_with(".aleo") {
+        return None;
+    }
+    with_session_globals(|s| {
+        let file = s.source_map.find_source_file(program_span.lo)?;
+        let mut offset = file.absolute_start;
+        for line in file.src.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            let text = if trimmed.starts_with("import ") {
+                format!("import {name};\n")
+            } else if trimmed.starts_with("program ") {
+                format!("import {name};\n\n")
+            } else {
+                offset += line.len() as u32;
+                continue;
+            };
+            return Some(Suggestion::insert(format!("Import `{name}`"), offset, text));
+        }
+        None
+    })
+}
+
+/// Return the source text under `span`, if it lies within a single source file.
+fn source_of(span: Span) -> Option<String> {
+    if span.lo >= span.hi {
+        return None;
+    }
+    with_session_globals(|s| {
+        let file = s.source_map.find_source_file(span.lo)?;
+        (span.hi <= file.absolute_end).then(|| file.contents_of_span(span).to_owned())
+    })
+}
+
+/// Whether `source` must be parenthesized to bind tighter than `as`.
+fn needs_parentheses(source: &str) -> bool {
+    let mut depth = 0i32;
+    for ch in source.chars() {
+        match ch {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '.' | ':' | '_' => {}
+            _ if depth == 0 && !ch.is_alphanumeric() => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// A literal of type `type_`, used as a placeholder the user is expected to replace.
+fn placeholder_value(type_: &Type) -> Option<String> {
+    Some(match type_ {
+        Type::Boolean => "false".to_owned(),
+        Type::Integer(integer) => format!("0{integer}"),
+        Type::Field => "0field".to_owned(),
+        Type::Group => "0group".to_owned(),
+        Type::Scalar => "0scalar".to_owned(),
+        Type::Optional(_) => "none".to_owned(),
+        Type::Tuple(tuple) => {
+            let elements = tuple.elements().iter().map(placeholder_value).collect::<Option<Vec<_>>>()?;
+            format!("({})", elements.join(", "))
+        }
+        _ => return None,
+    })
+}
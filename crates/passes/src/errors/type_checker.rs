@@ -1138,6 +1138,204 @@ pub(crate) fn offchain_op_in_view(operation: impl Display, span: Span) -> Format
         .with_help("Read the value in an off-chain scope and pass it in.")
 }
 
+pub(crate) fn cheatcode_outside_native_test(operation: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 197,
+        format!("`{operation}` can only be used in a `@test(mode = \"native\")` function"),
+        span,
+    )
+    .with_note("Native tests run in the interpreter, which is the only place test cheatcodes can take effect.")
+    .with_help(
+        "Annotate the test with `@test(mode = \"native\")`, or set up the state through the program's entry points.",
+    )
+}
+
+pub(crate) fn empty_enum(name: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 198, format!("enum `{name}` must have at least one variant"), span)
+}
+
+pub(crate) fn duplicate_enum_variant(enum_: impl Display, variant: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 199,
+        format!("enum `{enum_}` has more than one variant named `{variant}`"),
+        span,
+    )
+}
+
+pub(crate) fn too_many_enum_variants(name: impl Display, max: usize, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 200, format!("enum `{name}` has more than {max} variants"), span)
+        .with_note("The variant of an enum value is stored as a `u8` tag.")
+}
+
+pub(crate) fn unsupported_enum_payload_type(type_: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 201, format!("the type `{type_}` cannot be carried by an enum variant"), span)
+        .with_help(
+            "Variant payloads may be integers, `bool`, `address`, `field`, `group`, `scalar`, `signature`, arrays, structs, or other enums.",
+        )
+}
+
+pub(crate) fn incorrect_num_variant_args(
+    variant: impl Display,
+    expected: impl Display,
+    received: impl Display,
+    span: Span,
+) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 202,
+        format!("variant `{variant}` takes {expected} argument(s), but {received} were given"),
+        span,
+    )
+}
+
+pub(crate) fn variant_requires_arguments(variant: impl Display, expected: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 203, format!("variant `{variant}` takes {expected} argument(s)"), span)
+        .with_help(format!("Construct it with `{variant}(..)`."))
+}
+
+pub(crate) fn enum_cannot_be_constructed_as_struct(name: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 204, format!("enum `{name}` cannot be constructed with `{{ .. }}`"), span)
+        .with_help(format!("Construct one of its variants instead, e.g. `{name}::Variant`."))
+}
+
+pub(crate) fn match_on_non_enum(type_: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 205, format!("cannot `match` on a value of type `{type_}`"), span)
+        .with_help("Only enum values can be matched on.")
+}
+
+pub(crate) fn not_a_variant_of_enum(pattern: impl Display, enum_: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 206, format!("`{pattern}` is not a variant of enum `{enum_}`"), span)
+}
+
+pub(crate) fn incorrect_num_variant_bindings(
+    variant: impl Display,
+    expected: impl Display,
+    received: impl Display,
+    span: Span,
+) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 207,
+        format!("variant `{variant}` has {expected} field(s), but the pattern binds {received}"),
+        span,
+    )
+    .with_help("Use `_` for the fields you do not need.")
+}
+
+pub(crate) fn unreachable_match_arm(span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 208, "unreachable match arm", span)
+        .with_note("An earlier arm already matches every value this arm matches.")
+}
+
+pub(crate) fn non_exhaustive_match(missing: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 209, format!("non-exhaustive match: {missing} not covered"), span)
+        .with_help("Add an arm for each missing variant, or a `_` arm.")
+}
+
+pub(crate) fn match_arm_type_mismatch(expected: impl Display, found: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 210,
+        format!("match arms have incompatible types: expected `{expected}`, found `{found}`"),
+        span,
+    )
+}
+
+pub(crate) fn unknown_method(method: impl Display, type_: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 211, format!("no method `{method}` found for type `{type_}`"), span)
+        .with_help("Methods are declared in an `impl` block for the type, and take `self` as their first parameter.")
+}
+
+pub(crate) fn associated_function_is_not_a_method(
+    function: impl Display,
+    type_: impl Display,
+    span: Span,
+) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 212,
+        format!("`{function}` is an associated function of `{type_}`, not a method"),
+        span,
+    )
+    .with_help(format!("Call it as `{type_}::{function}(...)`, or take `self` as its first parameter."))
+}
+
+pub(crate) fn expected_type_argument(parameter: impl Display, found: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 213,
+        format!("expected a struct or record for the type parameter `{parameter}`, found `{found}`"),
+        span,
+    )
+    .with_help(
+        "A type parameter bounded by an interface, like `T: Hasher`, takes the name of a struct or record without const parameters, e.g. `hash::[Point](p)`.",
+    )
+}
+
+pub(crate) fn jump_outside_of_loop(keyword: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 214, format!("`{keyword}` outside of a loop"), span)
+        .with_help(format!("`{keyword}` can only be used inside the body of a `for` or `while` loop."))
+}
+
+pub(crate) fn early_loop_exit_in_final(construct: impl Display, span: Span) -> Formatted {
+    Formatted::error(CODE_PREFIX, CODE_MASK + 215, format!("{construct} cannot be used in a final context"), span)
+        .with_help(
+            "Loops that exit early are lowered to guarded code, which on-chain code cannot express yet. Use a `for` loop over a constant range and guard its body with a ternary `?` instead.",
+        )
+}
+
+pub(crate) fn function_cannot_take_enum_as_input(name: impl Display, ty: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 216,
+        format!("the input `{name}` has type `{ty}`, which is or contains an enum"),
+        span,
+    )
+    .with_help("Inputs to entry point and view fns cannot be enums, as their tags could not be trusted. Pass the variant's payload instead, and construct the enum in the fn.")
+}
+
+pub(crate) fn function_cannot_return_enum_as_output(ty: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 217,
+        format!("this function has output type `{ty}`, which is or contains an enum"),
+        span,
+    )
+    .with_help("Outputs of entry point and view fns cannot be enums. Return the variant's payload instead.")
+}
+
+pub(crate) fn record_field_cannot_be_enum(name: impl Display, ty: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 218,
+        format!("the field `{name}` has type `{ty}`, which is or contains an enum"),
+        span,
+    )
+    .with_help("Records cannot have enum fields, as their tags could not be trusted when the record is spent.")
+}
+
+pub(crate) fn enum_type_not_allowed_in_mapping(ty: impl Display, kind: impl Display, span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 219,
+        format!("the type `{ty}` contains an enum and cannot be used as the {kind} in a mapping"),
+        span,
+    )
+    .with_help(format!("Use a non-enum type for the mapping's {kind}, e.g. the enum's tag as an integer."))
+}
+
+pub(crate) fn const_cannot_be_enum(span: Span) -> Formatted {
+    Formatted::error(
+        CODE_PREFIX,
+        CODE_MASK + 220,
+        "constants cannot have an enum type or a type that contains an enum",
+        span,
+    )
+    .with_help("Construct the variant where it is used, or make the constant the variant's payload instead.")
+}
+
 // TypeCheckerWarning builder functions
 
 pub(crate) fn caller_as_record_owner(record_name: impl Display, span: Span) -> Formatted {
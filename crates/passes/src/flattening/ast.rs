@@ -281,9 +281,15 @@ impl AstReconstructor for FlatteningVisitor<'_> {
 
         // Flatten each statement, accumulating any new statements produced.
         for statement in block.statements {
+            let span = statement.span();
             let (reconstructed_statement, additional_statements) = self.reconstruct_statement(statement);
+            // Statements introduced for `statement` inherit its span, so source maps point back at it.
+            let start = statements.len();
             statements.extend(additional_statements);
             statements.push(reconstructed_statement);
+            for statement in statements[start..].iter_mut().filter(|statement| statement.span().is_dummy()) {
+                statement.set_span(span);
+            }
         }
 
         (Block { span: block.span, statements, id: self.state.node_builder.next_id() }, Default::default())
@@ -413,6 +419,18 @@ impl AstReconstructor for FlatteningVisitor<'_> {
         panic!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    fn reconstruct_while(&mut self, _input: WhileStatement) -> (Statement, Self::AdditionalOutput) {
+        panic!("`WhileStatement`s should not be in the AST at this phase of compilation.");
+    }
+
+    fn reconstruct_break(&mut self, _input: BreakStatement) -> (Statement, Self::AdditionalOutput) {
+        panic!("`BreakStatement`s should be lowered by loop unrolling.");
+    }
+
+    fn reconstruct_continue(&mut self, _input: ContinueStatement) -> (Statement, Self::AdditionalOutput) {
+        panic!("`ContinueStatement`s should be lowered by loop unrolling.");
+    }
+
     /// Transforms a return statement into an empty block statement.
     /// Stores the arguments to the return statement, which are later folded into a single return statement at the end of the function.
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
@@ -46,7 +46,7 @@ use leo_ast::{
     Type,
     UnitExpression,
 };
-use leo_span::Symbol;
+use leo_span::{Span, Symbol};
 
 /// An expression representing a conditional to reach the current
 /// point in the AST.
@@ -243,13 +243,14 @@ impl FlatteningVisitor<'_> {
 
     /// Fold guards and expressions into a single expression.
     /// Note that this function assumes that at least one guard is present.
+    /// Each selection is spanned by the span paired with its guard.
     pub fn fold_guards(
         &mut self,
         prefix: &str,
-        mut guards: Vec<(Option<Expression>, Expression)>,
+        mut guards: Vec<(Option<Expression>, Expression, Span)>,
     ) -> (Expression, Vec<Statement>) {
         // Type checking guarantees that there exists at least one return statement in the function body.
-        let (_, last_expression) = guards.pop().unwrap();
+        let (_, last_expression, _) = guards.pop().unwrap();
 
         match last_expression {
             // If the expression is a unit expression, then return it directly.
@@ -261,7 +262,7 @@ impl FlatteningVisitor<'_> {
 
                 // Helper to construct and store ternary assignments. e.g `$ret$0 = $var$0 ? $var$1 : $var$2`
                 let mut construct_ternary_assignment =
-                    |guard: Expression, if_true: Expression, if_false: Expression| {
+                    |guard: Expression, if_true: Expression, if_false: Expression, span: Span| {
                         let place = Identifier {
                             name: self.state.assigner.unique_symbol(prefix, "$"),
                             span: Default::default(),
@@ -274,11 +275,15 @@ impl FlatteningVisitor<'_> {
                             condition: guard,
                             if_true,
                             if_false,
-                            span: Default::default(),
+                            span,
                             id: self.state.node_builder.next_id(),
                         };
                         self.state.type_table.insert(ternary.id(), type_);
-                        let (value, stmts) = self.reconstruct_ternary(ternary, &());
+                        let (value, mut stmts) = self.reconstruct_ternary(ternary, &());
+                        // The selection is attributed to the guarded return it stands for.
+                        for statement in stmts.iter_mut().filter(|statement| statement.span().is_dummy()) {
+                            statement.set_span(span);
+                        }
                         statements.extend(stmts);
 
                         if let Expression::Tuple(..) = &value {
@@ -287,16 +292,19 @@ impl FlatteningVisitor<'_> {
                             value
                         } else {
                             // Otherwise, assign the expression to a variable and return the variable.
-                            statements.push(self.simple_definition(place, value));
+                            let mut definition = self.simple_definition(place, value);
+                            definition.set_span(span);
+                            statements.push(definition);
                             Path::from(place).to_local().into()
                         }
                     };
 
-                let expression = guards.into_iter().rev().fold(last_expression, |acc, (guard, expr)| match guard {
-                    None => unreachable!("All expressions except for the last one must have a guard."),
-                    // Note that type checking guarantees that all expressions have the same type.
-                    Some(guard) => construct_ternary_assignment(guard, expr, acc),
-                });
+                let expression =
+                    guards.into_iter().rev().fold(last_expression, |acc, (guard, expr, span)| match guard {
+                        None => unreachable!("All expressions except for the last one must have a guard."),
+                        // Note that type checking guarantees that all expressions have the same type.
+                        Some(guard) => construct_ternary_assignment(guard, expr, acc, span),
+                    });
 
                 (expression, statements)
             }
@@ -332,10 +340,12 @@ impl FlatteningVisitor<'_> {
         // If the list of returns is not empty, then fold them into a single return statement.
         if !returns.is_empty() {
             let mut return_expressions = Vec::with_capacity(returns.len());
+            // The folded return stands in for the final return of the function, which has no guard.
+            let span = returns.last().map_or_else(Span::default, |(_, return_statement)| return_statement.span);
 
             // Aggregate the return expressions and finalize arguments and their respective guards.
             for (guard, return_statement) in returns {
-                return_expressions.push((guard.clone(), return_statement.expression));
+                return_expressions.push((guard.clone(), return_statement.expression, return_statement.span));
             }
 
             // Fold the return expressions into a single expression.
@@ -345,9 +355,7 @@ impl FlatteningVisitor<'_> {
             block.statements.extend(stmts);
 
             // Add the `ReturnStatement` to the end of the block.
-            block.statements.push(
-                ReturnStatement { expression, span: Default::default(), id: self.state.node_builder.next_id() }.into(),
-            );
+            block.statements.push(ReturnStatement { expression, span, id: self.state.node_builder.next_id() }.into());
         }
         // Otherwise, push a dummy return statement to the end of the block.
         else {
@@ -17,10 +17,10 @@
 //! Collects all *global items* into the symbol table after path resolution.
 //!
 //! This pass is responsible for registering globally visible program items,
-//! including functions, structs, records, mappings, constants, and storage
-//! variables, along with their associated types. It operates only on *resolved*
-//! paths and assumes that global variables and local scopes have already been
-//! established by earlier passes.
+//! including functions, structs, records and their associated functions,
+//! mappings, constants, and storage variables, along with their associated
+//! types. It operates only on *resolved* paths and assumes that global
+//! variables and local scopes have already been established by earlier passes.
 //!
 //! Unlike earlier pipeline stages, this pass does **not** create scopes,
 //! resolve names, or insert local symbols. Its sole responsibility is to
@@ -157,6 +157,9 @@ impl UnitVisitor for GlobalItemsCollectionVisitor<'_> {
         {
             self.state.handler.emit_err(err);
         }
+
+        // Associated functions are named `Foo::name`, and live next to `Foo` in its module.
+        input.functions.iter().for_each(|(_, f)| self.visit_function(f));
     }
 
     fn visit_mapping(&mut self, input: &Mapping) {
@@ -49,6 +49,9 @@ pub use destructuring::*;
 mod disambiguate;
 pub use disambiguate::*;
 
+mod enum_lowering;
+pub use enum_lowering::*;
+
 mod flattening;
 pub use flattening::*;
 
@@ -67,6 +70,9 @@ pub use library_pruning::*;
 mod loop_unrolling;
 pub use loop_unrolling::*;
 
+mod method_lowering;
+pub use method_lowering::*;
+
 mod monomorphization;
 pub use monomorphization::*;
 
@@ -114,6 +120,8 @@ pub use write_transforming::*;
 pub struct CompiledPrograms {
     /// The generated Aleo bytecode for the primary program.
     pub primary_bytecode: String,
+    /// Maps the primary program's instructions back to the Leo source.
+    pub primary_source_map: AleoSourceMap,
     /// Compiled bytecodes for imported programs.
     pub import_bytecodes: Vec<Bytecode>,
 }
@@ -125,6 +133,8 @@ pub struct Bytecode {
     pub program_name: String,
     /// The generated Aleo bytecode.
     pub bytecode: String,
+    /// Maps the program's instructions back to the Leo source.
+    pub source_map: AleoSourceMap,
 }
 
 #[cfg(test)]
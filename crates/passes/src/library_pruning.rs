@@ -23,11 +23,16 @@
 //!
 //! This complements [`crate::DeadCodeEliminating`], which drops uncalled functions within a
 //! program but is a no-op for libraries; library stubs are pruned here instead.
+//!
+//! The pass also removes what only the interpreter can run: `@test(mode = "native")` functions,
+//! together with the finalize functions lifted from their `final {}` blocks, and the `std::test`
+//! module of cheatcodes that only those tests may call.
 
 use crate::{CompilerState, Pass};
 
-use leo_ast::{Ast, Library, Location, Stub};
+use leo_ast::{Ast, Library, Location, Program, Stub, Variant};
 use leo_errors::Result;
+use leo_span::sym;
 
 use indexmap::IndexSet;
 
@@ -41,6 +46,10 @@ impl Pass for LibraryPruning {
     const NAME: &str = "LibraryPruning";
 
     fn do_pass(_input: Self::Input, state: &mut CompilerState) -> Result<Self::Output> {
+        // Native tests run in the interpreter and have no bytecode, so they are dropped from test
+        // builds before anything tries to lower them. Other builds never contain them.
+        let native_tests = if state.is_test { strip_native_tests(state) } else { IndexSet::new() };
+
         // This pass runs in the program backend, which a library build skips (frontend only, no
         // bytecode), so it never sees an `Ast::Library`. The guard skips programs that import no
         // library (e.g. `no_std`); otherwise every `FromLibrary` stub below is pruned, std included.
@@ -56,7 +65,7 @@ impl Pass for LibraryPruning {
         let mut reachable: IndexSet<Location> = IndexSet::new();
         let mut queue: Vec<Location> = Vec::new();
         for node in state.call_graph.nodes() {
-            if !state.symbol_table.is_library(node.program) {
+            if !state.symbol_table.is_library(node.program) && !native_tests.contains(node) {
                 queue.extend(state.call_graph.neighbors(node).cloned());
             }
         }
@@ -69,6 +78,11 @@ impl Pass for LibraryPruning {
         let Ast::Program(program) = &mut state.ast else { unreachable!("checked above") };
         for stub in program.stubs.values_mut() {
             if let Stub::FromLibrary { library, .. } = stub {
+                // Nothing left can call a cheatcode, and the type checker confines them to native
+                // tests, so `std::test` goes regardless of whether the library declares consts.
+                if library.name == sym::std {
+                    library.modules.shift_remove(&vec![sym::test]);
+                }
                 prune_library(library, &reachable);
             }
         }
@@ -77,6 +91,34 @@ impl Pass for LibraryPruning {
     }
 }
 
+/// Removes native tests, and the finalize functions lifted from their `final {}` blocks, from the
+/// program scopes, returning the locations of everything removed.
+fn strip_native_tests(state: &mut CompilerState) -> IndexSet<Location> {
+    let Ast::Program(Program { program_scopes, .. }) = &mut state.ast else {
+        return IndexSet::new();
+    };
+
+    let mut removed = IndexSet::new();
+    for scope in program_scopes.values_mut() {
+        let program = scope.program_id.as_symbol();
+        for (name, _) in scope.functions.iter().filter(|(_, function)| function.is_native_test()) {
+            let test = Location::new(program, vec![*name]);
+            // A lifted finalize function is only ever called by the function it was lifted from.
+            let lifted = state.call_graph.neighbors(&test).filter(|callee| {
+                callee.program == program
+                    && scope
+                        .functions
+                        .iter()
+                        .any(|(symbol, function)| callee.path == [*symbol] && function.variant == Variant::Finalize)
+            });
+            removed.extend(lifted.cloned());
+            removed.insert(test);
+        }
+        scope.functions.retain(|(name, _)| !removed.contains(&Location::new(program, vec![*name])));
+    }
+    removed
+}
+
 /// Removes functions not in `reachable` from `library` and its submodules. Structs, consts, and
 /// interfaces are kept for now.
 fn prune_library(library: &mut Library, reachable: &IndexSet<Location>) {
@@ -54,6 +54,33 @@ impl AstReconstructor for UnrollingVisitor<'_> {
         })
     }
 
+    fn reconstruct_match(&mut self, input: MatchExpression, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                self.in_scope(arm.id, |slf| MatchArm {
+                    expression: slf.reconstruct_expression(arm.expression, &()).0,
+                    ..arm
+                })
+            })
+            .collect();
+        (MatchExpression { scrutinee, arms, ..input }.into(), Default::default())
+    }
+
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                self.in_scope(arm.id, |slf| MatchStatementArm { block: slf.reconstruct_block(arm.block).0, ..arm })
+            })
+            .collect();
+        (MatchStatement { scrutinee, arms, ..input }.into(), Default::default())
+    }
+
     fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
         (input.into(), Default::default())
     }
@@ -84,20 +111,9 @@ impl AstReconstructor for UnrollingVisitor<'_> {
             return (Statement::Iteration(Box::new(input)), Default::default());
         };
 
-        // Helper to clone and resolve Unsuffixed -> Integer literal based on type table
-        let resolve_unsuffixed = |lit: &leo_ast::Literal, expr_id| {
-            let mut resolved = lit.clone();
-            if let LiteralVariant::Unsuffixed(s) = &resolved.variant
-                && let Some(Type::Integer(integer_type)) = self.state.type_table.get(&expr_id)
-            {
-                resolved.variant = LiteralVariant::Integer(integer_type, s.clone());
-            }
-            resolved
-        };
-
         // Clone and resolve both literals
-        let resolved_start_lit = resolve_unsuffixed(start_lit_ref, input.start.id());
-        let resolved_stop_lit = resolve_unsuffixed(stop_lit_ref, input.stop.id());
+        let resolved_start_lit = self.resolve_unsuffixed(start_lit_ref, input.start.id());
+        let resolved_stop_lit = self.resolve_unsuffixed(stop_lit_ref, input.stop.id());
 
         // Convert resolved literals into constant values
         let start_value =
@@ -126,4 +142,21 @@ impl AstReconstructor for UnrollingVisitor<'_> {
             Default::default(),
         )
     }
+
+    fn reconstruct_while(&mut self, input: WhileStatement) -> (Statement, Self::AdditionalOutput) {
+        // As with iteration bounds, the bound must be a constant that constant propagation evaluates.
+        let Literal(bound_lit) = &input.bound else {
+            self.loop_not_unrolled = Some(input.bound.span());
+            return (Statement::While(Box::new(input)), Default::default());
+        };
+
+        let bound = self
+            .resolve_unsuffixed(bound_lit, input.bound.id())
+            .as_u32()
+            .expect("Type checking guarantees the bound is a `u32`.");
+
+        self.loop_unrolled = true;
+
+        (self.unroll_while_statement(input, bound), Default::default())
+    }
 }
@@ -29,6 +29,16 @@ pub fn duplicate(
     Duplicator { symbol_table, node_builder, type_table }.reconstruct_block(block).0
 }
 
+/// Duplicate this expression, recursively giving new `NodeID`s to it and its subexpressions.
+pub fn duplicate_expression(
+    expression: Expression,
+    symbol_table: &mut SymbolTable,
+    node_builder: &NodeBuilder,
+    type_table: &TypeTable,
+) -> Expression {
+    Duplicator { symbol_table, node_builder, type_table }.reconstruct_expression(expression, &()).0
+}
+
 struct Duplicator<'a> {
     symbol_table: &'a mut SymbolTable,
     node_builder: &'a NodeBuilder,
@@ -68,6 +78,8 @@ impl AstReconstructor for Duplicator<'_> {
             Expression::Err(e) => self.reconstruct_err(e, &()),
             Expression::Intrinsic(e) => self.reconstruct_intrinsic(*e, &()),
             Expression::Literal(e) => self.reconstruct_literal(e, &()),
+            Expression::Match(e) => self.reconstruct_match(*e, &()),
+            Expression::MethodCall(e) => self.reconstruct_method_call(*e, &()),
             Expression::MemberAccess(e) => self.reconstruct_member_access(*e, &()),
             Expression::Path(e) => self.reconstruct_path(e, &()),
             Expression::Repeat(e) => self.reconstruct_repeat(*e, &()),
@@ -109,6 +121,42 @@ impl AstReconstructor for Duplicator<'_> {
         (input.into(), Default::default())
     }
 
+    fn reconstruct_match(
+        &mut self,
+        mut input: MatchExpression,
+        _additional: &(),
+    ) -> (Expression, Self::AdditionalOutput) {
+        input.scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        input.arms = input
+            .arms
+            .into_iter()
+            .map(|mut arm| {
+                self.in_scope_duped(arm.id, |slf, new_id| {
+                    arm.id = new_id;
+                    arm.expression = slf.reconstruct_expression(arm.expression, &()).0;
+                    arm
+                })
+            })
+            .collect();
+        (input.into(), Default::default())
+    }
+
+    fn reconstruct_match_statement(&mut self, mut input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        input.scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        input.arms = input
+            .arms
+            .into_iter()
+            .map(|mut arm| {
+                self.in_scope_duped(arm.id, |slf, new_id| {
+                    arm.id = new_id;
+                    arm.block = slf.reconstruct_block(arm.block).0;
+                    arm
+                })
+            })
+            .collect();
+        (input.into(), Default::default())
+    }
+
     fn reconstruct_iteration(&mut self, mut input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
         self.in_scope_duped(input.id(), |slf, new_id| {
             input.id = new_id;
@@ -14,7 +14,30 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{AstReconstructor, Block, IterationStatement, Literal, Node, NodeID, Statement, Type, const_eval::Value};
+use leo_ast::{
+    AssertStatement,
+    AssertVariant,
+    AssignStatement,
+    AstReconstructor,
+    BinaryExpression,
+    BinaryOperation,
+    Block,
+    ConditionalStatement,
+    Expression,
+    Identifier,
+    IterationStatement,
+    Literal,
+    LiteralVariant,
+    Node,
+    NodeID,
+    Path,
+    Statement,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+    WhileStatement,
+    const_eval::Value,
+};
 use leo_span::{Span, Symbol};
 
 use itertools::Either;
@@ -31,6 +54,16 @@ pub struct UnrollingVisitor<'a> {
     pub loop_unrolled: bool,
 }
 
+/// The flags recording that a loop being unrolled was exited early.
+#[derive(Clone, Copy)]
+struct LoopFlags {
+    /// Set once the loop has been exited, by `break` or by the condition of a `while` loop failing.
+    /// `None` for a `for` loop without `break`.
+    exit: Option<Symbol>,
+    /// Set once the rest of the current iteration is skipped. The same as `exit` for a loop without `continue`.
+    skip: Symbol,
+}
+
 impl UnrollingVisitor<'_> {
     pub fn in_scope<T>(&mut self, id: NodeID, func: impl FnOnce(&mut Self) -> T) -> T {
         self.state.symbol_table.enter_scope(Some(id));
@@ -67,19 +100,97 @@ impl UnrollingVisitor<'_> {
 
         let iter = if input.inclusive { Either::Left(start..=stop) } else { Either::Right(start..stop) };
 
+        let flags = self.loop_flags(&input.block, false);
+
         // Create a block statement to replace the iteration statement.
         self.in_scope(new_block_id, |slf| {
-            Block {
-                span: input.span,
-                statements: iter.map(|iteration_count| slf.unroll_single_iteration(&input, iteration_count)).collect(),
-                id: new_block_id,
+            let mut statements: Vec<Statement> =
+                flags.and_then(|flags| flags.exit).map(|exit| slf.flag_definition(exit)).into_iter().collect();
+            statements.extend(iter.map(|iteration_count| slf.unroll_single_iteration(&input, iteration_count, flags)));
+
+            Block { span: input.span, statements, id: new_block_id }.into()
+        })
+    }
+
+    /// Unrolls a WhileStatement into `bound` guarded copies of its body.
+    ///
+    /// ```leo
+    /// let exit = false;
+    /// if !exit && condition { body } else { exit = true; }
+    /// ...
+    /// assert(exit || !condition);
+    /// ```
+    ///
+    /// The trailing assertion fails if the loop still runs once its bound is exhausted.
+    pub fn unroll_while_statement(&mut self, input: WhileStatement, bound: u32) -> Statement {
+        let flags = self.loop_flags(&input.block, true).expect("`while` loops always have flags");
+        let exit = flags.exit.expect("`while` loops always have an exit flag");
+
+        let new_block_id = self.state.node_builder.next_id();
+
+        self.in_scope(new_block_id, |slf| {
+            let mut statements = vec![slf.flag_definition(exit)];
+
+            for _ in 0..bound {
+                let not_exited = slf.flag_unset(exit);
+                let condition = slf.duplicate_condition(&input.condition);
+                let condition = slf.binary(BinaryOperation::And, not_exited, condition);
+                let then = slf.iteration_block(&input.block, flags, input.span);
+
+                let otherwise_id = slf.state.node_builder.next_id();
+                let otherwise = slf.in_scope(otherwise_id, |slf| Block {
+                    statements: vec![slf.set_flag(exit, input.span)],
+                    span: input.span,
+                    id: otherwise_id,
+                });
+
+                statements.push(
+                    ConditionalStatement {
+                        condition,
+                        then,
+                        otherwise: Some(Box::new(otherwise.into())),
+                        span: input.span,
+                        id: slf.state.node_builder.next_id(),
+                    }
+                    .into(),
+                );
             }
-            .into()
+
+            let exited = slf.flag(exit);
+            let condition = slf.duplicate_condition(&input.condition);
+            let condition = slf.unary(UnaryOperation::Not, condition);
+            let exited = slf.binary(BinaryOperation::Or, exited, condition);
+            statements.push(
+                AssertStatement {
+                    variant: AssertVariant::Assert(exited),
+                    span: input.span,
+                    id: slf.state.node_builder.next_id(),
+                }
+                .into(),
+            );
+
+            Block { span: input.span, statements, id: new_block_id }.into()
         })
     }
 
+    /// Clones `literal`, resolving an unsuffixed integer literal to the type recorded in the type table.
+    pub fn resolve_unsuffixed(&self, literal: &Literal, expr_id: NodeID) -> Literal {
+        let mut resolved = literal.clone();
+        if let LiteralVariant::Unsuffixed(s) = &resolved.variant
+            && let Some(Type::Integer(integer_type)) = self.state.type_table.get(&expr_id)
+        {
+            resolved.variant = LiteralVariant::Integer(integer_type, s.clone());
+        }
+        resolved
+    }
+
     /// A helper function to unroll a single iteration an IterationStatement.
-    fn unroll_single_iteration(&mut self, input: &IterationStatement, iteration_count: i128) -> Statement {
+    fn unroll_single_iteration(
+        &mut self,
+        input: &IterationStatement,
+        iteration_count: i128,
+        flags: Option<LoopFlags>,
+    ) -> Statement {
         // Construct a new node ID.
         let const_id = self.state.node_builder.next_id();
 
@@ -102,16 +213,265 @@ impl UnrollingVisitor<'_> {
             // Add the loop variable as a constant for the current scope.
             slf.state.symbol_table.insert_local_const(input.variable.name, value.into());
 
-            let duplicated_body = super::duplicate::duplicate(
-                input.block.clone(),
-                &mut slf.state.symbol_table,
-                &slf.state.node_builder,
-                &slf.state.type_table,
-            );
-
-            let result = slf.reconstruct_block(duplicated_body).0.into();
+            let result: Statement = match flags {
+                None => slf.unroll_body(&input.block, None).into(),
+                Some(flags) => {
+                    let iteration = slf.iteration_block(&input.block, flags, input.span);
+                    match flags.exit {
+                        // Once the loop has been exited, the remaining iterations are skipped.
+                        Some(exit) => ConditionalStatement {
+                            condition: slf.flag_unset(exit),
+                            then: iteration,
+                            otherwise: None,
+                            span: input.span,
+                            id: slf.state.node_builder.next_id(),
+                        }
+                        .into(),
+                        None => iteration.into(),
+                    }
+                }
+            };
 
             Block { statements: vec![result], span: input.span(), id: outer_block_id }.into()
         })
     }
+
+    /// Duplicates and unrolls the body of a loop, lowering its `break` and `continue` statements if it has `flags`.
+    fn unroll_body(&mut self, body: &Block, flags: Option<LoopFlags>) -> Block {
+        let duplicated_body = super::duplicate::duplicate(
+            body.clone(),
+            &mut self.state.symbol_table,
+            &self.state.node_builder,
+            &self.state.type_table,
+        );
+
+        // Unroll nested loops first, so that the remaining jumps all belong to this loop.
+        let body = self.reconstruct_block(duplicated_body).0;
+
+        match flags {
+            Some(flags) => self.lower_jumps(body, flags),
+            None => body,
+        }
+    }
+
+    /// Builds the block running a single iteration of a loop with early exits, resetting its `skip` flag first.
+    fn iteration_block(&mut self, body: &Block, flags: LoopFlags, span: Span) -> Block {
+        let id = self.state.node_builder.next_id();
+
+        self.in_scope(id, |slf| {
+            let mut statements = Vec::new();
+            if flags.exit != Some(flags.skip) {
+                statements.push(slf.flag_definition(flags.skip));
+            }
+            statements.push(slf.unroll_body(body, Some(flags)).into());
+
+            Block { statements, span, id }
+        })
+    }
+
+    /// Creates the flags for a loop with body `body`, or `None` if it is a `for` loop that never exits early.
+    fn loop_flags(&self, body: &Block, is_while: bool) -> Option<LoopFlags> {
+        let (mut has_break, mut has_continue) = (false, false);
+        body.statements.iter().for_each(|statement| find_jumps(statement, &mut has_break, &mut has_continue));
+
+        if !has_break && !has_continue && !is_while {
+            return None;
+        }
+
+        let exit = (has_break || is_while).then(|| self.state.assigner.unique_symbol("loop_exit", "$$"));
+        let skip = match exit {
+            Some(exit) if !has_continue => exit,
+            _ => self.state.assigner.unique_symbol("loop_skip", "$$"),
+        };
+
+        Some(LoopFlags { exit, skip })
+    }
+
+    /// Replaces the `break` and `continue` statements in `block` with assignments to the loop's flags,
+    /// guarding the statements that follow them.
+    fn lower_jumps(&mut self, block: Block, flags: LoopFlags) -> Block {
+        self.in_scope(block.id, |slf| Block { statements: slf.lower_jump_statements(block.statements, flags), ..block })
+    }
+
+    fn lower_jump_statements(&mut self, statements: Vec<Statement>, flags: LoopFlags) -> Vec<Statement> {
+        let mut lowered = Vec::with_capacity(statements.len());
+        let mut statements = statements.into_iter();
+
+        while let Some(statement) = statements.next() {
+            match statement {
+                // Statements after a jump are unreachable.
+                Statement::Break(input) => {
+                    if let Some(exit) = flags.exit {
+                        lowered.push(self.set_flag(exit, input.span));
+                    }
+                    if flags.exit != Some(flags.skip) {
+                        lowered.push(self.set_flag(flags.skip, input.span));
+                    }
+                    return lowered;
+                }
+                Statement::Continue(input) => {
+                    lowered.push(self.set_flag(flags.skip, input.span));
+                    return lowered;
+                }
+                statement if contains_jump(&statement) => {
+                    let span = statement.span();
+                    lowered.push(self.lower_jump_statement(statement, flags));
+
+                    let rest: Vec<Statement> = statements.collect();
+                    if !rest.is_empty() {
+                        lowered.push(self.guard_rest(rest, flags, span));
+                    }
+                    return lowered;
+                }
+                statement => lowered.push(statement),
+            }
+        }
+
+        lowered
+    }
+
+    fn lower_jump_statement(&mut self, statement: Statement, flags: LoopFlags) -> Statement {
+        match statement {
+            Statement::Block(block) => self.lower_jumps(block, flags).into(),
+            Statement::Conditional(mut input) => {
+                input.then = self.lower_jumps(input.then, flags);
+                input.otherwise =
+                    input.otherwise.map(|otherwise| Box::new(self.lower_jump_statement(*otherwise, flags)));
+                input.into()
+            }
+            Statement::Match(mut input) => {
+                for arm in input.arms.iter_mut() {
+                    let block = std::mem::take(&mut arm.block);
+                    arm.block = self.in_scope(arm.id, |slf| slf.lower_jumps(block, flags));
+                }
+                input.into()
+            }
+            statement => statement,
+        }
+    }
+
+    /// Wraps `rest` in `if !skip { rest }`, moving the scopes it owns into the new block.
+    fn guard_rest(&mut self, rest: Vec<Statement>, flags: LoopFlags, span: Span) -> Statement {
+        let scopes: Vec<NodeID> = rest.iter().flat_map(child_scopes).collect();
+        let id = self.state.node_builder.next_id();
+
+        let then = self.in_scope(id, |slf| {
+            slf.state.symbol_table.reparent_scopes(&scopes, id);
+            Block { statements: slf.lower_jump_statements(rest, flags), span, id }
+        });
+
+        ConditionalStatement {
+            condition: self.flag_unset(flags.skip),
+            then,
+            otherwise: None,
+            span,
+            id: self.state.node_builder.next_id(),
+        }
+        .into()
+    }
+
+    fn duplicate_condition(&mut self, condition: &Expression) -> Expression {
+        super::duplicate::duplicate_expression(
+            condition.clone(),
+            &mut self.state.symbol_table,
+            &self.state.node_builder,
+            &self.state.type_table,
+        )
+    }
+
+    /// Constructs `let flag = false;`.
+    fn flag_definition(&mut self, flag: Symbol) -> Statement {
+        let value = self.boolean(false);
+        let identifier = Identifier::new(flag, self.state.node_builder.next_id());
+        self.state.assigner.simple_definition(identifier, value, self.state.node_builder.next_id())
+    }
+
+    /// Constructs `flag = true;`.
+    fn set_flag(&mut self, flag: Symbol, span: Span) -> Statement {
+        AssignStatement {
+            place: self.flag(flag),
+            value: self.boolean(true),
+            span,
+            id: self.state.node_builder.next_id(),
+        }
+        .into()
+    }
+
+    fn flag(&mut self, flag: Symbol) -> Expression {
+        let id = self.state.node_builder.next_id();
+        self.state.type_table.insert(id, Type::Boolean);
+        Path::from(Identifier::new(flag, id)).to_local().into()
+    }
+
+    /// Constructs `!flag`.
+    fn flag_unset(&mut self, flag: Symbol) -> Expression {
+        let flag = self.flag(flag);
+        self.unary(UnaryOperation::Not, flag)
+    }
+
+    fn boolean(&mut self, value: bool) -> Expression {
+        let id = self.state.node_builder.next_id();
+        self.state.type_table.insert(id, Type::Boolean);
+        Literal::boolean(value, Default::default(), id).into()
+    }
+
+    fn unary(&mut self, op: UnaryOperation, receiver: Expression) -> Expression {
+        let id = self.state.node_builder.next_id();
+        self.state.type_table.insert(id, Type::Boolean);
+        UnaryExpression { receiver, op, span: Default::default(), id }.into()
+    }
+
+    fn binary(&mut self, op: BinaryOperation, left: Expression, right: Expression) -> Expression {
+        let id = self.state.node_builder.next_id();
+        self.state.type_table.insert(id, Type::Boolean);
+        BinaryExpression { left, right, op, span: Default::default(), id }.into()
+    }
+}
+
+/// Records whether `statement` contains a `break` or `continue` exiting the enclosing loop.
+fn find_jumps(statement: &Statement, has_break: &mut bool, has_continue: &mut bool) {
+    match statement {
+        Statement::Break(_) => *has_break = true,
+        Statement::Continue(_) => *has_continue = true,
+        Statement::Block(block) => {
+            block.statements.iter().for_each(|statement| find_jumps(statement, has_break, has_continue))
+        }
+        Statement::Conditional(input) => {
+            input.then.statements.iter().for_each(|statement| find_jumps(statement, has_break, has_continue));
+            if let Some(otherwise) = &input.otherwise {
+                find_jumps(otherwise, has_break, has_continue);
+            }
+        }
+        Statement::Match(input) => input
+            .arms
+            .iter()
+            .flat_map(|arm| &arm.block.statements)
+            .for_each(|statement| find_jumps(statement, has_break, has_continue)),
+        // Jumps in a nested loop exit that loop instead.
+        _ => {}
+    }
+}
+
+fn contains_jump(statement: &Statement) -> bool {
+    let (mut has_break, mut has_continue) = (false, false);
+    find_jumps(statement, &mut has_break, &mut has_continue);
+    has_break || has_continue
+}
+
+/// The scopes directly owned by `statement`.
+fn child_scopes(statement: &Statement) -> Vec<NodeID> {
+    match statement {
+        Statement::Block(block) => vec![block.id],
+        Statement::Conditional(input) => {
+            let mut scopes = vec![input.then.id];
+            if let Some(otherwise) = &input.otherwise {
+                scopes.extend(child_scopes(otherwise));
+            }
+            scopes
+        }
+        Statement::Iteration(input) => vec![input.id],
+        Statement::Match(input) => input.arms.iter().map(|arm| arm.id).collect(),
+        Statement::While(input) => vec![input.block.id],
+        _ => vec![],
+    }
 }
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{MethodLoweringVisitor, method_call_to_call};
+
+use leo_ast::*;
+
+impl AstReconstructor for MethodLoweringVisitor<'_> {
+    type AdditionalInput = ();
+    type AdditionalOutput = ();
+
+    /* Expressions */
+    fn reconstruct_method_call(
+        &mut self,
+        input: MethodCallExpression,
+        _additional: &(),
+    ) -> (Expression, Self::AdditionalOutput) {
+        let receiver_type =
+            self.state.type_table.get(&input.receiver.id()).expect("type checking guarantees the receiver has a type");
+        let call = method_call_to_call(input, &receiver_type)
+            .expect("type checking guarantees that the receiver is a struct or record");
+
+        // The const arguments of the receiver's type are copies of those in the type, so give them fresh node IDs.
+        let type_arguments = match &receiver_type {
+            Type::Composite(composite) => composite.const_arguments.len(),
+            _ => 0,
+        };
+        let const_arguments = call
+            .const_arguments
+            .into_iter()
+            .enumerate()
+            .map(|(i, arg)| if i < type_arguments { self.copy(arg) } else { self.reconstruct_expression(arg, &()).0 })
+            .collect();
+        let arguments = call.arguments.into_iter().map(|arg| self.reconstruct_expression(arg, &()).0).collect();
+
+        (CallExpression { const_arguments, arguments, ..call }.into(), ())
+    }
+}
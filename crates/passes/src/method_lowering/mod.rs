@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Performs lowering of `impl` blocks and method calls.
+//!
+//! The associated functions of every struct and record are moved out of it, into the module that declares it,
+//! where they already live under their `Foo::name` names. A method call is rewritten into a call of the associated
+//! function it names, with the receiver as its first argument and the const arguments of the receiver's type
+//! before its own.
+//!
+//! ### Example
+//!
+//! ```leo
+//! struct Point { x: u32, y: u32 }
+//!
+//! impl Point {
+//!     fn new(x: u32, y: u32) -> Self { return Self { x, y }; }
+//!     fn sum(self) -> u32 { return self.x + self.y; }
+//! }
+//!
+//! let s: u32 = Point::new(1u32, 2u32).sum();
+//! ```
+//!
+//! is lowered to:
+//!
+//! ```leo
+//! struct Point { x: u32, y: u32 }
+//!
+//! fn Point::new(x: u32, y: u32) -> Point { return Point { x, y }; }
+//! fn Point::sum(self: Point) -> u32 { return self.x + self.y; }
+//!
+//! let s: u32 = Point::sum(Point::new(1u32, 2u32));
+//! ```
+//!
+//! After this pass, no associated functions or method calls remain in the program.
+
+use crate::{
+    GlobalItemsCollection,
+    GlobalVarsCollection,
+    Pass,
+    PathResolution,
+    SymbolTable,
+    TypeChecking,
+    TypeCheckingInput,
+};
+
+use leo_ast::{
+    Ast,
+    CallExpression,
+    Composite,
+    Identifier,
+    Location,
+    MethodCallExpression,
+    Path,
+    Type,
+    UnitReconstructor as _,
+};
+use leo_errors::Result;
+
+mod ast;
+
+mod program;
+use program::*;
+
+mod visitor;
+use visitor::*;
+
+pub struct MethodLowering;
+
+impl Pass for MethodLowering {
+    type Input = TypeCheckingInput;
+    type Output = ();
+
+    const NAME: &str = "MethodLowering";
+
+    fn do_pass(input: TypeCheckingInput, state: &mut crate::CompilerState) -> Result<Self::Output> {
+        let mut ast = std::mem::take(&mut state.ast);
+
+        // Nothing to lower without associated functions.
+        if !hoist_associated_functions(&mut ast) {
+            state.ast = ast;
+            return Ok(());
+        }
+
+        let mut visitor = MethodLoweringVisitor { state };
+        let ast = match ast {
+            Ast::Program(program) => Ast::Program(visitor.reconstruct_program(program)),
+            Ast::Library(library) => Ast::Library(visitor.reconstruct_library(library)),
+        };
+
+        visitor.state.handler.last_err()?;
+        visitor.state.ast = ast;
+
+        // We need to recreate the symbol table and run type checking again because this pass moves functions out
+        // of composites and replaces method calls with calls.
+        visitor.state.symbol_table = SymbolTable::default();
+        GlobalVarsCollection::do_pass((), state)?;
+        PathResolution::do_pass((), state)?;
+        GlobalItemsCollection::do_pass((), state)?;
+        TypeChecking::do_pass(input, state)?;
+
+        Ok(())
+    }
+}
+
+/// Returns the call of the associated function that `input` calls on a receiver of type `receiver_type`, i.e.
+/// `Foo::name::[N, M](receiver, args)` for `receiver.name::[M](args)` with a receiver of type `Foo::[N]`.
+///
+/// Returns `None` if the receiver is not a struct or record.
+pub fn method_call_to_call(input: MethodCallExpression, receiver_type: &Type) -> Option<CallExpression> {
+    let Type::Composite(composite) = receiver_type else {
+        return None;
+    };
+    let location = composite.path.try_global_location()?;
+    let (type_name, module) = location.path.split_last()?;
+    let name = Composite::associated_function_name(*type_name, input.name.name);
+    let function = Path::from(Identifier { name, ..input.name })
+        .to_global(Location::new(location.program, module.iter().copied().chain([name]).collect()));
+
+    Some(CallExpression {
+        function,
+        const_arguments: composite.const_arguments.iter().cloned().chain(input.const_arguments).collect(),
+        arguments: std::iter::once(input.receiver).chain(input.arguments).collect(),
+        span: input.span,
+        id: input.id,
+    })
+}
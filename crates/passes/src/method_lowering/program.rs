@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::MethodLoweringVisitor;
+
+use leo_ast::{Ast, Composite, Function, Library, Program, Stub, UnitReconstructor};
+use leo_span::Symbol;
+
+impl UnitReconstructor for MethodLoweringVisitor<'_> {}
+
+/// Moves the associated functions of every composite in `ast` into the functions of the module declaring it.
+///
+/// Returns `true` if there were any.
+pub fn hoist_associated_functions(ast: &mut Ast) -> bool {
+    match ast {
+        Ast::Program(program) => hoist_from_program(program),
+        Ast::Library(library) => hoist_from_library(library),
+    }
+}
+
+fn hoist_from_program(program: &mut Program) -> bool {
+    let mut hoisted = false;
+    for scope in program.program_scopes.values_mut() {
+        hoisted |= hoist(&mut scope.composites, &mut scope.functions);
+    }
+    for module in program.modules.values_mut() {
+        hoisted |= hoist(&mut module.composites, &mut module.functions);
+    }
+    for stub in program.stubs.values_mut() {
+        hoisted |= hoist_from_stub(stub);
+    }
+    hoisted
+}
+
+fn hoist_from_library(library: &mut Library) -> bool {
+    let mut hoisted = hoist(&mut library.structs, &mut library.functions);
+    for module in library.modules.values_mut() {
+        hoisted |= hoist(&mut module.composites, &mut module.functions);
+    }
+    for stub in library.stubs.values_mut() {
+        hoisted |= hoist_from_stub(stub);
+    }
+    hoisted
+}
+
+fn hoist_from_stub(stub: &mut Stub) -> bool {
+    match stub {
+        Stub::FromLeo { program, .. } => hoist_from_program(program),
+        Stub::FromLibrary { library, .. } => hoist_from_library(library),
+        Stub::FromAleo { .. } => false,
+    }
+}
+
+fn hoist(composites: &mut [(Symbol, Composite)], functions: &mut Vec<(Symbol, Function)>) -> bool {
+    let len = functions.len();
+    for (_, composite) in composites.iter_mut() {
+        functions.append(&mut composite.functions);
+    }
+    functions.len() != len
+}
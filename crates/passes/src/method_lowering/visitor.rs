@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CompilerState, Replacer};
+
+use leo_ast::{AstReconstructor as _, Expression};
+
+pub struct MethodLoweringVisitor<'a> {
+    pub state: &'a mut CompilerState,
+}
+
+impl MethodLoweringVisitor<'_> {
+    /// Returns a copy of `expression` with fresh node IDs.
+    pub fn copy(&mut self, expression: Expression) -> Expression {
+        Replacer::new(|expr: &Expression| expr.clone(), true /* refresh IDs */, self.state)
+            .reconstruct_expression(expression, &())
+            .0
+    }
+}
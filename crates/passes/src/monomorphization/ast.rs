@@ -100,6 +100,8 @@ impl AstReconstructor for MonomorphizationVisitor<'_> {
             Expression::Err(err) => self.reconstruct_err(err, &()),
             Expression::Path(path) => self.reconstruct_path(path, &()),
             Expression::Literal(value) => self.reconstruct_literal(value, &()),
+            Expression::Match(match_) => self.reconstruct_match(*match_, &()),
+            Expression::MethodCall(call) => self.reconstruct_method_call(*call, &()),
             Expression::MemberAccess(access) => self.reconstruct_member_access(*access, &()),
             Expression::Repeat(repeat) => self.reconstruct_repeat(*repeat, &()),
             Expression::Ternary(ternary) => self.reconstruct_ternary(*ternary, &()),
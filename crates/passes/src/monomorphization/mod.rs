@@ -78,6 +78,9 @@ mod program;
 mod visitor;
 use visitor::*;
 
+mod type_parameters;
+pub use type_parameters::*;
+
 #[derive(Debug)]
 pub struct MonomorphizationOutput {
     /// If we encountered calls to const generic functions that were not resolved, keep track of them in this vector
@@ -0,0 +1,759 @@
+// Copyright (C) 2019-2026 Provable Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Monomorphizes functions with type parameters.
+//!
+//! A const parameter whose type is an interface, like `T` in `fn hash_both::[T: Hasher](a: T, b: T)`, is a type
+//! parameter: its argument is a struct or record, which must satisfy the interface. A type satisfies an interface
+//! when, for every function declared by the interface or by the interfaces it inherits from, it has an associated
+//! function with the same name and signature, where `Self` stands for the type itself.
+//!
+//! Every call of such a function is checked against its bounds, and then rewritten into a call of a copy of the
+//! function in which the type parameters are replaced with their arguments: `T` in types, and `T::name` in calls.
+//! The copy is named after its type arguments, like `hash_both::[Point]`, and keeps the other const parameters of
+//! the function, which `Monomorphization` handles later.
+//!
+//! The copy is placed next to the original function. If a type argument isn't visible from there, like a struct of a
+//! program passed to a function of a library, it is placed next to the caller instead.
+//!
+//! ### Example
+//!
+//! ```leo
+//! interface Hasher {
+//!     fn hash(self) -> field;
+//! }
+//!
+//! fn hash_both::[T: Hasher](a: T, b: T) -> field {
+//!     return a.hash() + b.hash();
+//! }
+//!
+//! let h: field = hash_both::[Point](p, q);
+//! ```
+//!
+//! is transformed into:
+//!
+//! ```leo
+//! fn hash_both::[Point](a: Point, b: Point) -> field {
+//!     return a.hash() + b.hash();
+//! }
+//!
+//! let h: field = hash_both::[Point](p, q);
+//! ```
+//!
+//! Before any call is rewritten, the body of each function with type parameters is type checked against their
+//! bounds, so that it is correct for every type argument satisfying them, not just for those it is called with. Each
+//! type parameter `T` stands for an archetype there: a struct `T` without fields, whose associated functions are
+//! those declared by its bound. In the example, `a.hash()` is accepted because `Hasher` declares `hash`, whatever
+//! the type arguments of `hash_both` are.
+//!
+//! The functions with type parameters are removed afterwards.
+
+use crate::{
+    CompilerState,
+    GlobalItemsCollection,
+    GlobalVarsCollection,
+    Pass,
+    PathResolution,
+    Replacer,
+    SymbolTable,
+    TypeChecking,
+    TypeCheckingInput,
+};
+
+use leo_ast::{
+    Ast,
+    AstReconstructor,
+    CallExpression,
+    Composite,
+    CompositeType,
+    ConstParameter,
+    Constructor,
+    Expression,
+    Function,
+    FunctionPrototype,
+    Identifier,
+    Interface,
+    Library,
+    Location,
+    Node as _,
+    Path,
+    Program,
+    ProgramId,
+    Stub,
+    Type,
+    UnitReconstructor,
+};
+use leo_errors::Result;
+use leo_span::{Span, Symbol, sym};
+
+use indexmap::{IndexMap, IndexSet};
+use itertools::Itertools;
+
+pub struct TypeMonomorphization;
+
+impl Pass for TypeMonomorphization {
+    type Input = ();
+    type Output = ();
+
+    const NAME: &str = "TypeMonomorphization";
+
+    fn do_pass(_input: Self::Input, state: &mut CompilerState) -> Result<Self::Output> {
+        let mut ast = std::mem::take(&mut state.ast);
+
+        let generics: IndexMap<Location, Function> = items_mut(&mut ast)
+            .into_iter()
+            .flat_map(|items| {
+                items
+                    .functions
+                    .iter()
+                    .filter(|(_, function)| {
+                        function
+                            .const_parameters
+                            .iter()
+                            .any(|param| bound(&state.symbol_table, items.unit, param).is_some())
+                    })
+                    .map(|(name, function)| (item_location(items.unit, &items.module, *name), function.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Nothing to instantiate without type parameters.
+        if generics.is_empty() {
+            state.ast = ast;
+            return Ok(());
+        }
+
+        let mut visitor = TypeMonomorphizationVisitor {
+            state,
+            generics,
+            unit: Symbol::intern(""),
+            module: Vec::new(),
+            instance: None,
+            instances: IndexMap::new(),
+        };
+
+        let generics = visitor.generics.clone();
+        for (location, generic) in &generics {
+            visitor.check_generic(location, generic);
+        }
+        visitor.state.handler.last_err()?;
+
+        for ItemsMut { unit, module, functions, composites, constructor } in items_mut(&mut ast) {
+            for (name, function) in functions.iter_mut() {
+                // The bodies of the generic functions are instantiated, not rewritten in place.
+                if !visitor.generics.contains_key(&item_location(unit, &module, *name)) {
+                    visitor.unit = unit;
+                    visitor.module = module.clone();
+                    *function = visitor.reconstruct_function(std::mem::take(function));
+                }
+            }
+            visitor.unit = unit;
+            visitor.module = module;
+            for (_, composite) in composites.iter_mut() {
+                for (_, function) in composite.functions.iter_mut() {
+                    *function = visitor.reconstruct_function(std::mem::take(function));
+                }
+            }
+            if let Some(constructor) = constructor {
+                *constructor = visitor.reconstruct_constructor(std::mem::take(constructor));
+            }
+        }
+
+        visitor.state.handler.last_err()?;
+
+        // Replace the generic functions with their instantiations.
+        let TypeMonomorphizationVisitor { state, generics, mut instances, .. } = visitor;
+        for ItemsMut { unit, module, functions, .. } in items_mut(&mut ast) {
+            functions.retain(|(name, _)| !generics.contains_key(&item_location(unit, &module, *name)));
+            let (here, elsewhere): (IndexMap<_, _>, IndexMap<_, _>) = std::mem::take(&mut instances)
+                .into_iter()
+                .partition(|(location, _)| location.program == unit && location.module_path() == module);
+            functions.extend(here.into_values().map(|function| (function.identifier.name, function)));
+            instances = elsewhere;
+        }
+        state.ast = ast;
+
+        // We need to recreate the symbol table because this pass adds and removes functions.
+        state.symbol_table = SymbolTable::default();
+        GlobalVarsCollection::do_pass((), state)?;
+        PathResolution::do_pass((), state)?;
+        GlobalItemsCollection::do_pass((), state)?;
+
+        Ok(())
+    }
+}
+
+/// Returns the interface bounding `param`, if it is a type parameter of a function of the compilation unit `unit`.
+fn bound(symbol_table: &SymbolTable, unit: Symbol, param: &ConstParameter) -> Option<Location> {
+    let Type::Composite(composite) = &param.type_ else {
+        return None;
+    };
+    let location = composite.path.try_global_location()?;
+    symbol_table.lookup_interface(unit, location).map(|_| location.clone())
+}
+
+/// The functions of a module, or of the top level of a program or library.
+struct ItemsMut<'a> {
+    unit: Symbol,
+    module: Vec<Symbol>,
+    functions: &'a mut Vec<(Symbol, Function)>,
+    composites: &'a mut [(Symbol, Composite)],
+    constructor: Option<&'a mut Constructor>,
+}
+
+/// Returns the location of the item `name` of the module `module` of the compilation unit `unit`.
+fn item_location(unit: Symbol, module: &[Symbol], name: Symbol) -> Location {
+    Location::new(unit, module.iter().copied().chain([name]).collect())
+}
+
+/// Returns the items of every module of `ast`, including those of its stubs.
+fn items_mut(ast: &mut Ast) -> Vec<ItemsMut<'_>> {
+    let mut items = Vec::new();
+    match ast {
+        Ast::Program(program) => program_items_mut(program, &mut items),
+        Ast::Library(library) => library_items_mut(library, &mut items),
+    }
+    items
+}
+
+fn program_items_mut<'a>(program: &'a mut Program, items: &mut Vec<ItemsMut<'a>>) {
+    for scope in program.program_scopes.values_mut() {
+        items.push(ItemsMut {
+            unit: scope.program_id.as_symbol(),
+            module: Vec::new(),
+            functions: &mut scope.functions,
+            composites: &mut scope.composites,
+            constructor: scope.constructor.as_mut(),
+        });
+    }
+    for module in program.modules.values_mut() {
+        items.push(ItemsMut {
+            unit: module.unit_name,
+            module: module.path.clone(),
+            functions: &mut module.functions,
+            composites: &mut module.composites,
+            constructor: None,
+        });
+    }
+    for stub in program.stubs.values_mut() {
+        stub_items_mut(stub, items);
+    }
+}
+
+fn library_items_mut<'a>(library: &'a mut Library, items: &mut Vec<ItemsMut<'a>>) {
+    items.push(ItemsMut {
+        unit: library.name,
+        module: Vec::new(),
+        functions: &mut library.functions,
+        composites: &mut library.structs,
+        constructor: None,
+    });
+    for module in library.modules.values_mut() {
+        items.push(ItemsMut {
+            unit: module.unit_name,
+            module: module.path.clone(),
+            functions: &mut module.functions,
+            composites: &mut module.composites,
+            constructor: None,
+        });
+    }
+    for stub in library.stubs.values_mut() {
+        stub_items_mut(stub, items);
+    }
+}
+
+fn stub_items_mut<'a>(stub: &'a mut Stub, items: &mut Vec<ItemsMut<'a>>) {
+    match stub {
+        Stub::FromLeo { program, .. } => program_items_mut(program, items),
+        Stub::FromLibrary { library, .. } => library_items_mut(library, items),
+        Stub::FromAleo { .. } => {}
+    }
+}
+
+struct TypeMonomorphizationVisitor<'a> {
+    state: &'a mut CompilerState,
+    /// The functions with type parameters, by location.
+    generics: IndexMap<Location, Function>,
+    /// The compilation unit of the function being reconstructed.
+    unit: Symbol,
+    /// The module of the function being reconstructed.
+    module: Vec<Symbol>,
+    /// The substitution applied to the function being reconstructed, if it is an instantiation.
+    instance: Option<Instance>,
+    /// The instantiations of the generic functions, by location.
+    instances: IndexMap<Location, Function>,
+}
+
+/// The substitution of the type parameters of a generic function in one of its instantiations.
+struct Instance {
+    /// The location of the generic function.
+    generic: Location,
+    /// The paths of the type arguments, by type parameter.
+    arguments: IndexMap<Symbol, Path>,
+    /// Whether the instantiation is placed in another module than the generic function.
+    relocated: bool,
+}
+
+impl Instance {
+    /// Returns the type argument of the type parameter at `location`, if it is one.
+    fn argument(&self, location: &Location) -> Option<&Path> {
+        let (name, module) = location.path.split_last()?;
+        if location.program == self.generic.program && module == self.generic.module_path() {
+            self.arguments.get(name)
+        } else {
+            None
+        }
+    }
+}
+
+impl TypeMonomorphizationVisitor<'_> {
+    /// Type checks the body of the generic function `generic` at `location`, with its type parameters standing for
+    /// archetypes of their bounds.
+    ///
+    /// The archetypes, and the instantiations their calls need, are only added to the symbol table for the check.
+    fn check_generic(&mut self, location: &Location, generic: &Function) {
+        let unit = location.program;
+        let module = location.module_path().to_vec();
+
+        let mut arguments = IndexMap::new();
+        let mut structs = Vec::new();
+        let mut functions = Vec::new();
+        for param in &generic.const_parameters {
+            let Some(bound) = bound(&self.state.symbol_table, unit, param) else {
+                continue;
+            };
+
+            // `T` is resolved as an item of the generic function's module, so its archetype is placed there. A
+            // type parameter named after another item of the module is only checked through its instantiations.
+            let archetype = item_location(unit, &module, param.identifier.name);
+            let composite = Composite {
+                is_exported: None,
+                identifier: param.identifier,
+                const_parameters: vec![],
+                members: vec![],
+                is_record: false,
+                variants: None,
+                functions: vec![],
+                span: param.span,
+                id: self.state.node_builder.next_id(),
+            };
+            if self.state.symbol_table.insert_struct(archetype.clone(), composite).is_err() {
+                arguments.clear();
+                break;
+            }
+            structs.push(archetype.clone());
+
+            // The associated functions of `T` are the prototypes of its bound, where `Self` stands for `T`.
+            let path = Path::from(param.identifier).to_global(archetype);
+            let mut substitution =
+                SelfSubstitution { type_: CompositeType { path: path.clone(), const_arguments: vec![] } };
+            let prototypes: Vec<FunctionPrototype> = self
+                .interfaces(&bound)
+                .into_iter()
+                .flat_map(|interface| interface.functions.iter().map(|(_, prototype)| prototype.clone()))
+                .collect();
+            for prototype in prototypes {
+                let prototype = substitution.reconstruct_function_prototype(prototype);
+                let name = Composite::associated_function_name(param.identifier.name, prototype.identifier.name);
+                let function = Function {
+                    identifier: Identifier { name, ..prototype.identifier },
+                    const_parameters: prototype.const_parameters,
+                    input: prototype.input,
+                    output: prototype.output,
+                    output_type: prototype.output_type,
+                    span: prototype.span,
+                    id: self.state.node_builder.next_id(),
+                    ..Default::default()
+                };
+                let location = item_location(unit, &module, name);
+                // An interface inherited twice declares its functions twice.
+                if self.state.symbol_table.insert_function(location.clone(), function, false).is_ok() {
+                    functions.push(location);
+                }
+            }
+
+            arguments.insert(param.identifier.name, path);
+        }
+
+        if !arguments.is_empty() {
+            let type_parameters: IndexSet<Symbol> = arguments.keys().copied().collect();
+            self.unit = unit;
+            self.module = module.clone();
+            self.instance = Some(Instance { generic: location.clone(), arguments, relocated: false });
+            let mut function = self.reconstruct_function(generic.clone());
+            self.instance = None;
+            function.const_parameters.retain(|param| !type_parameters.contains(&param.identifier.name));
+
+            // The calls of generic functions in the body now call their instantiations for the archetypes.
+            for (location, instance) in std::mem::take(&mut self.instances) {
+                if self.state.symbol_table.insert_function(location.clone(), instance, false).is_ok() {
+                    functions.push(location);
+                }
+            }
+
+            TypeChecking::check_function(
+                TypeCheckingInput::new(self.state.network),
+                self.state,
+                unit,
+                module,
+                &function,
+            );
+        }
+
+        for location in &structs {
+            self.state.symbol_table.remove_struct(location);
+        }
+        for location in &functions {
+            self.state.symbol_table.remove_function(location);
+        }
+    }
+
+    /// Rewrites a call of the generic function at `location` into a call of its instantiation for the type arguments
+    /// of the call, after checking them against the bounds of their parameters.
+    fn instantiate_call(&mut self, input: CallExpression, location: Location) -> Expression {
+        let generic = self.generics[&location].clone();
+
+        if generic.const_parameters.len() != input.const_arguments.len() {
+            self.state.handler.emit_err(crate::errors::type_checker::incorrect_num_const_args(
+                "function",
+                generic.const_parameters.len(),
+                input.const_arguments.len(),
+                input.span,
+            ));
+            return input.into();
+        }
+
+        let mut arguments = IndexMap::new();
+        let mut const_arguments = Vec::new();
+        for (param, argument) in generic.const_parameters.iter().zip(input.const_arguments.iter()) {
+            match bound(&self.state.symbol_table, location.program, param) {
+                Some(bound) => {
+                    let Some(type_) = self.type_argument(param, argument) else {
+                        return input.into();
+                    };
+                    if !self.satisfies(&type_, param, &bound, argument.span()) {
+                        return input.into();
+                    }
+                    arguments.insert(param.identifier.name, type_);
+                }
+                None => const_arguments.push(argument.clone()),
+            }
+        }
+
+        // The instantiation is placed next to the generic function, unless one of the type arguments isn't visible
+        // from there. It is then placed next to the caller, and named after the generic function's full path.
+        let relocated = !arguments.values().all(|type_| self.is_composite(location.program, type_));
+        let (unit, module, name) = if relocated {
+            let unit = location.program.to_string();
+            let name = std::iter::once(unit.trim_end_matches(".aleo").to_string())
+                .chain(location.path.iter().map(|segment| segment.to_string()))
+                .join("__");
+            (self.unit, self.module.clone(), Symbol::intern(&name))
+        } else {
+            (location.program, location.module_path().to_vec(), generic.identifier.name)
+        };
+
+        // The type arguments are named relative to the instantiation's unit, like `Point` or `other.aleo/Point`.
+        let type_names = arguments.values().map(|type_| {
+            let type_location = type_.expect_global_location();
+            if type_location.program == unit { type_location.path.iter().join("::") } else { type_location.to_string() }
+        });
+        let name = Symbol::intern(&format!("{name}::[{}]", type_names.format(", ")));
+        let instance_location = item_location(unit, &module, name);
+
+        if !self.instances.contains_key(&instance_location) {
+            // Claim the location first, so that the instantiation isn't repeated by the calls in its own body.
+            self.instances.insert(instance_location.clone(), Function::default());
+            let instance = Instance { generic: location, arguments, relocated };
+            let function = self.instantiate_function(generic, instance, unit, module, name);
+            self.instances.insert(instance_location.clone(), function);
+        }
+
+        let function = if relocated {
+            Path::from(Identifier { name, ..*input.function.identifier() }).to_global(instance_location)
+        } else {
+            input.function.with_updated_last_symbol(name)
+        };
+        CallExpression { function, const_arguments, ..input }.into()
+    }
+
+    /// Returns a copy of `generic`, named `name`, with the substitution of `instance` applied to its body.
+    fn instantiate_function(
+        &mut self,
+        generic: Function,
+        instance: Instance,
+        unit: Symbol,
+        module: Vec<Symbol>,
+        name: Symbol,
+    ) -> Function {
+        let type_parameters: IndexSet<Symbol> = instance.arguments.keys().copied().collect();
+
+        // The body is reconstructed as if it were declared at the instantiation's location.
+        let caller_unit = std::mem::replace(&mut self.unit, unit);
+        let caller_module = std::mem::replace(&mut self.module, module);
+        let caller_instance = self.instance.replace(instance);
+
+        let mut replacer = Replacer::new(|expr: &Expression| expr.clone(), true /* refresh IDs */, self.state);
+        let function = replacer.reconstruct_function(generic);
+        let mut function = self.reconstruct_function(function);
+
+        self.unit = caller_unit;
+        self.module = caller_module;
+        self.instance = caller_instance;
+
+        function.identifier =
+            Identifier { name, span: function.identifier.span, id: self.state.node_builder.next_id() };
+        function.const_parameters.retain(|param| !type_parameters.contains(&param.identifier.name));
+        function.id = self.state.node_builder.next_id();
+        function
+    }
+
+    /// Returns the path of the struct or record passed as `argument` to the type parameter `param`.
+    fn type_argument(&self, param: &ConstParameter, argument: &Expression) -> Option<Path> {
+        if let Expression::Path(path) = argument
+            && let Some(location) = path.try_global_location()
+            && let Some(composite) = self
+                .state
+                .symbol_table
+                .lookup_struct(self.unit, location)
+                .or_else(|| self.state.symbol_table.lookup_record(self.unit, location))
+            && composite.const_parameters.is_empty()
+        {
+            return Some(path.clone());
+        }
+        self.state.handler.emit_err(crate::errors::type_checker::expected_type_argument(
+            param.identifier.name,
+            argument,
+            argument.span(),
+        ));
+        None
+    }
+
+    /// Returns whether `path` is a struct or record visible from the compilation unit `unit`.
+    fn is_composite(&self, unit: Symbol, path: &Path) -> bool {
+        path.try_global_location().is_some_and(|location| {
+            self.state.symbol_table.lookup_struct(unit, location).is_some()
+                || self.state.symbol_table.lookup_record(unit, location).is_some()
+        })
+    }
+
+    /// Checks that the type `type_` satisfies the interface `bound` of the type parameter `param`, emitting an error
+    /// naming the bound if it doesn't.
+    fn satisfies(&self, type_: &Path, param: &ConstParameter, bound: &Location, span: Span) -> bool {
+        for interface in self.interfaces(bound) {
+            for (_, prototype) in &interface.functions {
+                if let Err(reason) = self.implements(type_, prototype, interface.identifier.name) {
+                    self.state.handler.emit_err(crate::errors::check_interfaces::unsatisfied_bound(
+                        type_,
+                        param.identifier.name,
+                        &param.type_,
+                        reason,
+                        span,
+                    ));
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns the interface `bound` and the interfaces it inherits from.
+    fn interfaces(&self, bound: &Location) -> Vec<&Interface> {
+        let mut interfaces = Vec::new();
+        let mut pending = vec![bound.clone()];
+        let mut visited = IndexSet::new();
+        while let Some(location) = pending.pop() {
+            if !visited.insert(location.clone()) {
+                continue;
+            }
+            // Interfaces and their inheritance have been checked by `CheckInterfaces`.
+            let Some(interface) = self.state.symbol_table.lookup_interface(location.program, &location) else {
+                continue;
+            };
+            pending.extend(interface.parents.iter().filter_map(|(_, parent)| match parent {
+                Type::Composite(parent) => parent.path.try_global_location().cloned(),
+                _ => None,
+            }));
+            interfaces.push(interface);
+        }
+        interfaces
+    }
+
+    /// Checks that the type `type_` has an associated function matching `prototype`, declared by `interface`.
+    ///
+    /// Returns why it doesn't otherwise.
+    fn implements(&self, type_: &Path, prototype: &FunctionPrototype, interface: Symbol) -> Result<(), String> {
+        let type_location = type_.expect_global_location();
+        let (type_name, module) = type_location.path.split_last().expect("a type has a name");
+        let name = Composite::associated_function_name(*type_name, prototype.identifier.name);
+        let location = item_location(type_location.program, module, name);
+
+        let Some(function) = self.state.symbol_table.lookup_function(type_location.program, &location) else {
+            return Err(format!("it has no associated function `{}`", prototype.identifier.name));
+        };
+        let function = &function.function;
+
+        let is_method = prototype.input.first().is_some_and(|input| input.identifier.name == sym::SelfLower);
+        if function.is_method() != is_method {
+            let requirement = if is_method { "take" } else { "not take" };
+            return Err(format!("`{interface}` requires `{name}` to {requirement} `self` as its first parameter"));
+        }
+
+        // `Self` stands for `type_` in the prototype.
+        let mut substitution =
+            SelfSubstitution { type_: CompositeType { path: type_.clone(), const_arguments: vec![] } };
+        let inputs: Vec<Type> =
+            prototype.input.iter().map(|input| substitution.reconstruct_type(input.type_.clone()).0).collect();
+        let output = substitution.reconstruct_type(prototype.output_type.clone()).0;
+
+        let matches = function.input.len() == inputs.len()
+            && function.input.iter().zip(&inputs).all(|(input, expected)| input.type_.types_equivalent(expected))
+            && function.output_type.types_equivalent(&output);
+        if !matches {
+            let found = function.input.iter().map(|input| &input.type_);
+            return Err(format!(
+                "`{name}` has the signature `fn({}) -> {}`, but `{interface}` requires `fn({}) -> {output}`",
+                found.format(", "),
+                function.output_type,
+                inputs.iter().format(", "),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns `path` written out in full, so that it resolves to the same location from any module.
+    fn qualify(&self, path: Path) -> Path {
+        let location = path.expect_global_location().clone();
+        let (name, module) = location.path.split_last().expect("a global path has a name");
+        let identifier = |name: Symbol| Identifier { name, ..*path.identifier() };
+        let qualified = if self.state.symbol_table.is_library(location.program) {
+            let qualifier = std::iter::once(location.program).chain(module.iter().copied()).map(identifier).collect();
+            Path::new(None, qualifier, identifier(*name), path.span, path.id)
+        } else {
+            let program = Symbol::intern(location.program.to_string().trim_end_matches(".aleo"));
+            let qualifier = module.iter().copied().map(identifier).collect();
+            Path::new(Some(ProgramId::from(identifier(program))), qualifier, identifier(*name), path.span, path.id)
+        };
+        qualified.to_global(location)
+    }
+}
+
+impl AstReconstructor for TypeMonomorphizationVisitor<'_> {
+    type AdditionalInput = ();
+    type AdditionalOutput = ();
+
+    /* Types */
+    fn reconstruct_composite_type(&mut self, input: CompositeType) -> (Type, Self::AdditionalOutput) {
+        // `T` is replaced with its argument.
+        if let Some(instance) = &self.instance
+            && let Some(argument) = input.path.try_global_location().and_then(|location| instance.argument(location))
+        {
+            return (CompositeType { path: argument.clone(), const_arguments: vec![] }.into(), Default::default());
+        }
+        (
+            CompositeType {
+                const_arguments: input
+                    .const_arguments
+                    .into_iter()
+                    .map(|arg| self.reconstruct_expression(arg, &()).0)
+                    .collect(),
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
+    /* Expressions */
+    fn reconstruct_call(&mut self, input: CallExpression, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        let mut input = CallExpression {
+            const_arguments: input
+                .const_arguments
+                .into_iter()
+                .map(|arg| self.reconstruct_expression(arg, &()).0)
+                .collect(),
+            arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg, &()).0).collect(),
+            ..input
+        };
+
+        // `T::name` is replaced with the associated function `name` of the argument of `T`.
+        if let Some(instance) = &self.instance
+            && let Some(location) = input.function.try_global_location()
+            && let Some((name, type_path)) = location.path.split_last()
+            && let Some(argument) = instance.argument(&Location::new(location.program, type_path.to_vec()))
+        {
+            let type_location = argument.expect_global_location();
+            let (type_name, module) = type_location.path.split_last().expect("a type has a name");
+            let name = Composite::associated_function_name(*type_name, *name);
+            let location = item_location(type_location.program, module, name);
+            input.function = Path::from(Identifier { name, ..*input.function.identifier() }).to_global(location);
+        }
+
+        match input.function.try_global_location() {
+            Some(location) if self.generics.contains_key(location) => {
+                let location = location.clone();
+                (self.instantiate_call(input, location), Default::default())
+            }
+            _ => (input.into(), Default::default()),
+        }
+    }
+
+    fn reconstruct_path(&mut self, input: Path, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        let Some(instance) = &self.instance else {
+            return (input.into(), Default::default());
+        };
+
+        // `T` is replaced with its argument, e.g. when passed on to another generic function. Path resolution runs
+        // again on the instantiation, so the argument is written out in full.
+        if input.is_local()
+            && let Some(argument) = instance.arguments.get(&input.identifier().name)
+        {
+            let mut argument = self.qualify(argument.clone());
+            argument.span = input.span;
+            argument.id = input.id;
+            return (argument.into(), Default::default());
+        }
+
+        // The other global paths are written out in full when the instantiation is moved to another module.
+        if instance.relocated && input.is_global() {
+            return (self.qualify(input).into(), Default::default());
+        }
+
+        (input.into(), Default::default())
+    }
+}
+
+impl UnitReconstructor for TypeMonomorphizationVisitor<'_> {}
+
+/// Replaces `Self` with `type_` in the types of an interface.
+struct SelfSubstitution {
+    type_: CompositeType,
+}
+
+impl AstReconstructor for SelfSubstitution {
+    type AdditionalInput = ();
+    type AdditionalOutput = ();
+
+    fn reconstruct_composite_type(&mut self, input: CompositeType) -> (Type, Self::AdditionalOutput) {
+        if input.path.qualifier().is_empty() && input.path.identifier().name == sym::SelfUpper {
+            (self.type_.clone().into(), Default::default())
+        } else {
+            (input.into(), Default::default())
+        }
+    }
+}
+
+impl UnitReconstructor for SelfSubstitution {}
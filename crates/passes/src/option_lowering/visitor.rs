@@ -216,6 +216,8 @@ impl OptionLoweringVisitor<'_> {
                 },
             ],
             is_record: false,
+            variants: None,
+            functions: vec![],
             span: Span::default(),
             id: self.state.node_builder.next_id(),
         });
@@ -29,6 +29,11 @@ use leo_ast::{
     ErrExpression,
     Expression,
     IterationStatement,
+    MatchArm,
+    MatchExpression,
+    MatchPattern,
+    MatchStatement,
+    MatchStatementArm,
     Path,
     Statement,
     Type,
@@ -69,11 +74,12 @@ impl AstReconstructor for PathResolutionVisitor<'_> {
         _additional: &(),
     ) -> (Expression, Self::AdditionalOutput) {
         if !input.function.is_resolved() {
-            input.function = input.function.resolve_as_global_in_module(
+            let function = input.function.resolve_as_global_in_module(
                 self.program,
                 &self.state.symbol_table.get_imports(&self.program),
                 self.module.clone(),
             );
+            input.function = self.resolve_associated_function(function);
         }
         (
             CallExpression {
@@ -130,6 +136,21 @@ impl AstReconstructor for PathResolutionVisitor<'_> {
         )
     }
 
+    fn reconstruct_match(&mut self, input: MatchExpression, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                self.in_scope(arm.id, |slf| {
+                    let pattern = slf.resolve_match_pattern(arm.pattern);
+                    MatchArm { pattern, expression: slf.reconstruct_expression(arm.expression, &()).0, ..arm }
+                })
+            })
+            .collect();
+        (MatchExpression { scrutinee, arms, ..input }.into(), Default::default())
+    }
+
     fn reconstruct_path(&mut self, mut input: Path, _additional: &()) -> (Expression, Self::AdditionalOutput) {
         let has_qualifier = !input.qualifier().is_empty();
 
@@ -158,6 +179,9 @@ impl AstReconstructor for PathResolutionVisitor<'_> {
             } else if self.state.symbol_table.lookup_local(input.identifier().name).is_some() {
                 // If already inserted as a local variable in the symbol table, just resolve it to local
                 input = input.to_local();
+            } else if self.composites.contains(potentially_global.expect_global_location()) {
+                // A struct or record passed to a type parameter, like `Point` in `hash::[Point](p)`.
+                input = potentially_global;
             } else {
                 // Otherwise, unknown path.
                 self.state.handler.emit_err(crate::errors::type_checker::unknown_sym(
@@ -234,6 +258,21 @@ impl AstReconstructor for PathResolutionVisitor<'_> {
         )
     }
 
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                self.in_scope(arm.id, |slf| {
+                    let pattern = slf.resolve_match_pattern(arm.pattern);
+                    MatchStatementArm { pattern, block: slf.reconstruct_block(arm.block).0, ..arm }
+                })
+            })
+            .collect();
+        (MatchStatement { scrutinee, arms, ..input }.into(), Default::default())
+    }
+
     fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
         self.in_scope(input.id, |slf| {
             (
@@ -279,3 +318,35 @@ impl AstReconstructor for PathResolutionVisitor<'_> {
         })
     }
 }
+
+impl PathResolutionVisitor<'_> {
+    /// Resolves the variant path of a match pattern and inserts its bindings into the current scope.
+    fn resolve_match_pattern(&mut self, pattern: MatchPattern) -> MatchPattern {
+        let MatchPattern::Variant { path, bindings } = pattern else {
+            return pattern;
+        };
+
+        let path = if path.is_resolved() {
+            path
+        } else {
+            path.resolve_as_global_in_module(
+                self.program,
+                &self.state.symbol_table.get_imports(&self.program),
+                self.module.clone(),
+            )
+        };
+
+        for binding in bindings.iter().flatten() {
+            if let Err(err) = self.state.symbol_table.insert_variable(self.program, &[binding.name], VariableSymbol {
+                type_: None,
+                span: binding.span,
+                declaration: VariableType::Mut,
+                is_exported: None,
+            }) {
+                self.state.handler.emit_err(err);
+            }
+        }
+
+        MatchPattern::Variant { path, bindings }
+    }
+}
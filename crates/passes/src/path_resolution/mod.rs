@@ -32,6 +32,10 @@
 //! - Paths with qualifiers are always resolved as global paths.
 //! - Unqualified paths are resolved as global or local based on symbol table lookup.
 //! - Global paths are resolved relative to the current module and program context.
+//! - Calls of associated functions, like `Foo::new()`, are resolved to the location the
+//!   function is collected at, `Foo::new` being a single path segment.
+//! - Unqualified paths naming a struct or record, like `Point` in `hash::[Point](p)`, are
+//!   resolved as global paths, so that types can be passed to type parameters.
 //! - Local scopes are created for functions, blocks, composites, constructors, and loops.
 //! - Local variables are inserted with their declaration kind, but without final types.
 //!
@@ -40,12 +44,17 @@
 //! Subsequent passes (e.g. type checking) assume that all paths are resolved and
 //! that all scopes already exist, and therefore do not create or mutate scopes.
 
-use crate::Pass;
+use crate::{
+    Pass,
+    common::{library_composites, program_composites, stub_composites},
+};
 
-use leo_ast::{Ast, UnitReconstructor as _};
+use leo_ast::{Ast, Composite, Location, UnitReconstructor as _};
 use leo_errors::Result;
 use leo_span::Symbol;
 
+use indexmap::IndexSet;
+
 mod ast;
 
 mod program;
@@ -63,7 +72,16 @@ impl Pass for PathResolution {
 
     fn do_pass(_input: Self::Input, state: &mut crate::CompilerState) -> Result<Self::Output> {
         let ast = std::mem::take(&mut state.ast);
-        let mut visitor = PathResolutionVisitor { state, program: Symbol::intern(""), module: Vec::new() };
+        let composites = visible_composites(&ast);
+        let associated_functions = associated_function_locations(&composites);
+        let composites = composites.into_iter().map(|(location, _)| location).collect();
+        let mut visitor = PathResolutionVisitor {
+            state,
+            program: Symbol::intern(""),
+            module: Vec::new(),
+            associated_functions,
+            composites,
+        };
 
         let ast = match ast {
             Ast::Program(program) => Ast::Program(visitor.reconstruct_program(program)),
@@ -76,3 +94,27 @@ impl Pass for PathResolution {
         Ok(())
     }
 }
+
+/// Returns every composite visible to the compilation unit, with its location.
+fn visible_composites(ast: &Ast) -> Vec<(Location, &Composite)> {
+    match ast {
+        Ast::Program(program) => {
+            program_composites(program).chain(program.stubs.values().flat_map(stub_composites)).collect()
+        }
+        Ast::Library(library) => {
+            library_composites(library).chain(library.stubs.values().flat_map(stub_composites)).collect()
+        }
+    }
+}
+
+/// Returns the locations of the associated functions of `composites`.
+fn associated_function_locations(composites: &[(Location, &Composite)]) -> IndexSet<Location> {
+    composites
+        .iter()
+        .flat_map(|(location, composite)| {
+            composite.functions.iter().map(move |(name, _)| {
+                Location::new(location.program, location.module_path().iter().copied().chain([*name]).collect())
+            })
+        })
+        .collect()
+}
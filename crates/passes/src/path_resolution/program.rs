@@ -22,6 +22,7 @@ use leo_ast::{
     Composite,
     ConstParameter,
     Constructor,
+    EnumVariant,
     Function,
     FunctionStub,
     Input,
@@ -197,8 +198,12 @@ impl UnitReconstructor for PathResolutionVisitor<'_> {
         })
     }
 
-    fn reconstruct_composite(&mut self, input: Composite) -> Composite {
-        self.in_scope(input.id, |slf| {
+    fn reconstruct_composite(&mut self, mut input: Composite) -> Composite {
+        // Associated functions are scoped like the other functions of the module, not within the composite.
+        let functions =
+            std::mem::take(&mut input.functions).into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect();
+
+        let composite = self.in_scope(input.id, |slf| {
             Composite {
                 const_parameters: input
                     .const_parameters
@@ -235,9 +240,21 @@ impl UnitReconstructor for PathResolutionVisitor<'_> {
                     })
                     .collect(),
 
+                variants: input.variants.as_ref().map(|variants| {
+                    variants
+                        .iter()
+                        .map(|variant| EnumVariant {
+                            types: variant.types.iter().map(|ty| slf.reconstruct_type(ty.clone()).0).collect(),
+                            ..variant.clone()
+                        })
+                        .collect()
+                }),
+
                 ..input
             }
-        })
+        });
+
+        Composite { functions, ..composite }
     }
 
     fn reconstruct_constructor(&mut self, input: Constructor) -> Constructor {
@@ -15,16 +15,22 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::CompilerState;
-use leo_ast::NodeID;
+use leo_ast::{Composite, Location, NodeID, Path};
 
 use leo_span::Symbol;
 
+use indexmap::IndexSet;
+
 pub struct PathResolutionVisitor<'a> {
     pub state: &'a mut CompilerState,
     /// The current program.
     pub program: Symbol,
     /// The current module.
     pub module: Vec<Symbol>,
+    /// The locations of the associated functions of all composites, e.g. `foo.aleo/Foo::new`.
+    pub associated_functions: IndexSet<Location>,
+    /// The locations of all composites, e.g. `foo.aleo/Foo`.
+    pub composites: IndexSet<Location>,
 }
 
 impl PathResolutionVisitor<'_> {
@@ -37,6 +43,19 @@ impl PathResolutionVisitor<'_> {
         result
     }
 
+    /// Resolves a global `path` like `Foo::new` to the associated function `new` of `Foo`, if there is one.
+    pub fn resolve_associated_function(&self, path: Path) -> Path {
+        let location = path.expect_global_location();
+        if let [module @ .., type_name, name] = location.path.as_slice() {
+            let function_name = Composite::associated_function_name(*type_name, *name);
+            let function = Location::new(location.program, module.iter().copied().chain([function_name]).collect());
+            if self.associated_functions.contains(&function) {
+                return path.to_global(function);
+            }
+        }
+        path
+    }
+
     pub fn in_scope<T>(&mut self, id: NodeID, func: impl FnOnce(&mut Self) -> T) -> T {
         self.state.symbol_table.enter_scope(Some(id));
         let result = func(self);
@@ -17,7 +17,8 @@
 //! Peephole optimization pass on generated Aleo bytecode instructions.
 //!
 //! This pass runs after code generation and before serialization. It applies
-//! local optimizations on the flat instruction lists (`Vec<AleoStmt>`):
+//! local optimizations on the flat instruction lists (`AleoStatements`), which keep each
+//! remaining instruction's Leo span so the source map stays accurate:
 //!
 //! - **Identity operation folding**: eliminates `add x 0`, `mul x 1`, `or x false`, etc.
 //! - **Trivial assert elimination**: removes `assert.eq <lit> <lit>` when both sides are equal,
@@ -26,7 +27,7 @@
 //! - **Consecutive cast folding**: merges adjacent casts through the same intermediate register.
 //! - **Register renumbering**: compacts register indices after instruction removal.
 
-use crate::{AleoExpr, AleoReg, AleoStmt, AleoType, CompiledPrograms, GeneratedPrograms, Pass};
+use crate::{AleoExpr, AleoReg, AleoStatements, AleoStmt, AleoType, CompiledPrograms, GeneratedPrograms, Pass};
 
 use leo_errors::Result;
 
@@ -364,7 +365,7 @@ fn substitute_aliases_in_stmt(stmt: &mut AleoStmt, aliases: &HashMap<AleoReg, Al
     }
 }
 
-fn fold_identity_operations(stmts: &mut Vec<AleoStmt>) {
+fn fold_identity_operations(stmts: &mut AleoStatements) {
     // Apply strength reductions first (e.g. nand(x, true) → not(x)).
     for stmt in stmts.iter_mut() {
         if let Some(reduced) = try_strength_reduce(stmt) {
@@ -403,7 +404,7 @@ fn fold_identity_operations(stmts: &mut Vec<AleoStmt>) {
 
 // Trivial assert elimination
 
-fn eliminate_trivial_asserts(stmts: &mut Vec<AleoStmt>) {
+fn eliminate_trivial_asserts(stmts: &mut AleoStatements) {
     fn is_trivial_assert(s: &AleoStmt) -> bool {
         matches!(s, AleoStmt::AssertEq(a, b) if is_literal(a) && is_literal(b) && a == b)
     }
@@ -686,7 +687,7 @@ fn dest_register_if_pure(stmt: &AleoStmt) -> Option<&AleoReg> {
     }
 }
 
-fn eliminate_dead_registers(stmts: &mut Vec<AleoStmt>) {
+fn eliminate_dead_registers(stmts: &mut AleoStatements) {
     // Collect all registers that are read.
     let mut used = HashSet::new();
     for stmt in stmts.iter() {
@@ -705,7 +706,7 @@ fn eliminate_dead_registers(stmts: &mut Vec<AleoStmt>) {
 
 // Consecutive cast folding
 
-fn fold_consecutive_casts(stmts: &mut Vec<AleoStmt>) {
+fn fold_consecutive_casts(stmts: &mut AleoStatements) {
     if stmts.len() < 2 {
         return;
     }
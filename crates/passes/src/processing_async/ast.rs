@@ -16,7 +16,20 @@
 
 use super::ProcessingAsyncVisitor;
 use crate::BlockToFunctionRewriter;
-use leo_ast::{AstReconstructor, AsyncExpression, Block, Expression, IterationStatement, Node, Statement, Variant};
+use leo_ast::{
+    AstReconstructor,
+    AsyncExpression,
+    Block,
+    Expression,
+    IterationStatement,
+    MatchArm,
+    MatchExpression,
+    MatchStatement,
+    MatchStatementArm,
+    Node,
+    Statement,
+    Variant,
+};
 
 impl AstReconstructor for ProcessingAsyncVisitor<'_> {
     type AdditionalInput = ();
@@ -63,6 +76,33 @@ impl AstReconstructor for ProcessingAsyncVisitor<'_> {
         })
     }
 
+    fn reconstruct_match(&mut self, input: MatchExpression, _additional: &()) -> (Expression, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                self.in_scope(arm.id, |slf| MatchArm {
+                    expression: slf.reconstruct_expression(arm.expression, &()).0,
+                    ..arm
+                })
+            })
+            .collect();
+        (MatchExpression { scrutinee, arms, ..input }.into(), Default::default())
+    }
+
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        let scrutinee = self.reconstruct_expression(input.scrutinee, &()).0;
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                self.in_scope(arm.id, |slf| MatchStatementArm { block: slf.reconstruct_block(arm.block).0, ..arm })
+            })
+            .collect();
+        (MatchStatement { scrutinee, arms, ..input }.into(), Default::default())
+    }
+
     fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
         self.in_scope(input.id(), |slf| {
             (
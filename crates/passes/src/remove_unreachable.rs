@@ -152,6 +152,35 @@ impl AstReconstructor for RemoveUnreachableVisitor<'_> {
         )
     }
 
+    fn reconstruct_match_statement(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        // As with conditionals, each arm sees returns independently, and the match returns if every arm does.
+        let previous_has_return = core::mem::take(&mut self.has_return);
+        let mut all_arms_return = !input.arms.is_empty();
+
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                self.has_return = false;
+                let block = self.reconstruct_block(arm.block).0;
+                all_arms_return &= self.has_return;
+                MatchStatementArm { block, ..arm }
+            })
+            .collect();
+
+        self.has_return = previous_has_return || all_arms_return;
+
+        (
+            MatchStatement {
+                scrutinee: self.reconstruct_expression(input.scrutinee, &Default::default()).0,
+                arms,
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
         let prior_has_return = core::mem::take(&mut self.has_return);
         let block = self.reconstruct_block(input.block).0;
@@ -170,6 +199,23 @@ impl AstReconstructor for RemoveUnreachableVisitor<'_> {
         )
     }
 
+    fn reconstruct_while(&mut self, input: WhileStatement) -> (Statement, Self::AdditionalOutput) {
+        let prior_has_return = core::mem::take(&mut self.has_return);
+        let block = self.reconstruct_block(input.block).0;
+        self.has_return = prior_has_return;
+
+        (
+            WhileStatement {
+                bound: self.reconstruct_expression(input.bound, &Default::default()).0,
+                condition: self.reconstruct_expression(input.condition, &Default::default()).0,
+                block,
+                ..input
+            }
+            .into(),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         self.has_return = true;
         (
@@ -71,16 +71,11 @@ impl UnitVisitor for StaticAnalyzingVisitor<'_> {
         if self.variant.is_some_and(|v| v.is_finalize_context()) {
             // Throw error if not all futures awaits even appear once.
             if !self.await_checker.static_to_await.is_empty() {
-                self.emit_err(static_analyzer::final_runs_missing(
-                    self.await_checker
-                        .static_to_await
-                        .clone()
-                        .iter()
-                        .map(|f| f.to_string())
-                        .collect::<Vec<String>>()
-                        .join(", "),
-                    function.span(),
-                ));
+                let unrun = self.await_checker.static_to_await.iter().map(|f| f.to_string()).collect::<Vec<String>>();
+                self.emit_err(
+                    static_analyzer::final_runs_missing(unrun.join(", "), function.span())
+                        .with_suggestions(crate::errors::suggestions::run_finals(&unrun, &function.block)),
+                );
             } else if !self.await_checker.to_await.is_empty() {
                 // Tally up number of paths that are unawaited and number of paths that are awaited more than once.
                 let (num_paths_unawaited, num_paths_duplicate_awaited, num_perfect) =
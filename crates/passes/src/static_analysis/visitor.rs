@@ -87,11 +87,12 @@ impl AstVisitor for StaticAnalyzingVisitor<'_> {
     }
 
     fn visit_call(&mut self, input: &CallExpression, _: &Self::AdditionalInput) -> Self::Output {
-        let func_symbol = self
-            .state
-            .symbol_table
-            .lookup_function(self.current_unit, input.function.expect_global_location())
-            .expect("Type checking guarantees functions exist.");
+        // Type checking guarantees functions exist, so anything else is an enum variant with a payload.
+        let Some(func_symbol) =
+            self.state.symbol_table.lookup_function(self.current_unit, input.function.expect_global_location())
+        else {
+            return;
+        };
 
         if func_symbol.function.variant == Variant::EntryPoint && !func_symbol.function.has_final_output() {
             self.non_async_external_call_seen = true;
@@ -144,4 +145,34 @@ impl AstVisitor for StaticAnalyzingVisitor<'_> {
         // Update the set of all possible BST paths.
         self.await_checker.exit_statement_scope(self.variant.is_some_and(|v| v.is_finalize_context()), saved_paths);
     }
+
+    fn visit_match_statement(&mut self, input: &MatchStatement) {
+        self.visit_expression(&input.scrutinee, &Default::default());
+        self.visit_match_arms(&input.arms);
+    }
+}
+
+impl StaticAnalyzingVisitor<'_> {
+    /// Checks the awaits in the arms of a match statement, which branch like the conditional
+    /// `if arm0 { .. } else if arm1 { .. } else { .. }`.
+    fn visit_match_arms(&mut self, arms: &[MatchStatementArm]) {
+        let [first, rest @ ..] = arms else {
+            return;
+        };
+        if rest.is_empty() {
+            return self.visit_block(&first.block);
+        }
+
+        let is_finalize = self.variant.is_some_and(|v| v.is_finalize_context());
+        let current_bst_nodes = match self.await_checker.create_then_scope(is_finalize, first.span) {
+            Ok(nodes) => nodes,
+            Err(warn) => return self.emit_warning(warn),
+        };
+        self.visit_block(&first.block);
+        let saved_paths = self.await_checker.exit_then_scope(is_finalize, current_bst_nodes);
+
+        self.visit_match_arms(rest);
+
+        self.await_checker.exit_statement_scope(is_finalize, saved_paths);
+    }
 }
@@ -55,7 +55,9 @@ impl SsaFormingVisitor<'_> {
         if matches!(expr, Expression::Path(..) | Expression::Unit(..) | Expression::Err(..)) {
             (expr, statements)
         } else {
-            let (place, statement) = self.unique_simple_definition(expr);
+            let span = expr.span();
+            let (place, mut statement) = self.unique_simple_definition(expr);
+            statement.set_span(span);
             statements.push(statement);
             (Path::from(place).to_local().into(), statements)
         }
@@ -71,13 +73,6 @@ impl ExpressionConsumer for SsaFormingVisitor<'_> {
     }
 
     fn consume_member_access(&mut self, input: MemberAccess) -> Self::Output {
-        // If the access expression is of the form `self.<name>`, then don't rename it.
-        if let Expression::Path(path) = &input.inner
-            && path.identifier().name == sym::SelfLower
-        {
-            return (input.into(), Vec::new());
-        }
-
         let (inner, statements) = self.consume_expression_and_define(input.inner);
         (MemberAccess { inner, ..input }.into(), statements)
     }
@@ -118,7 +118,14 @@ impl StatementConsumer for SsaFormingVisitor<'_> {
 
     /// Consumes a `Block`, flattening its constituent `ConditionalStatement`s.
     fn consume_block(&mut self, block: Block) -> Self::Output {
-        block.statements.into_iter().flat_map(|statement| self.consume_statement(statement)).collect()
+        block
+            .statements
+            .into_iter()
+            .flat_map(|statement| {
+                let span = statement.span();
+                Self::spanned(self.consume_statement(statement), span)
+            })
+            .collect()
     }
 
     /// Consumes a `ConditionalStatement`, producing phi functions (assign statements) for variables written in the then-block and otherwise-block.
@@ -130,7 +137,12 @@ impl StatementConsumer for SsaFormingVisitor<'_> {
     ///   - `if b { x = x + 1 }` remains the same.
     fn consume_conditional(&mut self, conditional: ConditionalStatement) -> Self::Output {
         // Simplify the condition and add it into the rename table.
-        let (condition, mut statements) = self.consume_expression_and_define(conditional.condition);
+        let condition_span = conditional.condition.span();
+        let (mut condition, mut statements) = self.consume_expression_and_define(conditional.condition);
+        // Keep the original condition's span so that the branches generated for it map back to it.
+        if condition.span().is_dummy() {
+            condition.set_span(condition_span);
+        }
 
         // Instantiate a `RenameTable` for the then-block.
         self.push();
@@ -155,11 +167,10 @@ impl StatementConsumer for SsaFormingVisitor<'_> {
                 id: block.id,
                 statements: self.consume_block(block),
             },
-            Statement::Conditional(conditional) => Block {
-                span: conditional.span,
-                id: conditional.id,
-                statements: self.consume_conditional(conditional),
-            },
+            Statement::Conditional(conditional) => {
+                let (span, id) = (conditional.span, conditional.id);
+                Block { span, id, statements: Self::spanned(self.consume_conditional(conditional), span) }
+            }
             _ => panic!("Type checking guarantees that the otherwise-block of a conditional statement is a block or another conditional statement."),
         })));
 
@@ -17,7 +17,7 @@
 use crate::{CompilerState, RenameTable};
 
 use leo_ast::{Expression, Identifier, Node, Statement};
-use leo_span::Symbol;
+use leo_span::{Span, Symbol};
 
 pub struct SsaFormingVisitor<'a> {
     pub state: &'a mut CompilerState,
@@ -46,6 +46,15 @@ impl<'a> SsaFormingVisitor<'a> {
         core::mem::replace(&mut self.rename_table, *parent)
     }
 
+    /// Gives `span` to the statements without one among those a statement spanning it was split into, so that
+    /// source maps point their instructions back at it.
+    pub(crate) fn spanned(mut statements: Vec<Statement>, span: Span) -> Vec<Statement> {
+        for statement in statements.iter_mut().filter(|statement| statement.span().is_dummy()) {
+            statement.set_span(span);
+        }
+        statements
+    }
+
     pub(crate) fn rename_identifier(&mut self, mut identifier: Identifier) -> Identifier {
         // Associate this name with its id.
         self.rename_table.update(identifier.name, identifier.name, identifier.id);
@@ -49,6 +49,13 @@ pub enum AssignTargetKind {
     Err,
 }
 
+/// The variants matched by the arms of a `match` visited so far.
+#[derive(Default)]
+struct MatchCoverage {
+    variants: HashSet<usize>,
+    wildcard: bool,
+}
+
 impl TypeCheckingVisitor<'_> {
     /// Returns information about an expression when used as the LHS of an assignment.
     ///
@@ -380,6 +387,148 @@ impl TypeCheckingVisitor<'_> {
 
         inferred
     }
+
+    /// Type checks the construction of the variant at `index` of the enum at `enum_location`, written as `path`
+    /// with `arguments`, or as a bare path if `arguments` is `None`.
+    fn visit_enum_variant(
+        &mut self,
+        path: &Path,
+        (enum_location, enum_, index): (Location, Composite, usize),
+        arguments: Option<&[Expression]>,
+        expected: &Option<Type>,
+        span: Span,
+    ) -> Type {
+        if !self.check_composite_accessible(&enum_location, &enum_, path.span()) {
+            return Type::Err;
+        }
+
+        let variant = &enum_.variants.as_ref().expect("`lookup_enum_variant` only returns enums")[index];
+        match arguments {
+            None if !variant.types.is_empty() => {
+                self.emit_err(crate::errors::type_checker::variant_requires_arguments(path, variant.types.len(), span));
+            }
+            None => {}
+            Some(arguments) => {
+                if arguments.len() != variant.types.len() {
+                    self.emit_err(crate::errors::type_checker::incorrect_num_variant_args(
+                        path,
+                        variant.types.len(),
+                        arguments.len(),
+                        span,
+                    ));
+                }
+                for (argument, type_) in arguments.iter().zip(variant.types.iter()) {
+                    self.visit_expression(argument, &Some(type_.clone()));
+                }
+                for argument in arguments.iter().skip(variant.types.len()) {
+                    self.visit_expression(argument, &None);
+                }
+            }
+        }
+
+        let type_ = self.enum_type_of_variant(path, enum_location);
+        self.maybe_assert_type(&type_, expected, span);
+        type_
+    }
+
+    /// Type checks the scrutinee of a `match`, returning the location of the enum it matches on and the enum.
+    fn visit_match_scrutinee(&mut self, scrutinee: &Expression) -> Option<(Location, Composite)> {
+        let type_ = self.visit_expression(scrutinee, &None);
+        if let Type::Composite(composite_type) = &type_
+            && let Some(location) = composite_type.path.try_global_location()
+            && let Some(composite) = self.lookup_composite(location)
+            && composite.is_enum()
+        {
+            return Some((location.clone(), composite));
+        }
+        if type_ != Type::Err {
+            self.emit_err(crate::errors::type_checker::match_on_non_enum(type_, scrutinee.span()));
+        }
+        None
+    }
+
+    /// Type checks the pattern of a match arm against the matched enum, if known, and sets the types of its bindings.
+    fn visit_match_pattern(
+        &mut self,
+        pattern: &MatchPattern,
+        matched: Option<&(Location, Composite)>,
+        coverage: &mut MatchCoverage,
+        span: Span,
+    ) {
+        if coverage.wildcard {
+            self.emit_err(crate::errors::type_checker::unreachable_match_arm(span));
+        }
+
+        let MatchPattern::Variant { path, bindings } = pattern else {
+            coverage.wildcard = true;
+            return;
+        };
+
+        let variant = matched.and_then(|(matched_location, enum_)| {
+            let variant = path
+                .try_global_location()
+                .and_then(|location| self.lookup_enum_variant(location))
+                .filter(|(location, ..)| location == matched_location);
+            if variant.is_none() {
+                self.emit_err(crate::errors::type_checker::not_a_variant_of_enum(
+                    path,
+                    enum_.identifier.name,
+                    path.span(),
+                ));
+            }
+            variant
+        });
+
+        let mut types = Vec::new();
+        if let Some((_, enum_, index)) = variant {
+            if !coverage.variants.insert(index) && !coverage.wildcard {
+                self.emit_err(crate::errors::type_checker::unreachable_match_arm(span));
+            }
+            types = enum_.variants.expect("`lookup_enum_variant` only returns enums").swap_remove(index).types;
+            if bindings.len() != types.len() {
+                self.emit_err(crate::errors::type_checker::incorrect_num_variant_bindings(
+                    path,
+                    types.len(),
+                    bindings.len(),
+                    path.span(),
+                ));
+            }
+        }
+
+        // Bindings without a known type are set to `Type::Err`, as an error has already been reported.
+        for (i, binding) in bindings.iter().enumerate() {
+            if let Some(binding) = binding {
+                let type_ = types.get(i).cloned().unwrap_or(Type::Err);
+                self.set_local_type(None, binding, type_);
+            }
+        }
+    }
+
+    /// Emits an error if the arms of a `match` on the enum `matched` do not cover all of its variants.
+    fn check_match_exhaustive(
+        &mut self,
+        matched: Option<&(Location, Composite)>,
+        coverage: &MatchCoverage,
+        span: Span,
+    ) {
+        let Some((_, enum_)) = matched else {
+            return;
+        };
+        if coverage.wildcard {
+            return;
+        }
+        let missing = enum_
+            .variants
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(index, _)| !coverage.variants.contains(index))
+            .map(|(_, variant)| format!("`{}::{}`", enum_.identifier.name, variant.name()))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            self.emit_err(crate::errors::type_checker::non_exhaustive_match(missing.join(", "), span));
+        }
+    }
 }
 
 impl AstVisitor for TypeCheckingVisitor<'_> {
@@ -431,7 +580,9 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Path(path) => self.visit_path(path, additional),
             Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Match(match_) => self.visit_match(match_, additional),
             Expression::MemberAccess(access) => self.visit_member_access_general(access, false, additional),
+            Expression::MethodCall(call) => self.visit_method_call(call, additional),
             Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
             Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
             Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
@@ -584,10 +735,12 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
         }
 
         // Check that operation is not restricted to finalize blocks. Views are admitted here so
-        // their read-only subset can pass; finer-grained per-intrinsic checks happen later.
+        // their read-only subset can pass; finer-grained per-intrinsic checks happen later. Native
+        // tests run in the interpreter, where every operation is available.
         if !matches!(self.scope_state.variant, Some(Variant::Finalize | Variant::FinalFn | Variant::View))
             && self.async_block_id.is_none()
             && intrinsic.is_finalize_command()
+            && !self.in_native_test()
         {
             self.emit_err(crate::errors::type_checker::operation_must_be_in_final_block_or_function(input.span()));
         }
@@ -1130,12 +1283,19 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
         let callee_program = callee_location.program;
         let callee_path = callee_location.path.clone();
 
+        // A variant with a payload, e.g. `Shape::Circle(r)`.
+        if let Some(variant) = self.lookup_enum_variant(callee_location) {
+            if !input.const_arguments.is_empty() {
+                self.emit_err(crate::errors::type_checker::unexpected_const_args(input, input.span));
+            }
+            return self.visit_enum_variant(&input.function, variant, Some(&input.arguments), expected, input.span);
+        }
+
         let Some(func_symbol) = self.state.symbol_table.lookup_function(current_program, callee_location) else {
-            self.emit_err(crate::errors::type_checker::unknown_sym(
-                "function",
-                input.function.clone(),
-                input.function.span(),
-            ));
+            self.emit_err(
+                crate::errors::type_checker::unknown_sym("function", input.function.clone(), input.function.span())
+                    .with_suggestions(self.import_suggestion(callee_program)),
+            );
             return Type::Err;
         };
 
@@ -1245,12 +1405,19 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             let in_view = matches!(self.scope_state.variant, Some(Variant::View));
             let in_finalize_ctx = self.scope_state.variant.is_some_and(|v| v.is_finalize_context());
             let in_async_block = self.async_block_id.is_some();
-            if !in_view && !in_finalize_ctx && !in_async_block {
+            if !in_view && !in_finalize_ctx && !in_async_block && !self.in_native_test() {
                 let name = format!("{}()", input.function);
                 self.emit_err(crate::errors::type_checker::invalid_operation_outside_onchain(name, input.span));
             }
         }
 
+        // `@_test_cheatcode` marks the `std::test` wrappers, which only the interpreter behind
+        // native tests can carry out.
+        if func.annotations.iter().any(|a| a.identifier.name == sym::_test_cheatcode) && !self.in_native_test() {
+            let name = format!("{}()", input.function);
+            self.emit_err(crate::errors::type_checker::cheatcode_outside_native_test(name, input.span));
+        }
+
         // `@_program_id_arg` marks a compiler-internal wrapper whose first const generic argument
         // is the program-ID literal threaded into a program-metadata intrinsic. Validate the
         // literal shape at the user's call site (the wrapper body never sees the substituted
@@ -1630,11 +1797,10 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
         let composite_location = input.path.expect_global_location();
         let composite = self.lookup_composite(composite_location).clone();
         let Some(composite) = composite else {
-            self.emit_err(crate::errors::type_checker::unknown_sym(
-                "struct or record",
-                input.path.clone(),
-                input.path.span(),
-            ));
+            self.emit_err(
+                crate::errors::type_checker::unknown_sym("struct or record", input.path.clone(), input.path.span())
+                    .with_suggestions(self.import_suggestion(composite_location.program)),
+            );
             return Type::Err;
         };
 
@@ -1642,6 +1808,14 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             return Type::Err;
         }
 
+        if composite.is_enum() {
+            self.emit_err(crate::errors::type_checker::enum_cannot_be_constructed_as_struct(
+                composite.identifier.name,
+                input.span(),
+            ));
+            return Type::Err;
+        }
+
         // Check the number of const arguments against the number of the composite's const parameters
         if composite.const_parameters.len() != input.const_arguments.len() {
             self.emit_err(crate::errors::type_checker::incorrect_num_const_args(
@@ -1834,6 +2008,11 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
     }
 
     fn visit_path(&mut self, input: &Path, expected: &Self::AdditionalInput) -> Self::Output {
+        // A variant without a payload, e.g. `Shape::Empty`.
+        if let Some(variant) = input.try_global_location().and_then(|loc| self.lookup_enum_variant(loc)) {
+            return self.visit_enum_variant(input, variant, None, expected, input.span());
+        }
+
         let current_program = self.scope_state.unit_name.unwrap();
         let var = self.state.symbol_table.lookup_path(current_program, input);
 
@@ -1950,7 +2129,19 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             },
         };
 
-        self.maybe_assert_type(&type_, expected, span);
+        // A suffixed integer literal of the wrong type is fixed by changing its suffix, not by a cast.
+        if let (LiteralVariant::Integer(found, _), Some(expected)) = (&input.variant, expected)
+            && type_ != Type::Err
+            && !type_.can_coerce_to(expected)
+            && let Some(suggestion) = crate::errors::suggestions::literal_suffix(*found, expected, span)
+        {
+            self.emit_err(
+                crate::errors::type_checker::type_should_be2(&type_, format!("type `{expected}`"), span)
+                    .with_suggestion(suggestion),
+            );
+        } else {
+            self.maybe_assert_type(&type_, expected, span);
+        }
 
         type_
     }
@@ -2039,6 +2230,98 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
         typ
     }
 
+    fn visit_match(&mut self, input: &MatchExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        let matched = self.visit_match_scrutinee(&input.scrutinee);
+
+        let previous_is_conditional = core::mem::replace(&mut self.scope_state.is_conditional, true);
+
+        let mut coverage = MatchCoverage::default();
+        let mut arm_types = Vec::with_capacity(input.arms.len());
+        for arm in &input.arms {
+            let type_ = self.in_conditional_scope(|slf| {
+                slf.in_scope(arm.id, |slf| {
+                    slf.visit_match_pattern(&arm.pattern, matched.as_ref(), &mut coverage, arm.span);
+                    slf.visit_expression_reject_numeric(&arm.expression, expected)
+                })
+            });
+            arm_types.push((type_, arm.expression.span()));
+        }
+
+        self.scope_state.is_conditional = previous_is_conditional;
+
+        self.check_match_exhaustive(matched.as_ref(), &coverage, input.span);
+
+        // As with ternaries, the type of the match is the type every arm can be coerced to.
+        let mut typ = match expected {
+            Some(expected) if arm_types.iter().all(|(type_, _)| type_ != &Type::Err) => expected.clone(),
+            Some(_) => Type::Err,
+            None => arm_types.first().map(|(type_, _)| type_.clone()).unwrap_or(Type::Err),
+        };
+        if expected.is_none() {
+            for (type_, span) in arm_types.iter().skip(1) {
+                if typ == Type::Err || type_ == &Type::Err {
+                    typ = Type::Err;
+                } else if type_.can_coerce_to(&typ) {
+                    // Keep the current type.
+                } else if typ.can_coerce_to(type_) {
+                    typ = type_.clone();
+                } else {
+                    self.emit_err(crate::errors::type_checker::match_arm_type_mismatch(&typ, type_, *span));
+                    typ = Type::Err;
+                }
+            }
+        }
+
+        // A match is lowered to ternaries, so it produces the same types as they do.
+        let is_external_record = match &typ {
+            Type::Tuple(tuple) => tuple.elements().iter().any(|ty| self.is_external_record(ty)),
+            _ => self.is_external_record(&typ),
+        };
+        if is_external_record {
+            self.emit_err(crate::errors::type_checker::ternary_over_external_records(&typ, input.span));
+        }
+        if matches!(typ, Type::DynRecord) {
+            self.emit_err(crate::errors::type_checker::type_should_be2(
+                "dyn record",
+                "a type supported by `match` expressions",
+                input.span,
+            ));
+        }
+
+        typ
+    }
+
+    fn visit_method_call(&mut self, input: &MethodCallExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        let receiver_type = self.visit_expression(&input.receiver, &None);
+        if receiver_type == Type::Err {
+            return Type::Err;
+        }
+
+        // A method is an associated function of the receiver's type that takes `self` first.
+        let call = crate::method_call_to_call(input.clone(), &receiver_type);
+        let is_method = call.as_ref().and_then(|call| {
+            let current_unit = self.scope_state.unit_name.unwrap();
+            let function =
+                self.state.symbol_table.lookup_function(current_unit, call.function.expect_global_location());
+            function.map(|symbol| symbol.function.is_method())
+        });
+        match (call, is_method) {
+            (Some(call), Some(true)) => self.visit_call(&call, expected),
+            (Some(_), Some(false)) => {
+                self.emit_err(crate::errors::type_checker::associated_function_is_not_a_method(
+                    input.name,
+                    receiver_type,
+                    input.name.span,
+                ));
+                Type::Err
+            }
+            _ => {
+                self.emit_err(crate::errors::type_checker::unknown_method(input.name, receiver_type, input.name.span));
+                Type::Err
+            }
+        }
+    }
+
     fn visit_tuple(&mut self, input: &TupleExpression, expected: &Self::AdditionalInput) -> Self::Output {
         if let Some(expected) = expected {
             if let Type::Tuple(expected_types) = expected {
@@ -2254,12 +2537,16 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(stmt) => self.visit_break(stmt),
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Continue(stmt) => self.visit_continue(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match_statement(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
+            Statement::While(stmt) => self.visit_while(stmt),
         }
     }
 
@@ -2297,7 +2584,7 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             self.check_access_allowed("storage write", AccessScope::FinalizeWrite, input.place.span())
         }
 
-        if assign_target_info.kind == AssignTargetKind::ExternalStorage {
+        if assign_target_info.kind == AssignTargetKind::ExternalStorage && !self.in_native_test() {
             self.emit_err(crate::errors::type_checker::cannot_modify_external_storage_variable(input.span()));
         }
 
@@ -2375,6 +2662,33 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
         self.scope_state.is_conditional = previous_is_conditional;
     }
 
+    fn visit_match_statement(&mut self, input: &MatchStatement) {
+        let matched = self.visit_match_scrutinee(&input.scrutinee);
+
+        let previous_has_return = self.scope_state.has_return;
+        let previous_is_conditional = core::mem::replace(&mut self.scope_state.is_conditional, true);
+
+        let mut coverage = MatchCoverage::default();
+        let mut all_arms_return = !input.arms.is_empty();
+        for arm in &input.arms {
+            // Each arm starts without a return, as in the blocks of a conditional.
+            self.scope_state.has_return = false;
+            self.in_conditional_scope(|slf| {
+                slf.in_scope(arm.id, |slf| {
+                    slf.visit_match_pattern(&arm.pattern, matched.as_ref(), &mut coverage, arm.span);
+                    slf.visit_block(&arm.block);
+                })
+            });
+            all_arms_return &= self.scope_state.has_return;
+        }
+
+        self.check_match_exhaustive(matched.as_ref(), &coverage, input.span);
+
+        // The match returns if every arm returns.
+        self.scope_state.has_return = previous_has_return || all_arms_return;
+        self.scope_state.is_conditional = previous_is_conditional;
+    }
+
     fn visit_const(&mut self, input: &ConstDeclaration) {
         self.visit_type(&input.type_);
 
@@ -2384,6 +2698,11 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             self.emit_err(crate::errors::type_checker::const_cannot_be_optional(input.span));
         }
 
+        // Enums are only lowered after const propagation, which therefore can't evaluate them.
+        if self.contains_enum_type(&input.type_) {
+            self.emit_err(crate::errors::type_checker::const_cannot_be_enum(input.span));
+        }
+
         // Check that the type of the definition is not a unit type, singleton tuple type, or nested tuple type.
         match &input.type_ {
             // If the type is an empty tuple, return an error.
@@ -2561,26 +2880,29 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
             // Set the type of the loop variable in the scope of the loop body.
             slf.state.symbol_table.set_local_type(input.variable.name, iterator_ty.clone());
 
-            let prior_has_return = core::mem::take(&mut slf.scope_state.has_return);
-            let prior_has_finalize = core::mem::take(&mut slf.scope_state.has_called_finalize);
+            slf.visit_loop_body(&input.block, input.span());
+        });
+    }
 
-            slf.visit_block(&input.block);
+    fn visit_while(&mut self, input: &WhileStatement) {
+        // A `while` loop is lowered to conditional branches setting an exit flag, like a loop with `break`.
+        if self.in_final_context() {
+            self.emit_err(crate::errors::type_checker::early_loop_exit_in_final("a `while` loop", input.span()));
+        }
 
-            if slf.scope_state.has_return {
-                slf.emit_err(crate::errors::type_checker::loop_body_contains_return(input.span()));
-            }
+        // The bound is an iteration count, which must be evaluated to a `u32` before the loop is unrolled.
+        self.visit_expression(&input.bound, &Some(Type::Integer(IntegerType::U32)));
+        self.visit_expression(&input.condition, &Some(Type::Boolean));
 
-            if slf.scope_state.has_called_finalize {
-                slf.emit_err(crate::errors::type_checker::loop_body_contains_final(input.span()));
-            }
+        self.visit_loop_body(&input.block, input.span());
+    }
 
-            if slf.scope_state.already_contains_an_async_block {
-                slf.emit_err(crate::errors::type_checker::loop_body_contains_final(input.span()));
-            }
+    fn visit_break(&mut self, input: &BreakStatement) {
+        self.check_loop_jump("break", input.span());
+    }
 
-            slf.scope_state.has_return = prior_has_return;
-            slf.scope_state.has_called_finalize = prior_has_finalize;
-        });
+    fn visit_continue(&mut self, input: &ContinueStatement) {
+        self.check_loop_jump("continue", input.span());
     }
 
     fn visit_return(&mut self, input: &ReturnStatement) {
@@ -2647,7 +2969,10 @@ impl AstVisitor for TypeCheckingVisitor<'_> {
                 // TODO - This is a bit hackish. We're reusing an existing error, because
                 // we have too many errors in TypeCheckerError without hitting the recursion
                 // limit for macros. But the error message to the user should still be pretty clear.
-                return self.emit_err(crate::errors::type_checker::missing_return(input.span()));
+                return self.emit_err(
+                    crate::errors::type_checker::missing_return(input.span())
+                        .with_suggestions(crate::errors::suggestions::return_value(&return_type, input.span())),
+                );
             }
         }
 
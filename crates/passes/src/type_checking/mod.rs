@@ -23,16 +23,14 @@ mod scope_state;
 mod visitor;
 use visitor::*;
 
-use self::scope_state::ScopeState;
 use crate::{CompilerState, Pass};
 
-use leo_ast::{Ast, CallGraph, CompositeGraph, NetworkName, UnitVisitor};
+use leo_ast::{Ast, Function, NetworkName, UnitVisitor};
 use leo_errors::Result;
+use leo_span::Symbol;
 
 use snarkvm::prelude::{CanaryV0, MainnetV0, Network, TestnetV0};
 
-use indexmap::{IndexMap, IndexSet};
-
 /// Specify network limits for type checking.
 #[derive(Clone)]
 pub struct TypeCheckingInput {
@@ -85,36 +83,13 @@ impl Pass for TypeChecking {
     const NAME: &'static str = "TypeChecking";
 
     fn do_pass(input: Self::Input, state: &mut CompilerState) -> Result<Self::Output> {
-        let composite_names = state
-            .symbol_table
-            .iter_records()
-            .map(|(loc, _)| loc.clone())
-            .chain(state.symbol_table.iter_structs().map(|(loc, _)| loc.clone()))
-            .collect();
-        let function_names: IndexSet<leo_ast::Location> =
-            state.symbol_table.iter_functions().map(|(loc, _)| loc.clone()).collect();
-
         let ast = std::mem::take(&mut state.ast);
 
-        // Initialize the composite graph with all the composites in the program.
-        state.composite_graph = CompositeGraph::new(composite_names);
-        // Reinitialize the call counts
-        state.call_count = function_names.iter().cloned().map(|n| (n, 0)).collect();
-        // Initialize the call graph with all the functions in the program.
-        state.call_graph = CallGraph::new(function_names);
-
-        let mut visitor = TypeCheckingVisitor {
-            state,
-            scope_state: ScopeState::new(),
-            async_function_input_types: IndexMap::new(),
-            async_function_callers: IndexMap::new(),
-            used_composites: IndexSet::new(),
-            conditional_scopes: Vec::new(),
-            limits: input,
-            async_block_id: None,
-            offchain_intrinsic_users: IndexSet::new(),
-            pending_offchain_checks: Vec::new(),
+        let program_span = match &ast {
+            Ast::Program(program) => program.program_scopes.values().next().map(|scope| scope.span),
+            Ast::Library(_) => None,
         };
+        let mut visitor = TypeCheckingVisitor::new(state, input, program_span);
 
         match &ast {
             Ast::Program(program) => visitor.visit_program(program),
@@ -153,3 +128,20 @@ impl Pass for TypeChecking {
         Ok(())
     }
 }
+
+impl TypeChecking {
+    /// Type checks `function` on its own, as if it were declared in the module `module` of the compilation unit
+    /// `unit`. The errors are emitted to the handler of `state`.
+    pub(crate) fn check_function(
+        input: TypeCheckingInput,
+        state: &mut CompilerState,
+        unit: Symbol,
+        module: Vec<Symbol>,
+        function: &Function,
+    ) {
+        let mut visitor = TypeCheckingVisitor::new(state, input, None);
+        visitor.scope_state.unit_name = Some(unit);
+        visitor.scope_state.module_name = module;
+        visitor.visit_function(function);
+    }
+}
@@ -170,6 +170,9 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
             }
         }
 
+        // Typecheck the associated functions of each composite.
+        input.composites.iter().flat_map(|(_, c)| &c.functions).for_each(|(_, function)| self.visit_function(function));
+
         if let Some(constructor) = &input.constructor {
             self.visit_constructor(constructor);
         }
@@ -221,6 +224,9 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
             self.visit_function(function);
         }
 
+        // Typecheck the associated functions of each composite.
+        input.composites.iter().flat_map(|(_, c)| &c.functions).for_each(|(_, function)| self.visit_function(function));
+
         self.scope_state.module_name = parent_module;
     }
 
@@ -234,6 +240,7 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
         input.consts.iter().for_each(|(_, c)| self.visit_const(c));
         input.interfaces.iter().for_each(|(_, i)| self.visit_interface(i));
         input.functions.iter().for_each(|(_, f)| self.visit_function(f));
+        input.structs.iter().flat_map(|(_, s)| &s.functions).for_each(|(_, f)| self.visit_function(f));
         input.modules.values().for_each(|m| {
             self.visit_module(m);
         });
@@ -309,9 +316,20 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
                 }
 
                 input.members.iter().for_each(|member| slf.visit_type(&member.type_));
+                input
+                    .variants
+                    .iter()
+                    .flatten()
+                    .flat_map(|variant| &variant.types)
+                    .for_each(|type_| slf.visit_type(type_));
             })
         });
 
+        if let Some(variants) = &input.variants {
+            self.visit_enum_variants(input, variants);
+            return;
+        }
+
         // Check for conflicting struct/record member names.
         let mut used = HashMap::new();
         for Member { identifier, type_, span, .. } in &input.members {
@@ -366,6 +384,9 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
                         identifier, type_, *span,
                     ));
                 }
+                if self.contains_enum_type(type_) {
+                    self.emit_err(crate::errors::type_checker::record_field_cannot_be_enum(identifier, type_, *span));
+                }
             }
         }
         // For structs, check that there is at least one member.
@@ -463,6 +484,14 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
             ))
         }
 
+        if self.contains_enum_type(&input.key_type) {
+            self.emit_err(crate::errors::type_checker::enum_type_not_allowed_in_mapping(
+                input.key_type.clone(),
+                "key",
+                input.span,
+            ))
+        }
+
         // Check that a mapping's value type is valid.
         self.assert_type_is_valid(&input.value_type, input.span);
         // Check that a mapping's value type is not a future, tuple, record or mapping.
@@ -503,6 +532,14 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
                 input.span,
             ))
         }
+
+        if self.contains_enum_type(&input.value_type) {
+            self.emit_err(crate::errors::type_checker::enum_type_not_allowed_in_mapping(
+                input.value_type.clone(),
+                "value",
+                input.span,
+            ))
+        }
     }
 
     fn visit_storage_variable(&mut self, input: &StorageVariable) {
@@ -533,7 +570,11 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
                 matches!(annotation.identifier.name, sym::test | sym::should_fail | sym::no_inline | sym::inline);
             let is_internal = matches!(
                 annotation.identifier.name,
-                sym::_caller_annotation | sym::_program_id_arg | sym::_callable_function_arg | sym::_onchain_context
+                sym::_caller_annotation
+                    | sym::_program_id_arg
+                    | sym::_callable_function_arg
+                    | sym::_onchain_context
+                    | sym::_test_cheatcode
             );
             if !(is_public || (is_internal && in_std)) {
                 self.emit_err(crate::errors::type_checker::unknown_annotation(annotation, annotation.span))
@@ -545,6 +586,10 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
         // `visit_call` enforces the caller-side rule.
         self.scope_state.has_onchain_context =
             function.annotations.iter().any(|a| a.identifier.name == sym::_onchain_context);
+        // Likewise, `@_test_cheatcode` wrappers evaluate a cheatcode intrinsic outside a native test.
+        self.scope_state.is_test_cheatcode =
+            function.annotations.iter().any(|a| a.identifier.name == sym::_test_cheatcode);
+        self.scope_state.is_native_test = function.is_native_test();
 
         let get = |symbol: Symbol| -> &Annotation {
             function.annotations.iter().find(|ann| ann.identifier.name == symbol).unwrap()
@@ -572,8 +617,16 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
             count > 0
         };
 
-        let has_test = check_annotation(sym::test, &[sym::private_key]);
-        let has_should_fail = check_annotation(sym::should_fail, &[]);
+        let has_test = check_annotation(sym::test, &[
+            sym::private_key,
+            sym::inputs,
+            sym::runs,
+            sym::bounds,
+            sym::output,
+            sym::mode,
+            sym::tags,
+        ]);
+        let has_should_fail = check_annotation(sym::should_fail, &[sym::message]);
 
         if has_test && !self.state.is_test {
             self.emit_err(crate::errors::type_checker::annotation_error(
@@ -582,6 +635,20 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
             ));
         }
 
+        if has_test
+            && let Some(mode) = get(sym::test).map.get(&sym::mode)
+            && !matches!(mode.as_str(), "ledger" | "native")
+        {
+            self.emit_err(crate::errors::type_checker::annotation_error(
+                format_args!("Invalid mode `{mode}` for annotation @test; expected `ledger` or `native`"),
+                get(sym::test).span,
+            ));
+        }
+
+        if has_test {
+            self.check_test_inputs(function, get(sym::test));
+        }
+
         if has_should_fail && !self.state.is_test {
             self.emit_err(crate::errors::type_checker::annotation_error(
                 format_args!("Test annotation @should_fail appears outside of tests"),
@@ -603,13 +670,6 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
             ));
         }
 
-        if (has_test) && !function.input.is_empty() {
-            self.emit_err(crate::errors::type_checker::annotation_error(
-                "A test procedure cannot have inputs",
-                function.input[0].span,
-            ));
-        }
-
         self.in_conditional_scope(|slf| {
             slf.in_scope(function.id, |slf| {
                 function
@@ -629,7 +689,9 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
 
                 // If the function has a return type, then check that it has a return.
                 if function.output_type != Type::Unit && !slf.scope_state.has_return {
-                    slf.emit_err(crate::errors::type_checker::missing_return(function.span));
+                    slf.emit_err(crate::errors::type_checker::missing_return(function.span).with_suggestions(
+                        crate::errors::suggestions::return_placeholder(&function.output_type, &function.block),
+                    ));
                 }
             })
         });
@@ -779,3 +841,58 @@ impl UnitVisitor for TypeCheckingVisitor<'_> {
         self.visit_composite(input);
     }
 }
+
+impl TypeCheckingVisitor<'_> {
+    /// Checks the variants of the enum `input`.
+    fn visit_enum_variants(&mut self, input: &Composite, variants: &[EnumVariant]) {
+        if variants.is_empty() {
+            self.emit_err(crate::errors::type_checker::empty_enum(input.identifier.name, input.span()));
+        } else if variants.len() > MAX_ENUM_VARIANTS {
+            self.emit_err(crate::errors::type_checker::too_many_enum_variants(
+                input.identifier.name,
+                MAX_ENUM_VARIANTS,
+                input.span(),
+            ));
+        }
+
+        let enum_path = self
+            .scope_state
+            .module_name
+            .iter()
+            .cloned()
+            .chain(std::iter::once(input.identifier.name))
+            .collect::<Vec<Symbol>>();
+        let enum_location = Location::new(self.scope_state.unit_name.unwrap(), enum_path);
+
+        let mut used = HashMap::new();
+        for variant in variants {
+            if let Some(first_span) = used.get(&variant.name()) {
+                self.emit_err(
+                    crate::errors::type_checker::duplicate_enum_variant(
+                        input.identifier.name,
+                        variant.name(),
+                        variant.span,
+                    )
+                    .with_labels(vec![
+                        Label::new(*first_span)
+                            .with_message(format!("`{}` first declared here", variant.name()))
+                            .with_color(leo_errors::Color::Blue),
+                        Label::new(variant.span).with_message("variant already declared"),
+                    ]),
+                );
+            } else {
+                used.insert(variant.name(), variant.span);
+            }
+
+            for type_ in &variant.types {
+                // Check that the payload type is defined, and that it can be stored in the lowered struct.
+                self.assert_type_is_valid(type_, variant.span);
+                if !self.is_enum_payload_supported(type_) {
+                    self.emit_err(crate::errors::type_checker::unsupported_enum_payload_type(type_, variant.span));
+                }
+                // Payloads are stored inline, so a recursive enum is as invalid as a recursive struct.
+                add_composite_dependencies(type_, &enum_location, &mut self.state.composite_graph);
+            }
+        }
+    }
+}
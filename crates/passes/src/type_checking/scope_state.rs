@@ -40,6 +40,8 @@ pub struct ScopeState {
     pub(crate) already_contains_an_async_block: bool,
     /// Whether we are currently traversing a conditional statement.
     pub(crate) is_conditional: bool,
+    /// Whether we are currently traversing the body of a loop.
+    pub(crate) is_loop: bool,
     /// Location of most recent external call that produced a future.
     pub(crate) call_location: Option<Location>,
     /// Whether we are currently traversing a constructor.
@@ -48,6 +50,12 @@ pub struct ScopeState {
     /// stdlib wrappers whose body invokes a `FinalizeRead` intrinsic; propagation at each
     /// callsite enforces that the wrapper's own caller is in a finalize or view scope.
     pub(crate) has_onchain_context: bool,
+    /// Whether the enclosing function is a `@test(mode = "native")` function, which runs in the
+    /// interpreter and may use cheatcodes and read or write any program's state.
+    pub(crate) is_native_test: bool,
+    /// Whether the enclosing function is annotated `@_test_cheatcode`. Set while visiting the
+    /// `std::test` wrappers; callsite checks in `visit_call` confine them to native tests.
+    pub(crate) is_test_cheatcode: bool,
 }
 
 impl ScopeState {
@@ -64,9 +72,12 @@ impl ScopeState {
             has_called_finalize: false,
             already_contains_an_async_block: false,
             is_conditional: false,
+            is_loop: false,
             call_location: None,
             is_constructor: false,
             has_onchain_context: false,
+            is_native_test: false,
+            is_test_cheatcode: false,
         }
     }
 
@@ -78,11 +89,14 @@ impl ScopeState {
         self.is_stub = false;
         self.has_called_finalize = false;
         self.is_conditional = false;
+        self.is_loop = false;
         self.call_location = None;
         self.is_constructor = false;
         self.already_contains_an_async_block = false;
         self.futures = IndexMap::new();
         self.has_onchain_context = false;
+        self.is_native_test = false;
+        self.is_test_cheatcode = false;
     }
 
     /// Get the current location.
@@ -36,7 +36,7 @@ use snarkvm::{
         SerializeVariant,
     },
 };
-use std::ops::Deref;
+use std::{ops::Deref, str::FromStr as _};
 
 /// Where in the program text a snarkVM-bound access operation is permitted.
 ///
@@ -79,6 +79,44 @@ pub struct TypeCheckingVisitor<'a> {
     /// Each entry is a call from a scope that would inline the callee into a finalize context;
     /// if closure analysis proves the callee reaches an offchain intrinsic, the call must error.
     pub pending_offchain_checks: Vec<(Span, Location)>,
+    /// Span of the program scope being checked, used to place suggested `import`s.
+    pub program_span: Option<Span>,
+}
+
+impl<'a> TypeCheckingVisitor<'a> {
+    /// Creates a visitor over the items of the symbol table of `state`, resetting the graphs and call counts that
+    /// type checking builds.
+    pub fn new(state: &'a mut CompilerState, limits: TypeCheckingInput, program_span: Option<Span>) -> Self {
+        let composite_names = state
+            .symbol_table
+            .iter_records()
+            .map(|(loc, _)| loc.clone())
+            .chain(state.symbol_table.iter_structs().map(|(loc, _)| loc.clone()))
+            .collect();
+        let function_names: IndexSet<Location> =
+            state.symbol_table.iter_functions().map(|(loc, _)| loc.clone()).collect();
+
+        // Initialize the composite graph with all the composites in the program.
+        state.composite_graph = CompositeGraph::new(composite_names);
+        // Reinitialize the call counts
+        state.call_count = function_names.iter().cloned().map(|n| (n, 0)).collect();
+        // Initialize the call graph with all the functions in the program.
+        state.call_graph = CallGraph::new(function_names);
+
+        Self {
+            state,
+            scope_state: ScopeState::new(),
+            async_function_input_types: IndexMap::new(),
+            async_function_callers: IndexMap::new(),
+            used_composites: IndexSet::new(),
+            conditional_scopes: Vec::new(),
+            limits,
+            async_block_id: None,
+            offchain_intrinsic_users: IndexSet::new(),
+            pending_offchain_checks: Vec::new(),
+            program_span,
+        }
+    }
 }
 
 impl TypeCheckingVisitor<'_> {
@@ -89,6 +127,184 @@ impl TypeCheckingVisitor<'_> {
         result
     }
 
+    /// Visits the body of a `for` or `while` loop spanning `span`.
+    pub fn visit_loop_body(&mut self, block: &Block, span: Span) {
+        let prior_has_return = core::mem::take(&mut self.scope_state.has_return);
+        let prior_has_finalize = core::mem::take(&mut self.scope_state.has_called_finalize);
+        let prior_is_loop = core::mem::replace(&mut self.scope_state.is_loop, true);
+
+        self.visit_block(block);
+
+        if self.scope_state.has_return {
+            self.emit_err(crate::errors::type_checker::loop_body_contains_return(span));
+        }
+
+        if self.scope_state.has_called_finalize {
+            self.emit_err(crate::errors::type_checker::loop_body_contains_final(span));
+        }
+
+        if self.scope_state.already_contains_an_async_block {
+            self.emit_err(crate::errors::type_checker::loop_body_contains_final(span));
+        }
+
+        self.scope_state.has_return = prior_has_return;
+        self.scope_state.has_called_finalize = prior_has_finalize;
+        self.scope_state.is_loop = prior_is_loop;
+    }
+
+    /// Whether the statements being visited run on-chain, in a final function or a `final` block.
+    pub fn in_final_context(&self) -> bool {
+        self.scope_state.variant.is_some_and(|variant| variant.is_finalize_context()) || self.async_block_id.is_some()
+    }
+
+    /// Checks that a `break` or `continue` statement exits a loop that can be lowered.
+    ///
+    /// Loop unrolling lowers the jumps to flags declared before the loop and set in conditional branches. Final code
+    /// can't reassign a variable in a narrower conditional scope than its declaration, so the jumps are rejected
+    /// there up front, rather than with an error about the flags once the loop is unrolled.
+    pub fn check_loop_jump(&mut self, keyword: &str, span: Span) {
+        if !self.scope_state.is_loop {
+            self.emit_err(crate::errors::type_checker::jump_outside_of_loop(keyword, span));
+        } else if self.in_final_context() {
+            self.emit_err(crate::errors::type_checker::early_loop_exit_in_final(format!("`{keyword}`"), span));
+        }
+    }
+
+    /// Checks the values of the keys of a `@test` annotation that the test runner parses: `runs`, and the `inputs`
+    /// table, which must have a row of values of the types of the parameters of `function` for each case.
+    pub fn check_test_inputs(&mut self, function: &Function, annotation: &Annotation) {
+        let invalid = |message: String| crate::errors::type_checker::annotation_error(message, annotation.span);
+
+        if let Some(runs) = annotation.map.get(&sym::runs)
+            && !runs.parse::<usize>().is_ok_and(|runs| runs > 0)
+        {
+            self.emit_err(invalid(format!(
+                "Invalid value `{runs}` for key `runs` of annotation @test; expected a positive integer"
+            )));
+        }
+
+        let Some(table) = annotation.map.get(&sym::inputs) else {
+            return;
+        };
+        if annotation.map.contains_key(&sym::runs) || annotation.map.contains_key(&sym::bounds) {
+            self.emit_err(invalid(
+                "Keys `runs` and `bounds` of annotation @test apply to generated inputs, not to an `inputs` table"
+                    .to_string(),
+            ));
+        }
+        let Some(rows) = Annotation::split_list(table) else {
+            self.emit_err(invalid(format!("Invalid `inputs` `{table}` for annotation @test; expected a list of rows")));
+            return;
+        };
+        for row in rows {
+            let Some(values) = Annotation::split_list(row) else {
+                self.emit_err(invalid(format!("Row `{row}` of `inputs` for annotation @test is not a list of values")));
+                continue;
+            };
+            if values.len() != function.input.len() {
+                self.emit_err(invalid(format!(
+                    "Row `{row}` of `inputs` for annotation @test has {} values, but `{}` takes {}",
+                    values.len(),
+                    function.identifier,
+                    function.input.len(),
+                )));
+                continue;
+            }
+            for (value, input) in values.into_iter().zip(&function.input) {
+                if let Err(reason) = self.check_test_value(value, input.type_()) {
+                    self.emit_err(invalid(format!(
+                        "Invalid value for `{}` in row `{row}` of `inputs` for annotation @test: {reason}",
+                        input.identifier,
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Checks that `text`, a value written as `leo run` takes it, has the type `ty`. Returns why it doesn't otherwise.
+    ///
+    /// Optionals are written in their lowered form, `{ is_some: true, val: 5u8 }`. Values of the types that the test
+    /// runner can't check ahead of the run, like records, are accepted.
+    fn check_test_value(&self, text: &str, ty: &Type) -> Result<(), String> {
+        let mismatch = || Err(format!("`{text}` is not a value of type `{ty}`"));
+        match ty {
+            Type::Address
+            | Type::Boolean
+            | Type::Field
+            | Type::Group
+            | Type::Integer(_)
+            | Type::Scalar
+            | Type::Signature => {
+                // The literal types of snarkVM are named like Leo's, except for `boolean`.
+                let expected = if *ty == Type::Boolean { "boolean".to_string() } else { ty.to_string() };
+                match snarkvm::prelude::Literal::<snarkvm::prelude::TestnetV0>::from_str(text) {
+                    Ok(literal) if literal.to_type().to_string() == expected => Ok(()),
+                    _ => mismatch(),
+                }
+            }
+            Type::Array(array) => {
+                let Some(elements) = Annotation::split_list(text) else {
+                    return mismatch();
+                };
+                if array.length.as_u32().is_some_and(|length| length as usize != elements.len()) {
+                    return Err(format!("`{text}` has {} elements, but `{ty}` has {}", elements.len(), array.length));
+                }
+                elements.into_iter().try_for_each(|element| self.check_test_value(element, array.element_type()))
+            }
+            Type::Optional(optional) => {
+                let fields = [(Symbol::intern("is_some"), &Type::Boolean), (Symbol::intern("val"), &*optional.inner)];
+                self.check_test_fields(text, ty, fields.into_iter())
+            }
+            Type::Composite(composite) => {
+                let unit = self.scope_state.unit_name.unwrap();
+                let Some(location) = composite.path.try_global_location() else {
+                    return Ok(());
+                };
+                match self.state.symbol_table.lookup_struct(unit, location) {
+                    Some(struct_) => self.check_test_fields(
+                        text,
+                        ty,
+                        struct_.members.iter().map(|member| (member.identifier.name, &member.type_)),
+                    ),
+                    None => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that `text` is written `{ name: value, ... }` with a value of the right type for each of `fields`.
+    fn check_test_fields<'t>(
+        &self,
+        text: &str,
+        ty: &Type,
+        fields: impl Iterator<Item = (Symbol, &'t Type)>,
+    ) -> Result<(), String> {
+        let Some(inner) = text.trim().strip_prefix('{').and_then(|text| text.strip_suffix('}')) else {
+            return Err(format!("`{text}` is not a value of type `{ty}`"));
+        };
+        let list = format!("[{inner}]");
+        let Some(mut values) = Annotation::split_list(&list).map(|elements| {
+            elements
+                .into_iter()
+                .filter_map(|element| element.split_once(':'))
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .collect::<IndexMap<_, _>>()
+        }) else {
+            return Err(format!("`{text}` is not a value of type `{ty}`"));
+        };
+        for (name, field_type) in fields {
+            let Some(value) = values.shift_remove(name.to_string().as_str()) else {
+                return Err(format!("`{text}` has no field `{name}`"));
+            };
+            self.check_test_value(&value, field_type)?;
+        }
+        match values.keys().next() {
+            Some(name) => Err(format!("`{text}` has a field `{name}`, which `{ty}` doesn't have")),
+            None => Ok(()),
+        }
+    }
+
     pub fn in_conditional_scope<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
         self.conditional_scopes.push(Default::default());
         let result = func(self);
@@ -229,9 +445,10 @@ impl TypeCheckingVisitor<'_> {
     /// Emits an error if the two given types are not equal.
     pub fn check_eq_types(&self, t1: &Option<Type>, t2: &Option<Type>, span: Span) {
         match (t1, t2) {
-            (Some(t1), Some(t2)) if !t1.types_equivalent(t2) => {
-                self.emit_err(crate::errors::type_checker::type_should_be(t1, t2, span))
-            }
+            (Some(t1), Some(t2)) if !t1.types_equivalent(t2) => self.emit_err(
+                crate::errors::type_checker::type_should_be(t1, t2, span)
+                    .with_suggestions(crate::errors::suggestions::cast(t1, t2, span)),
+            ),
             (Some(type_), None) | (None, Some(type_)) => {
                 self.emit_err(crate::errors::type_checker::type_should_be("no type", type_, span))
             }
@@ -258,8 +475,20 @@ impl TypeCheckingVisitor<'_> {
     pub fn assert_type(&mut self, actual: &Type, expected: &Type, span: Span) {
         if actual != &Type::Err && !actual.can_coerce_to(expected) {
             // If `actual` is Err, we will have already reported an error.
-            self.emit_err(crate::errors::type_checker::type_should_be2(actual, format!("type `{expected}`"), span));
+            self.emit_err(
+                crate::errors::type_checker::type_should_be2(actual, format!("type `{expected}`"), span)
+                    .with_suggestions(crate::errors::suggestions::cast(actual, expected, span)),
+            );
+        }
+    }
+
+    /// Suggests importing `program` when it is an external program the current program does not import.
+    pub fn import_suggestion(&self, program: Symbol) -> Option<leo_errors::Suggestion> {
+        let current = self.scope_state.unit_name?;
+        if program == current || self.state.symbol_table.get_imports(&current).contains(&program) {
+            return None;
         }
+        crate::errors::suggestions::import_program(program, self.program_span?)
     }
 
     /// Unwraps an optional type to its inner type for use with operands.
@@ -1128,8 +1357,8 @@ impl TypeCheckingVisitor<'_> {
                     return Type::Err;
                 }
 
-                // Argument 0 must be a local path (cannot modify external mappings).
-                if !is_local_path(map_expr) {
+                // Argument 0 must be a local path (cannot modify external mappings) outside native tests.
+                if !is_local_path(map_expr) && !self.in_native_test() {
                     self.state.handler.emit_err(crate::errors::type_checker::cannot_modify_external_container(
                         "set",
                         "mapping",
@@ -1178,8 +1407,8 @@ impl TypeCheckingVisitor<'_> {
                     return Type::Err;
                 }
 
-                // Argument 0 must be a local path (cannot modify external mappings).
-                if !is_local_path(map_expr) {
+                // Argument 0 must be a local path (cannot modify external mappings) outside native tests.
+                if !is_local_path(map_expr) && !self.in_native_test() {
                     self.state.handler.emit_err(crate::errors::type_checker::cannot_modify_external_container(
                         "remove",
                         "mapping",
@@ -1264,8 +1493,8 @@ impl TypeCheckingVisitor<'_> {
                     return Type::Err;
                 }
 
-                // Argument 0 must be a local path (cannot modify external vectors).
-                if !is_local_path(vec_expr) {
+                // Argument 0 must be a local path (cannot modify external vectors) outside native tests.
+                if !is_local_path(vec_expr) && !self.in_native_test() {
                     self.state.handler.emit_err(crate::errors::type_checker::cannot_modify_external_container(
                         "set",
                         "vector",
@@ -1296,8 +1525,8 @@ impl TypeCheckingVisitor<'_> {
                     return Type::Err;
                 }
 
-                // Argument 0 must be a local path (cannot modify external vectors).
-                if !is_local_path(vec_expr) {
+                // Argument 0 must be a local path (cannot modify external vectors) outside native tests.
+                if !is_local_path(vec_expr) && !self.in_native_test() {
                     self.state.handler.emit_err(crate::errors::type_checker::cannot_modify_external_container(
                         "push",
                         "vector",
@@ -1340,8 +1569,8 @@ impl TypeCheckingVisitor<'_> {
                     return Type::Err;
                 }
 
-                // Argument 0 must be a local path (cannot modify external vectors).
-                if !is_local_path(vec_expr) {
+                // Argument 0 must be a local path (cannot modify external vectors) outside native tests.
+                if !is_local_path(vec_expr) && !self.in_native_test() {
                     self.state.handler.emit_err(crate::errors::type_checker::cannot_modify_external_container(
                         "pop",
                         "vector",
@@ -1367,8 +1596,8 @@ impl TypeCheckingVisitor<'_> {
                     return Type::Err;
                 }
 
-                // Argument 0 must be a local path (cannot modify external vectors).
-                if !is_local_path(vec_expr) {
+                // Argument 0 must be a local path (cannot modify external vectors) outside native tests.
+                if !is_local_path(vec_expr) && !self.in_native_test() {
                     self.state.handler.emit_err(crate::errors::type_checker::cannot_modify_external_container(
                         "swap_remove",
                         "vector",
@@ -1393,8 +1622,8 @@ impl TypeCheckingVisitor<'_> {
                     return Type::Err;
                 }
 
-                // Argument 0 must be a local path (cannot modify external vectors).
-                if !is_local_path(vec_expr) {
+                // Argument 0 must be a local path (cannot modify external vectors) outside native tests.
+                if !is_local_path(vec_expr) && !self.in_native_test() {
                     self.state.handler.emit_err(crate::errors::type_checker::cannot_modify_external_container(
                         "clear",
                         "vector",
@@ -1690,6 +1919,34 @@ impl TypeCheckingVisitor<'_> {
                 self.check_access_allowed("std::ctx::network_id()", AccessScope::FinalizeRead, function_span);
                 Type::Integer(IntegerType::U16)
             }
+            Intrinsic::TestSetCaller => {
+                self.check_cheatcode_allowed("std::test::set_caller()", function_span);
+                self.assert_type(&arguments[0].0, &Type::Address, arguments[0].1.span());
+                Type::Unit
+            }
+            Intrinsic::TestSetSigner => {
+                self.check_cheatcode_allowed("std::test::set_signer()", function_span);
+                self.assert_type(&arguments[0].0, &Type::Address, arguments[0].1.span());
+                Type::Unit
+            }
+            Intrinsic::TestWarp => {
+                self.check_cheatcode_allowed("std::test::warp()", function_span);
+                self.assert_type(&arguments[0].0, &Type::Integer(IntegerType::U32), arguments[0].1.span());
+                Type::Unit
+            }
+            Intrinsic::TestExpectRevert => {
+                self.check_cheatcode_allowed("std::test::expect_revert()", function_span);
+                Type::Unit
+            }
+            Intrinsic::TestSnapshot => {
+                self.check_cheatcode_allowed("std::test::snapshot()", function_span);
+                Type::Integer(IntegerType::U32)
+            }
+            Intrinsic::TestRestore => {
+                self.check_cheatcode_allowed("std::test::restore()", function_span);
+                self.assert_type(&arguments[0].0, &Type::Integer(IntegerType::U32), arguments[0].1.span());
+                Type::Unit
+            }
             // Dynamic dispatch intrinsics are handled in visit_intrinsic before check_intrinsic.
             Intrinsic::DynamicCall
             | Intrinsic::DynamicContains
@@ -2060,6 +2317,13 @@ impl TypeCheckingVisitor<'_> {
                             return true;
                         }
                     }
+
+                    // and the payloads of all variants, if it's an enum
+                    for payload_ty in composite.variants.iter().flatten().flat_map(|variant| &variant.types) {
+                        if self.disallowed_inside_optional_inner(payload_ty, visited_paths) {
+                            return true;
+                        }
+                    }
                 }
                 false
             }
@@ -2083,6 +2347,68 @@ impl TypeCheckingVisitor<'_> {
         }
     }
 
+    /// Can type `ty` be carried by an enum variant?
+    ///
+    /// Enums are lowered to structs with a field per payload value, and the fields of the variants not
+    /// constructed are set to zero, so payloads are limited to types with a zero value that a struct can hold.
+    pub fn is_enum_payload_supported(&mut self, ty: &Type) -> bool {
+        let mut visited_paths = IndexSet::<Vec<Symbol>>::new();
+        self.is_enum_payload_supported_inner(ty, &mut visited_paths)
+    }
+
+    fn is_enum_payload_supported_inner(&mut self, ty: &Type, visited_paths: &mut IndexSet<Vec<Symbol>>) -> bool {
+        match ty {
+            Type::Address
+            | Type::Boolean
+            | Type::Field
+            | Type::Group
+            | Type::Integer(_)
+            | Type::Scalar
+            | Type::Signature
+            | Type::Err => true,
+
+            Type::Array(array_type) => self.is_enum_payload_supported_inner(array_type.element_type(), visited_paths),
+
+            Type::Composite(composite_type) => {
+                let composite_location = composite_type.path.expect_global_location();
+
+                // Recursive types are reported by the composite graph check.
+                if !visited_paths.insert(composite_location.path.clone()) {
+                    return true;
+                }
+
+                // Undefined types are reported by `assert_type_is_valid`.
+                let Some(composite) = self.lookup_composite(composite_location) else {
+                    return true;
+                };
+                if composite.is_record {
+                    return false;
+                }
+
+                let member_types = composite.members.iter().map(|member| &member.type_);
+                let payload_types = composite.variants.iter().flatten().flat_map(|variant| &variant.types);
+                for ty in member_types.chain(payload_types) {
+                    if !self.is_enum_payload_supported_inner(ty, visited_paths) {
+                        return false;
+                    }
+                }
+                true
+            }
+
+            Type::Unit
+            | Type::Future(_)
+            | Type::Ident(_)
+            | Type::Mapping(_)
+            | Type::Optional(_)
+            | Type::String
+            | Type::Identifier
+            | Type::DynRecord
+            | Type::Numeric
+            | Type::Tuple(_)
+            | Type::Vector(_) => false,
+        }
+    }
+
     /// Ensures the given type is valid for use in storage.
     /// Emits an error if the type or any of its inner types are invalid.
     pub fn assert_storage_type_is_valid(&mut self, type_: &Type, span: Span) {
@@ -2123,6 +2449,10 @@ impl TypeCheckingVisitor<'_> {
                         self.emit_err(crate::errors::type_checker::invalid_storage_type("record", span));
                         return;
                     }
+                    if composite.is_enum() {
+                        self.emit_err(crate::errors::type_checker::invalid_storage_type("enum", span));
+                        return;
+                    }
 
                     // Recursively check fields.
                     for field in &composite.members {
@@ -2243,6 +2573,45 @@ impl TypeCheckingVisitor<'_> {
         }
     }
 
+    /// Returns whether `ty` is or contains an enum.
+    ///
+    /// Values of such types cannot enter a program from outside, as nothing guarantees that their tags name a variant.
+    pub fn contains_enum_type(&mut self, ty: &Type) -> bool {
+        let mut visited_paths = IndexSet::<Vec<Symbol>>::new();
+        self.contains_enum_type_inner(ty, &mut visited_paths)
+    }
+
+    fn contains_enum_type_inner(&mut self, ty: &Type, visited_paths: &mut IndexSet<Vec<Symbol>>) -> bool {
+        match ty {
+            Type::Optional(optional) => self.contains_enum_type_inner(&optional.inner, visited_paths),
+
+            Type::Tuple(tuple) => tuple.elements.iter().any(|e| self.contains_enum_type_inner(e, visited_paths)),
+
+            Type::Array(array) => self.contains_enum_type_inner(&array.element_type, visited_paths),
+
+            Type::Composite(composite_type) => {
+                let composite_location = composite_type.path.expect_global_location();
+
+                // Prevent revisiting the same type
+                if !visited_paths.insert(composite_location.path.clone()) {
+                    return false;
+                }
+
+                if let Some(comp) = self.lookup_composite(composite_location) {
+                    comp.is_enum()
+                        || comp
+                            .members
+                            .iter()
+                            .any(|Member { type_, .. }| self.contains_enum_type_inner(type_, visited_paths))
+                } else {
+                    false
+                }
+            }
+
+            _ => false,
+        }
+    }
+
     pub fn assert_array_type(&self, type_: &Type, span: Span) {
         if type_ != &Type::Err && !matches!(type_, Type::Array(_)) {
             self.emit_err(crate::errors::type_checker::type_should_be2(type_, "an array", span));
@@ -2386,6 +2755,15 @@ impl TypeCheckingVisitor<'_> {
                 ))
             }
 
+            // Check that the type of the input parameter does not contain an enum.
+            if function.variant.is_externally_callable() && self.contains_enum_type(table_type) {
+                self.emit_err(crate::errors::type_checker::function_cannot_take_enum_as_input(
+                    input.identifier,
+                    table_type,
+                    input.span(),
+                ))
+            }
+
             // Make sure only transitions can take a record as an input.
             if let Type::Composite(composite) = table_type {
                 // Throw error for undefined type.
@@ -2507,6 +2885,14 @@ impl TypeCheckingVisitor<'_> {
                 ))
             }
 
+            // Check that the type of the output does not contain an enum.
+            if function.variant.is_externally_callable() && self.contains_enum_type(&function_output.type_) {
+                self.emit_err(crate::errors::type_checker::function_cannot_return_enum_as_output(
+                    &function_output.type_,
+                    function_output.span(),
+                ))
+            }
+
             // Check that the mode of the output is valid.
             // Records and `Final`s lower to `.record`/`.future` markers, neither of which carries a
             // visibility, so an explicit mode on such an output is meaningless. These types are only
@@ -2610,13 +2996,45 @@ impl TypeCheckingVisitor<'_> {
         comp.cloned()
     }
 
+    /// If `loc` names a variant of an enum, returns the location of the enum, the enum, and the index of the variant.
+    pub fn lookup_enum_variant(&mut self, loc: &Location) -> Option<(Location, Composite, usize)> {
+        let (variant, enum_path) = loc.path.split_last()?;
+        if enum_path.is_empty() {
+            return None;
+        }
+        let enum_location = Location::new(loc.program, enum_path.to_vec());
+        let current_program = self.scope_state.unit_name.unwrap();
+        let enum_ = self.state.symbol_table.lookup_struct(current_program, &enum_location)?;
+        let index = enum_.variants.as_ref()?.iter().position(|v| v.name() == *variant)?;
+        let enum_ = enum_.clone();
+        // Record the usage.
+        self.used_composites.insert(enum_location.clone());
+        Some((enum_location, enum_, index))
+    }
+
+    /// Returns the type of the enum at `enum_location`, named as the variant `path` names it, i.e. `Shape` for
+    /// `Shape::Circle`.
+    pub fn enum_type_of_variant(&self, path: &Path, enum_location: Location) -> Type {
+        let mut qualifier = path.qualifier().to_vec();
+        let identifier = qualifier.pop().unwrap_or(*path.identifier());
+        let path = Path::new(path.user_program().copied(), qualifier, identifier, path.span(), Default::default())
+            .to_global(enum_location);
+        Type::Composite(CompositeType { path, const_arguments: vec![] })
+    }
+
     /// Emits `inaccessible_item` if `comp` is not visible from the current scope. `span` is the
     /// user's reference site, not the declaration. Returns `true` when accessible.
     pub fn check_composite_accessible(&mut self, loc: &Location, comp: &Composite, span: Span) -> bool {
         if self.scope_state.is_accessible(loc, comp.is_exported) {
             return true;
         }
-        let kind = if comp.is_record { "record" } else { "struct" };
+        let kind = if comp.is_record {
+            "record"
+        } else if comp.is_enum() {
+            "enum"
+        } else {
+            "struct"
+        };
         self.emit_err(crate::errors::type_checker::inaccessible_item(kind, comp.identifier.name, span));
         false
     }
@@ -2674,6 +3092,11 @@ impl TypeCheckingVisitor<'_> {
             return;
         }
 
+        // The interpreter runs native tests, so they can read and write state anywhere in their body.
+        if matches!(scope, AccessScope::FinalizeRead | AccessScope::FinalizeWrite) && self.in_native_test() {
+            return;
+        }
+
         let in_view = matches!(self.scope_state.variant, Some(Variant::View));
         let in_finalize_ctx = self.scope_state.variant.is_some_and(|v| v.is_finalize_context());
         let in_async_block = self.async_block_id.is_some();
@@ -2717,6 +3140,24 @@ impl TypeCheckingVisitor<'_> {
         }
     }
 
+    /// Whether the current function is a native test, or the finalize function lifted from the
+    /// `final {}` block of one. Only the interpreter runs these, so they may use cheatcodes and
+    /// modify the state of any program; `LibraryPruning` drops them before code generation.
+    ///
+    /// Tests of the other mode are type checked before their `final {}` blocks are lifted, so any
+    /// lifted finalize function of a test program that gets this far follows the same rules.
+    pub fn in_native_test(&self) -> bool {
+        self.state.is_test
+            && (self.scope_state.is_native_test || matches!(self.scope_state.variant, Some(Variant::Finalize)))
+    }
+
+    /// Rejects the cheatcode `name` outside native tests and the `std::test` wrappers over it.
+    fn check_cheatcode_allowed(&mut self, name: &str, span: Span) {
+        if !self.scope_state.is_test_cheatcode && !self.in_native_test() {
+            self.emit_err(crate::errors::type_checker::cheatcode_outside_native_test(name, span));
+        }
+    }
+
     pub fn is_external_record(&self, ty: &Type) -> bool {
         if let Type::Composite(typ) = &ty {
             let this_program = self.scope_state.unit_name.unwrap();